@@ -13,8 +13,10 @@ let rust = `/*
 
 \tData source: https://bulbapedia.bulbagarden.net/wiki/List_of_items_by_index_number_in_Generation_IV
 */
+use crate::save::error::UnknownValue;
 
-#[derive(Debug, Eq, Hash, PartialEq)]
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+#[repr(u16)]
 pub enum DPPTItem {`;
 let dedupe = [];
 for (const item of json) {
@@ -29,10 +31,35 @@ for (const item of json) {
     rust += '\n\t' + `${name} = ${item.id},`;
 }
 rust += `
+	/// An ID with no known item, e.g. from a corrupted save
+	Unknown(u16),
 }
 
-impl From<u16> for DPPTItem {
-    fn from(value: u16) -> Self {
+impl TryFrom<u16> for DPPTItem {
+    type Error = UnknownValue<u16>;
+
+    fn try_from(value: u16) -> Result<Self, Self::Error> {
+        match value {`;
+dedupe = [];
+for (const item of json) {
+    if (item.name.toLowerCase() ==  "unknown" || dedupe.includes(item.id)) continue;
+    let name = item.name
+        .replace('♀', ' Female')
+        .replace('♂', ' Male')
+        .normalize('NFKD')
+        .replace(/\w\S*/g, function(text) { return text.charAt(0).toUpperCase() + text.substr(1).toLowerCase() })
+        .replace(/\W/g, '');
+    dedupe.push(item.id);
+    rust += '\n\t\t\t' + `${item.id} => Ok(DPPTItem::${name}),`;
+}
+rust += `
+            _ => Err(UnknownValue { name: "DPPTItem", value })
+        }
+    }
+}
+
+impl From<DPPTItem> for u16 {
+    fn from(value: DPPTItem) -> Self {
         match value {`;
 dedupe = [];
 for (const item of json) {
@@ -44,10 +71,10 @@ for (const item of json) {
         .replace(/\w\S*/g, function(text) { return text.charAt(0).toUpperCase() + text.substr(1).toLowerCase() })
         .replace(/\W/g, '');
     dedupe.push(item.id);
-    rust += '\n\t\t\t' + `${item.id} => DPPTItem::${name},`;
+    rust += '\n\t\t\t' + `DPPTItem::${name} => ${item.id},`;
 }
 rust += `
-            _ => unreachable!()
+            DPPTItem::Unknown(value) => value,
         }
     }
 }`;
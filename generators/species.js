@@ -9,7 +9,10 @@ fetch('https://raw.githubusercontent.com/Purukitto/pokemon-data.json/refs/heads/
 
 \tData source: https://raw.githubusercontent.com/Purukitto/pokemon-data.json/refs/heads/master/pokedex.json
 */
-#[derive(Debug)]
+use crate::save::error::UnknownValue;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(u16)]
 pub enum Species {`
         for (const pokemon of res) {
             let name = pokemon.name.english
@@ -20,10 +23,34 @@ pub enum Species {`
                 .replace(/\W/g, '');
             rust += '\n\t' + `${name} = ${pokemon.id},`;
         }
-        rust += `}
+        rust += `
+	/// An ID with no known species, e.g. from a corrupted save
+	Unknown(u16),
+}
+
+impl TryFrom<u16> for Species {
+    type Error = UnknownValue<u16>;
+
+    fn try_from(value: u16) -> Result<Self, Self::Error> {
+        match value {`;
+
+        for (const pokemon of res) {
+            let name = pokemon.name.english
+                .replace('♀', ' Female')
+                .replace('♂', ' Male')
+                .normalize('NFKD')
+                .replace(/\w\S*/g, function(text) { return text.charAt(0).toUpperCase() + text.substr(1).toLowerCase() })
+                .replace(/\W/g, '');
+            rust += '\n\t\t\t' + `${pokemon.id} => Ok(Species::${name}),`;
+        }
+        rust += `
+            _ => Err(UnknownValue { name: "Species", value })
+        }
+    }
+}
 
-impl From<u16> for Species {
-    fn from(value: u16) -> Self {
+impl From<Species> for u16 {
+    fn from(value: Species) -> Self {
         match value {`;
 
         for (const pokemon of res) {
@@ -33,10 +60,10 @@ impl From<u16> for Species {
                 .normalize('NFKD')
                 .replace(/\w\S*/g, function(text) { return text.charAt(0).toUpperCase() + text.substr(1).toLowerCase() })
                 .replace(/\W/g, '');
-            rust += '\n\t\t\t' + `${pokemon.id} => Species::${name},`;
+            rust += '\n\t\t\t' + `Species::${name} => ${pokemon.id},`;
         }
         rust += `
-            _ => unreachable!()
+            Species::Unknown(value) => value,
         }
     }
 }`;
@@ -0,0 +1,67 @@
+/*
+* The species are national dex order, with `stats` as HP, Attack, Defense, Sp. Atk, Sp. Def & Speed like Bulbapedia
+* lists them. `forms` are the alternate forms with their own entries in the games' personal data, on top of the
+* species' own entry.
+* */
+const json = {"species":[{"id":1,"name":"Bulbasaur","stats":[45,49,49,65,65,45],"types":["Grass","Poison"],"abilities":["Overgrow"],"gender":31,"growth":"MediumSlow","catchRate":45,"friendship":70,"eggGroups":["Monster","Grass"],"hatchCycles":20},{"id":2,"name":"Ivysaur","stats":[60,62,63,80,80,60],"types":["Grass","Poison"],"abilities":["Overgrow"],"gender":31,"growth":"MediumSlow","catchRate":45,"friendship":70,"eggGroups":["Monster","Grass"],"hatchCycles":20},{"id":3,"name":"Venusaur","stats":[80,82,83,100,100,80],"types":["Grass","Poison"],"abilities":["Overgrow"],"gender":31,"growth":"MediumSlow","catchRate":45,"friendship":70,"eggGroups":["Monster","Grass"],"hatchCycles":20},{"id":4,"name":"Charmander","stats":[39,52,43,60,50,65],"types":["Fire"],"abilities":["Blaze"],"gender":31,"growth":"MediumSlow","catchRate":45,"friendship":70,"eggGroups":["Monster","Dragon"],"hatchCycles":20},{"id":5,"name":"Charmeleon","stats":[58,64,58,80,65,80],"types":["Fire"],"abilities":["Blaze"],"gender":31,"growth":"MediumSlow","catchRate":45,"friendship":70,"eggGroups":["Monster","Dragon"],"hatchCycles":20},{"id":6,"name":"Charizard","stats":[78,84,78,109,85,100],"types":["Fire","Flying"],"abilities":["Blaze"],"gender":31,"growth":"MediumSlow","catchRate":45,"friendship":70,"eggGroups":["Monster","Dragon"],"hatchCycles":20},{"id":7,"name":"Squirtle","stats":[44,48,65,50,64,43],"types":["Water"],"abilities":["Torrent"],"gender":31,"growth":"MediumSlow","catchRate":45,"friendship":70,"eggGroups":["Monster","Water1"],"hatchCycles":20},{"id":8,"name":"Wartortle","stats":[59,63,80,65,80,58],"types":["Water"],"abilities":["Torrent"],"gender":31,"growth":"MediumSlow","catchRate":45,"friendship":70,"eggGroups":["Monster","Water1"],"hatchCycles":20},{"id":9,"name":"Blastoise","stats":[79,83,100,85,105,78],"types":["Water"],"abilities":["Torrent"],"gender":31,"growth":"MediumSlow","catchRate":45,"friendship":70,"eggGroups":["Monster","Water1"],"hatchCycles":20},{"id":10,"name":"Caterpie","stats":[45,30,35,20,20,45],"types":["Bug"],"abilities":["ShieldDust"],"gender":127,"growth":"MediumFast","catchRate":255,"friendship":70,"eggGroups":["Bug"],"hatchCycles":15},{"id":11,"name":"Metapod","stats":[50,20,55,25,25,30],"types":["Bug"],"abilities":["ShedSkin"],"gender":127,"growth":"MediumFast","catchRate":120,"friendship":70,"eggGroups":["Bug"],"hatchCycles":15},{"id":12,"name":"Butterfree","stats":[60,45,50,80,80,70],"types":["Bug","Flying"],"abilities":["CompoundEyes"],"gender":127,"growth":"MediumFast","catchRate":45,"friendship":70,"eggGroups":["Bug"],"hatchCycles":15},{"id":13,"name":"Weedle","stats":[40,35,30,20,20,50],"types":["Bug","Poison"],"abilities":["ShieldDust"],"gender":127,"growth":"MediumFast","catchRate":255,"friendship":70,"eggGroups":["Bug"],"hatchCycles":15},{"id":14,"name":"Kakuna","stats":[45,25,50,25,25,35],"types":["Bug","Poison"],"abilities":["ShedSkin"],"gender":127,"growth":"MediumFast","catchRate":120,"friendship":70,"eggGroups":["Bug"],"hatchCycles":15},{"id":15,"name":"Beedrill","stats":[65,80,40,45,80,75],"types":["Bug","Poison"],"abilities":["Swarm"],"gender":127,"growth":"MediumFast","catchRate":45,"friendship":70,"eggGroups":["Bug"],"hatchCycles":15},{"id":16,"name":"Pidgey","stats":[40,45,40,35,35,56],"types":["Normal","Flying"],"abilities":["KeenEye","TangledFeet"],"gender":127,"growth":"MediumSlow","catchRate":255,"friendship":70,"eggGroups":["Flying"],"hatchCycles":15},{"id":17,"name":"Pidgeotto","stats":[63,60,55,50,50,71],"types":["Normal","Flying"],"abilities":["KeenEye","TangledFeet"],"gender":127,"growth":"MediumSlow","catchRate":120,"friendship":70,"eggGroups":["Flying"],"hatchCycles":15},{"id":18,"name":"Pidgeot","stats":[83,80,75,70,70,91],"types":["Normal","Flying"],"abilities":["KeenEye","TangledFeet"],"gender":127,"growth":"MediumSlow","catchRate":45,"friendship":70,"eggGroups":["Flying"],"hatchCycles":15},{"id":19,"name":"Rattata","stats":[30,56,35,25,35,72],"types":["Normal"],"abilities":["RunAway","Guts"],"gender":127,"growth":"MediumFast","catchRate":255,"friendship":70,"eggGroups":["Field"],"hatchCycles":15},{"id":20,"name":"Raticate","stats":[55,81,60,50,70,97],"types":["Normal"],"abilities":["RunAway","Guts"],"gender":127,"growth":"MediumFast","catchRate":127,"friendship":70,"eggGroups":["Field"],"hatchCycles":15},{"id":21,"name":"Spearow","stats":[40,60,30,31,31,70],"types":["Normal","Flying"],"abilities":["KeenEye"],"gender":127,"growth":"MediumFast","catchRate":255,"friendship":70,"eggGroups":["Flying"],"hatchCycles":15},{"id":22,"name":"Fearow","stats":[65,90,65,61,61,100],"types":["Normal","Flying"],"abilities":["KeenEye"],"gender":127,"growth":"MediumFast","catchRate":90,"friendship":70,"eggGroups":["Flying"],"hatchCycles":15},{"id":23,"name":"Ekans","stats":[35,60,44,40,54,55],"types":["Poison"],"abilities":["Intimidate","ShedSkin"],"gender":127,"growth":"MediumFast","catchRate":255,"friendship":70,"eggGroups":["Field","Dragon"],"hatchCycles":20},{"id":24,"name":"Arbok","stats":[60,85,69,65,79,80],"types":["Poison"],"abilities":["Intimidate","ShedSkin"],"gender":127,"growth":"MediumFast","catchRate":90,"friendship":70,"eggGroups":["Field","Dragon"],"hatchCycles":20},{"id":25,"name":"Pikachu","stats":[35,55,30,50,40,90],"types":["Electric"],"abilities":["Static"],"gender":127,"growth":"MediumFast","catchRate":190,"friendship":70,"eggGroups":["Field","Fairy"],"hatchCycles":10},{"id":26,"name":"Raichu","stats":[60,90,55,90,80,100],"types":["Electric"],"abilities":["Static"],"gender":127,"growth":"MediumFast","catchRate":75,"friendship":70,"eggGroups":["Field","Fairy"],"hatchCycles":10},{"id":27,"name":"Sandshrew","stats":[50,75,85,20,30,40],"types":["Ground"],"abilities":["SandVeil"],"gender":127,"growth":"MediumFast","catchRate":255,"friendship":70,"eggGroups":["Field"],"hatchCycles":20},{"id":28,"name":"Sandslash","stats":[75,100,110,45,55,65],"types":["Ground"],"abilities":["SandVeil"],"gender":127,"growth":"MediumFast","catchRate":90,"friendship":70,"eggGroups":["Field"],"hatchCycles":20},{"id":29,"name":"NidoranFemale","stats":[55,47,52,40,40,41],"types":["Poison"],"abilities":["PoisonPoint","Rivalry"],"gender":254,"growth":"MediumSlow","catchRate":235,"friendship":70,"eggGroups":["Monster","Field"],"hatchCycles":20},{"id":30,"name":"Nidorina","stats":[70,62,67,55,55,56],"types":["Poison"],"abilities":["PoisonPoint","Rivalry"],"gender":254,"growth":"MediumSlow","catchRate":120,"friendship":70,"eggGroups":["Undiscovered"],"hatchCycles":20},{"id":31,"name":"Nidoqueen","stats":[90,82,87,75,85,76],"types":["Poison","Ground"],"abilities":["PoisonPoint","Rivalry"],"gender":254,"growth":"MediumSlow","catchRate":45,"friendship":70,"eggGroups":["Undiscovered"],"hatchCycles":20},{"id":32,"name":"NidoranMale","stats":[46,57,40,40,40,50],"types":["Poison"],"abilities":["PoisonPoint","Rivalry"],"gender":0,"growth":"MediumSlow","catchRate":235,"friendship":70,"eggGroups":["Monster","Field"],"hatchCycles":20},{"id":33,"name":"Nidorino","stats":[61,72,57,55,55,65],"types":["Poison"],"abilities":["PoisonPoint","Rivalry"],"gender":0,"growth":"MediumSlow","catchRate":120,"friendship":70,"eggGroups":["Monster","Field"],"hatchCycles":20},{"id":34,"name":"Nidoking","stats":[81,92,77,85,75,85],"types":["Poison","Ground"],"abilities":["PoisonPoint","Rivalry"],"gender":0,"growth":"MediumSlow","catchRate":45,"friendship":70,"eggGroups":["Monster","Field"],"hatchCycles":20},{"id":35,"name":"Clefairy","stats":[70,45,48,60,65,35],"types":["Normal"],"abilities":["CuteCharm","MagicGuard"],"gender":191,"growth":"Fast","catchRate":150,"friendship":140,"eggGroups":["Fairy"],"hatchCycles":10},{"id":36,"name":"Clefable","stats":[95,70,73,85,90,60],"types":["Normal"],"abilities":["CuteCharm","MagicGuard"],"gender":191,"growth":"Fast","catchRate":25,"friendship":140,"eggGroups":["Fairy"],"hatchCycles":10},{"id":37,"name":"Vulpix","stats":[38,41,40,50,65,65],"types":["Fire"],"abilities":["FlashFire"],"gender":191,"growth":"MediumFast","catchRate":190,"friendship":70,"eggGroups":["Field"],"hatchCycles":20},{"id":38,"name":"Ninetales","stats":[73,76,75,81,100,100],"types":["Fire"],"abilities":["FlashFire"],"gender":191,"growth":"MediumFast","catchRate":75,"friendship":70,"eggGroups":["Field"],"hatchCycles":20},{"id":39,"name":"Jigglypuff","stats":[115,45,20,45,25,20],"types":["Normal"],"abilities":["CuteCharm"],"gender":191,"growth":"Fast","catchRate":170,"friendship":50,"eggGroups":["Fairy"],"hatchCycles":10},{"id":40,"name":"Wigglytuff","stats":[140,70,45,75,50,45],"types":["Normal"],"abilities":["CuteCharm"],"gender":191,"growth":"Fast","catchRate":50,"friendship":50,"eggGroups":["Fairy"],"hatchCycles":10},{"id":41,"name":"Zubat","stats":[40,45,35,30,40,55],"types":["Poison","Flying"],"abilities":["InnerFocus"],"gender":127,"growth":"MediumFast","catchRate":255,"friendship":70,"eggGroups":["Flying"],"hatchCycles":15},{"id":42,"name":"Golbat","stats":[75,80,70,65,75,90],"types":["Poison","Flying"],"abilities":["InnerFocus"],"gender":127,"growth":"MediumFast","catchRate":90,"friendship":70,"eggGroups":["Flying"],"hatchCycles":15},{"id":43,"name":"Oddish","stats":[45,50,55,75,65,30],"types":["Grass","Poison"],"abilities":["Chlorophyll"],"gender":127,"growth":"MediumSlow","catchRate":255,"friendship":70,"eggGroups":["Grass"],"hatchCycles":20},{"id":44,"name":"Gloom","stats":[60,65,70,85,75,40],"types":["Grass","Poison"],"abilities":["Chlorophyll"],"gender":127,"growth":"MediumSlow","catchRate":120,"friendship":70,"eggGroups":["Grass"],"hatchCycles":20},{"id":45,"name":"Vileplume","stats":[75,80,85,100,90,50],"types":["Grass","Poison"],"abilities":["Chlorophyll"],"gender":127,"growth":"MediumSlow","catchRate":45,"friendship":70,"eggGroups":["Grass"],"hatchCycles":20},{"id":46,"name":"Paras","stats":[35,70,55,45,55,25],"types":["Bug","Grass"],"abilities":["EffectSpore","DrySkin"],"gender":127,"growth":"MediumFast","catchRate":190,"friendship":70,"eggGroups":["Bug","Grass"],"hatchCycles":20},{"id":47,"name":"Parasect","stats":[60,95,80,60,80,30],"types":["Bug","Grass"],"abilities":["EffectSpore","DrySkin"],"gender":127,"growth":"MediumFast","catchRate":75,"friendship":70,"eggGroups":["Bug","Grass"],"hatchCycles":20},{"id":48,"name":"Venonat","stats":[60,55,50,40,55,45],"types":["Bug","Poison"],"abilities":["CompoundEyes","TintedLens"],"gender":127,"growth":"MediumFast","catchRate":190,"friendship":70,"eggGroups":["Bug"],"hatchCycles":20},{"id":49,"name":"Venomoth","stats":[70,65,60,90,75,90],"types":["Bug","Poison"],"abilities":["ShieldDust","TintedLens"],"gender":127,"growth":"MediumFast","catchRate":75,"friendship":70,"eggGroups":["Bug"],"hatchCycles":20},{"id":50,"name":"Diglett","stats":[10,55,25,35,45,95],"types":["Ground"],"abilities":["SandVeil","ArenaTrap"],"gender":127,"growth":"MediumFast","catchRate":255,"friendship":70,"eggGroups":["Field"],"hatchCycles":20},{"id":51,"name":"Dugtrio","stats":[35,80,50,50,70,120],"types":["Ground"],"abilities":["SandVeil","ArenaTrap"],"gender":127,"growth":"MediumFast","catchRate":50,"friendship":70,"eggGroups":["Field"],"hatchCycles":20},{"id":52,"name":"Meowth","stats":[40,45,35,40,40,90],"types":["Normal"],"abilities":["Pickup","Technician"],"gender":127,"growth":"MediumFast","catchRate":255,"friendship":70,"eggGroups":["Field"],"hatchCycles":20},{"id":53,"name":"Persian","stats":[65,70,60,65,65,115],"types":["Normal"],"abilities":["Limber","Technician"],"gender":127,"growth":"MediumFast","catchRate":90,"friendship":70,"eggGroups":["Field"],"hatchCycles":20},{"id":54,"name":"Psyduck","stats":[50,52,48,65,50,55],"types":["Water"],"abilities":["Damp","CloudNine"],"gender":127,"growth":"MediumFast","catchRate":190,"friendship":70,"eggGroups":["Water1","Field"],"hatchCycles":20},{"id":55,"name":"Golduck","stats":[80,82,78,95,80,85],"types":["Water"],"abilities":["Damp","CloudNine"],"gender":127,"growth":"MediumFast","catchRate":75,"friendship":70,"eggGroups":["Water1","Field"],"hatchCycles":20},{"id":56,"name":"Mankey","stats":[40,80,35,35,45,70],"types":["Fighting"],"abilities":["VitalSpirit","AngerPoint"],"gender":127,"growth":"MediumFast","catchRate":190,"friendship":70,"eggGroups":["Field"],"hatchCycles":20},{"id":57,"name":"Primeape","stats":[65,105,60,60,70,95],"types":["Fighting"],"abilities":["VitalSpirit","AngerPoint"],"gender":127,"growth":"MediumFast","catchRate":75,"friendship":70,"eggGroups":["Field"],"hatchCycles":20},{"id":58,"name":"Growlithe","stats":[55,70,45,70,50,60],"types":["Fire"],"abilities":["Intimidate","FlashFire"],"gender":63,"growth":"Slow","catchRate":190,"friendship":70,"eggGroups":["Field"],"hatchCycles":20},{"id":59,"name":"Arcanine","stats":[90,110,80,100,80,95],"types":["Fire"],"abilities":["Intimidate","FlashFire"],"gender":63,"growth":"Slow","catchRate":75,"friendship":70,"eggGroups":["Field"],"hatchCycles":20},{"id":60,"name":"Poliwag","stats":[40,50,40,40,40,90],"types":["Water"],"abilities":["WaterAbsorb","Damp"],"gender":127,"growth":"MediumSlow","catchRate":255,"friendship":70,"eggGroups":["Water1"],"hatchCycles":20},{"id":61,"name":"Poliwhirl","stats":[65,65,65,50,50,90],"types":["Water"],"abilities":["WaterAbsorb","Damp"],"gender":127,"growth":"MediumSlow","catchRate":120,"friendship":70,"eggGroups":["Water1"],"hatchCycles":20},{"id":62,"name":"Poliwrath","stats":[90,85,95,70,90,70],"types":["Water","Fighting"],"abilities":["WaterAbsorb","Damp"],"gender":127,"growth":"MediumSlow","catchRate":45,"friendship":70,"eggGroups":["Water1"],"hatchCycles":20},{"id":63,"name":"Abra","stats":[25,20,15,105,55,90],"types":["Psychic"],"abilities":["Synchronize","InnerFocus"],"gender":63,"growth":"MediumSlow","catchRate":200,"friendship":70,"eggGroups":["HumanLike"],"hatchCycles":20},{"id":64,"name":"Kadabra","stats":[40,35,30,120,70,105],"types":["Psychic"],"abilities":["Synchronize","InnerFocus"],"gender":63,"growth":"MediumSlow","catchRate":100,"friendship":70,"eggGroups":["HumanLike"],"hatchCycles":20},{"id":65,"name":"Alakazam","stats":[55,50,45,135,85,120],"types":["Psychic"],"abilities":["Synchronize","InnerFocus"],"gender":63,"growth":"MediumSlow","catchRate":50,"friendship":70,"eggGroups":["HumanLike"],"hatchCycles":20},{"id":66,"name":"Machop","stats":[70,80,50,35,35,35],"types":["Fighting"],"abilities":["Guts","NoGuard"],"gender":63,"growth":"MediumSlow","catchRate":180,"friendship":70,"eggGroups":["HumanLike"],"hatchCycles":20},{"id":67,"name":"Machoke","stats":[80,100,70,50,60,45],"types":["Fighting"],"abilities":["Guts","NoGuard"],"gender":63,"growth":"MediumSlow","catchRate":90,"friendship":70,"eggGroups":["HumanLike"],"hatchCycles":20},{"id":68,"name":"Machamp","stats":[90,130,80,65,85,55],"types":["Fighting"],"abilities":["Guts","NoGuard"],"gender":63,"growth":"MediumSlow","catchRate":45,"friendship":70,"eggGroups":["HumanLike"],"hatchCycles":20},{"id":69,"name":"Bellsprout","stats":[50,75,35,70,30,40],"types":["Grass","Poison"],"abilities":["Chlorophyll"],"gender":127,"growth":"MediumSlow","catchRate":255,"friendship":70,"eggGroups":["Grass"],"hatchCycles":20},{"id":70,"name":"Weepinbell","stats":[65,90,50,85,45,55],"types":["Grass","Poison"],"abilities":["Chlorophyll"],"gender":127,"growth":"MediumSlow","catchRate":120,"friendship":70,"eggGroups":["Grass"],"hatchCycles":20},{"id":71,"name":"Victreebel","stats":[80,105,65,100,60,70],"types":["Grass","Poison"],"abilities":["Chlorophyll"],"gender":127,"growth":"MediumSlow","catchRate":45,"friendship":70,"eggGroups":["Grass"],"hatchCycles":20},{"id":72,"name":"Tentacool","stats":[40,40,35,50,100,70],"types":["Water","Poison"],"abilities":["ClearBody","LiquidOoze"],"gender":127,"growth":"Slow","catchRate":190,"friendship":70,"eggGroups":["Water3"],"hatchCycles":20},{"id":73,"name":"Tentacruel","stats":[80,70,65,80,120,100],"types":["Water","Poison"],"abilities":["ClearBody","LiquidOoze"],"gender":127,"growth":"Slow","catchRate":60,"friendship":70,"eggGroups":["Water3"],"hatchCycles":20},{"id":74,"name":"Geodude","stats":[40,80,100,30,30,20],"types":["Rock","Ground"],"abilities":["RockHead","Sturdy"],"gender":127,"growth":"MediumSlow","catchRate":255,"friendship":70,"eggGroups":["Mineral"],"hatchCycles":15},{"id":75,"name":"Graveler","stats":[55,95,115,45,45,35],"types":["Rock","Ground"],"abilities":["RockHead","Sturdy"],"gender":127,"growth":"MediumSlow","catchRate":120,"friendship":70,"eggGroups":["Mineral"],"hatchCycles":15},{"id":76,"name":"Golem","stats":[80,110,130,55,65,45],"types":["Rock","Ground"],"abilities":["RockHead","Sturdy"],"gender":127,"growth":"MediumSlow","catchRate":45,"friendship":70,"eggGroups":["Mineral"],"hatchCycles":15},{"id":77,"name":"Ponyta","stats":[50,85,55,65,65,90],"types":["Fire"],"abilities":["RunAway","FlashFire"],"gender":127,"growth":"MediumFast","catchRate":190,"friendship":70,"eggGroups":["Field"],"hatchCycles":20},{"id":78,"name":"Rapidash","stats":[65,100,70,80,80,105],"types":["Fire"],"abilities":["RunAway","FlashFire"],"gender":127,"growth":"MediumFast","catchRate":60,"friendship":70,"eggGroups":["Field"],"hatchCycles":20},{"id":79,"name":"Slowpoke","stats":[90,65,65,40,40,15],"types":["Water","Psychic"],"abilities":["Oblivious","OwnTempo"],"gender":127,"growth":"MediumFast","catchRate":190,"friendship":70,"eggGroups":["Monster","Water1"],"hatchCycles":20},{"id":80,"name":"Slowbro","stats":[95,75,110,100,80,30],"types":["Water","Psychic"],"abilities":["Oblivious","OwnTempo"],"gender":127,"growth":"MediumFast","catchRate":75,"friendship":70,"eggGroups":["Monster","Water1"],"hatchCycles":20},{"id":81,"name":"Magnemite","stats":[25,35,70,95,55,45],"types":["Electric","Steel"],"abilities":["MagnetPull","Sturdy"],"gender":255,"growth":"MediumFast","catchRate":190,"friendship":70,"eggGroups":["Mineral"],"hatchCycles":20},{"id":82,"name":"Magneton","stats":[50,60,95,120,70,70],"types":["Electric","Steel"],"abilities":["MagnetPull","Sturdy"],"gender":255,"growth":"MediumFast","catchRate":60,"friendship":70,"eggGroups":["Mineral"],"hatchCycles":20},{"id":83,"name":"Farfetchd","stats":[52,65,55,58,62,60],"types":["Normal","Flying"],"abilities":["KeenEye","InnerFocus"],"gender":127,"growth":"MediumFast","catchRate":45,"friendship":70,"eggGroups":["Flying","Field"],"hatchCycles":20},{"id":84,"name":"Doduo","stats":[35,85,45,35,35,75],"types":["Normal","Flying"],"abilities":["RunAway","EarlyBird"],"gender":127,"growth":"MediumFast","catchRate":190,"friendship":70,"eggGroups":["Flying"],"hatchCycles":20},{"id":85,"name":"Dodrio","stats":[60,110,70,60,60,100],"types":["Normal","Flying"],"abilities":["RunAway","EarlyBird"],"gender":127,"growth":"MediumFast","catchRate":45,"friendship":70,"eggGroups":["Flying"],"hatchCycles":20},{"id":86,"name":"Seel","stats":[65,45,55,45,70,45],"types":["Water"],"abilities":["ThickFat","Hydration"],"gender":127,"growth":"MediumFast","catchRate":190,"friendship":70,"eggGroups":["Water1","Field"],"hatchCycles":20},{"id":87,"name":"Dewgong","stats":[90,70,80,70,95,70],"types":["Water","Ice"],"abilities":["ThickFat","Hydration"],"gender":127,"growth":"MediumFast","catchRate":75,"friendship":70,"eggGroups":["Water1","Field"],"hatchCycles":20},{"id":88,"name":"Grimer","stats":[80,80,50,40,50,25],"types":["Poison"],"abilities":["Stench","StickyHold"],"gender":127,"growth":"MediumFast","catchRate":190,"friendship":70,"eggGroups":["Amorphous"],"hatchCycles":20},{"id":89,"name":"Muk","stats":[105,105,75,65,100,50],"types":["Poison"],"abilities":["Stench","StickyHold"],"gender":127,"growth":"MediumFast","catchRate":75,"friendship":70,"eggGroups":["Amorphous"],"hatchCycles":20},{"id":90,"name":"Shellder","stats":[30,65,100,45,25,40],"types":["Water"],"abilities":["ShellArmor","SkillLink"],"gender":127,"growth":"Slow","catchRate":190,"friendship":70,"eggGroups":["Water3"],"hatchCycles":20},{"id":91,"name":"Cloyster","stats":[50,95,180,85,45,70],"types":["Water","Ice"],"abilities":["ShellArmor","SkillLink"],"gender":127,"growth":"Slow","catchRate":60,"friendship":70,"eggGroups":["Water3"],"hatchCycles":20},{"id":92,"name":"Gastly","stats":[30,35,30,100,35,80],"types":["Ghost","Poison"],"abilities":["Levitate"],"gender":127,"growth":"MediumSlow","catchRate":190,"friendship":70,"eggGroups":["Amorphous"],"hatchCycles":20},{"id":93,"name":"Haunter","stats":[45,50,45,115,55,95],"types":["Ghost","Poison"],"abilities":["Levitate"],"gender":127,"growth":"MediumSlow","catchRate":90,"friendship":70,"eggGroups":["Amorphous"],"hatchCycles":20},{"id":94,"name":"Gengar","stats":[60,65,60,130,75,110],"types":["Ghost","Poison"],"abilities":["Levitate"],"gender":127,"growth":"MediumSlow","catchRate":45,"friendship":70,"eggGroups":["Amorphous"],"hatchCycles":20},{"id":95,"name":"Onix","stats":[35,45,160,30,45,70],"types":["Rock","Ground"],"abilities":["RockHead","Sturdy"],"gender":127,"growth":"MediumFast","catchRate":45,"friendship":70,"eggGroups":["Mineral"],"hatchCycles":25},{"id":96,"name":"Drowzee","stats":[60,48,45,43,90,42],"types":["Psychic"],"abilities":["Insomnia","Forewarn"],"gender":127,"growth":"MediumFast","catchRate":190,"friendship":70,"eggGroups":["HumanLike"],"hatchCycles":20},{"id":97,"name":"Hypno","stats":[85,73,70,73,115,67],"types":["Psychic"],"abilities":["Insomnia","Forewarn"],"gender":127,"growth":"MediumFast","catchRate":75,"friendship":70,"eggGroups":["HumanLike"],"hatchCycles":20},{"id":98,"name":"Krabby","stats":[30,105,90,25,25,50],"types":["Water"],"abilities":["HyperCutter","ShellArmor"],"gender":127,"growth":"MediumFast","catchRate":225,"friendship":70,"eggGroups":["Water3"],"hatchCycles":20},{"id":99,"name":"Kingler","stats":[55,130,115,50,50,75],"types":["Water"],"abilities":["HyperCutter","ShellArmor"],"gender":127,"growth":"MediumFast","catchRate":60,"friendship":70,"eggGroups":["Water3"],"hatchCycles":20},{"id":100,"name":"Voltorb","stats":[40,30,50,55,55,100],"types":["Electric"],"abilities":["Soundproof","Static"],"gender":255,"growth":"MediumFast","catchRate":190,"friendship":70,"eggGroups":["Mineral"],"hatchCycles":20},{"id":101,"name":"Electrode","stats":[60,50,70,80,80,140],"types":["Electric"],"abilities":["Soundproof","Static"],"gender":255,"growth":"MediumFast","catchRate":60,"friendship":70,"eggGroups":["Mineral"],"hatchCycles":20},{"id":102,"name":"Exeggcute","stats":[60,40,80,60,45,40],"types":["Grass","Psychic"],"abilities":["Chlorophyll"],"gender":127,"growth":"Slow","catchRate":90,"friendship":70,"eggGroups":["Grass"],"hatchCycles":20},{"id":103,"name":"Exeggutor","stats":[95,95,85,125,65,55],"types":["Grass","Psychic"],"abilities":["Chlorophyll"],"gender":127,"growth":"Slow","catchRate":45,"friendship":70,"eggGroups":["Grass"],"hatchCycles":20},{"id":104,"name":"Cubone","stats":[50,50,95,40,50,35],"types":["Ground"],"abilities":["RockHead","LightningRod"],"gender":127,"growth":"MediumFast","catchRate":190,"friendship":70,"eggGroups":["Monster"],"hatchCycles":20},{"id":105,"name":"Marowak","stats":[60,80,110,50,80,45],"types":["Ground"],"abilities":["RockHead","LightningRod"],"gender":127,"growth":"MediumFast","catchRate":75,"friendship":70,"eggGroups":["Monster"],"hatchCycles":20},{"id":106,"name":"Hitmonlee","stats":[50,120,53,35,110,87],"types":["Fighting"],"abilities":["Limber","Reckless"],"gender":0,"growth":"MediumFast","catchRate":45,"friendship":70,"eggGroups":["HumanLike"],"hatchCycles":25},{"id":107,"name":"Hitmonchan","stats":[50,105,79,35,110,76],"types":["Fighting"],"abilities":["KeenEye","IronFist"],"gender":0,"growth":"MediumFast","catchRate":45,"friendship":70,"eggGroups":["HumanLike"],"hatchCycles":25},{"id":108,"name":"Lickitung","stats":[90,55,75,60,75,30],"types":["Normal"],"abilities":["OwnTempo","Oblivious"],"gender":127,"growth":"MediumFast","catchRate":45,"friendship":70,"eggGroups":["Monster"],"hatchCycles":20},{"id":109,"name":"Koffing","stats":[40,65,95,60,45,35],"types":["Poison"],"abilities":["Levitate"],"gender":127,"growth":"MediumFast","catchRate":190,"friendship":70,"eggGroups":["Amorphous"],"hatchCycles":20},{"id":110,"name":"Weezing","stats":[65,90,120,85,70,60],"types":["Poison"],"abilities":["Levitate"],"gender":127,"growth":"MediumFast","catchRate":60,"friendship":70,"eggGroups":["Amorphous"],"hatchCycles":20},{"id":111,"name":"Rhyhorn","stats":[80,85,95,30,30,25],"types":["Ground","Rock"],"abilities":["LightningRod","RockHead"],"gender":127,"growth":"Slow","catchRate":120,"friendship":70,"eggGroups":["Monster","Field"],"hatchCycles":20},{"id":112,"name":"Rhydon","stats":[105,130,120,45,45,40],"types":["Ground","Rock"],"abilities":["LightningRod","RockHead"],"gender":127,"growth":"Slow","catchRate":60,"friendship":70,"eggGroups":["Monster","Field"],"hatchCycles":20},{"id":113,"name":"Chansey","stats":[250,5,5,35,105,50],"types":["Normal"],"abilities":["NaturalCure","SereneGrace"],"gender":254,"growth":"Fast","catchRate":30,"friendship":140,"eggGroups":["Fairy"],"hatchCycles":40},{"id":114,"name":"Tangela","stats":[65,55,115,100,40,60],"types":["Grass"],"abilities":["Chlorophyll","LeafGuard"],"gender":127,"growth":"MediumFast","catchRate":45,"friendship":70,"eggGroups":["Grass"],"hatchCycles":20},{"id":115,"name":"Kangaskhan","stats":[105,95,80,40,80,90],"types":["Normal"],"abilities":["EarlyBird","Scrappy"],"gender":254,"growth":"MediumFast","catchRate":45,"friendship":70,"eggGroups":["Monster"],"hatchCycles":20},{"id":116,"name":"Horsea","stats":[30,40,70,70,25,60],"types":["Water"],"abilities":["SwiftSwim","Sniper"],"gender":127,"growth":"MediumFast","catchRate":225,"friendship":70,"eggGroups":["Water1","Dragon"],"hatchCycles":20},{"id":117,"name":"Seadra","stats":[55,65,95,95,45,85],"types":["Water"],"abilities":["PoisonPoint","Sniper"],"gender":127,"growth":"MediumFast","catchRate":75,"friendship":70,"eggGroups":["Water1","Dragon"],"hatchCycles":20},{"id":118,"name":"Goldeen","stats":[45,67,60,35,50,63],"types":["Water"],"abilities":["SwiftSwim","WaterVeil"],"gender":127,"growth":"MediumFast","catchRate":225,"friendship":70,"eggGroups":["Water2"],"hatchCycles":20},{"id":119,"name":"Seaking","stats":[80,92,65,65,80,68],"types":["Water"],"abilities":["SwiftSwim","WaterVeil"],"gender":127,"growth":"MediumFast","catchRate":60,"friendship":70,"eggGroups":["Water2"],"hatchCycles":20},{"id":120,"name":"Staryu","stats":[30,45,55,70,55,85],"types":["Water"],"abilities":["Illuminate","NaturalCure"],"gender":255,"growth":"Slow","catchRate":225,"friendship":70,"eggGroups":["Water3"],"hatchCycles":20},{"id":121,"name":"Starmie","stats":[60,75,85,100,85,115],"types":["Water","Psychic"],"abilities":["Illuminate","NaturalCure"],"gender":255,"growth":"Slow","catchRate":60,"friendship":70,"eggGroups":["Water3"],"hatchCycles":20},{"id":122,"name":"MrMime","stats":[40,45,65,100,120,90],"types":["Psychic"],"abilities":["Soundproof","Filter"],"gender":127,"growth":"MediumFast","catchRate":45,"friendship":70,"eggGroups":["HumanLike"],"hatchCycles":25},{"id":123,"name":"Scyther","stats":[70,110,80,55,80,105],"types":["Bug","Flying"],"abilities":["Swarm","Technician"],"gender":127,"growth":"MediumFast","catchRate":45,"friendship":70,"eggGroups":["Bug"],"hatchCycles":25},{"id":124,"name":"Jynx","stats":[65,50,35,115,95,95],"types":["Ice","Psychic"],"abilities":["Oblivious","Forewarn"],"gender":254,"growth":"MediumFast","catchRate":45,"friendship":70,"eggGroups":["HumanLike"],"hatchCycles":25},{"id":125,"name":"Electabuzz","stats":[65,83,57,95,85,105],"types":["Electric"],"abilities":["Static"],"gender":63,"growth":"MediumFast","catchRate":45,"friendship":70,"eggGroups":["HumanLike"],"hatchCycles":25},{"id":126,"name":"Magmar","stats":[65,95,57,100,85,93],"types":["Fire"],"abilities":["FlameBody"],"gender":63,"growth":"MediumFast","catchRate":45,"friendship":70,"eggGroups":["HumanLike"],"hatchCycles":25},{"id":127,"name":"Pinsir","stats":[65,125,100,55,70,85],"types":["Bug"],"abilities":["HyperCutter","MoldBreaker"],"gender":127,"growth":"Slow","catchRate":45,"friendship":70,"eggGroups":["Bug"],"hatchCycles":25},{"id":128,"name":"Tauros","stats":[75,100,95,40,70,110],"types":["Normal"],"abilities":["Intimidate","AngerPoint"],"gender":0,"growth":"Slow","catchRate":45,"friendship":70,"eggGroups":["Field"],"hatchCycles":20},{"id":129,"name":"Magikarp","stats":[20,10,55,15,20,80],"types":["Water"],"abilities":["SwiftSwim"],"gender":127,"growth":"Slow","catchRate":255,"friendship":70,"eggGroups":["Water2","Dragon"],"hatchCycles":5},{"id":130,"name":"Gyarados","stats":[95,125,79,60,100,81],"types":["Water","Flying"],"abilities":["Intimidate"],"gender":127,"growth":"Slow","catchRate":45,"friendship":70,"eggGroups":["Water2","Dragon"],"hatchCycles":5},{"id":131,"name":"Lapras","stats":[130,85,80,85,95,60],"types":["Water","Ice"],"abilities":["WaterAbsorb","ShellArmor"],"gender":127,"growth":"Slow","catchRate":45,"friendship":70,"eggGroups":["Monster","Water1"],"hatchCycles":40},{"id":132,"name":"Ditto","stats":[48,48,48,48,48,48],"types":["Normal"],"abilities":["Limber"],"gender":255,"growth":"MediumFast","catchRate":35,"friendship":70,"eggGroups":["Ditto"],"hatchCycles":20},{"id":133,"name":"Eevee","stats":[55,55,50,45,65,55],"types":["Normal"],"abilities":["RunAway","Adaptability"],"gender":31,"growth":"MediumFast","catchRate":45,"friendship":70,"eggGroups":["Field"],"hatchCycles":35},{"id":134,"name":"Vaporeon","stats":[130,65,60,110,95,65],"types":["Water"],"abilities":["WaterAbsorb"],"gender":31,"growth":"MediumFast","catchRate":45,"friendship":70,"eggGroups":["Field"],"hatchCycles":35},{"id":135,"name":"Jolteon","stats":[65,65,60,110,95,130],"types":["Electric"],"abilities":["VoltAbsorb"],"gender":31,"growth":"MediumFast","catchRate":45,"friendship":70,"eggGroups":["Field"],"hatchCycles":35},{"id":136,"name":"Flareon","stats":[65,130,60,95,110,65],"types":["Fire"],"abilities":["FlashFire"],"gender":31,"growth":"MediumFast","catchRate":45,"friendship":70,"eggGroups":["Field"],"hatchCycles":35},{"id":137,"name":"Porygon","stats":[65,60,70,85,75,40],"types":["Normal"],"abilities":["Trace","Download"],"gender":255,"growth":"MediumFast","catchRate":45,"friendship":70,"eggGroups":["Mineral"],"hatchCycles":20},{"id":138,"name":"Omanyte","stats":[35,40,100,90,55,35],"types":["Rock","Water"],"abilities":["SwiftSwim","ShellArmor"],"gender":31,"growth":"MediumFast","catchRate":45,"friendship":70,"eggGroups":["Water1","Water3"],"hatchCycles":30},{"id":139,"name":"Omastar","stats":[70,60,125,115,70,55],"types":["Rock","Water"],"abilities":["SwiftSwim","ShellArmor"],"gender":31,"growth":"MediumFast","catchRate":45,"friendship":70,"eggGroups":["Water1","Water3"],"hatchCycles":30},{"id":140,"name":"Kabuto","stats":[30,80,90,55,45,55],"types":["Rock","Water"],"abilities":["SwiftSwim","BattleArmor"],"gender":31,"growth":"MediumFast","catchRate":45,"friendship":70,"eggGroups":["Water1","Water3"],"hatchCycles":30},{"id":141,"name":"Kabutops","stats":[60,115,105,65,70,80],"types":["Rock","Water"],"abilities":["SwiftSwim","BattleArmor"],"gender":31,"growth":"MediumFast","catchRate":45,"friendship":70,"eggGroups":["Water1","Water3"],"hatchCycles":30},{"id":142,"name":"Aerodactyl","stats":[80,105,65,60,75,130],"types":["Rock","Flying"],"abilities":["RockHead","Pressure"],"gender":31,"growth":"Slow","catchRate":45,"friendship":70,"eggGroups":["Flying"],"hatchCycles":35},{"id":143,"name":"Snorlax","stats":[160,110,65,65,110,30],"types":["Normal"],"abilities":["Immunity","ThickFat"],"gender":31,"growth":"Slow","catchRate":25,"friendship":70,"eggGroups":["Monster"],"hatchCycles":40},{"id":144,"name":"Articuno","stats":[90,85,100,95,125,85],"types":["Ice","Flying"],"abilities":["Pressure"],"gender":255,"growth":"Slow","catchRate":3,"friendship":35,"eggGroups":["Undiscovered"],"hatchCycles":80},{"id":145,"name":"Zapdos","stats":[90,90,85,125,90,100],"types":["Electric","Flying"],"abilities":["Pressure"],"gender":255,"growth":"Slow","catchRate":3,"friendship":35,"eggGroups":["Undiscovered"],"hatchCycles":80},{"id":146,"name":"Moltres","stats":[90,100,90,125,85,90],"types":["Fire","Flying"],"abilities":["Pressure"],"gender":255,"growth":"Slow","catchRate":3,"friendship":35,"eggGroups":["Undiscovered"],"hatchCycles":80},{"id":147,"name":"Dratini","stats":[41,64,45,50,50,50],"types":["Dragon"],"abilities":["ShedSkin"],"gender":127,"growth":"Slow","catchRate":45,"friendship":35,"eggGroups":["Water1","Dragon"],"hatchCycles":40},{"id":148,"name":"Dragonair","stats":[61,84,65,70,70,70],"types":["Dragon"],"abilities":["ShedSkin"],"gender":127,"growth":"Slow","catchRate":45,"friendship":35,"eggGroups":["Water1","Dragon"],"hatchCycles":40},{"id":149,"name":"Dragonite","stats":[91,134,95,100,100,80],"types":["Dragon","Flying"],"abilities":["InnerFocus"],"gender":127,"growth":"Slow","catchRate":45,"friendship":35,"eggGroups":["Water1","Dragon"],"hatchCycles":40},{"id":150,"name":"Mewtwo","stats":[106,110,90,154,90,130],"types":["Psychic"],"abilities":["Pressure"],"gender":255,"growth":"Slow","catchRate":3,"friendship":0,"eggGroups":["Undiscovered"],"hatchCycles":120},{"id":151,"name":"Mew","stats":[100,100,100,100,100,100],"types":["Psychic"],"abilities":["Synchronize"],"gender":255,"growth":"MediumSlow","catchRate":45,"friendship":100,"eggGroups":["Undiscovered"],"hatchCycles":120},{"id":152,"name":"Chikorita","stats":[45,49,65,49,65,45],"types":["Grass"],"abilities":["Overgrow"],"gender":31,"growth":"MediumSlow","catchRate":45,"friendship":70,"eggGroups":["Monster","Grass"],"hatchCycles":20},{"id":153,"name":"Bayleef","stats":[60,62,80,63,80,60],"types":["Grass"],"abilities":["Overgrow"],"gender":31,"growth":"MediumSlow","catchRate":45,"friendship":70,"eggGroups":["Monster","Grass"],"hatchCycles":20},{"id":154,"name":"Meganium","stats":[80,82,100,83,100,80],"types":["Grass"],"abilities":["Overgrow"],"gender":31,"growth":"MediumSlow","catchRate":45,"friendship":70,"eggGroups":["Monster","Grass"],"hatchCycles":20},{"id":155,"name":"Cyndaquil","stats":[39,52,43,60,50,65],"types":["Fire"],"abilities":["Blaze"],"gender":31,"growth":"MediumSlow","catchRate":45,"friendship":70,"eggGroups":["Field"],"hatchCycles":20},{"id":156,"name":"Quilava","stats":[58,64,58,80,65,80],"types":["Fire"],"abilities":["Blaze"],"gender":31,"growth":"MediumSlow","catchRate":45,"friendship":70,"eggGroups":["Field"],"hatchCycles":20},{"id":157,"name":"Typhlosion","stats":[78,84,78,109,85,100],"types":["Fire"],"abilities":["Blaze"],"gender":31,"growth":"MediumSlow","catchRate":45,"friendship":70,"eggGroups":["Field"],"hatchCycles":20},{"id":158,"name":"Totodile","stats":[50,65,64,44,48,43],"types":["Water"],"abilities":["Torrent"],"gender":31,"growth":"MediumSlow","catchRate":45,"friendship":70,"eggGroups":["Monster","Water1"],"hatchCycles":20},{"id":159,"name":"Croconaw","stats":[65,80,80,59,63,58],"types":["Water"],"abilities":["Torrent"],"gender":31,"growth":"MediumSlow","catchRate":45,"friendship":70,"eggGroups":["Monster","Water1"],"hatchCycles":20},{"id":160,"name":"Feraligatr","stats":[85,105,100,79,83,78],"types":["Water"],"abilities":["Torrent"],"gender":31,"growth":"MediumSlow","catchRate":45,"friendship":70,"eggGroups":["Monster","Water1"],"hatchCycles":20},{"id":161,"name":"Sentret","stats":[35,46,34,35,45,20],"types":["Normal"],"abilities":["RunAway","KeenEye"],"gender":127,"growth":"MediumFast","catchRate":255,"friendship":70,"eggGroups":["Field"],"hatchCycles":15},{"id":162,"name":"Furret","stats":[85,76,64,45,55,90],"types":["Normal"],"abilities":["RunAway","KeenEye"],"gender":127,"growth":"MediumFast","catchRate":90,"friendship":70,"eggGroups":["Field"],"hatchCycles":15},{"id":163,"name":"Hoothoot","stats":[60,30,30,36,56,50],"types":["Normal","Flying"],"abilities":["Insomnia","KeenEye"],"gender":127,"growth":"MediumFast","catchRate":255,"friendship":70,"eggGroups":["Flying"],"hatchCycles":15},{"id":164,"name":"Noctowl","stats":[100,50,50,76,96,70],"types":["Normal","Flying"],"abilities":["Insomnia","KeenEye"],"gender":127,"growth":"MediumFast","catchRate":90,"friendship":70,"eggGroups":["Flying"],"hatchCycles":15},{"id":165,"name":"Ledyba","stats":[40,20,30,40,80,55],"types":["Bug","Flying"],"abilities":["Swarm","EarlyBird"],"gender":127,"growth":"Fast","catchRate":255,"friendship":70,"eggGroups":["Bug"],"hatchCycles":15},{"id":166,"name":"Ledian","stats":[55,35,50,55,110,85],"types":["Bug","Flying"],"abilities":["Swarm","EarlyBird"],"gender":127,"growth":"Fast","catchRate":90,"friendship":70,"eggGroups":["Bug"],"hatchCycles":15},{"id":167,"name":"Spinarak","stats":[40,60,40,40,40,30],"types":["Bug","Poison"],"abilities":["Swarm","Insomnia"],"gender":127,"growth":"Fast","catchRate":255,"friendship":70,"eggGroups":["Bug"],"hatchCycles":15},{"id":168,"name":"Ariados","stats":[70,90,70,60,60,40],"types":["Bug","Poison"],"abilities":["Swarm","Insomnia"],"gender":127,"growth":"Fast","catchRate":90,"friendship":70,"eggGroups":["Bug"],"hatchCycles":15},{"id":169,"name":"Crobat","stats":[85,90,80,70,80,130],"types":["Poison","Flying"],"abilities":["InnerFocus"],"gender":127,"growth":"MediumFast","catchRate":90,"friendship":70,"eggGroups":["Flying"],"hatchCycles":15},{"id":170,"name":"Chinchou","stats":[75,38,38,56,56,67],"types":["Water","Electric"],"abilities":["VoltAbsorb","Illuminate"],"gender":127,"growth":"Slow","catchRate":190,"friendship":70,"eggGroups":["Water2"],"hatchCycles":20},{"id":171,"name":"Lanturn","stats":[125,58,58,76,76,67],"types":["Water","Electric"],"abilities":["VoltAbsorb","Illuminate"],"gender":127,"growth":"Slow","catchRate":75,"friendship":70,"eggGroups":["Water2"],"hatchCycles":20},{"id":172,"name":"Pichu","stats":[20,40,15,35,35,60],"types":["Electric"],"abilities":["Static"],"gender":127,"growth":"MediumFast","catchRate":190,"friendship":70,"eggGroups":["Undiscovered"],"hatchCycles":10},{"id":173,"name":"Cleffa","stats":[50,25,28,45,55,15],"types":["Normal"],"abilities":["CuteCharm","MagicGuard"],"gender":191,"growth":"Fast","catchRate":150,"friendship":140,"eggGroups":["Undiscovered"],"hatchCycles":10},{"id":174,"name":"Igglybuff","stats":[90,30,15,40,20,15],"types":["Normal"],"abilities":["CuteCharm"],"gender":191,"growth":"Fast","catchRate":170,"friendship":50,"eggGroups":["Undiscovered"],"hatchCycles":10},{"id":175,"name":"Togepi","stats":[35,20,65,40,65,20],"types":["Normal"],"abilities":["Hustle","SereneGrace"],"gender":31,"growth":"Fast","catchRate":190,"friendship":70,"eggGroups":["Undiscovered"],"hatchCycles":10},{"id":176,"name":"Togetic","stats":[55,40,85,80,105,40],"types":["Normal","Flying"],"abilities":["Hustle","SereneGrace"],"gender":31,"growth":"Fast","catchRate":75,"friendship":70,"eggGroups":["Flying","Fairy"],"hatchCycles":10},{"id":177,"name":"Natu","stats":[40,50,45,70,45,70],"types":["Psychic","Flying"],"abilities":["Synchronize","EarlyBird"],"gender":127,"growth":"MediumFast","catchRate":190,"friendship":70,"eggGroups":["Flying"],"hatchCycles":20},{"id":178,"name":"Xatu","stats":[65,75,70,95,70,95],"types":["Psychic","Flying"],"abilities":["Synchronize","EarlyBird"],"gender":127,"growth":"MediumFast","catchRate":75,"friendship":70,"eggGroups":["Flying"],"hatchCycles":20},{"id":179,"name":"Mareep","stats":[55,40,40,65,45,35],"types":["Electric"],"abilities":["Static"],"gender":127,"growth":"MediumSlow","catchRate":235,"friendship":70,"eggGroups":["Monster","Field"],"hatchCycles":20},{"id":180,"name":"Flaaffy","stats":[70,55,55,80,60,45],"types":["Electric"],"abilities":["Static"],"gender":127,"growth":"MediumSlow","catchRate":120,"friendship":70,"eggGroups":["Monster","Field"],"hatchCycles":20},{"id":181,"name":"Ampharos","stats":[90,75,75,115,90,55],"types":["Electric"],"abilities":["Static"],"gender":127,"growth":"MediumSlow","catchRate":45,"friendship":70,"eggGroups":["Monster","Field"],"hatchCycles":20},{"id":182,"name":"Bellossom","stats":[75,80,85,90,100,50],"types":["Grass"],"abilities":["Chlorophyll"],"gender":127,"growth":"MediumSlow","catchRate":45,"friendship":70,"eggGroups":["Grass"],"hatchCycles":20},{"id":183,"name":"Marill","stats":[70,20,50,20,50,40],"types":["Water"],"abilities":["ThickFat","HugePower"],"gender":127,"growth":"Fast","catchRate":190,"friendship":70,"eggGroups":["Water1","Fairy"],"hatchCycles":10},{"id":184,"name":"Azumarill","stats":[100,50,80,50,80,50],"types":["Water"],"abilities":["ThickFat","HugePower"],"gender":127,"growth":"Fast","catchRate":75,"friendship":70,"eggGroups":["Water1","Fairy"],"hatchCycles":10},{"id":185,"name":"Sudowoodo","stats":[70,100,115,30,65,30],"types":["Rock"],"abilities":["Sturdy","RockHead"],"gender":127,"growth":"MediumFast","catchRate":65,"friendship":70,"eggGroups":["Mineral"],"hatchCycles":20},{"id":186,"name":"Politoed","stats":[90,75,75,90,100,70],"types":["Water"],"abilities":["WaterAbsorb","Damp"],"gender":127,"growth":"MediumSlow","catchRate":45,"friendship":70,"eggGroups":["Water1"],"hatchCycles":20},{"id":187,"name":"Hoppip","stats":[35,35,40,35,55,50],"types":["Grass","Flying"],"abilities":["Chlorophyll","LeafGuard"],"gender":127,"growth":"MediumSlow","catchRate":255,"friendship":70,"eggGroups":["Fairy","Grass"],"hatchCycles":20},{"id":188,"name":"Skiploom","stats":[55,45,50,45,65,80],"types":["Grass","Flying"],"abilities":["Chlorophyll","LeafGuard"],"gender":127,"growth":"MediumSlow","catchRate":120,"friendship":70,"eggGroups":["Fairy","Grass"],"hatchCycles":20},{"id":189,"name":"Jumpluff","stats":[75,55,70,55,85,110],"types":["Grass","Flying"],"abilities":["Chlorophyll","LeafGuard"],"gender":127,"growth":"MediumSlow","catchRate":45,"friendship":70,"eggGroups":["Fairy","Grass"],"hatchCycles":20},{"id":190,"name":"Aipom","stats":[55,70,55,40,55,85],"types":["Normal"],"abilities":["RunAway","Pickup"],"gender":127,"growth":"Fast","catchRate":45,"friendship":70,"eggGroups":["Field"],"hatchCycles":20},{"id":191,"name":"Sunkern","stats":[30,30,30,30,30,30],"types":["Grass"],"abilities":["Chlorophyll","SolarPower"],"gender":127,"growth":"MediumSlow","catchRate":235,"friendship":70,"eggGroups":["Grass"],"hatchCycles":20},{"id":192,"name":"Sunflora","stats":[75,75,55,105,85,30],"types":["Grass"],"abilities":["Chlorophyll","SolarPower"],"gender":127,"growth":"MediumSlow","catchRate":120,"friendship":70,"eggGroups":["Grass"],"hatchCycles":20},{"id":193,"name":"Yanma","stats":[65,65,45,75,45,95],"types":["Bug","Flying"],"abilities":["SpeedBoost","CompoundEyes"],"gender":127,"growth":"MediumFast","catchRate":75,"friendship":70,"eggGroups":["Bug"],"hatchCycles":20},{"id":194,"name":"Wooper","stats":[55,45,45,25,25,15],"types":["Water","Ground"],"abilities":["Damp","WaterAbsorb"],"gender":127,"growth":"MediumFast","catchRate":255,"friendship":70,"eggGroups":["Water1","Field"],"hatchCycles":20},{"id":195,"name":"Quagsire","stats":[95,85,85,65,65,35],"types":["Water","Ground"],"abilities":["Damp","WaterAbsorb"],"gender":127,"growth":"MediumFast","catchRate":90,"friendship":70,"eggGroups":["Water1","Field"],"hatchCycles":20},{"id":196,"name":"Espeon","stats":[65,65,60,130,95,110],"types":["Psychic"],"abilities":["Synchronize"],"gender":31,"growth":"MediumFast","catchRate":45,"friendship":70,"eggGroups":["Field"],"hatchCycles":35},{"id":197,"name":"Umbreon","stats":[95,65,110,60,130,65],"types":["Dark"],"abilities":["Synchronize"],"gender":31,"growth":"MediumFast","catchRate":45,"friendship":35,"eggGroups":["Field"],"hatchCycles":35},{"id":198,"name":"Murkrow","stats":[60,85,42,85,42,91],"types":["Dark","Flying"],"abilities":["Insomnia","SuperLuck"],"gender":127,"growth":"MediumSlow","catchRate":30,"friendship":35,"eggGroups":["Flying"],"hatchCycles":20},{"id":199,"name":"Slowking","stats":[95,75,80,100,110,30],"types":["Water","Psychic"],"abilities":["Oblivious","OwnTempo"],"gender":127,"growth":"MediumFast","catchRate":70,"friendship":70,"eggGroups":["Monster","Water1"],"hatchCycles":20},{"id":200,"name":"Misdreavus","stats":[60,60,60,85,85,85],"types":["Ghost"],"abilities":["Levitate"],"gender":127,"growth":"Fast","catchRate":45,"friendship":35,"eggGroups":["Amorphous"],"hatchCycles":25},{"id":201,"name":"Unown","stats":[48,72,48,72,48,48],"types":["Psychic"],"abilities":["Levitate"],"gender":255,"growth":"MediumFast","catchRate":225,"friendship":70,"eggGroups":["Undiscovered"],"hatchCycles":40},{"id":202,"name":"Wobbuffet","stats":[190,33,58,33,58,33],"types":["Psychic"],"abilities":["ShadowTag"],"gender":127,"growth":"MediumFast","catchRate":45,"friendship":70,"eggGroups":["Amorphous"],"hatchCycles":20},{"id":203,"name":"Girafarig","stats":[70,80,65,90,65,85],"types":["Normal","Psychic"],"abilities":["InnerFocus","EarlyBird"],"gender":127,"growth":"MediumFast","catchRate":60,"friendship":70,"eggGroups":["Field"],"hatchCycles":20},{"id":204,"name":"Pineco","stats":[50,65,90,35,35,15],"types":["Bug"],"abilities":["Sturdy"],"gender":127,"growth":"MediumFast","catchRate":190,"friendship":70,"eggGroups":["Bug"],"hatchCycles":20},{"id":205,"name":"Forretress","stats":[75,90,140,60,60,40],"types":["Bug","Steel"],"abilities":["Sturdy"],"gender":127,"growth":"MediumFast","catchRate":75,"friendship":70,"eggGroups":["Bug"],"hatchCycles":20},{"id":206,"name":"Dunsparce","stats":[100,70,70,65,65,45],"types":["Normal"],"abilities":["SereneGrace","RunAway"],"gender":127,"growth":"MediumFast","catchRate":190,"friendship":70,"eggGroups":["Field"],"hatchCycles":20},{"id":207,"name":"Gligar","stats":[65,75,105,35,65,85],"types":["Ground","Flying"],"abilities":["HyperCutter","SandVeil"],"gender":127,"growth":"MediumSlow","catchRate":60,"friendship":70,"eggGroups":["Bug"],"hatchCycles":20},{"id":208,"name":"Steelix","stats":[75,85,200,55,65,30],"types":["Steel","Ground"],"abilities":["RockHead","Sturdy"],"gender":127,"growth":"MediumFast","catchRate":25,"friendship":70,"eggGroups":["Mineral"],"hatchCycles":25},{"id":209,"name":"Snubbull","stats":[60,80,50,40,40,30],"types":["Normal"],"abilities":["Intimidate","RunAway"],"gender":191,"growth":"Fast","catchRate":190,"friendship":70,"eggGroups":["Field","Fairy"],"hatchCycles":20},{"id":210,"name":"Granbull","stats":[90,120,75,60,60,45],"types":["Normal"],"abilities":["Intimidate","QuickFeet"],"gender":191,"growth":"Fast","catchRate":75,"friendship":70,"eggGroups":["Field","Fairy"],"hatchCycles":20},{"id":211,"name":"Qwilfish","stats":[65,95,75,55,55,85],"types":["Water","Poison"],"abilities":["PoisonPoint","SwiftSwim"],"gender":127,"growth":"MediumFast","catchRate":45,"friendship":70,"eggGroups":["Water2"],"hatchCycles":20},{"id":212,"name":"Scizor","stats":[70,130,100,55,80,65],"types":["Bug","Steel"],"abilities":["Swarm","Technician"],"gender":127,"growth":"MediumFast","catchRate":25,"friendship":70,"eggGroups":["Bug"],"hatchCycles":25},{"id":213,"name":"Shuckle","stats":[20,10,230,10,230,5],"types":["Bug","Rock"],"abilities":["Sturdy","Gluttony"],"gender":127,"growth":"MediumSlow","catchRate":190,"friendship":70,"eggGroups":["Bug"],"hatchCycles":20},{"id":214,"name":"Heracross","stats":[80,125,75,40,95,85],"types":["Bug","Fighting"],"abilities":["Swarm","Guts"],"gender":127,"growth":"Slow","catchRate":45,"friendship":70,"eggGroups":["Bug"],"hatchCycles":25},{"id":215,"name":"Sneasel","stats":[55,95,55,35,75,115],"types":["Dark","Ice"],"abilities":["InnerFocus","KeenEye"],"gender":127,"growth":"MediumSlow","catchRate":60,"friendship":35,"eggGroups":["Field"],"hatchCycles":20},{"id":216,"name":"Teddiursa","stats":[60,80,50,50,50,40],"types":["Normal"],"abilities":["Pickup","QuickFeet"],"gender":127,"growth":"MediumFast","catchRate":120,"friendship":70,"eggGroups":["Field"],"hatchCycles":20},{"id":217,"name":"Ursaring","stats":[90,130,75,75,75,55],"types":["Normal"],"abilities":["Guts","QuickFeet"],"gender":127,"growth":"MediumFast","catchRate":60,"friendship":70,"eggGroups":["Field"],"hatchCycles":20},{"id":218,"name":"Slugma","stats":[40,40,40,70,40,20],"types":["Fire"],"abilities":["MagmaArmor","FlameBody"],"gender":127,"growth":"MediumFast","catchRate":190,"friendship":70,"eggGroups":["Amorphous"],"hatchCycles":20},{"id":219,"name":"Magcargo","stats":[50,50,120,80,80,30],"types":["Fire","Rock"],"abilities":["MagmaArmor","FlameBody"],"gender":127,"growth":"MediumFast","catchRate":75,"friendship":70,"eggGroups":["Amorphous"],"hatchCycles":20},{"id":220,"name":"Swinub","stats":[50,50,40,30,30,50],"types":["Ice","Ground"],"abilities":["Oblivious","SnowCloak"],"gender":127,"growth":"Slow","catchRate":225,"friendship":70,"eggGroups":["Field"],"hatchCycles":20},{"id":221,"name":"Piloswine","stats":[100,100,80,60,60,50],"types":["Ice","Ground"],"abilities":["Oblivious","SnowCloak"],"gender":127,"growth":"Slow","catchRate":75,"friendship":70,"eggGroups":["Field"],"hatchCycles":20},{"id":222,"name":"Corsola","stats":[55,55,85,65,85,35],"types":["Water","Rock"],"abilities":["Hustle","NaturalCure"],"gender":191,"growth":"Fast","catchRate":60,"friendship":70,"eggGroups":["Water1","Water3"],"hatchCycles":20},{"id":223,"name":"Remoraid","stats":[35,65,35,65,35,65],"types":["Water"],"abilities":["Hustle","Sniper"],"gender":127,"growth":"MediumFast","catchRate":190,"friendship":70,"eggGroups":["Water1","Water2"],"hatchCycles":20},{"id":224,"name":"Octillery","stats":[75,105,75,105,75,45],"types":["Water"],"abilities":["SuctionCups","Sniper"],"gender":127,"growth":"MediumFast","catchRate":75,"friendship":70,"eggGroups":["Water1","Water2"],"hatchCycles":20},{"id":225,"name":"Delibird","stats":[45,55,45,65,45,75],"types":["Ice","Flying"],"abilities":["VitalSpirit","Hustle"],"gender":127,"growth":"Fast","catchRate":45,"friendship":70,"eggGroups":["Water1","Field"],"hatchCycles":20},{"id":226,"name":"Mantine","stats":[65,40,70,80,140,70],"types":["Water","Flying"],"abilities":["SwiftSwim","WaterAbsorb"],"gender":127,"growth":"Slow","catchRate":25,"friendship":70,"eggGroups":["Water1"],"hatchCycles":25},{"id":227,"name":"Skarmory","stats":[65,80,140,40,70,70],"types":["Steel","Flying"],"abilities":["KeenEye","Sturdy"],"gender":127,"growth":"Slow","catchRate":25,"friendship":70,"eggGroups":["Flying"],"hatchCycles":25},{"id":228,"name":"Houndour","stats":[45,60,30,80,50,65],"types":["Dark","Fire"],"abilities":["EarlyBird","FlashFire"],"gender":127,"growth":"Slow","catchRate":120,"friendship":35,"eggGroups":["Field"],"hatchCycles":20},{"id":229,"name":"Houndoom","stats":[75,90,50,110,80,95],"types":["Dark","Fire"],"abilities":["EarlyBird","FlashFire"],"gender":127,"growth":"Slow","catchRate":45,"friendship":35,"eggGroups":["Field"],"hatchCycles":20},{"id":230,"name":"Kingdra","stats":[75,95,95,95,95,85],"types":["Water","Dragon"],"abilities":["SwiftSwim","Sniper"],"gender":127,"growth":"MediumFast","catchRate":45,"friendship":70,"eggGroups":["Water1","Dragon"],"hatchCycles":20},{"id":231,"name":"Phanpy","stats":[90,60,60,40,40,40],"types":["Ground"],"abilities":["Pickup"],"gender":127,"growth":"MediumFast","catchRate":120,"friendship":70,"eggGroups":["Field"],"hatchCycles":20},{"id":232,"name":"Donphan","stats":[90,120,120,60,60,50],"types":["Ground"],"abilities":["Sturdy"],"gender":127,"growth":"MediumFast","catchRate":60,"friendship":70,"eggGroups":["Field"],"hatchCycles":20},{"id":233,"name":"Porygon2","stats":[85,80,90,105,95,60],"types":["Normal"],"abilities":["Trace","Download"],"gender":255,"growth":"MediumFast","catchRate":45,"friendship":70,"eggGroups":["Mineral"],"hatchCycles":20},{"id":234,"name":"Stantler","stats":[73,95,62,85,65,85],"types":["Normal"],"abilities":["Intimidate","Frisk"],"gender":127,"growth":"Slow","catchRate":45,"friendship":70,"eggGroups":["Field"],"hatchCycles":20},{"id":235,"name":"Smeargle","stats":[55,20,35,20,45,75],"types":["Normal"],"abilities":["OwnTempo","Technician"],"gender":127,"growth":"Fast","catchRate":45,"friendship":70,"eggGroups":["Field"],"hatchCycles":20},{"id":236,"name":"Tyrogue","stats":[35,35,35,35,35,35],"types":["Fighting"],"abilities":["Guts","Steadfast"],"gender":0,"growth":"MediumFast","catchRate":75,"friendship":70,"eggGroups":["Undiscovered"],"hatchCycles":25},{"id":237,"name":"Hitmontop","stats":[50,95,95,35,110,70],"types":["Fighting"],"abilities":["Intimidate","Technician"],"gender":0,"growth":"MediumFast","catchRate":45,"friendship":70,"eggGroups":["HumanLike"],"hatchCycles":25},{"id":238,"name":"Smoochum","stats":[45,30,15,85,65,65],"types":["Ice","Psychic"],"abilities":["Oblivious","Forewarn"],"gender":254,"growth":"MediumFast","catchRate":45,"friendship":70,"eggGroups":["Undiscovered"],"hatchCycles":25},{"id":239,"name":"Elekid","stats":[45,63,37,65,55,95],"types":["Electric"],"abilities":["Static"],"gender":63,"growth":"MediumFast","catchRate":45,"friendship":70,"eggGroups":["Undiscovered"],"hatchCycles":25},{"id":240,"name":"Magby","stats":[45,75,37,70,55,83],"types":["Fire"],"abilities":["FlameBody"],"gender":63,"growth":"MediumFast","catchRate":45,"friendship":70,"eggGroups":["Undiscovered"],"hatchCycles":25},{"id":241,"name":"Miltank","stats":[95,80,105,40,70,100],"types":["Normal"],"abilities":["ThickFat","Scrappy"],"gender":254,"growth":"Slow","catchRate":45,"friendship":70,"eggGroups":["Field"],"hatchCycles":20},{"id":242,"name":"Blissey","stats":[255,10,10,75,135,55],"types":["Normal"],"abilities":["NaturalCure","SereneGrace"],"gender":254,"growth":"Fast","catchRate":30,"friendship":140,"eggGroups":["Fairy"],"hatchCycles":40},{"id":243,"name":"Raikou","stats":[90,85,75,115,100,115],"types":["Electric"],"abilities":["Pressure"],"gender":255,"growth":"Slow","catchRate":3,"friendship":35,"eggGroups":["Undiscovered"],"hatchCycles":80},{"id":244,"name":"Entei","stats":[115,115,85,90,75,100],"types":["Fire"],"abilities":["Pressure"],"gender":255,"growth":"Slow","catchRate":3,"friendship":35,"eggGroups":["Undiscovered"],"hatchCycles":80},{"id":245,"name":"Suicune","stats":[100,75,115,90,115,85],"types":["Water"],"abilities":["Pressure"],"gender":255,"growth":"Slow","catchRate":3,"friendship":35,"eggGroups":["Undiscovered"],"hatchCycles":80},{"id":246,"name":"Larvitar","stats":[50,64,50,45,50,41],"types":["Rock","Ground"],"abilities":["Guts"],"gender":127,"growth":"Slow","catchRate":45,"friendship":35,"eggGroups":["Monster"],"hatchCycles":40},{"id":247,"name":"Pupitar","stats":[70,84,70,65,70,51],"types":["Rock","Ground"],"abilities":["ShedSkin"],"gender":127,"growth":"Slow","catchRate":45,"friendship":35,"eggGroups":["Monster"],"hatchCycles":40},{"id":248,"name":"Tyranitar","stats":[100,134,110,95,100,61],"types":["Rock","Dark"],"abilities":["SandStream"],"gender":127,"growth":"Slow","catchRate":45,"friendship":35,"eggGroups":["Monster"],"hatchCycles":40},{"id":249,"name":"Lugia","stats":[106,90,130,90,154,110],"types":["Psychic","Flying"],"abilities":["Pressure"],"gender":255,"growth":"Slow","catchRate":3,"friendship":0,"eggGroups":["Undiscovered"],"hatchCycles":120},{"id":250,"name":"Hooh","stats":[106,130,90,110,154,90],"types":["Fire","Flying"],"abilities":["Pressure"],"gender":255,"growth":"Slow","catchRate":3,"friendship":0,"eggGroups":["Undiscovered"],"hatchCycles":120},{"id":251,"name":"Celebi","stats":[100,100,100,100,100,100],"types":["Psychic","Grass"],"abilities":["NaturalCure"],"gender":255,"growth":"MediumSlow","catchRate":45,"friendship":100,"eggGroups":["Undiscovered"],"hatchCycles":120},{"id":252,"name":"Treecko","stats":[40,45,35,65,55,70],"types":["Grass"],"abilities":["Overgrow"],"gender":31,"growth":"MediumSlow","catchRate":45,"friendship":70,"eggGroups":["Monster","Dragon"],"hatchCycles":20},{"id":253,"name":"Grovyle","stats":[50,65,45,85,65,95],"types":["Grass"],"abilities":["Overgrow"],"gender":31,"growth":"MediumSlow","catchRate":45,"friendship":70,"eggGroups":["Monster","Dragon"],"hatchCycles":20},{"id":254,"name":"Sceptile","stats":[70,85,65,105,85,120],"types":["Grass"],"abilities":["Overgrow"],"gender":31,"growth":"MediumSlow","catchRate":45,"friendship":70,"eggGroups":["Monster","Dragon"],"hatchCycles":20},{"id":255,"name":"Torchic","stats":[45,60,40,70,50,45],"types":["Fire"],"abilities":["Blaze"],"gender":31,"growth":"MediumSlow","catchRate":45,"friendship":70,"eggGroups":["Field"],"hatchCycles":20},{"id":256,"name":"Combusken","stats":[60,85,60,85,60,55],"types":["Fire","Fighting"],"abilities":["Blaze"],"gender":31,"growth":"MediumSlow","catchRate":45,"friendship":70,"eggGroups":["Field"],"hatchCycles":20},{"id":257,"name":"Blaziken","stats":[80,120,70,110,70,80],"types":["Fire","Fighting"],"abilities":["Blaze"],"gender":31,"growth":"MediumSlow","catchRate":45,"friendship":70,"eggGroups":["Field"],"hatchCycles":20},{"id":258,"name":"Mudkip","stats":[50,70,50,50,50,40],"types":["Water"],"abilities":["Torrent"],"gender":31,"growth":"MediumSlow","catchRate":45,"friendship":70,"eggGroups":["Monster","Water1"],"hatchCycles":20},{"id":259,"name":"Marshtomp","stats":[70,85,70,60,70,50],"types":["Water","Ground"],"abilities":["Torrent"],"gender":31,"growth":"MediumSlow","catchRate":45,"friendship":70,"eggGroups":["Monster","Water1"],"hatchCycles":20},{"id":260,"name":"Swampert","stats":[100,110,90,85,90,60],"types":["Water","Ground"],"abilities":["Torrent"],"gender":31,"growth":"MediumSlow","catchRate":45,"friendship":70,"eggGroups":["Monster","Water1"],"hatchCycles":20},{"id":261,"name":"Poochyena","stats":[35,55,35,30,30,35],"types":["Dark"],"abilities":["RunAway","QuickFeet"],"gender":127,"growth":"MediumFast","catchRate":255,"friendship":70,"eggGroups":["Field"],"hatchCycles":15},{"id":262,"name":"Mightyena","stats":[70,90,70,60,60,70],"types":["Dark"],"abilities":["Intimidate","QuickFeet"],"gender":127,"growth":"MediumFast","catchRate":127,"friendship":70,"eggGroups":["Field"],"hatchCycles":15},{"id":263,"name":"Zigzagoon","stats":[38,30,41,30,41,60],"types":["Normal"],"abilities":["Pickup","Gluttony"],"gender":127,"growth":"MediumFast","catchRate":255,"friendship":70,"eggGroups":["Field"],"hatchCycles":15},{"id":264,"name":"Linoone","stats":[78,70,61,50,61,100],"types":["Normal"],"abilities":["Pickup","Gluttony"],"gender":127,"growth":"MediumFast","catchRate":90,"friendship":70,"eggGroups":["Field"],"hatchCycles":15},{"id":265,"name":"Wurmple","stats":[45,45,35,20,30,20],"types":["Bug"],"abilities":["ShieldDust"],"gender":127,"growth":"MediumFast","catchRate":255,"friendship":70,"eggGroups":["Bug"],"hatchCycles":15},{"id":266,"name":"Silcoon","stats":[50,35,55,25,25,15],"types":["Bug"],"abilities":["ShedSkin"],"gender":127,"growth":"MediumFast","catchRate":120,"friendship":70,"eggGroups":["Bug"],"hatchCycles":15},{"id":267,"name":"Beautifly","stats":[60,70,50,90,50,65],"types":["Bug","Flying"],"abilities":["Swarm"],"gender":127,"growth":"MediumFast","catchRate":45,"friendship":70,"eggGroups":["Bug"],"hatchCycles":15},{"id":268,"name":"Cascoon","stats":[50,35,55,25,25,15],"types":["Bug"],"abilities":["ShedSkin"],"gender":127,"growth":"MediumFast","catchRate":120,"friendship":70,"eggGroups":["Bug"],"hatchCycles":15},{"id":269,"name":"Dustox","stats":[60,50,70,50,90,65],"types":["Bug","Poison"],"abilities":["ShieldDust"],"gender":127,"growth":"MediumFast","catchRate":45,"friendship":70,"eggGroups":["Bug"],"hatchCycles":15},{"id":270,"name":"Lotad","stats":[40,30,30,40,50,30],"types":["Water","Grass"],"abilities":["SwiftSwim","RainDish"],"gender":127,"growth":"MediumSlow","catchRate":255,"friendship":70,"eggGroups":["Water1","Grass"],"hatchCycles":15},{"id":271,"name":"Lombre","stats":[60,50,50,60,70,50],"types":["Water","Grass"],"abilities":["SwiftSwim","RainDish"],"gender":127,"growth":"MediumSlow","catchRate":120,"friendship":70,"eggGroups":["Water1","Grass"],"hatchCycles":15},{"id":272,"name":"Ludicolo","stats":[80,70,70,90,100,70],"types":["Water","Grass"],"abilities":["SwiftSwim","RainDish"],"gender":127,"growth":"MediumSlow","catchRate":45,"friendship":70,"eggGroups":["Water1","Grass"],"hatchCycles":15},{"id":273,"name":"Seedot","stats":[40,40,50,30,30,30],"types":["Grass"],"abilities":["Chlorophyll","EarlyBird"],"gender":127,"growth":"MediumSlow","catchRate":255,"friendship":70,"eggGroups":["Field","Grass"],"hatchCycles":15},{"id":274,"name":"Nuzleaf","stats":[70,70,40,60,40,60],"types":["Grass","Dark"],"abilities":["Chlorophyll","EarlyBird"],"gender":127,"growth":"MediumSlow","catchRate":120,"friendship":70,"eggGroups":["Field","Grass"],"hatchCycles":15},{"id":275,"name":"Shiftry","stats":[90,100,60,90,60,80],"types":["Grass","Dark"],"abilities":["Chlorophyll","EarlyBird"],"gender":127,"growth":"MediumSlow","catchRate":45,"friendship":70,"eggGroups":["Field","Grass"],"hatchCycles":15},{"id":276,"name":"Taillow","stats":[40,55,30,30,30,85],"types":["Normal","Flying"],"abilities":["Guts"],"gender":127,"growth":"MediumSlow","catchRate":200,"friendship":70,"eggGroups":["Flying"],"hatchCycles":15},{"id":277,"name":"Swellow","stats":[60,85,60,50,50,125],"types":["Normal","Flying"],"abilities":["Guts"],"gender":127,"growth":"MediumSlow","catchRate":45,"friendship":70,"eggGroups":["Flying"],"hatchCycles":15},{"id":278,"name":"Wingull","stats":[40,30,30,55,30,85],"types":["Water","Flying"],"abilities":["KeenEye"],"gender":127,"growth":"MediumFast","catchRate":190,"friendship":70,"eggGroups":["Water1","Flying"],"hatchCycles":20},{"id":279,"name":"Pelipper","stats":[60,50,100,85,70,65],"types":["Water","Flying"],"abilities":["KeenEye"],"gender":127,"growth":"MediumFast","catchRate":45,"friendship":70,"eggGroups":["Water1","Flying"],"hatchCycles":20},{"id":280,"name":"Ralts","stats":[28,25,25,45,35,40],"types":["Psychic"],"abilities":["Synchronize","Trace"],"gender":127,"growth":"Slow","catchRate":235,"friendship":35,"eggGroups":["Amorphous"],"hatchCycles":20},{"id":281,"name":"Kirlia","stats":[38,35,35,65,55,50],"types":["Psychic"],"abilities":["Synchronize","Trace"],"gender":127,"growth":"Slow","catchRate":120,"friendship":35,"eggGroups":["Amorphous"],"hatchCycles":20},{"id":282,"name":"Gardevoir","stats":[68,65,65,125,115,80],"types":["Psychic"],"abilities":["Synchronize","Trace"],"gender":127,"growth":"Slow","catchRate":45,"friendship":35,"eggGroups":["Amorphous"],"hatchCycles":20},{"id":283,"name":"Surskit","stats":[40,30,32,50,52,65],"types":["Bug","Water"],"abilities":["SwiftSwim"],"gender":127,"growth":"MediumFast","catchRate":200,"friendship":70,"eggGroups":["Water1","Bug"],"hatchCycles":15},{"id":284,"name":"Masquerain","stats":[70,60,62,80,82,60],"types":["Bug","Flying"],"abilities":["Intimidate"],"gender":127,"growth":"MediumFast","catchRate":75,"friendship":70,"eggGroups":["Water1","Bug"],"hatchCycles":15},{"id":285,"name":"Shroomish","stats":[60,40,60,40,60,35],"types":["Grass"],"abilities":["EffectSpore","PoisonHeal"],"gender":127,"growth":"Fluctuating","catchRate":255,"friendship":70,"eggGroups":["Fairy","Grass"],"hatchCycles":15},{"id":286,"name":"Breloom","stats":[60,130,80,60,60,70],"types":["Grass","Fighting"],"abilities":["EffectSpore","PoisonHeal"],"gender":127,"growth":"Fluctuating","catchRate":90,"friendship":70,"eggGroups":["Fairy","Grass"],"hatchCycles":15},{"id":287,"name":"Slakoth","stats":[60,60,60,35,35,30],"types":["Normal"],"abilities":["Truant"],"gender":127,"growth":"Slow","catchRate":255,"friendship":70,"eggGroups":["Field"],"hatchCycles":15},{"id":288,"name":"Vigoroth","stats":[80,80,80,55,55,90],"types":["Normal"],"abilities":["VitalSpirit"],"gender":127,"growth":"Slow","catchRate":120,"friendship":70,"eggGroups":["Field"],"hatchCycles":15},{"id":289,"name":"Slaking","stats":[150,160,100,95,65,100],"types":["Normal"],"abilities":["Truant"],"gender":127,"growth":"Slow","catchRate":45,"friendship":70,"eggGroups":["Field"],"hatchCycles":15},{"id":290,"name":"Nincada","stats":[31,45,90,30,30,40],"types":["Bug","Ground"],"abilities":["CompoundEyes"],"gender":127,"growth":"Erratic","catchRate":255,"friendship":70,"eggGroups":["Bug"],"hatchCycles":15},{"id":291,"name":"Ninjask","stats":[61,90,45,50,50,160],"types":["Bug","Flying"],"abilities":["SpeedBoost"],"gender":127,"growth":"Erratic","catchRate":120,"friendship":70,"eggGroups":["Bug"],"hatchCycles":15},{"id":292,"name":"Shedinja","stats":[1,90,45,30,30,40],"types":["Bug","Ghost"],"abilities":["WonderGuard"],"gender":255,"growth":"Erratic","catchRate":45,"friendship":70,"eggGroups":["Mineral"],"hatchCycles":15},{"id":293,"name":"Whismur","stats":[64,51,23,51,23,28],"types":["Normal"],"abilities":["Soundproof"],"gender":127,"growth":"MediumSlow","catchRate":190,"friendship":70,"eggGroups":["Monster","Field"],"hatchCycles":20},{"id":294,"name":"Loudred","stats":[84,71,43,71,43,48],"types":["Normal"],"abilities":["Soundproof"],"gender":127,"growth":"MediumSlow","catchRate":120,"friendship":70,"eggGroups":["Monster","Field"],"hatchCycles":20},{"id":295,"name":"Exploud","stats":[104,91,63,91,63,68],"types":["Normal"],"abilities":["Soundproof"],"gender":127,"growth":"MediumSlow","catchRate":45,"friendship":70,"eggGroups":["Monster","Field"],"hatchCycles":20},{"id":296,"name":"Makuhita","stats":[72,60,30,20,30,25],"types":["Fighting"],"abilities":["ThickFat","Guts"],"gender":127,"growth":"Fluctuating","catchRate":180,"friendship":70,"eggGroups":["HumanLike"],"hatchCycles":20},{"id":297,"name":"Hariyama","stats":[144,120,60,40,60,50],"types":["Fighting"],"abilities":["ThickFat","Guts"],"gender":127,"growth":"Fluctuating","catchRate":200,"friendship":70,"eggGroups":["HumanLike"],"hatchCycles":20},{"id":298,"name":"Azurill","stats":[50,20,40,20,40,20],"types":["Normal"],"abilities":["ThickFat","HugePower"],"gender":191,"growth":"Fast","catchRate":150,"friendship":70,"eggGroups":["Undiscovered"],"hatchCycles":10},{"id":299,"name":"Nosepass","stats":[30,45,135,45,90,30],"types":["Rock"],"abilities":["Sturdy","MagnetPull"],"gender":127,"growth":"MediumFast","catchRate":255,"friendship":70,"eggGroups":["Mineral"],"hatchCycles":20},{"id":300,"name":"Skitty","stats":[50,45,45,35,35,50],"types":["Normal"],"abilities":["CuteCharm","Normalize"],"gender":191,"growth":"Fast","catchRate":255,"friendship":70,"eggGroups":["Field","Fairy"],"hatchCycles":15},{"id":301,"name":"Delcatty","stats":[70,65,65,55,55,70],"types":["Normal"],"abilities":["CuteCharm","Normalize"],"gender":191,"growth":"Fast","catchRate":60,"friendship":70,"eggGroups":["Field","Fairy"],"hatchCycles":15},{"id":302,"name":"Sableye","stats":[50,75,75,65,65,50],"types":["Dark","Ghost"],"abilities":["KeenEye","Stall"],"gender":127,"growth":"MediumSlow","catchRate":45,"friendship":35,"eggGroups":["HumanLike"],"hatchCycles":25},{"id":303,"name":"Mawile","stats":[50,85,85,55,55,50],"types":["Steel"],"abilities":["HyperCutter","Intimidate"],"gender":127,"growth":"Fast","catchRate":45,"friendship":70,"eggGroups":["Field","Fairy"],"hatchCycles":20},{"id":304,"name":"Aron","stats":[50,70,100,40,40,30],"types":["Steel","Rock"],"abilities":["Sturdy","RockHead"],"gender":127,"growth":"Slow","catchRate":180,"friendship":35,"eggGroups":["Monster"],"hatchCycles":35},{"id":305,"name":"Lairon","stats":[60,90,140,50,50,40],"types":["Steel","Rock"],"abilities":["Sturdy","RockHead"],"gender":127,"growth":"Slow","catchRate":90,"friendship":35,"eggGroups":["Monster"],"hatchCycles":35},{"id":306,"name":"Aggron","stats":[70,110,180,60,60,50],"types":["Steel","Rock"],"abilities":["Sturdy","RockHead"],"gender":127,"growth":"Slow","catchRate":45,"friendship":35,"eggGroups":["Monster"],"hatchCycles":35},{"id":307,"name":"Meditite","stats":[30,40,55,40,55,60],"types":["Fighting","Psychic"],"abilities":["PurePower"],"gender":127,"growth":"MediumFast","catchRate":180,"friendship":70,"eggGroups":["HumanLike"],"hatchCycles":20},{"id":308,"name":"Medicham","stats":[60,60,75,60,75,80],"types":["Fighting","Psychic"],"abilities":["PurePower"],"gender":127,"growth":"MediumFast","catchRate":90,"friendship":70,"eggGroups":["HumanLike"],"hatchCycles":20},{"id":309,"name":"Electrike","stats":[40,45,40,65,40,65],"types":["Electric"],"abilities":["Static","LightningRod"],"gender":127,"growth":"Slow","catchRate":120,"friendship":70,"eggGroups":["Field"],"hatchCycles":20},{"id":310,"name":"Manectric","stats":[70,75,60,105,60,105],"types":["Electric"],"abilities":["Static","LightningRod"],"gender":127,"growth":"Slow","catchRate":45,"friendship":70,"eggGroups":["Field"],"hatchCycles":20},{"id":311,"name":"Plusle","stats":[60,50,40,85,75,95],"types":["Electric"],"abilities":["Plus"],"gender":127,"growth":"MediumFast","catchRate":200,"friendship":70,"eggGroups":["Fairy"],"hatchCycles":20},{"id":312,"name":"Minun","stats":[60,40,50,75,85,95],"types":["Electric"],"abilities":["Minus"],"gender":127,"growth":"MediumFast","catchRate":200,"friendship":70,"eggGroups":["Fairy"],"hatchCycles":20},{"id":313,"name":"Volbeat","stats":[65,73,55,47,75,85],"types":["Bug"],"abilities":["Illuminate","Swarm"],"gender":0,"growth":"Erratic","catchRate":150,"friendship":70,"eggGroups":["Bug","HumanLike"],"hatchCycles":15},{"id":314,"name":"Illumise","stats":[65,47,55,73,75,85],"types":["Bug"],"abilities":["Oblivious","TintedLens"],"gender":254,"growth":"Fluctuating","catchRate":150,"friendship":70,"eggGroups":["Bug","HumanLike"],"hatchCycles":15},{"id":315,"name":"Roselia","stats":[50,60,45,100,80,65],"types":["Grass","Poison"],"abilities":["NaturalCure","PoisonPoint"],"gender":127,"growth":"MediumSlow","catchRate":150,"friendship":70,"eggGroups":["Fairy","Grass"],"hatchCycles":20},{"id":316,"name":"Gulpin","stats":[70,43,53,43,53,40],"types":["Poison"],"abilities":["LiquidOoze","StickyHold"],"gender":127,"growth":"Fluctuating","catchRate":225,"friendship":70,"eggGroups":["Amorphous"],"hatchCycles":20},{"id":317,"name":"Swalot","stats":[100,73,83,73,83,55],"types":["Poison"],"abilities":["LiquidOoze","StickyHold"],"gender":127,"growth":"Fluctuating","catchRate":75,"friendship":70,"eggGroups":["Amorphous"],"hatchCycles":20},{"id":318,"name":"Carvanha","stats":[45,90,20,65,20,65],"types":["Water","Dark"],"abilities":["RoughSkin"],"gender":127,"growth":"Slow","catchRate":225,"friendship":35,"eggGroups":["Water2"],"hatchCycles":20},{"id":319,"name":"Sharpedo","stats":[70,120,40,95,40,95],"types":["Water","Dark"],"abilities":["RoughSkin"],"gender":127,"growth":"Slow","catchRate":60,"friendship":35,"eggGroups":["Water2"],"hatchCycles":20},{"id":320,"name":"Wailmer","stats":[130,70,35,70,35,60],"types":["Water"],"abilities":["WaterVeil","Oblivious"],"gender":127,"growth":"Fluctuating","catchRate":125,"friendship":70,"eggGroups":["Field","Water2"],"hatchCycles":40},{"id":321,"name":"Wailord","stats":[170,90,45,90,45,60],"types":["Water"],"abilities":["WaterVeil","Oblivious"],"gender":127,"growth":"Fluctuating","catchRate":60,"friendship":70,"eggGroups":["Field","Water2"],"hatchCycles":40},{"id":322,"name":"Numel","stats":[60,60,40,65,45,35],"types":["Fire","Ground"],"abilities":["Oblivious","Simple"],"gender":127,"growth":"MediumFast","catchRate":255,"friendship":70,"eggGroups":["Field"],"hatchCycles":20},{"id":323,"name":"Camerupt","stats":[70,100,70,105,75,40],"types":["Fire","Ground"],"abilities":["MagmaArmor","SolidRock"],"gender":127,"growth":"MediumFast","catchRate":150,"friendship":70,"eggGroups":["Field"],"hatchCycles":20},{"id":324,"name":"Torkoal","stats":[70,85,140,85,70,20],"types":["Fire"],"abilities":["WhiteSmoke"],"gender":127,"growth":"MediumFast","catchRate":90,"friendship":70,"eggGroups":["Field"],"hatchCycles":20},{"id":325,"name":"Spoink","stats":[60,25,35,70,80,60],"types":["Psychic"],"abilities":["ThickFat","OwnTempo"],"gender":127,"growth":"Fast","catchRate":255,"friendship":70,"eggGroups":["Field"],"hatchCycles":20},{"id":326,"name":"Grumpig","stats":[80,45,65,90,110,80],"types":["Psychic"],"abilities":["ThickFat","OwnTempo"],"gender":127,"growth":"Fast","catchRate":60,"friendship":70,"eggGroups":["Field"],"hatchCycles":20},{"id":327,"name":"Spinda","stats":[60,60,60,60,60,60],"types":["Normal"],"abilities":["OwnTempo","TangledFeet"],"gender":127,"growth":"Fast","catchRate":255,"friendship":70,"eggGroups":["Field","HumanLike"],"hatchCycles":15},{"id":328,"name":"Trapinch","stats":[45,100,45,45,45,10],"types":["Ground"],"abilities":["HyperCutter","ArenaTrap"],"gender":127,"growth":"MediumSlow","catchRate":255,"friendship":70,"eggGroups":["Bug"],"hatchCycles":20},{"id":329,"name":"Vibrava","stats":[50,70,50,50,50,70],"types":["Ground","Dragon"],"abilities":["Levitate"],"gender":127,"growth":"MediumSlow","catchRate":120,"friendship":70,"eggGroups":["Bug"],"hatchCycles":20},{"id":330,"name":"Flygon","stats":[80,100,80,80,80,100],"types":["Ground","Dragon"],"abilities":["Levitate"],"gender":127,"growth":"MediumSlow","catchRate":45,"friendship":70,"eggGroups":["Bug"],"hatchCycles":20},{"id":331,"name":"Cacnea","stats":[50,85,40,85,40,35],"types":["Grass"],"abilities":["SandVeil"],"gender":127,"growth":"MediumSlow","catchRate":190,"friendship":35,"eggGroups":["Grass","HumanLike"],"hatchCycles":20},{"id":332,"name":"Cacturne","stats":[70,115,60,115,60,55],"types":["Grass","Dark"],"abilities":["SandVeil"],"gender":127,"growth":"MediumSlow","catchRate":60,"friendship":35,"eggGroups":["Grass","HumanLike"],"hatchCycles":20},{"id":333,"name":"Swablu","stats":[45,40,60,40,75,50],"types":["Normal","Flying"],"abilities":["NaturalCure"],"gender":127,"growth":"Erratic","catchRate":255,"friendship":70,"eggGroups":["Flying","Dragon"],"hatchCycles":20},{"id":334,"name":"Altaria","stats":[75,70,90,70,105,80],"types":["Dragon","Flying"],"abilities":["NaturalCure"],"gender":127,"growth":"Erratic","catchRate":45,"friendship":70,"eggGroups":["Flying","Dragon"],"hatchCycles":20},{"id":335,"name":"Zangoose","stats":[73,115,60,60,60,90],"types":["Normal"],"abilities":["Immunity"],"gender":127,"growth":"Erratic","catchRate":90,"friendship":70,"eggGroups":["Field"],"hatchCycles":20},{"id":336,"name":"Seviper","stats":[73,100,60,100,60,65],"types":["Poison"],"abilities":["ShedSkin"],"gender":127,"growth":"Fluctuating","catchRate":90,"friendship":70,"eggGroups":["Field","Dragon"],"hatchCycles":20},{"id":337,"name":"Lunatone","stats":[70,55,65,95,85,70],"types":["Rock","Psychic"],"abilities":["Levitate"],"gender":255,"growth":"Fast","catchRate":45,"friendship":70,"eggGroups":["Mineral"],"hatchCycles":25},{"id":338,"name":"Solrock","stats":[70,95,85,55,65,70],"types":["Rock","Psychic"],"abilities":["Levitate"],"gender":255,"growth":"Fast","catchRate":45,"friendship":70,"eggGroups":["Mineral"],"hatchCycles":25},{"id":339,"name":"Barboach","stats":[50,48,43,46,41,60],"types":["Water","Ground"],"abilities":["Oblivious","Anticipation"],"gender":127,"growth":"MediumFast","catchRate":190,"friendship":70,"eggGroups":["Water2"],"hatchCycles":20},{"id":340,"name":"Whiscash","stats":[110,78,73,76,71,60],"types":["Water","Ground"],"abilities":["Oblivious","Anticipation"],"gender":127,"growth":"MediumFast","catchRate":75,"friendship":70,"eggGroups":["Water2"],"hatchCycles":20},{"id":341,"name":"Corphish","stats":[43,80,65,50,35,35],"types":["Water"],"abilities":["HyperCutter","ShellArmor"],"gender":127,"growth":"Fluctuating","catchRate":205,"friendship":70,"eggGroups":["Water1","Water3"],"hatchCycles":15},{"id":342,"name":"Crawdaunt","stats":[63,120,85,90,55,55],"types":["Water","Dark"],"abilities":["HyperCutter","ShellArmor"],"gender":127,"growth":"Fluctuating","catchRate":155,"friendship":70,"eggGroups":["Water1","Water3"],"hatchCycles":15},{"id":343,"name":"Baltoy","stats":[40,40,55,40,70,55],"types":["Ground","Psychic"],"abilities":["Levitate"],"gender":255,"growth":"MediumFast","catchRate":255,"friendship":70,"eggGroups":["Mineral"],"hatchCycles":20},{"id":344,"name":"Claydol","stats":[60,70,105,70,120,75],"types":["Ground","Psychic"],"abilities":["Levitate"],"gender":255,"growth":"MediumFast","catchRate":90,"friendship":70,"eggGroups":["Mineral"],"hatchCycles":20},{"id":345,"name":"Lileep","stats":[66,41,77,61,87,23],"types":["Rock","Grass"],"abilities":["SuctionCups"],"gender":31,"growth":"Erratic","catchRate":45,"friendship":70,"eggGroups":["Water3"],"hatchCycles":30},{"id":346,"name":"Cradily","stats":[86,81,97,81,107,43],"types":["Rock","Grass"],"abilities":["SuctionCups"],"gender":31,"growth":"Erratic","catchRate":45,"friendship":70,"eggGroups":["Water3"],"hatchCycles":30},{"id":347,"name":"Anorith","stats":[45,95,50,40,50,75],"types":["Rock","Bug"],"abilities":["BattleArmor"],"gender":31,"growth":"Erratic","catchRate":45,"friendship":70,"eggGroups":["Water3"],"hatchCycles":30},{"id":348,"name":"Armaldo","stats":[75,125,100,70,80,45],"types":["Rock","Bug"],"abilities":["BattleArmor"],"gender":31,"growth":"Erratic","catchRate":45,"friendship":70,"eggGroups":["Water3"],"hatchCycles":30},{"id":349,"name":"Feebas","stats":[20,15,20,10,55,80],"types":["Water"],"abilities":["SwiftSwim"],"gender":127,"growth":"Erratic","catchRate":255,"friendship":70,"eggGroups":["Water1","Dragon"],"hatchCycles":20},{"id":350,"name":"Milotic","stats":[95,60,79,100,125,81],"types":["Water"],"abilities":["MarvelScale"],"gender":127,"growth":"Erratic","catchRate":60,"friendship":70,"eggGroups":["Water1","Dragon"],"hatchCycles":20},{"id":351,"name":"Castform","stats":[70,70,70,70,70,70],"types":["Normal"],"abilities":["Forecast"],"gender":127,"growth":"MediumFast","catchRate":45,"friendship":70,"eggGroups":["Fairy","Amorphous"],"hatchCycles":25},{"id":352,"name":"Kecleon","stats":[60,90,70,60,120,40],"types":["Normal"],"abilities":["ColorChange"],"gender":127,"growth":"MediumSlow","catchRate":200,"friendship":70,"eggGroups":["Field"],"hatchCycles":20},{"id":353,"name":"Shuppet","stats":[44,75,35,63,33,45],"types":["Ghost"],"abilities":["Insomnia","Frisk"],"gender":127,"growth":"Fast","catchRate":225,"friendship":35,"eggGroups":["Amorphous"],"hatchCycles":25},{"id":354,"name":"Banette","stats":[64,115,65,83,63,65],"types":["Ghost"],"abilities":["Insomnia","Frisk"],"gender":127,"growth":"Fast","catchRate":45,"friendship":35,"eggGroups":["Amorphous"],"hatchCycles":25},{"id":355,"name":"Duskull","stats":[20,40,90,30,90,25],"types":["Ghost"],"abilities":["Levitate"],"gender":127,"growth":"Fast","catchRate":190,"friendship":35,"eggGroups":["Amorphous"],"hatchCycles":25},{"id":356,"name":"Dusclops","stats":[40,70,130,60,130,25],"types":["Ghost"],"abilities":["Pressure"],"gender":127,"growth":"Fast","catchRate":90,"friendship":35,"eggGroups":["Amorphous"],"hatchCycles":25},{"id":357,"name":"Tropius","stats":[99,68,83,72,87,51],"types":["Grass","Flying"],"abilities":["Chlorophyll","SolarPower"],"gender":127,"growth":"Slow","catchRate":200,"friendship":70,"eggGroups":["Monster","Grass"],"hatchCycles":25},{"id":358,"name":"Chimecho","stats":[65,50,70,95,80,65],"types":["Psychic"],"abilities":["Levitate"],"gender":127,"growth":"Fast","catchRate":45,"friendship":70,"eggGroups":["Amorphous"],"hatchCycles":25},{"id":359,"name":"Absol","stats":[65,130,60,75,60,75],"types":["Dark"],"abilities":["Pressure","SuperLuck"],"gender":127,"growth":"MediumSlow","catchRate":30,"friendship":35,"eggGroups":["Field"],"hatchCycles":25},{"id":360,"name":"Wynaut","stats":[95,23,48,23,48,23],"types":["Psychic"],"abilities":["ShadowTag"],"gender":127,"growth":"MediumFast","catchRate":125,"friendship":70,"eggGroups":["Undiscovered"],"hatchCycles":20},{"id":361,"name":"Snorunt","stats":[50,50,50,50,50,50],"types":["Ice"],"abilities":["InnerFocus","IceBody"],"gender":127,"growth":"MediumFast","catchRate":190,"friendship":70,"eggGroups":["Fairy","Mineral"],"hatchCycles":20},{"id":362,"name":"Glalie","stats":[80,80,80,80,80,80],"types":["Ice"],"abilities":["InnerFocus","IceBody"],"gender":127,"growth":"MediumFast","catchRate":75,"friendship":70,"eggGroups":["Fairy","Mineral"],"hatchCycles":20},{"id":363,"name":"Spheal","stats":[70,40,50,55,50,25],"types":["Ice","Water"],"abilities":["ThickFat","IceBody"],"gender":127,"growth":"MediumSlow","catchRate":255,"friendship":70,"eggGroups":["Water1","Field"],"hatchCycles":20},{"id":364,"name":"Sealeo","stats":[90,60,70,75,70,45],"types":["Ice","Water"],"abilities":["ThickFat","IceBody"],"gender":127,"growth":"MediumSlow","catchRate":120,"friendship":70,"eggGroups":["Water1","Field"],"hatchCycles":20},{"id":365,"name":"Walrein","stats":[110,80,90,95,90,65],"types":["Ice","Water"],"abilities":["ThickFat","IceBody"],"gender":127,"growth":"MediumSlow","catchRate":45,"friendship":70,"eggGroups":["Water1","Field"],"hatchCycles":20},{"id":366,"name":"Clamperl","stats":[35,64,85,74,55,32],"types":["Water"],"abilities":["ShellArmor"],"gender":127,"growth":"Erratic","catchRate":255,"friendship":70,"eggGroups":["Water1"],"hatchCycles":20},{"id":367,"name":"Huntail","stats":[55,104,105,94,75,52],"types":["Water"],"abilities":["SwiftSwim"],"gender":127,"growth":"Erratic","catchRate":60,"friendship":70,"eggGroups":["Water1"],"hatchCycles":20},{"id":368,"name":"Gorebyss","stats":[55,84,105,114,75,52],"types":["Water"],"abilities":["SwiftSwim"],"gender":127,"growth":"Erratic","catchRate":60,"friendship":70,"eggGroups":["Water1"],"hatchCycles":20},{"id":369,"name":"Relicanth","stats":[100,90,130,45,65,55],"types":["Water","Rock"],"abilities":["SwiftSwim","RockHead"],"gender":31,"growth":"Slow","catchRate":25,"friendship":70,"eggGroups":["Water1","Water2"],"hatchCycles":40},{"id":370,"name":"Luvdisc","stats":[43,30,55,40,65,97],"types":["Water"],"abilities":["SwiftSwim"],"gender":191,"growth":"Fast","catchRate":225,"friendship":70,"eggGroups":["Water2"],"hatchCycles":20},{"id":371,"name":"Bagon","stats":[45,75,60,40,30,50],"types":["Dragon"],"abilities":["RockHead"],"gender":127,"growth":"Slow","catchRate":45,"friendship":35,"eggGroups":["Dragon"],"hatchCycles":40},{"id":372,"name":"Shelgon","stats":[65,95,100,60,50,50],"types":["Dragon"],"abilities":["RockHead"],"gender":127,"growth":"Slow","catchRate":45,"friendship":35,"eggGroups":["Dragon"],"hatchCycles":40},{"id":373,"name":"Salamence","stats":[95,135,80,110,80,100],"types":["Dragon","Flying"],"abilities":["Intimidate"],"gender":127,"growth":"Slow","catchRate":45,"friendship":35,"eggGroups":["Dragon"],"hatchCycles":40},{"id":374,"name":"Beldum","stats":[40,55,80,35,60,30],"types":["Steel","Psychic"],"abilities":["ClearBody"],"gender":255,"growth":"Slow","catchRate":3,"friendship":35,"eggGroups":["Mineral"],"hatchCycles":40},{"id":375,"name":"Metang","stats":[60,75,100,55,80,50],"types":["Steel","Psychic"],"abilities":["ClearBody"],"gender":255,"growth":"Slow","catchRate":3,"friendship":35,"eggGroups":["Mineral"],"hatchCycles":40},{"id":376,"name":"Metagross","stats":[80,135,130,95,90,70],"types":["Steel","Psychic"],"abilities":["ClearBody"],"gender":255,"growth":"Slow","catchRate":3,"friendship":35,"eggGroups":["Mineral"],"hatchCycles":40},{"id":377,"name":"Regirock","stats":[80,100,200,50,100,50],"types":["Rock"],"abilities":["ClearBody"],"gender":255,"growth":"Slow","catchRate":3,"friendship":35,"eggGroups":["Undiscovered"],"hatchCycles":80},{"id":378,"name":"Regice","stats":[80,50,100,100,200,50],"types":["Ice"],"abilities":["ClearBody"],"gender":255,"growth":"Slow","catchRate":3,"friendship":35,"eggGroups":["Undiscovered"],"hatchCycles":80},{"id":379,"name":"Registeel","stats":[80,75,150,75,150,50],"types":["Steel"],"abilities":["ClearBody"],"gender":255,"growth":"Slow","catchRate":3,"friendship":35,"eggGroups":["Undiscovered"],"hatchCycles":80},{"id":380,"name":"Latias","stats":[80,80,90,110,130,110],"types":["Dragon","Psychic"],"abilities":["Levitate"],"gender":254,"growth":"Slow","catchRate":3,"friendship":90,"eggGroups":["Undiscovered"],"hatchCycles":120},{"id":381,"name":"Latios","stats":[80,90,80,130,110,110],"types":["Dragon","Psychic"],"abilities":["Levitate"],"gender":0,"growth":"Slow","catchRate":3,"friendship":90,"eggGroups":["Undiscovered"],"hatchCycles":120},{"id":382,"name":"Kyogre","stats":[100,100,90,150,140,90],"types":["Water"],"abilities":["Drizzle"],"gender":255,"growth":"Slow","catchRate":5,"friendship":0,"eggGroups":["Undiscovered"],"hatchCycles":120},{"id":383,"name":"Groudon","stats":[100,150,140,100,90,90],"types":["Ground"],"abilities":["Drought"],"gender":255,"growth":"Slow","catchRate":5,"friendship":0,"eggGroups":["Undiscovered"],"hatchCycles":120},{"id":384,"name":"Rayquaza","stats":[105,150,90,150,90,95],"types":["Dragon","Flying"],"abilities":["AirLock"],"gender":255,"growth":"Slow","catchRate":3,"friendship":0,"eggGroups":["Undiscovered"],"hatchCycles":120},{"id":385,"name":"Jirachi","stats":[100,100,100,100,100,100],"types":["Steel","Psychic"],"abilities":["SereneGrace"],"gender":255,"growth":"Slow","catchRate":3,"friendship":100,"eggGroups":["Undiscovered"],"hatchCycles":120},{"id":386,"name":"Deoxys","stats":[50,150,50,150,50,150],"types":["Psychic"],"abilities":["Pressure"],"gender":255,"growth":"Slow","catchRate":3,"friendship":0,"eggGroups":["Undiscovered"],"hatchCycles":120},{"id":387,"name":"Turtwig","stats":[55,68,64,45,55,31],"types":["Grass"],"abilities":["Overgrow"],"gender":31,"growth":"MediumSlow","catchRate":45,"friendship":70,"eggGroups":["Monster","Grass"],"hatchCycles":20},{"id":388,"name":"Grotle","stats":[75,89,85,55,65,36],"types":["Grass"],"abilities":["Overgrow"],"gender":31,"growth":"MediumSlow","catchRate":45,"friendship":70,"eggGroups":["Monster","Grass"],"hatchCycles":20},{"id":389,"name":"Torterra","stats":[95,109,105,75,85,56],"types":["Grass","Ground"],"abilities":["Overgrow"],"gender":31,"growth":"MediumSlow","catchRate":45,"friendship":70,"eggGroups":["Monster","Grass"],"hatchCycles":20},{"id":390,"name":"Chimchar","stats":[44,58,44,58,44,61],"types":["Fire"],"abilities":["Blaze"],"gender":31,"growth":"MediumSlow","catchRate":45,"friendship":70,"eggGroups":["Field","HumanLike"],"hatchCycles":20},{"id":391,"name":"Monferno","stats":[64,78,52,78,52,81],"types":["Fire","Fighting"],"abilities":["Blaze"],"gender":31,"growth":"MediumSlow","catchRate":45,"friendship":70,"eggGroups":["Field","HumanLike"],"hatchCycles":20},{"id":392,"name":"Infernape","stats":[76,104,71,104,71,108],"types":["Fire","Fighting"],"abilities":["Blaze"],"gender":31,"growth":"MediumSlow","catchRate":45,"friendship":70,"eggGroups":["Field","HumanLike"],"hatchCycles":20},{"id":393,"name":"Piplup","stats":[53,51,53,61,56,40],"types":["Water"],"abilities":["Torrent"],"gender":31,"growth":"MediumSlow","catchRate":45,"friendship":70,"eggGroups":["Water1","Field"],"hatchCycles":20},{"id":394,"name":"Prinplup","stats":[64,66,68,81,76,50],"types":["Water"],"abilities":["Torrent"],"gender":31,"growth":"MediumSlow","catchRate":45,"friendship":70,"eggGroups":["Water1","Field"],"hatchCycles":20},{"id":395,"name":"Empoleon","stats":[84,86,88,111,101,60],"types":["Water","Steel"],"abilities":["Torrent"],"gender":31,"growth":"MediumSlow","catchRate":45,"friendship":70,"eggGroups":["Water1","Field"],"hatchCycles":20},{"id":396,"name":"Starly","stats":[40,55,30,30,30,60],"types":["Normal","Flying"],"abilities":["KeenEye"],"gender":127,"growth":"MediumSlow","catchRate":255,"friendship":70,"eggGroups":["Flying"],"hatchCycles":15},{"id":397,"name":"Staravia","stats":[55,75,50,40,40,80],"types":["Normal","Flying"],"abilities":["Intimidate"],"gender":127,"growth":"MediumSlow","catchRate":120,"friendship":70,"eggGroups":["Flying"],"hatchCycles":15},{"id":398,"name":"Staraptor","stats":[85,120,70,50,50,100],"types":["Normal","Flying"],"abilities":["Intimidate"],"gender":127,"growth":"MediumSlow","catchRate":45,"friendship":70,"eggGroups":["Flying"],"hatchCycles":15},{"id":399,"name":"Bidoof","stats":[59,45,40,35,40,31],"types":["Normal"],"abilities":["Simple","Unaware"],"gender":127,"growth":"MediumFast","catchRate":255,"friendship":70,"eggGroups":["Water1","Field"],"hatchCycles":15},{"id":400,"name":"Bibarel","stats":[79,85,60,55,60,71],"types":["Normal","Water"],"abilities":["Simple","Unaware"],"gender":127,"growth":"MediumFast","catchRate":127,"friendship":70,"eggGroups":["Water1","Field"],"hatchCycles":15},{"id":401,"name":"Kricketot","stats":[37,25,41,25,41,25],"types":["Bug"],"abilities":["ShedSkin"],"gender":127,"growth":"MediumSlow","catchRate":255,"friendship":70,"eggGroups":["Bug"],"hatchCycles":15},{"id":402,"name":"Kricketune","stats":[77,85,51,55,51,65],"types":["Bug"],"abilities":["Swarm"],"gender":127,"growth":"MediumSlow","catchRate":45,"friendship":70,"eggGroups":["Bug"],"hatchCycles":15},{"id":403,"name":"Shinx","stats":[45,65,34,40,34,45],"types":["Electric"],"abilities":["Rivalry","Intimidate"],"gender":127,"growth":"MediumSlow","catchRate":235,"friendship":70,"eggGroups":["Field"],"hatchCycles":20},{"id":404,"name":"Luxio","stats":[60,85,49,60,49,60],"types":["Electric"],"abilities":["Rivalry","Intimidate"],"gender":127,"growth":"MediumSlow","catchRate":120,"friendship":100,"eggGroups":["Field"],"hatchCycles":20},{"id":405,"name":"Luxray","stats":[80,120,79,95,79,70],"types":["Electric"],"abilities":["Rivalry","Intimidate"],"gender":127,"growth":"MediumSlow","catchRate":45,"friendship":70,"eggGroups":["Field"],"hatchCycles":20},{"id":406,"name":"Budew","stats":[40,30,35,50,70,55],"types":["Grass","Poison"],"abilities":["NaturalCure","PoisonPoint"],"gender":127,"growth":"MediumSlow","catchRate":255,"friendship":70,"eggGroups":["Undiscovered"],"hatchCycles":20},{"id":407,"name":"Roserade","stats":[60,70,55,125,105,90],"types":["Grass","Poison"],"abilities":["NaturalCure","PoisonPoint"],"gender":127,"growth":"MediumSlow","catchRate":75,"friendship":70,"eggGroups":["Fairy","Grass"],"hatchCycles":20},{"id":408,"name":"Cranidos","stats":[67,125,40,30,30,58],"types":["Rock"],"abilities":["MoldBreaker"],"gender":31,"growth":"Erratic","catchRate":45,"friendship":70,"eggGroups":["Monster"],"hatchCycles":30},{"id":409,"name":"Rampardos","stats":[97,165,60,65,50,58],"types":["Rock"],"abilities":["MoldBreaker"],"gender":31,"growth":"Erratic","catchRate":45,"friendship":70,"eggGroups":["Monster"],"hatchCycles":30},{"id":410,"name":"Shieldon","stats":[30,42,118,42,88,30],"types":["Rock","Steel"],"abilities":["Sturdy"],"gender":31,"growth":"Erratic","catchRate":45,"friendship":70,"eggGroups":["Monster"],"hatchCycles":30},{"id":411,"name":"Bastiodon","stats":[60,52,168,47,138,30],"types":["Rock","Steel"],"abilities":["Sturdy"],"gender":31,"growth":"Erratic","catchRate":45,"friendship":70,"eggGroups":["Monster"],"hatchCycles":30},{"id":412,"name":"Burmy","stats":[40,29,45,29,45,36],"types":["Bug"],"abilities":["ShedSkin"],"gender":127,"growth":"MediumFast","catchRate":120,"friendship":70,"eggGroups":["Bug"],"hatchCycles":15},{"id":413,"name":"Wormadam","stats":[60,59,85,79,105,36],"types":["Bug","Grass"],"abilities":["Anticipation"],"gender":254,"growth":"MediumFast","catchRate":45,"friendship":70,"eggGroups":["Bug"],"hatchCycles":15},{"id":414,"name":"Mothim","stats":[70,94,50,94,50,66],"types":["Bug","Flying"],"abilities":["Swarm"],"gender":0,"growth":"MediumFast","catchRate":45,"friendship":70,"eggGroups":["Bug"],"hatchCycles":15},{"id":415,"name":"Combee","stats":[30,30,42,30,42,70],"types":["Bug","Flying"],"abilities":["HoneyGather"],"gender":31,"growth":"MediumSlow","catchRate":120,"friendship":70,"eggGroups":["Bug"],"hatchCycles":15},{"id":416,"name":"Vespiquen","stats":[70,80,102,80,102,40],"types":["Bug","Flying"],"abilities":["Pressure"],"gender":254,"growth":"MediumSlow","catchRate":45,"friendship":70,"eggGroups":["Bug"],"hatchCycles":15},{"id":417,"name":"Pachirisu","stats":[60,45,70,45,90,95],"types":["Electric"],"abilities":["RunAway","Pickup"],"gender":127,"growth":"MediumFast","catchRate":200,"friendship":100,"eggGroups":["Field","Fairy"],"hatchCycles":10},{"id":418,"name":"Buizel","stats":[55,65,35,60,30,85],"types":["Water"],"abilities":["SwiftSwim"],"gender":127,"growth":"MediumFast","catchRate":190,"friendship":70,"eggGroups":["Water1","Field"],"hatchCycles":20},{"id":419,"name":"Floatzel","stats":[85,105,55,85,50,115],"types":["Water"],"abilities":["SwiftSwim"],"gender":127,"growth":"MediumFast","catchRate":75,"friendship":70,"eggGroups":["Water1","Field"],"hatchCycles":20},{"id":420,"name":"Cherubi","stats":[45,35,45,62,53,35],"types":["Grass"],"abilities":["Chlorophyll"],"gender":127,"growth":"MediumFast","catchRate":190,"friendship":70,"eggGroups":["Fairy","Grass"],"hatchCycles":20},{"id":421,"name":"Cherrim","stats":[70,60,70,87,78,85],"types":["Grass"],"abilities":["FlowerGift"],"gender":127,"growth":"MediumFast","catchRate":75,"friendship":70,"eggGroups":["Fairy","Grass"],"hatchCycles":20},{"id":422,"name":"Shellos","stats":[76,48,48,57,62,34],"types":["Water"],"abilities":["StickyHold","StormDrain"],"gender":127,"growth":"MediumFast","catchRate":190,"friendship":70,"eggGroups":["Water1","Amorphous"],"hatchCycles":20},{"id":423,"name":"Gastrodon","stats":[111,83,68,92,82,39],"types":["Water","Ground"],"abilities":["StickyHold","StormDrain"],"gender":127,"growth":"MediumFast","catchRate":75,"friendship":70,"eggGroups":["Water1","Amorphous"],"hatchCycles":20},{"id":424,"name":"Ambipom","stats":[75,100,66,60,66,115],"types":["Normal"],"abilities":["Technician","Pickup"],"gender":127,"growth":"Fast","catchRate":45,"friendship":100,"eggGroups":["Field"],"hatchCycles":20},{"id":425,"name":"Drifloon","stats":[90,50,34,60,44,70],"types":["Ghost","Flying"],"abilities":["Aftermath","Unburden"],"gender":127,"growth":"Fluctuating","catchRate":125,"friendship":70,"eggGroups":["Amorphous"],"hatchCycles":30},{"id":426,"name":"Drifblim","stats":[150,80,44,90,54,80],"types":["Ghost","Flying"],"abilities":["Aftermath","Unburden"],"gender":127,"growth":"Fluctuating","catchRate":60,"friendship":70,"eggGroups":["Amorphous"],"hatchCycles":30},{"id":427,"name":"Buneary","stats":[55,66,44,44,56,85],"types":["Normal"],"abilities":["RunAway","Klutz"],"gender":127,"growth":"MediumFast","catchRate":190,"friendship":0,"eggGroups":["Field","HumanLike"],"hatchCycles":20},{"id":428,"name":"Lopunny","stats":[65,76,84,54,96,105],"types":["Normal"],"abilities":["CuteCharm","Klutz"],"gender":127,"growth":"MediumFast","catchRate":60,"friendship":140,"eggGroups":["Field","HumanLike"],"hatchCycles":20},{"id":429,"name":"Mismagius","stats":[60,60,60,105,105,105],"types":["Ghost"],"abilities":["Levitate"],"gender":127,"growth":"Fast","catchRate":45,"friendship":35,"eggGroups":["Amorphous"],"hatchCycles":25},{"id":430,"name":"Honchkrow","stats":[100,125,52,105,52,71],"types":["Dark","Flying"],"abilities":["Insomnia","SuperLuck"],"gender":127,"growth":"MediumSlow","catchRate":30,"friendship":35,"eggGroups":["Flying"],"hatchCycles":20},{"id":431,"name":"Glameow","stats":[49,55,42,42,37,85],"types":["Normal"],"abilities":["Limber","OwnTempo"],"gender":191,"growth":"Fast","catchRate":190,"friendship":70,"eggGroups":["Field"],"hatchCycles":20},{"id":432,"name":"Purugly","stats":[71,82,64,64,59,112],"types":["Normal"],"abilities":["ThickFat","OwnTempo"],"gender":191,"growth":"Fast","catchRate":75,"friendship":70,"eggGroups":["Field"],"hatchCycles":20},{"id":433,"name":"Chingling","stats":[45,30,50,65,50,45],"types":["Psychic"],"abilities":["Levitate"],"gender":127,"growth":"Fast","catchRate":120,"friendship":70,"eggGroups":["Undiscovered"],"hatchCycles":25},{"id":434,"name":"Stunky","stats":[63,63,47,41,41,74],"types":["Poison","Dark"],"abilities":["Stench","Aftermath"],"gender":127,"growth":"MediumFast","catchRate":225,"friendship":70,"eggGroups":["Field"],"hatchCycles":20},{"id":435,"name":"Skuntank","stats":[103,93,67,71,61,84],"types":["Poison","Dark"],"abilities":["Stench","Aftermath"],"gender":127,"growth":"MediumFast","catchRate":60,"friendship":70,"eggGroups":["Field"],"hatchCycles":20},{"id":436,"name":"Bronzor","stats":[57,24,86,24,86,23],"types":["Steel","Psychic"],"abilities":["Levitate","Heatproof"],"gender":255,"growth":"MediumFast","catchRate":255,"friendship":70,"eggGroups":["Mineral"],"hatchCycles":20},{"id":437,"name":"Bronzong","stats":[67,89,116,79,116,33],"types":["Steel","Psychic"],"abilities":["Levitate","Heatproof"],"gender":255,"growth":"MediumFast","catchRate":90,"friendship":70,"eggGroups":["Mineral"],"hatchCycles":20},{"id":438,"name":"Bonsly","stats":[50,80,95,10,45,10],"types":["Rock"],"abilities":["Sturdy","RockHead"],"gender":127,"growth":"MediumFast","catchRate":255,"friendship":70,"eggGroups":["Undiscovered"],"hatchCycles":20},{"id":439,"name":"MimeJr","stats":[20,25,45,70,90,60],"types":["Psychic"],"abilities":["Soundproof","Filter"],"gender":127,"growth":"MediumFast","catchRate":145,"friendship":70,"eggGroups":["Undiscovered"],"hatchCycles":25},{"id":440,"name":"Happiny","stats":[100,5,5,15,65,30],"types":["Normal"],"abilities":["NaturalCure","SereneGrace"],"gender":254,"growth":"Fast","catchRate":130,"friendship":140,"eggGroups":["Undiscovered"],"hatchCycles":40},{"id":441,"name":"Chatot","stats":[76,65,45,92,42,91],"types":["Normal","Flying"],"abilities":["KeenEye","TangledFeet"],"gender":127,"growth":"MediumSlow","catchRate":30,"friendship":35,"eggGroups":["Flying"],"hatchCycles":20},{"id":442,"name":"Spiritomb","stats":[50,92,108,92,108,35],"types":["Ghost","Dark"],"abilities":["Pressure"],"gender":127,"growth":"MediumFast","catchRate":100,"friendship":70,"eggGroups":["Amorphous"],"hatchCycles":30},{"id":443,"name":"Gible","stats":[58,70,45,40,45,42],"types":["Dragon","Ground"],"abilities":["SandVeil"],"gender":127,"growth":"Slow","catchRate":45,"friendship":70,"eggGroups":["Monster","Dragon"],"hatchCycles":40},{"id":444,"name":"Gabite","stats":[68,90,65,50,55,82],"types":["Dragon","Ground"],"abilities":["SandVeil"],"gender":127,"growth":"Slow","catchRate":45,"friendship":70,"eggGroups":["Monster","Dragon"],"hatchCycles":40},{"id":445,"name":"Garchomp","stats":[108,130,95,80,85,102],"types":["Dragon","Ground"],"abilities":["SandVeil"],"gender":127,"growth":"Slow","catchRate":45,"friendship":70,"eggGroups":["Monster","Dragon"],"hatchCycles":40},{"id":446,"name":"Munchlax","stats":[135,85,40,40,85,5],"types":["Normal"],"abilities":["Pickup","ThickFat"],"gender":31,"growth":"Slow","catchRate":50,"friendship":70,"eggGroups":["Undiscovered"],"hatchCycles":40},{"id":447,"name":"Riolu","stats":[40,70,40,35,40,60],"types":["Fighting"],"abilities":["Steadfast","InnerFocus"],"gender":31,"growth":"MediumSlow","catchRate":75,"friendship":70,"eggGroups":["Undiscovered"],"hatchCycles":25},{"id":448,"name":"Lucario","stats":[70,110,70,115,70,90],"types":["Fighting","Steel"],"abilities":["Steadfast","InnerFocus"],"gender":31,"growth":"MediumSlow","catchRate":45,"friendship":70,"eggGroups":["Field","HumanLike"],"hatchCycles":25},{"id":449,"name":"Hippopotas","stats":[68,72,78,38,42,32],"types":["Ground"],"abilities":["SandStream"],"gender":127,"growth":"Slow","catchRate":140,"friendship":70,"eggGroups":["Field"],"hatchCycles":30},{"id":450,"name":"Hippowdon","stats":[108,112,118,68,72,47],"types":["Ground"],"abilities":["SandStream"],"gender":127,"growth":"Slow","catchRate":60,"friendship":70,"eggGroups":["Field"],"hatchCycles":30},{"id":451,"name":"Skorupi","stats":[40,50,90,30,55,65],"types":["Poison","Bug"],"abilities":["BattleArmor","Sniper"],"gender":127,"growth":"Slow","catchRate":120,"friendship":70,"eggGroups":["Bug","Water3"],"hatchCycles":20},{"id":452,"name":"Drapion","stats":[70,90,110,60,75,95],"types":["Poison","Dark"],"abilities":["BattleArmor","Sniper"],"gender":127,"growth":"Slow","catchRate":45,"friendship":70,"eggGroups":["Bug","Water3"],"hatchCycles":20},{"id":453,"name":"Croagunk","stats":[48,61,40,61,40,50],"types":["Poison","Fighting"],"abilities":["Anticipation","DrySkin"],"gender":127,"growth":"MediumFast","catchRate":140,"friendship":100,"eggGroups":["HumanLike"],"hatchCycles":10},{"id":454,"name":"Toxicroak","stats":[83,106,65,86,65,85],"types":["Poison","Fighting"],"abilities":["Anticipation","DrySkin"],"gender":127,"growth":"MediumFast","catchRate":75,"friendship":70,"eggGroups":["HumanLike"],"hatchCycles":10},{"id":455,"name":"Carnivine","stats":[74,100,72,90,72,46],"types":["Grass"],"abilities":["Levitate"],"gender":127,"growth":"Slow","catchRate":200,"friendship":70,"eggGroups":["Grass"],"hatchCycles":25},{"id":456,"name":"Finneon","stats":[49,49,56,49,61,66],"types":["Water"],"abilities":["SwiftSwim","StormDrain"],"gender":127,"growth":"Erratic","catchRate":190,"friendship":70,"eggGroups":["Water2"],"hatchCycles":20},{"id":457,"name":"Lumineon","stats":[69,69,76,69,86,91],"types":["Water"],"abilities":["SwiftSwim","StormDrain"],"gender":127,"growth":"Erratic","catchRate":75,"friendship":70,"eggGroups":["Water2"],"hatchCycles":20},{"id":458,"name":"Mantyke","stats":[45,20,50,60,120,50],"types":["Water","Flying"],"abilities":["SwiftSwim","WaterAbsorb"],"gender":127,"growth":"Slow","catchRate":25,"friendship":70,"eggGroups":["Undiscovered"],"hatchCycles":25},{"id":459,"name":"Snover","stats":[60,62,50,62,60,40],"types":["Grass","Ice"],"abilities":["SnowWarning"],"gender":127,"growth":"Slow","catchRate":120,"friendship":70,"eggGroups":["Monster","Grass"],"hatchCycles":20},{"id":460,"name":"Abomasnow","stats":[90,92,75,92,85,60],"types":["Grass","Ice"],"abilities":["SnowWarning"],"gender":127,"growth":"Slow","catchRate":60,"friendship":70,"eggGroups":["Monster","Grass"],"hatchCycles":20},{"id":461,"name":"Weavile","stats":[70,120,65,45,85,125],"types":["Dark","Ice"],"abilities":["Pressure"],"gender":127,"growth":"MediumSlow","catchRate":45,"friendship":35,"eggGroups":["Field"],"hatchCycles":20},{"id":462,"name":"Magnezone","stats":[70,70,115,130,90,60],"types":["Electric","Steel"],"abilities":["MagnetPull","Sturdy"],"gender":255,"growth":"MediumFast","catchRate":30,"friendship":70,"eggGroups":["Mineral"],"hatchCycles":20},{"id":463,"name":"Lickilicky","stats":[110,85,95,80,95,50],"types":["Normal"],"abilities":["OwnTempo","Oblivious"],"gender":127,"growth":"MediumFast","catchRate":30,"friendship":70,"eggGroups":["Monster"],"hatchCycles":20},{"id":464,"name":"Rhyperior","stats":[115,140,130,55,55,40],"types":["Ground","Rock"],"abilities":["LightningRod","SolidRock"],"gender":127,"growth":"Slow","catchRate":30,"friendship":70,"eggGroups":["Monster","Field"],"hatchCycles":20},{"id":465,"name":"Tangrowth","stats":[100,100,125,110,50,50],"types":["Grass"],"abilities":["Chlorophyll","LeafGuard"],"gender":127,"growth":"MediumFast","catchRate":30,"friendship":70,"eggGroups":["Grass"],"hatchCycles":20},{"id":466,"name":"Electivire","stats":[75,123,67,95,85,95],"types":["Electric"],"abilities":["MotorDrive"],"gender":63,"growth":"MediumFast","catchRate":30,"friendship":70,"eggGroups":["HumanLike"],"hatchCycles":25},{"id":467,"name":"Magmortar","stats":[75,95,67,125,95,83],"types":["Fire"],"abilities":["FlameBody"],"gender":63,"growth":"MediumFast","catchRate":30,"friendship":70,"eggGroups":["HumanLike"],"hatchCycles":25},{"id":468,"name":"Togekiss","stats":[85,50,95,120,115,80],"types":["Normal","Flying"],"abilities":["Hustle","SereneGrace"],"gender":31,"growth":"Fast","catchRate":30,"friendship":70,"eggGroups":["Flying","Fairy"],"hatchCycles":10},{"id":469,"name":"Yanmega","stats":[86,76,86,116,56,95],"types":["Bug","Flying"],"abilities":["SpeedBoost","TintedLens"],"gender":127,"growth":"MediumFast","catchRate":30,"friendship":70,"eggGroups":["Bug"],"hatchCycles":20},{"id":470,"name":"Leafeon","stats":[65,110,130,60,65,95],"types":["Grass"],"abilities":["LeafGuard"],"gender":31,"growth":"MediumFast","catchRate":45,"friendship":35,"eggGroups":["Field"],"hatchCycles":35},{"id":471,"name":"Glaceon","stats":[65,60,110,130,95,65],"types":["Ice"],"abilities":["SnowCloak"],"gender":31,"growth":"MediumFast","catchRate":45,"friendship":35,"eggGroups":["Field"],"hatchCycles":35},{"id":472,"name":"Gliscor","stats":[75,95,125,45,75,95],"types":["Ground","Flying"],"abilities":["HyperCutter","SandVeil"],"gender":127,"growth":"MediumSlow","catchRate":30,"friendship":70,"eggGroups":["Bug"],"hatchCycles":20},{"id":473,"name":"Mamoswine","stats":[110,130,80,70,60,80],"types":["Ice","Ground"],"abilities":["Oblivious","SnowCloak"],"gender":127,"growth":"Slow","catchRate":50,"friendship":70,"eggGroups":["Field"],"hatchCycles":20},{"id":474,"name":"Porygonz","stats":[85,80,70,135,75,90],"types":["Normal"],"abilities":["Adaptability","Download"],"gender":255,"growth":"MediumFast","catchRate":30,"friendship":70,"eggGroups":["Mineral"],"hatchCycles":20},{"id":475,"name":"Gallade","stats":[68,125,65,65,115,80],"types":["Psychic","Fighting"],"abilities":["Steadfast"],"gender":0,"growth":"Slow","catchRate":45,"friendship":35,"eggGroups":["Amorphous"],"hatchCycles":20},{"id":476,"name":"Probopass","stats":[60,55,145,75,150,40],"types":["Rock","Steel"],"abilities":["Sturdy","MagnetPull"],"gender":127,"growth":"MediumFast","catchRate":60,"friendship":70,"eggGroups":["Mineral"],"hatchCycles":20},{"id":477,"name":"Dusknoir","stats":[45,100,135,65,135,45],"types":["Ghost"],"abilities":["Pressure"],"gender":127,"growth":"Fast","catchRate":45,"friendship":35,"eggGroups":["Amorphous"],"hatchCycles":25},{"id":478,"name":"Froslass","stats":[70,80,70,80,70,110],"types":["Ice","Ghost"],"abilities":["SnowCloak"],"gender":254,"growth":"MediumFast","catchRate":75,"friendship":70,"eggGroups":["Fairy","Mineral"],"hatchCycles":20},{"id":479,"name":"Rotom","stats":[50,50,77,95,77,91],"types":["Electric","Ghost"],"abilities":["Levitate"],"gender":255,"growth":"MediumFast","catchRate":45,"friendship":70,"eggGroups":["Amorphous"],"hatchCycles":20},{"id":480,"name":"Uxie","stats":[75,75,130,75,130,95],"types":["Psychic"],"abilities":["Levitate"],"gender":255,"growth":"Slow","catchRate":3,"friendship":140,"eggGroups":["Undiscovered"],"hatchCycles":80},{"id":481,"name":"Mesprit","stats":[80,105,105,105,105,80],"types":["Psychic"],"abilities":["Levitate"],"gender":255,"growth":"Slow","catchRate":3,"friendship":140,"eggGroups":["Undiscovered"],"hatchCycles":80},{"id":482,"name":"Azelf","stats":[75,125,70,125,70,115],"types":["Psychic"],"abilities":["Levitate"],"gender":255,"growth":"Slow","catchRate":3,"friendship":140,"eggGroups":["Undiscovered"],"hatchCycles":80},{"id":483,"name":"Dialga","stats":[100,120,120,150,100,90],"types":["Steel","Dragon"],"abilities":["Pressure"],"gender":255,"growth":"Slow","catchRate":30,"friendship":0,"eggGroups":["Undiscovered"],"hatchCycles":120},{"id":484,"name":"Palkia","stats":[90,120,100,150,120,100],"types":["Water","Dragon"],"abilities":["Pressure"],"gender":255,"growth":"Slow","catchRate":30,"friendship":0,"eggGroups":["Undiscovered"],"hatchCycles":120},{"id":485,"name":"Heatran","stats":[91,90,106,130,106,77],"types":["Fire","Steel"],"abilities":["FlashFire"],"gender":127,"growth":"Slow","catchRate":3,"friendship":100,"eggGroups":["Undiscovered"],"hatchCycles":10},{"id":486,"name":"Regigigas","stats":[110,160,110,80,110,100],"types":["Normal"],"abilities":["SlowStart"],"gender":255,"growth":"Slow","catchRate":3,"friendship":0,"eggGroups":["Undiscovered"],"hatchCycles":120},{"id":487,"name":"Giratina","stats":[150,100,120,100,120,90],"types":["Ghost","Dragon"],"abilities":["Pressure"],"gender":255,"growth":"Slow","catchRate":3,"friendship":0,"eggGroups":["Undiscovered"],"hatchCycles":120},{"id":488,"name":"Cresselia","stats":[120,70,120,75,130,85],"types":["Psychic"],"abilities":["Levitate"],"gender":255,"growth":"Slow","catchRate":3,"friendship":100,"eggGroups":["Undiscovered"],"hatchCycles":120},{"id":489,"name":"Phione","stats":[80,80,80,80,80,80],"types":["Water"],"abilities":["Hydration"],"gender":255,"growth":"Slow","catchRate":30,"friendship":70,"eggGroups":["Water1","Fairy"],"hatchCycles":40},{"id":490,"name":"Manaphy","stats":[100,100,100,100,100,100],"types":["Water"],"abilities":["Hydration"],"gender":255,"growth":"Slow","catchRate":3,"friendship":70,"eggGroups":["Water1","Fairy"],"hatchCycles":10},{"id":491,"name":"Darkrai","stats":[70,90,90,135,90,125],"types":["Dark"],"abilities":["BadDreams"],"gender":255,"growth":"Slow","catchRate":3,"friendship":0,"eggGroups":["Undiscovered"],"hatchCycles":120},{"id":492,"name":"Shaymin","stats":[100,100,100,100,100,100],"types":["Grass"],"abilities":["NaturalCure"],"gender":255,"growth":"MediumSlow","catchRate":45,"friendship":100,"eggGroups":["Undiscovered"],"hatchCycles":120},{"id":493,"name":"Arceus","stats":[120,120,120,120,120,120],"types":["Normal"],"abilities":["Multitype"],"gender":255,"growth":"Slow","catchRate":3,"friendship":0,"eggGroups":["Undiscovered"],"hatchCycles":120}],"forms":[{"name":"Deoxys","stats":[50,180,20,180,20,150],"types":["Psychic"],"abilities":["Pressure"],"gender":255,"growth":"Slow","catchRate":3,"friendship":0,"eggGroups":["Undiscovered"],"hatchCycles":120,"form":1},{"name":"Deoxys","stats":[50,70,160,70,160,90],"types":["Psychic"],"abilities":["Pressure"],"gender":255,"growth":"Slow","catchRate":3,"friendship":0,"eggGroups":["Undiscovered"],"hatchCycles":120,"form":2},{"name":"Deoxys","stats":[50,95,90,95,90,180],"types":["Psychic"],"abilities":["Pressure"],"gender":255,"growth":"Slow","catchRate":3,"friendship":0,"eggGroups":["Undiscovered"],"hatchCycles":120,"form":3},{"name":"Wormadam","stats":[60,79,105,59,85,36],"types":["Bug","Ground"],"abilities":["Anticipation"],"gender":254,"growth":"MediumFast","catchRate":45,"friendship":70,"eggGroups":["Bug"],"hatchCycles":15,"form":1},{"name":"Wormadam","stats":[60,69,95,69,95,36],"types":["Bug","Steel"],"abilities":["Anticipation"],"gender":254,"growth":"MediumFast","catchRate":45,"friendship":70,"eggGroups":["Bug"],"hatchCycles":15,"form":2},{"name":"Giratina","stats":[150,120,100,120,100,90],"types":["Ghost","Dragon"],"abilities":["Levitate"],"gender":255,"growth":"Slow","catchRate":3,"friendship":0,"eggGroups":["Undiscovered"],"hatchCycles":120,"form":1},{"name":"Shaymin","stats":[100,103,75,120,75,127],"types":["Grass","Flying"],"abilities":["SereneGrace"],"gender":255,"growth":"MediumSlow","catchRate":45,"friendship":100,"eggGroups":["Undiscovered"],"hatchCycles":120,"form":1},{"name":"Rotom","stats":[50,65,107,105,107,86],"types":["Electric","Ghost"],"abilities":["Levitate"],"gender":255,"growth":"MediumFast","catchRate":45,"friendship":70,"eggGroups":["Amorphous"],"hatchCycles":20,"form":1},{"name":"Rotom","stats":[50,65,107,105,107,86],"types":["Electric","Ghost"],"abilities":["Levitate"],"gender":255,"growth":"MediumFast","catchRate":45,"friendship":70,"eggGroups":["Amorphous"],"hatchCycles":20,"form":2},{"name":"Rotom","stats":[50,65,107,105,107,86],"types":["Electric","Ghost"],"abilities":["Levitate"],"gender":255,"growth":"MediumFast","catchRate":45,"friendship":70,"eggGroups":["Amorphous"],"hatchCycles":20,"form":3},{"name":"Rotom","stats":[50,65,107,105,107,86],"types":["Electric","Ghost"],"abilities":["Levitate"],"gender":255,"growth":"MediumFast","catchRate":45,"friendship":70,"eggGroups":["Amorphous"],"hatchCycles":20,"form":4},{"name":"Rotom","stats":[50,65,107,105,107,86],"types":["Electric","Ghost"],"abilities":["Levitate"],"gender":255,"growth":"MediumFast","catchRate":45,"friendship":70,"eggGroups":["Amorphous"],"hatchCycles":20,"form":5}]};
+
+const gender = (ratio) => {
+    switch (ratio) {
+        case 0: return 'GenderRatio::MALE_ONLY';
+        case 254: return 'GenderRatio::FEMALE_ONLY';
+        case 255: return 'GenderRatio::GENDERLESS';
+        default: return `GenderRatio(${ratio})`;
+    }
+};
+
+const info = (pokemon, indent) => {
+    const [hp, attack, defense, spAttack, spDefense, speed] = pokemon.stats;
+    // like the games, repeat a single type or egg group & leave the second ability empty
+    const types = pokemon.types.length == 1 ? [pokemon.types[0], pokemon.types[0]] : pokemon.types;
+    const abilities = pokemon.abilities.length == 1 ? [pokemon.abilities[0], 'None'] : pokemon.abilities;
+    const eggGroups = pokemon.eggGroups.length == 1 ? [pokemon.eggGroups[0], pokemon.eggGroups[0]] : pokemon.eggGroups;
+    return `SpeciesInfo {
+${indent}\tbase_stats: Stats { hp: ${hp}, attack: ${attack}, defense: ${defense}, speed: ${speed}, sp_attack: ${spAttack}, sp_defense: ${spDefense} },
+${indent}\ttypes: [T::${types[0]}, T::${types[1]}],
+${indent}\tabilities: [A::${abilities[0]}, A::${abilities[1]}],
+${indent}\tgender_ratio: ${gender(pokemon.gender)},
+${indent}\tgrowth_rate: GrowthRate::${pokemon.growth},
+${indent}\tcatch_rate: ${pokemon.catchRate},
+${indent}\tbase_friendship: ${pokemon.friendship},
+${indent}\tegg_groups: [G::${eggGroups[0]}, G::${eggGroups[1]}],
+${indent}\thatch_cycles: ${pokemon.hatchCycles},
+${indent}}`;
+};
+
+let rust = `/*
+\tAUTOGENERATED
+\tThis file has been automatically generated by generators/species_info.js. The generator should be
+\tedited, not this file.
+
+\tData source: https://bulbapedia.bulbagarden.net/wiki/List_of_Pokémon_by_base_stats_(Generation_II-V)
+\tand the species pages' gen 4 data, plus https://github.com/pret/pokeplatinum for forms
+*/
+use crate::save::data::gender::GenderRatio;
+use crate::save::data::growth::GrowthRate;
+use crate::save::data::species::Species;
+use crate::save::data::species_info::{Ability as A, EggGroup as G, PokemonType as T, SpeciesInfo};
+use crate::save::format::dppt::pokemon::Stats;
+
+/// The info of each species from Bulbasaur to Arceus, by national dex number
+pub(super) const SPECIES: [SpeciesInfo; ${json.species.length}] = [`;
+for (const pokemon of json.species) {
+    rust += `\n\t// ${pokemon.id}: ${pokemon.name}\n\t${info(pokemon, '\t')},`;
+}
+rust += `
+];
+
+/// The alternate forms whose info differs from their species', by species & form
+pub(super) const FORMS: [(Species, u8, SpeciesInfo); ${json.forms.length}] = [`;
+for (const pokemon of json.forms) {
+    rust += `\n\t(Species::${pokemon.name}, ${pokemon.form}, ${info(pokemon, '\t')}),`;
+}
+rust += `
+];
+`;
+
+Bun.write('../src/save/data/species_info/table.rs', rust);
@@ -68,13 +68,19 @@ fn read_meta_file(file_path: String) -> File {
 /// ```rust,ignore
 /// metang_enum!("./enum.txt", u16, MyEnum);
 ///
-/// assert_eq!(MyEnum::from(42), MyEnum::OPTION_ONE);
-/// assert_eq!(MyEnum::OPTION_TWO.into(), 43);
+/// assert_eq!(MyEnum::try_from(42), Ok(MyEnum::OPTION_ONE));
+/// assert_eq!(u16::from(MyEnum::OPTION_TWO), 43);
 /// 
 /// // OPTION_THREE is the canonical option for 44
-/// assert_eq!(MyEnum::from(44), MyEnum::OPTION_THREE);
+/// assert_eq!(MyEnum::try_from(44), Ok(MyEnum::OPTION_THREE));
+///
+/// // values without an option are an error
+/// assert!(MyEnum::try_from(45).is_err());
 /// ```
 ///
+/// Converting from the representation gives a `pokerus::save::error::UnknownValue` for values without
+/// an option, so the `pokerus` crate must be available.
+///
 /// [metang]: https://github.com/lhearachel/metang
 #[proc_macro]
 pub fn metang_enum(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
@@ -116,7 +122,7 @@ pub fn metang_enum(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
         variants.push(quote::quote! { #name = #literal });
         if !canonical.contains(&value) {
             canonical.push(value);
-            matches.push(quote::quote! { #literal => #enum_name::#name, });
+            matches.push(quote::quote! { #literal => Ok(#enum_name::#name), });
         }
     }
 
@@ -127,18 +133,20 @@ pub fn metang_enum(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
             #(#variants),*
         }
 
-        impl From<#repr_type> for #enum_name {
-            fn from(value: #repr_type) -> Self {
+        impl TryFrom<#repr_type> for #enum_name {
+            type Error = ::pokerus::save::error::UnknownValue<#repr_type>;
+
+            fn try_from(value: #repr_type) -> Result<Self, Self::Error> {
                 match value {
                     #(#matches)*
-                    _ => unreachable!(),
+                    _ => Err(::pokerus::save::error::UnknownValue { name: stringify!(#enum_name), value }),
                 }
             }
         }
 
-        impl Into<#repr_type> for #enum_name {
-            fn into(self) -> #repr_type {
-                self as #repr_type
+        impl From<#enum_name> for #repr_type {
+            fn from(value: #enum_name) -> Self {
+                value as #repr_type
             }
         }
     };
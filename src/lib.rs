@@ -1,2 +1,5 @@
+// lets `pokerus_macro` refer to this crate as `::pokerus`, even from inside it
+extern crate self as pokerus;
+
 pub use pokerus_macro as macros;
 pub mod save;
\ No newline at end of file
@@ -0,0 +1,148 @@
+#[cfg(feature = "write")]
+use crate::save::error::WriteError;
+
+/// A gym badge from any generation. Each game stores its region's badges as bits, in the order of the
+/// region's array below.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Badge {
+    Coal,
+    Forest,
+    Cobble,
+    Fen,
+    Relic,
+    Mine,
+    Icicle,
+    Beacon,
+    Zephyr,
+    Hive,
+    Plain,
+    Fog,
+    Storm,
+    Mineral,
+    Glacier,
+    Rising,
+    Boulder,
+    Cascade,
+    Thunder,
+    Rainbow,
+    Soul,
+    Marsh,
+    Volcano,
+    Earth,
+    Stone,
+    Knuckle,
+    Dynamo,
+    Heat,
+    Balance,
+    Feather,
+    Mind,
+    Rain,
+    Trio,
+    Basic,
+    Insect,
+    Bolt,
+    Quake,
+    Jet,
+    Freeze,
+    Legend,
+    Toxic,
+    Wave,
+}
+
+impl Badge {
+    /// In the order of their bits
+    pub const SINNOH: [Badge; 8] = [
+        Badge::Coal,
+        Badge::Forest,
+        Badge::Cobble,
+        Badge::Fen,
+        Badge::Relic,
+        Badge::Mine,
+        Badge::Icicle,
+        Badge::Beacon,
+    ];
+
+    /// In the order of their bits
+    pub const JOHTO: [Badge; 8] = [
+        Badge::Zephyr,
+        Badge::Hive,
+        Badge::Plain,
+        Badge::Fog,
+        Badge::Storm,
+        Badge::Mineral,
+        Badge::Glacier,
+        Badge::Rising,
+    ];
+
+    /// In the order of their bits
+    pub const KANTO: [Badge; 8] = [
+        Badge::Boulder,
+        Badge::Cascade,
+        Badge::Thunder,
+        Badge::Rainbow,
+        Badge::Soul,
+        Badge::Marsh,
+        Badge::Volcano,
+        Badge::Earth,
+    ];
+
+    /// In the order of their bits
+    pub const HOENN: [Badge; 8] = [
+        Badge::Stone,
+        Badge::Knuckle,
+        Badge::Dynamo,
+        Badge::Heat,
+        Badge::Balance,
+        Badge::Feather,
+        Badge::Mind,
+        Badge::Rain,
+    ];
+
+    /// Black & White's badges, in the order of their bits
+    pub const UNOVA_BW: [Badge; 8] = [
+        Badge::Trio,
+        Badge::Basic,
+        Badge::Insect,
+        Badge::Bolt,
+        Badge::Quake,
+        Badge::Jet,
+        Badge::Freeze,
+        Badge::Legend,
+    ];
+
+    /// Black 2 & White 2's badges, in the order of their bits
+    pub const UNOVA_B2W2: [Badge; 8] = [
+        Badge::Basic,
+        Badge::Toxic,
+        Badge::Insect,
+        Badge::Bolt,
+        Badge::Quake,
+        Badge::Jet,
+        Badge::Legend,
+        Badge::Wave,
+    ];
+}
+
+/// One region's badges, as stored in a single byte
+pub(crate) struct Badges(pub Vec<Badge>);
+
+impl Badges {
+    pub fn from_bits(bits: u8, region: &[Badge; 8]) -> Self {
+        Badges(region.iter().enumerate().filter(|(i, _)| bits & (1 << i) != 0).map(|(_, badge)| *badge).collect())
+    }
+
+    /// The bits of the badges from each of `regions`. A badge from none of them is
+    /// `WriteError::InvalidBadge`.
+    #[cfg(feature = "write")]
+    pub fn to_bits(badges: &[Badge], regions: &[&[Badge; 8]]) -> Result<Vec<u8>, WriteError> {
+        if let Some(badge) = badges.iter().find(|badge| !regions.iter().any(|region| region.contains(badge))) {
+            return Err(WriteError::InvalidBadge(*badge));
+        }
+
+        Ok(regions.iter()
+            .map(|region| region.iter().enumerate()
+                .filter(|(_, badge)| badges.contains(badge))
+                .fold(0, |acc, (i, _)| acc | (1 << i)))
+            .collect())
+    }
+}
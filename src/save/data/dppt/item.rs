@@ -5,8 +5,10 @@
 
 	Data source: https://bulbapedia.bulbagarden.net/wiki/List_of_items_by_index_number_in_Generation_IV
 */
+use crate::save::error::UnknownValue;
 
-#[derive(Debug, Eq, Hash, PartialEq)]
+#[derive(Debug, Clone, Copy, Eq, Hash, PartialEq)]
+#[repr(u16)]
 pub enum DPPTItem {
 	None = 0,
 	MasterBall = 1,
@@ -523,527 +525,1054 @@ pub enum DPPTItem {
 	RedOrb = 534,
 	BlueOrb = 535,
 	EnigmaStone = 536,
+	/// An ID with no known item, e.g. from a corrupted save
+	Unknown(u16),
 }
 
-impl From<u16> for DPPTItem {
-    fn from(value: u16) -> Self {
+impl TryFrom<u16> for DPPTItem {
+    type Error = UnknownValue<u16>;
+
+    fn try_from(value: u16) -> Result<Self, Self::Error> {
+        match value {
+			0 => Ok(DPPTItem::None),
+			1 => Ok(DPPTItem::MasterBall),
+			2 => Ok(DPPTItem::UltraBall),
+			3 => Ok(DPPTItem::GreatBall),
+			4 => Ok(DPPTItem::PokeBall),
+			5 => Ok(DPPTItem::SafariBall),
+			6 => Ok(DPPTItem::NetBall),
+			7 => Ok(DPPTItem::DiveBall),
+			8 => Ok(DPPTItem::NestBall),
+			9 => Ok(DPPTItem::RepeatBall),
+			10 => Ok(DPPTItem::TimerBall),
+			11 => Ok(DPPTItem::LuxuryBall),
+			12 => Ok(DPPTItem::PremierBall),
+			13 => Ok(DPPTItem::DuskBall),
+			14 => Ok(DPPTItem::HealBall),
+			15 => Ok(DPPTItem::QuickBall),
+			16 => Ok(DPPTItem::CherishBall),
+			17 => Ok(DPPTItem::Potion),
+			18 => Ok(DPPTItem::Antidote),
+			19 => Ok(DPPTItem::BurnHeal),
+			20 => Ok(DPPTItem::IceHeal),
+			21 => Ok(DPPTItem::Awakening),
+			22 => Ok(DPPTItem::ParlyzHeal),
+			23 => Ok(DPPTItem::FullRestore),
+			24 => Ok(DPPTItem::MaxPotion),
+			25 => Ok(DPPTItem::HyperPotion),
+			26 => Ok(DPPTItem::SuperPotion),
+			27 => Ok(DPPTItem::FullHeal),
+			28 => Ok(DPPTItem::Revive),
+			29 => Ok(DPPTItem::MaxRevive),
+			30 => Ok(DPPTItem::FreshWater),
+			31 => Ok(DPPTItem::SodaPop),
+			32 => Ok(DPPTItem::Lemonade),
+			33 => Ok(DPPTItem::MoomooMilk),
+			34 => Ok(DPPTItem::Energypowder),
+			35 => Ok(DPPTItem::EnergyRoot),
+			36 => Ok(DPPTItem::HealPowder),
+			37 => Ok(DPPTItem::RevivalHerb),
+			38 => Ok(DPPTItem::Ether),
+			39 => Ok(DPPTItem::MaxEther),
+			40 => Ok(DPPTItem::Elixir),
+			41 => Ok(DPPTItem::MaxElixir),
+			42 => Ok(DPPTItem::LavaCookie),
+			43 => Ok(DPPTItem::BerryJuice),
+			44 => Ok(DPPTItem::SacredAsh),
+			45 => Ok(DPPTItem::HpUp),
+			46 => Ok(DPPTItem::Protein),
+			47 => Ok(DPPTItem::Iron),
+			48 => Ok(DPPTItem::Carbos),
+			49 => Ok(DPPTItem::Calcium),
+			50 => Ok(DPPTItem::RareCandy),
+			51 => Ok(DPPTItem::PpUp),
+			52 => Ok(DPPTItem::Zinc),
+			53 => Ok(DPPTItem::PpMax),
+			54 => Ok(DPPTItem::OldGateau),
+			55 => Ok(DPPTItem::GuardSpec),
+			56 => Ok(DPPTItem::DireHit),
+			57 => Ok(DPPTItem::XAttack),
+			58 => Ok(DPPTItem::XDefense),
+			59 => Ok(DPPTItem::XSpeed),
+			60 => Ok(DPPTItem::XAccuracy),
+			61 => Ok(DPPTItem::XSpecial),
+			62 => Ok(DPPTItem::XSpDef),
+			63 => Ok(DPPTItem::PokeDoll),
+			64 => Ok(DPPTItem::FluffyTail),
+			65 => Ok(DPPTItem::BlueFlute),
+			66 => Ok(DPPTItem::YellowFlute),
+			67 => Ok(DPPTItem::RedFlute),
+			68 => Ok(DPPTItem::BlackFlute),
+			69 => Ok(DPPTItem::WhiteFlute),
+			70 => Ok(DPPTItem::ShoalSalt),
+			71 => Ok(DPPTItem::ShoalShell),
+			72 => Ok(DPPTItem::RedShard),
+			73 => Ok(DPPTItem::BlueShard),
+			74 => Ok(DPPTItem::YellowShard),
+			75 => Ok(DPPTItem::GreenShard),
+			76 => Ok(DPPTItem::SuperRepel),
+			77 => Ok(DPPTItem::MaxRepel),
+			78 => Ok(DPPTItem::EscapeRope),
+			79 => Ok(DPPTItem::Repel),
+			80 => Ok(DPPTItem::SunStone),
+			81 => Ok(DPPTItem::MoonStone),
+			82 => Ok(DPPTItem::FireStone),
+			83 => Ok(DPPTItem::Thunderstone),
+			84 => Ok(DPPTItem::WaterStone),
+			85 => Ok(DPPTItem::LeafStone),
+			86 => Ok(DPPTItem::Tinymushroom),
+			87 => Ok(DPPTItem::BigMushroom),
+			88 => Ok(DPPTItem::Pearl),
+			89 => Ok(DPPTItem::BigPearl),
+			90 => Ok(DPPTItem::Stardust),
+			91 => Ok(DPPTItem::StarPiece),
+			92 => Ok(DPPTItem::Nugget),
+			93 => Ok(DPPTItem::HeartScale),
+			94 => Ok(DPPTItem::Honey),
+			95 => Ok(DPPTItem::GrowthMulch),
+			96 => Ok(DPPTItem::DampMulch),
+			97 => Ok(DPPTItem::StableMulch),
+			98 => Ok(DPPTItem::GooeyMulch),
+			99 => Ok(DPPTItem::RootFossil),
+			100 => Ok(DPPTItem::ClawFossil),
+			101 => Ok(DPPTItem::HelixFossil),
+			102 => Ok(DPPTItem::DomeFossil),
+			103 => Ok(DPPTItem::OldAmber),
+			104 => Ok(DPPTItem::ArmorFossil),
+			105 => Ok(DPPTItem::SkullFossil),
+			106 => Ok(DPPTItem::RareBone),
+			107 => Ok(DPPTItem::ShinyStone),
+			108 => Ok(DPPTItem::DuskStone),
+			109 => Ok(DPPTItem::DawnStone),
+			110 => Ok(DPPTItem::OvalStone),
+			111 => Ok(DPPTItem::OddKeystone),
+			112 => Ok(DPPTItem::GriseousOrb),
+			135 => Ok(DPPTItem::AdamantOrb),
+			136 => Ok(DPPTItem::LustrousOrb),
+			137 => Ok(DPPTItem::GrassMail),
+			138 => Ok(DPPTItem::FlameMail),
+			139 => Ok(DPPTItem::BubbleMail),
+			140 => Ok(DPPTItem::BloomMail),
+			141 => Ok(DPPTItem::TunnelMail),
+			142 => Ok(DPPTItem::SteelMail),
+			143 => Ok(DPPTItem::HeartMail),
+			144 => Ok(DPPTItem::SnowMail),
+			145 => Ok(DPPTItem::SpaceMail),
+			146 => Ok(DPPTItem::AirMail),
+			147 => Ok(DPPTItem::MosaicMail),
+			148 => Ok(DPPTItem::BrickMail),
+			149 => Ok(DPPTItem::CheriBerry),
+			150 => Ok(DPPTItem::ChestoBerry),
+			151 => Ok(DPPTItem::PechaBerry),
+			152 => Ok(DPPTItem::RawstBerry),
+			153 => Ok(DPPTItem::AspearBerry),
+			154 => Ok(DPPTItem::LeppaBerry),
+			155 => Ok(DPPTItem::OranBerry),
+			156 => Ok(DPPTItem::PersimBerry),
+			157 => Ok(DPPTItem::LumBerry),
+			158 => Ok(DPPTItem::SitrusBerry),
+			159 => Ok(DPPTItem::FigyBerry),
+			160 => Ok(DPPTItem::WikiBerry),
+			161 => Ok(DPPTItem::MagoBerry),
+			162 => Ok(DPPTItem::AguavBerry),
+			163 => Ok(DPPTItem::IapapaBerry),
+			164 => Ok(DPPTItem::RazzBerry),
+			165 => Ok(DPPTItem::BlukBerry),
+			166 => Ok(DPPTItem::NanabBerry),
+			167 => Ok(DPPTItem::WepearBerry),
+			168 => Ok(DPPTItem::PinapBerry),
+			169 => Ok(DPPTItem::PomegBerry),
+			170 => Ok(DPPTItem::KelpsyBerry),
+			171 => Ok(DPPTItem::QualotBerry),
+			172 => Ok(DPPTItem::HondewBerry),
+			173 => Ok(DPPTItem::GrepaBerry),
+			174 => Ok(DPPTItem::TamatoBerry),
+			175 => Ok(DPPTItem::CornnBerry),
+			176 => Ok(DPPTItem::MagostBerry),
+			177 => Ok(DPPTItem::RabutaBerry),
+			178 => Ok(DPPTItem::NomelBerry),
+			179 => Ok(DPPTItem::SpelonBerry),
+			180 => Ok(DPPTItem::PamtreBerry),
+			181 => Ok(DPPTItem::WatmelBerry),
+			182 => Ok(DPPTItem::DurinBerry),
+			183 => Ok(DPPTItem::BelueBerry),
+			184 => Ok(DPPTItem::OccaBerry),
+			185 => Ok(DPPTItem::PasshoBerry),
+			186 => Ok(DPPTItem::WacanBerry),
+			187 => Ok(DPPTItem::RindoBerry),
+			188 => Ok(DPPTItem::YacheBerry),
+			189 => Ok(DPPTItem::ChopleBerry),
+			190 => Ok(DPPTItem::KebiaBerry),
+			191 => Ok(DPPTItem::ShucaBerry),
+			192 => Ok(DPPTItem::CobaBerry),
+			193 => Ok(DPPTItem::PayapaBerry),
+			194 => Ok(DPPTItem::TangaBerry),
+			195 => Ok(DPPTItem::ChartiBerry),
+			196 => Ok(DPPTItem::KasibBerry),
+			197 => Ok(DPPTItem::HabanBerry),
+			198 => Ok(DPPTItem::ColburBerry),
+			199 => Ok(DPPTItem::BabiriBerry),
+			200 => Ok(DPPTItem::ChilanBerry),
+			201 => Ok(DPPTItem::LiechiBerry),
+			202 => Ok(DPPTItem::GanlonBerry),
+			203 => Ok(DPPTItem::SalacBerry),
+			204 => Ok(DPPTItem::PetayaBerry),
+			205 => Ok(DPPTItem::ApicotBerry),
+			206 => Ok(DPPTItem::LansatBerry),
+			207 => Ok(DPPTItem::StarfBerry),
+			208 => Ok(DPPTItem::EnigmaBerry),
+			209 => Ok(DPPTItem::MicleBerry),
+			210 => Ok(DPPTItem::CustapBerry),
+			211 => Ok(DPPTItem::JabocaBerry),
+			212 => Ok(DPPTItem::RowapBerry),
+			213 => Ok(DPPTItem::Brightpowder),
+			214 => Ok(DPPTItem::WhiteHerb),
+			215 => Ok(DPPTItem::MachoBrace),
+			216 => Ok(DPPTItem::ExpShare),
+			217 => Ok(DPPTItem::QuickClaw),
+			218 => Ok(DPPTItem::SootheBell),
+			219 => Ok(DPPTItem::MentalHerb),
+			220 => Ok(DPPTItem::ChoiceBand),
+			221 => Ok(DPPTItem::KingsRock),
+			222 => Ok(DPPTItem::Silverpowder),
+			223 => Ok(DPPTItem::AmuletCoin),
+			224 => Ok(DPPTItem::CleanseTag),
+			225 => Ok(DPPTItem::SoulDew),
+			226 => Ok(DPPTItem::Deepseatooth),
+			227 => Ok(DPPTItem::Deepseascale),
+			228 => Ok(DPPTItem::SmokeBall),
+			229 => Ok(DPPTItem::Everstone),
+			230 => Ok(DPPTItem::FocusBand),
+			231 => Ok(DPPTItem::LuckyEgg),
+			232 => Ok(DPPTItem::ScopeLens),
+			233 => Ok(DPPTItem::MetalCoat),
+			234 => Ok(DPPTItem::Leftovers),
+			235 => Ok(DPPTItem::DragonScale),
+			236 => Ok(DPPTItem::LightBall),
+			237 => Ok(DPPTItem::SoftSand),
+			238 => Ok(DPPTItem::HardStone),
+			239 => Ok(DPPTItem::MiracleSeed),
+			240 => Ok(DPPTItem::Blackglasses),
+			241 => Ok(DPPTItem::BlackBelt),
+			242 => Ok(DPPTItem::Magnet),
+			243 => Ok(DPPTItem::MysticWater),
+			244 => Ok(DPPTItem::SharpBeak),
+			245 => Ok(DPPTItem::PoisonBarb),
+			246 => Ok(DPPTItem::Nevermeltice),
+			247 => Ok(DPPTItem::SpellTag),
+			248 => Ok(DPPTItem::Twistedspoon),
+			249 => Ok(DPPTItem::Charcoal),
+			250 => Ok(DPPTItem::DragonFang),
+			251 => Ok(DPPTItem::SilkScarf),
+			252 => Ok(DPPTItem::Upgrade),
+			253 => Ok(DPPTItem::ShellBell),
+			254 => Ok(DPPTItem::SeaIncense),
+			255 => Ok(DPPTItem::LaxIncense),
+			256 => Ok(DPPTItem::LuckyPunch),
+			257 => Ok(DPPTItem::MetalPowder),
+			258 => Ok(DPPTItem::ThickClub),
+			259 => Ok(DPPTItem::Stick),
+			260 => Ok(DPPTItem::RedScarf),
+			261 => Ok(DPPTItem::BlueScarf),
+			262 => Ok(DPPTItem::PinkScarf),
+			263 => Ok(DPPTItem::GreenScarf),
+			264 => Ok(DPPTItem::YellowScarf),
+			265 => Ok(DPPTItem::WideLens),
+			266 => Ok(DPPTItem::MuscleBand),
+			267 => Ok(DPPTItem::WiseGlasses),
+			268 => Ok(DPPTItem::ExpertBelt),
+			269 => Ok(DPPTItem::LightClay),
+			270 => Ok(DPPTItem::LifeOrb),
+			271 => Ok(DPPTItem::PowerHerb),
+			272 => Ok(DPPTItem::ToxicOrb),
+			273 => Ok(DPPTItem::FlameOrb),
+			274 => Ok(DPPTItem::QuickPowder),
+			275 => Ok(DPPTItem::FocusSash),
+			276 => Ok(DPPTItem::ZoomLens),
+			277 => Ok(DPPTItem::Metronome),
+			278 => Ok(DPPTItem::IronBall),
+			279 => Ok(DPPTItem::LaggingTail),
+			280 => Ok(DPPTItem::DestinyKnot),
+			281 => Ok(DPPTItem::BlackSludge),
+			282 => Ok(DPPTItem::IcyRock),
+			283 => Ok(DPPTItem::SmoothRock),
+			284 => Ok(DPPTItem::HeatRock),
+			285 => Ok(DPPTItem::DampRock),
+			286 => Ok(DPPTItem::GripClaw),
+			287 => Ok(DPPTItem::ChoiceScarf),
+			288 => Ok(DPPTItem::StickyBarb),
+			289 => Ok(DPPTItem::PowerBracer),
+			290 => Ok(DPPTItem::PowerBelt),
+			291 => Ok(DPPTItem::PowerLens),
+			292 => Ok(DPPTItem::PowerBand),
+			293 => Ok(DPPTItem::PowerAnklet),
+			294 => Ok(DPPTItem::PowerWeight),
+			295 => Ok(DPPTItem::ShedShell),
+			296 => Ok(DPPTItem::BigRoot),
+			297 => Ok(DPPTItem::ChoiceSpecs),
+			298 => Ok(DPPTItem::FlamePlate),
+			299 => Ok(DPPTItem::SplashPlate),
+			300 => Ok(DPPTItem::ZapPlate),
+			301 => Ok(DPPTItem::MeadowPlate),
+			302 => Ok(DPPTItem::IciclePlate),
+			303 => Ok(DPPTItem::FistPlate),
+			304 => Ok(DPPTItem::ToxicPlate),
+			305 => Ok(DPPTItem::EarthPlate),
+			306 => Ok(DPPTItem::SkyPlate),
+			307 => Ok(DPPTItem::MindPlate),
+			308 => Ok(DPPTItem::InsectPlate),
+			309 => Ok(DPPTItem::StonePlate),
+			310 => Ok(DPPTItem::SpookyPlate),
+			311 => Ok(DPPTItem::DracoPlate),
+			312 => Ok(DPPTItem::DreadPlate),
+			313 => Ok(DPPTItem::IronPlate),
+			314 => Ok(DPPTItem::OddIncense),
+			315 => Ok(DPPTItem::RockIncense),
+			316 => Ok(DPPTItem::FullIncense),
+			317 => Ok(DPPTItem::WaveIncense),
+			318 => Ok(DPPTItem::RoseIncense),
+			319 => Ok(DPPTItem::LuckIncense),
+			320 => Ok(DPPTItem::PureIncense),
+			321 => Ok(DPPTItem::Protector),
+			322 => Ok(DPPTItem::Electirizer),
+			323 => Ok(DPPTItem::Magmarizer),
+			324 => Ok(DPPTItem::DubiousDisc),
+			325 => Ok(DPPTItem::ReaperCloth),
+			326 => Ok(DPPTItem::RazorClaw),
+			327 => Ok(DPPTItem::RazorFang),
+			328 => Ok(DPPTItem::Tm01),
+			329 => Ok(DPPTItem::Tm02),
+			330 => Ok(DPPTItem::Tm03),
+			331 => Ok(DPPTItem::Tm04),
+			332 => Ok(DPPTItem::Tm05),
+			333 => Ok(DPPTItem::Tm06),
+			334 => Ok(DPPTItem::Tm07),
+			335 => Ok(DPPTItem::Tm08),
+			336 => Ok(DPPTItem::Tm09),
+			337 => Ok(DPPTItem::Tm10),
+			338 => Ok(DPPTItem::Tm11),
+			339 => Ok(DPPTItem::Tm12),
+			340 => Ok(DPPTItem::Tm13),
+			341 => Ok(DPPTItem::Tm14),
+			342 => Ok(DPPTItem::Tm15),
+			343 => Ok(DPPTItem::Tm16),
+			344 => Ok(DPPTItem::Tm17),
+			345 => Ok(DPPTItem::Tm18),
+			346 => Ok(DPPTItem::Tm19),
+			347 => Ok(DPPTItem::Tm20),
+			348 => Ok(DPPTItem::Tm21),
+			349 => Ok(DPPTItem::Tm22),
+			350 => Ok(DPPTItem::Tm23),
+			351 => Ok(DPPTItem::Tm24),
+			352 => Ok(DPPTItem::Tm25),
+			353 => Ok(DPPTItem::Tm26),
+			354 => Ok(DPPTItem::Tm27),
+			355 => Ok(DPPTItem::Tm28),
+			356 => Ok(DPPTItem::Tm29),
+			357 => Ok(DPPTItem::Tm30),
+			358 => Ok(DPPTItem::Tm31),
+			359 => Ok(DPPTItem::Tm32),
+			360 => Ok(DPPTItem::Tm33),
+			361 => Ok(DPPTItem::Tm34),
+			362 => Ok(DPPTItem::Tm35),
+			363 => Ok(DPPTItem::Tm36),
+			364 => Ok(DPPTItem::Tm37),
+			365 => Ok(DPPTItem::Tm38),
+			366 => Ok(DPPTItem::Tm39),
+			367 => Ok(DPPTItem::Tm40),
+			368 => Ok(DPPTItem::Tm41),
+			369 => Ok(DPPTItem::Tm42),
+			370 => Ok(DPPTItem::Tm43),
+			371 => Ok(DPPTItem::Tm44),
+			372 => Ok(DPPTItem::Tm45),
+			373 => Ok(DPPTItem::Tm46),
+			374 => Ok(DPPTItem::Tm47),
+			375 => Ok(DPPTItem::Tm48),
+			376 => Ok(DPPTItem::Tm49),
+			377 => Ok(DPPTItem::Tm50),
+			378 => Ok(DPPTItem::Tm51),
+			379 => Ok(DPPTItem::Tm52),
+			380 => Ok(DPPTItem::Tm53),
+			381 => Ok(DPPTItem::Tm54),
+			382 => Ok(DPPTItem::Tm55),
+			383 => Ok(DPPTItem::Tm56),
+			384 => Ok(DPPTItem::Tm57),
+			385 => Ok(DPPTItem::Tm58),
+			386 => Ok(DPPTItem::Tm59),
+			387 => Ok(DPPTItem::Tm60),
+			388 => Ok(DPPTItem::Tm61),
+			389 => Ok(DPPTItem::Tm62),
+			390 => Ok(DPPTItem::Tm63),
+			391 => Ok(DPPTItem::Tm64),
+			392 => Ok(DPPTItem::Tm65),
+			393 => Ok(DPPTItem::Tm66),
+			394 => Ok(DPPTItem::Tm67),
+			395 => Ok(DPPTItem::Tm68),
+			396 => Ok(DPPTItem::Tm69),
+			397 => Ok(DPPTItem::Tm70),
+			398 => Ok(DPPTItem::Tm71),
+			399 => Ok(DPPTItem::Tm72),
+			400 => Ok(DPPTItem::Tm73),
+			401 => Ok(DPPTItem::Tm74),
+			402 => Ok(DPPTItem::Tm75),
+			403 => Ok(DPPTItem::Tm76),
+			404 => Ok(DPPTItem::Tm77),
+			405 => Ok(DPPTItem::Tm78),
+			406 => Ok(DPPTItem::Tm79),
+			407 => Ok(DPPTItem::Tm80),
+			408 => Ok(DPPTItem::Tm81),
+			409 => Ok(DPPTItem::Tm82),
+			410 => Ok(DPPTItem::Tm83),
+			411 => Ok(DPPTItem::Tm84),
+			412 => Ok(DPPTItem::Tm85),
+			413 => Ok(DPPTItem::Tm86),
+			414 => Ok(DPPTItem::Tm87),
+			415 => Ok(DPPTItem::Tm88),
+			416 => Ok(DPPTItem::Tm89),
+			417 => Ok(DPPTItem::Tm90),
+			418 => Ok(DPPTItem::Tm91),
+			419 => Ok(DPPTItem::Tm92),
+			420 => Ok(DPPTItem::Hm01),
+			421 => Ok(DPPTItem::Hm02),
+			422 => Ok(DPPTItem::Hm03),
+			423 => Ok(DPPTItem::Hm04),
+			424 => Ok(DPPTItem::Hm05),
+			425 => Ok(DPPTItem::Hm06),
+			426 => Ok(DPPTItem::Hm07),
+			427 => Ok(DPPTItem::Hm08),
+			428 => Ok(DPPTItem::ExplorerKit),
+			429 => Ok(DPPTItem::LootSack),
+			430 => Ok(DPPTItem::RuleBook),
+			431 => Ok(DPPTItem::PokeRadar),
+			432 => Ok(DPPTItem::PointCard),
+			433 => Ok(DPPTItem::Journal),
+			434 => Ok(DPPTItem::SealCase),
+			435 => Ok(DPPTItem::FashionCase),
+			436 => Ok(DPPTItem::SealBag),
+			437 => Ok(DPPTItem::PalPad),
+			438 => Ok(DPPTItem::WorksKey),
+			439 => Ok(DPPTItem::OldCharm),
+			440 => Ok(DPPTItem::GalacticKey),
+			441 => Ok(DPPTItem::RedChain),
+			442 => Ok(DPPTItem::TownMap),
+			443 => Ok(DPPTItem::VsSeeker),
+			444 => Ok(DPPTItem::CoinCase),
+			445 => Ok(DPPTItem::OldRod),
+			446 => Ok(DPPTItem::GoodRod),
+			447 => Ok(DPPTItem::SuperRod),
+			448 => Ok(DPPTItem::Sprayduck),
+			449 => Ok(DPPTItem::PoffinCase),
+			450 => Ok(DPPTItem::Bicycle),
+			451 => Ok(DPPTItem::SuiteKey),
+			452 => Ok(DPPTItem::OaksLetter),
+			453 => Ok(DPPTItem::LunarWing),
+			454 => Ok(DPPTItem::MemberCard),
+			455 => Ok(DPPTItem::AzureFlute),
+			456 => Ok(DPPTItem::SsTicket),
+			457 => Ok(DPPTItem::ContestPass),
+			458 => Ok(DPPTItem::MagmaStone),
+			459 => Ok(DPPTItem::Parcel),
+			460 => Ok(DPPTItem::Coupon1),
+			461 => Ok(DPPTItem::Coupon2),
+			462 => Ok(DPPTItem::Coupon3),
+			463 => Ok(DPPTItem::StorageKey),
+			464 => Ok(DPPTItem::Secretpotion),
+			465 => Ok(DPPTItem::VsRecorder),
+			466 => Ok(DPPTItem::Gracidea),
+			467 => Ok(DPPTItem::SecretKey),
+			468 => Ok(DPPTItem::ApricornBox),
+			469 => Ok(DPPTItem::UnownReport),
+			470 => Ok(DPPTItem::BerryPots),
+			471 => Ok(DPPTItem::DowsingMchn),
+			472 => Ok(DPPTItem::BlueCard),
+			473 => Ok(DPPTItem::Slowpoketail),
+			474 => Ok(DPPTItem::ClearBell),
+			475 => Ok(DPPTItem::CardKey),
+			476 => Ok(DPPTItem::BasementKey),
+			477 => Ok(DPPTItem::Squirtbottle),
+			478 => Ok(DPPTItem::RedScale),
+			479 => Ok(DPPTItem::LostItem),
+			480 => Ok(DPPTItem::Pass),
+			481 => Ok(DPPTItem::MachinePart),
+			482 => Ok(DPPTItem::SilverWing),
+			483 => Ok(DPPTItem::RainbowWing),
+			484 => Ok(DPPTItem::MysteryEgg),
+			485 => Ok(DPPTItem::RedApricorn),
+			486 => Ok(DPPTItem::YlwApricorn),
+			487 => Ok(DPPTItem::BluApricorn),
+			488 => Ok(DPPTItem::GrnApricorn),
+			489 => Ok(DPPTItem::PnkApricorn),
+			490 => Ok(DPPTItem::WhtApricorn),
+			491 => Ok(DPPTItem::BlkApricorn),
+			492 => Ok(DPPTItem::FastBall),
+			493 => Ok(DPPTItem::LevelBall),
+			494 => Ok(DPPTItem::LureBall),
+			495 => Ok(DPPTItem::HeavyBall),
+			496 => Ok(DPPTItem::LoveBall),
+			497 => Ok(DPPTItem::FriendBall),
+			498 => Ok(DPPTItem::MoonBall),
+			499 => Ok(DPPTItem::SportBall),
+			500 => Ok(DPPTItem::ParkBall),
+			501 => Ok(DPPTItem::PhotoAlbum),
+			502 => Ok(DPPTItem::GbSounds),
+			503 => Ok(DPPTItem::TidalBell),
+			504 => Ok(DPPTItem::Ragecandybar),
+			505 => Ok(DPPTItem::DataCard01),
+			506 => Ok(DPPTItem::DataCard02),
+			507 => Ok(DPPTItem::DataCard03),
+			508 => Ok(DPPTItem::DataCard04),
+			509 => Ok(DPPTItem::DataCard05),
+			510 => Ok(DPPTItem::DataCard06),
+			511 => Ok(DPPTItem::DataCard07),
+			512 => Ok(DPPTItem::DataCard08),
+			513 => Ok(DPPTItem::DataCard09),
+			514 => Ok(DPPTItem::DataCard10),
+			515 => Ok(DPPTItem::DataCard11),
+			516 => Ok(DPPTItem::DataCard12),
+			517 => Ok(DPPTItem::DataCard13),
+			518 => Ok(DPPTItem::DataCard14),
+			519 => Ok(DPPTItem::DataCard15),
+			520 => Ok(DPPTItem::DataCard16),
+			521 => Ok(DPPTItem::DataCard17),
+			522 => Ok(DPPTItem::DataCard18),
+			523 => Ok(DPPTItem::DataCard19),
+			524 => Ok(DPPTItem::DataCard20),
+			525 => Ok(DPPTItem::DataCard21),
+			526 => Ok(DPPTItem::DataCard22),
+			527 => Ok(DPPTItem::DataCard23),
+			528 => Ok(DPPTItem::DataCard24),
+			529 => Ok(DPPTItem::DataCard25),
+			530 => Ok(DPPTItem::DataCard26),
+			531 => Ok(DPPTItem::DataCard27),
+			532 => Ok(DPPTItem::JadeOrb),
+			533 => Ok(DPPTItem::LockCapsule),
+			534 => Ok(DPPTItem::RedOrb),
+			535 => Ok(DPPTItem::BlueOrb),
+			536 => Ok(DPPTItem::EnigmaStone),
+            _ => Err(UnknownValue { name: "DPPTItem", value })
+        }
+    }
+}
+
+impl From<DPPTItem> for u16 {
+    fn from(value: DPPTItem) -> Self {
         match value {
-			0 => DPPTItem::None,
-			1 => DPPTItem::MasterBall,
-			2 => DPPTItem::UltraBall,
-			3 => DPPTItem::GreatBall,
-			4 => DPPTItem::PokeBall,
-			5 => DPPTItem::SafariBall,
-			6 => DPPTItem::NetBall,
-			7 => DPPTItem::DiveBall,
-			8 => DPPTItem::NestBall,
-			9 => DPPTItem::RepeatBall,
-			10 => DPPTItem::TimerBall,
-			11 => DPPTItem::LuxuryBall,
-			12 => DPPTItem::PremierBall,
-			13 => DPPTItem::DuskBall,
-			14 => DPPTItem::HealBall,
-			15 => DPPTItem::QuickBall,
-			16 => DPPTItem::CherishBall,
-			17 => DPPTItem::Potion,
-			18 => DPPTItem::Antidote,
-			19 => DPPTItem::BurnHeal,
-			20 => DPPTItem::IceHeal,
-			21 => DPPTItem::Awakening,
-			22 => DPPTItem::ParlyzHeal,
-			23 => DPPTItem::FullRestore,
-			24 => DPPTItem::MaxPotion,
-			25 => DPPTItem::HyperPotion,
-			26 => DPPTItem::SuperPotion,
-			27 => DPPTItem::FullHeal,
-			28 => DPPTItem::Revive,
-			29 => DPPTItem::MaxRevive,
-			30 => DPPTItem::FreshWater,
-			31 => DPPTItem::SodaPop,
-			32 => DPPTItem::Lemonade,
-			33 => DPPTItem::MoomooMilk,
-			34 => DPPTItem::Energypowder,
-			35 => DPPTItem::EnergyRoot,
-			36 => DPPTItem::HealPowder,
-			37 => DPPTItem::RevivalHerb,
-			38 => DPPTItem::Ether,
-			39 => DPPTItem::MaxEther,
-			40 => DPPTItem::Elixir,
-			41 => DPPTItem::MaxElixir,
-			42 => DPPTItem::LavaCookie,
-			43 => DPPTItem::BerryJuice,
-			44 => DPPTItem::SacredAsh,
-			45 => DPPTItem::HpUp,
-			46 => DPPTItem::Protein,
-			47 => DPPTItem::Iron,
-			48 => DPPTItem::Carbos,
-			49 => DPPTItem::Calcium,
-			50 => DPPTItem::RareCandy,
-			51 => DPPTItem::PpUp,
-			52 => DPPTItem::Zinc,
-			53 => DPPTItem::PpMax,
-			54 => DPPTItem::OldGateau,
-			55 => DPPTItem::GuardSpec,
-			56 => DPPTItem::DireHit,
-			57 => DPPTItem::XAttack,
-			58 => DPPTItem::XDefense,
-			59 => DPPTItem::XSpeed,
-			60 => DPPTItem::XAccuracy,
-			61 => DPPTItem::XSpecial,
-			62 => DPPTItem::XSpDef,
-			63 => DPPTItem::PokeDoll,
-			64 => DPPTItem::FluffyTail,
-			65 => DPPTItem::BlueFlute,
-			66 => DPPTItem::YellowFlute,
-			67 => DPPTItem::RedFlute,
-			68 => DPPTItem::BlackFlute,
-			69 => DPPTItem::WhiteFlute,
-			70 => DPPTItem::ShoalSalt,
-			71 => DPPTItem::ShoalShell,
-			72 => DPPTItem::RedShard,
-			73 => DPPTItem::BlueShard,
-			74 => DPPTItem::YellowShard,
-			75 => DPPTItem::GreenShard,
-			76 => DPPTItem::SuperRepel,
-			77 => DPPTItem::MaxRepel,
-			78 => DPPTItem::EscapeRope,
-			79 => DPPTItem::Repel,
-			80 => DPPTItem::SunStone,
-			81 => DPPTItem::MoonStone,
-			82 => DPPTItem::FireStone,
-			83 => DPPTItem::Thunderstone,
-			84 => DPPTItem::WaterStone,
-			85 => DPPTItem::LeafStone,
-			86 => DPPTItem::Tinymushroom,
-			87 => DPPTItem::BigMushroom,
-			88 => DPPTItem::Pearl,
-			89 => DPPTItem::BigPearl,
-			90 => DPPTItem::Stardust,
-			91 => DPPTItem::StarPiece,
-			92 => DPPTItem::Nugget,
-			93 => DPPTItem::HeartScale,
-			94 => DPPTItem::Honey,
-			95 => DPPTItem::GrowthMulch,
-			96 => DPPTItem::DampMulch,
-			97 => DPPTItem::StableMulch,
-			98 => DPPTItem::GooeyMulch,
-			99 => DPPTItem::RootFossil,
-			100 => DPPTItem::ClawFossil,
-			101 => DPPTItem::HelixFossil,
-			102 => DPPTItem::DomeFossil,
-			103 => DPPTItem::OldAmber,
-			104 => DPPTItem::ArmorFossil,
-			105 => DPPTItem::SkullFossil,
-			106 => DPPTItem::RareBone,
-			107 => DPPTItem::ShinyStone,
-			108 => DPPTItem::DuskStone,
-			109 => DPPTItem::DawnStone,
-			110 => DPPTItem::OvalStone,
-			111 => DPPTItem::OddKeystone,
-			112 => DPPTItem::GriseousOrb,
-			135 => DPPTItem::AdamantOrb,
-			136 => DPPTItem::LustrousOrb,
-			137 => DPPTItem::GrassMail,
-			138 => DPPTItem::FlameMail,
-			139 => DPPTItem::BubbleMail,
-			140 => DPPTItem::BloomMail,
-			141 => DPPTItem::TunnelMail,
-			142 => DPPTItem::SteelMail,
-			143 => DPPTItem::HeartMail,
-			144 => DPPTItem::SnowMail,
-			145 => DPPTItem::SpaceMail,
-			146 => DPPTItem::AirMail,
-			147 => DPPTItem::MosaicMail,
-			148 => DPPTItem::BrickMail,
-			149 => DPPTItem::CheriBerry,
-			150 => DPPTItem::ChestoBerry,
-			151 => DPPTItem::PechaBerry,
-			152 => DPPTItem::RawstBerry,
-			153 => DPPTItem::AspearBerry,
-			154 => DPPTItem::LeppaBerry,
-			155 => DPPTItem::OranBerry,
-			156 => DPPTItem::PersimBerry,
-			157 => DPPTItem::LumBerry,
-			158 => DPPTItem::SitrusBerry,
-			159 => DPPTItem::FigyBerry,
-			160 => DPPTItem::WikiBerry,
-			161 => DPPTItem::MagoBerry,
-			162 => DPPTItem::AguavBerry,
-			163 => DPPTItem::IapapaBerry,
-			164 => DPPTItem::RazzBerry,
-			165 => DPPTItem::BlukBerry,
-			166 => DPPTItem::NanabBerry,
-			167 => DPPTItem::WepearBerry,
-			168 => DPPTItem::PinapBerry,
-			169 => DPPTItem::PomegBerry,
-			170 => DPPTItem::KelpsyBerry,
-			171 => DPPTItem::QualotBerry,
-			172 => DPPTItem::HondewBerry,
-			173 => DPPTItem::GrepaBerry,
-			174 => DPPTItem::TamatoBerry,
-			175 => DPPTItem::CornnBerry,
-			176 => DPPTItem::MagostBerry,
-			177 => DPPTItem::RabutaBerry,
-			178 => DPPTItem::NomelBerry,
-			179 => DPPTItem::SpelonBerry,
-			180 => DPPTItem::PamtreBerry,
-			181 => DPPTItem::WatmelBerry,
-			182 => DPPTItem::DurinBerry,
-			183 => DPPTItem::BelueBerry,
-			184 => DPPTItem::OccaBerry,
-			185 => DPPTItem::PasshoBerry,
-			186 => DPPTItem::WacanBerry,
-			187 => DPPTItem::RindoBerry,
-			188 => DPPTItem::YacheBerry,
-			189 => DPPTItem::ChopleBerry,
-			190 => DPPTItem::KebiaBerry,
-			191 => DPPTItem::ShucaBerry,
-			192 => DPPTItem::CobaBerry,
-			193 => DPPTItem::PayapaBerry,
-			194 => DPPTItem::TangaBerry,
-			195 => DPPTItem::ChartiBerry,
-			196 => DPPTItem::KasibBerry,
-			197 => DPPTItem::HabanBerry,
-			198 => DPPTItem::ColburBerry,
-			199 => DPPTItem::BabiriBerry,
-			200 => DPPTItem::ChilanBerry,
-			201 => DPPTItem::LiechiBerry,
-			202 => DPPTItem::GanlonBerry,
-			203 => DPPTItem::SalacBerry,
-			204 => DPPTItem::PetayaBerry,
-			205 => DPPTItem::ApicotBerry,
-			206 => DPPTItem::LansatBerry,
-			207 => DPPTItem::StarfBerry,
-			208 => DPPTItem::EnigmaBerry,
-			209 => DPPTItem::MicleBerry,
-			210 => DPPTItem::CustapBerry,
-			211 => DPPTItem::JabocaBerry,
-			212 => DPPTItem::RowapBerry,
-			213 => DPPTItem::Brightpowder,
-			214 => DPPTItem::WhiteHerb,
-			215 => DPPTItem::MachoBrace,
-			216 => DPPTItem::ExpShare,
-			217 => DPPTItem::QuickClaw,
-			218 => DPPTItem::SootheBell,
-			219 => DPPTItem::MentalHerb,
-			220 => DPPTItem::ChoiceBand,
-			221 => DPPTItem::KingsRock,
-			222 => DPPTItem::Silverpowder,
-			223 => DPPTItem::AmuletCoin,
-			224 => DPPTItem::CleanseTag,
-			225 => DPPTItem::SoulDew,
-			226 => DPPTItem::Deepseatooth,
-			227 => DPPTItem::Deepseascale,
-			228 => DPPTItem::SmokeBall,
-			229 => DPPTItem::Everstone,
-			230 => DPPTItem::FocusBand,
-			231 => DPPTItem::LuckyEgg,
-			232 => DPPTItem::ScopeLens,
-			233 => DPPTItem::MetalCoat,
-			234 => DPPTItem::Leftovers,
-			235 => DPPTItem::DragonScale,
-			236 => DPPTItem::LightBall,
-			237 => DPPTItem::SoftSand,
-			238 => DPPTItem::HardStone,
-			239 => DPPTItem::MiracleSeed,
-			240 => DPPTItem::Blackglasses,
-			241 => DPPTItem::BlackBelt,
-			242 => DPPTItem::Magnet,
-			243 => DPPTItem::MysticWater,
-			244 => DPPTItem::SharpBeak,
-			245 => DPPTItem::PoisonBarb,
-			246 => DPPTItem::Nevermeltice,
-			247 => DPPTItem::SpellTag,
-			248 => DPPTItem::Twistedspoon,
-			249 => DPPTItem::Charcoal,
-			250 => DPPTItem::DragonFang,
-			251 => DPPTItem::SilkScarf,
-			252 => DPPTItem::Upgrade,
-			253 => DPPTItem::ShellBell,
-			254 => DPPTItem::SeaIncense,
-			255 => DPPTItem::LaxIncense,
-			256 => DPPTItem::LuckyPunch,
-			257 => DPPTItem::MetalPowder,
-			258 => DPPTItem::ThickClub,
-			259 => DPPTItem::Stick,
-			260 => DPPTItem::RedScarf,
-			261 => DPPTItem::BlueScarf,
-			262 => DPPTItem::PinkScarf,
-			263 => DPPTItem::GreenScarf,
-			264 => DPPTItem::YellowScarf,
-			265 => DPPTItem::WideLens,
-			266 => DPPTItem::MuscleBand,
-			267 => DPPTItem::WiseGlasses,
-			268 => DPPTItem::ExpertBelt,
-			269 => DPPTItem::LightClay,
-			270 => DPPTItem::LifeOrb,
-			271 => DPPTItem::PowerHerb,
-			272 => DPPTItem::ToxicOrb,
-			273 => DPPTItem::FlameOrb,
-			274 => DPPTItem::QuickPowder,
-			275 => DPPTItem::FocusSash,
-			276 => DPPTItem::ZoomLens,
-			277 => DPPTItem::Metronome,
-			278 => DPPTItem::IronBall,
-			279 => DPPTItem::LaggingTail,
-			280 => DPPTItem::DestinyKnot,
-			281 => DPPTItem::BlackSludge,
-			282 => DPPTItem::IcyRock,
-			283 => DPPTItem::SmoothRock,
-			284 => DPPTItem::HeatRock,
-			285 => DPPTItem::DampRock,
-			286 => DPPTItem::GripClaw,
-			287 => DPPTItem::ChoiceScarf,
-			288 => DPPTItem::StickyBarb,
-			289 => DPPTItem::PowerBracer,
-			290 => DPPTItem::PowerBelt,
-			291 => DPPTItem::PowerLens,
-			292 => DPPTItem::PowerBand,
-			293 => DPPTItem::PowerAnklet,
-			294 => DPPTItem::PowerWeight,
-			295 => DPPTItem::ShedShell,
-			296 => DPPTItem::BigRoot,
-			297 => DPPTItem::ChoiceSpecs,
-			298 => DPPTItem::FlamePlate,
-			299 => DPPTItem::SplashPlate,
-			300 => DPPTItem::ZapPlate,
-			301 => DPPTItem::MeadowPlate,
-			302 => DPPTItem::IciclePlate,
-			303 => DPPTItem::FistPlate,
-			304 => DPPTItem::ToxicPlate,
-			305 => DPPTItem::EarthPlate,
-			306 => DPPTItem::SkyPlate,
-			307 => DPPTItem::MindPlate,
-			308 => DPPTItem::InsectPlate,
-			309 => DPPTItem::StonePlate,
-			310 => DPPTItem::SpookyPlate,
-			311 => DPPTItem::DracoPlate,
-			312 => DPPTItem::DreadPlate,
-			313 => DPPTItem::IronPlate,
-			314 => DPPTItem::OddIncense,
-			315 => DPPTItem::RockIncense,
-			316 => DPPTItem::FullIncense,
-			317 => DPPTItem::WaveIncense,
-			318 => DPPTItem::RoseIncense,
-			319 => DPPTItem::LuckIncense,
-			320 => DPPTItem::PureIncense,
-			321 => DPPTItem::Protector,
-			322 => DPPTItem::Electirizer,
-			323 => DPPTItem::Magmarizer,
-			324 => DPPTItem::DubiousDisc,
-			325 => DPPTItem::ReaperCloth,
-			326 => DPPTItem::RazorClaw,
-			327 => DPPTItem::RazorFang,
-			328 => DPPTItem::Tm01,
-			329 => DPPTItem::Tm02,
-			330 => DPPTItem::Tm03,
-			331 => DPPTItem::Tm04,
-			332 => DPPTItem::Tm05,
-			333 => DPPTItem::Tm06,
-			334 => DPPTItem::Tm07,
-			335 => DPPTItem::Tm08,
-			336 => DPPTItem::Tm09,
-			337 => DPPTItem::Tm10,
-			338 => DPPTItem::Tm11,
-			339 => DPPTItem::Tm12,
-			340 => DPPTItem::Tm13,
-			341 => DPPTItem::Tm14,
-			342 => DPPTItem::Tm15,
-			343 => DPPTItem::Tm16,
-			344 => DPPTItem::Tm17,
-			345 => DPPTItem::Tm18,
-			346 => DPPTItem::Tm19,
-			347 => DPPTItem::Tm20,
-			348 => DPPTItem::Tm21,
-			349 => DPPTItem::Tm22,
-			350 => DPPTItem::Tm23,
-			351 => DPPTItem::Tm24,
-			352 => DPPTItem::Tm25,
-			353 => DPPTItem::Tm26,
-			354 => DPPTItem::Tm27,
-			355 => DPPTItem::Tm28,
-			356 => DPPTItem::Tm29,
-			357 => DPPTItem::Tm30,
-			358 => DPPTItem::Tm31,
-			359 => DPPTItem::Tm32,
-			360 => DPPTItem::Tm33,
-			361 => DPPTItem::Tm34,
-			362 => DPPTItem::Tm35,
-			363 => DPPTItem::Tm36,
-			364 => DPPTItem::Tm37,
-			365 => DPPTItem::Tm38,
-			366 => DPPTItem::Tm39,
-			367 => DPPTItem::Tm40,
-			368 => DPPTItem::Tm41,
-			369 => DPPTItem::Tm42,
-			370 => DPPTItem::Tm43,
-			371 => DPPTItem::Tm44,
-			372 => DPPTItem::Tm45,
-			373 => DPPTItem::Tm46,
-			374 => DPPTItem::Tm47,
-			375 => DPPTItem::Tm48,
-			376 => DPPTItem::Tm49,
-			377 => DPPTItem::Tm50,
-			378 => DPPTItem::Tm51,
-			379 => DPPTItem::Tm52,
-			380 => DPPTItem::Tm53,
-			381 => DPPTItem::Tm54,
-			382 => DPPTItem::Tm55,
-			383 => DPPTItem::Tm56,
-			384 => DPPTItem::Tm57,
-			385 => DPPTItem::Tm58,
-			386 => DPPTItem::Tm59,
-			387 => DPPTItem::Tm60,
-			388 => DPPTItem::Tm61,
-			389 => DPPTItem::Tm62,
-			390 => DPPTItem::Tm63,
-			391 => DPPTItem::Tm64,
-			392 => DPPTItem::Tm65,
-			393 => DPPTItem::Tm66,
-			394 => DPPTItem::Tm67,
-			395 => DPPTItem::Tm68,
-			396 => DPPTItem::Tm69,
-			397 => DPPTItem::Tm70,
-			398 => DPPTItem::Tm71,
-			399 => DPPTItem::Tm72,
-			400 => DPPTItem::Tm73,
-			401 => DPPTItem::Tm74,
-			402 => DPPTItem::Tm75,
-			403 => DPPTItem::Tm76,
-			404 => DPPTItem::Tm77,
-			405 => DPPTItem::Tm78,
-			406 => DPPTItem::Tm79,
-			407 => DPPTItem::Tm80,
-			408 => DPPTItem::Tm81,
-			409 => DPPTItem::Tm82,
-			410 => DPPTItem::Tm83,
-			411 => DPPTItem::Tm84,
-			412 => DPPTItem::Tm85,
-			413 => DPPTItem::Tm86,
-			414 => DPPTItem::Tm87,
-			415 => DPPTItem::Tm88,
-			416 => DPPTItem::Tm89,
-			417 => DPPTItem::Tm90,
-			418 => DPPTItem::Tm91,
-			419 => DPPTItem::Tm92,
-			420 => DPPTItem::Hm01,
-			421 => DPPTItem::Hm02,
-			422 => DPPTItem::Hm03,
-			423 => DPPTItem::Hm04,
-			424 => DPPTItem::Hm05,
-			425 => DPPTItem::Hm06,
-			426 => DPPTItem::Hm07,
-			427 => DPPTItem::Hm08,
-			428 => DPPTItem::ExplorerKit,
-			429 => DPPTItem::LootSack,
-			430 => DPPTItem::RuleBook,
-			431 => DPPTItem::PokeRadar,
-			432 => DPPTItem::PointCard,
-			433 => DPPTItem::Journal,
-			434 => DPPTItem::SealCase,
-			435 => DPPTItem::FashionCase,
-			436 => DPPTItem::SealBag,
-			437 => DPPTItem::PalPad,
-			438 => DPPTItem::WorksKey,
-			439 => DPPTItem::OldCharm,
-			440 => DPPTItem::GalacticKey,
-			441 => DPPTItem::RedChain,
-			442 => DPPTItem::TownMap,
-			443 => DPPTItem::VsSeeker,
-			444 => DPPTItem::CoinCase,
-			445 => DPPTItem::OldRod,
-			446 => DPPTItem::GoodRod,
-			447 => DPPTItem::SuperRod,
-			448 => DPPTItem::Sprayduck,
-			449 => DPPTItem::PoffinCase,
-			450 => DPPTItem::Bicycle,
-			451 => DPPTItem::SuiteKey,
-			452 => DPPTItem::OaksLetter,
-			453 => DPPTItem::LunarWing,
-			454 => DPPTItem::MemberCard,
-			455 => DPPTItem::AzureFlute,
-			456 => DPPTItem::SsTicket,
-			457 => DPPTItem::ContestPass,
-			458 => DPPTItem::MagmaStone,
-			459 => DPPTItem::Parcel,
-			460 => DPPTItem::Coupon1,
-			461 => DPPTItem::Coupon2,
-			462 => DPPTItem::Coupon3,
-			463 => DPPTItem::StorageKey,
-			464 => DPPTItem::Secretpotion,
-			465 => DPPTItem::VsRecorder,
-			466 => DPPTItem::Gracidea,
-			467 => DPPTItem::SecretKey,
-			468 => DPPTItem::ApricornBox,
-			469 => DPPTItem::UnownReport,
-			470 => DPPTItem::BerryPots,
-			471 => DPPTItem::DowsingMchn,
-			472 => DPPTItem::BlueCard,
-			473 => DPPTItem::Slowpoketail,
-			474 => DPPTItem::ClearBell,
-			475 => DPPTItem::CardKey,
-			476 => DPPTItem::BasementKey,
-			477 => DPPTItem::Squirtbottle,
-			478 => DPPTItem::RedScale,
-			479 => DPPTItem::LostItem,
-			480 => DPPTItem::Pass,
-			481 => DPPTItem::MachinePart,
-			482 => DPPTItem::SilverWing,
-			483 => DPPTItem::RainbowWing,
-			484 => DPPTItem::MysteryEgg,
-			485 => DPPTItem::RedApricorn,
-			486 => DPPTItem::YlwApricorn,
-			487 => DPPTItem::BluApricorn,
-			488 => DPPTItem::GrnApricorn,
-			489 => DPPTItem::PnkApricorn,
-			490 => DPPTItem::WhtApricorn,
-			491 => DPPTItem::BlkApricorn,
-			492 => DPPTItem::FastBall,
-			493 => DPPTItem::LevelBall,
-			494 => DPPTItem::LureBall,
-			495 => DPPTItem::HeavyBall,
-			496 => DPPTItem::LoveBall,
-			497 => DPPTItem::FriendBall,
-			498 => DPPTItem::MoonBall,
-			499 => DPPTItem::SportBall,
-			500 => DPPTItem::ParkBall,
-			501 => DPPTItem::PhotoAlbum,
-			502 => DPPTItem::GbSounds,
-			503 => DPPTItem::TidalBell,
-			504 => DPPTItem::Ragecandybar,
-			505 => DPPTItem::DataCard01,
-			506 => DPPTItem::DataCard02,
-			507 => DPPTItem::DataCard03,
-			508 => DPPTItem::DataCard04,
-			509 => DPPTItem::DataCard05,
-			510 => DPPTItem::DataCard06,
-			511 => DPPTItem::DataCard07,
-			512 => DPPTItem::DataCard08,
-			513 => DPPTItem::DataCard09,
-			514 => DPPTItem::DataCard10,
-			515 => DPPTItem::DataCard11,
-			516 => DPPTItem::DataCard12,
-			517 => DPPTItem::DataCard13,
-			518 => DPPTItem::DataCard14,
-			519 => DPPTItem::DataCard15,
-			520 => DPPTItem::DataCard16,
-			521 => DPPTItem::DataCard17,
-			522 => DPPTItem::DataCard18,
-			523 => DPPTItem::DataCard19,
-			524 => DPPTItem::DataCard20,
-			525 => DPPTItem::DataCard21,
-			526 => DPPTItem::DataCard22,
-			527 => DPPTItem::DataCard23,
-			528 => DPPTItem::DataCard24,
-			529 => DPPTItem::DataCard25,
-			530 => DPPTItem::DataCard26,
-			531 => DPPTItem::DataCard27,
-			532 => DPPTItem::JadeOrb,
-			533 => DPPTItem::LockCapsule,
-			534 => DPPTItem::RedOrb,
-			535 => DPPTItem::BlueOrb,
-			536 => DPPTItem::EnigmaStone,
-            _ => unreachable!()
+			DPPTItem::None => 0,
+			DPPTItem::MasterBall => 1,
+			DPPTItem::UltraBall => 2,
+			DPPTItem::GreatBall => 3,
+			DPPTItem::PokeBall => 4,
+			DPPTItem::SafariBall => 5,
+			DPPTItem::NetBall => 6,
+			DPPTItem::DiveBall => 7,
+			DPPTItem::NestBall => 8,
+			DPPTItem::RepeatBall => 9,
+			DPPTItem::TimerBall => 10,
+			DPPTItem::LuxuryBall => 11,
+			DPPTItem::PremierBall => 12,
+			DPPTItem::DuskBall => 13,
+			DPPTItem::HealBall => 14,
+			DPPTItem::QuickBall => 15,
+			DPPTItem::CherishBall => 16,
+			DPPTItem::Potion => 17,
+			DPPTItem::Antidote => 18,
+			DPPTItem::BurnHeal => 19,
+			DPPTItem::IceHeal => 20,
+			DPPTItem::Awakening => 21,
+			DPPTItem::ParlyzHeal => 22,
+			DPPTItem::FullRestore => 23,
+			DPPTItem::MaxPotion => 24,
+			DPPTItem::HyperPotion => 25,
+			DPPTItem::SuperPotion => 26,
+			DPPTItem::FullHeal => 27,
+			DPPTItem::Revive => 28,
+			DPPTItem::MaxRevive => 29,
+			DPPTItem::FreshWater => 30,
+			DPPTItem::SodaPop => 31,
+			DPPTItem::Lemonade => 32,
+			DPPTItem::MoomooMilk => 33,
+			DPPTItem::Energypowder => 34,
+			DPPTItem::EnergyRoot => 35,
+			DPPTItem::HealPowder => 36,
+			DPPTItem::RevivalHerb => 37,
+			DPPTItem::Ether => 38,
+			DPPTItem::MaxEther => 39,
+			DPPTItem::Elixir => 40,
+			DPPTItem::MaxElixir => 41,
+			DPPTItem::LavaCookie => 42,
+			DPPTItem::BerryJuice => 43,
+			DPPTItem::SacredAsh => 44,
+			DPPTItem::HpUp => 45,
+			DPPTItem::Protein => 46,
+			DPPTItem::Iron => 47,
+			DPPTItem::Carbos => 48,
+			DPPTItem::Calcium => 49,
+			DPPTItem::RareCandy => 50,
+			DPPTItem::PpUp => 51,
+			DPPTItem::Zinc => 52,
+			DPPTItem::PpMax => 53,
+			DPPTItem::OldGateau => 54,
+			DPPTItem::GuardSpec => 55,
+			DPPTItem::DireHit => 56,
+			DPPTItem::XAttack => 57,
+			DPPTItem::XDefense => 58,
+			DPPTItem::XSpeed => 59,
+			DPPTItem::XAccuracy => 60,
+			DPPTItem::XSpecial => 61,
+			DPPTItem::XSpDef => 62,
+			DPPTItem::PokeDoll => 63,
+			DPPTItem::FluffyTail => 64,
+			DPPTItem::BlueFlute => 65,
+			DPPTItem::YellowFlute => 66,
+			DPPTItem::RedFlute => 67,
+			DPPTItem::BlackFlute => 68,
+			DPPTItem::WhiteFlute => 69,
+			DPPTItem::ShoalSalt => 70,
+			DPPTItem::ShoalShell => 71,
+			DPPTItem::RedShard => 72,
+			DPPTItem::BlueShard => 73,
+			DPPTItem::YellowShard => 74,
+			DPPTItem::GreenShard => 75,
+			DPPTItem::SuperRepel => 76,
+			DPPTItem::MaxRepel => 77,
+			DPPTItem::EscapeRope => 78,
+			DPPTItem::Repel => 79,
+			DPPTItem::SunStone => 80,
+			DPPTItem::MoonStone => 81,
+			DPPTItem::FireStone => 82,
+			DPPTItem::Thunderstone => 83,
+			DPPTItem::WaterStone => 84,
+			DPPTItem::LeafStone => 85,
+			DPPTItem::Tinymushroom => 86,
+			DPPTItem::BigMushroom => 87,
+			DPPTItem::Pearl => 88,
+			DPPTItem::BigPearl => 89,
+			DPPTItem::Stardust => 90,
+			DPPTItem::StarPiece => 91,
+			DPPTItem::Nugget => 92,
+			DPPTItem::HeartScale => 93,
+			DPPTItem::Honey => 94,
+			DPPTItem::GrowthMulch => 95,
+			DPPTItem::DampMulch => 96,
+			DPPTItem::StableMulch => 97,
+			DPPTItem::GooeyMulch => 98,
+			DPPTItem::RootFossil => 99,
+			DPPTItem::ClawFossil => 100,
+			DPPTItem::HelixFossil => 101,
+			DPPTItem::DomeFossil => 102,
+			DPPTItem::OldAmber => 103,
+			DPPTItem::ArmorFossil => 104,
+			DPPTItem::SkullFossil => 105,
+			DPPTItem::RareBone => 106,
+			DPPTItem::ShinyStone => 107,
+			DPPTItem::DuskStone => 108,
+			DPPTItem::DawnStone => 109,
+			DPPTItem::OvalStone => 110,
+			DPPTItem::OddKeystone => 111,
+			DPPTItem::GriseousOrb => 112,
+			DPPTItem::AdamantOrb => 135,
+			DPPTItem::LustrousOrb => 136,
+			DPPTItem::GrassMail => 137,
+			DPPTItem::FlameMail => 138,
+			DPPTItem::BubbleMail => 139,
+			DPPTItem::BloomMail => 140,
+			DPPTItem::TunnelMail => 141,
+			DPPTItem::SteelMail => 142,
+			DPPTItem::HeartMail => 143,
+			DPPTItem::SnowMail => 144,
+			DPPTItem::SpaceMail => 145,
+			DPPTItem::AirMail => 146,
+			DPPTItem::MosaicMail => 147,
+			DPPTItem::BrickMail => 148,
+			DPPTItem::CheriBerry => 149,
+			DPPTItem::ChestoBerry => 150,
+			DPPTItem::PechaBerry => 151,
+			DPPTItem::RawstBerry => 152,
+			DPPTItem::AspearBerry => 153,
+			DPPTItem::LeppaBerry => 154,
+			DPPTItem::OranBerry => 155,
+			DPPTItem::PersimBerry => 156,
+			DPPTItem::LumBerry => 157,
+			DPPTItem::SitrusBerry => 158,
+			DPPTItem::FigyBerry => 159,
+			DPPTItem::WikiBerry => 160,
+			DPPTItem::MagoBerry => 161,
+			DPPTItem::AguavBerry => 162,
+			DPPTItem::IapapaBerry => 163,
+			DPPTItem::RazzBerry => 164,
+			DPPTItem::BlukBerry => 165,
+			DPPTItem::NanabBerry => 166,
+			DPPTItem::WepearBerry => 167,
+			DPPTItem::PinapBerry => 168,
+			DPPTItem::PomegBerry => 169,
+			DPPTItem::KelpsyBerry => 170,
+			DPPTItem::QualotBerry => 171,
+			DPPTItem::HondewBerry => 172,
+			DPPTItem::GrepaBerry => 173,
+			DPPTItem::TamatoBerry => 174,
+			DPPTItem::CornnBerry => 175,
+			DPPTItem::MagostBerry => 176,
+			DPPTItem::RabutaBerry => 177,
+			DPPTItem::NomelBerry => 178,
+			DPPTItem::SpelonBerry => 179,
+			DPPTItem::PamtreBerry => 180,
+			DPPTItem::WatmelBerry => 181,
+			DPPTItem::DurinBerry => 182,
+			DPPTItem::BelueBerry => 183,
+			DPPTItem::OccaBerry => 184,
+			DPPTItem::PasshoBerry => 185,
+			DPPTItem::WacanBerry => 186,
+			DPPTItem::RindoBerry => 187,
+			DPPTItem::YacheBerry => 188,
+			DPPTItem::ChopleBerry => 189,
+			DPPTItem::KebiaBerry => 190,
+			DPPTItem::ShucaBerry => 191,
+			DPPTItem::CobaBerry => 192,
+			DPPTItem::PayapaBerry => 193,
+			DPPTItem::TangaBerry => 194,
+			DPPTItem::ChartiBerry => 195,
+			DPPTItem::KasibBerry => 196,
+			DPPTItem::HabanBerry => 197,
+			DPPTItem::ColburBerry => 198,
+			DPPTItem::BabiriBerry => 199,
+			DPPTItem::ChilanBerry => 200,
+			DPPTItem::LiechiBerry => 201,
+			DPPTItem::GanlonBerry => 202,
+			DPPTItem::SalacBerry => 203,
+			DPPTItem::PetayaBerry => 204,
+			DPPTItem::ApicotBerry => 205,
+			DPPTItem::LansatBerry => 206,
+			DPPTItem::StarfBerry => 207,
+			DPPTItem::EnigmaBerry => 208,
+			DPPTItem::MicleBerry => 209,
+			DPPTItem::CustapBerry => 210,
+			DPPTItem::JabocaBerry => 211,
+			DPPTItem::RowapBerry => 212,
+			DPPTItem::Brightpowder => 213,
+			DPPTItem::WhiteHerb => 214,
+			DPPTItem::MachoBrace => 215,
+			DPPTItem::ExpShare => 216,
+			DPPTItem::QuickClaw => 217,
+			DPPTItem::SootheBell => 218,
+			DPPTItem::MentalHerb => 219,
+			DPPTItem::ChoiceBand => 220,
+			DPPTItem::KingsRock => 221,
+			DPPTItem::Silverpowder => 222,
+			DPPTItem::AmuletCoin => 223,
+			DPPTItem::CleanseTag => 224,
+			DPPTItem::SoulDew => 225,
+			DPPTItem::Deepseatooth => 226,
+			DPPTItem::Deepseascale => 227,
+			DPPTItem::SmokeBall => 228,
+			DPPTItem::Everstone => 229,
+			DPPTItem::FocusBand => 230,
+			DPPTItem::LuckyEgg => 231,
+			DPPTItem::ScopeLens => 232,
+			DPPTItem::MetalCoat => 233,
+			DPPTItem::Leftovers => 234,
+			DPPTItem::DragonScale => 235,
+			DPPTItem::LightBall => 236,
+			DPPTItem::SoftSand => 237,
+			DPPTItem::HardStone => 238,
+			DPPTItem::MiracleSeed => 239,
+			DPPTItem::Blackglasses => 240,
+			DPPTItem::BlackBelt => 241,
+			DPPTItem::Magnet => 242,
+			DPPTItem::MysticWater => 243,
+			DPPTItem::SharpBeak => 244,
+			DPPTItem::PoisonBarb => 245,
+			DPPTItem::Nevermeltice => 246,
+			DPPTItem::SpellTag => 247,
+			DPPTItem::Twistedspoon => 248,
+			DPPTItem::Charcoal => 249,
+			DPPTItem::DragonFang => 250,
+			DPPTItem::SilkScarf => 251,
+			DPPTItem::Upgrade => 252,
+			DPPTItem::ShellBell => 253,
+			DPPTItem::SeaIncense => 254,
+			DPPTItem::LaxIncense => 255,
+			DPPTItem::LuckyPunch => 256,
+			DPPTItem::MetalPowder => 257,
+			DPPTItem::ThickClub => 258,
+			DPPTItem::Stick => 259,
+			DPPTItem::RedScarf => 260,
+			DPPTItem::BlueScarf => 261,
+			DPPTItem::PinkScarf => 262,
+			DPPTItem::GreenScarf => 263,
+			DPPTItem::YellowScarf => 264,
+			DPPTItem::WideLens => 265,
+			DPPTItem::MuscleBand => 266,
+			DPPTItem::WiseGlasses => 267,
+			DPPTItem::ExpertBelt => 268,
+			DPPTItem::LightClay => 269,
+			DPPTItem::LifeOrb => 270,
+			DPPTItem::PowerHerb => 271,
+			DPPTItem::ToxicOrb => 272,
+			DPPTItem::FlameOrb => 273,
+			DPPTItem::QuickPowder => 274,
+			DPPTItem::FocusSash => 275,
+			DPPTItem::ZoomLens => 276,
+			DPPTItem::Metronome => 277,
+			DPPTItem::IronBall => 278,
+			DPPTItem::LaggingTail => 279,
+			DPPTItem::DestinyKnot => 280,
+			DPPTItem::BlackSludge => 281,
+			DPPTItem::IcyRock => 282,
+			DPPTItem::SmoothRock => 283,
+			DPPTItem::HeatRock => 284,
+			DPPTItem::DampRock => 285,
+			DPPTItem::GripClaw => 286,
+			DPPTItem::ChoiceScarf => 287,
+			DPPTItem::StickyBarb => 288,
+			DPPTItem::PowerBracer => 289,
+			DPPTItem::PowerBelt => 290,
+			DPPTItem::PowerLens => 291,
+			DPPTItem::PowerBand => 292,
+			DPPTItem::PowerAnklet => 293,
+			DPPTItem::PowerWeight => 294,
+			DPPTItem::ShedShell => 295,
+			DPPTItem::BigRoot => 296,
+			DPPTItem::ChoiceSpecs => 297,
+			DPPTItem::FlamePlate => 298,
+			DPPTItem::SplashPlate => 299,
+			DPPTItem::ZapPlate => 300,
+			DPPTItem::MeadowPlate => 301,
+			DPPTItem::IciclePlate => 302,
+			DPPTItem::FistPlate => 303,
+			DPPTItem::ToxicPlate => 304,
+			DPPTItem::EarthPlate => 305,
+			DPPTItem::SkyPlate => 306,
+			DPPTItem::MindPlate => 307,
+			DPPTItem::InsectPlate => 308,
+			DPPTItem::StonePlate => 309,
+			DPPTItem::SpookyPlate => 310,
+			DPPTItem::DracoPlate => 311,
+			DPPTItem::DreadPlate => 312,
+			DPPTItem::IronPlate => 313,
+			DPPTItem::OddIncense => 314,
+			DPPTItem::RockIncense => 315,
+			DPPTItem::FullIncense => 316,
+			DPPTItem::WaveIncense => 317,
+			DPPTItem::RoseIncense => 318,
+			DPPTItem::LuckIncense => 319,
+			DPPTItem::PureIncense => 320,
+			DPPTItem::Protector => 321,
+			DPPTItem::Electirizer => 322,
+			DPPTItem::Magmarizer => 323,
+			DPPTItem::DubiousDisc => 324,
+			DPPTItem::ReaperCloth => 325,
+			DPPTItem::RazorClaw => 326,
+			DPPTItem::RazorFang => 327,
+			DPPTItem::Tm01 => 328,
+			DPPTItem::Tm02 => 329,
+			DPPTItem::Tm03 => 330,
+			DPPTItem::Tm04 => 331,
+			DPPTItem::Tm05 => 332,
+			DPPTItem::Tm06 => 333,
+			DPPTItem::Tm07 => 334,
+			DPPTItem::Tm08 => 335,
+			DPPTItem::Tm09 => 336,
+			DPPTItem::Tm10 => 337,
+			DPPTItem::Tm11 => 338,
+			DPPTItem::Tm12 => 339,
+			DPPTItem::Tm13 => 340,
+			DPPTItem::Tm14 => 341,
+			DPPTItem::Tm15 => 342,
+			DPPTItem::Tm16 => 343,
+			DPPTItem::Tm17 => 344,
+			DPPTItem::Tm18 => 345,
+			DPPTItem::Tm19 => 346,
+			DPPTItem::Tm20 => 347,
+			DPPTItem::Tm21 => 348,
+			DPPTItem::Tm22 => 349,
+			DPPTItem::Tm23 => 350,
+			DPPTItem::Tm24 => 351,
+			DPPTItem::Tm25 => 352,
+			DPPTItem::Tm26 => 353,
+			DPPTItem::Tm27 => 354,
+			DPPTItem::Tm28 => 355,
+			DPPTItem::Tm29 => 356,
+			DPPTItem::Tm30 => 357,
+			DPPTItem::Tm31 => 358,
+			DPPTItem::Tm32 => 359,
+			DPPTItem::Tm33 => 360,
+			DPPTItem::Tm34 => 361,
+			DPPTItem::Tm35 => 362,
+			DPPTItem::Tm36 => 363,
+			DPPTItem::Tm37 => 364,
+			DPPTItem::Tm38 => 365,
+			DPPTItem::Tm39 => 366,
+			DPPTItem::Tm40 => 367,
+			DPPTItem::Tm41 => 368,
+			DPPTItem::Tm42 => 369,
+			DPPTItem::Tm43 => 370,
+			DPPTItem::Tm44 => 371,
+			DPPTItem::Tm45 => 372,
+			DPPTItem::Tm46 => 373,
+			DPPTItem::Tm47 => 374,
+			DPPTItem::Tm48 => 375,
+			DPPTItem::Tm49 => 376,
+			DPPTItem::Tm50 => 377,
+			DPPTItem::Tm51 => 378,
+			DPPTItem::Tm52 => 379,
+			DPPTItem::Tm53 => 380,
+			DPPTItem::Tm54 => 381,
+			DPPTItem::Tm55 => 382,
+			DPPTItem::Tm56 => 383,
+			DPPTItem::Tm57 => 384,
+			DPPTItem::Tm58 => 385,
+			DPPTItem::Tm59 => 386,
+			DPPTItem::Tm60 => 387,
+			DPPTItem::Tm61 => 388,
+			DPPTItem::Tm62 => 389,
+			DPPTItem::Tm63 => 390,
+			DPPTItem::Tm64 => 391,
+			DPPTItem::Tm65 => 392,
+			DPPTItem::Tm66 => 393,
+			DPPTItem::Tm67 => 394,
+			DPPTItem::Tm68 => 395,
+			DPPTItem::Tm69 => 396,
+			DPPTItem::Tm70 => 397,
+			DPPTItem::Tm71 => 398,
+			DPPTItem::Tm72 => 399,
+			DPPTItem::Tm73 => 400,
+			DPPTItem::Tm74 => 401,
+			DPPTItem::Tm75 => 402,
+			DPPTItem::Tm76 => 403,
+			DPPTItem::Tm77 => 404,
+			DPPTItem::Tm78 => 405,
+			DPPTItem::Tm79 => 406,
+			DPPTItem::Tm80 => 407,
+			DPPTItem::Tm81 => 408,
+			DPPTItem::Tm82 => 409,
+			DPPTItem::Tm83 => 410,
+			DPPTItem::Tm84 => 411,
+			DPPTItem::Tm85 => 412,
+			DPPTItem::Tm86 => 413,
+			DPPTItem::Tm87 => 414,
+			DPPTItem::Tm88 => 415,
+			DPPTItem::Tm89 => 416,
+			DPPTItem::Tm90 => 417,
+			DPPTItem::Tm91 => 418,
+			DPPTItem::Tm92 => 419,
+			DPPTItem::Hm01 => 420,
+			DPPTItem::Hm02 => 421,
+			DPPTItem::Hm03 => 422,
+			DPPTItem::Hm04 => 423,
+			DPPTItem::Hm05 => 424,
+			DPPTItem::Hm06 => 425,
+			DPPTItem::Hm07 => 426,
+			DPPTItem::Hm08 => 427,
+			DPPTItem::ExplorerKit => 428,
+			DPPTItem::LootSack => 429,
+			DPPTItem::RuleBook => 430,
+			DPPTItem::PokeRadar => 431,
+			DPPTItem::PointCard => 432,
+			DPPTItem::Journal => 433,
+			DPPTItem::SealCase => 434,
+			DPPTItem::FashionCase => 435,
+			DPPTItem::SealBag => 436,
+			DPPTItem::PalPad => 437,
+			DPPTItem::WorksKey => 438,
+			DPPTItem::OldCharm => 439,
+			DPPTItem::GalacticKey => 440,
+			DPPTItem::RedChain => 441,
+			DPPTItem::TownMap => 442,
+			DPPTItem::VsSeeker => 443,
+			DPPTItem::CoinCase => 444,
+			DPPTItem::OldRod => 445,
+			DPPTItem::GoodRod => 446,
+			DPPTItem::SuperRod => 447,
+			DPPTItem::Sprayduck => 448,
+			DPPTItem::PoffinCase => 449,
+			DPPTItem::Bicycle => 450,
+			DPPTItem::SuiteKey => 451,
+			DPPTItem::OaksLetter => 452,
+			DPPTItem::LunarWing => 453,
+			DPPTItem::MemberCard => 454,
+			DPPTItem::AzureFlute => 455,
+			DPPTItem::SsTicket => 456,
+			DPPTItem::ContestPass => 457,
+			DPPTItem::MagmaStone => 458,
+			DPPTItem::Parcel => 459,
+			DPPTItem::Coupon1 => 460,
+			DPPTItem::Coupon2 => 461,
+			DPPTItem::Coupon3 => 462,
+			DPPTItem::StorageKey => 463,
+			DPPTItem::Secretpotion => 464,
+			DPPTItem::VsRecorder => 465,
+			DPPTItem::Gracidea => 466,
+			DPPTItem::SecretKey => 467,
+			DPPTItem::ApricornBox => 468,
+			DPPTItem::UnownReport => 469,
+			DPPTItem::BerryPots => 470,
+			DPPTItem::DowsingMchn => 471,
+			DPPTItem::BlueCard => 472,
+			DPPTItem::Slowpoketail => 473,
+			DPPTItem::ClearBell => 474,
+			DPPTItem::CardKey => 475,
+			DPPTItem::BasementKey => 476,
+			DPPTItem::Squirtbottle => 477,
+			DPPTItem::RedScale => 478,
+			DPPTItem::LostItem => 479,
+			DPPTItem::Pass => 480,
+			DPPTItem::MachinePart => 481,
+			DPPTItem::SilverWing => 482,
+			DPPTItem::RainbowWing => 483,
+			DPPTItem::MysteryEgg => 484,
+			DPPTItem::RedApricorn => 485,
+			DPPTItem::YlwApricorn => 486,
+			DPPTItem::BluApricorn => 487,
+			DPPTItem::GrnApricorn => 488,
+			DPPTItem::PnkApricorn => 489,
+			DPPTItem::WhtApricorn => 490,
+			DPPTItem::BlkApricorn => 491,
+			DPPTItem::FastBall => 492,
+			DPPTItem::LevelBall => 493,
+			DPPTItem::LureBall => 494,
+			DPPTItem::HeavyBall => 495,
+			DPPTItem::LoveBall => 496,
+			DPPTItem::FriendBall => 497,
+			DPPTItem::MoonBall => 498,
+			DPPTItem::SportBall => 499,
+			DPPTItem::ParkBall => 500,
+			DPPTItem::PhotoAlbum => 501,
+			DPPTItem::GbSounds => 502,
+			DPPTItem::TidalBell => 503,
+			DPPTItem::Ragecandybar => 504,
+			DPPTItem::DataCard01 => 505,
+			DPPTItem::DataCard02 => 506,
+			DPPTItem::DataCard03 => 507,
+			DPPTItem::DataCard04 => 508,
+			DPPTItem::DataCard05 => 509,
+			DPPTItem::DataCard06 => 510,
+			DPPTItem::DataCard07 => 511,
+			DPPTItem::DataCard08 => 512,
+			DPPTItem::DataCard09 => 513,
+			DPPTItem::DataCard10 => 514,
+			DPPTItem::DataCard11 => 515,
+			DPPTItem::DataCard12 => 516,
+			DPPTItem::DataCard13 => 517,
+			DPPTItem::DataCard14 => 518,
+			DPPTItem::DataCard15 => 519,
+			DPPTItem::DataCard16 => 520,
+			DPPTItem::DataCard17 => 521,
+			DPPTItem::DataCard18 => 522,
+			DPPTItem::DataCard19 => 523,
+			DPPTItem::DataCard20 => 524,
+			DPPTItem::DataCard21 => 525,
+			DPPTItem::DataCard22 => 526,
+			DPPTItem::DataCard23 => 527,
+			DPPTItem::DataCard24 => 528,
+			DPPTItem::DataCard25 => 529,
+			DPPTItem::DataCard26 => 530,
+			DPPTItem::DataCard27 => 531,
+			DPPTItem::JadeOrb => 532,
+			DPPTItem::LockCapsule => 533,
+			DPPTItem::RedOrb => 534,
+			DPPTItem::BlueOrb => 535,
+			DPPTItem::EnigmaStone => 536,
+            DPPTItem::Unknown(value) => value,
         }
     }
 }
\ No newline at end of file
@@ -1,4 +1,5 @@
 pub mod item;
+pub mod pocket;
 
 pub mod enums {
     pokerus_macro::metang_enum!("./metafiles/gen4/platinum_vars.txt", u16, Vars);
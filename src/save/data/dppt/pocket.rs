@@ -0,0 +1,2 @@
+/// Gen 4 has every pocket, so its pockets are the ones every generation uses
+pub use crate::save::data::pocket::Pocket;
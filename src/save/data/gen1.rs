@@ -0,0 +1,61 @@
+use crate::save::data::species::Species;
+
+/// The national dex number of each gen 1 internal index, from 1; `0` is one of the 39 unused MissingNo.
+/// slots
+const INTERNAL_ORDER: [u16; 190] = [
+    112, 115, 32, 35, 21, 100, 34, 80, 2, 103, 108, 102, 88, 94, 29, 31, // 01-10
+    104, 111, 131, 59, 151, 130, 90, 72, 92, 123, 120, 9, 127, 114, 0, 0, // 11-20
+    58, 95, 22, 16, 79, 64, 75, 113, 67, 122, 106, 107, 24, 47, 54, 96, // 21-30
+    76, 0, 126, 0, 125, 82, 109, 0, 56, 86, 50, 128, 0, 0, 0, 83, // 31-40
+    48, 149, 0, 0, 0, 84, 60, 124, 146, 144, 145, 132, 52, 98, 0, 0, // 41-50
+    0, 37, 38, 25, 26, 0, 0, 147, 148, 140, 141, 116, 117, 0, 0, 27, // 51-60
+    28, 138, 139, 39, 40, 133, 136, 135, 134, 66, 41, 23, 46, 61, 62, 13, // 61-70
+    14, 15, 0, 85, 57, 51, 49, 87, 0, 0, 10, 11, 12, 68, 0, 55, // 71-80
+    97, 42, 150, 143, 129, 0, 0, 89, 0, 99, 91, 0, 101, 36, 110, 53, // 81-90
+    105, 0, 93, 63, 65, 17, 18, 121, 1, 3, 73, 0, 118, 119, 0, 0, // 91-A0
+    0, 0, 77, 78, 19, 20, 33, 30, 74, 137, 142, 0, 81, 0, 0, 4, // A1-B0
+    7, 5, 8, 6, 0, 0, 0, 0, 43, 44, 45, 69, 70, 71, // B1-BE
+];
+
+/// The species with the gen 1 internal index `index`, if there is one
+pub fn species_from_index(index: u8) -> Option<Species> {
+    match index {
+        0 => None,
+        _ => INTERNAL_ORDER.get(index as usize - 1)
+            .filter(|national| **national != 0)
+            .and_then(|national| Species::try_from(*national).ok()),
+    }
+}
+
+/// The gen 1 internal index of `species`, if gen 1 has it
+pub fn species_to_index(species: Species) -> Option<u8> {
+    let national = u16::from(species);
+    match national {
+        1..=151 => INTERNAL_ORDER.iter().position(|n| *n == national).map(|i| i as u8 + 1),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Are internal indexes mapped to the right national dex numbers, and back?
+    #[test]
+    fn convert_species_index() {
+        assert_eq!(species_from_index(0x01), Some(Species::Rhydon));
+        assert_eq!(species_from_index(0x15), Some(Species::Mew));
+        assert_eq!(species_from_index(0x54), Some(Species::Pikachu));
+        assert_eq!(species_from_index(0x99), Some(Species::Bulbasaur));
+        assert_eq!(species_from_index(0xBE), Some(Species::Victreebel));
+        assert_eq!(species_from_index(0x1F), None);
+        assert_eq!(species_from_index(0xBF), None);
+        assert_eq!(species_from_index(0), None);
+
+        for national in 1..=151 {
+            let species = Species::try_from(national).unwrap();
+            assert_eq!(species_to_index(species).and_then(species_from_index), Some(species));
+        }
+        assert_eq!(species_to_index(Species::Chikorita), None);
+    }
+}
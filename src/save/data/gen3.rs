@@ -0,0 +1,63 @@
+use crate::save::data::species::Species;
+
+/// The first internal index of a Hoenn Pokémon; the 25 before it are unused
+const HOENN_START: u16 = 277;
+
+/// Gen 3 stores Hoenn Pokémon in its own order, starting at `HOENN_START`, rather than by national dex number
+const HOENN_SPECIES: [Species; 135] = [
+    Species::Treecko, Species::Grovyle, Species::Sceptile, Species::Torchic, Species::Combusken, Species::Blaziken, Species::Mudkip, Species::Marshtomp,
+    Species::Swampert, Species::Poochyena, Species::Mightyena, Species::Zigzagoon, Species::Linoone, Species::Wurmple, Species::Silcoon, Species::Beautifly,
+    Species::Cascoon, Species::Dustox, Species::Lotad, Species::Lombre, Species::Ludicolo, Species::Seedot, Species::Nuzleaf, Species::Shiftry,
+    Species::Nincada, Species::Ninjask, Species::Shedinja, Species::Taillow, Species::Swellow, Species::Shroomish, Species::Breloom, Species::Spinda,
+    Species::Wingull, Species::Pelipper, Species::Surskit, Species::Masquerain, Species::Wailmer, Species::Wailord, Species::Skitty, Species::Delcatty,
+    Species::Kecleon, Species::Baltoy, Species::Claydol, Species::Nosepass, Species::Torkoal, Species::Sableye, Species::Barboach, Species::Whiscash,
+    Species::Luvdisc, Species::Corphish, Species::Crawdaunt, Species::Feebas, Species::Milotic, Species::Carvanha, Species::Sharpedo, Species::Trapinch,
+    Species::Vibrava, Species::Flygon, Species::Makuhita, Species::Hariyama, Species::Electrike, Species::Manectric, Species::Numel, Species::Camerupt,
+    Species::Spheal, Species::Sealeo, Species::Walrein, Species::Cacnea, Species::Cacturne, Species::Snorunt, Species::Glalie, Species::Lunatone,
+    Species::Solrock, Species::Azurill, Species::Spoink, Species::Grumpig, Species::Plusle, Species::Minun, Species::Mawile, Species::Meditite,
+    Species::Medicham, Species::Swablu, Species::Altaria, Species::Wynaut, Species::Duskull, Species::Dusclops, Species::Roselia, Species::Slakoth,
+    Species::Vigoroth, Species::Slaking, Species::Gulpin, Species::Swalot, Species::Tropius, Species::Whismur, Species::Loudred, Species::Exploud,
+    Species::Clamperl, Species::Huntail, Species::Gorebyss, Species::Absol, Species::Shuppet, Species::Banette, Species::Seviper, Species::Zangoose,
+    Species::Relicanth, Species::Aron, Species::Lairon, Species::Aggron, Species::Castform, Species::Volbeat, Species::Illumise, Species::Lileep,
+    Species::Cradily, Species::Anorith, Species::Armaldo, Species::Ralts, Species::Kirlia, Species::Gardevoir, Species::Bagon, Species::Shelgon,
+    Species::Salamence, Species::Beldum, Species::Metang, Species::Metagross, Species::Regirock, Species::Regice, Species::Registeel, Species::Kyogre,
+    Species::Groudon, Species::Rayquaza, Species::Latias, Species::Latios, Species::Jirachi, Species::Deoxys, Species::Chimecho,
+];
+
+/// The species with the gen 3 internal index `index`, if there is one
+pub fn species_from_index(index: u16) -> Option<Species> {
+    match index {
+        1..=251 => Species::try_from(index).ok(),
+        HOENN_START.. => HOENN_SPECIES.get((index - HOENN_START) as usize).copied(),
+        _ => None,
+    }
+}
+
+/// The gen 3 internal index of `species`, if gen 3 has it
+pub fn species_to_index(species: Species) -> Option<u16> {
+    match u16::from(species) {
+        national @ 1..=251 => Some(national),
+        _ => HOENN_SPECIES.iter().position(|s| *s == species).map(|i| i as u16 + HOENN_START),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Are internal indexes mapped to the right national dex numbers, and back?
+    #[test]
+    fn convert_species_index() {
+        assert_eq!(species_from_index(25), Some(Species::Pikachu));
+        assert_eq!(species_from_index(260), None);
+        assert_eq!(species_from_index(277), Some(Species::Treecko));
+        assert_eq!(species_from_index(411), Some(Species::Chimecho));
+        assert_eq!(species_from_index(412), None);
+
+        for national in 1..=386 {
+            let species = Species::try_from(national).unwrap();
+            assert_eq!(species_to_index(species).and_then(species_from_index), Some(species));
+        }
+        assert_eq!(species_to_index(Species::Turtwig), None);
+    }
+}
@@ -0,0 +1,74 @@
+use crate::save::data::species::Species;
+
+/// A Pokémon's gender, which unlike a trainer's can be neither
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PokemonGender {
+    Male,
+    Female,
+    Genderless,
+}
+
+impl PokemonGender {
+    /// The gender from bits 1-2 of a gen 4 or 5 Pokémon's form byte
+    pub(crate) fn from_bits(bits: u8) -> Self {
+        match bits & 0b110 {
+            0b000 => PokemonGender::Male,
+            0b010 => PokemonGender::Female,
+            _ => PokemonGender::Genderless,
+        }
+    }
+
+    /// Bits 1-2 of the form byte, see `from_bits`
+    #[cfg(feature = "write")]
+    pub(crate) fn to_bits(self) -> u8 {
+        match self {
+            PokemonGender::Male => 0b000,
+            PokemonGender::Female => 0b010,
+            PokemonGender::Genderless => 0b100,
+        }
+    }
+}
+
+/// How likely a species is to be female, as the threshold the lowest byte of a Pokémon's PID is compared
+/// to. `0` is always male, `254` always female & `255` genderless.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct GenderRatio(pub u8);
+
+impl GenderRatio {
+    pub const MALE_ONLY: GenderRatio = GenderRatio(0);
+    pub const FEMALE_ONLY: GenderRatio = GenderRatio(254);
+    pub const GENDERLESS: GenderRatio = GenderRatio(255);
+
+    /// The gender ratio of `species`, if it's from gen 1 to 4
+    pub fn of(species: Species) -> Option<GenderRatio> {
+        species.try_info().map(|info| info.gender_ratio)
+    }
+
+    /// The gender of a Pokémon of this species with `pid`
+    pub fn gender(self, pid: u32) -> PokemonGender {
+        match self {
+            GenderRatio::MALE_ONLY => PokemonGender::Male,
+            GenderRatio::FEMALE_ONLY => PokemonGender::Female,
+            GenderRatio::GENDERLESS => PokemonGender::Genderless,
+            GenderRatio(threshold) if (pid & 0xFF) < threshold as u32 => PokemonGender::Female,
+            _ => PokemonGender::Male,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Is the gender decided by the lowest byte of the PID?
+    #[test]
+    fn gender_from_pid() {
+        let piplup = GenderRatio::of(Species::Piplup).unwrap();
+        assert_eq!(piplup, GenderRatio(31));
+        assert_eq!(piplup.gender(0x1234_5600), PokemonGender::Female);
+        assert_eq!(piplup.gender(0x1234_561F), PokemonGender::Male);
+        assert_eq!(GenderRatio::of(Species::Chansey), Some(GenderRatio::FEMALE_ONLY));
+        assert_eq!(GenderRatio::of(Species::Rotom).unwrap().gender(0), PokemonGender::Genderless);
+        assert_eq!(GenderRatio::of(Species::Snivy), None);
+    }
+}
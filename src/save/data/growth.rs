@@ -0,0 +1,90 @@
+use crate::save::data::species::Species;
+
+/// How much experience a species needs for each level; see
+/// https://bulbapedia.bulbagarden.net/wiki/Experience#Relation_to_level
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum GrowthRate {
+    /// 600,000 experience at level 100
+    Erratic,
+    /// 800,000 experience at level 100
+    Fast,
+    /// 1,000,000 experience at level 100
+    MediumFast,
+    /// 1,059,860 experience at level 100
+    MediumSlow,
+    /// 1,250,000 experience at level 100
+    Slow,
+    /// 1,640,000 experience at level 100
+    Fluctuating,
+}
+
+impl GrowthRate {
+    /// The growth rate of `species`, if it's from gen 1 to 4
+    pub fn of(species: Species) -> Option<GrowthRate> {
+        species.try_info().map(|info| info.growth_rate)
+    }
+
+    /// The total experience needed to reach `level`, from 1 to 100
+    pub fn experience(self, level: u8) -> u32 {
+        let n = level.clamp(1, 100) as i64;
+        if n == 1 {
+            return 0;
+        }
+
+        let cube = n * n * n;
+        let experience = match self {
+            GrowthRate::Erratic => match n {
+                ..50 => cube * (100 - n) / 50,
+                50..68 => cube * (150 - n) / 100,
+                68..98 => cube * ((1911 - 10 * n) / 3) / 500,
+                _ => cube * (160 - n) / 100,
+            },
+            GrowthRate::Fast => 4 * cube / 5,
+            GrowthRate::MediumFast => cube,
+            GrowthRate::MediumSlow => 6 * cube / 5 - 15 * n * n + 100 * n - 140,
+            GrowthRate::Slow => 5 * cube / 4,
+            GrowthRate::Fluctuating => match n {
+                ..15 => cube * ((n + 1) / 3 + 24) / 50,
+                15..36 => cube * (n + 14) / 50,
+                _ => cube * (n / 2 + 32) / 50,
+            },
+        };
+        experience as u32
+    }
+
+    /// The level a Pokémon with `experience` is at, up to 100
+    pub fn level(self, experience: u32) -> u8 {
+        (2..=100).take_while(|level| self.experience(*level) <= experience).last().unwrap_or(1)
+    }
+
+    /// The most experience a Pokémon can have, i.e. the experience for level 100
+    pub fn max_experience(self) -> u32 {
+        self.experience(100)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use GrowthRate::{Erratic as E, Fast as Fa, Fluctuating as F, MediumFast as MF, MediumSlow as MS, Slow as S};
+
+    /// Does each curve give the experience in the games' tables?
+    #[test]
+    fn experience_curves() {
+        assert_eq!(GrowthRate::of(Species::Piplup), Some(GrowthRate::MediumSlow));
+        assert_eq!(GrowthRate::of(Species::Arceus), Some(GrowthRate::Slow));
+        assert_eq!(GrowthRate::of(Species::Snivy), None);
+
+        let max: Vec<u32> = [E, Fa, MF, MS, S, F].iter().map(|rate| rate.max_experience()).collect();
+        assert_eq!(max, [600_000, 800_000, 1_000_000, 1_059_860, 1_250_000, 1_640_000]);
+        assert_eq!(MS.experience(2), 9);
+        assert_eq!(MS.experience(5), 135);
+        assert_eq!(E.experience(70), 276_458);
+        assert_eq!(F.experience(10), 540);
+
+        assert_eq!(MS.level(0), 1);
+        assert_eq!(MS.level(134), 4);
+        assert_eq!(MS.level(135), 5);
+        assert_eq!(MF.level(u32::MAX), 100);
+    }
+}
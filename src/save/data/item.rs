@@ -0,0 +1,66 @@
+use crate::save::data::dppt::item::DPPTItem;
+use crate::save::data::pocket::Pocket;
+
+/// An item from any generation's bag
+///
+/// Gen 4 items are `DPPTItem`s. Gen 5 keeps gen 4's IDs and adds its own after them, but its pockets
+/// differ, so its items are kept by ID, as are older generations' which number their items differently.
+///
+/// # Examples
+///
+/// ```
+/// use pokerus::save::data::dppt::item::DPPTItem;
+/// use pokerus::save::data::pocket::Pocket;
+/// use pokerus::save::data::item::Item;
+/// let item = Item::from(DPPTItem::MasterBall);
+/// assert_eq!(item, Item::Gen4(DPPTItem::MasterBall));
+/// assert_eq!(item.pocket(), Some(Pocket::PokeBalls));
+/// assert_eq!(Item::Gen5(u16::from(DPPTItem::MasterBall)).pocket(), Some(Pocket::Items));
+/// assert_eq!(Item::Gen3(1).pocket(), Some(Pocket::PokeBalls));
+/// assert_eq!(Item::Gen1(1).pocket(), Some(Pocket::Items));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Item {
+    Gen4(DPPTItem),
+    /// A gen 5 item, by its ID
+    Gen5(u16),
+    /// A gen 3 item, by its gen 3 ID
+    Gen3(u16),
+    /// A gen 2 item, by its gen 2 ID
+    Gen2(u8),
+    /// A gen 1 item, by its gen 1 ID
+    Gen1(u8),
+}
+
+impl Item {
+    /// The most of one item a bag slot holds in gens 3 to 5
+    pub const MAX_QUANTITY: u16 = 999;
+
+    /// The pocket this item goes in, in its own generation's bag; `None` for unknown items
+    pub fn pocket(&self) -> Option<Pocket> {
+        match self {
+            Item::Gen4(item) => Pocket::of(*item),
+            Item::Gen5(id) => Pocket::of_gen5(*id),
+            Item::Gen3(id) => Pocket::of_gen3(*id),
+            Item::Gen2(id) => Pocket::of_gen2(*id),
+            // gen 1's bag is a single list
+            Item::Gen1(0) => None,
+            Item::Gen1(_) => Some(Pocket::Items),
+        }
+    }
+
+    /// The item's ID, in its own generation's numbering
+    pub fn id(&self) -> u16 {
+        match self {
+            Item::Gen4(item) => u16::from(*item),
+            Item::Gen5(id) | Item::Gen3(id) => *id,
+            Item::Gen2(id) | Item::Gen1(id) => *id as u16,
+        }
+    }
+}
+
+impl From<DPPTItem> for Item {
+    fn from(value: DPPTItem) -> Self {
+        Item::Gen4(value)
+    }
+}
@@ -1,2 +1,12 @@
 pub mod species;
-pub mod dppt;
\ No newline at end of file
+pub mod species_info;
+pub mod dppt;
+pub mod gen1;
+pub mod gen3;
+pub mod gender;
+pub mod growth;
+pub mod nature;
+pub mod stat;
+pub mod item;
+pub mod badge;
+pub mod pocket;
\ No newline at end of file
@@ -0,0 +1,95 @@
+use crate::save::data::stat::Stat;
+
+/// A Pokémon's nature, which raises one stat by 10% & lowers another by 10%. The five natures that
+/// would raise & lower the same stat change neither.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Nature {
+    Hardy,
+    Lonely,
+    Brave,
+    Adamant,
+    Naughty,
+    Bold,
+    Docile,
+    Relaxed,
+    Impish,
+    Lax,
+    Timid,
+    Hasty,
+    Serious,
+    Jolly,
+    Naive,
+    Modest,
+    Mild,
+    Quiet,
+    Bashful,
+    Rash,
+    Calm,
+    Gentle,
+    Sassy,
+    Careful,
+    Quirky,
+}
+
+/// Every nature, in the games' order
+const NATURES: [Nature; 25] = {
+    use Nature::*;
+    [
+        Hardy, Lonely, Brave, Adamant, Naughty,
+        Bold, Docile, Relaxed, Impish, Lax,
+        Timid, Hasty, Serious, Jolly, Naive,
+        Modest, Mild, Quiet, Bashful, Rash,
+        Calm, Gentle, Sassy, Careful, Quirky,
+    ]
+};
+
+/// The stats a nature can change, in the order natures cycle through them
+const NATURE_STATS: [Stat; 5] = [Stat::Attack, Stat::Defense, Stat::Speed, Stat::SpAttack, Stat::SpDefense];
+
+impl Nature {
+    /// The nature of a Pokémon with `pid`, which gens 3 & 4 don't store separately
+    pub fn from_pid(pid: u32) -> Self {
+        NATURES[(pid % 25) as usize]
+    }
+
+    /// The stat this nature raises, if it changes any
+    pub fn increased(self) -> Option<Stat> {
+        let index = self as usize;
+        (index / 5 != index % 5).then(|| NATURE_STATS[index / 5])
+    }
+
+    /// The stat this nature lowers, if it changes any
+    pub fn decreased(self) -> Option<Stat> {
+        let index = self as usize;
+        (index / 5 != index % 5).then(|| NATURE_STATS[index % 5])
+    }
+
+    /// `value` for `stat` with this nature applied, rounded down like the games
+    pub fn apply(self, stat: Stat, value: u16) -> u16 {
+        let value = value as u32;
+        let value = match self {
+            nature if nature.increased() == Some(stat) => value * 110 / 100,
+            nature if nature.decreased() == Some(stat) => value * 90 / 100,
+            _ => value,
+        };
+        value as u16
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Do natures come from the PID & change the right stats?
+    #[test]
+    fn natures() {
+        assert_eq!(Nature::from_pid(0), Nature::Hardy);
+        assert_eq!(Nature::from_pid(0x12345678), Nature::Gentle);
+        assert_eq!(Nature::Adamant.increased(), Some(Stat::Attack));
+        assert_eq!(Nature::Adamant.decreased(), Some(Stat::SpAttack));
+        assert_eq!(Nature::Serious.increased(), None);
+        assert_eq!(Nature::Modest.apply(Stat::SpAttack, 119), 130);
+        assert_eq!(Nature::Modest.apply(Stat::Attack, 119), 107);
+        assert_eq!(Nature::Modest.apply(Stat::Hp, 119), 119);
+    }
+}
@@ -0,0 +1,119 @@
+use crate::save::data::dppt::item::DPPTItem;
+
+/// A bag pocket. Gen 4 has every one of them; other generations' bags have some of them, and keep the
+/// rest of their items in `Pocket::Items`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Pocket {
+    Items,
+    KeyItems,
+    TMsHMs,
+    Mail,
+    Medicine,
+    Berries,
+    PokeBalls,
+    BattleItems,
+}
+
+impl Pocket {
+    /// Every pocket, in the order they are stored in a gen 4 bag block
+    pub const ALL: [Pocket; 8] = [
+        Pocket::Items,
+        Pocket::KeyItems,
+        Pocket::TMsHMs,
+        Pocket::Mail,
+        Pocket::Medicine,
+        Pocket::Berries,
+        Pocket::PokeBalls,
+        Pocket::BattleItems,
+    ];
+
+    /// The number of item slots in this pocket in DPPt
+    pub fn capacity(self) -> usize {
+        match self {
+            Pocket::Items => 165,
+            Pocket::KeyItems => 50,
+            Pocket::TMsHMs => 100,
+            Pocket::Mail => 12,
+            Pocket::Medicine => 40,
+            Pocket::Berries => 64,
+            Pocket::PokeBalls => 15,
+            Pocket::BattleItems => 30,
+        }
+    }
+
+    /// The number of item slots in this pocket in HGSS
+    pub fn hgss_capacity(self) -> usize {
+        match self {
+            Pocket::TMsHMs => 101,
+            Pocket::PokeBalls => 24,
+            _ => self.capacity(),
+        }
+    }
+
+    /// The gen 4 pocket `item` is stored in, or `None` for `DPPTItem::None` and unknown items
+    pub fn of(item: DPPTItem) -> Option<Pocket> {
+        if let DPPTItem::Unknown(_) = item {
+            return None;
+        }
+
+        match u16::from(item) {
+            0 => None,
+            1..=16 => Some(Pocket::PokeBalls),
+            17..=54 => Some(Pocket::Medicine),
+            55..=67 => Some(Pocket::BattleItems),
+            68..=136 => Some(Pocket::Items),
+            137..=148 => Some(Pocket::Mail),
+            149..=212 => Some(Pocket::Berries),
+            213..=327 => Some(Pocket::Items),
+            328..=427 => Some(Pocket::TMsHMs),
+            // HGSS Poké Balls
+            492..=500 => Some(Pocket::PokeBalls),
+            _ => Some(Pocket::KeyItems),
+        }
+    }
+
+    /// The gen 5 pocket of the item with `id`, or `None` for `0` and unknown items. Gen 5 keeps gen 4's IDs,
+    /// but has no pockets for Poké Balls, battle items or mail.
+    pub fn of_gen5(id: u16) -> Option<Pocket> {
+        let pocket = match id {
+            0 => return None,
+            1..=536 => DPPTItem::try_from(id).ok().and_then(Pocket::of)?,
+            565..=570 | 591 => Pocket::Medicine,
+            618..=620 => Pocket::TMsHMs,
+            574 | 578 | 579 | 616 | 617 | 621..=638 => Pocket::KeyItems,
+            537..=638 => Pocket::Items,
+            _ => return None,
+        };
+
+        match pocket {
+            Pocket::PokeBalls | Pocket::BattleItems | Pocket::Mail => Some(Pocket::Items),
+            pocket => Some(pocket),
+        }
+    }
+
+    /// The gen 3 pocket of the item with `id`, by its gen 3 ID, or `None` for `0` and unknown items. Gen 3
+    /// has no pockets for medicine, battle items or mail.
+    pub fn of_gen3(id: u16) -> Option<Pocket> {
+        match id {
+            0 => None,
+            1..=12 => Some(Pocket::PokeBalls),
+            133..=175 => Some(Pocket::Berries),
+            259..=288 | 349..=376 => Some(Pocket::KeyItems),
+            289..=346 => Some(Pocket::TMsHMs),
+            13..=348 => Some(Pocket::Items),
+            _ => None,
+        }
+    }
+
+    /// The gen 2 pocket of the item with `id`, by its gen 2 ID, or `None` for `0` and unknown items. Gen 1
+    /// has a single pocket, `Pocket::Items`.
+    pub fn of_gen2(id: u8) -> Option<Pocket> {
+        match id {
+            0 | 0xC3 | 0xDC | 0xFA.. => None,
+            1 | 2 | 4 | 5 | 157 | 159..=161 | 164..=166 | 177 => Some(Pocket::PokeBalls),
+            7 | 54 | 55 | 58 | 59 | 61 | 66..=71 | 115 | 116 | 127..=130 | 133 | 134 | 175 | 178 => Some(Pocket::KeyItems),
+            191..=249 => Some(Pocket::TMsHMs),
+            _ => Some(Pocket::Items),
+        }
+    }
+}
@@ -5,7 +5,10 @@
 	
 	Data source: https://raw.githubusercontent.com/Purukitto/pokemon-data.json/refs/heads/master/pokedex.json
 */
-#[derive(Debug)]
+use crate::save::error::UnknownValue;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(u16)]
 pub enum Species {
 	Bulbasaur = 1,
 	Ivysaur = 2,
@@ -904,916 +907,1821 @@ pub enum Species {
 	Regidrago = 895,
 	Glastrier = 896,
 	Spectrier = 897,
-	Calyrex = 898,}
+	Calyrex = 898,
+	/// An ID with no known species, e.g. from a corrupted save
+	Unknown(u16),
+}
+
+impl TryFrom<u16> for Species {
+    type Error = UnknownValue<u16>;
 
-impl From<u16> for Species {
-    fn from(value: u16) -> Self {
+    fn try_from(value: u16) -> Result<Self, Self::Error> {
         match value {
-			1 => Species::Bulbasaur,
-			2 => Species::Ivysaur,
-			3 => Species::Venusaur,
-			4 => Species::Charmander,
-			5 => Species::Charmeleon,
-			6 => Species::Charizard,
-			7 => Species::Squirtle,
-			8 => Species::Wartortle,
-			9 => Species::Blastoise,
-			10 => Species::Caterpie,
-			11 => Species::Metapod,
-			12 => Species::Butterfree,
-			13 => Species::Weedle,
-			14 => Species::Kakuna,
-			15 => Species::Beedrill,
-			16 => Species::Pidgey,
-			17 => Species::Pidgeotto,
-			18 => Species::Pidgeot,
-			19 => Species::Rattata,
-			20 => Species::Raticate,
-			21 => Species::Spearow,
-			22 => Species::Fearow,
-			23 => Species::Ekans,
-			24 => Species::Arbok,
-			25 => Species::Pikachu,
-			26 => Species::Raichu,
-			27 => Species::Sandshrew,
-			28 => Species::Sandslash,
-			29 => Species::NidoranFemale,
-			30 => Species::Nidorina,
-			31 => Species::Nidoqueen,
-			32 => Species::NidoranMale,
-			33 => Species::Nidorino,
-			34 => Species::Nidoking,
-			35 => Species::Clefairy,
-			36 => Species::Clefable,
-			37 => Species::Vulpix,
-			38 => Species::Ninetales,
-			39 => Species::Jigglypuff,
-			40 => Species::Wigglytuff,
-			41 => Species::Zubat,
-			42 => Species::Golbat,
-			43 => Species::Oddish,
-			44 => Species::Gloom,
-			45 => Species::Vileplume,
-			46 => Species::Paras,
-			47 => Species::Parasect,
-			48 => Species::Venonat,
-			49 => Species::Venomoth,
-			50 => Species::Diglett,
-			51 => Species::Dugtrio,
-			52 => Species::Meowth,
-			53 => Species::Persian,
-			54 => Species::Psyduck,
-			55 => Species::Golduck,
-			56 => Species::Mankey,
-			57 => Species::Primeape,
-			58 => Species::Growlithe,
-			59 => Species::Arcanine,
-			60 => Species::Poliwag,
-			61 => Species::Poliwhirl,
-			62 => Species::Poliwrath,
-			63 => Species::Abra,
-			64 => Species::Kadabra,
-			65 => Species::Alakazam,
-			66 => Species::Machop,
-			67 => Species::Machoke,
-			68 => Species::Machamp,
-			69 => Species::Bellsprout,
-			70 => Species::Weepinbell,
-			71 => Species::Victreebel,
-			72 => Species::Tentacool,
-			73 => Species::Tentacruel,
-			74 => Species::Geodude,
-			75 => Species::Graveler,
-			76 => Species::Golem,
-			77 => Species::Ponyta,
-			78 => Species::Rapidash,
-			79 => Species::Slowpoke,
-			80 => Species::Slowbro,
-			81 => Species::Magnemite,
-			82 => Species::Magneton,
-			83 => Species::Farfetchd,
-			84 => Species::Doduo,
-			85 => Species::Dodrio,
-			86 => Species::Seel,
-			87 => Species::Dewgong,
-			88 => Species::Grimer,
-			89 => Species::Muk,
-			90 => Species::Shellder,
-			91 => Species::Cloyster,
-			92 => Species::Gastly,
-			93 => Species::Haunter,
-			94 => Species::Gengar,
-			95 => Species::Onix,
-			96 => Species::Drowzee,
-			97 => Species::Hypno,
-			98 => Species::Krabby,
-			99 => Species::Kingler,
-			100 => Species::Voltorb,
-			101 => Species::Electrode,
-			102 => Species::Exeggcute,
-			103 => Species::Exeggutor,
-			104 => Species::Cubone,
-			105 => Species::Marowak,
-			106 => Species::Hitmonlee,
-			107 => Species::Hitmonchan,
-			108 => Species::Lickitung,
-			109 => Species::Koffing,
-			110 => Species::Weezing,
-			111 => Species::Rhyhorn,
-			112 => Species::Rhydon,
-			113 => Species::Chansey,
-			114 => Species::Tangela,
-			115 => Species::Kangaskhan,
-			116 => Species::Horsea,
-			117 => Species::Seadra,
-			118 => Species::Goldeen,
-			119 => Species::Seaking,
-			120 => Species::Staryu,
-			121 => Species::Starmie,
-			122 => Species::MrMime,
-			123 => Species::Scyther,
-			124 => Species::Jynx,
-			125 => Species::Electabuzz,
-			126 => Species::Magmar,
-			127 => Species::Pinsir,
-			128 => Species::Tauros,
-			129 => Species::Magikarp,
-			130 => Species::Gyarados,
-			131 => Species::Lapras,
-			132 => Species::Ditto,
-			133 => Species::Eevee,
-			134 => Species::Vaporeon,
-			135 => Species::Jolteon,
-			136 => Species::Flareon,
-			137 => Species::Porygon,
-			138 => Species::Omanyte,
-			139 => Species::Omastar,
-			140 => Species::Kabuto,
-			141 => Species::Kabutops,
-			142 => Species::Aerodactyl,
-			143 => Species::Snorlax,
-			144 => Species::Articuno,
-			145 => Species::Zapdos,
-			146 => Species::Moltres,
-			147 => Species::Dratini,
-			148 => Species::Dragonair,
-			149 => Species::Dragonite,
-			150 => Species::Mewtwo,
-			151 => Species::Mew,
-			152 => Species::Chikorita,
-			153 => Species::Bayleef,
-			154 => Species::Meganium,
-			155 => Species::Cyndaquil,
-			156 => Species::Quilava,
-			157 => Species::Typhlosion,
-			158 => Species::Totodile,
-			159 => Species::Croconaw,
-			160 => Species::Feraligatr,
-			161 => Species::Sentret,
-			162 => Species::Furret,
-			163 => Species::Hoothoot,
-			164 => Species::Noctowl,
-			165 => Species::Ledyba,
-			166 => Species::Ledian,
-			167 => Species::Spinarak,
-			168 => Species::Ariados,
-			169 => Species::Crobat,
-			170 => Species::Chinchou,
-			171 => Species::Lanturn,
-			172 => Species::Pichu,
-			173 => Species::Cleffa,
-			174 => Species::Igglybuff,
-			175 => Species::Togepi,
-			176 => Species::Togetic,
-			177 => Species::Natu,
-			178 => Species::Xatu,
-			179 => Species::Mareep,
-			180 => Species::Flaaffy,
-			181 => Species::Ampharos,
-			182 => Species::Bellossom,
-			183 => Species::Marill,
-			184 => Species::Azumarill,
-			185 => Species::Sudowoodo,
-			186 => Species::Politoed,
-			187 => Species::Hoppip,
-			188 => Species::Skiploom,
-			189 => Species::Jumpluff,
-			190 => Species::Aipom,
-			191 => Species::Sunkern,
-			192 => Species::Sunflora,
-			193 => Species::Yanma,
-			194 => Species::Wooper,
-			195 => Species::Quagsire,
-			196 => Species::Espeon,
-			197 => Species::Umbreon,
-			198 => Species::Murkrow,
-			199 => Species::Slowking,
-			200 => Species::Misdreavus,
-			201 => Species::Unown,
-			202 => Species::Wobbuffet,
-			203 => Species::Girafarig,
-			204 => Species::Pineco,
-			205 => Species::Forretress,
-			206 => Species::Dunsparce,
-			207 => Species::Gligar,
-			208 => Species::Steelix,
-			209 => Species::Snubbull,
-			210 => Species::Granbull,
-			211 => Species::Qwilfish,
-			212 => Species::Scizor,
-			213 => Species::Shuckle,
-			214 => Species::Heracross,
-			215 => Species::Sneasel,
-			216 => Species::Teddiursa,
-			217 => Species::Ursaring,
-			218 => Species::Slugma,
-			219 => Species::Magcargo,
-			220 => Species::Swinub,
-			221 => Species::Piloswine,
-			222 => Species::Corsola,
-			223 => Species::Remoraid,
-			224 => Species::Octillery,
-			225 => Species::Delibird,
-			226 => Species::Mantine,
-			227 => Species::Skarmory,
-			228 => Species::Houndour,
-			229 => Species::Houndoom,
-			230 => Species::Kingdra,
-			231 => Species::Phanpy,
-			232 => Species::Donphan,
-			233 => Species::Porygon2,
-			234 => Species::Stantler,
-			235 => Species::Smeargle,
-			236 => Species::Tyrogue,
-			237 => Species::Hitmontop,
-			238 => Species::Smoochum,
-			239 => Species::Elekid,
-			240 => Species::Magby,
-			241 => Species::Miltank,
-			242 => Species::Blissey,
-			243 => Species::Raikou,
-			244 => Species::Entei,
-			245 => Species::Suicune,
-			246 => Species::Larvitar,
-			247 => Species::Pupitar,
-			248 => Species::Tyranitar,
-			249 => Species::Lugia,
-			250 => Species::Hooh,
-			251 => Species::Celebi,
-			252 => Species::Treecko,
-			253 => Species::Grovyle,
-			254 => Species::Sceptile,
-			255 => Species::Torchic,
-			256 => Species::Combusken,
-			257 => Species::Blaziken,
-			258 => Species::Mudkip,
-			259 => Species::Marshtomp,
-			260 => Species::Swampert,
-			261 => Species::Poochyena,
-			262 => Species::Mightyena,
-			263 => Species::Zigzagoon,
-			264 => Species::Linoone,
-			265 => Species::Wurmple,
-			266 => Species::Silcoon,
-			267 => Species::Beautifly,
-			268 => Species::Cascoon,
-			269 => Species::Dustox,
-			270 => Species::Lotad,
-			271 => Species::Lombre,
-			272 => Species::Ludicolo,
-			273 => Species::Seedot,
-			274 => Species::Nuzleaf,
-			275 => Species::Shiftry,
-			276 => Species::Taillow,
-			277 => Species::Swellow,
-			278 => Species::Wingull,
-			279 => Species::Pelipper,
-			280 => Species::Ralts,
-			281 => Species::Kirlia,
-			282 => Species::Gardevoir,
-			283 => Species::Surskit,
-			284 => Species::Masquerain,
-			285 => Species::Shroomish,
-			286 => Species::Breloom,
-			287 => Species::Slakoth,
-			288 => Species::Vigoroth,
-			289 => Species::Slaking,
-			290 => Species::Nincada,
-			291 => Species::Ninjask,
-			292 => Species::Shedinja,
-			293 => Species::Whismur,
-			294 => Species::Loudred,
-			295 => Species::Exploud,
-			296 => Species::Makuhita,
-			297 => Species::Hariyama,
-			298 => Species::Azurill,
-			299 => Species::Nosepass,
-			300 => Species::Skitty,
-			301 => Species::Delcatty,
-			302 => Species::Sableye,
-			303 => Species::Mawile,
-			304 => Species::Aron,
-			305 => Species::Lairon,
-			306 => Species::Aggron,
-			307 => Species::Meditite,
-			308 => Species::Medicham,
-			309 => Species::Electrike,
-			310 => Species::Manectric,
-			311 => Species::Plusle,
-			312 => Species::Minun,
-			313 => Species::Volbeat,
-			314 => Species::Illumise,
-			315 => Species::Roselia,
-			316 => Species::Gulpin,
-			317 => Species::Swalot,
-			318 => Species::Carvanha,
-			319 => Species::Sharpedo,
-			320 => Species::Wailmer,
-			321 => Species::Wailord,
-			322 => Species::Numel,
-			323 => Species::Camerupt,
-			324 => Species::Torkoal,
-			325 => Species::Spoink,
-			326 => Species::Grumpig,
-			327 => Species::Spinda,
-			328 => Species::Trapinch,
-			329 => Species::Vibrava,
-			330 => Species::Flygon,
-			331 => Species::Cacnea,
-			332 => Species::Cacturne,
-			333 => Species::Swablu,
-			334 => Species::Altaria,
-			335 => Species::Zangoose,
-			336 => Species::Seviper,
-			337 => Species::Lunatone,
-			338 => Species::Solrock,
-			339 => Species::Barboach,
-			340 => Species::Whiscash,
-			341 => Species::Corphish,
-			342 => Species::Crawdaunt,
-			343 => Species::Baltoy,
-			344 => Species::Claydol,
-			345 => Species::Lileep,
-			346 => Species::Cradily,
-			347 => Species::Anorith,
-			348 => Species::Armaldo,
-			349 => Species::Feebas,
-			350 => Species::Milotic,
-			351 => Species::Castform,
-			352 => Species::Kecleon,
-			353 => Species::Shuppet,
-			354 => Species::Banette,
-			355 => Species::Duskull,
-			356 => Species::Dusclops,
-			357 => Species::Tropius,
-			358 => Species::Chimecho,
-			359 => Species::Absol,
-			360 => Species::Wynaut,
-			361 => Species::Snorunt,
-			362 => Species::Glalie,
-			363 => Species::Spheal,
-			364 => Species::Sealeo,
-			365 => Species::Walrein,
-			366 => Species::Clamperl,
-			367 => Species::Huntail,
-			368 => Species::Gorebyss,
-			369 => Species::Relicanth,
-			370 => Species::Luvdisc,
-			371 => Species::Bagon,
-			372 => Species::Shelgon,
-			373 => Species::Salamence,
-			374 => Species::Beldum,
-			375 => Species::Metang,
-			376 => Species::Metagross,
-			377 => Species::Regirock,
-			378 => Species::Regice,
-			379 => Species::Registeel,
-			380 => Species::Latias,
-			381 => Species::Latios,
-			382 => Species::Kyogre,
-			383 => Species::Groudon,
-			384 => Species::Rayquaza,
-			385 => Species::Jirachi,
-			386 => Species::Deoxys,
-			387 => Species::Turtwig,
-			388 => Species::Grotle,
-			389 => Species::Torterra,
-			390 => Species::Chimchar,
-			391 => Species::Monferno,
-			392 => Species::Infernape,
-			393 => Species::Piplup,
-			394 => Species::Prinplup,
-			395 => Species::Empoleon,
-			396 => Species::Starly,
-			397 => Species::Staravia,
-			398 => Species::Staraptor,
-			399 => Species::Bidoof,
-			400 => Species::Bibarel,
-			401 => Species::Kricketot,
-			402 => Species::Kricketune,
-			403 => Species::Shinx,
-			404 => Species::Luxio,
-			405 => Species::Luxray,
-			406 => Species::Budew,
-			407 => Species::Roserade,
-			408 => Species::Cranidos,
-			409 => Species::Rampardos,
-			410 => Species::Shieldon,
-			411 => Species::Bastiodon,
-			412 => Species::Burmy,
-			413 => Species::Wormadam,
-			414 => Species::Mothim,
-			415 => Species::Combee,
-			416 => Species::Vespiquen,
-			417 => Species::Pachirisu,
-			418 => Species::Buizel,
-			419 => Species::Floatzel,
-			420 => Species::Cherubi,
-			421 => Species::Cherrim,
-			422 => Species::Shellos,
-			423 => Species::Gastrodon,
-			424 => Species::Ambipom,
-			425 => Species::Drifloon,
-			426 => Species::Drifblim,
-			427 => Species::Buneary,
-			428 => Species::Lopunny,
-			429 => Species::Mismagius,
-			430 => Species::Honchkrow,
-			431 => Species::Glameow,
-			432 => Species::Purugly,
-			433 => Species::Chingling,
-			434 => Species::Stunky,
-			435 => Species::Skuntank,
-			436 => Species::Bronzor,
-			437 => Species::Bronzong,
-			438 => Species::Bonsly,
-			439 => Species::MimeJr,
-			440 => Species::Happiny,
-			441 => Species::Chatot,
-			442 => Species::Spiritomb,
-			443 => Species::Gible,
-			444 => Species::Gabite,
-			445 => Species::Garchomp,
-			446 => Species::Munchlax,
-			447 => Species::Riolu,
-			448 => Species::Lucario,
-			449 => Species::Hippopotas,
-			450 => Species::Hippowdon,
-			451 => Species::Skorupi,
-			452 => Species::Drapion,
-			453 => Species::Croagunk,
-			454 => Species::Toxicroak,
-			455 => Species::Carnivine,
-			456 => Species::Finneon,
-			457 => Species::Lumineon,
-			458 => Species::Mantyke,
-			459 => Species::Snover,
-			460 => Species::Abomasnow,
-			461 => Species::Weavile,
-			462 => Species::Magnezone,
-			463 => Species::Lickilicky,
-			464 => Species::Rhyperior,
-			465 => Species::Tangrowth,
-			466 => Species::Electivire,
-			467 => Species::Magmortar,
-			468 => Species::Togekiss,
-			469 => Species::Yanmega,
-			470 => Species::Leafeon,
-			471 => Species::Glaceon,
-			472 => Species::Gliscor,
-			473 => Species::Mamoswine,
-			474 => Species::Porygonz,
-			475 => Species::Gallade,
-			476 => Species::Probopass,
-			477 => Species::Dusknoir,
-			478 => Species::Froslass,
-			479 => Species::Rotom,
-			480 => Species::Uxie,
-			481 => Species::Mesprit,
-			482 => Species::Azelf,
-			483 => Species::Dialga,
-			484 => Species::Palkia,
-			485 => Species::Heatran,
-			486 => Species::Regigigas,
-			487 => Species::Giratina,
-			488 => Species::Cresselia,
-			489 => Species::Phione,
-			490 => Species::Manaphy,
-			491 => Species::Darkrai,
-			492 => Species::Shaymin,
-			493 => Species::Arceus,
-			494 => Species::Victini,
-			495 => Species::Snivy,
-			496 => Species::Servine,
-			497 => Species::Serperior,
-			498 => Species::Tepig,
-			499 => Species::Pignite,
-			500 => Species::Emboar,
-			501 => Species::Oshawott,
-			502 => Species::Dewott,
-			503 => Species::Samurott,
-			504 => Species::Patrat,
-			505 => Species::Watchog,
-			506 => Species::Lillipup,
-			507 => Species::Herdier,
-			508 => Species::Stoutland,
-			509 => Species::Purrloin,
-			510 => Species::Liepard,
-			511 => Species::Pansage,
-			512 => Species::Simisage,
-			513 => Species::Pansear,
-			514 => Species::Simisear,
-			515 => Species::Panpour,
-			516 => Species::Simipour,
-			517 => Species::Munna,
-			518 => Species::Musharna,
-			519 => Species::Pidove,
-			520 => Species::Tranquill,
-			521 => Species::Unfezant,
-			522 => Species::Blitzle,
-			523 => Species::Zebstrika,
-			524 => Species::Roggenrola,
-			525 => Species::Boldore,
-			526 => Species::Gigalith,
-			527 => Species::Woobat,
-			528 => Species::Swoobat,
-			529 => Species::Drilbur,
-			530 => Species::Excadrill,
-			531 => Species::Audino,
-			532 => Species::Timburr,
-			533 => Species::Gurdurr,
-			534 => Species::Conkeldurr,
-			535 => Species::Tympole,
-			536 => Species::Palpitoad,
-			537 => Species::Seismitoad,
-			538 => Species::Throh,
-			539 => Species::Sawk,
-			540 => Species::Sewaddle,
-			541 => Species::Swadloon,
-			542 => Species::Leavanny,
-			543 => Species::Venipede,
-			544 => Species::Whirlipede,
-			545 => Species::Scolipede,
-			546 => Species::Cottonee,
-			547 => Species::Whimsicott,
-			548 => Species::Petilil,
-			549 => Species::Lilligant,
-			550 => Species::Basculin,
-			551 => Species::Sandile,
-			552 => Species::Krokorok,
-			553 => Species::Krookodile,
-			554 => Species::Darumaka,
-			555 => Species::Darmanitan,
-			556 => Species::Maractus,
-			557 => Species::Dwebble,
-			558 => Species::Crustle,
-			559 => Species::Scraggy,
-			560 => Species::Scrafty,
-			561 => Species::Sigilyph,
-			562 => Species::Yamask,
-			563 => Species::Cofagrigus,
-			564 => Species::Tirtouga,
-			565 => Species::Carracosta,
-			566 => Species::Archen,
-			567 => Species::Archeops,
-			568 => Species::Trubbish,
-			569 => Species::Garbodor,
-			570 => Species::Zorua,
-			571 => Species::Zoroark,
-			572 => Species::Minccino,
-			573 => Species::Cinccino,
-			574 => Species::Gothita,
-			575 => Species::Gothorita,
-			576 => Species::Gothitelle,
-			577 => Species::Solosis,
-			578 => Species::Duosion,
-			579 => Species::Reuniclus,
-			580 => Species::Ducklett,
-			581 => Species::Swanna,
-			582 => Species::Vanillite,
-			583 => Species::Vanillish,
-			584 => Species::Vanilluxe,
-			585 => Species::Deerling,
-			586 => Species::Sawsbuck,
-			587 => Species::Emolga,
-			588 => Species::Karrablast,
-			589 => Species::Escavalier,
-			590 => Species::Foongus,
-			591 => Species::Amoonguss,
-			592 => Species::Frillish,
-			593 => Species::Jellicent,
-			594 => Species::Alomomola,
-			595 => Species::Joltik,
-			596 => Species::Galvantula,
-			597 => Species::Ferroseed,
-			598 => Species::Ferrothorn,
-			599 => Species::Klink,
-			600 => Species::Klang,
-			601 => Species::Klinklang,
-			602 => Species::Tynamo,
-			603 => Species::Eelektrik,
-			604 => Species::Eelektross,
-			605 => Species::Elgyem,
-			606 => Species::Beheeyem,
-			607 => Species::Litwick,
-			608 => Species::Lampent,
-			609 => Species::Chandelure,
-			610 => Species::Axew,
-			611 => Species::Fraxure,
-			612 => Species::Haxorus,
-			613 => Species::Cubchoo,
-			614 => Species::Beartic,
-			615 => Species::Cryogonal,
-			616 => Species::Shelmet,
-			617 => Species::Accelgor,
-			618 => Species::Stunfisk,
-			619 => Species::Mienfoo,
-			620 => Species::Mienshao,
-			621 => Species::Druddigon,
-			622 => Species::Golett,
-			623 => Species::Golurk,
-			624 => Species::Pawniard,
-			625 => Species::Bisharp,
-			626 => Species::Bouffalant,
-			627 => Species::Rufflet,
-			628 => Species::Braviary,
-			629 => Species::Vullaby,
-			630 => Species::Mandibuzz,
-			631 => Species::Heatmor,
-			632 => Species::Durant,
-			633 => Species::Deino,
-			634 => Species::Zweilous,
-			635 => Species::Hydreigon,
-			636 => Species::Larvesta,
-			637 => Species::Volcarona,
-			638 => Species::Cobalion,
-			639 => Species::Terrakion,
-			640 => Species::Virizion,
-			641 => Species::Tornadus,
-			642 => Species::Thundurus,
-			643 => Species::Reshiram,
-			644 => Species::Zekrom,
-			645 => Species::Landorus,
-			646 => Species::Kyurem,
-			647 => Species::Keldeo,
-			648 => Species::Meloetta,
-			649 => Species::Genesect,
-			650 => Species::Chespin,
-			651 => Species::Quilladin,
-			652 => Species::Chesnaught,
-			653 => Species::Fennekin,
-			654 => Species::Braixen,
-			655 => Species::Delphox,
-			656 => Species::Froakie,
-			657 => Species::Frogadier,
-			658 => Species::Greninja,
-			659 => Species::Bunnelby,
-			660 => Species::Diggersby,
-			661 => Species::Fletchling,
-			662 => Species::Fletchinder,
-			663 => Species::Talonflame,
-			664 => Species::Scatterbug,
-			665 => Species::Spewpa,
-			666 => Species::Vivillon,
-			667 => Species::Litleo,
-			668 => Species::Pyroar,
-			669 => Species::Flabebe,
-			670 => Species::Floette,
-			671 => Species::Florges,
-			672 => Species::Skiddo,
-			673 => Species::Gogoat,
-			674 => Species::Pancham,
-			675 => Species::Pangoro,
-			676 => Species::Furfrou,
-			677 => Species::Espurr,
-			678 => Species::Meowstic,
-			679 => Species::Honedge,
-			680 => Species::Doublade,
-			681 => Species::Aegislash,
-			682 => Species::Spritzee,
-			683 => Species::Aromatisse,
-			684 => Species::Swirlix,
-			685 => Species::Slurpuff,
-			686 => Species::Inkay,
-			687 => Species::Malamar,
-			688 => Species::Binacle,
-			689 => Species::Barbaracle,
-			690 => Species::Skrelp,
-			691 => Species::Dragalge,
-			692 => Species::Clauncher,
-			693 => Species::Clawitzer,
-			694 => Species::Helioptile,
-			695 => Species::Heliolisk,
-			696 => Species::Tyrunt,
-			697 => Species::Tyrantrum,
-			698 => Species::Amaura,
-			699 => Species::Aurorus,
-			700 => Species::Sylveon,
-			701 => Species::Hawlucha,
-			702 => Species::Dedenne,
-			703 => Species::Carbink,
-			704 => Species::Goomy,
-			705 => Species::Sliggoo,
-			706 => Species::Goodra,
-			707 => Species::Klefki,
-			708 => Species::Phantump,
-			709 => Species::Trevenant,
-			710 => Species::Pumpkaboo,
-			711 => Species::Gourgeist,
-			712 => Species::Bergmite,
-			713 => Species::Avalugg,
-			714 => Species::Noibat,
-			715 => Species::Noivern,
-			716 => Species::Xerneas,
-			717 => Species::Yveltal,
-			718 => Species::Zygarde,
-			719 => Species::Diancie,
-			720 => Species::Hoopa,
-			721 => Species::Volcanion,
-			722 => Species::Rowlet,
-			723 => Species::Dartrix,
-			724 => Species::Decidueye,
-			725 => Species::Litten,
-			726 => Species::Torracat,
-			727 => Species::Incineroar,
-			728 => Species::Popplio,
-			729 => Species::Brionne,
-			730 => Species::Primarina,
-			731 => Species::Pikipek,
-			732 => Species::Trumbeak,
-			733 => Species::Toucannon,
-			734 => Species::Yungoos,
-			735 => Species::Gumshoos,
-			736 => Species::Grubbin,
-			737 => Species::Charjabug,
-			738 => Species::Vikavolt,
-			739 => Species::Crabrawler,
-			740 => Species::Crabominable,
-			741 => Species::Oricorio,
-			742 => Species::Cutiefly,
-			743 => Species::Ribombee,
-			744 => Species::Rockruff,
-			745 => Species::Lycanroc,
-			746 => Species::Wishiwashi,
-			747 => Species::Mareanie,
-			748 => Species::Toxapex,
-			749 => Species::Mudbray,
-			750 => Species::Mudsdale,
-			751 => Species::Dewpider,
-			752 => Species::Araquanid,
-			753 => Species::Fomantis,
-			754 => Species::Lurantis,
-			755 => Species::Morelull,
-			756 => Species::Shiinotic,
-			757 => Species::Salandit,
-			758 => Species::Salazzle,
-			759 => Species::Stufful,
-			760 => Species::Bewear,
-			761 => Species::Bounsweet,
-			762 => Species::Steenee,
-			763 => Species::Tsareena,
-			764 => Species::Comfey,
-			765 => Species::Oranguru,
-			766 => Species::Passimian,
-			767 => Species::Wimpod,
-			768 => Species::Golisopod,
-			769 => Species::Sandygast,
-			770 => Species::Palossand,
-			771 => Species::Pyukumuku,
-			772 => Species::TypeNull,
-			773 => Species::Silvally,
-			774 => Species::Minior,
-			775 => Species::Komala,
-			776 => Species::Turtonator,
-			777 => Species::Togedemaru,
-			778 => Species::Mimikyu,
-			779 => Species::Bruxish,
-			780 => Species::Drampa,
-			781 => Species::Dhelmise,
-			782 => Species::Jangmoo,
-			783 => Species::Hakamoo,
-			784 => Species::Kommoo,
-			785 => Species::TapuKoko,
-			786 => Species::TapuLele,
-			787 => Species::TapuBulu,
-			788 => Species::TapuFini,
-			789 => Species::Cosmog,
-			790 => Species::Cosmoem,
-			791 => Species::Solgaleo,
-			792 => Species::Lunala,
-			793 => Species::Nihilego,
-			794 => Species::Buzzwole,
-			795 => Species::Pheromosa,
-			796 => Species::Xurkitree,
-			797 => Species::Celesteela,
-			798 => Species::Kartana,
-			799 => Species::Guzzlord,
-			800 => Species::Necrozma,
-			801 => Species::Magearna,
-			802 => Species::Marshadow,
-			803 => Species::Poipole,
-			804 => Species::Naganadel,
-			805 => Species::Stakataka,
-			806 => Species::Blacephalon,
-			807 => Species::Zeraora,
-			808 => Species::Meltan,
-			809 => Species::Melmetal,
-			810 => Species::Grookey,
-			811 => Species::Thwackey,
-			812 => Species::Rillaboom,
-			813 => Species::Scorbunny,
-			814 => Species::Raboot,
-			815 => Species::Cinderace,
-			816 => Species::Sobble,
-			817 => Species::Drizzile,
-			818 => Species::Inteleon,
-			819 => Species::Skwovet,
-			820 => Species::Greedent,
-			821 => Species::Rookidee,
-			822 => Species::Corvisquire,
-			823 => Species::Corviknight,
-			824 => Species::Blipbug,
-			825 => Species::Dottler,
-			826 => Species::Orbeetle,
-			827 => Species::Nickit,
-			828 => Species::Thievul,
-			829 => Species::Gossifleur,
-			830 => Species::Eldegoss,
-			831 => Species::Wooloo,
-			832 => Species::Dubwool,
-			833 => Species::Chewtle,
-			834 => Species::Drednaw,
-			835 => Species::Yamper,
-			836 => Species::Boltund,
-			837 => Species::Rolycoly,
-			838 => Species::Carkol,
-			839 => Species::Coalossal,
-			840 => Species::Applin,
-			841 => Species::Flapple,
-			842 => Species::Appletun,
-			843 => Species::Silicobra,
-			844 => Species::Sandaconda,
-			845 => Species::Cramorant,
-			846 => Species::Arrokuda,
-			847 => Species::Barraskewda,
-			848 => Species::Toxel,
-			849 => Species::Toxtricity,
-			850 => Species::Sizzlipede,
-			851 => Species::Centiskorch,
-			852 => Species::Clobbopus,
-			853 => Species::Grapploct,
-			854 => Species::Sinistea,
-			855 => Species::Polteageist,
-			856 => Species::Hatenna,
-			857 => Species::Hattrem,
-			858 => Species::Hatterene,
-			859 => Species::Impidimp,
-			860 => Species::Morgrem,
-			861 => Species::Grimmsnarl,
-			862 => Species::Obstagoon,
-			863 => Species::Perrserker,
-			864 => Species::Cursola,
-			865 => Species::Sirfetchd,
-			866 => Species::MrRime,
-			867 => Species::Runerigus,
-			868 => Species::Milcery,
-			869 => Species::Alcremie,
-			870 => Species::Falinks,
-			871 => Species::Pincurchin,
-			872 => Species::Snom,
-			873 => Species::Frosmoth,
-			874 => Species::Stonjourner,
-			875 => Species::Eiscue,
-			876 => Species::Indeedee,
-			877 => Species::Morpeko,
-			878 => Species::Cufant,
-			879 => Species::Copperajah,
-			880 => Species::Dracozolt,
-			881 => Species::Arctozolt,
-			882 => Species::Dracovish,
-			883 => Species::Arctovish,
-			884 => Species::Duraludon,
-			885 => Species::Dreepy,
-			886 => Species::Drakloak,
-			887 => Species::Dragapult,
-			888 => Species::Zacian,
-			889 => Species::Zamazenta,
-			890 => Species::Eternatus,
-			891 => Species::Kubfu,
-			892 => Species::Urshifu,
-			893 => Species::Zarude,
-			894 => Species::Regieleki,
-			895 => Species::Regidrago,
-			896 => Species::Glastrier,
-			897 => Species::Spectrier,
-			898 => Species::Calyrex,
-            _ => unreachable!()
+			1 => Ok(Species::Bulbasaur),
+			2 => Ok(Species::Ivysaur),
+			3 => Ok(Species::Venusaur),
+			4 => Ok(Species::Charmander),
+			5 => Ok(Species::Charmeleon),
+			6 => Ok(Species::Charizard),
+			7 => Ok(Species::Squirtle),
+			8 => Ok(Species::Wartortle),
+			9 => Ok(Species::Blastoise),
+			10 => Ok(Species::Caterpie),
+			11 => Ok(Species::Metapod),
+			12 => Ok(Species::Butterfree),
+			13 => Ok(Species::Weedle),
+			14 => Ok(Species::Kakuna),
+			15 => Ok(Species::Beedrill),
+			16 => Ok(Species::Pidgey),
+			17 => Ok(Species::Pidgeotto),
+			18 => Ok(Species::Pidgeot),
+			19 => Ok(Species::Rattata),
+			20 => Ok(Species::Raticate),
+			21 => Ok(Species::Spearow),
+			22 => Ok(Species::Fearow),
+			23 => Ok(Species::Ekans),
+			24 => Ok(Species::Arbok),
+			25 => Ok(Species::Pikachu),
+			26 => Ok(Species::Raichu),
+			27 => Ok(Species::Sandshrew),
+			28 => Ok(Species::Sandslash),
+			29 => Ok(Species::NidoranFemale),
+			30 => Ok(Species::Nidorina),
+			31 => Ok(Species::Nidoqueen),
+			32 => Ok(Species::NidoranMale),
+			33 => Ok(Species::Nidorino),
+			34 => Ok(Species::Nidoking),
+			35 => Ok(Species::Clefairy),
+			36 => Ok(Species::Clefable),
+			37 => Ok(Species::Vulpix),
+			38 => Ok(Species::Ninetales),
+			39 => Ok(Species::Jigglypuff),
+			40 => Ok(Species::Wigglytuff),
+			41 => Ok(Species::Zubat),
+			42 => Ok(Species::Golbat),
+			43 => Ok(Species::Oddish),
+			44 => Ok(Species::Gloom),
+			45 => Ok(Species::Vileplume),
+			46 => Ok(Species::Paras),
+			47 => Ok(Species::Parasect),
+			48 => Ok(Species::Venonat),
+			49 => Ok(Species::Venomoth),
+			50 => Ok(Species::Diglett),
+			51 => Ok(Species::Dugtrio),
+			52 => Ok(Species::Meowth),
+			53 => Ok(Species::Persian),
+			54 => Ok(Species::Psyduck),
+			55 => Ok(Species::Golduck),
+			56 => Ok(Species::Mankey),
+			57 => Ok(Species::Primeape),
+			58 => Ok(Species::Growlithe),
+			59 => Ok(Species::Arcanine),
+			60 => Ok(Species::Poliwag),
+			61 => Ok(Species::Poliwhirl),
+			62 => Ok(Species::Poliwrath),
+			63 => Ok(Species::Abra),
+			64 => Ok(Species::Kadabra),
+			65 => Ok(Species::Alakazam),
+			66 => Ok(Species::Machop),
+			67 => Ok(Species::Machoke),
+			68 => Ok(Species::Machamp),
+			69 => Ok(Species::Bellsprout),
+			70 => Ok(Species::Weepinbell),
+			71 => Ok(Species::Victreebel),
+			72 => Ok(Species::Tentacool),
+			73 => Ok(Species::Tentacruel),
+			74 => Ok(Species::Geodude),
+			75 => Ok(Species::Graveler),
+			76 => Ok(Species::Golem),
+			77 => Ok(Species::Ponyta),
+			78 => Ok(Species::Rapidash),
+			79 => Ok(Species::Slowpoke),
+			80 => Ok(Species::Slowbro),
+			81 => Ok(Species::Magnemite),
+			82 => Ok(Species::Magneton),
+			83 => Ok(Species::Farfetchd),
+			84 => Ok(Species::Doduo),
+			85 => Ok(Species::Dodrio),
+			86 => Ok(Species::Seel),
+			87 => Ok(Species::Dewgong),
+			88 => Ok(Species::Grimer),
+			89 => Ok(Species::Muk),
+			90 => Ok(Species::Shellder),
+			91 => Ok(Species::Cloyster),
+			92 => Ok(Species::Gastly),
+			93 => Ok(Species::Haunter),
+			94 => Ok(Species::Gengar),
+			95 => Ok(Species::Onix),
+			96 => Ok(Species::Drowzee),
+			97 => Ok(Species::Hypno),
+			98 => Ok(Species::Krabby),
+			99 => Ok(Species::Kingler),
+			100 => Ok(Species::Voltorb),
+			101 => Ok(Species::Electrode),
+			102 => Ok(Species::Exeggcute),
+			103 => Ok(Species::Exeggutor),
+			104 => Ok(Species::Cubone),
+			105 => Ok(Species::Marowak),
+			106 => Ok(Species::Hitmonlee),
+			107 => Ok(Species::Hitmonchan),
+			108 => Ok(Species::Lickitung),
+			109 => Ok(Species::Koffing),
+			110 => Ok(Species::Weezing),
+			111 => Ok(Species::Rhyhorn),
+			112 => Ok(Species::Rhydon),
+			113 => Ok(Species::Chansey),
+			114 => Ok(Species::Tangela),
+			115 => Ok(Species::Kangaskhan),
+			116 => Ok(Species::Horsea),
+			117 => Ok(Species::Seadra),
+			118 => Ok(Species::Goldeen),
+			119 => Ok(Species::Seaking),
+			120 => Ok(Species::Staryu),
+			121 => Ok(Species::Starmie),
+			122 => Ok(Species::MrMime),
+			123 => Ok(Species::Scyther),
+			124 => Ok(Species::Jynx),
+			125 => Ok(Species::Electabuzz),
+			126 => Ok(Species::Magmar),
+			127 => Ok(Species::Pinsir),
+			128 => Ok(Species::Tauros),
+			129 => Ok(Species::Magikarp),
+			130 => Ok(Species::Gyarados),
+			131 => Ok(Species::Lapras),
+			132 => Ok(Species::Ditto),
+			133 => Ok(Species::Eevee),
+			134 => Ok(Species::Vaporeon),
+			135 => Ok(Species::Jolteon),
+			136 => Ok(Species::Flareon),
+			137 => Ok(Species::Porygon),
+			138 => Ok(Species::Omanyte),
+			139 => Ok(Species::Omastar),
+			140 => Ok(Species::Kabuto),
+			141 => Ok(Species::Kabutops),
+			142 => Ok(Species::Aerodactyl),
+			143 => Ok(Species::Snorlax),
+			144 => Ok(Species::Articuno),
+			145 => Ok(Species::Zapdos),
+			146 => Ok(Species::Moltres),
+			147 => Ok(Species::Dratini),
+			148 => Ok(Species::Dragonair),
+			149 => Ok(Species::Dragonite),
+			150 => Ok(Species::Mewtwo),
+			151 => Ok(Species::Mew),
+			152 => Ok(Species::Chikorita),
+			153 => Ok(Species::Bayleef),
+			154 => Ok(Species::Meganium),
+			155 => Ok(Species::Cyndaquil),
+			156 => Ok(Species::Quilava),
+			157 => Ok(Species::Typhlosion),
+			158 => Ok(Species::Totodile),
+			159 => Ok(Species::Croconaw),
+			160 => Ok(Species::Feraligatr),
+			161 => Ok(Species::Sentret),
+			162 => Ok(Species::Furret),
+			163 => Ok(Species::Hoothoot),
+			164 => Ok(Species::Noctowl),
+			165 => Ok(Species::Ledyba),
+			166 => Ok(Species::Ledian),
+			167 => Ok(Species::Spinarak),
+			168 => Ok(Species::Ariados),
+			169 => Ok(Species::Crobat),
+			170 => Ok(Species::Chinchou),
+			171 => Ok(Species::Lanturn),
+			172 => Ok(Species::Pichu),
+			173 => Ok(Species::Cleffa),
+			174 => Ok(Species::Igglybuff),
+			175 => Ok(Species::Togepi),
+			176 => Ok(Species::Togetic),
+			177 => Ok(Species::Natu),
+			178 => Ok(Species::Xatu),
+			179 => Ok(Species::Mareep),
+			180 => Ok(Species::Flaaffy),
+			181 => Ok(Species::Ampharos),
+			182 => Ok(Species::Bellossom),
+			183 => Ok(Species::Marill),
+			184 => Ok(Species::Azumarill),
+			185 => Ok(Species::Sudowoodo),
+			186 => Ok(Species::Politoed),
+			187 => Ok(Species::Hoppip),
+			188 => Ok(Species::Skiploom),
+			189 => Ok(Species::Jumpluff),
+			190 => Ok(Species::Aipom),
+			191 => Ok(Species::Sunkern),
+			192 => Ok(Species::Sunflora),
+			193 => Ok(Species::Yanma),
+			194 => Ok(Species::Wooper),
+			195 => Ok(Species::Quagsire),
+			196 => Ok(Species::Espeon),
+			197 => Ok(Species::Umbreon),
+			198 => Ok(Species::Murkrow),
+			199 => Ok(Species::Slowking),
+			200 => Ok(Species::Misdreavus),
+			201 => Ok(Species::Unown),
+			202 => Ok(Species::Wobbuffet),
+			203 => Ok(Species::Girafarig),
+			204 => Ok(Species::Pineco),
+			205 => Ok(Species::Forretress),
+			206 => Ok(Species::Dunsparce),
+			207 => Ok(Species::Gligar),
+			208 => Ok(Species::Steelix),
+			209 => Ok(Species::Snubbull),
+			210 => Ok(Species::Granbull),
+			211 => Ok(Species::Qwilfish),
+			212 => Ok(Species::Scizor),
+			213 => Ok(Species::Shuckle),
+			214 => Ok(Species::Heracross),
+			215 => Ok(Species::Sneasel),
+			216 => Ok(Species::Teddiursa),
+			217 => Ok(Species::Ursaring),
+			218 => Ok(Species::Slugma),
+			219 => Ok(Species::Magcargo),
+			220 => Ok(Species::Swinub),
+			221 => Ok(Species::Piloswine),
+			222 => Ok(Species::Corsola),
+			223 => Ok(Species::Remoraid),
+			224 => Ok(Species::Octillery),
+			225 => Ok(Species::Delibird),
+			226 => Ok(Species::Mantine),
+			227 => Ok(Species::Skarmory),
+			228 => Ok(Species::Houndour),
+			229 => Ok(Species::Houndoom),
+			230 => Ok(Species::Kingdra),
+			231 => Ok(Species::Phanpy),
+			232 => Ok(Species::Donphan),
+			233 => Ok(Species::Porygon2),
+			234 => Ok(Species::Stantler),
+			235 => Ok(Species::Smeargle),
+			236 => Ok(Species::Tyrogue),
+			237 => Ok(Species::Hitmontop),
+			238 => Ok(Species::Smoochum),
+			239 => Ok(Species::Elekid),
+			240 => Ok(Species::Magby),
+			241 => Ok(Species::Miltank),
+			242 => Ok(Species::Blissey),
+			243 => Ok(Species::Raikou),
+			244 => Ok(Species::Entei),
+			245 => Ok(Species::Suicune),
+			246 => Ok(Species::Larvitar),
+			247 => Ok(Species::Pupitar),
+			248 => Ok(Species::Tyranitar),
+			249 => Ok(Species::Lugia),
+			250 => Ok(Species::Hooh),
+			251 => Ok(Species::Celebi),
+			252 => Ok(Species::Treecko),
+			253 => Ok(Species::Grovyle),
+			254 => Ok(Species::Sceptile),
+			255 => Ok(Species::Torchic),
+			256 => Ok(Species::Combusken),
+			257 => Ok(Species::Blaziken),
+			258 => Ok(Species::Mudkip),
+			259 => Ok(Species::Marshtomp),
+			260 => Ok(Species::Swampert),
+			261 => Ok(Species::Poochyena),
+			262 => Ok(Species::Mightyena),
+			263 => Ok(Species::Zigzagoon),
+			264 => Ok(Species::Linoone),
+			265 => Ok(Species::Wurmple),
+			266 => Ok(Species::Silcoon),
+			267 => Ok(Species::Beautifly),
+			268 => Ok(Species::Cascoon),
+			269 => Ok(Species::Dustox),
+			270 => Ok(Species::Lotad),
+			271 => Ok(Species::Lombre),
+			272 => Ok(Species::Ludicolo),
+			273 => Ok(Species::Seedot),
+			274 => Ok(Species::Nuzleaf),
+			275 => Ok(Species::Shiftry),
+			276 => Ok(Species::Taillow),
+			277 => Ok(Species::Swellow),
+			278 => Ok(Species::Wingull),
+			279 => Ok(Species::Pelipper),
+			280 => Ok(Species::Ralts),
+			281 => Ok(Species::Kirlia),
+			282 => Ok(Species::Gardevoir),
+			283 => Ok(Species::Surskit),
+			284 => Ok(Species::Masquerain),
+			285 => Ok(Species::Shroomish),
+			286 => Ok(Species::Breloom),
+			287 => Ok(Species::Slakoth),
+			288 => Ok(Species::Vigoroth),
+			289 => Ok(Species::Slaking),
+			290 => Ok(Species::Nincada),
+			291 => Ok(Species::Ninjask),
+			292 => Ok(Species::Shedinja),
+			293 => Ok(Species::Whismur),
+			294 => Ok(Species::Loudred),
+			295 => Ok(Species::Exploud),
+			296 => Ok(Species::Makuhita),
+			297 => Ok(Species::Hariyama),
+			298 => Ok(Species::Azurill),
+			299 => Ok(Species::Nosepass),
+			300 => Ok(Species::Skitty),
+			301 => Ok(Species::Delcatty),
+			302 => Ok(Species::Sableye),
+			303 => Ok(Species::Mawile),
+			304 => Ok(Species::Aron),
+			305 => Ok(Species::Lairon),
+			306 => Ok(Species::Aggron),
+			307 => Ok(Species::Meditite),
+			308 => Ok(Species::Medicham),
+			309 => Ok(Species::Electrike),
+			310 => Ok(Species::Manectric),
+			311 => Ok(Species::Plusle),
+			312 => Ok(Species::Minun),
+			313 => Ok(Species::Volbeat),
+			314 => Ok(Species::Illumise),
+			315 => Ok(Species::Roselia),
+			316 => Ok(Species::Gulpin),
+			317 => Ok(Species::Swalot),
+			318 => Ok(Species::Carvanha),
+			319 => Ok(Species::Sharpedo),
+			320 => Ok(Species::Wailmer),
+			321 => Ok(Species::Wailord),
+			322 => Ok(Species::Numel),
+			323 => Ok(Species::Camerupt),
+			324 => Ok(Species::Torkoal),
+			325 => Ok(Species::Spoink),
+			326 => Ok(Species::Grumpig),
+			327 => Ok(Species::Spinda),
+			328 => Ok(Species::Trapinch),
+			329 => Ok(Species::Vibrava),
+			330 => Ok(Species::Flygon),
+			331 => Ok(Species::Cacnea),
+			332 => Ok(Species::Cacturne),
+			333 => Ok(Species::Swablu),
+			334 => Ok(Species::Altaria),
+			335 => Ok(Species::Zangoose),
+			336 => Ok(Species::Seviper),
+			337 => Ok(Species::Lunatone),
+			338 => Ok(Species::Solrock),
+			339 => Ok(Species::Barboach),
+			340 => Ok(Species::Whiscash),
+			341 => Ok(Species::Corphish),
+			342 => Ok(Species::Crawdaunt),
+			343 => Ok(Species::Baltoy),
+			344 => Ok(Species::Claydol),
+			345 => Ok(Species::Lileep),
+			346 => Ok(Species::Cradily),
+			347 => Ok(Species::Anorith),
+			348 => Ok(Species::Armaldo),
+			349 => Ok(Species::Feebas),
+			350 => Ok(Species::Milotic),
+			351 => Ok(Species::Castform),
+			352 => Ok(Species::Kecleon),
+			353 => Ok(Species::Shuppet),
+			354 => Ok(Species::Banette),
+			355 => Ok(Species::Duskull),
+			356 => Ok(Species::Dusclops),
+			357 => Ok(Species::Tropius),
+			358 => Ok(Species::Chimecho),
+			359 => Ok(Species::Absol),
+			360 => Ok(Species::Wynaut),
+			361 => Ok(Species::Snorunt),
+			362 => Ok(Species::Glalie),
+			363 => Ok(Species::Spheal),
+			364 => Ok(Species::Sealeo),
+			365 => Ok(Species::Walrein),
+			366 => Ok(Species::Clamperl),
+			367 => Ok(Species::Huntail),
+			368 => Ok(Species::Gorebyss),
+			369 => Ok(Species::Relicanth),
+			370 => Ok(Species::Luvdisc),
+			371 => Ok(Species::Bagon),
+			372 => Ok(Species::Shelgon),
+			373 => Ok(Species::Salamence),
+			374 => Ok(Species::Beldum),
+			375 => Ok(Species::Metang),
+			376 => Ok(Species::Metagross),
+			377 => Ok(Species::Regirock),
+			378 => Ok(Species::Regice),
+			379 => Ok(Species::Registeel),
+			380 => Ok(Species::Latias),
+			381 => Ok(Species::Latios),
+			382 => Ok(Species::Kyogre),
+			383 => Ok(Species::Groudon),
+			384 => Ok(Species::Rayquaza),
+			385 => Ok(Species::Jirachi),
+			386 => Ok(Species::Deoxys),
+			387 => Ok(Species::Turtwig),
+			388 => Ok(Species::Grotle),
+			389 => Ok(Species::Torterra),
+			390 => Ok(Species::Chimchar),
+			391 => Ok(Species::Monferno),
+			392 => Ok(Species::Infernape),
+			393 => Ok(Species::Piplup),
+			394 => Ok(Species::Prinplup),
+			395 => Ok(Species::Empoleon),
+			396 => Ok(Species::Starly),
+			397 => Ok(Species::Staravia),
+			398 => Ok(Species::Staraptor),
+			399 => Ok(Species::Bidoof),
+			400 => Ok(Species::Bibarel),
+			401 => Ok(Species::Kricketot),
+			402 => Ok(Species::Kricketune),
+			403 => Ok(Species::Shinx),
+			404 => Ok(Species::Luxio),
+			405 => Ok(Species::Luxray),
+			406 => Ok(Species::Budew),
+			407 => Ok(Species::Roserade),
+			408 => Ok(Species::Cranidos),
+			409 => Ok(Species::Rampardos),
+			410 => Ok(Species::Shieldon),
+			411 => Ok(Species::Bastiodon),
+			412 => Ok(Species::Burmy),
+			413 => Ok(Species::Wormadam),
+			414 => Ok(Species::Mothim),
+			415 => Ok(Species::Combee),
+			416 => Ok(Species::Vespiquen),
+			417 => Ok(Species::Pachirisu),
+			418 => Ok(Species::Buizel),
+			419 => Ok(Species::Floatzel),
+			420 => Ok(Species::Cherubi),
+			421 => Ok(Species::Cherrim),
+			422 => Ok(Species::Shellos),
+			423 => Ok(Species::Gastrodon),
+			424 => Ok(Species::Ambipom),
+			425 => Ok(Species::Drifloon),
+			426 => Ok(Species::Drifblim),
+			427 => Ok(Species::Buneary),
+			428 => Ok(Species::Lopunny),
+			429 => Ok(Species::Mismagius),
+			430 => Ok(Species::Honchkrow),
+			431 => Ok(Species::Glameow),
+			432 => Ok(Species::Purugly),
+			433 => Ok(Species::Chingling),
+			434 => Ok(Species::Stunky),
+			435 => Ok(Species::Skuntank),
+			436 => Ok(Species::Bronzor),
+			437 => Ok(Species::Bronzong),
+			438 => Ok(Species::Bonsly),
+			439 => Ok(Species::MimeJr),
+			440 => Ok(Species::Happiny),
+			441 => Ok(Species::Chatot),
+			442 => Ok(Species::Spiritomb),
+			443 => Ok(Species::Gible),
+			444 => Ok(Species::Gabite),
+			445 => Ok(Species::Garchomp),
+			446 => Ok(Species::Munchlax),
+			447 => Ok(Species::Riolu),
+			448 => Ok(Species::Lucario),
+			449 => Ok(Species::Hippopotas),
+			450 => Ok(Species::Hippowdon),
+			451 => Ok(Species::Skorupi),
+			452 => Ok(Species::Drapion),
+			453 => Ok(Species::Croagunk),
+			454 => Ok(Species::Toxicroak),
+			455 => Ok(Species::Carnivine),
+			456 => Ok(Species::Finneon),
+			457 => Ok(Species::Lumineon),
+			458 => Ok(Species::Mantyke),
+			459 => Ok(Species::Snover),
+			460 => Ok(Species::Abomasnow),
+			461 => Ok(Species::Weavile),
+			462 => Ok(Species::Magnezone),
+			463 => Ok(Species::Lickilicky),
+			464 => Ok(Species::Rhyperior),
+			465 => Ok(Species::Tangrowth),
+			466 => Ok(Species::Electivire),
+			467 => Ok(Species::Magmortar),
+			468 => Ok(Species::Togekiss),
+			469 => Ok(Species::Yanmega),
+			470 => Ok(Species::Leafeon),
+			471 => Ok(Species::Glaceon),
+			472 => Ok(Species::Gliscor),
+			473 => Ok(Species::Mamoswine),
+			474 => Ok(Species::Porygonz),
+			475 => Ok(Species::Gallade),
+			476 => Ok(Species::Probopass),
+			477 => Ok(Species::Dusknoir),
+			478 => Ok(Species::Froslass),
+			479 => Ok(Species::Rotom),
+			480 => Ok(Species::Uxie),
+			481 => Ok(Species::Mesprit),
+			482 => Ok(Species::Azelf),
+			483 => Ok(Species::Dialga),
+			484 => Ok(Species::Palkia),
+			485 => Ok(Species::Heatran),
+			486 => Ok(Species::Regigigas),
+			487 => Ok(Species::Giratina),
+			488 => Ok(Species::Cresselia),
+			489 => Ok(Species::Phione),
+			490 => Ok(Species::Manaphy),
+			491 => Ok(Species::Darkrai),
+			492 => Ok(Species::Shaymin),
+			493 => Ok(Species::Arceus),
+			494 => Ok(Species::Victini),
+			495 => Ok(Species::Snivy),
+			496 => Ok(Species::Servine),
+			497 => Ok(Species::Serperior),
+			498 => Ok(Species::Tepig),
+			499 => Ok(Species::Pignite),
+			500 => Ok(Species::Emboar),
+			501 => Ok(Species::Oshawott),
+			502 => Ok(Species::Dewott),
+			503 => Ok(Species::Samurott),
+			504 => Ok(Species::Patrat),
+			505 => Ok(Species::Watchog),
+			506 => Ok(Species::Lillipup),
+			507 => Ok(Species::Herdier),
+			508 => Ok(Species::Stoutland),
+			509 => Ok(Species::Purrloin),
+			510 => Ok(Species::Liepard),
+			511 => Ok(Species::Pansage),
+			512 => Ok(Species::Simisage),
+			513 => Ok(Species::Pansear),
+			514 => Ok(Species::Simisear),
+			515 => Ok(Species::Panpour),
+			516 => Ok(Species::Simipour),
+			517 => Ok(Species::Munna),
+			518 => Ok(Species::Musharna),
+			519 => Ok(Species::Pidove),
+			520 => Ok(Species::Tranquill),
+			521 => Ok(Species::Unfezant),
+			522 => Ok(Species::Blitzle),
+			523 => Ok(Species::Zebstrika),
+			524 => Ok(Species::Roggenrola),
+			525 => Ok(Species::Boldore),
+			526 => Ok(Species::Gigalith),
+			527 => Ok(Species::Woobat),
+			528 => Ok(Species::Swoobat),
+			529 => Ok(Species::Drilbur),
+			530 => Ok(Species::Excadrill),
+			531 => Ok(Species::Audino),
+			532 => Ok(Species::Timburr),
+			533 => Ok(Species::Gurdurr),
+			534 => Ok(Species::Conkeldurr),
+			535 => Ok(Species::Tympole),
+			536 => Ok(Species::Palpitoad),
+			537 => Ok(Species::Seismitoad),
+			538 => Ok(Species::Throh),
+			539 => Ok(Species::Sawk),
+			540 => Ok(Species::Sewaddle),
+			541 => Ok(Species::Swadloon),
+			542 => Ok(Species::Leavanny),
+			543 => Ok(Species::Venipede),
+			544 => Ok(Species::Whirlipede),
+			545 => Ok(Species::Scolipede),
+			546 => Ok(Species::Cottonee),
+			547 => Ok(Species::Whimsicott),
+			548 => Ok(Species::Petilil),
+			549 => Ok(Species::Lilligant),
+			550 => Ok(Species::Basculin),
+			551 => Ok(Species::Sandile),
+			552 => Ok(Species::Krokorok),
+			553 => Ok(Species::Krookodile),
+			554 => Ok(Species::Darumaka),
+			555 => Ok(Species::Darmanitan),
+			556 => Ok(Species::Maractus),
+			557 => Ok(Species::Dwebble),
+			558 => Ok(Species::Crustle),
+			559 => Ok(Species::Scraggy),
+			560 => Ok(Species::Scrafty),
+			561 => Ok(Species::Sigilyph),
+			562 => Ok(Species::Yamask),
+			563 => Ok(Species::Cofagrigus),
+			564 => Ok(Species::Tirtouga),
+			565 => Ok(Species::Carracosta),
+			566 => Ok(Species::Archen),
+			567 => Ok(Species::Archeops),
+			568 => Ok(Species::Trubbish),
+			569 => Ok(Species::Garbodor),
+			570 => Ok(Species::Zorua),
+			571 => Ok(Species::Zoroark),
+			572 => Ok(Species::Minccino),
+			573 => Ok(Species::Cinccino),
+			574 => Ok(Species::Gothita),
+			575 => Ok(Species::Gothorita),
+			576 => Ok(Species::Gothitelle),
+			577 => Ok(Species::Solosis),
+			578 => Ok(Species::Duosion),
+			579 => Ok(Species::Reuniclus),
+			580 => Ok(Species::Ducklett),
+			581 => Ok(Species::Swanna),
+			582 => Ok(Species::Vanillite),
+			583 => Ok(Species::Vanillish),
+			584 => Ok(Species::Vanilluxe),
+			585 => Ok(Species::Deerling),
+			586 => Ok(Species::Sawsbuck),
+			587 => Ok(Species::Emolga),
+			588 => Ok(Species::Karrablast),
+			589 => Ok(Species::Escavalier),
+			590 => Ok(Species::Foongus),
+			591 => Ok(Species::Amoonguss),
+			592 => Ok(Species::Frillish),
+			593 => Ok(Species::Jellicent),
+			594 => Ok(Species::Alomomola),
+			595 => Ok(Species::Joltik),
+			596 => Ok(Species::Galvantula),
+			597 => Ok(Species::Ferroseed),
+			598 => Ok(Species::Ferrothorn),
+			599 => Ok(Species::Klink),
+			600 => Ok(Species::Klang),
+			601 => Ok(Species::Klinklang),
+			602 => Ok(Species::Tynamo),
+			603 => Ok(Species::Eelektrik),
+			604 => Ok(Species::Eelektross),
+			605 => Ok(Species::Elgyem),
+			606 => Ok(Species::Beheeyem),
+			607 => Ok(Species::Litwick),
+			608 => Ok(Species::Lampent),
+			609 => Ok(Species::Chandelure),
+			610 => Ok(Species::Axew),
+			611 => Ok(Species::Fraxure),
+			612 => Ok(Species::Haxorus),
+			613 => Ok(Species::Cubchoo),
+			614 => Ok(Species::Beartic),
+			615 => Ok(Species::Cryogonal),
+			616 => Ok(Species::Shelmet),
+			617 => Ok(Species::Accelgor),
+			618 => Ok(Species::Stunfisk),
+			619 => Ok(Species::Mienfoo),
+			620 => Ok(Species::Mienshao),
+			621 => Ok(Species::Druddigon),
+			622 => Ok(Species::Golett),
+			623 => Ok(Species::Golurk),
+			624 => Ok(Species::Pawniard),
+			625 => Ok(Species::Bisharp),
+			626 => Ok(Species::Bouffalant),
+			627 => Ok(Species::Rufflet),
+			628 => Ok(Species::Braviary),
+			629 => Ok(Species::Vullaby),
+			630 => Ok(Species::Mandibuzz),
+			631 => Ok(Species::Heatmor),
+			632 => Ok(Species::Durant),
+			633 => Ok(Species::Deino),
+			634 => Ok(Species::Zweilous),
+			635 => Ok(Species::Hydreigon),
+			636 => Ok(Species::Larvesta),
+			637 => Ok(Species::Volcarona),
+			638 => Ok(Species::Cobalion),
+			639 => Ok(Species::Terrakion),
+			640 => Ok(Species::Virizion),
+			641 => Ok(Species::Tornadus),
+			642 => Ok(Species::Thundurus),
+			643 => Ok(Species::Reshiram),
+			644 => Ok(Species::Zekrom),
+			645 => Ok(Species::Landorus),
+			646 => Ok(Species::Kyurem),
+			647 => Ok(Species::Keldeo),
+			648 => Ok(Species::Meloetta),
+			649 => Ok(Species::Genesect),
+			650 => Ok(Species::Chespin),
+			651 => Ok(Species::Quilladin),
+			652 => Ok(Species::Chesnaught),
+			653 => Ok(Species::Fennekin),
+			654 => Ok(Species::Braixen),
+			655 => Ok(Species::Delphox),
+			656 => Ok(Species::Froakie),
+			657 => Ok(Species::Frogadier),
+			658 => Ok(Species::Greninja),
+			659 => Ok(Species::Bunnelby),
+			660 => Ok(Species::Diggersby),
+			661 => Ok(Species::Fletchling),
+			662 => Ok(Species::Fletchinder),
+			663 => Ok(Species::Talonflame),
+			664 => Ok(Species::Scatterbug),
+			665 => Ok(Species::Spewpa),
+			666 => Ok(Species::Vivillon),
+			667 => Ok(Species::Litleo),
+			668 => Ok(Species::Pyroar),
+			669 => Ok(Species::Flabebe),
+			670 => Ok(Species::Floette),
+			671 => Ok(Species::Florges),
+			672 => Ok(Species::Skiddo),
+			673 => Ok(Species::Gogoat),
+			674 => Ok(Species::Pancham),
+			675 => Ok(Species::Pangoro),
+			676 => Ok(Species::Furfrou),
+			677 => Ok(Species::Espurr),
+			678 => Ok(Species::Meowstic),
+			679 => Ok(Species::Honedge),
+			680 => Ok(Species::Doublade),
+			681 => Ok(Species::Aegislash),
+			682 => Ok(Species::Spritzee),
+			683 => Ok(Species::Aromatisse),
+			684 => Ok(Species::Swirlix),
+			685 => Ok(Species::Slurpuff),
+			686 => Ok(Species::Inkay),
+			687 => Ok(Species::Malamar),
+			688 => Ok(Species::Binacle),
+			689 => Ok(Species::Barbaracle),
+			690 => Ok(Species::Skrelp),
+			691 => Ok(Species::Dragalge),
+			692 => Ok(Species::Clauncher),
+			693 => Ok(Species::Clawitzer),
+			694 => Ok(Species::Helioptile),
+			695 => Ok(Species::Heliolisk),
+			696 => Ok(Species::Tyrunt),
+			697 => Ok(Species::Tyrantrum),
+			698 => Ok(Species::Amaura),
+			699 => Ok(Species::Aurorus),
+			700 => Ok(Species::Sylveon),
+			701 => Ok(Species::Hawlucha),
+			702 => Ok(Species::Dedenne),
+			703 => Ok(Species::Carbink),
+			704 => Ok(Species::Goomy),
+			705 => Ok(Species::Sliggoo),
+			706 => Ok(Species::Goodra),
+			707 => Ok(Species::Klefki),
+			708 => Ok(Species::Phantump),
+			709 => Ok(Species::Trevenant),
+			710 => Ok(Species::Pumpkaboo),
+			711 => Ok(Species::Gourgeist),
+			712 => Ok(Species::Bergmite),
+			713 => Ok(Species::Avalugg),
+			714 => Ok(Species::Noibat),
+			715 => Ok(Species::Noivern),
+			716 => Ok(Species::Xerneas),
+			717 => Ok(Species::Yveltal),
+			718 => Ok(Species::Zygarde),
+			719 => Ok(Species::Diancie),
+			720 => Ok(Species::Hoopa),
+			721 => Ok(Species::Volcanion),
+			722 => Ok(Species::Rowlet),
+			723 => Ok(Species::Dartrix),
+			724 => Ok(Species::Decidueye),
+			725 => Ok(Species::Litten),
+			726 => Ok(Species::Torracat),
+			727 => Ok(Species::Incineroar),
+			728 => Ok(Species::Popplio),
+			729 => Ok(Species::Brionne),
+			730 => Ok(Species::Primarina),
+			731 => Ok(Species::Pikipek),
+			732 => Ok(Species::Trumbeak),
+			733 => Ok(Species::Toucannon),
+			734 => Ok(Species::Yungoos),
+			735 => Ok(Species::Gumshoos),
+			736 => Ok(Species::Grubbin),
+			737 => Ok(Species::Charjabug),
+			738 => Ok(Species::Vikavolt),
+			739 => Ok(Species::Crabrawler),
+			740 => Ok(Species::Crabominable),
+			741 => Ok(Species::Oricorio),
+			742 => Ok(Species::Cutiefly),
+			743 => Ok(Species::Ribombee),
+			744 => Ok(Species::Rockruff),
+			745 => Ok(Species::Lycanroc),
+			746 => Ok(Species::Wishiwashi),
+			747 => Ok(Species::Mareanie),
+			748 => Ok(Species::Toxapex),
+			749 => Ok(Species::Mudbray),
+			750 => Ok(Species::Mudsdale),
+			751 => Ok(Species::Dewpider),
+			752 => Ok(Species::Araquanid),
+			753 => Ok(Species::Fomantis),
+			754 => Ok(Species::Lurantis),
+			755 => Ok(Species::Morelull),
+			756 => Ok(Species::Shiinotic),
+			757 => Ok(Species::Salandit),
+			758 => Ok(Species::Salazzle),
+			759 => Ok(Species::Stufful),
+			760 => Ok(Species::Bewear),
+			761 => Ok(Species::Bounsweet),
+			762 => Ok(Species::Steenee),
+			763 => Ok(Species::Tsareena),
+			764 => Ok(Species::Comfey),
+			765 => Ok(Species::Oranguru),
+			766 => Ok(Species::Passimian),
+			767 => Ok(Species::Wimpod),
+			768 => Ok(Species::Golisopod),
+			769 => Ok(Species::Sandygast),
+			770 => Ok(Species::Palossand),
+			771 => Ok(Species::Pyukumuku),
+			772 => Ok(Species::TypeNull),
+			773 => Ok(Species::Silvally),
+			774 => Ok(Species::Minior),
+			775 => Ok(Species::Komala),
+			776 => Ok(Species::Turtonator),
+			777 => Ok(Species::Togedemaru),
+			778 => Ok(Species::Mimikyu),
+			779 => Ok(Species::Bruxish),
+			780 => Ok(Species::Drampa),
+			781 => Ok(Species::Dhelmise),
+			782 => Ok(Species::Jangmoo),
+			783 => Ok(Species::Hakamoo),
+			784 => Ok(Species::Kommoo),
+			785 => Ok(Species::TapuKoko),
+			786 => Ok(Species::TapuLele),
+			787 => Ok(Species::TapuBulu),
+			788 => Ok(Species::TapuFini),
+			789 => Ok(Species::Cosmog),
+			790 => Ok(Species::Cosmoem),
+			791 => Ok(Species::Solgaleo),
+			792 => Ok(Species::Lunala),
+			793 => Ok(Species::Nihilego),
+			794 => Ok(Species::Buzzwole),
+			795 => Ok(Species::Pheromosa),
+			796 => Ok(Species::Xurkitree),
+			797 => Ok(Species::Celesteela),
+			798 => Ok(Species::Kartana),
+			799 => Ok(Species::Guzzlord),
+			800 => Ok(Species::Necrozma),
+			801 => Ok(Species::Magearna),
+			802 => Ok(Species::Marshadow),
+			803 => Ok(Species::Poipole),
+			804 => Ok(Species::Naganadel),
+			805 => Ok(Species::Stakataka),
+			806 => Ok(Species::Blacephalon),
+			807 => Ok(Species::Zeraora),
+			808 => Ok(Species::Meltan),
+			809 => Ok(Species::Melmetal),
+			810 => Ok(Species::Grookey),
+			811 => Ok(Species::Thwackey),
+			812 => Ok(Species::Rillaboom),
+			813 => Ok(Species::Scorbunny),
+			814 => Ok(Species::Raboot),
+			815 => Ok(Species::Cinderace),
+			816 => Ok(Species::Sobble),
+			817 => Ok(Species::Drizzile),
+			818 => Ok(Species::Inteleon),
+			819 => Ok(Species::Skwovet),
+			820 => Ok(Species::Greedent),
+			821 => Ok(Species::Rookidee),
+			822 => Ok(Species::Corvisquire),
+			823 => Ok(Species::Corviknight),
+			824 => Ok(Species::Blipbug),
+			825 => Ok(Species::Dottler),
+			826 => Ok(Species::Orbeetle),
+			827 => Ok(Species::Nickit),
+			828 => Ok(Species::Thievul),
+			829 => Ok(Species::Gossifleur),
+			830 => Ok(Species::Eldegoss),
+			831 => Ok(Species::Wooloo),
+			832 => Ok(Species::Dubwool),
+			833 => Ok(Species::Chewtle),
+			834 => Ok(Species::Drednaw),
+			835 => Ok(Species::Yamper),
+			836 => Ok(Species::Boltund),
+			837 => Ok(Species::Rolycoly),
+			838 => Ok(Species::Carkol),
+			839 => Ok(Species::Coalossal),
+			840 => Ok(Species::Applin),
+			841 => Ok(Species::Flapple),
+			842 => Ok(Species::Appletun),
+			843 => Ok(Species::Silicobra),
+			844 => Ok(Species::Sandaconda),
+			845 => Ok(Species::Cramorant),
+			846 => Ok(Species::Arrokuda),
+			847 => Ok(Species::Barraskewda),
+			848 => Ok(Species::Toxel),
+			849 => Ok(Species::Toxtricity),
+			850 => Ok(Species::Sizzlipede),
+			851 => Ok(Species::Centiskorch),
+			852 => Ok(Species::Clobbopus),
+			853 => Ok(Species::Grapploct),
+			854 => Ok(Species::Sinistea),
+			855 => Ok(Species::Polteageist),
+			856 => Ok(Species::Hatenna),
+			857 => Ok(Species::Hattrem),
+			858 => Ok(Species::Hatterene),
+			859 => Ok(Species::Impidimp),
+			860 => Ok(Species::Morgrem),
+			861 => Ok(Species::Grimmsnarl),
+			862 => Ok(Species::Obstagoon),
+			863 => Ok(Species::Perrserker),
+			864 => Ok(Species::Cursola),
+			865 => Ok(Species::Sirfetchd),
+			866 => Ok(Species::MrRime),
+			867 => Ok(Species::Runerigus),
+			868 => Ok(Species::Milcery),
+			869 => Ok(Species::Alcremie),
+			870 => Ok(Species::Falinks),
+			871 => Ok(Species::Pincurchin),
+			872 => Ok(Species::Snom),
+			873 => Ok(Species::Frosmoth),
+			874 => Ok(Species::Stonjourner),
+			875 => Ok(Species::Eiscue),
+			876 => Ok(Species::Indeedee),
+			877 => Ok(Species::Morpeko),
+			878 => Ok(Species::Cufant),
+			879 => Ok(Species::Copperajah),
+			880 => Ok(Species::Dracozolt),
+			881 => Ok(Species::Arctozolt),
+			882 => Ok(Species::Dracovish),
+			883 => Ok(Species::Arctovish),
+			884 => Ok(Species::Duraludon),
+			885 => Ok(Species::Dreepy),
+			886 => Ok(Species::Drakloak),
+			887 => Ok(Species::Dragapult),
+			888 => Ok(Species::Zacian),
+			889 => Ok(Species::Zamazenta),
+			890 => Ok(Species::Eternatus),
+			891 => Ok(Species::Kubfu),
+			892 => Ok(Species::Urshifu),
+			893 => Ok(Species::Zarude),
+			894 => Ok(Species::Regieleki),
+			895 => Ok(Species::Regidrago),
+			896 => Ok(Species::Glastrier),
+			897 => Ok(Species::Spectrier),
+			898 => Ok(Species::Calyrex),
+            _ => Err(UnknownValue { name: "Species", value })
         }
     }
 }
 
-impl Into<u16> for Species {
-	fn into(self) -> u16 {
-		self as u16
-	}
+impl From<Species> for u16 {
+    fn from(value: Species) -> Self {
+        match value {
+			Species::Bulbasaur => 1,
+			Species::Ivysaur => 2,
+			Species::Venusaur => 3,
+			Species::Charmander => 4,
+			Species::Charmeleon => 5,
+			Species::Charizard => 6,
+			Species::Squirtle => 7,
+			Species::Wartortle => 8,
+			Species::Blastoise => 9,
+			Species::Caterpie => 10,
+			Species::Metapod => 11,
+			Species::Butterfree => 12,
+			Species::Weedle => 13,
+			Species::Kakuna => 14,
+			Species::Beedrill => 15,
+			Species::Pidgey => 16,
+			Species::Pidgeotto => 17,
+			Species::Pidgeot => 18,
+			Species::Rattata => 19,
+			Species::Raticate => 20,
+			Species::Spearow => 21,
+			Species::Fearow => 22,
+			Species::Ekans => 23,
+			Species::Arbok => 24,
+			Species::Pikachu => 25,
+			Species::Raichu => 26,
+			Species::Sandshrew => 27,
+			Species::Sandslash => 28,
+			Species::NidoranFemale => 29,
+			Species::Nidorina => 30,
+			Species::Nidoqueen => 31,
+			Species::NidoranMale => 32,
+			Species::Nidorino => 33,
+			Species::Nidoking => 34,
+			Species::Clefairy => 35,
+			Species::Clefable => 36,
+			Species::Vulpix => 37,
+			Species::Ninetales => 38,
+			Species::Jigglypuff => 39,
+			Species::Wigglytuff => 40,
+			Species::Zubat => 41,
+			Species::Golbat => 42,
+			Species::Oddish => 43,
+			Species::Gloom => 44,
+			Species::Vileplume => 45,
+			Species::Paras => 46,
+			Species::Parasect => 47,
+			Species::Venonat => 48,
+			Species::Venomoth => 49,
+			Species::Diglett => 50,
+			Species::Dugtrio => 51,
+			Species::Meowth => 52,
+			Species::Persian => 53,
+			Species::Psyduck => 54,
+			Species::Golduck => 55,
+			Species::Mankey => 56,
+			Species::Primeape => 57,
+			Species::Growlithe => 58,
+			Species::Arcanine => 59,
+			Species::Poliwag => 60,
+			Species::Poliwhirl => 61,
+			Species::Poliwrath => 62,
+			Species::Abra => 63,
+			Species::Kadabra => 64,
+			Species::Alakazam => 65,
+			Species::Machop => 66,
+			Species::Machoke => 67,
+			Species::Machamp => 68,
+			Species::Bellsprout => 69,
+			Species::Weepinbell => 70,
+			Species::Victreebel => 71,
+			Species::Tentacool => 72,
+			Species::Tentacruel => 73,
+			Species::Geodude => 74,
+			Species::Graveler => 75,
+			Species::Golem => 76,
+			Species::Ponyta => 77,
+			Species::Rapidash => 78,
+			Species::Slowpoke => 79,
+			Species::Slowbro => 80,
+			Species::Magnemite => 81,
+			Species::Magneton => 82,
+			Species::Farfetchd => 83,
+			Species::Doduo => 84,
+			Species::Dodrio => 85,
+			Species::Seel => 86,
+			Species::Dewgong => 87,
+			Species::Grimer => 88,
+			Species::Muk => 89,
+			Species::Shellder => 90,
+			Species::Cloyster => 91,
+			Species::Gastly => 92,
+			Species::Haunter => 93,
+			Species::Gengar => 94,
+			Species::Onix => 95,
+			Species::Drowzee => 96,
+			Species::Hypno => 97,
+			Species::Krabby => 98,
+			Species::Kingler => 99,
+			Species::Voltorb => 100,
+			Species::Electrode => 101,
+			Species::Exeggcute => 102,
+			Species::Exeggutor => 103,
+			Species::Cubone => 104,
+			Species::Marowak => 105,
+			Species::Hitmonlee => 106,
+			Species::Hitmonchan => 107,
+			Species::Lickitung => 108,
+			Species::Koffing => 109,
+			Species::Weezing => 110,
+			Species::Rhyhorn => 111,
+			Species::Rhydon => 112,
+			Species::Chansey => 113,
+			Species::Tangela => 114,
+			Species::Kangaskhan => 115,
+			Species::Horsea => 116,
+			Species::Seadra => 117,
+			Species::Goldeen => 118,
+			Species::Seaking => 119,
+			Species::Staryu => 120,
+			Species::Starmie => 121,
+			Species::MrMime => 122,
+			Species::Scyther => 123,
+			Species::Jynx => 124,
+			Species::Electabuzz => 125,
+			Species::Magmar => 126,
+			Species::Pinsir => 127,
+			Species::Tauros => 128,
+			Species::Magikarp => 129,
+			Species::Gyarados => 130,
+			Species::Lapras => 131,
+			Species::Ditto => 132,
+			Species::Eevee => 133,
+			Species::Vaporeon => 134,
+			Species::Jolteon => 135,
+			Species::Flareon => 136,
+			Species::Porygon => 137,
+			Species::Omanyte => 138,
+			Species::Omastar => 139,
+			Species::Kabuto => 140,
+			Species::Kabutops => 141,
+			Species::Aerodactyl => 142,
+			Species::Snorlax => 143,
+			Species::Articuno => 144,
+			Species::Zapdos => 145,
+			Species::Moltres => 146,
+			Species::Dratini => 147,
+			Species::Dragonair => 148,
+			Species::Dragonite => 149,
+			Species::Mewtwo => 150,
+			Species::Mew => 151,
+			Species::Chikorita => 152,
+			Species::Bayleef => 153,
+			Species::Meganium => 154,
+			Species::Cyndaquil => 155,
+			Species::Quilava => 156,
+			Species::Typhlosion => 157,
+			Species::Totodile => 158,
+			Species::Croconaw => 159,
+			Species::Feraligatr => 160,
+			Species::Sentret => 161,
+			Species::Furret => 162,
+			Species::Hoothoot => 163,
+			Species::Noctowl => 164,
+			Species::Ledyba => 165,
+			Species::Ledian => 166,
+			Species::Spinarak => 167,
+			Species::Ariados => 168,
+			Species::Crobat => 169,
+			Species::Chinchou => 170,
+			Species::Lanturn => 171,
+			Species::Pichu => 172,
+			Species::Cleffa => 173,
+			Species::Igglybuff => 174,
+			Species::Togepi => 175,
+			Species::Togetic => 176,
+			Species::Natu => 177,
+			Species::Xatu => 178,
+			Species::Mareep => 179,
+			Species::Flaaffy => 180,
+			Species::Ampharos => 181,
+			Species::Bellossom => 182,
+			Species::Marill => 183,
+			Species::Azumarill => 184,
+			Species::Sudowoodo => 185,
+			Species::Politoed => 186,
+			Species::Hoppip => 187,
+			Species::Skiploom => 188,
+			Species::Jumpluff => 189,
+			Species::Aipom => 190,
+			Species::Sunkern => 191,
+			Species::Sunflora => 192,
+			Species::Yanma => 193,
+			Species::Wooper => 194,
+			Species::Quagsire => 195,
+			Species::Espeon => 196,
+			Species::Umbreon => 197,
+			Species::Murkrow => 198,
+			Species::Slowking => 199,
+			Species::Misdreavus => 200,
+			Species::Unown => 201,
+			Species::Wobbuffet => 202,
+			Species::Girafarig => 203,
+			Species::Pineco => 204,
+			Species::Forretress => 205,
+			Species::Dunsparce => 206,
+			Species::Gligar => 207,
+			Species::Steelix => 208,
+			Species::Snubbull => 209,
+			Species::Granbull => 210,
+			Species::Qwilfish => 211,
+			Species::Scizor => 212,
+			Species::Shuckle => 213,
+			Species::Heracross => 214,
+			Species::Sneasel => 215,
+			Species::Teddiursa => 216,
+			Species::Ursaring => 217,
+			Species::Slugma => 218,
+			Species::Magcargo => 219,
+			Species::Swinub => 220,
+			Species::Piloswine => 221,
+			Species::Corsola => 222,
+			Species::Remoraid => 223,
+			Species::Octillery => 224,
+			Species::Delibird => 225,
+			Species::Mantine => 226,
+			Species::Skarmory => 227,
+			Species::Houndour => 228,
+			Species::Houndoom => 229,
+			Species::Kingdra => 230,
+			Species::Phanpy => 231,
+			Species::Donphan => 232,
+			Species::Porygon2 => 233,
+			Species::Stantler => 234,
+			Species::Smeargle => 235,
+			Species::Tyrogue => 236,
+			Species::Hitmontop => 237,
+			Species::Smoochum => 238,
+			Species::Elekid => 239,
+			Species::Magby => 240,
+			Species::Miltank => 241,
+			Species::Blissey => 242,
+			Species::Raikou => 243,
+			Species::Entei => 244,
+			Species::Suicune => 245,
+			Species::Larvitar => 246,
+			Species::Pupitar => 247,
+			Species::Tyranitar => 248,
+			Species::Lugia => 249,
+			Species::Hooh => 250,
+			Species::Celebi => 251,
+			Species::Treecko => 252,
+			Species::Grovyle => 253,
+			Species::Sceptile => 254,
+			Species::Torchic => 255,
+			Species::Combusken => 256,
+			Species::Blaziken => 257,
+			Species::Mudkip => 258,
+			Species::Marshtomp => 259,
+			Species::Swampert => 260,
+			Species::Poochyena => 261,
+			Species::Mightyena => 262,
+			Species::Zigzagoon => 263,
+			Species::Linoone => 264,
+			Species::Wurmple => 265,
+			Species::Silcoon => 266,
+			Species::Beautifly => 267,
+			Species::Cascoon => 268,
+			Species::Dustox => 269,
+			Species::Lotad => 270,
+			Species::Lombre => 271,
+			Species::Ludicolo => 272,
+			Species::Seedot => 273,
+			Species::Nuzleaf => 274,
+			Species::Shiftry => 275,
+			Species::Taillow => 276,
+			Species::Swellow => 277,
+			Species::Wingull => 278,
+			Species::Pelipper => 279,
+			Species::Ralts => 280,
+			Species::Kirlia => 281,
+			Species::Gardevoir => 282,
+			Species::Surskit => 283,
+			Species::Masquerain => 284,
+			Species::Shroomish => 285,
+			Species::Breloom => 286,
+			Species::Slakoth => 287,
+			Species::Vigoroth => 288,
+			Species::Slaking => 289,
+			Species::Nincada => 290,
+			Species::Ninjask => 291,
+			Species::Shedinja => 292,
+			Species::Whismur => 293,
+			Species::Loudred => 294,
+			Species::Exploud => 295,
+			Species::Makuhita => 296,
+			Species::Hariyama => 297,
+			Species::Azurill => 298,
+			Species::Nosepass => 299,
+			Species::Skitty => 300,
+			Species::Delcatty => 301,
+			Species::Sableye => 302,
+			Species::Mawile => 303,
+			Species::Aron => 304,
+			Species::Lairon => 305,
+			Species::Aggron => 306,
+			Species::Meditite => 307,
+			Species::Medicham => 308,
+			Species::Electrike => 309,
+			Species::Manectric => 310,
+			Species::Plusle => 311,
+			Species::Minun => 312,
+			Species::Volbeat => 313,
+			Species::Illumise => 314,
+			Species::Roselia => 315,
+			Species::Gulpin => 316,
+			Species::Swalot => 317,
+			Species::Carvanha => 318,
+			Species::Sharpedo => 319,
+			Species::Wailmer => 320,
+			Species::Wailord => 321,
+			Species::Numel => 322,
+			Species::Camerupt => 323,
+			Species::Torkoal => 324,
+			Species::Spoink => 325,
+			Species::Grumpig => 326,
+			Species::Spinda => 327,
+			Species::Trapinch => 328,
+			Species::Vibrava => 329,
+			Species::Flygon => 330,
+			Species::Cacnea => 331,
+			Species::Cacturne => 332,
+			Species::Swablu => 333,
+			Species::Altaria => 334,
+			Species::Zangoose => 335,
+			Species::Seviper => 336,
+			Species::Lunatone => 337,
+			Species::Solrock => 338,
+			Species::Barboach => 339,
+			Species::Whiscash => 340,
+			Species::Corphish => 341,
+			Species::Crawdaunt => 342,
+			Species::Baltoy => 343,
+			Species::Claydol => 344,
+			Species::Lileep => 345,
+			Species::Cradily => 346,
+			Species::Anorith => 347,
+			Species::Armaldo => 348,
+			Species::Feebas => 349,
+			Species::Milotic => 350,
+			Species::Castform => 351,
+			Species::Kecleon => 352,
+			Species::Shuppet => 353,
+			Species::Banette => 354,
+			Species::Duskull => 355,
+			Species::Dusclops => 356,
+			Species::Tropius => 357,
+			Species::Chimecho => 358,
+			Species::Absol => 359,
+			Species::Wynaut => 360,
+			Species::Snorunt => 361,
+			Species::Glalie => 362,
+			Species::Spheal => 363,
+			Species::Sealeo => 364,
+			Species::Walrein => 365,
+			Species::Clamperl => 366,
+			Species::Huntail => 367,
+			Species::Gorebyss => 368,
+			Species::Relicanth => 369,
+			Species::Luvdisc => 370,
+			Species::Bagon => 371,
+			Species::Shelgon => 372,
+			Species::Salamence => 373,
+			Species::Beldum => 374,
+			Species::Metang => 375,
+			Species::Metagross => 376,
+			Species::Regirock => 377,
+			Species::Regice => 378,
+			Species::Registeel => 379,
+			Species::Latias => 380,
+			Species::Latios => 381,
+			Species::Kyogre => 382,
+			Species::Groudon => 383,
+			Species::Rayquaza => 384,
+			Species::Jirachi => 385,
+			Species::Deoxys => 386,
+			Species::Turtwig => 387,
+			Species::Grotle => 388,
+			Species::Torterra => 389,
+			Species::Chimchar => 390,
+			Species::Monferno => 391,
+			Species::Infernape => 392,
+			Species::Piplup => 393,
+			Species::Prinplup => 394,
+			Species::Empoleon => 395,
+			Species::Starly => 396,
+			Species::Staravia => 397,
+			Species::Staraptor => 398,
+			Species::Bidoof => 399,
+			Species::Bibarel => 400,
+			Species::Kricketot => 401,
+			Species::Kricketune => 402,
+			Species::Shinx => 403,
+			Species::Luxio => 404,
+			Species::Luxray => 405,
+			Species::Budew => 406,
+			Species::Roserade => 407,
+			Species::Cranidos => 408,
+			Species::Rampardos => 409,
+			Species::Shieldon => 410,
+			Species::Bastiodon => 411,
+			Species::Burmy => 412,
+			Species::Wormadam => 413,
+			Species::Mothim => 414,
+			Species::Combee => 415,
+			Species::Vespiquen => 416,
+			Species::Pachirisu => 417,
+			Species::Buizel => 418,
+			Species::Floatzel => 419,
+			Species::Cherubi => 420,
+			Species::Cherrim => 421,
+			Species::Shellos => 422,
+			Species::Gastrodon => 423,
+			Species::Ambipom => 424,
+			Species::Drifloon => 425,
+			Species::Drifblim => 426,
+			Species::Buneary => 427,
+			Species::Lopunny => 428,
+			Species::Mismagius => 429,
+			Species::Honchkrow => 430,
+			Species::Glameow => 431,
+			Species::Purugly => 432,
+			Species::Chingling => 433,
+			Species::Stunky => 434,
+			Species::Skuntank => 435,
+			Species::Bronzor => 436,
+			Species::Bronzong => 437,
+			Species::Bonsly => 438,
+			Species::MimeJr => 439,
+			Species::Happiny => 440,
+			Species::Chatot => 441,
+			Species::Spiritomb => 442,
+			Species::Gible => 443,
+			Species::Gabite => 444,
+			Species::Garchomp => 445,
+			Species::Munchlax => 446,
+			Species::Riolu => 447,
+			Species::Lucario => 448,
+			Species::Hippopotas => 449,
+			Species::Hippowdon => 450,
+			Species::Skorupi => 451,
+			Species::Drapion => 452,
+			Species::Croagunk => 453,
+			Species::Toxicroak => 454,
+			Species::Carnivine => 455,
+			Species::Finneon => 456,
+			Species::Lumineon => 457,
+			Species::Mantyke => 458,
+			Species::Snover => 459,
+			Species::Abomasnow => 460,
+			Species::Weavile => 461,
+			Species::Magnezone => 462,
+			Species::Lickilicky => 463,
+			Species::Rhyperior => 464,
+			Species::Tangrowth => 465,
+			Species::Electivire => 466,
+			Species::Magmortar => 467,
+			Species::Togekiss => 468,
+			Species::Yanmega => 469,
+			Species::Leafeon => 470,
+			Species::Glaceon => 471,
+			Species::Gliscor => 472,
+			Species::Mamoswine => 473,
+			Species::Porygonz => 474,
+			Species::Gallade => 475,
+			Species::Probopass => 476,
+			Species::Dusknoir => 477,
+			Species::Froslass => 478,
+			Species::Rotom => 479,
+			Species::Uxie => 480,
+			Species::Mesprit => 481,
+			Species::Azelf => 482,
+			Species::Dialga => 483,
+			Species::Palkia => 484,
+			Species::Heatran => 485,
+			Species::Regigigas => 486,
+			Species::Giratina => 487,
+			Species::Cresselia => 488,
+			Species::Phione => 489,
+			Species::Manaphy => 490,
+			Species::Darkrai => 491,
+			Species::Shaymin => 492,
+			Species::Arceus => 493,
+			Species::Victini => 494,
+			Species::Snivy => 495,
+			Species::Servine => 496,
+			Species::Serperior => 497,
+			Species::Tepig => 498,
+			Species::Pignite => 499,
+			Species::Emboar => 500,
+			Species::Oshawott => 501,
+			Species::Dewott => 502,
+			Species::Samurott => 503,
+			Species::Patrat => 504,
+			Species::Watchog => 505,
+			Species::Lillipup => 506,
+			Species::Herdier => 507,
+			Species::Stoutland => 508,
+			Species::Purrloin => 509,
+			Species::Liepard => 510,
+			Species::Pansage => 511,
+			Species::Simisage => 512,
+			Species::Pansear => 513,
+			Species::Simisear => 514,
+			Species::Panpour => 515,
+			Species::Simipour => 516,
+			Species::Munna => 517,
+			Species::Musharna => 518,
+			Species::Pidove => 519,
+			Species::Tranquill => 520,
+			Species::Unfezant => 521,
+			Species::Blitzle => 522,
+			Species::Zebstrika => 523,
+			Species::Roggenrola => 524,
+			Species::Boldore => 525,
+			Species::Gigalith => 526,
+			Species::Woobat => 527,
+			Species::Swoobat => 528,
+			Species::Drilbur => 529,
+			Species::Excadrill => 530,
+			Species::Audino => 531,
+			Species::Timburr => 532,
+			Species::Gurdurr => 533,
+			Species::Conkeldurr => 534,
+			Species::Tympole => 535,
+			Species::Palpitoad => 536,
+			Species::Seismitoad => 537,
+			Species::Throh => 538,
+			Species::Sawk => 539,
+			Species::Sewaddle => 540,
+			Species::Swadloon => 541,
+			Species::Leavanny => 542,
+			Species::Venipede => 543,
+			Species::Whirlipede => 544,
+			Species::Scolipede => 545,
+			Species::Cottonee => 546,
+			Species::Whimsicott => 547,
+			Species::Petilil => 548,
+			Species::Lilligant => 549,
+			Species::Basculin => 550,
+			Species::Sandile => 551,
+			Species::Krokorok => 552,
+			Species::Krookodile => 553,
+			Species::Darumaka => 554,
+			Species::Darmanitan => 555,
+			Species::Maractus => 556,
+			Species::Dwebble => 557,
+			Species::Crustle => 558,
+			Species::Scraggy => 559,
+			Species::Scrafty => 560,
+			Species::Sigilyph => 561,
+			Species::Yamask => 562,
+			Species::Cofagrigus => 563,
+			Species::Tirtouga => 564,
+			Species::Carracosta => 565,
+			Species::Archen => 566,
+			Species::Archeops => 567,
+			Species::Trubbish => 568,
+			Species::Garbodor => 569,
+			Species::Zorua => 570,
+			Species::Zoroark => 571,
+			Species::Minccino => 572,
+			Species::Cinccino => 573,
+			Species::Gothita => 574,
+			Species::Gothorita => 575,
+			Species::Gothitelle => 576,
+			Species::Solosis => 577,
+			Species::Duosion => 578,
+			Species::Reuniclus => 579,
+			Species::Ducklett => 580,
+			Species::Swanna => 581,
+			Species::Vanillite => 582,
+			Species::Vanillish => 583,
+			Species::Vanilluxe => 584,
+			Species::Deerling => 585,
+			Species::Sawsbuck => 586,
+			Species::Emolga => 587,
+			Species::Karrablast => 588,
+			Species::Escavalier => 589,
+			Species::Foongus => 590,
+			Species::Amoonguss => 591,
+			Species::Frillish => 592,
+			Species::Jellicent => 593,
+			Species::Alomomola => 594,
+			Species::Joltik => 595,
+			Species::Galvantula => 596,
+			Species::Ferroseed => 597,
+			Species::Ferrothorn => 598,
+			Species::Klink => 599,
+			Species::Klang => 600,
+			Species::Klinklang => 601,
+			Species::Tynamo => 602,
+			Species::Eelektrik => 603,
+			Species::Eelektross => 604,
+			Species::Elgyem => 605,
+			Species::Beheeyem => 606,
+			Species::Litwick => 607,
+			Species::Lampent => 608,
+			Species::Chandelure => 609,
+			Species::Axew => 610,
+			Species::Fraxure => 611,
+			Species::Haxorus => 612,
+			Species::Cubchoo => 613,
+			Species::Beartic => 614,
+			Species::Cryogonal => 615,
+			Species::Shelmet => 616,
+			Species::Accelgor => 617,
+			Species::Stunfisk => 618,
+			Species::Mienfoo => 619,
+			Species::Mienshao => 620,
+			Species::Druddigon => 621,
+			Species::Golett => 622,
+			Species::Golurk => 623,
+			Species::Pawniard => 624,
+			Species::Bisharp => 625,
+			Species::Bouffalant => 626,
+			Species::Rufflet => 627,
+			Species::Braviary => 628,
+			Species::Vullaby => 629,
+			Species::Mandibuzz => 630,
+			Species::Heatmor => 631,
+			Species::Durant => 632,
+			Species::Deino => 633,
+			Species::Zweilous => 634,
+			Species::Hydreigon => 635,
+			Species::Larvesta => 636,
+			Species::Volcarona => 637,
+			Species::Cobalion => 638,
+			Species::Terrakion => 639,
+			Species::Virizion => 640,
+			Species::Tornadus => 641,
+			Species::Thundurus => 642,
+			Species::Reshiram => 643,
+			Species::Zekrom => 644,
+			Species::Landorus => 645,
+			Species::Kyurem => 646,
+			Species::Keldeo => 647,
+			Species::Meloetta => 648,
+			Species::Genesect => 649,
+			Species::Chespin => 650,
+			Species::Quilladin => 651,
+			Species::Chesnaught => 652,
+			Species::Fennekin => 653,
+			Species::Braixen => 654,
+			Species::Delphox => 655,
+			Species::Froakie => 656,
+			Species::Frogadier => 657,
+			Species::Greninja => 658,
+			Species::Bunnelby => 659,
+			Species::Diggersby => 660,
+			Species::Fletchling => 661,
+			Species::Fletchinder => 662,
+			Species::Talonflame => 663,
+			Species::Scatterbug => 664,
+			Species::Spewpa => 665,
+			Species::Vivillon => 666,
+			Species::Litleo => 667,
+			Species::Pyroar => 668,
+			Species::Flabebe => 669,
+			Species::Floette => 670,
+			Species::Florges => 671,
+			Species::Skiddo => 672,
+			Species::Gogoat => 673,
+			Species::Pancham => 674,
+			Species::Pangoro => 675,
+			Species::Furfrou => 676,
+			Species::Espurr => 677,
+			Species::Meowstic => 678,
+			Species::Honedge => 679,
+			Species::Doublade => 680,
+			Species::Aegislash => 681,
+			Species::Spritzee => 682,
+			Species::Aromatisse => 683,
+			Species::Swirlix => 684,
+			Species::Slurpuff => 685,
+			Species::Inkay => 686,
+			Species::Malamar => 687,
+			Species::Binacle => 688,
+			Species::Barbaracle => 689,
+			Species::Skrelp => 690,
+			Species::Dragalge => 691,
+			Species::Clauncher => 692,
+			Species::Clawitzer => 693,
+			Species::Helioptile => 694,
+			Species::Heliolisk => 695,
+			Species::Tyrunt => 696,
+			Species::Tyrantrum => 697,
+			Species::Amaura => 698,
+			Species::Aurorus => 699,
+			Species::Sylveon => 700,
+			Species::Hawlucha => 701,
+			Species::Dedenne => 702,
+			Species::Carbink => 703,
+			Species::Goomy => 704,
+			Species::Sliggoo => 705,
+			Species::Goodra => 706,
+			Species::Klefki => 707,
+			Species::Phantump => 708,
+			Species::Trevenant => 709,
+			Species::Pumpkaboo => 710,
+			Species::Gourgeist => 711,
+			Species::Bergmite => 712,
+			Species::Avalugg => 713,
+			Species::Noibat => 714,
+			Species::Noivern => 715,
+			Species::Xerneas => 716,
+			Species::Yveltal => 717,
+			Species::Zygarde => 718,
+			Species::Diancie => 719,
+			Species::Hoopa => 720,
+			Species::Volcanion => 721,
+			Species::Rowlet => 722,
+			Species::Dartrix => 723,
+			Species::Decidueye => 724,
+			Species::Litten => 725,
+			Species::Torracat => 726,
+			Species::Incineroar => 727,
+			Species::Popplio => 728,
+			Species::Brionne => 729,
+			Species::Primarina => 730,
+			Species::Pikipek => 731,
+			Species::Trumbeak => 732,
+			Species::Toucannon => 733,
+			Species::Yungoos => 734,
+			Species::Gumshoos => 735,
+			Species::Grubbin => 736,
+			Species::Charjabug => 737,
+			Species::Vikavolt => 738,
+			Species::Crabrawler => 739,
+			Species::Crabominable => 740,
+			Species::Oricorio => 741,
+			Species::Cutiefly => 742,
+			Species::Ribombee => 743,
+			Species::Rockruff => 744,
+			Species::Lycanroc => 745,
+			Species::Wishiwashi => 746,
+			Species::Mareanie => 747,
+			Species::Toxapex => 748,
+			Species::Mudbray => 749,
+			Species::Mudsdale => 750,
+			Species::Dewpider => 751,
+			Species::Araquanid => 752,
+			Species::Fomantis => 753,
+			Species::Lurantis => 754,
+			Species::Morelull => 755,
+			Species::Shiinotic => 756,
+			Species::Salandit => 757,
+			Species::Salazzle => 758,
+			Species::Stufful => 759,
+			Species::Bewear => 760,
+			Species::Bounsweet => 761,
+			Species::Steenee => 762,
+			Species::Tsareena => 763,
+			Species::Comfey => 764,
+			Species::Oranguru => 765,
+			Species::Passimian => 766,
+			Species::Wimpod => 767,
+			Species::Golisopod => 768,
+			Species::Sandygast => 769,
+			Species::Palossand => 770,
+			Species::Pyukumuku => 771,
+			Species::TypeNull => 772,
+			Species::Silvally => 773,
+			Species::Minior => 774,
+			Species::Komala => 775,
+			Species::Turtonator => 776,
+			Species::Togedemaru => 777,
+			Species::Mimikyu => 778,
+			Species::Bruxish => 779,
+			Species::Drampa => 780,
+			Species::Dhelmise => 781,
+			Species::Jangmoo => 782,
+			Species::Hakamoo => 783,
+			Species::Kommoo => 784,
+			Species::TapuKoko => 785,
+			Species::TapuLele => 786,
+			Species::TapuBulu => 787,
+			Species::TapuFini => 788,
+			Species::Cosmog => 789,
+			Species::Cosmoem => 790,
+			Species::Solgaleo => 791,
+			Species::Lunala => 792,
+			Species::Nihilego => 793,
+			Species::Buzzwole => 794,
+			Species::Pheromosa => 795,
+			Species::Xurkitree => 796,
+			Species::Celesteela => 797,
+			Species::Kartana => 798,
+			Species::Guzzlord => 799,
+			Species::Necrozma => 800,
+			Species::Magearna => 801,
+			Species::Marshadow => 802,
+			Species::Poipole => 803,
+			Species::Naganadel => 804,
+			Species::Stakataka => 805,
+			Species::Blacephalon => 806,
+			Species::Zeraora => 807,
+			Species::Meltan => 808,
+			Species::Melmetal => 809,
+			Species::Grookey => 810,
+			Species::Thwackey => 811,
+			Species::Rillaboom => 812,
+			Species::Scorbunny => 813,
+			Species::Raboot => 814,
+			Species::Cinderace => 815,
+			Species::Sobble => 816,
+			Species::Drizzile => 817,
+			Species::Inteleon => 818,
+			Species::Skwovet => 819,
+			Species::Greedent => 820,
+			Species::Rookidee => 821,
+			Species::Corvisquire => 822,
+			Species::Corviknight => 823,
+			Species::Blipbug => 824,
+			Species::Dottler => 825,
+			Species::Orbeetle => 826,
+			Species::Nickit => 827,
+			Species::Thievul => 828,
+			Species::Gossifleur => 829,
+			Species::Eldegoss => 830,
+			Species::Wooloo => 831,
+			Species::Dubwool => 832,
+			Species::Chewtle => 833,
+			Species::Drednaw => 834,
+			Species::Yamper => 835,
+			Species::Boltund => 836,
+			Species::Rolycoly => 837,
+			Species::Carkol => 838,
+			Species::Coalossal => 839,
+			Species::Applin => 840,
+			Species::Flapple => 841,
+			Species::Appletun => 842,
+			Species::Silicobra => 843,
+			Species::Sandaconda => 844,
+			Species::Cramorant => 845,
+			Species::Arrokuda => 846,
+			Species::Barraskewda => 847,
+			Species::Toxel => 848,
+			Species::Toxtricity => 849,
+			Species::Sizzlipede => 850,
+			Species::Centiskorch => 851,
+			Species::Clobbopus => 852,
+			Species::Grapploct => 853,
+			Species::Sinistea => 854,
+			Species::Polteageist => 855,
+			Species::Hatenna => 856,
+			Species::Hattrem => 857,
+			Species::Hatterene => 858,
+			Species::Impidimp => 859,
+			Species::Morgrem => 860,
+			Species::Grimmsnarl => 861,
+			Species::Obstagoon => 862,
+			Species::Perrserker => 863,
+			Species::Cursola => 864,
+			Species::Sirfetchd => 865,
+			Species::MrRime => 866,
+			Species::Runerigus => 867,
+			Species::Milcery => 868,
+			Species::Alcremie => 869,
+			Species::Falinks => 870,
+			Species::Pincurchin => 871,
+			Species::Snom => 872,
+			Species::Frosmoth => 873,
+			Species::Stonjourner => 874,
+			Species::Eiscue => 875,
+			Species::Indeedee => 876,
+			Species::Morpeko => 877,
+			Species::Cufant => 878,
+			Species::Copperajah => 879,
+			Species::Dracozolt => 880,
+			Species::Arctozolt => 881,
+			Species::Dracovish => 882,
+			Species::Arctovish => 883,
+			Species::Duraludon => 884,
+			Species::Dreepy => 885,
+			Species::Drakloak => 886,
+			Species::Dragapult => 887,
+			Species::Zacian => 888,
+			Species::Zamazenta => 889,
+			Species::Eternatus => 890,
+			Species::Kubfu => 891,
+			Species::Urshifu => 892,
+			Species::Zarude => 893,
+			Species::Regieleki => 894,
+			Species::Regidrago => 895,
+			Species::Glastrier => 896,
+			Species::Spectrier => 897,
+			Species::Calyrex => 898,
+            Species::Unknown(value) => value,
+        }
+    }
 }
\ No newline at end of file
@@ -0,0 +1,347 @@
+use crate::save::data::gender::GenderRatio;
+use crate::save::data::growth::GrowthRate;
+use crate::save::data::species::Species;
+use crate::save::error::UnknownValue;
+use crate::save::format::dppt::pokemon::Stats;
+
+mod table;
+
+/// A species' data as the gen 4 games have it, i.e. before later generations changed stats & added the
+/// Fairy type. Like the games, species with one type, or in one egg group, have it in both slots, and
+/// species with one ability have `Ability::None` in the second.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SpeciesInfo {
+    pub base_stats: Stats,
+    pub types: [PokemonType; 2],
+    /// Which of the two a Pokémon has is decided by the lowest bit of its PID
+    pub abilities: [Ability; 2],
+    pub gender_ratio: GenderRatio,
+    pub growth_rate: GrowthRate,
+    pub catch_rate: u8,
+    pub base_friendship: u8,
+    pub egg_groups: [EggGroup; 2],
+    /// Each cycle is 255 steps
+    pub hatch_cycles: u8,
+}
+
+/// What `Species::info` gives for species the gen 4 games don't have: zeroed like the games' entry for
+/// species 0, but genderless & unable to breed
+const NO_INFO: SpeciesInfo = SpeciesInfo {
+    base_stats: Stats { hp: 0, attack: 0, defense: 0, speed: 0, sp_attack: 0, sp_defense: 0 },
+    types: [PokemonType::Normal, PokemonType::Normal],
+    abilities: [Ability::None, Ability::None],
+    gender_ratio: GenderRatio::GENDERLESS,
+    growth_rate: GrowthRate::MediumFast,
+    catch_rate: 0,
+    base_friendship: 0,
+    egg_groups: [EggGroup::Undiscovered, EggGroup::Undiscovered],
+    hatch_cycles: 0,
+};
+
+impl Species {
+    /// The gen 4 info of this species, or a zeroed entry if it isn't from gen 1 to 4
+    pub fn info(&self) -> &'static SpeciesInfo {
+        self.try_info().unwrap_or(&NO_INFO)
+    }
+
+    /// The gen 4 info of this species, if it's from gen 1 to 4
+    pub fn try_info(&self) -> Option<&'static SpeciesInfo> {
+        let national = u16::from(*self) as usize;
+        national.checked_sub(1).and_then(|index| table::SPECIES.get(index))
+    }
+
+    /// The gen 4 info of this species in `form`. Only Deoxys, Wormadam, Giratina, Shaymin & Rotom have
+    /// forms with their own stats, types or abilities; any other form has the species' info.
+    pub fn form_info(&self, form: u8) -> &'static SpeciesInfo {
+        table::FORMS.iter()
+            .find(|(species, index, _)| species == self && *index == form)
+            .map(|(_, _, info)| info)
+            .unwrap_or_else(|| self.info())
+    }
+}
+
+/// A type, numbered as the gen 4 games number them
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum PokemonType {
+    Normal = 0,
+    Fighting = 1,
+    Flying = 2,
+    Poison = 3,
+    Ground = 4,
+    Rock = 5,
+    Bug = 6,
+    Ghost = 7,
+    Steel = 8,
+    /// The ??? type, which only Curse has
+    Mystery = 9,
+    Fire = 10,
+    Water = 11,
+    Grass = 12,
+    Electric = 13,
+    Psychic = 14,
+    Ice = 15,
+    Dragon = 16,
+    Dark = 17,
+}
+
+impl TryFrom<u8> for PokemonType {
+    type Error = UnknownValue<u8>;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(PokemonType::Normal),
+            1 => Ok(PokemonType::Fighting),
+            2 => Ok(PokemonType::Flying),
+            3 => Ok(PokemonType::Poison),
+            4 => Ok(PokemonType::Ground),
+            5 => Ok(PokemonType::Rock),
+            6 => Ok(PokemonType::Bug),
+            7 => Ok(PokemonType::Ghost),
+            8 => Ok(PokemonType::Steel),
+            9 => Ok(PokemonType::Mystery),
+            10 => Ok(PokemonType::Fire),
+            11 => Ok(PokemonType::Water),
+            12 => Ok(PokemonType::Grass),
+            13 => Ok(PokemonType::Electric),
+            14 => Ok(PokemonType::Psychic),
+            15 => Ok(PokemonType::Ice),
+            16 => Ok(PokemonType::Dragon),
+            17 => Ok(PokemonType::Dark),
+            _ => Err(UnknownValue { name: "PokemonType", value }),
+        }
+    }
+}
+
+impl From<PokemonType> for u8 {
+    fn from(value: PokemonType) -> Self {
+        value as u8
+    }
+}
+
+/// An egg group, numbered as the gen 4 games number them; Pokémon can breed if they share one
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum EggGroup {
+    Monster = 1,
+    Water1 = 2,
+    Bug = 3,
+    Flying = 4,
+    Field = 5,
+    Fairy = 6,
+    Grass = 7,
+    HumanLike = 8,
+    Water3 = 9,
+    Mineral = 10,
+    Amorphous = 11,
+    Water2 = 12,
+    /// Ditto's, which can breed with any group but Undiscovered
+    Ditto = 13,
+    Dragon = 14,
+    /// Legendaries, babies & others that can't breed
+    Undiscovered = 15,
+}
+
+/// An ability, numbered as the gen 4 games number them
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum Ability {
+    None = 0,
+    Stench = 1,
+    Drizzle = 2,
+    SpeedBoost = 3,
+    BattleArmor = 4,
+    Sturdy = 5,
+    Damp = 6,
+    Limber = 7,
+    SandVeil = 8,
+    Static = 9,
+    VoltAbsorb = 10,
+    WaterAbsorb = 11,
+    Oblivious = 12,
+    CloudNine = 13,
+    CompoundEyes = 14,
+    Insomnia = 15,
+    ColorChange = 16,
+    Immunity = 17,
+    FlashFire = 18,
+    ShieldDust = 19,
+    OwnTempo = 20,
+    SuctionCups = 21,
+    Intimidate = 22,
+    ShadowTag = 23,
+    RoughSkin = 24,
+    WonderGuard = 25,
+    Levitate = 26,
+    EffectSpore = 27,
+    Synchronize = 28,
+    ClearBody = 29,
+    NaturalCure = 30,
+    LightningRod = 31,
+    SereneGrace = 32,
+    SwiftSwim = 33,
+    Chlorophyll = 34,
+    Illuminate = 35,
+    Trace = 36,
+    HugePower = 37,
+    PoisonPoint = 38,
+    InnerFocus = 39,
+    MagmaArmor = 40,
+    WaterVeil = 41,
+    MagnetPull = 42,
+    Soundproof = 43,
+    RainDish = 44,
+    SandStream = 45,
+    Pressure = 46,
+    ThickFat = 47,
+    EarlyBird = 48,
+    FlameBody = 49,
+    RunAway = 50,
+    KeenEye = 51,
+    HyperCutter = 52,
+    Pickup = 53,
+    Truant = 54,
+    Hustle = 55,
+    CuteCharm = 56,
+    Plus = 57,
+    Minus = 58,
+    Forecast = 59,
+    StickyHold = 60,
+    ShedSkin = 61,
+    Guts = 62,
+    MarvelScale = 63,
+    LiquidOoze = 64,
+    Overgrow = 65,
+    Blaze = 66,
+    Torrent = 67,
+    Swarm = 68,
+    RockHead = 69,
+    Drought = 70,
+    ArenaTrap = 71,
+    VitalSpirit = 72,
+    WhiteSmoke = 73,
+    PurePower = 74,
+    ShellArmor = 75,
+    AirLock = 76,
+    TangledFeet = 77,
+    MotorDrive = 78,
+    Rivalry = 79,
+    Steadfast = 80,
+    SnowCloak = 81,
+    Gluttony = 82,
+    AngerPoint = 83,
+    Unburden = 84,
+    Heatproof = 85,
+    Simple = 86,
+    DrySkin = 87,
+    Download = 88,
+    IronFist = 89,
+    PoisonHeal = 90,
+    Adaptability = 91,
+    SkillLink = 92,
+    Hydration = 93,
+    SolarPower = 94,
+    QuickFeet = 95,
+    Normalize = 96,
+    Sniper = 97,
+    MagicGuard = 98,
+    NoGuard = 99,
+    Stall = 100,
+    Technician = 101,
+    LeafGuard = 102,
+    Klutz = 103,
+    MoldBreaker = 104,
+    SuperLuck = 105,
+    Aftermath = 106,
+    Anticipation = 107,
+    Forewarn = 108,
+    Unaware = 109,
+    TintedLens = 110,
+    Filter = 111,
+    SlowStart = 112,
+    Scrappy = 113,
+    StormDrain = 114,
+    IceBody = 115,
+    SolidRock = 116,
+    SnowWarning = 117,
+    HoneyGather = 118,
+    Frisk = 119,
+    Reckless = 120,
+    Multitype = 121,
+    FlowerGift = 122,
+    BadDreams = 123,
+}
+
+/// Every ability, by ID
+const ABILITIES: [Ability; 124] = {
+    use Ability::*;
+    [
+        None, Stench, Drizzle, SpeedBoost, BattleArmor, Sturdy, Damp, Limber,
+        SandVeil, Static, VoltAbsorb, WaterAbsorb, Oblivious, CloudNine, CompoundEyes, Insomnia,
+        ColorChange, Immunity, FlashFire, ShieldDust, OwnTempo, SuctionCups, Intimidate, ShadowTag,
+        RoughSkin, WonderGuard, Levitate, EffectSpore, Synchronize, ClearBody, NaturalCure, LightningRod,
+        SereneGrace, SwiftSwim, Chlorophyll, Illuminate, Trace, HugePower, PoisonPoint, InnerFocus,
+        MagmaArmor, WaterVeil, MagnetPull, Soundproof, RainDish, SandStream, Pressure, ThickFat,
+        EarlyBird, FlameBody, RunAway, KeenEye, HyperCutter, Pickup, Truant, Hustle,
+        CuteCharm, Plus, Minus, Forecast, StickyHold, ShedSkin, Guts, MarvelScale,
+        LiquidOoze, Overgrow, Blaze, Torrent, Swarm, RockHead, Drought, ArenaTrap,
+        VitalSpirit, WhiteSmoke, PurePower, ShellArmor, AirLock, TangledFeet, MotorDrive, Rivalry,
+        Steadfast, SnowCloak, Gluttony, AngerPoint, Unburden, Heatproof, Simple, DrySkin,
+        Download, IronFist, PoisonHeal, Adaptability, SkillLink, Hydration, SolarPower, QuickFeet,
+        Normalize, Sniper, MagicGuard, NoGuard, Stall, Technician, LeafGuard, Klutz,
+        MoldBreaker, SuperLuck, Aftermath, Anticipation, Forewarn, Unaware, TintedLens, Filter,
+        SlowStart, Scrappy, StormDrain, IceBody, SolidRock, SnowWarning, HoneyGather, Frisk,
+        Reckless, Multitype, FlowerGift, BadDreams,
+    ]
+};
+
+impl TryFrom<u8> for Ability {
+    type Error = UnknownValue<u8>;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        ABILITIES.get(value as usize).copied().ok_or(UnknownValue { name: "Ability", value })
+    }
+}
+
+impl From<Ability> for u8 {
+    fn from(value: Ability) -> Self {
+        value as u8
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Do species & forms get their gen 4 info, and everything else a zeroed entry?
+    #[test]
+    fn species_info() {
+        let piplup = Species::Piplup.info();
+        assert_eq!(piplup.base_stats, Stats { hp: 53, attack: 51, defense: 53, speed: 40, sp_attack: 61, sp_defense: 56 });
+        assert_eq!(piplup.types, [PokemonType::Water, PokemonType::Water]);
+        assert_eq!(piplup.abilities, [Ability::Torrent, Ability::None]);
+        assert_eq!(piplup.gender_ratio, GenderRatio(31));
+        assert_eq!(piplup.growth_rate, GrowthRate::MediumSlow);
+        assert_eq!((piplup.catch_rate, piplup.base_friendship, piplup.hatch_cycles), (45, 70, 20));
+        assert_eq!(piplup.egg_groups, [EggGroup::Water1, EggGroup::Field]);
+
+        // gen 4 values, from before Clefable's Sp. Atk buff & the Fairy type
+        let clefable = Species::Clefable.info();
+        assert_eq!(clefable.base_stats.sp_attack, 85);
+        assert_eq!(clefable.types, [PokemonType::Normal, PokemonType::Normal]);
+        assert_eq!(Species::Arceus.info().abilities[0], Ability::Multitype);
+
+        assert_eq!(Species::Giratina.form_info(0), Species::Giratina.info());
+        assert_eq!(Species::Giratina.form_info(1).abilities[0], Ability::Levitate);
+        assert_eq!(Species::Wormadam.form_info(2).types, [PokemonType::Bug, PokemonType::Steel]);
+        assert_eq!(Species::Deoxys.form_info(3).base_stats.speed, 180);
+        assert_eq!(Species::Shaymin.form_info(1).types, [PokemonType::Grass, PokemonType::Flying]);
+        assert_eq!(Species::Rotom.form_info(5).base_stats.defense, 107);
+        assert_eq!(Species::Unown.form_info(5), Species::Unown.info());
+
+        assert_eq!(Species::Snivy.try_info(), None);
+        assert_eq!(Species::Unknown(0).info(), &NO_INFO);
+        assert_eq!(Ability::try_from(123).map(u8::from), Ok(123));
+        assert!(Ability::try_from(124).is_err());
+    }
+}
@@ -0,0 +1,6069 @@
+/*
+	AUTOGENERATED
+	This file has been automatically generated by generators/species_info.js. The generator should be
+	edited, not this file.
+
+	Data source: https://bulbapedia.bulbagarden.net/wiki/List_of_Pokémon_by_base_stats_(Generation_II-V)
+	and the species pages' gen 4 data, plus https://github.com/pret/pokeplatinum for forms
+*/
+use crate::save::data::gender::GenderRatio;
+use crate::save::data::growth::GrowthRate;
+use crate::save::data::species::Species;
+use crate::save::data::species_info::{Ability as A, EggGroup as G, PokemonType as T, SpeciesInfo};
+use crate::save::format::dppt::pokemon::Stats;
+
+/// The info of each species from Bulbasaur to Arceus, by national dex number
+pub(super) const SPECIES: [SpeciesInfo; 493] = [
+	// 1: Bulbasaur
+	SpeciesInfo {
+		base_stats: Stats { hp: 45, attack: 49, defense: 49, speed: 45, sp_attack: 65, sp_defense: 65 },
+		types: [T::Grass, T::Poison],
+		abilities: [A::Overgrow, A::None],
+		gender_ratio: GenderRatio(31),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Monster, G::Grass],
+		hatch_cycles: 20,
+	},
+	// 2: Ivysaur
+	SpeciesInfo {
+		base_stats: Stats { hp: 60, attack: 62, defense: 63, speed: 60, sp_attack: 80, sp_defense: 80 },
+		types: [T::Grass, T::Poison],
+		abilities: [A::Overgrow, A::None],
+		gender_ratio: GenderRatio(31),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Monster, G::Grass],
+		hatch_cycles: 20,
+	},
+	// 3: Venusaur
+	SpeciesInfo {
+		base_stats: Stats { hp: 80, attack: 82, defense: 83, speed: 80, sp_attack: 100, sp_defense: 100 },
+		types: [T::Grass, T::Poison],
+		abilities: [A::Overgrow, A::None],
+		gender_ratio: GenderRatio(31),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Monster, G::Grass],
+		hatch_cycles: 20,
+	},
+	// 4: Charmander
+	SpeciesInfo {
+		base_stats: Stats { hp: 39, attack: 52, defense: 43, speed: 65, sp_attack: 60, sp_defense: 50 },
+		types: [T::Fire, T::Fire],
+		abilities: [A::Blaze, A::None],
+		gender_ratio: GenderRatio(31),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Monster, G::Dragon],
+		hatch_cycles: 20,
+	},
+	// 5: Charmeleon
+	SpeciesInfo {
+		base_stats: Stats { hp: 58, attack: 64, defense: 58, speed: 80, sp_attack: 80, sp_defense: 65 },
+		types: [T::Fire, T::Fire],
+		abilities: [A::Blaze, A::None],
+		gender_ratio: GenderRatio(31),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Monster, G::Dragon],
+		hatch_cycles: 20,
+	},
+	// 6: Charizard
+	SpeciesInfo {
+		base_stats: Stats { hp: 78, attack: 84, defense: 78, speed: 100, sp_attack: 109, sp_defense: 85 },
+		types: [T::Fire, T::Flying],
+		abilities: [A::Blaze, A::None],
+		gender_ratio: GenderRatio(31),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Monster, G::Dragon],
+		hatch_cycles: 20,
+	},
+	// 7: Squirtle
+	SpeciesInfo {
+		base_stats: Stats { hp: 44, attack: 48, defense: 65, speed: 43, sp_attack: 50, sp_defense: 64 },
+		types: [T::Water, T::Water],
+		abilities: [A::Torrent, A::None],
+		gender_ratio: GenderRatio(31),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Monster, G::Water1],
+		hatch_cycles: 20,
+	},
+	// 8: Wartortle
+	SpeciesInfo {
+		base_stats: Stats { hp: 59, attack: 63, defense: 80, speed: 58, sp_attack: 65, sp_defense: 80 },
+		types: [T::Water, T::Water],
+		abilities: [A::Torrent, A::None],
+		gender_ratio: GenderRatio(31),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Monster, G::Water1],
+		hatch_cycles: 20,
+	},
+	// 9: Blastoise
+	SpeciesInfo {
+		base_stats: Stats { hp: 79, attack: 83, defense: 100, speed: 78, sp_attack: 85, sp_defense: 105 },
+		types: [T::Water, T::Water],
+		abilities: [A::Torrent, A::None],
+		gender_ratio: GenderRatio(31),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Monster, G::Water1],
+		hatch_cycles: 20,
+	},
+	// 10: Caterpie
+	SpeciesInfo {
+		base_stats: Stats { hp: 45, attack: 30, defense: 35, speed: 45, sp_attack: 20, sp_defense: 20 },
+		types: [T::Bug, T::Bug],
+		abilities: [A::ShieldDust, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 255,
+		base_friendship: 70,
+		egg_groups: [G::Bug, G::Bug],
+		hatch_cycles: 15,
+	},
+	// 11: Metapod
+	SpeciesInfo {
+		base_stats: Stats { hp: 50, attack: 20, defense: 55, speed: 30, sp_attack: 25, sp_defense: 25 },
+		types: [T::Bug, T::Bug],
+		abilities: [A::ShedSkin, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 120,
+		base_friendship: 70,
+		egg_groups: [G::Bug, G::Bug],
+		hatch_cycles: 15,
+	},
+	// 12: Butterfree
+	SpeciesInfo {
+		base_stats: Stats { hp: 60, attack: 45, defense: 50, speed: 70, sp_attack: 80, sp_defense: 80 },
+		types: [T::Bug, T::Flying],
+		abilities: [A::CompoundEyes, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Bug, G::Bug],
+		hatch_cycles: 15,
+	},
+	// 13: Weedle
+	SpeciesInfo {
+		base_stats: Stats { hp: 40, attack: 35, defense: 30, speed: 50, sp_attack: 20, sp_defense: 20 },
+		types: [T::Bug, T::Poison],
+		abilities: [A::ShieldDust, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 255,
+		base_friendship: 70,
+		egg_groups: [G::Bug, G::Bug],
+		hatch_cycles: 15,
+	},
+	// 14: Kakuna
+	SpeciesInfo {
+		base_stats: Stats { hp: 45, attack: 25, defense: 50, speed: 35, sp_attack: 25, sp_defense: 25 },
+		types: [T::Bug, T::Poison],
+		abilities: [A::ShedSkin, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 120,
+		base_friendship: 70,
+		egg_groups: [G::Bug, G::Bug],
+		hatch_cycles: 15,
+	},
+	// 15: Beedrill
+	SpeciesInfo {
+		base_stats: Stats { hp: 65, attack: 80, defense: 40, speed: 75, sp_attack: 45, sp_defense: 80 },
+		types: [T::Bug, T::Poison],
+		abilities: [A::Swarm, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Bug, G::Bug],
+		hatch_cycles: 15,
+	},
+	// 16: Pidgey
+	SpeciesInfo {
+		base_stats: Stats { hp: 40, attack: 45, defense: 40, speed: 56, sp_attack: 35, sp_defense: 35 },
+		types: [T::Normal, T::Flying],
+		abilities: [A::KeenEye, A::TangledFeet],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 255,
+		base_friendship: 70,
+		egg_groups: [G::Flying, G::Flying],
+		hatch_cycles: 15,
+	},
+	// 17: Pidgeotto
+	SpeciesInfo {
+		base_stats: Stats { hp: 63, attack: 60, defense: 55, speed: 71, sp_attack: 50, sp_defense: 50 },
+		types: [T::Normal, T::Flying],
+		abilities: [A::KeenEye, A::TangledFeet],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 120,
+		base_friendship: 70,
+		egg_groups: [G::Flying, G::Flying],
+		hatch_cycles: 15,
+	},
+	// 18: Pidgeot
+	SpeciesInfo {
+		base_stats: Stats { hp: 83, attack: 80, defense: 75, speed: 91, sp_attack: 70, sp_defense: 70 },
+		types: [T::Normal, T::Flying],
+		abilities: [A::KeenEye, A::TangledFeet],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Flying, G::Flying],
+		hatch_cycles: 15,
+	},
+	// 19: Rattata
+	SpeciesInfo {
+		base_stats: Stats { hp: 30, attack: 56, defense: 35, speed: 72, sp_attack: 25, sp_defense: 35 },
+		types: [T::Normal, T::Normal],
+		abilities: [A::RunAway, A::Guts],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 255,
+		base_friendship: 70,
+		egg_groups: [G::Field, G::Field],
+		hatch_cycles: 15,
+	},
+	// 20: Raticate
+	SpeciesInfo {
+		base_stats: Stats { hp: 55, attack: 81, defense: 60, speed: 97, sp_attack: 50, sp_defense: 70 },
+		types: [T::Normal, T::Normal],
+		abilities: [A::RunAway, A::Guts],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 127,
+		base_friendship: 70,
+		egg_groups: [G::Field, G::Field],
+		hatch_cycles: 15,
+	},
+	// 21: Spearow
+	SpeciesInfo {
+		base_stats: Stats { hp: 40, attack: 60, defense: 30, speed: 70, sp_attack: 31, sp_defense: 31 },
+		types: [T::Normal, T::Flying],
+		abilities: [A::KeenEye, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 255,
+		base_friendship: 70,
+		egg_groups: [G::Flying, G::Flying],
+		hatch_cycles: 15,
+	},
+	// 22: Fearow
+	SpeciesInfo {
+		base_stats: Stats { hp: 65, attack: 90, defense: 65, speed: 100, sp_attack: 61, sp_defense: 61 },
+		types: [T::Normal, T::Flying],
+		abilities: [A::KeenEye, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 90,
+		base_friendship: 70,
+		egg_groups: [G::Flying, G::Flying],
+		hatch_cycles: 15,
+	},
+	// 23: Ekans
+	SpeciesInfo {
+		base_stats: Stats { hp: 35, attack: 60, defense: 44, speed: 55, sp_attack: 40, sp_defense: 54 },
+		types: [T::Poison, T::Poison],
+		abilities: [A::Intimidate, A::ShedSkin],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 255,
+		base_friendship: 70,
+		egg_groups: [G::Field, G::Dragon],
+		hatch_cycles: 20,
+	},
+	// 24: Arbok
+	SpeciesInfo {
+		base_stats: Stats { hp: 60, attack: 85, defense: 69, speed: 80, sp_attack: 65, sp_defense: 79 },
+		types: [T::Poison, T::Poison],
+		abilities: [A::Intimidate, A::ShedSkin],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 90,
+		base_friendship: 70,
+		egg_groups: [G::Field, G::Dragon],
+		hatch_cycles: 20,
+	},
+	// 25: Pikachu
+	SpeciesInfo {
+		base_stats: Stats { hp: 35, attack: 55, defense: 30, speed: 90, sp_attack: 50, sp_defense: 40 },
+		types: [T::Electric, T::Electric],
+		abilities: [A::Static, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 190,
+		base_friendship: 70,
+		egg_groups: [G::Field, G::Fairy],
+		hatch_cycles: 10,
+	},
+	// 26: Raichu
+	SpeciesInfo {
+		base_stats: Stats { hp: 60, attack: 90, defense: 55, speed: 100, sp_attack: 90, sp_defense: 80 },
+		types: [T::Electric, T::Electric],
+		abilities: [A::Static, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 75,
+		base_friendship: 70,
+		egg_groups: [G::Field, G::Fairy],
+		hatch_cycles: 10,
+	},
+	// 27: Sandshrew
+	SpeciesInfo {
+		base_stats: Stats { hp: 50, attack: 75, defense: 85, speed: 40, sp_attack: 20, sp_defense: 30 },
+		types: [T::Ground, T::Ground],
+		abilities: [A::SandVeil, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 255,
+		base_friendship: 70,
+		egg_groups: [G::Field, G::Field],
+		hatch_cycles: 20,
+	},
+	// 28: Sandslash
+	SpeciesInfo {
+		base_stats: Stats { hp: 75, attack: 100, defense: 110, speed: 65, sp_attack: 45, sp_defense: 55 },
+		types: [T::Ground, T::Ground],
+		abilities: [A::SandVeil, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 90,
+		base_friendship: 70,
+		egg_groups: [G::Field, G::Field],
+		hatch_cycles: 20,
+	},
+	// 29: NidoranFemale
+	SpeciesInfo {
+		base_stats: Stats { hp: 55, attack: 47, defense: 52, speed: 41, sp_attack: 40, sp_defense: 40 },
+		types: [T::Poison, T::Poison],
+		abilities: [A::PoisonPoint, A::Rivalry],
+		gender_ratio: GenderRatio::FEMALE_ONLY,
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 235,
+		base_friendship: 70,
+		egg_groups: [G::Monster, G::Field],
+		hatch_cycles: 20,
+	},
+	// 30: Nidorina
+	SpeciesInfo {
+		base_stats: Stats { hp: 70, attack: 62, defense: 67, speed: 56, sp_attack: 55, sp_defense: 55 },
+		types: [T::Poison, T::Poison],
+		abilities: [A::PoisonPoint, A::Rivalry],
+		gender_ratio: GenderRatio::FEMALE_ONLY,
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 120,
+		base_friendship: 70,
+		egg_groups: [G::Undiscovered, G::Undiscovered],
+		hatch_cycles: 20,
+	},
+	// 31: Nidoqueen
+	SpeciesInfo {
+		base_stats: Stats { hp: 90, attack: 82, defense: 87, speed: 76, sp_attack: 75, sp_defense: 85 },
+		types: [T::Poison, T::Ground],
+		abilities: [A::PoisonPoint, A::Rivalry],
+		gender_ratio: GenderRatio::FEMALE_ONLY,
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Undiscovered, G::Undiscovered],
+		hatch_cycles: 20,
+	},
+	// 32: NidoranMale
+	SpeciesInfo {
+		base_stats: Stats { hp: 46, attack: 57, defense: 40, speed: 50, sp_attack: 40, sp_defense: 40 },
+		types: [T::Poison, T::Poison],
+		abilities: [A::PoisonPoint, A::Rivalry],
+		gender_ratio: GenderRatio::MALE_ONLY,
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 235,
+		base_friendship: 70,
+		egg_groups: [G::Monster, G::Field],
+		hatch_cycles: 20,
+	},
+	// 33: Nidorino
+	SpeciesInfo {
+		base_stats: Stats { hp: 61, attack: 72, defense: 57, speed: 65, sp_attack: 55, sp_defense: 55 },
+		types: [T::Poison, T::Poison],
+		abilities: [A::PoisonPoint, A::Rivalry],
+		gender_ratio: GenderRatio::MALE_ONLY,
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 120,
+		base_friendship: 70,
+		egg_groups: [G::Monster, G::Field],
+		hatch_cycles: 20,
+	},
+	// 34: Nidoking
+	SpeciesInfo {
+		base_stats: Stats { hp: 81, attack: 92, defense: 77, speed: 85, sp_attack: 85, sp_defense: 75 },
+		types: [T::Poison, T::Ground],
+		abilities: [A::PoisonPoint, A::Rivalry],
+		gender_ratio: GenderRatio::MALE_ONLY,
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Monster, G::Field],
+		hatch_cycles: 20,
+	},
+	// 35: Clefairy
+	SpeciesInfo {
+		base_stats: Stats { hp: 70, attack: 45, defense: 48, speed: 35, sp_attack: 60, sp_defense: 65 },
+		types: [T::Normal, T::Normal],
+		abilities: [A::CuteCharm, A::MagicGuard],
+		gender_ratio: GenderRatio(191),
+		growth_rate: GrowthRate::Fast,
+		catch_rate: 150,
+		base_friendship: 140,
+		egg_groups: [G::Fairy, G::Fairy],
+		hatch_cycles: 10,
+	},
+	// 36: Clefable
+	SpeciesInfo {
+		base_stats: Stats { hp: 95, attack: 70, defense: 73, speed: 60, sp_attack: 85, sp_defense: 90 },
+		types: [T::Normal, T::Normal],
+		abilities: [A::CuteCharm, A::MagicGuard],
+		gender_ratio: GenderRatio(191),
+		growth_rate: GrowthRate::Fast,
+		catch_rate: 25,
+		base_friendship: 140,
+		egg_groups: [G::Fairy, G::Fairy],
+		hatch_cycles: 10,
+	},
+	// 37: Vulpix
+	SpeciesInfo {
+		base_stats: Stats { hp: 38, attack: 41, defense: 40, speed: 65, sp_attack: 50, sp_defense: 65 },
+		types: [T::Fire, T::Fire],
+		abilities: [A::FlashFire, A::None],
+		gender_ratio: GenderRatio(191),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 190,
+		base_friendship: 70,
+		egg_groups: [G::Field, G::Field],
+		hatch_cycles: 20,
+	},
+	// 38: Ninetales
+	SpeciesInfo {
+		base_stats: Stats { hp: 73, attack: 76, defense: 75, speed: 100, sp_attack: 81, sp_defense: 100 },
+		types: [T::Fire, T::Fire],
+		abilities: [A::FlashFire, A::None],
+		gender_ratio: GenderRatio(191),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 75,
+		base_friendship: 70,
+		egg_groups: [G::Field, G::Field],
+		hatch_cycles: 20,
+	},
+	// 39: Jigglypuff
+	SpeciesInfo {
+		base_stats: Stats { hp: 115, attack: 45, defense: 20, speed: 20, sp_attack: 45, sp_defense: 25 },
+		types: [T::Normal, T::Normal],
+		abilities: [A::CuteCharm, A::None],
+		gender_ratio: GenderRatio(191),
+		growth_rate: GrowthRate::Fast,
+		catch_rate: 170,
+		base_friendship: 50,
+		egg_groups: [G::Fairy, G::Fairy],
+		hatch_cycles: 10,
+	},
+	// 40: Wigglytuff
+	SpeciesInfo {
+		base_stats: Stats { hp: 140, attack: 70, defense: 45, speed: 45, sp_attack: 75, sp_defense: 50 },
+		types: [T::Normal, T::Normal],
+		abilities: [A::CuteCharm, A::None],
+		gender_ratio: GenderRatio(191),
+		growth_rate: GrowthRate::Fast,
+		catch_rate: 50,
+		base_friendship: 50,
+		egg_groups: [G::Fairy, G::Fairy],
+		hatch_cycles: 10,
+	},
+	// 41: Zubat
+	SpeciesInfo {
+		base_stats: Stats { hp: 40, attack: 45, defense: 35, speed: 55, sp_attack: 30, sp_defense: 40 },
+		types: [T::Poison, T::Flying],
+		abilities: [A::InnerFocus, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 255,
+		base_friendship: 70,
+		egg_groups: [G::Flying, G::Flying],
+		hatch_cycles: 15,
+	},
+	// 42: Golbat
+	SpeciesInfo {
+		base_stats: Stats { hp: 75, attack: 80, defense: 70, speed: 90, sp_attack: 65, sp_defense: 75 },
+		types: [T::Poison, T::Flying],
+		abilities: [A::InnerFocus, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 90,
+		base_friendship: 70,
+		egg_groups: [G::Flying, G::Flying],
+		hatch_cycles: 15,
+	},
+	// 43: Oddish
+	SpeciesInfo {
+		base_stats: Stats { hp: 45, attack: 50, defense: 55, speed: 30, sp_attack: 75, sp_defense: 65 },
+		types: [T::Grass, T::Poison],
+		abilities: [A::Chlorophyll, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 255,
+		base_friendship: 70,
+		egg_groups: [G::Grass, G::Grass],
+		hatch_cycles: 20,
+	},
+	// 44: Gloom
+	SpeciesInfo {
+		base_stats: Stats { hp: 60, attack: 65, defense: 70, speed: 40, sp_attack: 85, sp_defense: 75 },
+		types: [T::Grass, T::Poison],
+		abilities: [A::Chlorophyll, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 120,
+		base_friendship: 70,
+		egg_groups: [G::Grass, G::Grass],
+		hatch_cycles: 20,
+	},
+	// 45: Vileplume
+	SpeciesInfo {
+		base_stats: Stats { hp: 75, attack: 80, defense: 85, speed: 50, sp_attack: 100, sp_defense: 90 },
+		types: [T::Grass, T::Poison],
+		abilities: [A::Chlorophyll, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Grass, G::Grass],
+		hatch_cycles: 20,
+	},
+	// 46: Paras
+	SpeciesInfo {
+		base_stats: Stats { hp: 35, attack: 70, defense: 55, speed: 25, sp_attack: 45, sp_defense: 55 },
+		types: [T::Bug, T::Grass],
+		abilities: [A::EffectSpore, A::DrySkin],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 190,
+		base_friendship: 70,
+		egg_groups: [G::Bug, G::Grass],
+		hatch_cycles: 20,
+	},
+	// 47: Parasect
+	SpeciesInfo {
+		base_stats: Stats { hp: 60, attack: 95, defense: 80, speed: 30, sp_attack: 60, sp_defense: 80 },
+		types: [T::Bug, T::Grass],
+		abilities: [A::EffectSpore, A::DrySkin],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 75,
+		base_friendship: 70,
+		egg_groups: [G::Bug, G::Grass],
+		hatch_cycles: 20,
+	},
+	// 48: Venonat
+	SpeciesInfo {
+		base_stats: Stats { hp: 60, attack: 55, defense: 50, speed: 45, sp_attack: 40, sp_defense: 55 },
+		types: [T::Bug, T::Poison],
+		abilities: [A::CompoundEyes, A::TintedLens],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 190,
+		base_friendship: 70,
+		egg_groups: [G::Bug, G::Bug],
+		hatch_cycles: 20,
+	},
+	// 49: Venomoth
+	SpeciesInfo {
+		base_stats: Stats { hp: 70, attack: 65, defense: 60, speed: 90, sp_attack: 90, sp_defense: 75 },
+		types: [T::Bug, T::Poison],
+		abilities: [A::ShieldDust, A::TintedLens],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 75,
+		base_friendship: 70,
+		egg_groups: [G::Bug, G::Bug],
+		hatch_cycles: 20,
+	},
+	// 50: Diglett
+	SpeciesInfo {
+		base_stats: Stats { hp: 10, attack: 55, defense: 25, speed: 95, sp_attack: 35, sp_defense: 45 },
+		types: [T::Ground, T::Ground],
+		abilities: [A::SandVeil, A::ArenaTrap],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 255,
+		base_friendship: 70,
+		egg_groups: [G::Field, G::Field],
+		hatch_cycles: 20,
+	},
+	// 51: Dugtrio
+	SpeciesInfo {
+		base_stats: Stats { hp: 35, attack: 80, defense: 50, speed: 120, sp_attack: 50, sp_defense: 70 },
+		types: [T::Ground, T::Ground],
+		abilities: [A::SandVeil, A::ArenaTrap],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 50,
+		base_friendship: 70,
+		egg_groups: [G::Field, G::Field],
+		hatch_cycles: 20,
+	},
+	// 52: Meowth
+	SpeciesInfo {
+		base_stats: Stats { hp: 40, attack: 45, defense: 35, speed: 90, sp_attack: 40, sp_defense: 40 },
+		types: [T::Normal, T::Normal],
+		abilities: [A::Pickup, A::Technician],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 255,
+		base_friendship: 70,
+		egg_groups: [G::Field, G::Field],
+		hatch_cycles: 20,
+	},
+	// 53: Persian
+	SpeciesInfo {
+		base_stats: Stats { hp: 65, attack: 70, defense: 60, speed: 115, sp_attack: 65, sp_defense: 65 },
+		types: [T::Normal, T::Normal],
+		abilities: [A::Limber, A::Technician],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 90,
+		base_friendship: 70,
+		egg_groups: [G::Field, G::Field],
+		hatch_cycles: 20,
+	},
+	// 54: Psyduck
+	SpeciesInfo {
+		base_stats: Stats { hp: 50, attack: 52, defense: 48, speed: 55, sp_attack: 65, sp_defense: 50 },
+		types: [T::Water, T::Water],
+		abilities: [A::Damp, A::CloudNine],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 190,
+		base_friendship: 70,
+		egg_groups: [G::Water1, G::Field],
+		hatch_cycles: 20,
+	},
+	// 55: Golduck
+	SpeciesInfo {
+		base_stats: Stats { hp: 80, attack: 82, defense: 78, speed: 85, sp_attack: 95, sp_defense: 80 },
+		types: [T::Water, T::Water],
+		abilities: [A::Damp, A::CloudNine],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 75,
+		base_friendship: 70,
+		egg_groups: [G::Water1, G::Field],
+		hatch_cycles: 20,
+	},
+	// 56: Mankey
+	SpeciesInfo {
+		base_stats: Stats { hp: 40, attack: 80, defense: 35, speed: 70, sp_attack: 35, sp_defense: 45 },
+		types: [T::Fighting, T::Fighting],
+		abilities: [A::VitalSpirit, A::AngerPoint],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 190,
+		base_friendship: 70,
+		egg_groups: [G::Field, G::Field],
+		hatch_cycles: 20,
+	},
+	// 57: Primeape
+	SpeciesInfo {
+		base_stats: Stats { hp: 65, attack: 105, defense: 60, speed: 95, sp_attack: 60, sp_defense: 70 },
+		types: [T::Fighting, T::Fighting],
+		abilities: [A::VitalSpirit, A::AngerPoint],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 75,
+		base_friendship: 70,
+		egg_groups: [G::Field, G::Field],
+		hatch_cycles: 20,
+	},
+	// 58: Growlithe
+	SpeciesInfo {
+		base_stats: Stats { hp: 55, attack: 70, defense: 45, speed: 60, sp_attack: 70, sp_defense: 50 },
+		types: [T::Fire, T::Fire],
+		abilities: [A::Intimidate, A::FlashFire],
+		gender_ratio: GenderRatio(63),
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 190,
+		base_friendship: 70,
+		egg_groups: [G::Field, G::Field],
+		hatch_cycles: 20,
+	},
+	// 59: Arcanine
+	SpeciesInfo {
+		base_stats: Stats { hp: 90, attack: 110, defense: 80, speed: 95, sp_attack: 100, sp_defense: 80 },
+		types: [T::Fire, T::Fire],
+		abilities: [A::Intimidate, A::FlashFire],
+		gender_ratio: GenderRatio(63),
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 75,
+		base_friendship: 70,
+		egg_groups: [G::Field, G::Field],
+		hatch_cycles: 20,
+	},
+	// 60: Poliwag
+	SpeciesInfo {
+		base_stats: Stats { hp: 40, attack: 50, defense: 40, speed: 90, sp_attack: 40, sp_defense: 40 },
+		types: [T::Water, T::Water],
+		abilities: [A::WaterAbsorb, A::Damp],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 255,
+		base_friendship: 70,
+		egg_groups: [G::Water1, G::Water1],
+		hatch_cycles: 20,
+	},
+	// 61: Poliwhirl
+	SpeciesInfo {
+		base_stats: Stats { hp: 65, attack: 65, defense: 65, speed: 90, sp_attack: 50, sp_defense: 50 },
+		types: [T::Water, T::Water],
+		abilities: [A::WaterAbsorb, A::Damp],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 120,
+		base_friendship: 70,
+		egg_groups: [G::Water1, G::Water1],
+		hatch_cycles: 20,
+	},
+	// 62: Poliwrath
+	SpeciesInfo {
+		base_stats: Stats { hp: 90, attack: 85, defense: 95, speed: 70, sp_attack: 70, sp_defense: 90 },
+		types: [T::Water, T::Fighting],
+		abilities: [A::WaterAbsorb, A::Damp],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Water1, G::Water1],
+		hatch_cycles: 20,
+	},
+	// 63: Abra
+	SpeciesInfo {
+		base_stats: Stats { hp: 25, attack: 20, defense: 15, speed: 90, sp_attack: 105, sp_defense: 55 },
+		types: [T::Psychic, T::Psychic],
+		abilities: [A::Synchronize, A::InnerFocus],
+		gender_ratio: GenderRatio(63),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 200,
+		base_friendship: 70,
+		egg_groups: [G::HumanLike, G::HumanLike],
+		hatch_cycles: 20,
+	},
+	// 64: Kadabra
+	SpeciesInfo {
+		base_stats: Stats { hp: 40, attack: 35, defense: 30, speed: 105, sp_attack: 120, sp_defense: 70 },
+		types: [T::Psychic, T::Psychic],
+		abilities: [A::Synchronize, A::InnerFocus],
+		gender_ratio: GenderRatio(63),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 100,
+		base_friendship: 70,
+		egg_groups: [G::HumanLike, G::HumanLike],
+		hatch_cycles: 20,
+	},
+	// 65: Alakazam
+	SpeciesInfo {
+		base_stats: Stats { hp: 55, attack: 50, defense: 45, speed: 120, sp_attack: 135, sp_defense: 85 },
+		types: [T::Psychic, T::Psychic],
+		abilities: [A::Synchronize, A::InnerFocus],
+		gender_ratio: GenderRatio(63),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 50,
+		base_friendship: 70,
+		egg_groups: [G::HumanLike, G::HumanLike],
+		hatch_cycles: 20,
+	},
+	// 66: Machop
+	SpeciesInfo {
+		base_stats: Stats { hp: 70, attack: 80, defense: 50, speed: 35, sp_attack: 35, sp_defense: 35 },
+		types: [T::Fighting, T::Fighting],
+		abilities: [A::Guts, A::NoGuard],
+		gender_ratio: GenderRatio(63),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 180,
+		base_friendship: 70,
+		egg_groups: [G::HumanLike, G::HumanLike],
+		hatch_cycles: 20,
+	},
+	// 67: Machoke
+	SpeciesInfo {
+		base_stats: Stats { hp: 80, attack: 100, defense: 70, speed: 45, sp_attack: 50, sp_defense: 60 },
+		types: [T::Fighting, T::Fighting],
+		abilities: [A::Guts, A::NoGuard],
+		gender_ratio: GenderRatio(63),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 90,
+		base_friendship: 70,
+		egg_groups: [G::HumanLike, G::HumanLike],
+		hatch_cycles: 20,
+	},
+	// 68: Machamp
+	SpeciesInfo {
+		base_stats: Stats { hp: 90, attack: 130, defense: 80, speed: 55, sp_attack: 65, sp_defense: 85 },
+		types: [T::Fighting, T::Fighting],
+		abilities: [A::Guts, A::NoGuard],
+		gender_ratio: GenderRatio(63),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::HumanLike, G::HumanLike],
+		hatch_cycles: 20,
+	},
+	// 69: Bellsprout
+	SpeciesInfo {
+		base_stats: Stats { hp: 50, attack: 75, defense: 35, speed: 40, sp_attack: 70, sp_defense: 30 },
+		types: [T::Grass, T::Poison],
+		abilities: [A::Chlorophyll, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 255,
+		base_friendship: 70,
+		egg_groups: [G::Grass, G::Grass],
+		hatch_cycles: 20,
+	},
+	// 70: Weepinbell
+	SpeciesInfo {
+		base_stats: Stats { hp: 65, attack: 90, defense: 50, speed: 55, sp_attack: 85, sp_defense: 45 },
+		types: [T::Grass, T::Poison],
+		abilities: [A::Chlorophyll, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 120,
+		base_friendship: 70,
+		egg_groups: [G::Grass, G::Grass],
+		hatch_cycles: 20,
+	},
+	// 71: Victreebel
+	SpeciesInfo {
+		base_stats: Stats { hp: 80, attack: 105, defense: 65, speed: 70, sp_attack: 100, sp_defense: 60 },
+		types: [T::Grass, T::Poison],
+		abilities: [A::Chlorophyll, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Grass, G::Grass],
+		hatch_cycles: 20,
+	},
+	// 72: Tentacool
+	SpeciesInfo {
+		base_stats: Stats { hp: 40, attack: 40, defense: 35, speed: 70, sp_attack: 50, sp_defense: 100 },
+		types: [T::Water, T::Poison],
+		abilities: [A::ClearBody, A::LiquidOoze],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 190,
+		base_friendship: 70,
+		egg_groups: [G::Water3, G::Water3],
+		hatch_cycles: 20,
+	},
+	// 73: Tentacruel
+	SpeciesInfo {
+		base_stats: Stats { hp: 80, attack: 70, defense: 65, speed: 100, sp_attack: 80, sp_defense: 120 },
+		types: [T::Water, T::Poison],
+		abilities: [A::ClearBody, A::LiquidOoze],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 60,
+		base_friendship: 70,
+		egg_groups: [G::Water3, G::Water3],
+		hatch_cycles: 20,
+	},
+	// 74: Geodude
+	SpeciesInfo {
+		base_stats: Stats { hp: 40, attack: 80, defense: 100, speed: 20, sp_attack: 30, sp_defense: 30 },
+		types: [T::Rock, T::Ground],
+		abilities: [A::RockHead, A::Sturdy],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 255,
+		base_friendship: 70,
+		egg_groups: [G::Mineral, G::Mineral],
+		hatch_cycles: 15,
+	},
+	// 75: Graveler
+	SpeciesInfo {
+		base_stats: Stats { hp: 55, attack: 95, defense: 115, speed: 35, sp_attack: 45, sp_defense: 45 },
+		types: [T::Rock, T::Ground],
+		abilities: [A::RockHead, A::Sturdy],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 120,
+		base_friendship: 70,
+		egg_groups: [G::Mineral, G::Mineral],
+		hatch_cycles: 15,
+	},
+	// 76: Golem
+	SpeciesInfo {
+		base_stats: Stats { hp: 80, attack: 110, defense: 130, speed: 45, sp_attack: 55, sp_defense: 65 },
+		types: [T::Rock, T::Ground],
+		abilities: [A::RockHead, A::Sturdy],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Mineral, G::Mineral],
+		hatch_cycles: 15,
+	},
+	// 77: Ponyta
+	SpeciesInfo {
+		base_stats: Stats { hp: 50, attack: 85, defense: 55, speed: 90, sp_attack: 65, sp_defense: 65 },
+		types: [T::Fire, T::Fire],
+		abilities: [A::RunAway, A::FlashFire],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 190,
+		base_friendship: 70,
+		egg_groups: [G::Field, G::Field],
+		hatch_cycles: 20,
+	},
+	// 78: Rapidash
+	SpeciesInfo {
+		base_stats: Stats { hp: 65, attack: 100, defense: 70, speed: 105, sp_attack: 80, sp_defense: 80 },
+		types: [T::Fire, T::Fire],
+		abilities: [A::RunAway, A::FlashFire],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 60,
+		base_friendship: 70,
+		egg_groups: [G::Field, G::Field],
+		hatch_cycles: 20,
+	},
+	// 79: Slowpoke
+	SpeciesInfo {
+		base_stats: Stats { hp: 90, attack: 65, defense: 65, speed: 15, sp_attack: 40, sp_defense: 40 },
+		types: [T::Water, T::Psychic],
+		abilities: [A::Oblivious, A::OwnTempo],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 190,
+		base_friendship: 70,
+		egg_groups: [G::Monster, G::Water1],
+		hatch_cycles: 20,
+	},
+	// 80: Slowbro
+	SpeciesInfo {
+		base_stats: Stats { hp: 95, attack: 75, defense: 110, speed: 30, sp_attack: 100, sp_defense: 80 },
+		types: [T::Water, T::Psychic],
+		abilities: [A::Oblivious, A::OwnTempo],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 75,
+		base_friendship: 70,
+		egg_groups: [G::Monster, G::Water1],
+		hatch_cycles: 20,
+	},
+	// 81: Magnemite
+	SpeciesInfo {
+		base_stats: Stats { hp: 25, attack: 35, defense: 70, speed: 45, sp_attack: 95, sp_defense: 55 },
+		types: [T::Electric, T::Steel],
+		abilities: [A::MagnetPull, A::Sturdy],
+		gender_ratio: GenderRatio::GENDERLESS,
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 190,
+		base_friendship: 70,
+		egg_groups: [G::Mineral, G::Mineral],
+		hatch_cycles: 20,
+	},
+	// 82: Magneton
+	SpeciesInfo {
+		base_stats: Stats { hp: 50, attack: 60, defense: 95, speed: 70, sp_attack: 120, sp_defense: 70 },
+		types: [T::Electric, T::Steel],
+		abilities: [A::MagnetPull, A::Sturdy],
+		gender_ratio: GenderRatio::GENDERLESS,
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 60,
+		base_friendship: 70,
+		egg_groups: [G::Mineral, G::Mineral],
+		hatch_cycles: 20,
+	},
+	// 83: Farfetchd
+	SpeciesInfo {
+		base_stats: Stats { hp: 52, attack: 65, defense: 55, speed: 60, sp_attack: 58, sp_defense: 62 },
+		types: [T::Normal, T::Flying],
+		abilities: [A::KeenEye, A::InnerFocus],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Flying, G::Field],
+		hatch_cycles: 20,
+	},
+	// 84: Doduo
+	SpeciesInfo {
+		base_stats: Stats { hp: 35, attack: 85, defense: 45, speed: 75, sp_attack: 35, sp_defense: 35 },
+		types: [T::Normal, T::Flying],
+		abilities: [A::RunAway, A::EarlyBird],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 190,
+		base_friendship: 70,
+		egg_groups: [G::Flying, G::Flying],
+		hatch_cycles: 20,
+	},
+	// 85: Dodrio
+	SpeciesInfo {
+		base_stats: Stats { hp: 60, attack: 110, defense: 70, speed: 100, sp_attack: 60, sp_defense: 60 },
+		types: [T::Normal, T::Flying],
+		abilities: [A::RunAway, A::EarlyBird],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Flying, G::Flying],
+		hatch_cycles: 20,
+	},
+	// 86: Seel
+	SpeciesInfo {
+		base_stats: Stats { hp: 65, attack: 45, defense: 55, speed: 45, sp_attack: 45, sp_defense: 70 },
+		types: [T::Water, T::Water],
+		abilities: [A::ThickFat, A::Hydration],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 190,
+		base_friendship: 70,
+		egg_groups: [G::Water1, G::Field],
+		hatch_cycles: 20,
+	},
+	// 87: Dewgong
+	SpeciesInfo {
+		base_stats: Stats { hp: 90, attack: 70, defense: 80, speed: 70, sp_attack: 70, sp_defense: 95 },
+		types: [T::Water, T::Ice],
+		abilities: [A::ThickFat, A::Hydration],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 75,
+		base_friendship: 70,
+		egg_groups: [G::Water1, G::Field],
+		hatch_cycles: 20,
+	},
+	// 88: Grimer
+	SpeciesInfo {
+		base_stats: Stats { hp: 80, attack: 80, defense: 50, speed: 25, sp_attack: 40, sp_defense: 50 },
+		types: [T::Poison, T::Poison],
+		abilities: [A::Stench, A::StickyHold],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 190,
+		base_friendship: 70,
+		egg_groups: [G::Amorphous, G::Amorphous],
+		hatch_cycles: 20,
+	},
+	// 89: Muk
+	SpeciesInfo {
+		base_stats: Stats { hp: 105, attack: 105, defense: 75, speed: 50, sp_attack: 65, sp_defense: 100 },
+		types: [T::Poison, T::Poison],
+		abilities: [A::Stench, A::StickyHold],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 75,
+		base_friendship: 70,
+		egg_groups: [G::Amorphous, G::Amorphous],
+		hatch_cycles: 20,
+	},
+	// 90: Shellder
+	SpeciesInfo {
+		base_stats: Stats { hp: 30, attack: 65, defense: 100, speed: 40, sp_attack: 45, sp_defense: 25 },
+		types: [T::Water, T::Water],
+		abilities: [A::ShellArmor, A::SkillLink],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 190,
+		base_friendship: 70,
+		egg_groups: [G::Water3, G::Water3],
+		hatch_cycles: 20,
+	},
+	// 91: Cloyster
+	SpeciesInfo {
+		base_stats: Stats { hp: 50, attack: 95, defense: 180, speed: 70, sp_attack: 85, sp_defense: 45 },
+		types: [T::Water, T::Ice],
+		abilities: [A::ShellArmor, A::SkillLink],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 60,
+		base_friendship: 70,
+		egg_groups: [G::Water3, G::Water3],
+		hatch_cycles: 20,
+	},
+	// 92: Gastly
+	SpeciesInfo {
+		base_stats: Stats { hp: 30, attack: 35, defense: 30, speed: 80, sp_attack: 100, sp_defense: 35 },
+		types: [T::Ghost, T::Poison],
+		abilities: [A::Levitate, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 190,
+		base_friendship: 70,
+		egg_groups: [G::Amorphous, G::Amorphous],
+		hatch_cycles: 20,
+	},
+	// 93: Haunter
+	SpeciesInfo {
+		base_stats: Stats { hp: 45, attack: 50, defense: 45, speed: 95, sp_attack: 115, sp_defense: 55 },
+		types: [T::Ghost, T::Poison],
+		abilities: [A::Levitate, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 90,
+		base_friendship: 70,
+		egg_groups: [G::Amorphous, G::Amorphous],
+		hatch_cycles: 20,
+	},
+	// 94: Gengar
+	SpeciesInfo {
+		base_stats: Stats { hp: 60, attack: 65, defense: 60, speed: 110, sp_attack: 130, sp_defense: 75 },
+		types: [T::Ghost, T::Poison],
+		abilities: [A::Levitate, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Amorphous, G::Amorphous],
+		hatch_cycles: 20,
+	},
+	// 95: Onix
+	SpeciesInfo {
+		base_stats: Stats { hp: 35, attack: 45, defense: 160, speed: 70, sp_attack: 30, sp_defense: 45 },
+		types: [T::Rock, T::Ground],
+		abilities: [A::RockHead, A::Sturdy],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Mineral, G::Mineral],
+		hatch_cycles: 25,
+	},
+	// 96: Drowzee
+	SpeciesInfo {
+		base_stats: Stats { hp: 60, attack: 48, defense: 45, speed: 42, sp_attack: 43, sp_defense: 90 },
+		types: [T::Psychic, T::Psychic],
+		abilities: [A::Insomnia, A::Forewarn],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 190,
+		base_friendship: 70,
+		egg_groups: [G::HumanLike, G::HumanLike],
+		hatch_cycles: 20,
+	},
+	// 97: Hypno
+	SpeciesInfo {
+		base_stats: Stats { hp: 85, attack: 73, defense: 70, speed: 67, sp_attack: 73, sp_defense: 115 },
+		types: [T::Psychic, T::Psychic],
+		abilities: [A::Insomnia, A::Forewarn],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 75,
+		base_friendship: 70,
+		egg_groups: [G::HumanLike, G::HumanLike],
+		hatch_cycles: 20,
+	},
+	// 98: Krabby
+	SpeciesInfo {
+		base_stats: Stats { hp: 30, attack: 105, defense: 90, speed: 50, sp_attack: 25, sp_defense: 25 },
+		types: [T::Water, T::Water],
+		abilities: [A::HyperCutter, A::ShellArmor],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 225,
+		base_friendship: 70,
+		egg_groups: [G::Water3, G::Water3],
+		hatch_cycles: 20,
+	},
+	// 99: Kingler
+	SpeciesInfo {
+		base_stats: Stats { hp: 55, attack: 130, defense: 115, speed: 75, sp_attack: 50, sp_defense: 50 },
+		types: [T::Water, T::Water],
+		abilities: [A::HyperCutter, A::ShellArmor],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 60,
+		base_friendship: 70,
+		egg_groups: [G::Water3, G::Water3],
+		hatch_cycles: 20,
+	},
+	// 100: Voltorb
+	SpeciesInfo {
+		base_stats: Stats { hp: 40, attack: 30, defense: 50, speed: 100, sp_attack: 55, sp_defense: 55 },
+		types: [T::Electric, T::Electric],
+		abilities: [A::Soundproof, A::Static],
+		gender_ratio: GenderRatio::GENDERLESS,
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 190,
+		base_friendship: 70,
+		egg_groups: [G::Mineral, G::Mineral],
+		hatch_cycles: 20,
+	},
+	// 101: Electrode
+	SpeciesInfo {
+		base_stats: Stats { hp: 60, attack: 50, defense: 70, speed: 140, sp_attack: 80, sp_defense: 80 },
+		types: [T::Electric, T::Electric],
+		abilities: [A::Soundproof, A::Static],
+		gender_ratio: GenderRatio::GENDERLESS,
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 60,
+		base_friendship: 70,
+		egg_groups: [G::Mineral, G::Mineral],
+		hatch_cycles: 20,
+	},
+	// 102: Exeggcute
+	SpeciesInfo {
+		base_stats: Stats { hp: 60, attack: 40, defense: 80, speed: 40, sp_attack: 60, sp_defense: 45 },
+		types: [T::Grass, T::Psychic],
+		abilities: [A::Chlorophyll, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 90,
+		base_friendship: 70,
+		egg_groups: [G::Grass, G::Grass],
+		hatch_cycles: 20,
+	},
+	// 103: Exeggutor
+	SpeciesInfo {
+		base_stats: Stats { hp: 95, attack: 95, defense: 85, speed: 55, sp_attack: 125, sp_defense: 65 },
+		types: [T::Grass, T::Psychic],
+		abilities: [A::Chlorophyll, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Grass, G::Grass],
+		hatch_cycles: 20,
+	},
+	// 104: Cubone
+	SpeciesInfo {
+		base_stats: Stats { hp: 50, attack: 50, defense: 95, speed: 35, sp_attack: 40, sp_defense: 50 },
+		types: [T::Ground, T::Ground],
+		abilities: [A::RockHead, A::LightningRod],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 190,
+		base_friendship: 70,
+		egg_groups: [G::Monster, G::Monster],
+		hatch_cycles: 20,
+	},
+	// 105: Marowak
+	SpeciesInfo {
+		base_stats: Stats { hp: 60, attack: 80, defense: 110, speed: 45, sp_attack: 50, sp_defense: 80 },
+		types: [T::Ground, T::Ground],
+		abilities: [A::RockHead, A::LightningRod],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 75,
+		base_friendship: 70,
+		egg_groups: [G::Monster, G::Monster],
+		hatch_cycles: 20,
+	},
+	// 106: Hitmonlee
+	SpeciesInfo {
+		base_stats: Stats { hp: 50, attack: 120, defense: 53, speed: 87, sp_attack: 35, sp_defense: 110 },
+		types: [T::Fighting, T::Fighting],
+		abilities: [A::Limber, A::Reckless],
+		gender_ratio: GenderRatio::MALE_ONLY,
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::HumanLike, G::HumanLike],
+		hatch_cycles: 25,
+	},
+	// 107: Hitmonchan
+	SpeciesInfo {
+		base_stats: Stats { hp: 50, attack: 105, defense: 79, speed: 76, sp_attack: 35, sp_defense: 110 },
+		types: [T::Fighting, T::Fighting],
+		abilities: [A::KeenEye, A::IronFist],
+		gender_ratio: GenderRatio::MALE_ONLY,
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::HumanLike, G::HumanLike],
+		hatch_cycles: 25,
+	},
+	// 108: Lickitung
+	SpeciesInfo {
+		base_stats: Stats { hp: 90, attack: 55, defense: 75, speed: 30, sp_attack: 60, sp_defense: 75 },
+		types: [T::Normal, T::Normal],
+		abilities: [A::OwnTempo, A::Oblivious],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Monster, G::Monster],
+		hatch_cycles: 20,
+	},
+	// 109: Koffing
+	SpeciesInfo {
+		base_stats: Stats { hp: 40, attack: 65, defense: 95, speed: 35, sp_attack: 60, sp_defense: 45 },
+		types: [T::Poison, T::Poison],
+		abilities: [A::Levitate, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 190,
+		base_friendship: 70,
+		egg_groups: [G::Amorphous, G::Amorphous],
+		hatch_cycles: 20,
+	},
+	// 110: Weezing
+	SpeciesInfo {
+		base_stats: Stats { hp: 65, attack: 90, defense: 120, speed: 60, sp_attack: 85, sp_defense: 70 },
+		types: [T::Poison, T::Poison],
+		abilities: [A::Levitate, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 60,
+		base_friendship: 70,
+		egg_groups: [G::Amorphous, G::Amorphous],
+		hatch_cycles: 20,
+	},
+	// 111: Rhyhorn
+	SpeciesInfo {
+		base_stats: Stats { hp: 80, attack: 85, defense: 95, speed: 25, sp_attack: 30, sp_defense: 30 },
+		types: [T::Ground, T::Rock],
+		abilities: [A::LightningRod, A::RockHead],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 120,
+		base_friendship: 70,
+		egg_groups: [G::Monster, G::Field],
+		hatch_cycles: 20,
+	},
+	// 112: Rhydon
+	SpeciesInfo {
+		base_stats: Stats { hp: 105, attack: 130, defense: 120, speed: 40, sp_attack: 45, sp_defense: 45 },
+		types: [T::Ground, T::Rock],
+		abilities: [A::LightningRod, A::RockHead],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 60,
+		base_friendship: 70,
+		egg_groups: [G::Monster, G::Field],
+		hatch_cycles: 20,
+	},
+	// 113: Chansey
+	SpeciesInfo {
+		base_stats: Stats { hp: 250, attack: 5, defense: 5, speed: 50, sp_attack: 35, sp_defense: 105 },
+		types: [T::Normal, T::Normal],
+		abilities: [A::NaturalCure, A::SereneGrace],
+		gender_ratio: GenderRatio::FEMALE_ONLY,
+		growth_rate: GrowthRate::Fast,
+		catch_rate: 30,
+		base_friendship: 140,
+		egg_groups: [G::Fairy, G::Fairy],
+		hatch_cycles: 40,
+	},
+	// 114: Tangela
+	SpeciesInfo {
+		base_stats: Stats { hp: 65, attack: 55, defense: 115, speed: 60, sp_attack: 100, sp_defense: 40 },
+		types: [T::Grass, T::Grass],
+		abilities: [A::Chlorophyll, A::LeafGuard],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Grass, G::Grass],
+		hatch_cycles: 20,
+	},
+	// 115: Kangaskhan
+	SpeciesInfo {
+		base_stats: Stats { hp: 105, attack: 95, defense: 80, speed: 90, sp_attack: 40, sp_defense: 80 },
+		types: [T::Normal, T::Normal],
+		abilities: [A::EarlyBird, A::Scrappy],
+		gender_ratio: GenderRatio::FEMALE_ONLY,
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Monster, G::Monster],
+		hatch_cycles: 20,
+	},
+	// 116: Horsea
+	SpeciesInfo {
+		base_stats: Stats { hp: 30, attack: 40, defense: 70, speed: 60, sp_attack: 70, sp_defense: 25 },
+		types: [T::Water, T::Water],
+		abilities: [A::SwiftSwim, A::Sniper],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 225,
+		base_friendship: 70,
+		egg_groups: [G::Water1, G::Dragon],
+		hatch_cycles: 20,
+	},
+	// 117: Seadra
+	SpeciesInfo {
+		base_stats: Stats { hp: 55, attack: 65, defense: 95, speed: 85, sp_attack: 95, sp_defense: 45 },
+		types: [T::Water, T::Water],
+		abilities: [A::PoisonPoint, A::Sniper],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 75,
+		base_friendship: 70,
+		egg_groups: [G::Water1, G::Dragon],
+		hatch_cycles: 20,
+	},
+	// 118: Goldeen
+	SpeciesInfo {
+		base_stats: Stats { hp: 45, attack: 67, defense: 60, speed: 63, sp_attack: 35, sp_defense: 50 },
+		types: [T::Water, T::Water],
+		abilities: [A::SwiftSwim, A::WaterVeil],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 225,
+		base_friendship: 70,
+		egg_groups: [G::Water2, G::Water2],
+		hatch_cycles: 20,
+	},
+	// 119: Seaking
+	SpeciesInfo {
+		base_stats: Stats { hp: 80, attack: 92, defense: 65, speed: 68, sp_attack: 65, sp_defense: 80 },
+		types: [T::Water, T::Water],
+		abilities: [A::SwiftSwim, A::WaterVeil],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 60,
+		base_friendship: 70,
+		egg_groups: [G::Water2, G::Water2],
+		hatch_cycles: 20,
+	},
+	// 120: Staryu
+	SpeciesInfo {
+		base_stats: Stats { hp: 30, attack: 45, defense: 55, speed: 85, sp_attack: 70, sp_defense: 55 },
+		types: [T::Water, T::Water],
+		abilities: [A::Illuminate, A::NaturalCure],
+		gender_ratio: GenderRatio::GENDERLESS,
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 225,
+		base_friendship: 70,
+		egg_groups: [G::Water3, G::Water3],
+		hatch_cycles: 20,
+	},
+	// 121: Starmie
+	SpeciesInfo {
+		base_stats: Stats { hp: 60, attack: 75, defense: 85, speed: 115, sp_attack: 100, sp_defense: 85 },
+		types: [T::Water, T::Psychic],
+		abilities: [A::Illuminate, A::NaturalCure],
+		gender_ratio: GenderRatio::GENDERLESS,
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 60,
+		base_friendship: 70,
+		egg_groups: [G::Water3, G::Water3],
+		hatch_cycles: 20,
+	},
+	// 122: MrMime
+	SpeciesInfo {
+		base_stats: Stats { hp: 40, attack: 45, defense: 65, speed: 90, sp_attack: 100, sp_defense: 120 },
+		types: [T::Psychic, T::Psychic],
+		abilities: [A::Soundproof, A::Filter],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::HumanLike, G::HumanLike],
+		hatch_cycles: 25,
+	},
+	// 123: Scyther
+	SpeciesInfo {
+		base_stats: Stats { hp: 70, attack: 110, defense: 80, speed: 105, sp_attack: 55, sp_defense: 80 },
+		types: [T::Bug, T::Flying],
+		abilities: [A::Swarm, A::Technician],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Bug, G::Bug],
+		hatch_cycles: 25,
+	},
+	// 124: Jynx
+	SpeciesInfo {
+		base_stats: Stats { hp: 65, attack: 50, defense: 35, speed: 95, sp_attack: 115, sp_defense: 95 },
+		types: [T::Ice, T::Psychic],
+		abilities: [A::Oblivious, A::Forewarn],
+		gender_ratio: GenderRatio::FEMALE_ONLY,
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::HumanLike, G::HumanLike],
+		hatch_cycles: 25,
+	},
+	// 125: Electabuzz
+	SpeciesInfo {
+		base_stats: Stats { hp: 65, attack: 83, defense: 57, speed: 105, sp_attack: 95, sp_defense: 85 },
+		types: [T::Electric, T::Electric],
+		abilities: [A::Static, A::None],
+		gender_ratio: GenderRatio(63),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::HumanLike, G::HumanLike],
+		hatch_cycles: 25,
+	},
+	// 126: Magmar
+	SpeciesInfo {
+		base_stats: Stats { hp: 65, attack: 95, defense: 57, speed: 93, sp_attack: 100, sp_defense: 85 },
+		types: [T::Fire, T::Fire],
+		abilities: [A::FlameBody, A::None],
+		gender_ratio: GenderRatio(63),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::HumanLike, G::HumanLike],
+		hatch_cycles: 25,
+	},
+	// 127: Pinsir
+	SpeciesInfo {
+		base_stats: Stats { hp: 65, attack: 125, defense: 100, speed: 85, sp_attack: 55, sp_defense: 70 },
+		types: [T::Bug, T::Bug],
+		abilities: [A::HyperCutter, A::MoldBreaker],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Bug, G::Bug],
+		hatch_cycles: 25,
+	},
+	// 128: Tauros
+	SpeciesInfo {
+		base_stats: Stats { hp: 75, attack: 100, defense: 95, speed: 110, sp_attack: 40, sp_defense: 70 },
+		types: [T::Normal, T::Normal],
+		abilities: [A::Intimidate, A::AngerPoint],
+		gender_ratio: GenderRatio::MALE_ONLY,
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Field, G::Field],
+		hatch_cycles: 20,
+	},
+	// 129: Magikarp
+	SpeciesInfo {
+		base_stats: Stats { hp: 20, attack: 10, defense: 55, speed: 80, sp_attack: 15, sp_defense: 20 },
+		types: [T::Water, T::Water],
+		abilities: [A::SwiftSwim, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 255,
+		base_friendship: 70,
+		egg_groups: [G::Water2, G::Dragon],
+		hatch_cycles: 5,
+	},
+	// 130: Gyarados
+	SpeciesInfo {
+		base_stats: Stats { hp: 95, attack: 125, defense: 79, speed: 81, sp_attack: 60, sp_defense: 100 },
+		types: [T::Water, T::Flying],
+		abilities: [A::Intimidate, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Water2, G::Dragon],
+		hatch_cycles: 5,
+	},
+	// 131: Lapras
+	SpeciesInfo {
+		base_stats: Stats { hp: 130, attack: 85, defense: 80, speed: 60, sp_attack: 85, sp_defense: 95 },
+		types: [T::Water, T::Ice],
+		abilities: [A::WaterAbsorb, A::ShellArmor],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Monster, G::Water1],
+		hatch_cycles: 40,
+	},
+	// 132: Ditto
+	SpeciesInfo {
+		base_stats: Stats { hp: 48, attack: 48, defense: 48, speed: 48, sp_attack: 48, sp_defense: 48 },
+		types: [T::Normal, T::Normal],
+		abilities: [A::Limber, A::None],
+		gender_ratio: GenderRatio::GENDERLESS,
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 35,
+		base_friendship: 70,
+		egg_groups: [G::Ditto, G::Ditto],
+		hatch_cycles: 20,
+	},
+	// 133: Eevee
+	SpeciesInfo {
+		base_stats: Stats { hp: 55, attack: 55, defense: 50, speed: 55, sp_attack: 45, sp_defense: 65 },
+		types: [T::Normal, T::Normal],
+		abilities: [A::RunAway, A::Adaptability],
+		gender_ratio: GenderRatio(31),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Field, G::Field],
+		hatch_cycles: 35,
+	},
+	// 134: Vaporeon
+	SpeciesInfo {
+		base_stats: Stats { hp: 130, attack: 65, defense: 60, speed: 65, sp_attack: 110, sp_defense: 95 },
+		types: [T::Water, T::Water],
+		abilities: [A::WaterAbsorb, A::None],
+		gender_ratio: GenderRatio(31),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Field, G::Field],
+		hatch_cycles: 35,
+	},
+	// 135: Jolteon
+	SpeciesInfo {
+		base_stats: Stats { hp: 65, attack: 65, defense: 60, speed: 130, sp_attack: 110, sp_defense: 95 },
+		types: [T::Electric, T::Electric],
+		abilities: [A::VoltAbsorb, A::None],
+		gender_ratio: GenderRatio(31),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Field, G::Field],
+		hatch_cycles: 35,
+	},
+	// 136: Flareon
+	SpeciesInfo {
+		base_stats: Stats { hp: 65, attack: 130, defense: 60, speed: 65, sp_attack: 95, sp_defense: 110 },
+		types: [T::Fire, T::Fire],
+		abilities: [A::FlashFire, A::None],
+		gender_ratio: GenderRatio(31),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Field, G::Field],
+		hatch_cycles: 35,
+	},
+	// 137: Porygon
+	SpeciesInfo {
+		base_stats: Stats { hp: 65, attack: 60, defense: 70, speed: 40, sp_attack: 85, sp_defense: 75 },
+		types: [T::Normal, T::Normal],
+		abilities: [A::Trace, A::Download],
+		gender_ratio: GenderRatio::GENDERLESS,
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Mineral, G::Mineral],
+		hatch_cycles: 20,
+	},
+	// 138: Omanyte
+	SpeciesInfo {
+		base_stats: Stats { hp: 35, attack: 40, defense: 100, speed: 35, sp_attack: 90, sp_defense: 55 },
+		types: [T::Rock, T::Water],
+		abilities: [A::SwiftSwim, A::ShellArmor],
+		gender_ratio: GenderRatio(31),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Water1, G::Water3],
+		hatch_cycles: 30,
+	},
+	// 139: Omastar
+	SpeciesInfo {
+		base_stats: Stats { hp: 70, attack: 60, defense: 125, speed: 55, sp_attack: 115, sp_defense: 70 },
+		types: [T::Rock, T::Water],
+		abilities: [A::SwiftSwim, A::ShellArmor],
+		gender_ratio: GenderRatio(31),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Water1, G::Water3],
+		hatch_cycles: 30,
+	},
+	// 140: Kabuto
+	SpeciesInfo {
+		base_stats: Stats { hp: 30, attack: 80, defense: 90, speed: 55, sp_attack: 55, sp_defense: 45 },
+		types: [T::Rock, T::Water],
+		abilities: [A::SwiftSwim, A::BattleArmor],
+		gender_ratio: GenderRatio(31),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Water1, G::Water3],
+		hatch_cycles: 30,
+	},
+	// 141: Kabutops
+	SpeciesInfo {
+		base_stats: Stats { hp: 60, attack: 115, defense: 105, speed: 80, sp_attack: 65, sp_defense: 70 },
+		types: [T::Rock, T::Water],
+		abilities: [A::SwiftSwim, A::BattleArmor],
+		gender_ratio: GenderRatio(31),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Water1, G::Water3],
+		hatch_cycles: 30,
+	},
+	// 142: Aerodactyl
+	SpeciesInfo {
+		base_stats: Stats { hp: 80, attack: 105, defense: 65, speed: 130, sp_attack: 60, sp_defense: 75 },
+		types: [T::Rock, T::Flying],
+		abilities: [A::RockHead, A::Pressure],
+		gender_ratio: GenderRatio(31),
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Flying, G::Flying],
+		hatch_cycles: 35,
+	},
+	// 143: Snorlax
+	SpeciesInfo {
+		base_stats: Stats { hp: 160, attack: 110, defense: 65, speed: 30, sp_attack: 65, sp_defense: 110 },
+		types: [T::Normal, T::Normal],
+		abilities: [A::Immunity, A::ThickFat],
+		gender_ratio: GenderRatio(31),
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 25,
+		base_friendship: 70,
+		egg_groups: [G::Monster, G::Monster],
+		hatch_cycles: 40,
+	},
+	// 144: Articuno
+	SpeciesInfo {
+		base_stats: Stats { hp: 90, attack: 85, defense: 100, speed: 85, sp_attack: 95, sp_defense: 125 },
+		types: [T::Ice, T::Flying],
+		abilities: [A::Pressure, A::None],
+		gender_ratio: GenderRatio::GENDERLESS,
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 3,
+		base_friendship: 35,
+		egg_groups: [G::Undiscovered, G::Undiscovered],
+		hatch_cycles: 80,
+	},
+	// 145: Zapdos
+	SpeciesInfo {
+		base_stats: Stats { hp: 90, attack: 90, defense: 85, speed: 100, sp_attack: 125, sp_defense: 90 },
+		types: [T::Electric, T::Flying],
+		abilities: [A::Pressure, A::None],
+		gender_ratio: GenderRatio::GENDERLESS,
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 3,
+		base_friendship: 35,
+		egg_groups: [G::Undiscovered, G::Undiscovered],
+		hatch_cycles: 80,
+	},
+	// 146: Moltres
+	SpeciesInfo {
+		base_stats: Stats { hp: 90, attack: 100, defense: 90, speed: 90, sp_attack: 125, sp_defense: 85 },
+		types: [T::Fire, T::Flying],
+		abilities: [A::Pressure, A::None],
+		gender_ratio: GenderRatio::GENDERLESS,
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 3,
+		base_friendship: 35,
+		egg_groups: [G::Undiscovered, G::Undiscovered],
+		hatch_cycles: 80,
+	},
+	// 147: Dratini
+	SpeciesInfo {
+		base_stats: Stats { hp: 41, attack: 64, defense: 45, speed: 50, sp_attack: 50, sp_defense: 50 },
+		types: [T::Dragon, T::Dragon],
+		abilities: [A::ShedSkin, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 45,
+		base_friendship: 35,
+		egg_groups: [G::Water1, G::Dragon],
+		hatch_cycles: 40,
+	},
+	// 148: Dragonair
+	SpeciesInfo {
+		base_stats: Stats { hp: 61, attack: 84, defense: 65, speed: 70, sp_attack: 70, sp_defense: 70 },
+		types: [T::Dragon, T::Dragon],
+		abilities: [A::ShedSkin, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 45,
+		base_friendship: 35,
+		egg_groups: [G::Water1, G::Dragon],
+		hatch_cycles: 40,
+	},
+	// 149: Dragonite
+	SpeciesInfo {
+		base_stats: Stats { hp: 91, attack: 134, defense: 95, speed: 80, sp_attack: 100, sp_defense: 100 },
+		types: [T::Dragon, T::Flying],
+		abilities: [A::InnerFocus, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 45,
+		base_friendship: 35,
+		egg_groups: [G::Water1, G::Dragon],
+		hatch_cycles: 40,
+	},
+	// 150: Mewtwo
+	SpeciesInfo {
+		base_stats: Stats { hp: 106, attack: 110, defense: 90, speed: 130, sp_attack: 154, sp_defense: 90 },
+		types: [T::Psychic, T::Psychic],
+		abilities: [A::Pressure, A::None],
+		gender_ratio: GenderRatio::GENDERLESS,
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 3,
+		base_friendship: 0,
+		egg_groups: [G::Undiscovered, G::Undiscovered],
+		hatch_cycles: 120,
+	},
+	// 151: Mew
+	SpeciesInfo {
+		base_stats: Stats { hp: 100, attack: 100, defense: 100, speed: 100, sp_attack: 100, sp_defense: 100 },
+		types: [T::Psychic, T::Psychic],
+		abilities: [A::Synchronize, A::None],
+		gender_ratio: GenderRatio::GENDERLESS,
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 45,
+		base_friendship: 100,
+		egg_groups: [G::Undiscovered, G::Undiscovered],
+		hatch_cycles: 120,
+	},
+	// 152: Chikorita
+	SpeciesInfo {
+		base_stats: Stats { hp: 45, attack: 49, defense: 65, speed: 45, sp_attack: 49, sp_defense: 65 },
+		types: [T::Grass, T::Grass],
+		abilities: [A::Overgrow, A::None],
+		gender_ratio: GenderRatio(31),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Monster, G::Grass],
+		hatch_cycles: 20,
+	},
+	// 153: Bayleef
+	SpeciesInfo {
+		base_stats: Stats { hp: 60, attack: 62, defense: 80, speed: 60, sp_attack: 63, sp_defense: 80 },
+		types: [T::Grass, T::Grass],
+		abilities: [A::Overgrow, A::None],
+		gender_ratio: GenderRatio(31),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Monster, G::Grass],
+		hatch_cycles: 20,
+	},
+	// 154: Meganium
+	SpeciesInfo {
+		base_stats: Stats { hp: 80, attack: 82, defense: 100, speed: 80, sp_attack: 83, sp_defense: 100 },
+		types: [T::Grass, T::Grass],
+		abilities: [A::Overgrow, A::None],
+		gender_ratio: GenderRatio(31),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Monster, G::Grass],
+		hatch_cycles: 20,
+	},
+	// 155: Cyndaquil
+	SpeciesInfo {
+		base_stats: Stats { hp: 39, attack: 52, defense: 43, speed: 65, sp_attack: 60, sp_defense: 50 },
+		types: [T::Fire, T::Fire],
+		abilities: [A::Blaze, A::None],
+		gender_ratio: GenderRatio(31),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Field, G::Field],
+		hatch_cycles: 20,
+	},
+	// 156: Quilava
+	SpeciesInfo {
+		base_stats: Stats { hp: 58, attack: 64, defense: 58, speed: 80, sp_attack: 80, sp_defense: 65 },
+		types: [T::Fire, T::Fire],
+		abilities: [A::Blaze, A::None],
+		gender_ratio: GenderRatio(31),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Field, G::Field],
+		hatch_cycles: 20,
+	},
+	// 157: Typhlosion
+	SpeciesInfo {
+		base_stats: Stats { hp: 78, attack: 84, defense: 78, speed: 100, sp_attack: 109, sp_defense: 85 },
+		types: [T::Fire, T::Fire],
+		abilities: [A::Blaze, A::None],
+		gender_ratio: GenderRatio(31),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Field, G::Field],
+		hatch_cycles: 20,
+	},
+	// 158: Totodile
+	SpeciesInfo {
+		base_stats: Stats { hp: 50, attack: 65, defense: 64, speed: 43, sp_attack: 44, sp_defense: 48 },
+		types: [T::Water, T::Water],
+		abilities: [A::Torrent, A::None],
+		gender_ratio: GenderRatio(31),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Monster, G::Water1],
+		hatch_cycles: 20,
+	},
+	// 159: Croconaw
+	SpeciesInfo {
+		base_stats: Stats { hp: 65, attack: 80, defense: 80, speed: 58, sp_attack: 59, sp_defense: 63 },
+		types: [T::Water, T::Water],
+		abilities: [A::Torrent, A::None],
+		gender_ratio: GenderRatio(31),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Monster, G::Water1],
+		hatch_cycles: 20,
+	},
+	// 160: Feraligatr
+	SpeciesInfo {
+		base_stats: Stats { hp: 85, attack: 105, defense: 100, speed: 78, sp_attack: 79, sp_defense: 83 },
+		types: [T::Water, T::Water],
+		abilities: [A::Torrent, A::None],
+		gender_ratio: GenderRatio(31),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Monster, G::Water1],
+		hatch_cycles: 20,
+	},
+	// 161: Sentret
+	SpeciesInfo {
+		base_stats: Stats { hp: 35, attack: 46, defense: 34, speed: 20, sp_attack: 35, sp_defense: 45 },
+		types: [T::Normal, T::Normal],
+		abilities: [A::RunAway, A::KeenEye],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 255,
+		base_friendship: 70,
+		egg_groups: [G::Field, G::Field],
+		hatch_cycles: 15,
+	},
+	// 162: Furret
+	SpeciesInfo {
+		base_stats: Stats { hp: 85, attack: 76, defense: 64, speed: 90, sp_attack: 45, sp_defense: 55 },
+		types: [T::Normal, T::Normal],
+		abilities: [A::RunAway, A::KeenEye],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 90,
+		base_friendship: 70,
+		egg_groups: [G::Field, G::Field],
+		hatch_cycles: 15,
+	},
+	// 163: Hoothoot
+	SpeciesInfo {
+		base_stats: Stats { hp: 60, attack: 30, defense: 30, speed: 50, sp_attack: 36, sp_defense: 56 },
+		types: [T::Normal, T::Flying],
+		abilities: [A::Insomnia, A::KeenEye],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 255,
+		base_friendship: 70,
+		egg_groups: [G::Flying, G::Flying],
+		hatch_cycles: 15,
+	},
+	// 164: Noctowl
+	SpeciesInfo {
+		base_stats: Stats { hp: 100, attack: 50, defense: 50, speed: 70, sp_attack: 76, sp_defense: 96 },
+		types: [T::Normal, T::Flying],
+		abilities: [A::Insomnia, A::KeenEye],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 90,
+		base_friendship: 70,
+		egg_groups: [G::Flying, G::Flying],
+		hatch_cycles: 15,
+	},
+	// 165: Ledyba
+	SpeciesInfo {
+		base_stats: Stats { hp: 40, attack: 20, defense: 30, speed: 55, sp_attack: 40, sp_defense: 80 },
+		types: [T::Bug, T::Flying],
+		abilities: [A::Swarm, A::EarlyBird],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Fast,
+		catch_rate: 255,
+		base_friendship: 70,
+		egg_groups: [G::Bug, G::Bug],
+		hatch_cycles: 15,
+	},
+	// 166: Ledian
+	SpeciesInfo {
+		base_stats: Stats { hp: 55, attack: 35, defense: 50, speed: 85, sp_attack: 55, sp_defense: 110 },
+		types: [T::Bug, T::Flying],
+		abilities: [A::Swarm, A::EarlyBird],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Fast,
+		catch_rate: 90,
+		base_friendship: 70,
+		egg_groups: [G::Bug, G::Bug],
+		hatch_cycles: 15,
+	},
+	// 167: Spinarak
+	SpeciesInfo {
+		base_stats: Stats { hp: 40, attack: 60, defense: 40, speed: 30, sp_attack: 40, sp_defense: 40 },
+		types: [T::Bug, T::Poison],
+		abilities: [A::Swarm, A::Insomnia],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Fast,
+		catch_rate: 255,
+		base_friendship: 70,
+		egg_groups: [G::Bug, G::Bug],
+		hatch_cycles: 15,
+	},
+	// 168: Ariados
+	SpeciesInfo {
+		base_stats: Stats { hp: 70, attack: 90, defense: 70, speed: 40, sp_attack: 60, sp_defense: 60 },
+		types: [T::Bug, T::Poison],
+		abilities: [A::Swarm, A::Insomnia],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Fast,
+		catch_rate: 90,
+		base_friendship: 70,
+		egg_groups: [G::Bug, G::Bug],
+		hatch_cycles: 15,
+	},
+	// 169: Crobat
+	SpeciesInfo {
+		base_stats: Stats { hp: 85, attack: 90, defense: 80, speed: 130, sp_attack: 70, sp_defense: 80 },
+		types: [T::Poison, T::Flying],
+		abilities: [A::InnerFocus, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 90,
+		base_friendship: 70,
+		egg_groups: [G::Flying, G::Flying],
+		hatch_cycles: 15,
+	},
+	// 170: Chinchou
+	SpeciesInfo {
+		base_stats: Stats { hp: 75, attack: 38, defense: 38, speed: 67, sp_attack: 56, sp_defense: 56 },
+		types: [T::Water, T::Electric],
+		abilities: [A::VoltAbsorb, A::Illuminate],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 190,
+		base_friendship: 70,
+		egg_groups: [G::Water2, G::Water2],
+		hatch_cycles: 20,
+	},
+	// 171: Lanturn
+	SpeciesInfo {
+		base_stats: Stats { hp: 125, attack: 58, defense: 58, speed: 67, sp_attack: 76, sp_defense: 76 },
+		types: [T::Water, T::Electric],
+		abilities: [A::VoltAbsorb, A::Illuminate],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 75,
+		base_friendship: 70,
+		egg_groups: [G::Water2, G::Water2],
+		hatch_cycles: 20,
+	},
+	// 172: Pichu
+	SpeciesInfo {
+		base_stats: Stats { hp: 20, attack: 40, defense: 15, speed: 60, sp_attack: 35, sp_defense: 35 },
+		types: [T::Electric, T::Electric],
+		abilities: [A::Static, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 190,
+		base_friendship: 70,
+		egg_groups: [G::Undiscovered, G::Undiscovered],
+		hatch_cycles: 10,
+	},
+	// 173: Cleffa
+	SpeciesInfo {
+		base_stats: Stats { hp: 50, attack: 25, defense: 28, speed: 15, sp_attack: 45, sp_defense: 55 },
+		types: [T::Normal, T::Normal],
+		abilities: [A::CuteCharm, A::MagicGuard],
+		gender_ratio: GenderRatio(191),
+		growth_rate: GrowthRate::Fast,
+		catch_rate: 150,
+		base_friendship: 140,
+		egg_groups: [G::Undiscovered, G::Undiscovered],
+		hatch_cycles: 10,
+	},
+	// 174: Igglybuff
+	SpeciesInfo {
+		base_stats: Stats { hp: 90, attack: 30, defense: 15, speed: 15, sp_attack: 40, sp_defense: 20 },
+		types: [T::Normal, T::Normal],
+		abilities: [A::CuteCharm, A::None],
+		gender_ratio: GenderRatio(191),
+		growth_rate: GrowthRate::Fast,
+		catch_rate: 170,
+		base_friendship: 50,
+		egg_groups: [G::Undiscovered, G::Undiscovered],
+		hatch_cycles: 10,
+	},
+	// 175: Togepi
+	SpeciesInfo {
+		base_stats: Stats { hp: 35, attack: 20, defense: 65, speed: 20, sp_attack: 40, sp_defense: 65 },
+		types: [T::Normal, T::Normal],
+		abilities: [A::Hustle, A::SereneGrace],
+		gender_ratio: GenderRatio(31),
+		growth_rate: GrowthRate::Fast,
+		catch_rate: 190,
+		base_friendship: 70,
+		egg_groups: [G::Undiscovered, G::Undiscovered],
+		hatch_cycles: 10,
+	},
+	// 176: Togetic
+	SpeciesInfo {
+		base_stats: Stats { hp: 55, attack: 40, defense: 85, speed: 40, sp_attack: 80, sp_defense: 105 },
+		types: [T::Normal, T::Flying],
+		abilities: [A::Hustle, A::SereneGrace],
+		gender_ratio: GenderRatio(31),
+		growth_rate: GrowthRate::Fast,
+		catch_rate: 75,
+		base_friendship: 70,
+		egg_groups: [G::Flying, G::Fairy],
+		hatch_cycles: 10,
+	},
+	// 177: Natu
+	SpeciesInfo {
+		base_stats: Stats { hp: 40, attack: 50, defense: 45, speed: 70, sp_attack: 70, sp_defense: 45 },
+		types: [T::Psychic, T::Flying],
+		abilities: [A::Synchronize, A::EarlyBird],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 190,
+		base_friendship: 70,
+		egg_groups: [G::Flying, G::Flying],
+		hatch_cycles: 20,
+	},
+	// 178: Xatu
+	SpeciesInfo {
+		base_stats: Stats { hp: 65, attack: 75, defense: 70, speed: 95, sp_attack: 95, sp_defense: 70 },
+		types: [T::Psychic, T::Flying],
+		abilities: [A::Synchronize, A::EarlyBird],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 75,
+		base_friendship: 70,
+		egg_groups: [G::Flying, G::Flying],
+		hatch_cycles: 20,
+	},
+	// 179: Mareep
+	SpeciesInfo {
+		base_stats: Stats { hp: 55, attack: 40, defense: 40, speed: 35, sp_attack: 65, sp_defense: 45 },
+		types: [T::Electric, T::Electric],
+		abilities: [A::Static, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 235,
+		base_friendship: 70,
+		egg_groups: [G::Monster, G::Field],
+		hatch_cycles: 20,
+	},
+	// 180: Flaaffy
+	SpeciesInfo {
+		base_stats: Stats { hp: 70, attack: 55, defense: 55, speed: 45, sp_attack: 80, sp_defense: 60 },
+		types: [T::Electric, T::Electric],
+		abilities: [A::Static, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 120,
+		base_friendship: 70,
+		egg_groups: [G::Monster, G::Field],
+		hatch_cycles: 20,
+	},
+	// 181: Ampharos
+	SpeciesInfo {
+		base_stats: Stats { hp: 90, attack: 75, defense: 75, speed: 55, sp_attack: 115, sp_defense: 90 },
+		types: [T::Electric, T::Electric],
+		abilities: [A::Static, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Monster, G::Field],
+		hatch_cycles: 20,
+	},
+	// 182: Bellossom
+	SpeciesInfo {
+		base_stats: Stats { hp: 75, attack: 80, defense: 85, speed: 50, sp_attack: 90, sp_defense: 100 },
+		types: [T::Grass, T::Grass],
+		abilities: [A::Chlorophyll, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Grass, G::Grass],
+		hatch_cycles: 20,
+	},
+	// 183: Marill
+	SpeciesInfo {
+		base_stats: Stats { hp: 70, attack: 20, defense: 50, speed: 40, sp_attack: 20, sp_defense: 50 },
+		types: [T::Water, T::Water],
+		abilities: [A::ThickFat, A::HugePower],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Fast,
+		catch_rate: 190,
+		base_friendship: 70,
+		egg_groups: [G::Water1, G::Fairy],
+		hatch_cycles: 10,
+	},
+	// 184: Azumarill
+	SpeciesInfo {
+		base_stats: Stats { hp: 100, attack: 50, defense: 80, speed: 50, sp_attack: 50, sp_defense: 80 },
+		types: [T::Water, T::Water],
+		abilities: [A::ThickFat, A::HugePower],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Fast,
+		catch_rate: 75,
+		base_friendship: 70,
+		egg_groups: [G::Water1, G::Fairy],
+		hatch_cycles: 10,
+	},
+	// 185: Sudowoodo
+	SpeciesInfo {
+		base_stats: Stats { hp: 70, attack: 100, defense: 115, speed: 30, sp_attack: 30, sp_defense: 65 },
+		types: [T::Rock, T::Rock],
+		abilities: [A::Sturdy, A::RockHead],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 65,
+		base_friendship: 70,
+		egg_groups: [G::Mineral, G::Mineral],
+		hatch_cycles: 20,
+	},
+	// 186: Politoed
+	SpeciesInfo {
+		base_stats: Stats { hp: 90, attack: 75, defense: 75, speed: 70, sp_attack: 90, sp_defense: 100 },
+		types: [T::Water, T::Water],
+		abilities: [A::WaterAbsorb, A::Damp],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Water1, G::Water1],
+		hatch_cycles: 20,
+	},
+	// 187: Hoppip
+	SpeciesInfo {
+		base_stats: Stats { hp: 35, attack: 35, defense: 40, speed: 50, sp_attack: 35, sp_defense: 55 },
+		types: [T::Grass, T::Flying],
+		abilities: [A::Chlorophyll, A::LeafGuard],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 255,
+		base_friendship: 70,
+		egg_groups: [G::Fairy, G::Grass],
+		hatch_cycles: 20,
+	},
+	// 188: Skiploom
+	SpeciesInfo {
+		base_stats: Stats { hp: 55, attack: 45, defense: 50, speed: 80, sp_attack: 45, sp_defense: 65 },
+		types: [T::Grass, T::Flying],
+		abilities: [A::Chlorophyll, A::LeafGuard],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 120,
+		base_friendship: 70,
+		egg_groups: [G::Fairy, G::Grass],
+		hatch_cycles: 20,
+	},
+	// 189: Jumpluff
+	SpeciesInfo {
+		base_stats: Stats { hp: 75, attack: 55, defense: 70, speed: 110, sp_attack: 55, sp_defense: 85 },
+		types: [T::Grass, T::Flying],
+		abilities: [A::Chlorophyll, A::LeafGuard],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Fairy, G::Grass],
+		hatch_cycles: 20,
+	},
+	// 190: Aipom
+	SpeciesInfo {
+		base_stats: Stats { hp: 55, attack: 70, defense: 55, speed: 85, sp_attack: 40, sp_defense: 55 },
+		types: [T::Normal, T::Normal],
+		abilities: [A::RunAway, A::Pickup],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Fast,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Field, G::Field],
+		hatch_cycles: 20,
+	},
+	// 191: Sunkern
+	SpeciesInfo {
+		base_stats: Stats { hp: 30, attack: 30, defense: 30, speed: 30, sp_attack: 30, sp_defense: 30 },
+		types: [T::Grass, T::Grass],
+		abilities: [A::Chlorophyll, A::SolarPower],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 235,
+		base_friendship: 70,
+		egg_groups: [G::Grass, G::Grass],
+		hatch_cycles: 20,
+	},
+	// 192: Sunflora
+	SpeciesInfo {
+		base_stats: Stats { hp: 75, attack: 75, defense: 55, speed: 30, sp_attack: 105, sp_defense: 85 },
+		types: [T::Grass, T::Grass],
+		abilities: [A::Chlorophyll, A::SolarPower],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 120,
+		base_friendship: 70,
+		egg_groups: [G::Grass, G::Grass],
+		hatch_cycles: 20,
+	},
+	// 193: Yanma
+	SpeciesInfo {
+		base_stats: Stats { hp: 65, attack: 65, defense: 45, speed: 95, sp_attack: 75, sp_defense: 45 },
+		types: [T::Bug, T::Flying],
+		abilities: [A::SpeedBoost, A::CompoundEyes],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 75,
+		base_friendship: 70,
+		egg_groups: [G::Bug, G::Bug],
+		hatch_cycles: 20,
+	},
+	// 194: Wooper
+	SpeciesInfo {
+		base_stats: Stats { hp: 55, attack: 45, defense: 45, speed: 15, sp_attack: 25, sp_defense: 25 },
+		types: [T::Water, T::Ground],
+		abilities: [A::Damp, A::WaterAbsorb],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 255,
+		base_friendship: 70,
+		egg_groups: [G::Water1, G::Field],
+		hatch_cycles: 20,
+	},
+	// 195: Quagsire
+	SpeciesInfo {
+		base_stats: Stats { hp: 95, attack: 85, defense: 85, speed: 35, sp_attack: 65, sp_defense: 65 },
+		types: [T::Water, T::Ground],
+		abilities: [A::Damp, A::WaterAbsorb],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 90,
+		base_friendship: 70,
+		egg_groups: [G::Water1, G::Field],
+		hatch_cycles: 20,
+	},
+	// 196: Espeon
+	SpeciesInfo {
+		base_stats: Stats { hp: 65, attack: 65, defense: 60, speed: 110, sp_attack: 130, sp_defense: 95 },
+		types: [T::Psychic, T::Psychic],
+		abilities: [A::Synchronize, A::None],
+		gender_ratio: GenderRatio(31),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Field, G::Field],
+		hatch_cycles: 35,
+	},
+	// 197: Umbreon
+	SpeciesInfo {
+		base_stats: Stats { hp: 95, attack: 65, defense: 110, speed: 65, sp_attack: 60, sp_defense: 130 },
+		types: [T::Dark, T::Dark],
+		abilities: [A::Synchronize, A::None],
+		gender_ratio: GenderRatio(31),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 45,
+		base_friendship: 35,
+		egg_groups: [G::Field, G::Field],
+		hatch_cycles: 35,
+	},
+	// 198: Murkrow
+	SpeciesInfo {
+		base_stats: Stats { hp: 60, attack: 85, defense: 42, speed: 91, sp_attack: 85, sp_defense: 42 },
+		types: [T::Dark, T::Flying],
+		abilities: [A::Insomnia, A::SuperLuck],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 30,
+		base_friendship: 35,
+		egg_groups: [G::Flying, G::Flying],
+		hatch_cycles: 20,
+	},
+	// 199: Slowking
+	SpeciesInfo {
+		base_stats: Stats { hp: 95, attack: 75, defense: 80, speed: 30, sp_attack: 100, sp_defense: 110 },
+		types: [T::Water, T::Psychic],
+		abilities: [A::Oblivious, A::OwnTempo],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 70,
+		base_friendship: 70,
+		egg_groups: [G::Monster, G::Water1],
+		hatch_cycles: 20,
+	},
+	// 200: Misdreavus
+	SpeciesInfo {
+		base_stats: Stats { hp: 60, attack: 60, defense: 60, speed: 85, sp_attack: 85, sp_defense: 85 },
+		types: [T::Ghost, T::Ghost],
+		abilities: [A::Levitate, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Fast,
+		catch_rate: 45,
+		base_friendship: 35,
+		egg_groups: [G::Amorphous, G::Amorphous],
+		hatch_cycles: 25,
+	},
+	// 201: Unown
+	SpeciesInfo {
+		base_stats: Stats { hp: 48, attack: 72, defense: 48, speed: 48, sp_attack: 72, sp_defense: 48 },
+		types: [T::Psychic, T::Psychic],
+		abilities: [A::Levitate, A::None],
+		gender_ratio: GenderRatio::GENDERLESS,
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 225,
+		base_friendship: 70,
+		egg_groups: [G::Undiscovered, G::Undiscovered],
+		hatch_cycles: 40,
+	},
+	// 202: Wobbuffet
+	SpeciesInfo {
+		base_stats: Stats { hp: 190, attack: 33, defense: 58, speed: 33, sp_attack: 33, sp_defense: 58 },
+		types: [T::Psychic, T::Psychic],
+		abilities: [A::ShadowTag, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Amorphous, G::Amorphous],
+		hatch_cycles: 20,
+	},
+	// 203: Girafarig
+	SpeciesInfo {
+		base_stats: Stats { hp: 70, attack: 80, defense: 65, speed: 85, sp_attack: 90, sp_defense: 65 },
+		types: [T::Normal, T::Psychic],
+		abilities: [A::InnerFocus, A::EarlyBird],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 60,
+		base_friendship: 70,
+		egg_groups: [G::Field, G::Field],
+		hatch_cycles: 20,
+	},
+	// 204: Pineco
+	SpeciesInfo {
+		base_stats: Stats { hp: 50, attack: 65, defense: 90, speed: 15, sp_attack: 35, sp_defense: 35 },
+		types: [T::Bug, T::Bug],
+		abilities: [A::Sturdy, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 190,
+		base_friendship: 70,
+		egg_groups: [G::Bug, G::Bug],
+		hatch_cycles: 20,
+	},
+	// 205: Forretress
+	SpeciesInfo {
+		base_stats: Stats { hp: 75, attack: 90, defense: 140, speed: 40, sp_attack: 60, sp_defense: 60 },
+		types: [T::Bug, T::Steel],
+		abilities: [A::Sturdy, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 75,
+		base_friendship: 70,
+		egg_groups: [G::Bug, G::Bug],
+		hatch_cycles: 20,
+	},
+	// 206: Dunsparce
+	SpeciesInfo {
+		base_stats: Stats { hp: 100, attack: 70, defense: 70, speed: 45, sp_attack: 65, sp_defense: 65 },
+		types: [T::Normal, T::Normal],
+		abilities: [A::SereneGrace, A::RunAway],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 190,
+		base_friendship: 70,
+		egg_groups: [G::Field, G::Field],
+		hatch_cycles: 20,
+	},
+	// 207: Gligar
+	SpeciesInfo {
+		base_stats: Stats { hp: 65, attack: 75, defense: 105, speed: 85, sp_attack: 35, sp_defense: 65 },
+		types: [T::Ground, T::Flying],
+		abilities: [A::HyperCutter, A::SandVeil],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 60,
+		base_friendship: 70,
+		egg_groups: [G::Bug, G::Bug],
+		hatch_cycles: 20,
+	},
+	// 208: Steelix
+	SpeciesInfo {
+		base_stats: Stats { hp: 75, attack: 85, defense: 200, speed: 30, sp_attack: 55, sp_defense: 65 },
+		types: [T::Steel, T::Ground],
+		abilities: [A::RockHead, A::Sturdy],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 25,
+		base_friendship: 70,
+		egg_groups: [G::Mineral, G::Mineral],
+		hatch_cycles: 25,
+	},
+	// 209: Snubbull
+	SpeciesInfo {
+		base_stats: Stats { hp: 60, attack: 80, defense: 50, speed: 30, sp_attack: 40, sp_defense: 40 },
+		types: [T::Normal, T::Normal],
+		abilities: [A::Intimidate, A::RunAway],
+		gender_ratio: GenderRatio(191),
+		growth_rate: GrowthRate::Fast,
+		catch_rate: 190,
+		base_friendship: 70,
+		egg_groups: [G::Field, G::Fairy],
+		hatch_cycles: 20,
+	},
+	// 210: Granbull
+	SpeciesInfo {
+		base_stats: Stats { hp: 90, attack: 120, defense: 75, speed: 45, sp_attack: 60, sp_defense: 60 },
+		types: [T::Normal, T::Normal],
+		abilities: [A::Intimidate, A::QuickFeet],
+		gender_ratio: GenderRatio(191),
+		growth_rate: GrowthRate::Fast,
+		catch_rate: 75,
+		base_friendship: 70,
+		egg_groups: [G::Field, G::Fairy],
+		hatch_cycles: 20,
+	},
+	// 211: Qwilfish
+	SpeciesInfo {
+		base_stats: Stats { hp: 65, attack: 95, defense: 75, speed: 85, sp_attack: 55, sp_defense: 55 },
+		types: [T::Water, T::Poison],
+		abilities: [A::PoisonPoint, A::SwiftSwim],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Water2, G::Water2],
+		hatch_cycles: 20,
+	},
+	// 212: Scizor
+	SpeciesInfo {
+		base_stats: Stats { hp: 70, attack: 130, defense: 100, speed: 65, sp_attack: 55, sp_defense: 80 },
+		types: [T::Bug, T::Steel],
+		abilities: [A::Swarm, A::Technician],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 25,
+		base_friendship: 70,
+		egg_groups: [G::Bug, G::Bug],
+		hatch_cycles: 25,
+	},
+	// 213: Shuckle
+	SpeciesInfo {
+		base_stats: Stats { hp: 20, attack: 10, defense: 230, speed: 5, sp_attack: 10, sp_defense: 230 },
+		types: [T::Bug, T::Rock],
+		abilities: [A::Sturdy, A::Gluttony],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 190,
+		base_friendship: 70,
+		egg_groups: [G::Bug, G::Bug],
+		hatch_cycles: 20,
+	},
+	// 214: Heracross
+	SpeciesInfo {
+		base_stats: Stats { hp: 80, attack: 125, defense: 75, speed: 85, sp_attack: 40, sp_defense: 95 },
+		types: [T::Bug, T::Fighting],
+		abilities: [A::Swarm, A::Guts],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Bug, G::Bug],
+		hatch_cycles: 25,
+	},
+	// 215: Sneasel
+	SpeciesInfo {
+		base_stats: Stats { hp: 55, attack: 95, defense: 55, speed: 115, sp_attack: 35, sp_defense: 75 },
+		types: [T::Dark, T::Ice],
+		abilities: [A::InnerFocus, A::KeenEye],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 60,
+		base_friendship: 35,
+		egg_groups: [G::Field, G::Field],
+		hatch_cycles: 20,
+	},
+	// 216: Teddiursa
+	SpeciesInfo {
+		base_stats: Stats { hp: 60, attack: 80, defense: 50, speed: 40, sp_attack: 50, sp_defense: 50 },
+		types: [T::Normal, T::Normal],
+		abilities: [A::Pickup, A::QuickFeet],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 120,
+		base_friendship: 70,
+		egg_groups: [G::Field, G::Field],
+		hatch_cycles: 20,
+	},
+	// 217: Ursaring
+	SpeciesInfo {
+		base_stats: Stats { hp: 90, attack: 130, defense: 75, speed: 55, sp_attack: 75, sp_defense: 75 },
+		types: [T::Normal, T::Normal],
+		abilities: [A::Guts, A::QuickFeet],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 60,
+		base_friendship: 70,
+		egg_groups: [G::Field, G::Field],
+		hatch_cycles: 20,
+	},
+	// 218: Slugma
+	SpeciesInfo {
+		base_stats: Stats { hp: 40, attack: 40, defense: 40, speed: 20, sp_attack: 70, sp_defense: 40 },
+		types: [T::Fire, T::Fire],
+		abilities: [A::MagmaArmor, A::FlameBody],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 190,
+		base_friendship: 70,
+		egg_groups: [G::Amorphous, G::Amorphous],
+		hatch_cycles: 20,
+	},
+	// 219: Magcargo
+	SpeciesInfo {
+		base_stats: Stats { hp: 50, attack: 50, defense: 120, speed: 30, sp_attack: 80, sp_defense: 80 },
+		types: [T::Fire, T::Rock],
+		abilities: [A::MagmaArmor, A::FlameBody],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 75,
+		base_friendship: 70,
+		egg_groups: [G::Amorphous, G::Amorphous],
+		hatch_cycles: 20,
+	},
+	// 220: Swinub
+	SpeciesInfo {
+		base_stats: Stats { hp: 50, attack: 50, defense: 40, speed: 50, sp_attack: 30, sp_defense: 30 },
+		types: [T::Ice, T::Ground],
+		abilities: [A::Oblivious, A::SnowCloak],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 225,
+		base_friendship: 70,
+		egg_groups: [G::Field, G::Field],
+		hatch_cycles: 20,
+	},
+	// 221: Piloswine
+	SpeciesInfo {
+		base_stats: Stats { hp: 100, attack: 100, defense: 80, speed: 50, sp_attack: 60, sp_defense: 60 },
+		types: [T::Ice, T::Ground],
+		abilities: [A::Oblivious, A::SnowCloak],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 75,
+		base_friendship: 70,
+		egg_groups: [G::Field, G::Field],
+		hatch_cycles: 20,
+	},
+	// 222: Corsola
+	SpeciesInfo {
+		base_stats: Stats { hp: 55, attack: 55, defense: 85, speed: 35, sp_attack: 65, sp_defense: 85 },
+		types: [T::Water, T::Rock],
+		abilities: [A::Hustle, A::NaturalCure],
+		gender_ratio: GenderRatio(191),
+		growth_rate: GrowthRate::Fast,
+		catch_rate: 60,
+		base_friendship: 70,
+		egg_groups: [G::Water1, G::Water3],
+		hatch_cycles: 20,
+	},
+	// 223: Remoraid
+	SpeciesInfo {
+		base_stats: Stats { hp: 35, attack: 65, defense: 35, speed: 65, sp_attack: 65, sp_defense: 35 },
+		types: [T::Water, T::Water],
+		abilities: [A::Hustle, A::Sniper],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 190,
+		base_friendship: 70,
+		egg_groups: [G::Water1, G::Water2],
+		hatch_cycles: 20,
+	},
+	// 224: Octillery
+	SpeciesInfo {
+		base_stats: Stats { hp: 75, attack: 105, defense: 75, speed: 45, sp_attack: 105, sp_defense: 75 },
+		types: [T::Water, T::Water],
+		abilities: [A::SuctionCups, A::Sniper],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 75,
+		base_friendship: 70,
+		egg_groups: [G::Water1, G::Water2],
+		hatch_cycles: 20,
+	},
+	// 225: Delibird
+	SpeciesInfo {
+		base_stats: Stats { hp: 45, attack: 55, defense: 45, speed: 75, sp_attack: 65, sp_defense: 45 },
+		types: [T::Ice, T::Flying],
+		abilities: [A::VitalSpirit, A::Hustle],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Fast,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Water1, G::Field],
+		hatch_cycles: 20,
+	},
+	// 226: Mantine
+	SpeciesInfo {
+		base_stats: Stats { hp: 65, attack: 40, defense: 70, speed: 70, sp_attack: 80, sp_defense: 140 },
+		types: [T::Water, T::Flying],
+		abilities: [A::SwiftSwim, A::WaterAbsorb],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 25,
+		base_friendship: 70,
+		egg_groups: [G::Water1, G::Water1],
+		hatch_cycles: 25,
+	},
+	// 227: Skarmory
+	SpeciesInfo {
+		base_stats: Stats { hp: 65, attack: 80, defense: 140, speed: 70, sp_attack: 40, sp_defense: 70 },
+		types: [T::Steel, T::Flying],
+		abilities: [A::KeenEye, A::Sturdy],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 25,
+		base_friendship: 70,
+		egg_groups: [G::Flying, G::Flying],
+		hatch_cycles: 25,
+	},
+	// 228: Houndour
+	SpeciesInfo {
+		base_stats: Stats { hp: 45, attack: 60, defense: 30, speed: 65, sp_attack: 80, sp_defense: 50 },
+		types: [T::Dark, T::Fire],
+		abilities: [A::EarlyBird, A::FlashFire],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 120,
+		base_friendship: 35,
+		egg_groups: [G::Field, G::Field],
+		hatch_cycles: 20,
+	},
+	// 229: Houndoom
+	SpeciesInfo {
+		base_stats: Stats { hp: 75, attack: 90, defense: 50, speed: 95, sp_attack: 110, sp_defense: 80 },
+		types: [T::Dark, T::Fire],
+		abilities: [A::EarlyBird, A::FlashFire],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 45,
+		base_friendship: 35,
+		egg_groups: [G::Field, G::Field],
+		hatch_cycles: 20,
+	},
+	// 230: Kingdra
+	SpeciesInfo {
+		base_stats: Stats { hp: 75, attack: 95, defense: 95, speed: 85, sp_attack: 95, sp_defense: 95 },
+		types: [T::Water, T::Dragon],
+		abilities: [A::SwiftSwim, A::Sniper],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Water1, G::Dragon],
+		hatch_cycles: 20,
+	},
+	// 231: Phanpy
+	SpeciesInfo {
+		base_stats: Stats { hp: 90, attack: 60, defense: 60, speed: 40, sp_attack: 40, sp_defense: 40 },
+		types: [T::Ground, T::Ground],
+		abilities: [A::Pickup, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 120,
+		base_friendship: 70,
+		egg_groups: [G::Field, G::Field],
+		hatch_cycles: 20,
+	},
+	// 232: Donphan
+	SpeciesInfo {
+		base_stats: Stats { hp: 90, attack: 120, defense: 120, speed: 50, sp_attack: 60, sp_defense: 60 },
+		types: [T::Ground, T::Ground],
+		abilities: [A::Sturdy, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 60,
+		base_friendship: 70,
+		egg_groups: [G::Field, G::Field],
+		hatch_cycles: 20,
+	},
+	// 233: Porygon2
+	SpeciesInfo {
+		base_stats: Stats { hp: 85, attack: 80, defense: 90, speed: 60, sp_attack: 105, sp_defense: 95 },
+		types: [T::Normal, T::Normal],
+		abilities: [A::Trace, A::Download],
+		gender_ratio: GenderRatio::GENDERLESS,
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Mineral, G::Mineral],
+		hatch_cycles: 20,
+	},
+	// 234: Stantler
+	SpeciesInfo {
+		base_stats: Stats { hp: 73, attack: 95, defense: 62, speed: 85, sp_attack: 85, sp_defense: 65 },
+		types: [T::Normal, T::Normal],
+		abilities: [A::Intimidate, A::Frisk],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Field, G::Field],
+		hatch_cycles: 20,
+	},
+	// 235: Smeargle
+	SpeciesInfo {
+		base_stats: Stats { hp: 55, attack: 20, defense: 35, speed: 75, sp_attack: 20, sp_defense: 45 },
+		types: [T::Normal, T::Normal],
+		abilities: [A::OwnTempo, A::Technician],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Fast,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Field, G::Field],
+		hatch_cycles: 20,
+	},
+	// 236: Tyrogue
+	SpeciesInfo {
+		base_stats: Stats { hp: 35, attack: 35, defense: 35, speed: 35, sp_attack: 35, sp_defense: 35 },
+		types: [T::Fighting, T::Fighting],
+		abilities: [A::Guts, A::Steadfast],
+		gender_ratio: GenderRatio::MALE_ONLY,
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 75,
+		base_friendship: 70,
+		egg_groups: [G::Undiscovered, G::Undiscovered],
+		hatch_cycles: 25,
+	},
+	// 237: Hitmontop
+	SpeciesInfo {
+		base_stats: Stats { hp: 50, attack: 95, defense: 95, speed: 70, sp_attack: 35, sp_defense: 110 },
+		types: [T::Fighting, T::Fighting],
+		abilities: [A::Intimidate, A::Technician],
+		gender_ratio: GenderRatio::MALE_ONLY,
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::HumanLike, G::HumanLike],
+		hatch_cycles: 25,
+	},
+	// 238: Smoochum
+	SpeciesInfo {
+		base_stats: Stats { hp: 45, attack: 30, defense: 15, speed: 65, sp_attack: 85, sp_defense: 65 },
+		types: [T::Ice, T::Psychic],
+		abilities: [A::Oblivious, A::Forewarn],
+		gender_ratio: GenderRatio::FEMALE_ONLY,
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Undiscovered, G::Undiscovered],
+		hatch_cycles: 25,
+	},
+	// 239: Elekid
+	SpeciesInfo {
+		base_stats: Stats { hp: 45, attack: 63, defense: 37, speed: 95, sp_attack: 65, sp_defense: 55 },
+		types: [T::Electric, T::Electric],
+		abilities: [A::Static, A::None],
+		gender_ratio: GenderRatio(63),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Undiscovered, G::Undiscovered],
+		hatch_cycles: 25,
+	},
+	// 240: Magby
+	SpeciesInfo {
+		base_stats: Stats { hp: 45, attack: 75, defense: 37, speed: 83, sp_attack: 70, sp_defense: 55 },
+		types: [T::Fire, T::Fire],
+		abilities: [A::FlameBody, A::None],
+		gender_ratio: GenderRatio(63),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Undiscovered, G::Undiscovered],
+		hatch_cycles: 25,
+	},
+	// 241: Miltank
+	SpeciesInfo {
+		base_stats: Stats { hp: 95, attack: 80, defense: 105, speed: 100, sp_attack: 40, sp_defense: 70 },
+		types: [T::Normal, T::Normal],
+		abilities: [A::ThickFat, A::Scrappy],
+		gender_ratio: GenderRatio::FEMALE_ONLY,
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Field, G::Field],
+		hatch_cycles: 20,
+	},
+	// 242: Blissey
+	SpeciesInfo {
+		base_stats: Stats { hp: 255, attack: 10, defense: 10, speed: 55, sp_attack: 75, sp_defense: 135 },
+		types: [T::Normal, T::Normal],
+		abilities: [A::NaturalCure, A::SereneGrace],
+		gender_ratio: GenderRatio::FEMALE_ONLY,
+		growth_rate: GrowthRate::Fast,
+		catch_rate: 30,
+		base_friendship: 140,
+		egg_groups: [G::Fairy, G::Fairy],
+		hatch_cycles: 40,
+	},
+	// 243: Raikou
+	SpeciesInfo {
+		base_stats: Stats { hp: 90, attack: 85, defense: 75, speed: 115, sp_attack: 115, sp_defense: 100 },
+		types: [T::Electric, T::Electric],
+		abilities: [A::Pressure, A::None],
+		gender_ratio: GenderRatio::GENDERLESS,
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 3,
+		base_friendship: 35,
+		egg_groups: [G::Undiscovered, G::Undiscovered],
+		hatch_cycles: 80,
+	},
+	// 244: Entei
+	SpeciesInfo {
+		base_stats: Stats { hp: 115, attack: 115, defense: 85, speed: 100, sp_attack: 90, sp_defense: 75 },
+		types: [T::Fire, T::Fire],
+		abilities: [A::Pressure, A::None],
+		gender_ratio: GenderRatio::GENDERLESS,
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 3,
+		base_friendship: 35,
+		egg_groups: [G::Undiscovered, G::Undiscovered],
+		hatch_cycles: 80,
+	},
+	// 245: Suicune
+	SpeciesInfo {
+		base_stats: Stats { hp: 100, attack: 75, defense: 115, speed: 85, sp_attack: 90, sp_defense: 115 },
+		types: [T::Water, T::Water],
+		abilities: [A::Pressure, A::None],
+		gender_ratio: GenderRatio::GENDERLESS,
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 3,
+		base_friendship: 35,
+		egg_groups: [G::Undiscovered, G::Undiscovered],
+		hatch_cycles: 80,
+	},
+	// 246: Larvitar
+	SpeciesInfo {
+		base_stats: Stats { hp: 50, attack: 64, defense: 50, speed: 41, sp_attack: 45, sp_defense: 50 },
+		types: [T::Rock, T::Ground],
+		abilities: [A::Guts, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 45,
+		base_friendship: 35,
+		egg_groups: [G::Monster, G::Monster],
+		hatch_cycles: 40,
+	},
+	// 247: Pupitar
+	SpeciesInfo {
+		base_stats: Stats { hp: 70, attack: 84, defense: 70, speed: 51, sp_attack: 65, sp_defense: 70 },
+		types: [T::Rock, T::Ground],
+		abilities: [A::ShedSkin, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 45,
+		base_friendship: 35,
+		egg_groups: [G::Monster, G::Monster],
+		hatch_cycles: 40,
+	},
+	// 248: Tyranitar
+	SpeciesInfo {
+		base_stats: Stats { hp: 100, attack: 134, defense: 110, speed: 61, sp_attack: 95, sp_defense: 100 },
+		types: [T::Rock, T::Dark],
+		abilities: [A::SandStream, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 45,
+		base_friendship: 35,
+		egg_groups: [G::Monster, G::Monster],
+		hatch_cycles: 40,
+	},
+	// 249: Lugia
+	SpeciesInfo {
+		base_stats: Stats { hp: 106, attack: 90, defense: 130, speed: 110, sp_attack: 90, sp_defense: 154 },
+		types: [T::Psychic, T::Flying],
+		abilities: [A::Pressure, A::None],
+		gender_ratio: GenderRatio::GENDERLESS,
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 3,
+		base_friendship: 0,
+		egg_groups: [G::Undiscovered, G::Undiscovered],
+		hatch_cycles: 120,
+	},
+	// 250: Hooh
+	SpeciesInfo {
+		base_stats: Stats { hp: 106, attack: 130, defense: 90, speed: 90, sp_attack: 110, sp_defense: 154 },
+		types: [T::Fire, T::Flying],
+		abilities: [A::Pressure, A::None],
+		gender_ratio: GenderRatio::GENDERLESS,
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 3,
+		base_friendship: 0,
+		egg_groups: [G::Undiscovered, G::Undiscovered],
+		hatch_cycles: 120,
+	},
+	// 251: Celebi
+	SpeciesInfo {
+		base_stats: Stats { hp: 100, attack: 100, defense: 100, speed: 100, sp_attack: 100, sp_defense: 100 },
+		types: [T::Psychic, T::Grass],
+		abilities: [A::NaturalCure, A::None],
+		gender_ratio: GenderRatio::GENDERLESS,
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 45,
+		base_friendship: 100,
+		egg_groups: [G::Undiscovered, G::Undiscovered],
+		hatch_cycles: 120,
+	},
+	// 252: Treecko
+	SpeciesInfo {
+		base_stats: Stats { hp: 40, attack: 45, defense: 35, speed: 70, sp_attack: 65, sp_defense: 55 },
+		types: [T::Grass, T::Grass],
+		abilities: [A::Overgrow, A::None],
+		gender_ratio: GenderRatio(31),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Monster, G::Dragon],
+		hatch_cycles: 20,
+	},
+	// 253: Grovyle
+	SpeciesInfo {
+		base_stats: Stats { hp: 50, attack: 65, defense: 45, speed: 95, sp_attack: 85, sp_defense: 65 },
+		types: [T::Grass, T::Grass],
+		abilities: [A::Overgrow, A::None],
+		gender_ratio: GenderRatio(31),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Monster, G::Dragon],
+		hatch_cycles: 20,
+	},
+	// 254: Sceptile
+	SpeciesInfo {
+		base_stats: Stats { hp: 70, attack: 85, defense: 65, speed: 120, sp_attack: 105, sp_defense: 85 },
+		types: [T::Grass, T::Grass],
+		abilities: [A::Overgrow, A::None],
+		gender_ratio: GenderRatio(31),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Monster, G::Dragon],
+		hatch_cycles: 20,
+	},
+	// 255: Torchic
+	SpeciesInfo {
+		base_stats: Stats { hp: 45, attack: 60, defense: 40, speed: 45, sp_attack: 70, sp_defense: 50 },
+		types: [T::Fire, T::Fire],
+		abilities: [A::Blaze, A::None],
+		gender_ratio: GenderRatio(31),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Field, G::Field],
+		hatch_cycles: 20,
+	},
+	// 256: Combusken
+	SpeciesInfo {
+		base_stats: Stats { hp: 60, attack: 85, defense: 60, speed: 55, sp_attack: 85, sp_defense: 60 },
+		types: [T::Fire, T::Fighting],
+		abilities: [A::Blaze, A::None],
+		gender_ratio: GenderRatio(31),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Field, G::Field],
+		hatch_cycles: 20,
+	},
+	// 257: Blaziken
+	SpeciesInfo {
+		base_stats: Stats { hp: 80, attack: 120, defense: 70, speed: 80, sp_attack: 110, sp_defense: 70 },
+		types: [T::Fire, T::Fighting],
+		abilities: [A::Blaze, A::None],
+		gender_ratio: GenderRatio(31),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Field, G::Field],
+		hatch_cycles: 20,
+	},
+	// 258: Mudkip
+	SpeciesInfo {
+		base_stats: Stats { hp: 50, attack: 70, defense: 50, speed: 40, sp_attack: 50, sp_defense: 50 },
+		types: [T::Water, T::Water],
+		abilities: [A::Torrent, A::None],
+		gender_ratio: GenderRatio(31),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Monster, G::Water1],
+		hatch_cycles: 20,
+	},
+	// 259: Marshtomp
+	SpeciesInfo {
+		base_stats: Stats { hp: 70, attack: 85, defense: 70, speed: 50, sp_attack: 60, sp_defense: 70 },
+		types: [T::Water, T::Ground],
+		abilities: [A::Torrent, A::None],
+		gender_ratio: GenderRatio(31),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Monster, G::Water1],
+		hatch_cycles: 20,
+	},
+	// 260: Swampert
+	SpeciesInfo {
+		base_stats: Stats { hp: 100, attack: 110, defense: 90, speed: 60, sp_attack: 85, sp_defense: 90 },
+		types: [T::Water, T::Ground],
+		abilities: [A::Torrent, A::None],
+		gender_ratio: GenderRatio(31),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Monster, G::Water1],
+		hatch_cycles: 20,
+	},
+	// 261: Poochyena
+	SpeciesInfo {
+		base_stats: Stats { hp: 35, attack: 55, defense: 35, speed: 35, sp_attack: 30, sp_defense: 30 },
+		types: [T::Dark, T::Dark],
+		abilities: [A::RunAway, A::QuickFeet],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 255,
+		base_friendship: 70,
+		egg_groups: [G::Field, G::Field],
+		hatch_cycles: 15,
+	},
+	// 262: Mightyena
+	SpeciesInfo {
+		base_stats: Stats { hp: 70, attack: 90, defense: 70, speed: 70, sp_attack: 60, sp_defense: 60 },
+		types: [T::Dark, T::Dark],
+		abilities: [A::Intimidate, A::QuickFeet],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 127,
+		base_friendship: 70,
+		egg_groups: [G::Field, G::Field],
+		hatch_cycles: 15,
+	},
+	// 263: Zigzagoon
+	SpeciesInfo {
+		base_stats: Stats { hp: 38, attack: 30, defense: 41, speed: 60, sp_attack: 30, sp_defense: 41 },
+		types: [T::Normal, T::Normal],
+		abilities: [A::Pickup, A::Gluttony],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 255,
+		base_friendship: 70,
+		egg_groups: [G::Field, G::Field],
+		hatch_cycles: 15,
+	},
+	// 264: Linoone
+	SpeciesInfo {
+		base_stats: Stats { hp: 78, attack: 70, defense: 61, speed: 100, sp_attack: 50, sp_defense: 61 },
+		types: [T::Normal, T::Normal],
+		abilities: [A::Pickup, A::Gluttony],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 90,
+		base_friendship: 70,
+		egg_groups: [G::Field, G::Field],
+		hatch_cycles: 15,
+	},
+	// 265: Wurmple
+	SpeciesInfo {
+		base_stats: Stats { hp: 45, attack: 45, defense: 35, speed: 20, sp_attack: 20, sp_defense: 30 },
+		types: [T::Bug, T::Bug],
+		abilities: [A::ShieldDust, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 255,
+		base_friendship: 70,
+		egg_groups: [G::Bug, G::Bug],
+		hatch_cycles: 15,
+	},
+	// 266: Silcoon
+	SpeciesInfo {
+		base_stats: Stats { hp: 50, attack: 35, defense: 55, speed: 15, sp_attack: 25, sp_defense: 25 },
+		types: [T::Bug, T::Bug],
+		abilities: [A::ShedSkin, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 120,
+		base_friendship: 70,
+		egg_groups: [G::Bug, G::Bug],
+		hatch_cycles: 15,
+	},
+	// 267: Beautifly
+	SpeciesInfo {
+		base_stats: Stats { hp: 60, attack: 70, defense: 50, speed: 65, sp_attack: 90, sp_defense: 50 },
+		types: [T::Bug, T::Flying],
+		abilities: [A::Swarm, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Bug, G::Bug],
+		hatch_cycles: 15,
+	},
+	// 268: Cascoon
+	SpeciesInfo {
+		base_stats: Stats { hp: 50, attack: 35, defense: 55, speed: 15, sp_attack: 25, sp_defense: 25 },
+		types: [T::Bug, T::Bug],
+		abilities: [A::ShedSkin, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 120,
+		base_friendship: 70,
+		egg_groups: [G::Bug, G::Bug],
+		hatch_cycles: 15,
+	},
+	// 269: Dustox
+	SpeciesInfo {
+		base_stats: Stats { hp: 60, attack: 50, defense: 70, speed: 65, sp_attack: 50, sp_defense: 90 },
+		types: [T::Bug, T::Poison],
+		abilities: [A::ShieldDust, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Bug, G::Bug],
+		hatch_cycles: 15,
+	},
+	// 270: Lotad
+	SpeciesInfo {
+		base_stats: Stats { hp: 40, attack: 30, defense: 30, speed: 30, sp_attack: 40, sp_defense: 50 },
+		types: [T::Water, T::Grass],
+		abilities: [A::SwiftSwim, A::RainDish],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 255,
+		base_friendship: 70,
+		egg_groups: [G::Water1, G::Grass],
+		hatch_cycles: 15,
+	},
+	// 271: Lombre
+	SpeciesInfo {
+		base_stats: Stats { hp: 60, attack: 50, defense: 50, speed: 50, sp_attack: 60, sp_defense: 70 },
+		types: [T::Water, T::Grass],
+		abilities: [A::SwiftSwim, A::RainDish],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 120,
+		base_friendship: 70,
+		egg_groups: [G::Water1, G::Grass],
+		hatch_cycles: 15,
+	},
+	// 272: Ludicolo
+	SpeciesInfo {
+		base_stats: Stats { hp: 80, attack: 70, defense: 70, speed: 70, sp_attack: 90, sp_defense: 100 },
+		types: [T::Water, T::Grass],
+		abilities: [A::SwiftSwim, A::RainDish],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Water1, G::Grass],
+		hatch_cycles: 15,
+	},
+	// 273: Seedot
+	SpeciesInfo {
+		base_stats: Stats { hp: 40, attack: 40, defense: 50, speed: 30, sp_attack: 30, sp_defense: 30 },
+		types: [T::Grass, T::Grass],
+		abilities: [A::Chlorophyll, A::EarlyBird],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 255,
+		base_friendship: 70,
+		egg_groups: [G::Field, G::Grass],
+		hatch_cycles: 15,
+	},
+	// 274: Nuzleaf
+	SpeciesInfo {
+		base_stats: Stats { hp: 70, attack: 70, defense: 40, speed: 60, sp_attack: 60, sp_defense: 40 },
+		types: [T::Grass, T::Dark],
+		abilities: [A::Chlorophyll, A::EarlyBird],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 120,
+		base_friendship: 70,
+		egg_groups: [G::Field, G::Grass],
+		hatch_cycles: 15,
+	},
+	// 275: Shiftry
+	SpeciesInfo {
+		base_stats: Stats { hp: 90, attack: 100, defense: 60, speed: 80, sp_attack: 90, sp_defense: 60 },
+		types: [T::Grass, T::Dark],
+		abilities: [A::Chlorophyll, A::EarlyBird],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Field, G::Grass],
+		hatch_cycles: 15,
+	},
+	// 276: Taillow
+	SpeciesInfo {
+		base_stats: Stats { hp: 40, attack: 55, defense: 30, speed: 85, sp_attack: 30, sp_defense: 30 },
+		types: [T::Normal, T::Flying],
+		abilities: [A::Guts, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 200,
+		base_friendship: 70,
+		egg_groups: [G::Flying, G::Flying],
+		hatch_cycles: 15,
+	},
+	// 277: Swellow
+	SpeciesInfo {
+		base_stats: Stats { hp: 60, attack: 85, defense: 60, speed: 125, sp_attack: 50, sp_defense: 50 },
+		types: [T::Normal, T::Flying],
+		abilities: [A::Guts, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Flying, G::Flying],
+		hatch_cycles: 15,
+	},
+	// 278: Wingull
+	SpeciesInfo {
+		base_stats: Stats { hp: 40, attack: 30, defense: 30, speed: 85, sp_attack: 55, sp_defense: 30 },
+		types: [T::Water, T::Flying],
+		abilities: [A::KeenEye, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 190,
+		base_friendship: 70,
+		egg_groups: [G::Water1, G::Flying],
+		hatch_cycles: 20,
+	},
+	// 279: Pelipper
+	SpeciesInfo {
+		base_stats: Stats { hp: 60, attack: 50, defense: 100, speed: 65, sp_attack: 85, sp_defense: 70 },
+		types: [T::Water, T::Flying],
+		abilities: [A::KeenEye, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Water1, G::Flying],
+		hatch_cycles: 20,
+	},
+	// 280: Ralts
+	SpeciesInfo {
+		base_stats: Stats { hp: 28, attack: 25, defense: 25, speed: 40, sp_attack: 45, sp_defense: 35 },
+		types: [T::Psychic, T::Psychic],
+		abilities: [A::Synchronize, A::Trace],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 235,
+		base_friendship: 35,
+		egg_groups: [G::Amorphous, G::Amorphous],
+		hatch_cycles: 20,
+	},
+	// 281: Kirlia
+	SpeciesInfo {
+		base_stats: Stats { hp: 38, attack: 35, defense: 35, speed: 50, sp_attack: 65, sp_defense: 55 },
+		types: [T::Psychic, T::Psychic],
+		abilities: [A::Synchronize, A::Trace],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 120,
+		base_friendship: 35,
+		egg_groups: [G::Amorphous, G::Amorphous],
+		hatch_cycles: 20,
+	},
+	// 282: Gardevoir
+	SpeciesInfo {
+		base_stats: Stats { hp: 68, attack: 65, defense: 65, speed: 80, sp_attack: 125, sp_defense: 115 },
+		types: [T::Psychic, T::Psychic],
+		abilities: [A::Synchronize, A::Trace],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 45,
+		base_friendship: 35,
+		egg_groups: [G::Amorphous, G::Amorphous],
+		hatch_cycles: 20,
+	},
+	// 283: Surskit
+	SpeciesInfo {
+		base_stats: Stats { hp: 40, attack: 30, defense: 32, speed: 65, sp_attack: 50, sp_defense: 52 },
+		types: [T::Bug, T::Water],
+		abilities: [A::SwiftSwim, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 200,
+		base_friendship: 70,
+		egg_groups: [G::Water1, G::Bug],
+		hatch_cycles: 15,
+	},
+	// 284: Masquerain
+	SpeciesInfo {
+		base_stats: Stats { hp: 70, attack: 60, defense: 62, speed: 60, sp_attack: 80, sp_defense: 82 },
+		types: [T::Bug, T::Flying],
+		abilities: [A::Intimidate, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 75,
+		base_friendship: 70,
+		egg_groups: [G::Water1, G::Bug],
+		hatch_cycles: 15,
+	},
+	// 285: Shroomish
+	SpeciesInfo {
+		base_stats: Stats { hp: 60, attack: 40, defense: 60, speed: 35, sp_attack: 40, sp_defense: 60 },
+		types: [T::Grass, T::Grass],
+		abilities: [A::EffectSpore, A::PoisonHeal],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Fluctuating,
+		catch_rate: 255,
+		base_friendship: 70,
+		egg_groups: [G::Fairy, G::Grass],
+		hatch_cycles: 15,
+	},
+	// 286: Breloom
+	SpeciesInfo {
+		base_stats: Stats { hp: 60, attack: 130, defense: 80, speed: 70, sp_attack: 60, sp_defense: 60 },
+		types: [T::Grass, T::Fighting],
+		abilities: [A::EffectSpore, A::PoisonHeal],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Fluctuating,
+		catch_rate: 90,
+		base_friendship: 70,
+		egg_groups: [G::Fairy, G::Grass],
+		hatch_cycles: 15,
+	},
+	// 287: Slakoth
+	SpeciesInfo {
+		base_stats: Stats { hp: 60, attack: 60, defense: 60, speed: 30, sp_attack: 35, sp_defense: 35 },
+		types: [T::Normal, T::Normal],
+		abilities: [A::Truant, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 255,
+		base_friendship: 70,
+		egg_groups: [G::Field, G::Field],
+		hatch_cycles: 15,
+	},
+	// 288: Vigoroth
+	SpeciesInfo {
+		base_stats: Stats { hp: 80, attack: 80, defense: 80, speed: 90, sp_attack: 55, sp_defense: 55 },
+		types: [T::Normal, T::Normal],
+		abilities: [A::VitalSpirit, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 120,
+		base_friendship: 70,
+		egg_groups: [G::Field, G::Field],
+		hatch_cycles: 15,
+	},
+	// 289: Slaking
+	SpeciesInfo {
+		base_stats: Stats { hp: 150, attack: 160, defense: 100, speed: 100, sp_attack: 95, sp_defense: 65 },
+		types: [T::Normal, T::Normal],
+		abilities: [A::Truant, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Field, G::Field],
+		hatch_cycles: 15,
+	},
+	// 290: Nincada
+	SpeciesInfo {
+		base_stats: Stats { hp: 31, attack: 45, defense: 90, speed: 40, sp_attack: 30, sp_defense: 30 },
+		types: [T::Bug, T::Ground],
+		abilities: [A::CompoundEyes, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Erratic,
+		catch_rate: 255,
+		base_friendship: 70,
+		egg_groups: [G::Bug, G::Bug],
+		hatch_cycles: 15,
+	},
+	// 291: Ninjask
+	SpeciesInfo {
+		base_stats: Stats { hp: 61, attack: 90, defense: 45, speed: 160, sp_attack: 50, sp_defense: 50 },
+		types: [T::Bug, T::Flying],
+		abilities: [A::SpeedBoost, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Erratic,
+		catch_rate: 120,
+		base_friendship: 70,
+		egg_groups: [G::Bug, G::Bug],
+		hatch_cycles: 15,
+	},
+	// 292: Shedinja
+	SpeciesInfo {
+		base_stats: Stats { hp: 1, attack: 90, defense: 45, speed: 40, sp_attack: 30, sp_defense: 30 },
+		types: [T::Bug, T::Ghost],
+		abilities: [A::WonderGuard, A::None],
+		gender_ratio: GenderRatio::GENDERLESS,
+		growth_rate: GrowthRate::Erratic,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Mineral, G::Mineral],
+		hatch_cycles: 15,
+	},
+	// 293: Whismur
+	SpeciesInfo {
+		base_stats: Stats { hp: 64, attack: 51, defense: 23, speed: 28, sp_attack: 51, sp_defense: 23 },
+		types: [T::Normal, T::Normal],
+		abilities: [A::Soundproof, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 190,
+		base_friendship: 70,
+		egg_groups: [G::Monster, G::Field],
+		hatch_cycles: 20,
+	},
+	// 294: Loudred
+	SpeciesInfo {
+		base_stats: Stats { hp: 84, attack: 71, defense: 43, speed: 48, sp_attack: 71, sp_defense: 43 },
+		types: [T::Normal, T::Normal],
+		abilities: [A::Soundproof, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 120,
+		base_friendship: 70,
+		egg_groups: [G::Monster, G::Field],
+		hatch_cycles: 20,
+	},
+	// 295: Exploud
+	SpeciesInfo {
+		base_stats: Stats { hp: 104, attack: 91, defense: 63, speed: 68, sp_attack: 91, sp_defense: 63 },
+		types: [T::Normal, T::Normal],
+		abilities: [A::Soundproof, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Monster, G::Field],
+		hatch_cycles: 20,
+	},
+	// 296: Makuhita
+	SpeciesInfo {
+		base_stats: Stats { hp: 72, attack: 60, defense: 30, speed: 25, sp_attack: 20, sp_defense: 30 },
+		types: [T::Fighting, T::Fighting],
+		abilities: [A::ThickFat, A::Guts],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Fluctuating,
+		catch_rate: 180,
+		base_friendship: 70,
+		egg_groups: [G::HumanLike, G::HumanLike],
+		hatch_cycles: 20,
+	},
+	// 297: Hariyama
+	SpeciesInfo {
+		base_stats: Stats { hp: 144, attack: 120, defense: 60, speed: 50, sp_attack: 40, sp_defense: 60 },
+		types: [T::Fighting, T::Fighting],
+		abilities: [A::ThickFat, A::Guts],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Fluctuating,
+		catch_rate: 200,
+		base_friendship: 70,
+		egg_groups: [G::HumanLike, G::HumanLike],
+		hatch_cycles: 20,
+	},
+	// 298: Azurill
+	SpeciesInfo {
+		base_stats: Stats { hp: 50, attack: 20, defense: 40, speed: 20, sp_attack: 20, sp_defense: 40 },
+		types: [T::Normal, T::Normal],
+		abilities: [A::ThickFat, A::HugePower],
+		gender_ratio: GenderRatio(191),
+		growth_rate: GrowthRate::Fast,
+		catch_rate: 150,
+		base_friendship: 70,
+		egg_groups: [G::Undiscovered, G::Undiscovered],
+		hatch_cycles: 10,
+	},
+	// 299: Nosepass
+	SpeciesInfo {
+		base_stats: Stats { hp: 30, attack: 45, defense: 135, speed: 30, sp_attack: 45, sp_defense: 90 },
+		types: [T::Rock, T::Rock],
+		abilities: [A::Sturdy, A::MagnetPull],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 255,
+		base_friendship: 70,
+		egg_groups: [G::Mineral, G::Mineral],
+		hatch_cycles: 20,
+	},
+	// 300: Skitty
+	SpeciesInfo {
+		base_stats: Stats { hp: 50, attack: 45, defense: 45, speed: 50, sp_attack: 35, sp_defense: 35 },
+		types: [T::Normal, T::Normal],
+		abilities: [A::CuteCharm, A::Normalize],
+		gender_ratio: GenderRatio(191),
+		growth_rate: GrowthRate::Fast,
+		catch_rate: 255,
+		base_friendship: 70,
+		egg_groups: [G::Field, G::Fairy],
+		hatch_cycles: 15,
+	},
+	// 301: Delcatty
+	SpeciesInfo {
+		base_stats: Stats { hp: 70, attack: 65, defense: 65, speed: 70, sp_attack: 55, sp_defense: 55 },
+		types: [T::Normal, T::Normal],
+		abilities: [A::CuteCharm, A::Normalize],
+		gender_ratio: GenderRatio(191),
+		growth_rate: GrowthRate::Fast,
+		catch_rate: 60,
+		base_friendship: 70,
+		egg_groups: [G::Field, G::Fairy],
+		hatch_cycles: 15,
+	},
+	// 302: Sableye
+	SpeciesInfo {
+		base_stats: Stats { hp: 50, attack: 75, defense: 75, speed: 50, sp_attack: 65, sp_defense: 65 },
+		types: [T::Dark, T::Ghost],
+		abilities: [A::KeenEye, A::Stall],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 45,
+		base_friendship: 35,
+		egg_groups: [G::HumanLike, G::HumanLike],
+		hatch_cycles: 25,
+	},
+	// 303: Mawile
+	SpeciesInfo {
+		base_stats: Stats { hp: 50, attack: 85, defense: 85, speed: 50, sp_attack: 55, sp_defense: 55 },
+		types: [T::Steel, T::Steel],
+		abilities: [A::HyperCutter, A::Intimidate],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Fast,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Field, G::Fairy],
+		hatch_cycles: 20,
+	},
+	// 304: Aron
+	SpeciesInfo {
+		base_stats: Stats { hp: 50, attack: 70, defense: 100, speed: 30, sp_attack: 40, sp_defense: 40 },
+		types: [T::Steel, T::Rock],
+		abilities: [A::Sturdy, A::RockHead],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 180,
+		base_friendship: 35,
+		egg_groups: [G::Monster, G::Monster],
+		hatch_cycles: 35,
+	},
+	// 305: Lairon
+	SpeciesInfo {
+		base_stats: Stats { hp: 60, attack: 90, defense: 140, speed: 40, sp_attack: 50, sp_defense: 50 },
+		types: [T::Steel, T::Rock],
+		abilities: [A::Sturdy, A::RockHead],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 90,
+		base_friendship: 35,
+		egg_groups: [G::Monster, G::Monster],
+		hatch_cycles: 35,
+	},
+	// 306: Aggron
+	SpeciesInfo {
+		base_stats: Stats { hp: 70, attack: 110, defense: 180, speed: 50, sp_attack: 60, sp_defense: 60 },
+		types: [T::Steel, T::Rock],
+		abilities: [A::Sturdy, A::RockHead],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 45,
+		base_friendship: 35,
+		egg_groups: [G::Monster, G::Monster],
+		hatch_cycles: 35,
+	},
+	// 307: Meditite
+	SpeciesInfo {
+		base_stats: Stats { hp: 30, attack: 40, defense: 55, speed: 60, sp_attack: 40, sp_defense: 55 },
+		types: [T::Fighting, T::Psychic],
+		abilities: [A::PurePower, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 180,
+		base_friendship: 70,
+		egg_groups: [G::HumanLike, G::HumanLike],
+		hatch_cycles: 20,
+	},
+	// 308: Medicham
+	SpeciesInfo {
+		base_stats: Stats { hp: 60, attack: 60, defense: 75, speed: 80, sp_attack: 60, sp_defense: 75 },
+		types: [T::Fighting, T::Psychic],
+		abilities: [A::PurePower, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 90,
+		base_friendship: 70,
+		egg_groups: [G::HumanLike, G::HumanLike],
+		hatch_cycles: 20,
+	},
+	// 309: Electrike
+	SpeciesInfo {
+		base_stats: Stats { hp: 40, attack: 45, defense: 40, speed: 65, sp_attack: 65, sp_defense: 40 },
+		types: [T::Electric, T::Electric],
+		abilities: [A::Static, A::LightningRod],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 120,
+		base_friendship: 70,
+		egg_groups: [G::Field, G::Field],
+		hatch_cycles: 20,
+	},
+	// 310: Manectric
+	SpeciesInfo {
+		base_stats: Stats { hp: 70, attack: 75, defense: 60, speed: 105, sp_attack: 105, sp_defense: 60 },
+		types: [T::Electric, T::Electric],
+		abilities: [A::Static, A::LightningRod],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Field, G::Field],
+		hatch_cycles: 20,
+	},
+	// 311: Plusle
+	SpeciesInfo {
+		base_stats: Stats { hp: 60, attack: 50, defense: 40, speed: 95, sp_attack: 85, sp_defense: 75 },
+		types: [T::Electric, T::Electric],
+		abilities: [A::Plus, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 200,
+		base_friendship: 70,
+		egg_groups: [G::Fairy, G::Fairy],
+		hatch_cycles: 20,
+	},
+	// 312: Minun
+	SpeciesInfo {
+		base_stats: Stats { hp: 60, attack: 40, defense: 50, speed: 95, sp_attack: 75, sp_defense: 85 },
+		types: [T::Electric, T::Electric],
+		abilities: [A::Minus, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 200,
+		base_friendship: 70,
+		egg_groups: [G::Fairy, G::Fairy],
+		hatch_cycles: 20,
+	},
+	// 313: Volbeat
+	SpeciesInfo {
+		base_stats: Stats { hp: 65, attack: 73, defense: 55, speed: 85, sp_attack: 47, sp_defense: 75 },
+		types: [T::Bug, T::Bug],
+		abilities: [A::Illuminate, A::Swarm],
+		gender_ratio: GenderRatio::MALE_ONLY,
+		growth_rate: GrowthRate::Erratic,
+		catch_rate: 150,
+		base_friendship: 70,
+		egg_groups: [G::Bug, G::HumanLike],
+		hatch_cycles: 15,
+	},
+	// 314: Illumise
+	SpeciesInfo {
+		base_stats: Stats { hp: 65, attack: 47, defense: 55, speed: 85, sp_attack: 73, sp_defense: 75 },
+		types: [T::Bug, T::Bug],
+		abilities: [A::Oblivious, A::TintedLens],
+		gender_ratio: GenderRatio::FEMALE_ONLY,
+		growth_rate: GrowthRate::Fluctuating,
+		catch_rate: 150,
+		base_friendship: 70,
+		egg_groups: [G::Bug, G::HumanLike],
+		hatch_cycles: 15,
+	},
+	// 315: Roselia
+	SpeciesInfo {
+		base_stats: Stats { hp: 50, attack: 60, defense: 45, speed: 65, sp_attack: 100, sp_defense: 80 },
+		types: [T::Grass, T::Poison],
+		abilities: [A::NaturalCure, A::PoisonPoint],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 150,
+		base_friendship: 70,
+		egg_groups: [G::Fairy, G::Grass],
+		hatch_cycles: 20,
+	},
+	// 316: Gulpin
+	SpeciesInfo {
+		base_stats: Stats { hp: 70, attack: 43, defense: 53, speed: 40, sp_attack: 43, sp_defense: 53 },
+		types: [T::Poison, T::Poison],
+		abilities: [A::LiquidOoze, A::StickyHold],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Fluctuating,
+		catch_rate: 225,
+		base_friendship: 70,
+		egg_groups: [G::Amorphous, G::Amorphous],
+		hatch_cycles: 20,
+	},
+	// 317: Swalot
+	SpeciesInfo {
+		base_stats: Stats { hp: 100, attack: 73, defense: 83, speed: 55, sp_attack: 73, sp_defense: 83 },
+		types: [T::Poison, T::Poison],
+		abilities: [A::LiquidOoze, A::StickyHold],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Fluctuating,
+		catch_rate: 75,
+		base_friendship: 70,
+		egg_groups: [G::Amorphous, G::Amorphous],
+		hatch_cycles: 20,
+	},
+	// 318: Carvanha
+	SpeciesInfo {
+		base_stats: Stats { hp: 45, attack: 90, defense: 20, speed: 65, sp_attack: 65, sp_defense: 20 },
+		types: [T::Water, T::Dark],
+		abilities: [A::RoughSkin, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 225,
+		base_friendship: 35,
+		egg_groups: [G::Water2, G::Water2],
+		hatch_cycles: 20,
+	},
+	// 319: Sharpedo
+	SpeciesInfo {
+		base_stats: Stats { hp: 70, attack: 120, defense: 40, speed: 95, sp_attack: 95, sp_defense: 40 },
+		types: [T::Water, T::Dark],
+		abilities: [A::RoughSkin, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 60,
+		base_friendship: 35,
+		egg_groups: [G::Water2, G::Water2],
+		hatch_cycles: 20,
+	},
+	// 320: Wailmer
+	SpeciesInfo {
+		base_stats: Stats { hp: 130, attack: 70, defense: 35, speed: 60, sp_attack: 70, sp_defense: 35 },
+		types: [T::Water, T::Water],
+		abilities: [A::WaterVeil, A::Oblivious],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Fluctuating,
+		catch_rate: 125,
+		base_friendship: 70,
+		egg_groups: [G::Field, G::Water2],
+		hatch_cycles: 40,
+	},
+	// 321: Wailord
+	SpeciesInfo {
+		base_stats: Stats { hp: 170, attack: 90, defense: 45, speed: 60, sp_attack: 90, sp_defense: 45 },
+		types: [T::Water, T::Water],
+		abilities: [A::WaterVeil, A::Oblivious],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Fluctuating,
+		catch_rate: 60,
+		base_friendship: 70,
+		egg_groups: [G::Field, G::Water2],
+		hatch_cycles: 40,
+	},
+	// 322: Numel
+	SpeciesInfo {
+		base_stats: Stats { hp: 60, attack: 60, defense: 40, speed: 35, sp_attack: 65, sp_defense: 45 },
+		types: [T::Fire, T::Ground],
+		abilities: [A::Oblivious, A::Simple],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 255,
+		base_friendship: 70,
+		egg_groups: [G::Field, G::Field],
+		hatch_cycles: 20,
+	},
+	// 323: Camerupt
+	SpeciesInfo {
+		base_stats: Stats { hp: 70, attack: 100, defense: 70, speed: 40, sp_attack: 105, sp_defense: 75 },
+		types: [T::Fire, T::Ground],
+		abilities: [A::MagmaArmor, A::SolidRock],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 150,
+		base_friendship: 70,
+		egg_groups: [G::Field, G::Field],
+		hatch_cycles: 20,
+	},
+	// 324: Torkoal
+	SpeciesInfo {
+		base_stats: Stats { hp: 70, attack: 85, defense: 140, speed: 20, sp_attack: 85, sp_defense: 70 },
+		types: [T::Fire, T::Fire],
+		abilities: [A::WhiteSmoke, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 90,
+		base_friendship: 70,
+		egg_groups: [G::Field, G::Field],
+		hatch_cycles: 20,
+	},
+	// 325: Spoink
+	SpeciesInfo {
+		base_stats: Stats { hp: 60, attack: 25, defense: 35, speed: 60, sp_attack: 70, sp_defense: 80 },
+		types: [T::Psychic, T::Psychic],
+		abilities: [A::ThickFat, A::OwnTempo],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Fast,
+		catch_rate: 255,
+		base_friendship: 70,
+		egg_groups: [G::Field, G::Field],
+		hatch_cycles: 20,
+	},
+	// 326: Grumpig
+	SpeciesInfo {
+		base_stats: Stats { hp: 80, attack: 45, defense: 65, speed: 80, sp_attack: 90, sp_defense: 110 },
+		types: [T::Psychic, T::Psychic],
+		abilities: [A::ThickFat, A::OwnTempo],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Fast,
+		catch_rate: 60,
+		base_friendship: 70,
+		egg_groups: [G::Field, G::Field],
+		hatch_cycles: 20,
+	},
+	// 327: Spinda
+	SpeciesInfo {
+		base_stats: Stats { hp: 60, attack: 60, defense: 60, speed: 60, sp_attack: 60, sp_defense: 60 },
+		types: [T::Normal, T::Normal],
+		abilities: [A::OwnTempo, A::TangledFeet],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Fast,
+		catch_rate: 255,
+		base_friendship: 70,
+		egg_groups: [G::Field, G::HumanLike],
+		hatch_cycles: 15,
+	},
+	// 328: Trapinch
+	SpeciesInfo {
+		base_stats: Stats { hp: 45, attack: 100, defense: 45, speed: 10, sp_attack: 45, sp_defense: 45 },
+		types: [T::Ground, T::Ground],
+		abilities: [A::HyperCutter, A::ArenaTrap],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 255,
+		base_friendship: 70,
+		egg_groups: [G::Bug, G::Bug],
+		hatch_cycles: 20,
+	},
+	// 329: Vibrava
+	SpeciesInfo {
+		base_stats: Stats { hp: 50, attack: 70, defense: 50, speed: 70, sp_attack: 50, sp_defense: 50 },
+		types: [T::Ground, T::Dragon],
+		abilities: [A::Levitate, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 120,
+		base_friendship: 70,
+		egg_groups: [G::Bug, G::Bug],
+		hatch_cycles: 20,
+	},
+	// 330: Flygon
+	SpeciesInfo {
+		base_stats: Stats { hp: 80, attack: 100, defense: 80, speed: 100, sp_attack: 80, sp_defense: 80 },
+		types: [T::Ground, T::Dragon],
+		abilities: [A::Levitate, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Bug, G::Bug],
+		hatch_cycles: 20,
+	},
+	// 331: Cacnea
+	SpeciesInfo {
+		base_stats: Stats { hp: 50, attack: 85, defense: 40, speed: 35, sp_attack: 85, sp_defense: 40 },
+		types: [T::Grass, T::Grass],
+		abilities: [A::SandVeil, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 190,
+		base_friendship: 35,
+		egg_groups: [G::Grass, G::HumanLike],
+		hatch_cycles: 20,
+	},
+	// 332: Cacturne
+	SpeciesInfo {
+		base_stats: Stats { hp: 70, attack: 115, defense: 60, speed: 55, sp_attack: 115, sp_defense: 60 },
+		types: [T::Grass, T::Dark],
+		abilities: [A::SandVeil, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 60,
+		base_friendship: 35,
+		egg_groups: [G::Grass, G::HumanLike],
+		hatch_cycles: 20,
+	},
+	// 333: Swablu
+	SpeciesInfo {
+		base_stats: Stats { hp: 45, attack: 40, defense: 60, speed: 50, sp_attack: 40, sp_defense: 75 },
+		types: [T::Normal, T::Flying],
+		abilities: [A::NaturalCure, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Erratic,
+		catch_rate: 255,
+		base_friendship: 70,
+		egg_groups: [G::Flying, G::Dragon],
+		hatch_cycles: 20,
+	},
+	// 334: Altaria
+	SpeciesInfo {
+		base_stats: Stats { hp: 75, attack: 70, defense: 90, speed: 80, sp_attack: 70, sp_defense: 105 },
+		types: [T::Dragon, T::Flying],
+		abilities: [A::NaturalCure, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Erratic,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Flying, G::Dragon],
+		hatch_cycles: 20,
+	},
+	// 335: Zangoose
+	SpeciesInfo {
+		base_stats: Stats { hp: 73, attack: 115, defense: 60, speed: 90, sp_attack: 60, sp_defense: 60 },
+		types: [T::Normal, T::Normal],
+		abilities: [A::Immunity, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Erratic,
+		catch_rate: 90,
+		base_friendship: 70,
+		egg_groups: [G::Field, G::Field],
+		hatch_cycles: 20,
+	},
+	// 336: Seviper
+	SpeciesInfo {
+		base_stats: Stats { hp: 73, attack: 100, defense: 60, speed: 65, sp_attack: 100, sp_defense: 60 },
+		types: [T::Poison, T::Poison],
+		abilities: [A::ShedSkin, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Fluctuating,
+		catch_rate: 90,
+		base_friendship: 70,
+		egg_groups: [G::Field, G::Dragon],
+		hatch_cycles: 20,
+	},
+	// 337: Lunatone
+	SpeciesInfo {
+		base_stats: Stats { hp: 70, attack: 55, defense: 65, speed: 70, sp_attack: 95, sp_defense: 85 },
+		types: [T::Rock, T::Psychic],
+		abilities: [A::Levitate, A::None],
+		gender_ratio: GenderRatio::GENDERLESS,
+		growth_rate: GrowthRate::Fast,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Mineral, G::Mineral],
+		hatch_cycles: 25,
+	},
+	// 338: Solrock
+	SpeciesInfo {
+		base_stats: Stats { hp: 70, attack: 95, defense: 85, speed: 70, sp_attack: 55, sp_defense: 65 },
+		types: [T::Rock, T::Psychic],
+		abilities: [A::Levitate, A::None],
+		gender_ratio: GenderRatio::GENDERLESS,
+		growth_rate: GrowthRate::Fast,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Mineral, G::Mineral],
+		hatch_cycles: 25,
+	},
+	// 339: Barboach
+	SpeciesInfo {
+		base_stats: Stats { hp: 50, attack: 48, defense: 43, speed: 60, sp_attack: 46, sp_defense: 41 },
+		types: [T::Water, T::Ground],
+		abilities: [A::Oblivious, A::Anticipation],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 190,
+		base_friendship: 70,
+		egg_groups: [G::Water2, G::Water2],
+		hatch_cycles: 20,
+	},
+	// 340: Whiscash
+	SpeciesInfo {
+		base_stats: Stats { hp: 110, attack: 78, defense: 73, speed: 60, sp_attack: 76, sp_defense: 71 },
+		types: [T::Water, T::Ground],
+		abilities: [A::Oblivious, A::Anticipation],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 75,
+		base_friendship: 70,
+		egg_groups: [G::Water2, G::Water2],
+		hatch_cycles: 20,
+	},
+	// 341: Corphish
+	SpeciesInfo {
+		base_stats: Stats { hp: 43, attack: 80, defense: 65, speed: 35, sp_attack: 50, sp_defense: 35 },
+		types: [T::Water, T::Water],
+		abilities: [A::HyperCutter, A::ShellArmor],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Fluctuating,
+		catch_rate: 205,
+		base_friendship: 70,
+		egg_groups: [G::Water1, G::Water3],
+		hatch_cycles: 15,
+	},
+	// 342: Crawdaunt
+	SpeciesInfo {
+		base_stats: Stats { hp: 63, attack: 120, defense: 85, speed: 55, sp_attack: 90, sp_defense: 55 },
+		types: [T::Water, T::Dark],
+		abilities: [A::HyperCutter, A::ShellArmor],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Fluctuating,
+		catch_rate: 155,
+		base_friendship: 70,
+		egg_groups: [G::Water1, G::Water3],
+		hatch_cycles: 15,
+	},
+	// 343: Baltoy
+	SpeciesInfo {
+		base_stats: Stats { hp: 40, attack: 40, defense: 55, speed: 55, sp_attack: 40, sp_defense: 70 },
+		types: [T::Ground, T::Psychic],
+		abilities: [A::Levitate, A::None],
+		gender_ratio: GenderRatio::GENDERLESS,
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 255,
+		base_friendship: 70,
+		egg_groups: [G::Mineral, G::Mineral],
+		hatch_cycles: 20,
+	},
+	// 344: Claydol
+	SpeciesInfo {
+		base_stats: Stats { hp: 60, attack: 70, defense: 105, speed: 75, sp_attack: 70, sp_defense: 120 },
+		types: [T::Ground, T::Psychic],
+		abilities: [A::Levitate, A::None],
+		gender_ratio: GenderRatio::GENDERLESS,
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 90,
+		base_friendship: 70,
+		egg_groups: [G::Mineral, G::Mineral],
+		hatch_cycles: 20,
+	},
+	// 345: Lileep
+	SpeciesInfo {
+		base_stats: Stats { hp: 66, attack: 41, defense: 77, speed: 23, sp_attack: 61, sp_defense: 87 },
+		types: [T::Rock, T::Grass],
+		abilities: [A::SuctionCups, A::None],
+		gender_ratio: GenderRatio(31),
+		growth_rate: GrowthRate::Erratic,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Water3, G::Water3],
+		hatch_cycles: 30,
+	},
+	// 346: Cradily
+	SpeciesInfo {
+		base_stats: Stats { hp: 86, attack: 81, defense: 97, speed: 43, sp_attack: 81, sp_defense: 107 },
+		types: [T::Rock, T::Grass],
+		abilities: [A::SuctionCups, A::None],
+		gender_ratio: GenderRatio(31),
+		growth_rate: GrowthRate::Erratic,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Water3, G::Water3],
+		hatch_cycles: 30,
+	},
+	// 347: Anorith
+	SpeciesInfo {
+		base_stats: Stats { hp: 45, attack: 95, defense: 50, speed: 75, sp_attack: 40, sp_defense: 50 },
+		types: [T::Rock, T::Bug],
+		abilities: [A::BattleArmor, A::None],
+		gender_ratio: GenderRatio(31),
+		growth_rate: GrowthRate::Erratic,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Water3, G::Water3],
+		hatch_cycles: 30,
+	},
+	// 348: Armaldo
+	SpeciesInfo {
+		base_stats: Stats { hp: 75, attack: 125, defense: 100, speed: 45, sp_attack: 70, sp_defense: 80 },
+		types: [T::Rock, T::Bug],
+		abilities: [A::BattleArmor, A::None],
+		gender_ratio: GenderRatio(31),
+		growth_rate: GrowthRate::Erratic,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Water3, G::Water3],
+		hatch_cycles: 30,
+	},
+	// 349: Feebas
+	SpeciesInfo {
+		base_stats: Stats { hp: 20, attack: 15, defense: 20, speed: 80, sp_attack: 10, sp_defense: 55 },
+		types: [T::Water, T::Water],
+		abilities: [A::SwiftSwim, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Erratic,
+		catch_rate: 255,
+		base_friendship: 70,
+		egg_groups: [G::Water1, G::Dragon],
+		hatch_cycles: 20,
+	},
+	// 350: Milotic
+	SpeciesInfo {
+		base_stats: Stats { hp: 95, attack: 60, defense: 79, speed: 81, sp_attack: 100, sp_defense: 125 },
+		types: [T::Water, T::Water],
+		abilities: [A::MarvelScale, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Erratic,
+		catch_rate: 60,
+		base_friendship: 70,
+		egg_groups: [G::Water1, G::Dragon],
+		hatch_cycles: 20,
+	},
+	// 351: Castform
+	SpeciesInfo {
+		base_stats: Stats { hp: 70, attack: 70, defense: 70, speed: 70, sp_attack: 70, sp_defense: 70 },
+		types: [T::Normal, T::Normal],
+		abilities: [A::Forecast, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Fairy, G::Amorphous],
+		hatch_cycles: 25,
+	},
+	// 352: Kecleon
+	SpeciesInfo {
+		base_stats: Stats { hp: 60, attack: 90, defense: 70, speed: 40, sp_attack: 60, sp_defense: 120 },
+		types: [T::Normal, T::Normal],
+		abilities: [A::ColorChange, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 200,
+		base_friendship: 70,
+		egg_groups: [G::Field, G::Field],
+		hatch_cycles: 20,
+	},
+	// 353: Shuppet
+	SpeciesInfo {
+		base_stats: Stats { hp: 44, attack: 75, defense: 35, speed: 45, sp_attack: 63, sp_defense: 33 },
+		types: [T::Ghost, T::Ghost],
+		abilities: [A::Insomnia, A::Frisk],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Fast,
+		catch_rate: 225,
+		base_friendship: 35,
+		egg_groups: [G::Amorphous, G::Amorphous],
+		hatch_cycles: 25,
+	},
+	// 354: Banette
+	SpeciesInfo {
+		base_stats: Stats { hp: 64, attack: 115, defense: 65, speed: 65, sp_attack: 83, sp_defense: 63 },
+		types: [T::Ghost, T::Ghost],
+		abilities: [A::Insomnia, A::Frisk],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Fast,
+		catch_rate: 45,
+		base_friendship: 35,
+		egg_groups: [G::Amorphous, G::Amorphous],
+		hatch_cycles: 25,
+	},
+	// 355: Duskull
+	SpeciesInfo {
+		base_stats: Stats { hp: 20, attack: 40, defense: 90, speed: 25, sp_attack: 30, sp_defense: 90 },
+		types: [T::Ghost, T::Ghost],
+		abilities: [A::Levitate, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Fast,
+		catch_rate: 190,
+		base_friendship: 35,
+		egg_groups: [G::Amorphous, G::Amorphous],
+		hatch_cycles: 25,
+	},
+	// 356: Dusclops
+	SpeciesInfo {
+		base_stats: Stats { hp: 40, attack: 70, defense: 130, speed: 25, sp_attack: 60, sp_defense: 130 },
+		types: [T::Ghost, T::Ghost],
+		abilities: [A::Pressure, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Fast,
+		catch_rate: 90,
+		base_friendship: 35,
+		egg_groups: [G::Amorphous, G::Amorphous],
+		hatch_cycles: 25,
+	},
+	// 357: Tropius
+	SpeciesInfo {
+		base_stats: Stats { hp: 99, attack: 68, defense: 83, speed: 51, sp_attack: 72, sp_defense: 87 },
+		types: [T::Grass, T::Flying],
+		abilities: [A::Chlorophyll, A::SolarPower],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 200,
+		base_friendship: 70,
+		egg_groups: [G::Monster, G::Grass],
+		hatch_cycles: 25,
+	},
+	// 358: Chimecho
+	SpeciesInfo {
+		base_stats: Stats { hp: 65, attack: 50, defense: 70, speed: 65, sp_attack: 95, sp_defense: 80 },
+		types: [T::Psychic, T::Psychic],
+		abilities: [A::Levitate, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Fast,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Amorphous, G::Amorphous],
+		hatch_cycles: 25,
+	},
+	// 359: Absol
+	SpeciesInfo {
+		base_stats: Stats { hp: 65, attack: 130, defense: 60, speed: 75, sp_attack: 75, sp_defense: 60 },
+		types: [T::Dark, T::Dark],
+		abilities: [A::Pressure, A::SuperLuck],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 30,
+		base_friendship: 35,
+		egg_groups: [G::Field, G::Field],
+		hatch_cycles: 25,
+	},
+	// 360: Wynaut
+	SpeciesInfo {
+		base_stats: Stats { hp: 95, attack: 23, defense: 48, speed: 23, sp_attack: 23, sp_defense: 48 },
+		types: [T::Psychic, T::Psychic],
+		abilities: [A::ShadowTag, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 125,
+		base_friendship: 70,
+		egg_groups: [G::Undiscovered, G::Undiscovered],
+		hatch_cycles: 20,
+	},
+	// 361: Snorunt
+	SpeciesInfo {
+		base_stats: Stats { hp: 50, attack: 50, defense: 50, speed: 50, sp_attack: 50, sp_defense: 50 },
+		types: [T::Ice, T::Ice],
+		abilities: [A::InnerFocus, A::IceBody],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 190,
+		base_friendship: 70,
+		egg_groups: [G::Fairy, G::Mineral],
+		hatch_cycles: 20,
+	},
+	// 362: Glalie
+	SpeciesInfo {
+		base_stats: Stats { hp: 80, attack: 80, defense: 80, speed: 80, sp_attack: 80, sp_defense: 80 },
+		types: [T::Ice, T::Ice],
+		abilities: [A::InnerFocus, A::IceBody],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 75,
+		base_friendship: 70,
+		egg_groups: [G::Fairy, G::Mineral],
+		hatch_cycles: 20,
+	},
+	// 363: Spheal
+	SpeciesInfo {
+		base_stats: Stats { hp: 70, attack: 40, defense: 50, speed: 25, sp_attack: 55, sp_defense: 50 },
+		types: [T::Ice, T::Water],
+		abilities: [A::ThickFat, A::IceBody],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 255,
+		base_friendship: 70,
+		egg_groups: [G::Water1, G::Field],
+		hatch_cycles: 20,
+	},
+	// 364: Sealeo
+	SpeciesInfo {
+		base_stats: Stats { hp: 90, attack: 60, defense: 70, speed: 45, sp_attack: 75, sp_defense: 70 },
+		types: [T::Ice, T::Water],
+		abilities: [A::ThickFat, A::IceBody],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 120,
+		base_friendship: 70,
+		egg_groups: [G::Water1, G::Field],
+		hatch_cycles: 20,
+	},
+	// 365: Walrein
+	SpeciesInfo {
+		base_stats: Stats { hp: 110, attack: 80, defense: 90, speed: 65, sp_attack: 95, sp_defense: 90 },
+		types: [T::Ice, T::Water],
+		abilities: [A::ThickFat, A::IceBody],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Water1, G::Field],
+		hatch_cycles: 20,
+	},
+	// 366: Clamperl
+	SpeciesInfo {
+		base_stats: Stats { hp: 35, attack: 64, defense: 85, speed: 32, sp_attack: 74, sp_defense: 55 },
+		types: [T::Water, T::Water],
+		abilities: [A::ShellArmor, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Erratic,
+		catch_rate: 255,
+		base_friendship: 70,
+		egg_groups: [G::Water1, G::Water1],
+		hatch_cycles: 20,
+	},
+	// 367: Huntail
+	SpeciesInfo {
+		base_stats: Stats { hp: 55, attack: 104, defense: 105, speed: 52, sp_attack: 94, sp_defense: 75 },
+		types: [T::Water, T::Water],
+		abilities: [A::SwiftSwim, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Erratic,
+		catch_rate: 60,
+		base_friendship: 70,
+		egg_groups: [G::Water1, G::Water1],
+		hatch_cycles: 20,
+	},
+	// 368: Gorebyss
+	SpeciesInfo {
+		base_stats: Stats { hp: 55, attack: 84, defense: 105, speed: 52, sp_attack: 114, sp_defense: 75 },
+		types: [T::Water, T::Water],
+		abilities: [A::SwiftSwim, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Erratic,
+		catch_rate: 60,
+		base_friendship: 70,
+		egg_groups: [G::Water1, G::Water1],
+		hatch_cycles: 20,
+	},
+	// 369: Relicanth
+	SpeciesInfo {
+		base_stats: Stats { hp: 100, attack: 90, defense: 130, speed: 55, sp_attack: 45, sp_defense: 65 },
+		types: [T::Water, T::Rock],
+		abilities: [A::SwiftSwim, A::RockHead],
+		gender_ratio: GenderRatio(31),
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 25,
+		base_friendship: 70,
+		egg_groups: [G::Water1, G::Water2],
+		hatch_cycles: 40,
+	},
+	// 370: Luvdisc
+	SpeciesInfo {
+		base_stats: Stats { hp: 43, attack: 30, defense: 55, speed: 97, sp_attack: 40, sp_defense: 65 },
+		types: [T::Water, T::Water],
+		abilities: [A::SwiftSwim, A::None],
+		gender_ratio: GenderRatio(191),
+		growth_rate: GrowthRate::Fast,
+		catch_rate: 225,
+		base_friendship: 70,
+		egg_groups: [G::Water2, G::Water2],
+		hatch_cycles: 20,
+	},
+	// 371: Bagon
+	SpeciesInfo {
+		base_stats: Stats { hp: 45, attack: 75, defense: 60, speed: 50, sp_attack: 40, sp_defense: 30 },
+		types: [T::Dragon, T::Dragon],
+		abilities: [A::RockHead, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 45,
+		base_friendship: 35,
+		egg_groups: [G::Dragon, G::Dragon],
+		hatch_cycles: 40,
+	},
+	// 372: Shelgon
+	SpeciesInfo {
+		base_stats: Stats { hp: 65, attack: 95, defense: 100, speed: 50, sp_attack: 60, sp_defense: 50 },
+		types: [T::Dragon, T::Dragon],
+		abilities: [A::RockHead, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 45,
+		base_friendship: 35,
+		egg_groups: [G::Dragon, G::Dragon],
+		hatch_cycles: 40,
+	},
+	// 373: Salamence
+	SpeciesInfo {
+		base_stats: Stats { hp: 95, attack: 135, defense: 80, speed: 100, sp_attack: 110, sp_defense: 80 },
+		types: [T::Dragon, T::Flying],
+		abilities: [A::Intimidate, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 45,
+		base_friendship: 35,
+		egg_groups: [G::Dragon, G::Dragon],
+		hatch_cycles: 40,
+	},
+	// 374: Beldum
+	SpeciesInfo {
+		base_stats: Stats { hp: 40, attack: 55, defense: 80, speed: 30, sp_attack: 35, sp_defense: 60 },
+		types: [T::Steel, T::Psychic],
+		abilities: [A::ClearBody, A::None],
+		gender_ratio: GenderRatio::GENDERLESS,
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 3,
+		base_friendship: 35,
+		egg_groups: [G::Mineral, G::Mineral],
+		hatch_cycles: 40,
+	},
+	// 375: Metang
+	SpeciesInfo {
+		base_stats: Stats { hp: 60, attack: 75, defense: 100, speed: 50, sp_attack: 55, sp_defense: 80 },
+		types: [T::Steel, T::Psychic],
+		abilities: [A::ClearBody, A::None],
+		gender_ratio: GenderRatio::GENDERLESS,
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 3,
+		base_friendship: 35,
+		egg_groups: [G::Mineral, G::Mineral],
+		hatch_cycles: 40,
+	},
+	// 376: Metagross
+	SpeciesInfo {
+		base_stats: Stats { hp: 80, attack: 135, defense: 130, speed: 70, sp_attack: 95, sp_defense: 90 },
+		types: [T::Steel, T::Psychic],
+		abilities: [A::ClearBody, A::None],
+		gender_ratio: GenderRatio::GENDERLESS,
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 3,
+		base_friendship: 35,
+		egg_groups: [G::Mineral, G::Mineral],
+		hatch_cycles: 40,
+	},
+	// 377: Regirock
+	SpeciesInfo {
+		base_stats: Stats { hp: 80, attack: 100, defense: 200, speed: 50, sp_attack: 50, sp_defense: 100 },
+		types: [T::Rock, T::Rock],
+		abilities: [A::ClearBody, A::None],
+		gender_ratio: GenderRatio::GENDERLESS,
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 3,
+		base_friendship: 35,
+		egg_groups: [G::Undiscovered, G::Undiscovered],
+		hatch_cycles: 80,
+	},
+	// 378: Regice
+	SpeciesInfo {
+		base_stats: Stats { hp: 80, attack: 50, defense: 100, speed: 50, sp_attack: 100, sp_defense: 200 },
+		types: [T::Ice, T::Ice],
+		abilities: [A::ClearBody, A::None],
+		gender_ratio: GenderRatio::GENDERLESS,
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 3,
+		base_friendship: 35,
+		egg_groups: [G::Undiscovered, G::Undiscovered],
+		hatch_cycles: 80,
+	},
+	// 379: Registeel
+	SpeciesInfo {
+		base_stats: Stats { hp: 80, attack: 75, defense: 150, speed: 50, sp_attack: 75, sp_defense: 150 },
+		types: [T::Steel, T::Steel],
+		abilities: [A::ClearBody, A::None],
+		gender_ratio: GenderRatio::GENDERLESS,
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 3,
+		base_friendship: 35,
+		egg_groups: [G::Undiscovered, G::Undiscovered],
+		hatch_cycles: 80,
+	},
+	// 380: Latias
+	SpeciesInfo {
+		base_stats: Stats { hp: 80, attack: 80, defense: 90, speed: 110, sp_attack: 110, sp_defense: 130 },
+		types: [T::Dragon, T::Psychic],
+		abilities: [A::Levitate, A::None],
+		gender_ratio: GenderRatio::FEMALE_ONLY,
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 3,
+		base_friendship: 90,
+		egg_groups: [G::Undiscovered, G::Undiscovered],
+		hatch_cycles: 120,
+	},
+	// 381: Latios
+	SpeciesInfo {
+		base_stats: Stats { hp: 80, attack: 90, defense: 80, speed: 110, sp_attack: 130, sp_defense: 110 },
+		types: [T::Dragon, T::Psychic],
+		abilities: [A::Levitate, A::None],
+		gender_ratio: GenderRatio::MALE_ONLY,
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 3,
+		base_friendship: 90,
+		egg_groups: [G::Undiscovered, G::Undiscovered],
+		hatch_cycles: 120,
+	},
+	// 382: Kyogre
+	SpeciesInfo {
+		base_stats: Stats { hp: 100, attack: 100, defense: 90, speed: 90, sp_attack: 150, sp_defense: 140 },
+		types: [T::Water, T::Water],
+		abilities: [A::Drizzle, A::None],
+		gender_ratio: GenderRatio::GENDERLESS,
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 5,
+		base_friendship: 0,
+		egg_groups: [G::Undiscovered, G::Undiscovered],
+		hatch_cycles: 120,
+	},
+	// 383: Groudon
+	SpeciesInfo {
+		base_stats: Stats { hp: 100, attack: 150, defense: 140, speed: 90, sp_attack: 100, sp_defense: 90 },
+		types: [T::Ground, T::Ground],
+		abilities: [A::Drought, A::None],
+		gender_ratio: GenderRatio::GENDERLESS,
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 5,
+		base_friendship: 0,
+		egg_groups: [G::Undiscovered, G::Undiscovered],
+		hatch_cycles: 120,
+	},
+	// 384: Rayquaza
+	SpeciesInfo {
+		base_stats: Stats { hp: 105, attack: 150, defense: 90, speed: 95, sp_attack: 150, sp_defense: 90 },
+		types: [T::Dragon, T::Flying],
+		abilities: [A::AirLock, A::None],
+		gender_ratio: GenderRatio::GENDERLESS,
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 3,
+		base_friendship: 0,
+		egg_groups: [G::Undiscovered, G::Undiscovered],
+		hatch_cycles: 120,
+	},
+	// 385: Jirachi
+	SpeciesInfo {
+		base_stats: Stats { hp: 100, attack: 100, defense: 100, speed: 100, sp_attack: 100, sp_defense: 100 },
+		types: [T::Steel, T::Psychic],
+		abilities: [A::SereneGrace, A::None],
+		gender_ratio: GenderRatio::GENDERLESS,
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 3,
+		base_friendship: 100,
+		egg_groups: [G::Undiscovered, G::Undiscovered],
+		hatch_cycles: 120,
+	},
+	// 386: Deoxys
+	SpeciesInfo {
+		base_stats: Stats { hp: 50, attack: 150, defense: 50, speed: 150, sp_attack: 150, sp_defense: 50 },
+		types: [T::Psychic, T::Psychic],
+		abilities: [A::Pressure, A::None],
+		gender_ratio: GenderRatio::GENDERLESS,
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 3,
+		base_friendship: 0,
+		egg_groups: [G::Undiscovered, G::Undiscovered],
+		hatch_cycles: 120,
+	},
+	// 387: Turtwig
+	SpeciesInfo {
+		base_stats: Stats { hp: 55, attack: 68, defense: 64, speed: 31, sp_attack: 45, sp_defense: 55 },
+		types: [T::Grass, T::Grass],
+		abilities: [A::Overgrow, A::None],
+		gender_ratio: GenderRatio(31),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Monster, G::Grass],
+		hatch_cycles: 20,
+	},
+	// 388: Grotle
+	SpeciesInfo {
+		base_stats: Stats { hp: 75, attack: 89, defense: 85, speed: 36, sp_attack: 55, sp_defense: 65 },
+		types: [T::Grass, T::Grass],
+		abilities: [A::Overgrow, A::None],
+		gender_ratio: GenderRatio(31),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Monster, G::Grass],
+		hatch_cycles: 20,
+	},
+	// 389: Torterra
+	SpeciesInfo {
+		base_stats: Stats { hp: 95, attack: 109, defense: 105, speed: 56, sp_attack: 75, sp_defense: 85 },
+		types: [T::Grass, T::Ground],
+		abilities: [A::Overgrow, A::None],
+		gender_ratio: GenderRatio(31),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Monster, G::Grass],
+		hatch_cycles: 20,
+	},
+	// 390: Chimchar
+	SpeciesInfo {
+		base_stats: Stats { hp: 44, attack: 58, defense: 44, speed: 61, sp_attack: 58, sp_defense: 44 },
+		types: [T::Fire, T::Fire],
+		abilities: [A::Blaze, A::None],
+		gender_ratio: GenderRatio(31),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Field, G::HumanLike],
+		hatch_cycles: 20,
+	},
+	// 391: Monferno
+	SpeciesInfo {
+		base_stats: Stats { hp: 64, attack: 78, defense: 52, speed: 81, sp_attack: 78, sp_defense: 52 },
+		types: [T::Fire, T::Fighting],
+		abilities: [A::Blaze, A::None],
+		gender_ratio: GenderRatio(31),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Field, G::HumanLike],
+		hatch_cycles: 20,
+	},
+	// 392: Infernape
+	SpeciesInfo {
+		base_stats: Stats { hp: 76, attack: 104, defense: 71, speed: 108, sp_attack: 104, sp_defense: 71 },
+		types: [T::Fire, T::Fighting],
+		abilities: [A::Blaze, A::None],
+		gender_ratio: GenderRatio(31),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Field, G::HumanLike],
+		hatch_cycles: 20,
+	},
+	// 393: Piplup
+	SpeciesInfo {
+		base_stats: Stats { hp: 53, attack: 51, defense: 53, speed: 40, sp_attack: 61, sp_defense: 56 },
+		types: [T::Water, T::Water],
+		abilities: [A::Torrent, A::None],
+		gender_ratio: GenderRatio(31),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Water1, G::Field],
+		hatch_cycles: 20,
+	},
+	// 394: Prinplup
+	SpeciesInfo {
+		base_stats: Stats { hp: 64, attack: 66, defense: 68, speed: 50, sp_attack: 81, sp_defense: 76 },
+		types: [T::Water, T::Water],
+		abilities: [A::Torrent, A::None],
+		gender_ratio: GenderRatio(31),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Water1, G::Field],
+		hatch_cycles: 20,
+	},
+	// 395: Empoleon
+	SpeciesInfo {
+		base_stats: Stats { hp: 84, attack: 86, defense: 88, speed: 60, sp_attack: 111, sp_defense: 101 },
+		types: [T::Water, T::Steel],
+		abilities: [A::Torrent, A::None],
+		gender_ratio: GenderRatio(31),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Water1, G::Field],
+		hatch_cycles: 20,
+	},
+	// 396: Starly
+	SpeciesInfo {
+		base_stats: Stats { hp: 40, attack: 55, defense: 30, speed: 60, sp_attack: 30, sp_defense: 30 },
+		types: [T::Normal, T::Flying],
+		abilities: [A::KeenEye, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 255,
+		base_friendship: 70,
+		egg_groups: [G::Flying, G::Flying],
+		hatch_cycles: 15,
+	},
+	// 397: Staravia
+	SpeciesInfo {
+		base_stats: Stats { hp: 55, attack: 75, defense: 50, speed: 80, sp_attack: 40, sp_defense: 40 },
+		types: [T::Normal, T::Flying],
+		abilities: [A::Intimidate, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 120,
+		base_friendship: 70,
+		egg_groups: [G::Flying, G::Flying],
+		hatch_cycles: 15,
+	},
+	// 398: Staraptor
+	SpeciesInfo {
+		base_stats: Stats { hp: 85, attack: 120, defense: 70, speed: 100, sp_attack: 50, sp_defense: 50 },
+		types: [T::Normal, T::Flying],
+		abilities: [A::Intimidate, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Flying, G::Flying],
+		hatch_cycles: 15,
+	},
+	// 399: Bidoof
+	SpeciesInfo {
+		base_stats: Stats { hp: 59, attack: 45, defense: 40, speed: 31, sp_attack: 35, sp_defense: 40 },
+		types: [T::Normal, T::Normal],
+		abilities: [A::Simple, A::Unaware],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 255,
+		base_friendship: 70,
+		egg_groups: [G::Water1, G::Field],
+		hatch_cycles: 15,
+	},
+	// 400: Bibarel
+	SpeciesInfo {
+		base_stats: Stats { hp: 79, attack: 85, defense: 60, speed: 71, sp_attack: 55, sp_defense: 60 },
+		types: [T::Normal, T::Water],
+		abilities: [A::Simple, A::Unaware],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 127,
+		base_friendship: 70,
+		egg_groups: [G::Water1, G::Field],
+		hatch_cycles: 15,
+	},
+	// 401: Kricketot
+	SpeciesInfo {
+		base_stats: Stats { hp: 37, attack: 25, defense: 41, speed: 25, sp_attack: 25, sp_defense: 41 },
+		types: [T::Bug, T::Bug],
+		abilities: [A::ShedSkin, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 255,
+		base_friendship: 70,
+		egg_groups: [G::Bug, G::Bug],
+		hatch_cycles: 15,
+	},
+	// 402: Kricketune
+	SpeciesInfo {
+		base_stats: Stats { hp: 77, attack: 85, defense: 51, speed: 65, sp_attack: 55, sp_defense: 51 },
+		types: [T::Bug, T::Bug],
+		abilities: [A::Swarm, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Bug, G::Bug],
+		hatch_cycles: 15,
+	},
+	// 403: Shinx
+	SpeciesInfo {
+		base_stats: Stats { hp: 45, attack: 65, defense: 34, speed: 45, sp_attack: 40, sp_defense: 34 },
+		types: [T::Electric, T::Electric],
+		abilities: [A::Rivalry, A::Intimidate],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 235,
+		base_friendship: 70,
+		egg_groups: [G::Field, G::Field],
+		hatch_cycles: 20,
+	},
+	// 404: Luxio
+	SpeciesInfo {
+		base_stats: Stats { hp: 60, attack: 85, defense: 49, speed: 60, sp_attack: 60, sp_defense: 49 },
+		types: [T::Electric, T::Electric],
+		abilities: [A::Rivalry, A::Intimidate],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 120,
+		base_friendship: 100,
+		egg_groups: [G::Field, G::Field],
+		hatch_cycles: 20,
+	},
+	// 405: Luxray
+	SpeciesInfo {
+		base_stats: Stats { hp: 80, attack: 120, defense: 79, speed: 70, sp_attack: 95, sp_defense: 79 },
+		types: [T::Electric, T::Electric],
+		abilities: [A::Rivalry, A::Intimidate],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Field, G::Field],
+		hatch_cycles: 20,
+	},
+	// 406: Budew
+	SpeciesInfo {
+		base_stats: Stats { hp: 40, attack: 30, defense: 35, speed: 55, sp_attack: 50, sp_defense: 70 },
+		types: [T::Grass, T::Poison],
+		abilities: [A::NaturalCure, A::PoisonPoint],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 255,
+		base_friendship: 70,
+		egg_groups: [G::Undiscovered, G::Undiscovered],
+		hatch_cycles: 20,
+	},
+	// 407: Roserade
+	SpeciesInfo {
+		base_stats: Stats { hp: 60, attack: 70, defense: 55, speed: 90, sp_attack: 125, sp_defense: 105 },
+		types: [T::Grass, T::Poison],
+		abilities: [A::NaturalCure, A::PoisonPoint],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 75,
+		base_friendship: 70,
+		egg_groups: [G::Fairy, G::Grass],
+		hatch_cycles: 20,
+	},
+	// 408: Cranidos
+	SpeciesInfo {
+		base_stats: Stats { hp: 67, attack: 125, defense: 40, speed: 58, sp_attack: 30, sp_defense: 30 },
+		types: [T::Rock, T::Rock],
+		abilities: [A::MoldBreaker, A::None],
+		gender_ratio: GenderRatio(31),
+		growth_rate: GrowthRate::Erratic,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Monster, G::Monster],
+		hatch_cycles: 30,
+	},
+	// 409: Rampardos
+	SpeciesInfo {
+		base_stats: Stats { hp: 97, attack: 165, defense: 60, speed: 58, sp_attack: 65, sp_defense: 50 },
+		types: [T::Rock, T::Rock],
+		abilities: [A::MoldBreaker, A::None],
+		gender_ratio: GenderRatio(31),
+		growth_rate: GrowthRate::Erratic,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Monster, G::Monster],
+		hatch_cycles: 30,
+	},
+	// 410: Shieldon
+	SpeciesInfo {
+		base_stats: Stats { hp: 30, attack: 42, defense: 118, speed: 30, sp_attack: 42, sp_defense: 88 },
+		types: [T::Rock, T::Steel],
+		abilities: [A::Sturdy, A::None],
+		gender_ratio: GenderRatio(31),
+		growth_rate: GrowthRate::Erratic,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Monster, G::Monster],
+		hatch_cycles: 30,
+	},
+	// 411: Bastiodon
+	SpeciesInfo {
+		base_stats: Stats { hp: 60, attack: 52, defense: 168, speed: 30, sp_attack: 47, sp_defense: 138 },
+		types: [T::Rock, T::Steel],
+		abilities: [A::Sturdy, A::None],
+		gender_ratio: GenderRatio(31),
+		growth_rate: GrowthRate::Erratic,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Monster, G::Monster],
+		hatch_cycles: 30,
+	},
+	// 412: Burmy
+	SpeciesInfo {
+		base_stats: Stats { hp: 40, attack: 29, defense: 45, speed: 36, sp_attack: 29, sp_defense: 45 },
+		types: [T::Bug, T::Bug],
+		abilities: [A::ShedSkin, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 120,
+		base_friendship: 70,
+		egg_groups: [G::Bug, G::Bug],
+		hatch_cycles: 15,
+	},
+	// 413: Wormadam
+	SpeciesInfo {
+		base_stats: Stats { hp: 60, attack: 59, defense: 85, speed: 36, sp_attack: 79, sp_defense: 105 },
+		types: [T::Bug, T::Grass],
+		abilities: [A::Anticipation, A::None],
+		gender_ratio: GenderRatio::FEMALE_ONLY,
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Bug, G::Bug],
+		hatch_cycles: 15,
+	},
+	// 414: Mothim
+	SpeciesInfo {
+		base_stats: Stats { hp: 70, attack: 94, defense: 50, speed: 66, sp_attack: 94, sp_defense: 50 },
+		types: [T::Bug, T::Flying],
+		abilities: [A::Swarm, A::None],
+		gender_ratio: GenderRatio::MALE_ONLY,
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Bug, G::Bug],
+		hatch_cycles: 15,
+	},
+	// 415: Combee
+	SpeciesInfo {
+		base_stats: Stats { hp: 30, attack: 30, defense: 42, speed: 70, sp_attack: 30, sp_defense: 42 },
+		types: [T::Bug, T::Flying],
+		abilities: [A::HoneyGather, A::None],
+		gender_ratio: GenderRatio(31),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 120,
+		base_friendship: 70,
+		egg_groups: [G::Bug, G::Bug],
+		hatch_cycles: 15,
+	},
+	// 416: Vespiquen
+	SpeciesInfo {
+		base_stats: Stats { hp: 70, attack: 80, defense: 102, speed: 40, sp_attack: 80, sp_defense: 102 },
+		types: [T::Bug, T::Flying],
+		abilities: [A::Pressure, A::None],
+		gender_ratio: GenderRatio::FEMALE_ONLY,
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Bug, G::Bug],
+		hatch_cycles: 15,
+	},
+	// 417: Pachirisu
+	SpeciesInfo {
+		base_stats: Stats { hp: 60, attack: 45, defense: 70, speed: 95, sp_attack: 45, sp_defense: 90 },
+		types: [T::Electric, T::Electric],
+		abilities: [A::RunAway, A::Pickup],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 200,
+		base_friendship: 100,
+		egg_groups: [G::Field, G::Fairy],
+		hatch_cycles: 10,
+	},
+	// 418: Buizel
+	SpeciesInfo {
+		base_stats: Stats { hp: 55, attack: 65, defense: 35, speed: 85, sp_attack: 60, sp_defense: 30 },
+		types: [T::Water, T::Water],
+		abilities: [A::SwiftSwim, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 190,
+		base_friendship: 70,
+		egg_groups: [G::Water1, G::Field],
+		hatch_cycles: 20,
+	},
+	// 419: Floatzel
+	SpeciesInfo {
+		base_stats: Stats { hp: 85, attack: 105, defense: 55, speed: 115, sp_attack: 85, sp_defense: 50 },
+		types: [T::Water, T::Water],
+		abilities: [A::SwiftSwim, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 75,
+		base_friendship: 70,
+		egg_groups: [G::Water1, G::Field],
+		hatch_cycles: 20,
+	},
+	// 420: Cherubi
+	SpeciesInfo {
+		base_stats: Stats { hp: 45, attack: 35, defense: 45, speed: 35, sp_attack: 62, sp_defense: 53 },
+		types: [T::Grass, T::Grass],
+		abilities: [A::Chlorophyll, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 190,
+		base_friendship: 70,
+		egg_groups: [G::Fairy, G::Grass],
+		hatch_cycles: 20,
+	},
+	// 421: Cherrim
+	SpeciesInfo {
+		base_stats: Stats { hp: 70, attack: 60, defense: 70, speed: 85, sp_attack: 87, sp_defense: 78 },
+		types: [T::Grass, T::Grass],
+		abilities: [A::FlowerGift, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 75,
+		base_friendship: 70,
+		egg_groups: [G::Fairy, G::Grass],
+		hatch_cycles: 20,
+	},
+	// 422: Shellos
+	SpeciesInfo {
+		base_stats: Stats { hp: 76, attack: 48, defense: 48, speed: 34, sp_attack: 57, sp_defense: 62 },
+		types: [T::Water, T::Water],
+		abilities: [A::StickyHold, A::StormDrain],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 190,
+		base_friendship: 70,
+		egg_groups: [G::Water1, G::Amorphous],
+		hatch_cycles: 20,
+	},
+	// 423: Gastrodon
+	SpeciesInfo {
+		base_stats: Stats { hp: 111, attack: 83, defense: 68, speed: 39, sp_attack: 92, sp_defense: 82 },
+		types: [T::Water, T::Ground],
+		abilities: [A::StickyHold, A::StormDrain],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 75,
+		base_friendship: 70,
+		egg_groups: [G::Water1, G::Amorphous],
+		hatch_cycles: 20,
+	},
+	// 424: Ambipom
+	SpeciesInfo {
+		base_stats: Stats { hp: 75, attack: 100, defense: 66, speed: 115, sp_attack: 60, sp_defense: 66 },
+		types: [T::Normal, T::Normal],
+		abilities: [A::Technician, A::Pickup],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Fast,
+		catch_rate: 45,
+		base_friendship: 100,
+		egg_groups: [G::Field, G::Field],
+		hatch_cycles: 20,
+	},
+	// 425: Drifloon
+	SpeciesInfo {
+		base_stats: Stats { hp: 90, attack: 50, defense: 34, speed: 70, sp_attack: 60, sp_defense: 44 },
+		types: [T::Ghost, T::Flying],
+		abilities: [A::Aftermath, A::Unburden],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Fluctuating,
+		catch_rate: 125,
+		base_friendship: 70,
+		egg_groups: [G::Amorphous, G::Amorphous],
+		hatch_cycles: 30,
+	},
+	// 426: Drifblim
+	SpeciesInfo {
+		base_stats: Stats { hp: 150, attack: 80, defense: 44, speed: 80, sp_attack: 90, sp_defense: 54 },
+		types: [T::Ghost, T::Flying],
+		abilities: [A::Aftermath, A::Unburden],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Fluctuating,
+		catch_rate: 60,
+		base_friendship: 70,
+		egg_groups: [G::Amorphous, G::Amorphous],
+		hatch_cycles: 30,
+	},
+	// 427: Buneary
+	SpeciesInfo {
+		base_stats: Stats { hp: 55, attack: 66, defense: 44, speed: 85, sp_attack: 44, sp_defense: 56 },
+		types: [T::Normal, T::Normal],
+		abilities: [A::RunAway, A::Klutz],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 190,
+		base_friendship: 0,
+		egg_groups: [G::Field, G::HumanLike],
+		hatch_cycles: 20,
+	},
+	// 428: Lopunny
+	SpeciesInfo {
+		base_stats: Stats { hp: 65, attack: 76, defense: 84, speed: 105, sp_attack: 54, sp_defense: 96 },
+		types: [T::Normal, T::Normal],
+		abilities: [A::CuteCharm, A::Klutz],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 60,
+		base_friendship: 140,
+		egg_groups: [G::Field, G::HumanLike],
+		hatch_cycles: 20,
+	},
+	// 429: Mismagius
+	SpeciesInfo {
+		base_stats: Stats { hp: 60, attack: 60, defense: 60, speed: 105, sp_attack: 105, sp_defense: 105 },
+		types: [T::Ghost, T::Ghost],
+		abilities: [A::Levitate, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Fast,
+		catch_rate: 45,
+		base_friendship: 35,
+		egg_groups: [G::Amorphous, G::Amorphous],
+		hatch_cycles: 25,
+	},
+	// 430: Honchkrow
+	SpeciesInfo {
+		base_stats: Stats { hp: 100, attack: 125, defense: 52, speed: 71, sp_attack: 105, sp_defense: 52 },
+		types: [T::Dark, T::Flying],
+		abilities: [A::Insomnia, A::SuperLuck],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 30,
+		base_friendship: 35,
+		egg_groups: [G::Flying, G::Flying],
+		hatch_cycles: 20,
+	},
+	// 431: Glameow
+	SpeciesInfo {
+		base_stats: Stats { hp: 49, attack: 55, defense: 42, speed: 85, sp_attack: 42, sp_defense: 37 },
+		types: [T::Normal, T::Normal],
+		abilities: [A::Limber, A::OwnTempo],
+		gender_ratio: GenderRatio(191),
+		growth_rate: GrowthRate::Fast,
+		catch_rate: 190,
+		base_friendship: 70,
+		egg_groups: [G::Field, G::Field],
+		hatch_cycles: 20,
+	},
+	// 432: Purugly
+	SpeciesInfo {
+		base_stats: Stats { hp: 71, attack: 82, defense: 64, speed: 112, sp_attack: 64, sp_defense: 59 },
+		types: [T::Normal, T::Normal],
+		abilities: [A::ThickFat, A::OwnTempo],
+		gender_ratio: GenderRatio(191),
+		growth_rate: GrowthRate::Fast,
+		catch_rate: 75,
+		base_friendship: 70,
+		egg_groups: [G::Field, G::Field],
+		hatch_cycles: 20,
+	},
+	// 433: Chingling
+	SpeciesInfo {
+		base_stats: Stats { hp: 45, attack: 30, defense: 50, speed: 45, sp_attack: 65, sp_defense: 50 },
+		types: [T::Psychic, T::Psychic],
+		abilities: [A::Levitate, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Fast,
+		catch_rate: 120,
+		base_friendship: 70,
+		egg_groups: [G::Undiscovered, G::Undiscovered],
+		hatch_cycles: 25,
+	},
+	// 434: Stunky
+	SpeciesInfo {
+		base_stats: Stats { hp: 63, attack: 63, defense: 47, speed: 74, sp_attack: 41, sp_defense: 41 },
+		types: [T::Poison, T::Dark],
+		abilities: [A::Stench, A::Aftermath],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 225,
+		base_friendship: 70,
+		egg_groups: [G::Field, G::Field],
+		hatch_cycles: 20,
+	},
+	// 435: Skuntank
+	SpeciesInfo {
+		base_stats: Stats { hp: 103, attack: 93, defense: 67, speed: 84, sp_attack: 71, sp_defense: 61 },
+		types: [T::Poison, T::Dark],
+		abilities: [A::Stench, A::Aftermath],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 60,
+		base_friendship: 70,
+		egg_groups: [G::Field, G::Field],
+		hatch_cycles: 20,
+	},
+	// 436: Bronzor
+	SpeciesInfo {
+		base_stats: Stats { hp: 57, attack: 24, defense: 86, speed: 23, sp_attack: 24, sp_defense: 86 },
+		types: [T::Steel, T::Psychic],
+		abilities: [A::Levitate, A::Heatproof],
+		gender_ratio: GenderRatio::GENDERLESS,
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 255,
+		base_friendship: 70,
+		egg_groups: [G::Mineral, G::Mineral],
+		hatch_cycles: 20,
+	},
+	// 437: Bronzong
+	SpeciesInfo {
+		base_stats: Stats { hp: 67, attack: 89, defense: 116, speed: 33, sp_attack: 79, sp_defense: 116 },
+		types: [T::Steel, T::Psychic],
+		abilities: [A::Levitate, A::Heatproof],
+		gender_ratio: GenderRatio::GENDERLESS,
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 90,
+		base_friendship: 70,
+		egg_groups: [G::Mineral, G::Mineral],
+		hatch_cycles: 20,
+	},
+	// 438: Bonsly
+	SpeciesInfo {
+		base_stats: Stats { hp: 50, attack: 80, defense: 95, speed: 10, sp_attack: 10, sp_defense: 45 },
+		types: [T::Rock, T::Rock],
+		abilities: [A::Sturdy, A::RockHead],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 255,
+		base_friendship: 70,
+		egg_groups: [G::Undiscovered, G::Undiscovered],
+		hatch_cycles: 20,
+	},
+	// 439: MimeJr
+	SpeciesInfo {
+		base_stats: Stats { hp: 20, attack: 25, defense: 45, speed: 60, sp_attack: 70, sp_defense: 90 },
+		types: [T::Psychic, T::Psychic],
+		abilities: [A::Soundproof, A::Filter],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 145,
+		base_friendship: 70,
+		egg_groups: [G::Undiscovered, G::Undiscovered],
+		hatch_cycles: 25,
+	},
+	// 440: Happiny
+	SpeciesInfo {
+		base_stats: Stats { hp: 100, attack: 5, defense: 5, speed: 30, sp_attack: 15, sp_defense: 65 },
+		types: [T::Normal, T::Normal],
+		abilities: [A::NaturalCure, A::SereneGrace],
+		gender_ratio: GenderRatio::FEMALE_ONLY,
+		growth_rate: GrowthRate::Fast,
+		catch_rate: 130,
+		base_friendship: 140,
+		egg_groups: [G::Undiscovered, G::Undiscovered],
+		hatch_cycles: 40,
+	},
+	// 441: Chatot
+	SpeciesInfo {
+		base_stats: Stats { hp: 76, attack: 65, defense: 45, speed: 91, sp_attack: 92, sp_defense: 42 },
+		types: [T::Normal, T::Flying],
+		abilities: [A::KeenEye, A::TangledFeet],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 30,
+		base_friendship: 35,
+		egg_groups: [G::Flying, G::Flying],
+		hatch_cycles: 20,
+	},
+	// 442: Spiritomb
+	SpeciesInfo {
+		base_stats: Stats { hp: 50, attack: 92, defense: 108, speed: 35, sp_attack: 92, sp_defense: 108 },
+		types: [T::Ghost, T::Dark],
+		abilities: [A::Pressure, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 100,
+		base_friendship: 70,
+		egg_groups: [G::Amorphous, G::Amorphous],
+		hatch_cycles: 30,
+	},
+	// 443: Gible
+	SpeciesInfo {
+		base_stats: Stats { hp: 58, attack: 70, defense: 45, speed: 42, sp_attack: 40, sp_defense: 45 },
+		types: [T::Dragon, T::Ground],
+		abilities: [A::SandVeil, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Monster, G::Dragon],
+		hatch_cycles: 40,
+	},
+	// 444: Gabite
+	SpeciesInfo {
+		base_stats: Stats { hp: 68, attack: 90, defense: 65, speed: 82, sp_attack: 50, sp_defense: 55 },
+		types: [T::Dragon, T::Ground],
+		abilities: [A::SandVeil, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Monster, G::Dragon],
+		hatch_cycles: 40,
+	},
+	// 445: Garchomp
+	SpeciesInfo {
+		base_stats: Stats { hp: 108, attack: 130, defense: 95, speed: 102, sp_attack: 80, sp_defense: 85 },
+		types: [T::Dragon, T::Ground],
+		abilities: [A::SandVeil, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Monster, G::Dragon],
+		hatch_cycles: 40,
+	},
+	// 446: Munchlax
+	SpeciesInfo {
+		base_stats: Stats { hp: 135, attack: 85, defense: 40, speed: 5, sp_attack: 40, sp_defense: 85 },
+		types: [T::Normal, T::Normal],
+		abilities: [A::Pickup, A::ThickFat],
+		gender_ratio: GenderRatio(31),
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 50,
+		base_friendship: 70,
+		egg_groups: [G::Undiscovered, G::Undiscovered],
+		hatch_cycles: 40,
+	},
+	// 447: Riolu
+	SpeciesInfo {
+		base_stats: Stats { hp: 40, attack: 70, defense: 40, speed: 60, sp_attack: 35, sp_defense: 40 },
+		types: [T::Fighting, T::Fighting],
+		abilities: [A::Steadfast, A::InnerFocus],
+		gender_ratio: GenderRatio(31),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 75,
+		base_friendship: 70,
+		egg_groups: [G::Undiscovered, G::Undiscovered],
+		hatch_cycles: 25,
+	},
+	// 448: Lucario
+	SpeciesInfo {
+		base_stats: Stats { hp: 70, attack: 110, defense: 70, speed: 90, sp_attack: 115, sp_defense: 70 },
+		types: [T::Fighting, T::Steel],
+		abilities: [A::Steadfast, A::InnerFocus],
+		gender_ratio: GenderRatio(31),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Field, G::HumanLike],
+		hatch_cycles: 25,
+	},
+	// 449: Hippopotas
+	SpeciesInfo {
+		base_stats: Stats { hp: 68, attack: 72, defense: 78, speed: 32, sp_attack: 38, sp_defense: 42 },
+		types: [T::Ground, T::Ground],
+		abilities: [A::SandStream, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 140,
+		base_friendship: 70,
+		egg_groups: [G::Field, G::Field],
+		hatch_cycles: 30,
+	},
+	// 450: Hippowdon
+	SpeciesInfo {
+		base_stats: Stats { hp: 108, attack: 112, defense: 118, speed: 47, sp_attack: 68, sp_defense: 72 },
+		types: [T::Ground, T::Ground],
+		abilities: [A::SandStream, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 60,
+		base_friendship: 70,
+		egg_groups: [G::Field, G::Field],
+		hatch_cycles: 30,
+	},
+	// 451: Skorupi
+	SpeciesInfo {
+		base_stats: Stats { hp: 40, attack: 50, defense: 90, speed: 65, sp_attack: 30, sp_defense: 55 },
+		types: [T::Poison, T::Bug],
+		abilities: [A::BattleArmor, A::Sniper],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 120,
+		base_friendship: 70,
+		egg_groups: [G::Bug, G::Water3],
+		hatch_cycles: 20,
+	},
+	// 452: Drapion
+	SpeciesInfo {
+		base_stats: Stats { hp: 70, attack: 90, defense: 110, speed: 95, sp_attack: 60, sp_defense: 75 },
+		types: [T::Poison, T::Dark],
+		abilities: [A::BattleArmor, A::Sniper],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Bug, G::Water3],
+		hatch_cycles: 20,
+	},
+	// 453: Croagunk
+	SpeciesInfo {
+		base_stats: Stats { hp: 48, attack: 61, defense: 40, speed: 50, sp_attack: 61, sp_defense: 40 },
+		types: [T::Poison, T::Fighting],
+		abilities: [A::Anticipation, A::DrySkin],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 140,
+		base_friendship: 100,
+		egg_groups: [G::HumanLike, G::HumanLike],
+		hatch_cycles: 10,
+	},
+	// 454: Toxicroak
+	SpeciesInfo {
+		base_stats: Stats { hp: 83, attack: 106, defense: 65, speed: 85, sp_attack: 86, sp_defense: 65 },
+		types: [T::Poison, T::Fighting],
+		abilities: [A::Anticipation, A::DrySkin],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 75,
+		base_friendship: 70,
+		egg_groups: [G::HumanLike, G::HumanLike],
+		hatch_cycles: 10,
+	},
+	// 455: Carnivine
+	SpeciesInfo {
+		base_stats: Stats { hp: 74, attack: 100, defense: 72, speed: 46, sp_attack: 90, sp_defense: 72 },
+		types: [T::Grass, T::Grass],
+		abilities: [A::Levitate, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 200,
+		base_friendship: 70,
+		egg_groups: [G::Grass, G::Grass],
+		hatch_cycles: 25,
+	},
+	// 456: Finneon
+	SpeciesInfo {
+		base_stats: Stats { hp: 49, attack: 49, defense: 56, speed: 66, sp_attack: 49, sp_defense: 61 },
+		types: [T::Water, T::Water],
+		abilities: [A::SwiftSwim, A::StormDrain],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Erratic,
+		catch_rate: 190,
+		base_friendship: 70,
+		egg_groups: [G::Water2, G::Water2],
+		hatch_cycles: 20,
+	},
+	// 457: Lumineon
+	SpeciesInfo {
+		base_stats: Stats { hp: 69, attack: 69, defense: 76, speed: 91, sp_attack: 69, sp_defense: 86 },
+		types: [T::Water, T::Water],
+		abilities: [A::SwiftSwim, A::StormDrain],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Erratic,
+		catch_rate: 75,
+		base_friendship: 70,
+		egg_groups: [G::Water2, G::Water2],
+		hatch_cycles: 20,
+	},
+	// 458: Mantyke
+	SpeciesInfo {
+		base_stats: Stats { hp: 45, attack: 20, defense: 50, speed: 50, sp_attack: 60, sp_defense: 120 },
+		types: [T::Water, T::Flying],
+		abilities: [A::SwiftSwim, A::WaterAbsorb],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 25,
+		base_friendship: 70,
+		egg_groups: [G::Undiscovered, G::Undiscovered],
+		hatch_cycles: 25,
+	},
+	// 459: Snover
+	SpeciesInfo {
+		base_stats: Stats { hp: 60, attack: 62, defense: 50, speed: 40, sp_attack: 62, sp_defense: 60 },
+		types: [T::Grass, T::Ice],
+		abilities: [A::SnowWarning, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 120,
+		base_friendship: 70,
+		egg_groups: [G::Monster, G::Grass],
+		hatch_cycles: 20,
+	},
+	// 460: Abomasnow
+	SpeciesInfo {
+		base_stats: Stats { hp: 90, attack: 92, defense: 75, speed: 60, sp_attack: 92, sp_defense: 85 },
+		types: [T::Grass, T::Ice],
+		abilities: [A::SnowWarning, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 60,
+		base_friendship: 70,
+		egg_groups: [G::Monster, G::Grass],
+		hatch_cycles: 20,
+	},
+	// 461: Weavile
+	SpeciesInfo {
+		base_stats: Stats { hp: 70, attack: 120, defense: 65, speed: 125, sp_attack: 45, sp_defense: 85 },
+		types: [T::Dark, T::Ice],
+		abilities: [A::Pressure, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 45,
+		base_friendship: 35,
+		egg_groups: [G::Field, G::Field],
+		hatch_cycles: 20,
+	},
+	// 462: Magnezone
+	SpeciesInfo {
+		base_stats: Stats { hp: 70, attack: 70, defense: 115, speed: 60, sp_attack: 130, sp_defense: 90 },
+		types: [T::Electric, T::Steel],
+		abilities: [A::MagnetPull, A::Sturdy],
+		gender_ratio: GenderRatio::GENDERLESS,
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 30,
+		base_friendship: 70,
+		egg_groups: [G::Mineral, G::Mineral],
+		hatch_cycles: 20,
+	},
+	// 463: Lickilicky
+	SpeciesInfo {
+		base_stats: Stats { hp: 110, attack: 85, defense: 95, speed: 50, sp_attack: 80, sp_defense: 95 },
+		types: [T::Normal, T::Normal],
+		abilities: [A::OwnTempo, A::Oblivious],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 30,
+		base_friendship: 70,
+		egg_groups: [G::Monster, G::Monster],
+		hatch_cycles: 20,
+	},
+	// 464: Rhyperior
+	SpeciesInfo {
+		base_stats: Stats { hp: 115, attack: 140, defense: 130, speed: 40, sp_attack: 55, sp_defense: 55 },
+		types: [T::Ground, T::Rock],
+		abilities: [A::LightningRod, A::SolidRock],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 30,
+		base_friendship: 70,
+		egg_groups: [G::Monster, G::Field],
+		hatch_cycles: 20,
+	},
+	// 465: Tangrowth
+	SpeciesInfo {
+		base_stats: Stats { hp: 100, attack: 100, defense: 125, speed: 50, sp_attack: 110, sp_defense: 50 },
+		types: [T::Grass, T::Grass],
+		abilities: [A::Chlorophyll, A::LeafGuard],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 30,
+		base_friendship: 70,
+		egg_groups: [G::Grass, G::Grass],
+		hatch_cycles: 20,
+	},
+	// 466: Electivire
+	SpeciesInfo {
+		base_stats: Stats { hp: 75, attack: 123, defense: 67, speed: 95, sp_attack: 95, sp_defense: 85 },
+		types: [T::Electric, T::Electric],
+		abilities: [A::MotorDrive, A::None],
+		gender_ratio: GenderRatio(63),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 30,
+		base_friendship: 70,
+		egg_groups: [G::HumanLike, G::HumanLike],
+		hatch_cycles: 25,
+	},
+	// 467: Magmortar
+	SpeciesInfo {
+		base_stats: Stats { hp: 75, attack: 95, defense: 67, speed: 83, sp_attack: 125, sp_defense: 95 },
+		types: [T::Fire, T::Fire],
+		abilities: [A::FlameBody, A::None],
+		gender_ratio: GenderRatio(63),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 30,
+		base_friendship: 70,
+		egg_groups: [G::HumanLike, G::HumanLike],
+		hatch_cycles: 25,
+	},
+	// 468: Togekiss
+	SpeciesInfo {
+		base_stats: Stats { hp: 85, attack: 50, defense: 95, speed: 80, sp_attack: 120, sp_defense: 115 },
+		types: [T::Normal, T::Flying],
+		abilities: [A::Hustle, A::SereneGrace],
+		gender_ratio: GenderRatio(31),
+		growth_rate: GrowthRate::Fast,
+		catch_rate: 30,
+		base_friendship: 70,
+		egg_groups: [G::Flying, G::Fairy],
+		hatch_cycles: 10,
+	},
+	// 469: Yanmega
+	SpeciesInfo {
+		base_stats: Stats { hp: 86, attack: 76, defense: 86, speed: 95, sp_attack: 116, sp_defense: 56 },
+		types: [T::Bug, T::Flying],
+		abilities: [A::SpeedBoost, A::TintedLens],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 30,
+		base_friendship: 70,
+		egg_groups: [G::Bug, G::Bug],
+		hatch_cycles: 20,
+	},
+	// 470: Leafeon
+	SpeciesInfo {
+		base_stats: Stats { hp: 65, attack: 110, defense: 130, speed: 95, sp_attack: 60, sp_defense: 65 },
+		types: [T::Grass, T::Grass],
+		abilities: [A::LeafGuard, A::None],
+		gender_ratio: GenderRatio(31),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 45,
+		base_friendship: 35,
+		egg_groups: [G::Field, G::Field],
+		hatch_cycles: 35,
+	},
+	// 471: Glaceon
+	SpeciesInfo {
+		base_stats: Stats { hp: 65, attack: 60, defense: 110, speed: 65, sp_attack: 130, sp_defense: 95 },
+		types: [T::Ice, T::Ice],
+		abilities: [A::SnowCloak, A::None],
+		gender_ratio: GenderRatio(31),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 45,
+		base_friendship: 35,
+		egg_groups: [G::Field, G::Field],
+		hatch_cycles: 35,
+	},
+	// 472: Gliscor
+	SpeciesInfo {
+		base_stats: Stats { hp: 75, attack: 95, defense: 125, speed: 95, sp_attack: 45, sp_defense: 75 },
+		types: [T::Ground, T::Flying],
+		abilities: [A::HyperCutter, A::SandVeil],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 30,
+		base_friendship: 70,
+		egg_groups: [G::Bug, G::Bug],
+		hatch_cycles: 20,
+	},
+	// 473: Mamoswine
+	SpeciesInfo {
+		base_stats: Stats { hp: 110, attack: 130, defense: 80, speed: 80, sp_attack: 70, sp_defense: 60 },
+		types: [T::Ice, T::Ground],
+		abilities: [A::Oblivious, A::SnowCloak],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 50,
+		base_friendship: 70,
+		egg_groups: [G::Field, G::Field],
+		hatch_cycles: 20,
+	},
+	// 474: Porygonz
+	SpeciesInfo {
+		base_stats: Stats { hp: 85, attack: 80, defense: 70, speed: 90, sp_attack: 135, sp_defense: 75 },
+		types: [T::Normal, T::Normal],
+		abilities: [A::Adaptability, A::Download],
+		gender_ratio: GenderRatio::GENDERLESS,
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 30,
+		base_friendship: 70,
+		egg_groups: [G::Mineral, G::Mineral],
+		hatch_cycles: 20,
+	},
+	// 475: Gallade
+	SpeciesInfo {
+		base_stats: Stats { hp: 68, attack: 125, defense: 65, speed: 80, sp_attack: 65, sp_defense: 115 },
+		types: [T::Psychic, T::Fighting],
+		abilities: [A::Steadfast, A::None],
+		gender_ratio: GenderRatio::MALE_ONLY,
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 45,
+		base_friendship: 35,
+		egg_groups: [G::Amorphous, G::Amorphous],
+		hatch_cycles: 20,
+	},
+	// 476: Probopass
+	SpeciesInfo {
+		base_stats: Stats { hp: 60, attack: 55, defense: 145, speed: 40, sp_attack: 75, sp_defense: 150 },
+		types: [T::Rock, T::Steel],
+		abilities: [A::Sturdy, A::MagnetPull],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 60,
+		base_friendship: 70,
+		egg_groups: [G::Mineral, G::Mineral],
+		hatch_cycles: 20,
+	},
+	// 477: Dusknoir
+	SpeciesInfo {
+		base_stats: Stats { hp: 45, attack: 100, defense: 135, speed: 45, sp_attack: 65, sp_defense: 135 },
+		types: [T::Ghost, T::Ghost],
+		abilities: [A::Pressure, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Fast,
+		catch_rate: 45,
+		base_friendship: 35,
+		egg_groups: [G::Amorphous, G::Amorphous],
+		hatch_cycles: 25,
+	},
+	// 478: Froslass
+	SpeciesInfo {
+		base_stats: Stats { hp: 70, attack: 80, defense: 70, speed: 110, sp_attack: 80, sp_defense: 70 },
+		types: [T::Ice, T::Ghost],
+		abilities: [A::SnowCloak, A::None],
+		gender_ratio: GenderRatio::FEMALE_ONLY,
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 75,
+		base_friendship: 70,
+		egg_groups: [G::Fairy, G::Mineral],
+		hatch_cycles: 20,
+	},
+	// 479: Rotom
+	SpeciesInfo {
+		base_stats: Stats { hp: 50, attack: 50, defense: 77, speed: 91, sp_attack: 95, sp_defense: 77 },
+		types: [T::Electric, T::Ghost],
+		abilities: [A::Levitate, A::None],
+		gender_ratio: GenderRatio::GENDERLESS,
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Amorphous, G::Amorphous],
+		hatch_cycles: 20,
+	},
+	// 480: Uxie
+	SpeciesInfo {
+		base_stats: Stats { hp: 75, attack: 75, defense: 130, speed: 95, sp_attack: 75, sp_defense: 130 },
+		types: [T::Psychic, T::Psychic],
+		abilities: [A::Levitate, A::None],
+		gender_ratio: GenderRatio::GENDERLESS,
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 3,
+		base_friendship: 140,
+		egg_groups: [G::Undiscovered, G::Undiscovered],
+		hatch_cycles: 80,
+	},
+	// 481: Mesprit
+	SpeciesInfo {
+		base_stats: Stats { hp: 80, attack: 105, defense: 105, speed: 80, sp_attack: 105, sp_defense: 105 },
+		types: [T::Psychic, T::Psychic],
+		abilities: [A::Levitate, A::None],
+		gender_ratio: GenderRatio::GENDERLESS,
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 3,
+		base_friendship: 140,
+		egg_groups: [G::Undiscovered, G::Undiscovered],
+		hatch_cycles: 80,
+	},
+	// 482: Azelf
+	SpeciesInfo {
+		base_stats: Stats { hp: 75, attack: 125, defense: 70, speed: 115, sp_attack: 125, sp_defense: 70 },
+		types: [T::Psychic, T::Psychic],
+		abilities: [A::Levitate, A::None],
+		gender_ratio: GenderRatio::GENDERLESS,
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 3,
+		base_friendship: 140,
+		egg_groups: [G::Undiscovered, G::Undiscovered],
+		hatch_cycles: 80,
+	},
+	// 483: Dialga
+	SpeciesInfo {
+		base_stats: Stats { hp: 100, attack: 120, defense: 120, speed: 90, sp_attack: 150, sp_defense: 100 },
+		types: [T::Steel, T::Dragon],
+		abilities: [A::Pressure, A::None],
+		gender_ratio: GenderRatio::GENDERLESS,
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 30,
+		base_friendship: 0,
+		egg_groups: [G::Undiscovered, G::Undiscovered],
+		hatch_cycles: 120,
+	},
+	// 484: Palkia
+	SpeciesInfo {
+		base_stats: Stats { hp: 90, attack: 120, defense: 100, speed: 100, sp_attack: 150, sp_defense: 120 },
+		types: [T::Water, T::Dragon],
+		abilities: [A::Pressure, A::None],
+		gender_ratio: GenderRatio::GENDERLESS,
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 30,
+		base_friendship: 0,
+		egg_groups: [G::Undiscovered, G::Undiscovered],
+		hatch_cycles: 120,
+	},
+	// 485: Heatran
+	SpeciesInfo {
+		base_stats: Stats { hp: 91, attack: 90, defense: 106, speed: 77, sp_attack: 130, sp_defense: 106 },
+		types: [T::Fire, T::Steel],
+		abilities: [A::FlashFire, A::None],
+		gender_ratio: GenderRatio(127),
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 3,
+		base_friendship: 100,
+		egg_groups: [G::Undiscovered, G::Undiscovered],
+		hatch_cycles: 10,
+	},
+	// 486: Regigigas
+	SpeciesInfo {
+		base_stats: Stats { hp: 110, attack: 160, defense: 110, speed: 100, sp_attack: 80, sp_defense: 110 },
+		types: [T::Normal, T::Normal],
+		abilities: [A::SlowStart, A::None],
+		gender_ratio: GenderRatio::GENDERLESS,
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 3,
+		base_friendship: 0,
+		egg_groups: [G::Undiscovered, G::Undiscovered],
+		hatch_cycles: 120,
+	},
+	// 487: Giratina
+	SpeciesInfo {
+		base_stats: Stats { hp: 150, attack: 100, defense: 120, speed: 90, sp_attack: 100, sp_defense: 120 },
+		types: [T::Ghost, T::Dragon],
+		abilities: [A::Pressure, A::None],
+		gender_ratio: GenderRatio::GENDERLESS,
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 3,
+		base_friendship: 0,
+		egg_groups: [G::Undiscovered, G::Undiscovered],
+		hatch_cycles: 120,
+	},
+	// 488: Cresselia
+	SpeciesInfo {
+		base_stats: Stats { hp: 120, attack: 70, defense: 120, speed: 85, sp_attack: 75, sp_defense: 130 },
+		types: [T::Psychic, T::Psychic],
+		abilities: [A::Levitate, A::None],
+		gender_ratio: GenderRatio::GENDERLESS,
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 3,
+		base_friendship: 100,
+		egg_groups: [G::Undiscovered, G::Undiscovered],
+		hatch_cycles: 120,
+	},
+	// 489: Phione
+	SpeciesInfo {
+		base_stats: Stats { hp: 80, attack: 80, defense: 80, speed: 80, sp_attack: 80, sp_defense: 80 },
+		types: [T::Water, T::Water],
+		abilities: [A::Hydration, A::None],
+		gender_ratio: GenderRatio::GENDERLESS,
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 30,
+		base_friendship: 70,
+		egg_groups: [G::Water1, G::Fairy],
+		hatch_cycles: 40,
+	},
+	// 490: Manaphy
+	SpeciesInfo {
+		base_stats: Stats { hp: 100, attack: 100, defense: 100, speed: 100, sp_attack: 100, sp_defense: 100 },
+		types: [T::Water, T::Water],
+		abilities: [A::Hydration, A::None],
+		gender_ratio: GenderRatio::GENDERLESS,
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 3,
+		base_friendship: 70,
+		egg_groups: [G::Water1, G::Fairy],
+		hatch_cycles: 10,
+	},
+	// 491: Darkrai
+	SpeciesInfo {
+		base_stats: Stats { hp: 70, attack: 90, defense: 90, speed: 125, sp_attack: 135, sp_defense: 90 },
+		types: [T::Dark, T::Dark],
+		abilities: [A::BadDreams, A::None],
+		gender_ratio: GenderRatio::GENDERLESS,
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 3,
+		base_friendship: 0,
+		egg_groups: [G::Undiscovered, G::Undiscovered],
+		hatch_cycles: 120,
+	},
+	// 492: Shaymin
+	SpeciesInfo {
+		base_stats: Stats { hp: 100, attack: 100, defense: 100, speed: 100, sp_attack: 100, sp_defense: 100 },
+		types: [T::Grass, T::Grass],
+		abilities: [A::NaturalCure, A::None],
+		gender_ratio: GenderRatio::GENDERLESS,
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 45,
+		base_friendship: 100,
+		egg_groups: [G::Undiscovered, G::Undiscovered],
+		hatch_cycles: 120,
+	},
+	// 493: Arceus
+	SpeciesInfo {
+		base_stats: Stats { hp: 120, attack: 120, defense: 120, speed: 120, sp_attack: 120, sp_defense: 120 },
+		types: [T::Normal, T::Normal],
+		abilities: [A::Multitype, A::None],
+		gender_ratio: GenderRatio::GENDERLESS,
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 3,
+		base_friendship: 0,
+		egg_groups: [G::Undiscovered, G::Undiscovered],
+		hatch_cycles: 120,
+	},
+];
+
+/// The alternate forms whose info differs from their species', by species & form
+pub(super) const FORMS: [(Species, u8, SpeciesInfo); 12] = [
+	(Species::Deoxys, 1, SpeciesInfo {
+		base_stats: Stats { hp: 50, attack: 180, defense: 20, speed: 150, sp_attack: 180, sp_defense: 20 },
+		types: [T::Psychic, T::Psychic],
+		abilities: [A::Pressure, A::None],
+		gender_ratio: GenderRatio::GENDERLESS,
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 3,
+		base_friendship: 0,
+		egg_groups: [G::Undiscovered, G::Undiscovered],
+		hatch_cycles: 120,
+	}),
+	(Species::Deoxys, 2, SpeciesInfo {
+		base_stats: Stats { hp: 50, attack: 70, defense: 160, speed: 90, sp_attack: 70, sp_defense: 160 },
+		types: [T::Psychic, T::Psychic],
+		abilities: [A::Pressure, A::None],
+		gender_ratio: GenderRatio::GENDERLESS,
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 3,
+		base_friendship: 0,
+		egg_groups: [G::Undiscovered, G::Undiscovered],
+		hatch_cycles: 120,
+	}),
+	(Species::Deoxys, 3, SpeciesInfo {
+		base_stats: Stats { hp: 50, attack: 95, defense: 90, speed: 180, sp_attack: 95, sp_defense: 90 },
+		types: [T::Psychic, T::Psychic],
+		abilities: [A::Pressure, A::None],
+		gender_ratio: GenderRatio::GENDERLESS,
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 3,
+		base_friendship: 0,
+		egg_groups: [G::Undiscovered, G::Undiscovered],
+		hatch_cycles: 120,
+	}),
+	(Species::Wormadam, 1, SpeciesInfo {
+		base_stats: Stats { hp: 60, attack: 79, defense: 105, speed: 36, sp_attack: 59, sp_defense: 85 },
+		types: [T::Bug, T::Ground],
+		abilities: [A::Anticipation, A::None],
+		gender_ratio: GenderRatio::FEMALE_ONLY,
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Bug, G::Bug],
+		hatch_cycles: 15,
+	}),
+	(Species::Wormadam, 2, SpeciesInfo {
+		base_stats: Stats { hp: 60, attack: 69, defense: 95, speed: 36, sp_attack: 69, sp_defense: 95 },
+		types: [T::Bug, T::Steel],
+		abilities: [A::Anticipation, A::None],
+		gender_ratio: GenderRatio::FEMALE_ONLY,
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Bug, G::Bug],
+		hatch_cycles: 15,
+	}),
+	(Species::Giratina, 1, SpeciesInfo {
+		base_stats: Stats { hp: 150, attack: 120, defense: 100, speed: 90, sp_attack: 120, sp_defense: 100 },
+		types: [T::Ghost, T::Dragon],
+		abilities: [A::Levitate, A::None],
+		gender_ratio: GenderRatio::GENDERLESS,
+		growth_rate: GrowthRate::Slow,
+		catch_rate: 3,
+		base_friendship: 0,
+		egg_groups: [G::Undiscovered, G::Undiscovered],
+		hatch_cycles: 120,
+	}),
+	(Species::Shaymin, 1, SpeciesInfo {
+		base_stats: Stats { hp: 100, attack: 103, defense: 75, speed: 127, sp_attack: 120, sp_defense: 75 },
+		types: [T::Grass, T::Flying],
+		abilities: [A::SereneGrace, A::None],
+		gender_ratio: GenderRatio::GENDERLESS,
+		growth_rate: GrowthRate::MediumSlow,
+		catch_rate: 45,
+		base_friendship: 100,
+		egg_groups: [G::Undiscovered, G::Undiscovered],
+		hatch_cycles: 120,
+	}),
+	(Species::Rotom, 1, SpeciesInfo {
+		base_stats: Stats { hp: 50, attack: 65, defense: 107, speed: 86, sp_attack: 105, sp_defense: 107 },
+		types: [T::Electric, T::Ghost],
+		abilities: [A::Levitate, A::None],
+		gender_ratio: GenderRatio::GENDERLESS,
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Amorphous, G::Amorphous],
+		hatch_cycles: 20,
+	}),
+	(Species::Rotom, 2, SpeciesInfo {
+		base_stats: Stats { hp: 50, attack: 65, defense: 107, speed: 86, sp_attack: 105, sp_defense: 107 },
+		types: [T::Electric, T::Ghost],
+		abilities: [A::Levitate, A::None],
+		gender_ratio: GenderRatio::GENDERLESS,
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Amorphous, G::Amorphous],
+		hatch_cycles: 20,
+	}),
+	(Species::Rotom, 3, SpeciesInfo {
+		base_stats: Stats { hp: 50, attack: 65, defense: 107, speed: 86, sp_attack: 105, sp_defense: 107 },
+		types: [T::Electric, T::Ghost],
+		abilities: [A::Levitate, A::None],
+		gender_ratio: GenderRatio::GENDERLESS,
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Amorphous, G::Amorphous],
+		hatch_cycles: 20,
+	}),
+	(Species::Rotom, 4, SpeciesInfo {
+		base_stats: Stats { hp: 50, attack: 65, defense: 107, speed: 86, sp_attack: 105, sp_defense: 107 },
+		types: [T::Electric, T::Ghost],
+		abilities: [A::Levitate, A::None],
+		gender_ratio: GenderRatio::GENDERLESS,
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Amorphous, G::Amorphous],
+		hatch_cycles: 20,
+	}),
+	(Species::Rotom, 5, SpeciesInfo {
+		base_stats: Stats { hp: 50, attack: 65, defense: 107, speed: 86, sp_attack: 105, sp_defense: 107 },
+		types: [T::Electric, T::Ghost],
+		abilities: [A::Levitate, A::None],
+		gender_ratio: GenderRatio::GENDERLESS,
+		growth_rate: GrowthRate::MediumFast,
+		catch_rate: 45,
+		base_friendship: 70,
+		egg_groups: [G::Amorphous, G::Amorphous],
+		hatch_cycles: 20,
+	}),
+];
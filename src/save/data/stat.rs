@@ -0,0 +1,10 @@
+/// Which of the six stats, in the order they're stored
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Stat {
+    Hp,
+    Attack,
+    Defense,
+    Speed,
+    SpAttack,
+    SpDefense,
+}
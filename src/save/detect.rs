@@ -0,0 +1,310 @@
+use crate::save::data::badge::Badge;
+use crate::save::error::{Block, ReadError};
+use crate::save::format::container::Container;
+use crate::save::format::dppt::block::select_block;
+use crate::save::format::dppt::save::{Game, Gen4Save};
+use crate::save::format::dppt::sinnoh::{self, Layout};
+use crate::save::format::gen12::{self, Gen12Save};
+use crate::save::format::gen3::{self, sector, Gen3Save};
+use crate::save::format::gen5::{self, Gen5Save};
+use crate::save::format::ParseOptions;
+use crate::save::save::{Playtime, SaveFile, SaveGame};
+use byteorder::{ByteOrder, LittleEndian};
+use std::path::PathBuf;
+
+/// What kind of save a file holds, as far as can be told without reading all of it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Format {
+    /// Diamond, Pearl, Platinum, HeartGold or SoulSilver
+    Gen4(Game),
+    /// Ruby, Sapphire, Emerald, FireRed or LeafGreen
+    Gen3(gen3::Game),
+    /// Black, White, Black 2 or White 2
+    Gen5(gen5::Game),
+    /// Red, Blue, Yellow, Gold, Silver or Crystal
+    Gen12(gen12::Game),
+}
+
+/// A save from any game we can read, see `open`
+#[derive(Debug, PartialEq)]
+#[non_exhaustive]
+pub enum Save {
+    Gen4(Gen4Save),
+    Gen3(Gen3Save),
+    Gen5(Gen5Save),
+    Gen12(Gen12Save),
+}
+
+impl Save {
+    pub fn format(&self) -> Format {
+        match self {
+            Save::Gen4(save) => Format::Gen4(save.game),
+            Save::Gen3(save) => Format::Gen3(save.game),
+            Save::Gen5(save) => Format::Gen5(save.game),
+            Save::Gen12(save) => Format::Gen12(save.game),
+        }
+    }
+
+    /// What the save was wrapped in when it was read, and will be written back in
+    pub fn container(&self) -> &Container {
+        match self {
+            Save::Gen4(save) => save.raw.container(),
+            Save::Gen3(save) => &save.container,
+            Save::Gen5(save) => &save.container,
+            Save::Gen12(save) => &save.container,
+        }
+    }
+
+    /// The parts of the save that every generation has
+    pub fn base(&self) -> &SaveFile {
+        match self {
+            Save::Gen4(save) => &save.base,
+            Save::Gen3(save) => &save.base,
+            Save::Gen5(save) => &save.base,
+            Save::Gen12(save) => &save.base,
+        }
+    }
+
+    fn save_game(&self) -> &dyn SaveGame {
+        match self {
+            Save::Gen4(save) => save,
+            Save::Gen3(save) => save,
+            Save::Gen5(save) => save,
+            Save::Gen12(save) => save,
+        }
+    }
+}
+
+impl SaveGame for Save {
+    fn base(&self) -> &SaveFile {
+        self.save_game().base()
+    }
+
+    fn playtime(&self) -> Playtime {
+        self.save_game().playtime()
+    }
+
+    fn badges(&self) -> &[Badge] {
+        self.save_game().badges()
+    }
+}
+
+/// Work out which game `bytes` is a save from. The save can be in any `Container`.
+///
+/// A gen 4 save is recognised by a general block with a valid footer where DP, Pt or HGSS put it, which
+/// also tells their block lengths apart. The game version in the player block then picks the game; if it
+/// doesn't match the layout, the layout wins.
+///
+/// A gen 5 save is recognised by a valid checksum table where BW or B2W2 put it, and the game by the
+/// game version in the trainer block, in the same way.
+///
+/// Otherwise, a gen 3 save is recognised by a complete set of valid sectors, and the game by its game
+/// code; see `gen3::read_save`.
+///
+/// Last, a gen 1 or 2 save is recognised by a valid checksum where RBY, GS or Crystal put it, as long as
+/// the file isn't much bigger than the 32 KiB of a Game Boy cartridge's SRAM.
+pub fn detect(bytes: &[u8]) -> Option<Format> {
+    detect_gen4(bytes)
+        .or_else(|| detect_gen5(bytes))
+        .or_else(|| detect_gen3(bytes))
+        .or_else(|| detect_gen12(bytes))
+}
+
+fn detect_gen4(bytes: &[u8]) -> Option<Format> {
+    let (_, image) = Container::unwrap(bytes.to_vec(), sinnoh::SAVE_SIZE);
+    let layout = Layout::detect(&image)?;
+    let general = select_block(&image, Block::General, 0x00, layout.general_size, layout.footer).ok()?;
+    let game = image.get(general.slot.offset() + layout.trainer + 0x1C)
+        .and_then(|code| Game::try_from(*code).ok())
+        .filter(|game| layout.games.contains(game))
+        .unwrap_or(layout.games[0]);
+
+    Some(Format::Gen4(game))
+}
+
+fn detect_gen5(bytes: &[u8]) -> Option<Format> {
+    let (_, image) = Container::unwrap(bytes.to_vec(), gen5::block::SAVE_SIZE);
+    let layout = gen5::Layout::detect(&image)?;
+    let trainer = gen5::block::select_block(&image, Block::Player, layout.trainer, layout.backup).ok()?.1;
+    let game = gen5::Game::try_from(image[trainer.offset + 0x1F]).ok()
+        .filter(|game| layout.games.contains(game))
+        .unwrap_or(layout.games[0]);
+
+    Some(Format::Gen5(game))
+}
+
+fn detect_gen3(bytes: &[u8]) -> Option<Format> {
+    let (_, image) = Container::unwrap(bytes.to_vec(), sector::SAVE_SIZE);
+    let sections = sector::select_sections(&image).ok()?;
+    let trainer_info = sections.data(&image, 0);
+    let game_code = LittleEndian::read_u32(&trainer_info[0xAC..]);
+    Some(Format::Gen3(gen3::Game::from_code(game_code)))
+}
+
+fn detect_gen12(bytes: &[u8]) -> Option<Format> {
+    // a gen 1 checksum is a single byte, so only trust it in files the right size
+    if bytes.len() > gen12::SAVE_SIZE + gen12::MAX_RTC_SIZE {
+        return None;
+    }
+    let (_, image) = Container::unwrap(bytes.to_vec(), gen12::SAVE_SIZE);
+    gen12::Game::detect(&image).map(Format::Gen12)
+}
+
+/// Read a save from any supported game from `path`, see `detect`
+pub fn open(path: impl Into<PathBuf>) -> Result<Save, ReadError> {
+    open_with_options(path, ParseOptions::default())
+}
+
+/// Read a save from any supported game from `path`, see `detect` & `ParseOptions`
+pub fn open_with_options(path: impl Into<PathBuf>, options: ParseOptions) -> Result<Save, ReadError> {
+    let data = std::fs::read(path.into())?;
+    from_bytes_with_options(data, options)
+}
+
+/// Read a save from any supported game from the contents of a file, see `detect`
+pub fn from_bytes(data: Vec<u8>) -> Result<Save, ReadError> {
+    from_bytes_with_options(data, ParseOptions::default())
+}
+
+/// Read a save from any supported game from the contents of a file, see `detect` & `ParseOptions`
+pub fn from_bytes_with_options(data: Vec<u8>, options: ParseOptions) -> Result<Save, ReadError> {
+    match detect(&data) {
+        Some(Format::Gen4(game)) => Ok(Save::Gen4(sinnoh::read_save_from_bytes(data, options, Layout::of(game))?)),
+        Some(Format::Gen3(_)) => Ok(Save::Gen3(gen3::read_save_from_bytes(data, options)?)),
+        Some(Format::Gen5(_)) => Ok(Save::Gen5(gen5::read_save_from_bytes(data, options)?)),
+        Some(Format::Gen12(_)) => Ok(Save::Gen12(gen12::read_save_from_bytes(data, options)?)),
+        None => Err(ReadError::UnknownFormat { size: data.len() }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Is something that isn't a save rejected?
+    #[test]
+    fn detect_unknown() {
+        assert_eq!(detect(&[]), None);
+        assert_eq!(detect(&vec![0xFFu8; 0x80000]), None);
+        assert!(matches!(from_bytes(vec![0u8; 0x80000]), Err(ReadError::UnknownFormat { size: 0x80000 })));
+        assert!(matches!(open("test-files/missing.sav"), Err(ReadError::Io(_))));
+    }
+
+    /// Is every gen 4 game told apart, and read with the right layout?
+    #[cfg(feature = "write")]
+    #[test]
+    fn detect_gen4() {
+        for game in [Game::Diamond, Game::Pearl, Game::Platinum, Game::HeartGold, Game::SoulSilver] {
+            let written = sinnoh::write_save_to_bytes(&sinnoh::test_save(game)).unwrap();
+            assert_eq!(detect(&written), Some(Format::Gen4(game)));
+
+            let read = from_bytes(written).unwrap();
+            assert_eq!(read.format(), Format::Gen4(game));
+            assert_eq!(read.container(), &Container::Raw);
+            assert_eq!(read.base().trainer().name(), "ELLie");
+        }
+    }
+
+    /// Is every gen 3 game told apart, whatever it's wrapped in?
+    #[test]
+    fn detect_gen3() {
+        for game in [gen3::Game::RubySapphire, gen3::Game::Emerald, gen3::Game::FireRedLeafGreen] {
+            let data = gen3::tests::test_save_bytes(game);
+            assert_eq!(detect(&data), Some(Format::Gen3(game)));
+
+            let mut trimmed = data.clone();
+            trimmed.truncate(2 * sector::SECTOR_COUNT * sector::SECTOR_SIZE);
+            let read = from_bytes(trimmed).unwrap();
+            assert_eq!(read.format(), Format::Gen3(game));
+            assert!(matches!(read.container(), Container::Trimmed { .. }));
+            assert_eq!(read.base().trainer().name(), "Lena");
+        }
+    }
+
+    /// Is every gen 5 game told apart?
+    #[test]
+    fn detect_gen5() {
+        for game in [gen5::Game::Black, gen5::Game::White, gen5::Game::Black2, gen5::Game::White2] {
+            let data = gen5::tests::test_save_bytes(game);
+            assert_eq!(detect(&data), Some(Format::Gen5(game)));
+
+            let read = from_bytes(data).unwrap();
+            assert_eq!(read.format(), Format::Gen5(game));
+            assert_eq!(read.base().trainer().name(), "Hilda");
+        }
+    }
+
+    /// Are gen 1 & 2 saves recognised, with or without an emulator's clock after them?
+    #[test]
+    fn detect_gen12() {
+        for game in [gen12::Game::RedBlueYellow, gen12::Game::GoldSilver, gen12::Game::Crystal] {
+            let mut data = gen12::tests::test_save_bytes(game);
+            assert_eq!(detect(&data), Some(Format::Gen12(game)));
+
+            data.extend_from_slice(&[0; 0x30]);
+            let read = from_bytes(data).unwrap();
+            assert_eq!(read.format(), Format::Gen12(game));
+            assert_eq!(read.container(), &Container::Padded { padding: vec![0; 0x30] });
+            assert_eq!(read.base().trainer().name(), "RED");
+        }
+        assert_eq!(detect(&vec![0u8; gen12::SAVE_SIZE]), None);
+    }
+
+    /// Can a save from any generation be looked through as a `SaveGame`, without knowing which it is?
+    #[test]
+    fn browse_any_generation() {
+        fn summary(save: &impl SaveGame) -> (String, usize, Vec<Badge>, u16) {
+            (save.trainer().name().to_string(), save.party().len(), save.badges().to_vec(), save.playtime().hours)
+        }
+
+        let gen3 = from_bytes(gen3::tests::test_save_bytes(gen3::Game::Emerald)).unwrap();
+        let gen5 = from_bytes(gen5::tests::test_save_bytes(gen5::Game::Black2)).unwrap();
+        let gen12 = from_bytes(gen12::tests::test_save_bytes(gen12::Game::Crystal)).unwrap();
+        assert_eq!(summary(&gen3), ("Lena".to_string(), 1, vec![Badge::Stone, Badge::Rain], 12));
+        assert_eq!(summary(&gen5), ("Hilda".to_string(), 1, vec![Badge::Basic, Badge::Toxic], 99));
+        assert_eq!(summary(&gen12), ("RED".to_string(), 1, vec![Badge::Zephyr, Badge::Rising, Badge::Boulder], 12));
+    }
+
+    /// Are saves found inside a container, and written back in it?
+    #[cfg(feature = "write")]
+    #[test]
+    fn read_and_write_containers() {
+        use crate::save::format::dppt::block::PARTITION_SIZE;
+        use crate::save::error::WriteError;
+
+        // the test save is written to the second partition; move it to the first, and trim off the rest
+        let mut trimmed = sinnoh::write_save_to_bytes(&sinnoh::test_save(Game::Platinum)).unwrap();
+        trimmed.copy_within(PARTITION_SIZE.., 0);
+        trimmed.truncate(PARTITION_SIZE);
+
+        let desmume_footer = [&b"|<--Snip above here to create a raw sav by excluding this DeSmuME savedata footer:"[..], &[0; 24], b"|-DESMUME SAVE-|"].concat();
+        let dsv = [sinnoh::write_save_to_bytes(&sinnoh::test_save(Game::HeartGold)).unwrap(), desmume_footer.clone()].concat();
+        // a 256 KiB save, as DeSmuME keeps it for a smaller flash chip
+        let trimmed_dsv = [trimmed.clone(), desmume_footer].concat();
+
+        let mut padded = sinnoh::write_save_to_bytes(&sinnoh::test_save(Game::Diamond)).unwrap();
+        padded.resize(2 * sinnoh::SAVE_SIZE, 0xFF);
+
+        for data in [trimmed, dsv, padded, trimmed_dsv] {
+            let Save::Gen4(mut save) = from_bytes(data.clone()).unwrap() else { panic!("not read as gen 4") };
+            assert_ne!(save.raw.container(), &Container::Raw);
+
+            let (badge, foreign) = match save.game {
+                Game::HeartGold | Game::SoulSilver => (Badge::Zephyr, Badge::Coal),
+                _ => (Badge::Coal, Badge::Zephyr),
+            };
+            save.badges.push(foreign);
+            assert!(matches!(sinnoh::write_save_to_bytes(&save), Err(WriteError::InvalidBadge(b)) if b == foreign));
+
+            save.badges = vec![badge];
+            let written = sinnoh::write_save_to_bytes(&save).unwrap();
+            assert_eq!(written.len(), data.len());
+            assert_eq!(&written[written.len() - 16..], &data[data.len() - 16..]);
+
+            let Save::Gen4(read) = from_bytes(written).unwrap() else { panic!("not read as gen 4") };
+            assert_eq!(read.raw.container(), save.raw.container());
+            assert_eq!(read.badges, vec![badge]);
+        }
+    }
+}
@@ -1,5 +1,240 @@
+use std::error::Error;
+use std::fmt;
+use std::io;
+#[cfg(feature = "write")]
+use crate::save::data::badge::Badge;
+#[cfg(feature = "write")]
+use crate::save::data::pocket::Pocket;
+use crate::save::format::BlockStatus;
+
+/// A part of a save, used to give errors some context
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Block {
+    /// The whole general block, i.e. its footer
+    General,
+    System,
+    Player,
+    Party,
+    Bag,
+    /// Script variables & flags
+    Events,
+    /// The whole storage block, and the boxes within it
+    Storage,
+    /// A Pokémon outside of a save, e.g. a `.pk4` file
+    Pokemon,
+    /// A gen 3 sector, by the ID of the section it holds
+    Sector(u16),
+}
+
+impl fmt::Display for Block {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(self, f)
+    }
+}
+
+/// A value that doesn't match any variant of the enum `name`, e.g. a species ID past the end of the Pokédex
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UnknownValue<T> {
+    pub name: &'static str,
+    pub value: T,
+}
+
+impl<T: fmt::Display> fmt::Display for UnknownValue<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown {} {}", self.name, self.value)
+    }
+}
+
+impl<T: fmt::Debug + fmt::Display> Error for UnknownValue<T> {}
+
 #[derive(Debug)]
 pub enum ReadError {
-    FileNotFound,
-    Generic
+    /// The save couldn't be read, e.g. the file doesn't exist
+    Io(io::Error),
+    /// The save ended while reading `field`
+    Truncated {
+        block: Block,
+        offset: usize,
+        field: &'static str,
+        source: io::Error,
+    },
+    /// `field` holds a value we don't understand
+    InvalidValue {
+        block: Block,
+        offset: usize,
+        field: &'static str,
+        value: u64,
+    },
+    /// Neither copy of a general or storage block passed validation
+    NoValidBlock {
+        block: Block,
+        offset: usize,
+        first: BlockStatus,
+        second: BlockStatus,
+    },
+    /// A Pokémon wasn't `BOX_POKEMON_SIZE` or `PARTY_POKEMON_SIZE` bytes long
+    InvalidPokemonSize(usize),
+    /// The file isn't a save from any game we can read
+    UnknownFormat { size: usize },
+    /// A Pokémon can't be added to the party, as it already has 6
+    PartyFull,
+    /// There's no box with this index
+    InvalidBox(usize),
+    /// A slot index past the end of its box
+    InvalidSlot(usize),
+}
+
+/// Something in a save that was skipped over or replaced when reading leniently. In strict mode, this is
+/// returned as a `ReadError::InvalidValue` instead.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    pub block: Block,
+    /// Absolute offset of the field within the save
+    pub offset: usize,
+    pub field: &'static str,
+    pub value: u64,
+}
+
+impl fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid {} {:#X} ({} block, offset {:#07X})", self.field, self.value, self.block, self.offset)
+    }
+}
+
+impl From<Diagnostic> for ReadError {
+    fn from(value: Diagnostic) -> Self {
+        ReadError::InvalidValue { block: value.block, offset: value.offset, field: value.field, value: value.value }
+    }
+}
+
+impl From<io::Error> for ReadError {
+    fn from(value: io::Error) -> Self {
+        ReadError::Io(value)
+    }
+}
+
+impl fmt::Display for ReadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ReadError::Io(e) => write!(f, "could not read save: {}", e),
+            ReadError::Truncated { block, offset, field, .. } =>
+                write!(f, "save ended while reading {} ({} block, offset {:#07X})", field, block, offset),
+            ReadError::InvalidValue { block, offset, field, value } =>
+                write!(f, "invalid {} {:#X} ({} block, offset {:#07X})", field, value, block, offset),
+            ReadError::NoValidBlock { block, offset, first, second } =>
+                write!(f, "no valid copy of the {} block at offset {:#07X} (first: {:?}, second: {:?})", block, offset, first, second),
+            ReadError::InvalidPokemonSize(size) => write!(f, "a Pokémon can't be {} bytes long", size),
+            ReadError::UnknownFormat { size } => write!(f, "not a save from a supported game ({} bytes)", size),
+            ReadError::PartyFull => write!(f, "the party is full"),
+            ReadError::InvalidBox(index) => write!(f, "there's no box {}", index),
+            ReadError::InvalidSlot(index) => write!(f, "there's no slot {} in the box", index),
+        }
+    }
+}
+
+impl Error for ReadError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ReadError::Io(e) | ReadError::Truncated { source: e, .. } => Some(e),
+            _ => None,
+        }
+    }
+}
+
+/// Why a string couldn't be encoded as a game's character codes
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EncodeError {
+    /// The string is `length` characters long, but its field only fits `max`
+    TooLong { length: usize, max: usize },
+    /// The game has no character code for this
+    UnsupportedChar(char),
+    /// A `\` or `{` at byte `position` doesn't start a valid escape
+    InvalidEscape { position: usize },
+}
+
+impl fmt::Display for EncodeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EncodeError::TooLong { length, max } => write!(f, "{} characters is longer than the {} that fit", length, max),
+            EncodeError::UnsupportedChar(c) => write!(f, "{:?} can't be displayed", c),
+            EncodeError::InvalidEscape { position } => write!(f, "invalid escape at byte {}", position),
+        }
+    }
+}
+
+impl Error for EncodeError {}
+
+#[cfg(feature = "write")]
+#[derive(Debug)]
+pub enum WriteError {
+    /// The save couldn't be written, e.g. the file isn't writable
+    Io(io::Error),
+    /// A string is too long for its field, or contains characters the game can't display
+    InvalidString(EncodeError),
+    /// A Pokémon wasn't `BOX_POKEMON_SIZE` or `PARTY_POKEMON_SIZE` bytes long
+    InvalidPokemonSize(usize),
+    /// There's no Pokémon with gen 4 data in the slot being exported
+    MissingPokemon,
+    /// A pocket has more kinds of item than its `capacity` slots
+    PocketFull { pocket: Pocket, capacity: usize },
+    /// A `length` byte field at `offset` runs past the end of its block
+    Truncated { offset: usize, length: usize },
+    /// A badge from a region the game doesn't have, e.g. a Johto badge in Platinum
+    InvalidBadge(Badge),
+    /// A value the game doesn't have, e.g. a species from a later generation
+    UnknownValue(UnknownValue<u16>),
+    /// The party has this many Pokémon, counting corrupt slots, but only fits `PARTY_SIZE`
+    PartyFull(usize),
+}
+
+#[cfg(feature = "write")]
+impl From<EncodeError> for WriteError {
+    fn from(value: EncodeError) -> Self {
+        WriteError::InvalidString(value)
+    }
+}
+
+#[cfg(feature = "write")]
+impl From<UnknownValue<u16>> for WriteError {
+    fn from(value: UnknownValue<u16>) -> Self {
+        WriteError::UnknownValue(value)
+    }
+}
+
+#[cfg(feature = "write")]
+impl From<io::Error> for WriteError {
+    fn from(value: io::Error) -> Self {
+        WriteError::Io(value)
+    }
+}
+
+#[cfg(feature = "write")]
+impl fmt::Display for WriteError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            WriteError::Io(e) => write!(f, "could not write save: {}", e),
+            WriteError::InvalidString(e) => write!(f, "string can't be encoded: {}", e),
+            WriteError::InvalidPokemonSize(size) => write!(f, "a Pokémon can't be {} bytes long", size),
+            WriteError::MissingPokemon => write!(f, "no gen 4 Pokémon to write"),
+            WriteError::PocketFull { pocket, capacity } =>
+                write!(f, "too many items for the {:?} pocket, which has {} slots", pocket, capacity),
+            WriteError::Truncated { offset, length } =>
+                write!(f, "block ended while writing {} bytes at offset {:#07X}", length, offset),
+            WriteError::InvalidBadge(badge) => write!(f, "the {:?} badge isn't from this game's region", badge),
+            WriteError::UnknownValue(e) => write!(f, "{} isn't in this game", e),
+            WriteError::PartyFull(count) => write!(f, "{} Pokémon don't fit in the party", count),
+        }
+    }
+}
+
+#[cfg(feature = "write")]
+impl Error for WriteError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            WriteError::Io(e) => Some(e),
+            WriteError::InvalidString(e) => Some(e),
+            WriteError::UnknownValue(e) => Some(e),
+            _ => None,
+        }
+    }
 }
\ No newline at end of file
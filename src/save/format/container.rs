@@ -0,0 +1,126 @@
+/// Written by DeSmuME before its footer. Everything from here on isn't part of the save.
+const DESMUME_SNIP: &[u8] = b"|<--Snip above here to create a raw sav by excluding this DeSmuME savedata footer:";
+/// The last bytes of every `.dsv`
+const DESMUME_COOKIE: &[u8] = b"|-DESMUME SAVE-|";
+
+/// What a save image was wrapped in when it was read, so it can be written back the same way
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum Container {
+    /// Just the save, as dumped from the cartridge or written by melonDS
+    #[default]
+    Raw,
+    /// DeSmuME's `.dsv`: the save followed by a metadata footer, kept as-is. The save before the footer
+    /// can itself be trimmed or padded, which `inner` keeps.
+    Desmume { footer: Vec<u8>, inner: Box<Container> },
+    /// Only the first `size` bytes of the save, e.g. a 256 KiB dump of a 512 KiB save
+    Trimmed { size: usize },
+    /// The save followed by padding, e.g. from some flashcarts & 3DS dumps, kept as-is
+    Padded { padding: Vec<u8> },
+}
+
+impl Container {
+    /// Strip the container from `data`, a save that should be `size` bytes long. A trimmed save is
+    /// filled out with `0xFF`, like an erased flash chip.
+    pub fn unwrap(mut data: Vec<u8>, size: usize) -> (Container, Vec<u8>) {
+        if data.ends_with(DESMUME_COOKIE) {
+            let snip = data.windows(DESMUME_SNIP.len()).rposition(|window| window == DESMUME_SNIP);
+            if let Some(snip) = snip {
+                let footer = data.split_off(snip);
+                let (inner, data) = Container::unwrap(data, size);
+                return (Container::Desmume { footer, inner: Box::new(inner) }, data);
+            }
+        }
+
+        match data.len() {
+            len if len == size => (Container::Raw, data),
+            len if len < size => {
+                data.resize(size, 0xFF);
+                (Container::Trimmed { size: len }, data)
+            }
+            _ => {
+                let padding = data.split_off(size);
+                (Container::Padded { padding }, data)
+            }
+        }
+    }
+
+    /// Put `data`, a save unwrapped by `unwrap`, back in this container
+    pub fn wrap(&self, mut data: Vec<u8>) -> Vec<u8> {
+        match self {
+            Container::Raw => {}
+            Container::Desmume { footer, inner } => {
+                data = inner.wrap(data);
+                data.extend_from_slice(footer);
+            }
+            Container::Trimmed { size } => data.truncate(*size),
+            Container::Padded { padding } => data.extend_from_slice(padding),
+        }
+        data
+    }
+
+    /// Does the file have room for the save up to `end`? Anything past the end of a trimmed save is lost
+    /// when it's written back.
+    pub fn holds(&self, end: usize) -> bool {
+        match self {
+            Container::Trimmed { size } => end <= *size,
+            Container::Desmume { inner, .. } => inner.holds(end),
+            _ => true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Does each container come off & go back on as it was?
+    #[test]
+    fn unwrap_and_wrap() {
+        let save: Vec<u8> = (0..64).collect();
+
+        let mut dsv = save.clone();
+        dsv.extend_from_slice(DESMUME_SNIP);
+        dsv.extend_from_slice(&[0x40, 0, 0, 0, 0x40, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+        dsv.extend_from_slice(DESMUME_COOKIE);
+
+        let trimmed = save[..32].to_vec();
+        let mut padded = save.clone();
+        padded.extend_from_slice(&[0xFF; 64]);
+
+        for (data, expected) in [
+            (save.clone(), Container::Raw),
+            (dsv.clone(), Container::Desmume { footer: dsv[64..].to_vec(), inner: Box::new(Container::Raw) }),
+            (trimmed.clone(), Container::Trimmed { size: 32 }),
+            (padded.clone(), Container::Padded { padding: vec![0xFF; 64] }),
+        ] {
+            let (container, image) = Container::unwrap(data.clone(), 64);
+            assert_eq!(container, expected);
+            assert_eq!(image.len(), 64);
+            assert_eq!(container.wrap(image), data);
+        }
+
+        let (_, image) = Container::unwrap(trimmed, 64);
+        assert_eq!(&image[..32], &save[..32]);
+        assert_eq!(&image[32..], &[0xFF; 32]);
+    }
+
+    /// Is a trimmed or padded save inside a `.dsv` brought to size, and wrapped back as it was?
+    #[test]
+    fn unwrap_nested_desmume() {
+        let save: Vec<u8> = (0..64).collect();
+        let footer = [DESMUME_SNIP, &[0; 24], DESMUME_COOKIE].concat();
+
+        let trimmed = [&save[..32], &footer[..]].concat();
+        let padded = [&save[..], &[0xFF; 16], &footer[..]].concat();
+        for (data, inner) in [
+            (trimmed, Container::Trimmed { size: 32 }),
+            (padded, Container::Padded { padding: vec![0xFF; 16] }),
+        ] {
+            let (container, image) = Container::unwrap(data.clone(), 64);
+            assert_eq!(container, Container::Desmume { footer: footer.clone(), inner: Box::new(inner.clone()) });
+            assert_eq!(image.len(), 64);
+            assert_eq!(container.holds(64), inner.holds(64));
+            assert_eq!(container.wrap(image), data);
+        }
+    }
+}
@@ -0,0 +1,381 @@
+use crate::save::error::{Block, ReadError};
+use crate::save::format::container::Container;
+use crate::save::format::BlockStatus;
+use byteorder::{ByteOrder, LittleEndian};
+use std::fmt;
+use std::ops::Range;
+
+/// Every gen 4 `.sav` holds two full copies of the save, one per `0x40000` partition
+pub const PARTITION_SIZE: usize = 0x40000;
+pub const FOOTER_SIZE: usize = 0x14;
+/// HGSS footers have no link ID
+pub const HGSS_FOOTER_SIZE: usize = 0x10;
+
+/// Stored little endian, so this appears as `23 06 06 20` in the file
+pub const FOOTER_SIGNATURE: u32 = 0x20060623;
+
+/// Which games' footer a block has
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FooterFormat {
+    /// DP & Pt, `FOOTER_SIZE` bytes
+    Sinnoh,
+    /// HGSS, `HGSS_FOOTER_SIZE` bytes
+    Johto,
+}
+
+impl FooterFormat {
+    pub fn size(self) -> usize {
+        match self {
+            FooterFormat::Sinnoh => FOOTER_SIZE,
+            FooterFormat::Johto => HGSS_FOOTER_SIZE,
+        }
+    }
+}
+
+/// The footer found at the end of every general & storage block.
+///
+/// See the "Footer" section of `doc/format/gen4/dppt.md`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BlockFooter {
+    pub format: FooterFormat,
+    /// Always `0` in HGSS
+    pub link_id: u32,
+    pub save_id: u32,
+    pub size: u32,
+    pub signature: u32,
+    /// `0` for the general block, `1` for the storage block
+    pub block_id: u8,
+    pub checksum: u16,
+}
+
+impl BlockFooter {
+    /// Parse the footer from the tail of `block`, which must be at least `format.size()` long
+    pub fn from_block(block: &[u8], format: FooterFormat) -> Self {
+        let footer = &block[block.len() - format.size()..];
+        let (link_id, rest) = match format {
+            FooterFormat::Sinnoh => (LittleEndian::read_u32(footer), &footer[0x04..]),
+            FooterFormat::Johto => (0, footer),
+        };
+
+        Self {
+            format,
+            link_id,
+            save_id: LittleEndian::read_u32(&rest[0x00..]),
+            size: LittleEndian::read_u32(&rest[0x04..]),
+            signature: LittleEndian::read_u32(&rest[0x08..]),
+            block_id: rest[0x0C],
+            checksum: LittleEndian::read_u16(&rest[0x0E..]),
+        }
+    }
+
+    /// Write this footer to the tail of `block`, recomputing the checksum over the rest of the block.
+    #[cfg(feature = "write")]
+    pub fn write_to_block(&mut self, block: &mut [u8]) {
+        let data_len = block.len() - self.format.size();
+        self.checksum = crc16_ccitt(&block[..data_len]);
+
+        let footer = &mut block[data_len..];
+        let rest = match self.format {
+            FooterFormat::Sinnoh => {
+                LittleEndian::write_u32(footer, self.link_id);
+                &mut footer[0x04..]
+            }
+            FooterFormat::Johto => footer,
+        };
+        LittleEndian::write_u32(&mut rest[0x00..], self.save_id);
+        LittleEndian::write_u32(&mut rest[0x04..], self.size);
+        LittleEndian::write_u32(&mut rest[0x08..], self.signature);
+        rest[0x0C] = self.block_id;
+        rest[0x0D] = 0;
+        LittleEndian::write_u16(&mut rest[0x0E..], self.checksum);
+    }
+
+    /// Is this footer newer than `other`? Link IDs are compared first, then save IDs.
+    fn is_newer_than(&self, other: &BlockFooter) -> Option<bool> {
+        match (self.link_id, self.save_id).cmp(&(other.link_id, other.save_id)) {
+            std::cmp::Ordering::Greater => Some(true),
+            std::cmp::Ordering::Less => Some(false),
+            std::cmp::Ordering::Equal => None,
+        }
+    }
+}
+
+/// CRC-16-CCITT (initial value `0xFFFF`, polynomial `0x1021`), as used by the block footers
+pub fn crc16_ccitt(data: &[u8]) -> u16 {
+    let mut crc: u16 = 0xFFFF;
+    for &byte in data {
+        crc ^= (byte as u16) << 8;
+        for _ in 0..8 {
+            crc = if crc & 0x8000 != 0 {
+                (crc << 1) ^ 0x1021
+            } else {
+                crc << 1
+            };
+        }
+    }
+    crc
+}
+
+/// Validate the footer of a single block. `block` must be exactly the block, footer included.
+pub fn validate_block(block: &[u8], format: FooterFormat) -> BlockStatus {
+    let footer = BlockFooter::from_block(block, format);
+    if footer.signature != FOOTER_SIGNATURE {
+        return BlockStatus::BadSignature;
+    }
+
+    if footer.size as usize != block.len() {
+        return BlockStatus::BadSize;
+    }
+
+    let computed = crc16_ccitt(&block[..block.len() - format.size()]);
+    if computed != footer.checksum {
+        return BlockStatus::BadChecksum { stored: footer.checksum, computed };
+    }
+
+    BlockStatus::Valid
+}
+
+/// Which of the two `0x40000` partitions a block was read from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SaveSlot {
+    First = 0,
+    Second = 1,
+}
+
+impl SaveSlot {
+    /// Absolute offset of this partition within the `.sav`
+    pub fn offset(self) -> usize {
+        self as usize * PARTITION_SIZE
+    }
+
+    /// The opposite partition, which the game writes to next
+    pub fn other(self) -> SaveSlot {
+        match self {
+            SaveSlot::First => SaveSlot::Second,
+            SaveSlot::Second => SaveSlot::First,
+        }
+    }
+}
+
+/// Why a particular copy of a block was chosen
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SlotReason {
+    /// Both copies are valid, and this one has the newer footer
+    Newer,
+    /// Both copies are valid with identical footers, so the first is used
+    Identical,
+    /// Only this copy is valid; the other failed with the given status
+    OtherInvalid(BlockStatus),
+}
+
+/// The copy of a block chosen when reading a save
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SlotSelection {
+    pub slot: SaveSlot,
+    pub reason: SlotReason,
+    pub footer: BlockFooter,
+}
+
+/// The original bytes of a `.sav` (without its container), and where the chosen general & storage blocks
+/// are within it.
+///
+/// This is kept so that writing a save back only touches what has changed; anything the reader
+/// doesn't understand is preserved as-is.
+#[derive(Clone, Default, PartialEq, Eq)]
+pub struct RawSave {
+    data: Vec<u8>,
+    general: Range<usize>,
+    storage: Range<usize>,
+    container: Container,
+}
+
+impl RawSave {
+    pub(crate) fn new(data: Vec<u8>, general: Range<usize>, storage: Range<usize>, container: Container) -> Self {
+        Self { data, general, storage, container }
+    }
+
+    /// Is there no original file, i.e. the save was built from scratch?
+    pub fn is_empty(&self) -> bool {
+        self.data.is_empty()
+    }
+
+    /// The whole original save, out of its container
+    pub fn bytes(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// What the save was wrapped in, which it's written back in
+    pub fn container(&self) -> &Container {
+        &self.container
+    }
+
+    /// The copy of the general block that was read
+    pub fn general_block(&self) -> Option<&[u8]> {
+        self.data.get(self.general.clone()).filter(|block| !block.is_empty())
+    }
+
+    /// The copy of the storage block that was read
+    pub fn storage_block(&self) -> Option<&[u8]> {
+        self.data.get(self.storage.clone()).filter(|block| !block.is_empty())
+    }
+}
+
+impl fmt::Debug for RawSave {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("RawSave")
+            .field("len", &self.data.len())
+            .field("general", &self.general)
+            .field("storage", &self.storage)
+            .field("container", &self.container)
+            .finish()
+    }
+}
+
+/// Choose the newest valid copy of the block at `offset` (relative to a partition) of `size` bytes.
+///
+/// `block` names the block in the error if neither copy is valid.
+pub fn select_block(data: &[u8], block: Block, offset: usize, size: usize, format: FooterFormat) -> Result<SlotSelection, ReadError> {
+    let status = |slot: SaveSlot| {
+        let start = slot.offset() + offset;
+        match data.get(start..start + size) {
+            Some(block) => (validate_block(block, format), Some(block)),
+            None => (BlockStatus::Missing, None),
+        }
+    };
+
+    let (first_status, first) = status(SaveSlot::First);
+    let (second_status, second) = status(SaveSlot::Second);
+
+    match (first, second) {
+        (Some(first), Some(second)) if first_status == BlockStatus::Valid && second_status == BlockStatus::Valid => {
+            let first = BlockFooter::from_block(first, format);
+            let second = BlockFooter::from_block(second, format);
+            Ok(match second.is_newer_than(&first) {
+                Some(true) => SlotSelection { slot: SaveSlot::Second, reason: SlotReason::Newer, footer: second },
+                Some(false) => SlotSelection { slot: SaveSlot::First, reason: SlotReason::Newer, footer: first },
+                None => SlotSelection { slot: SaveSlot::First, reason: SlotReason::Identical, footer: first },
+            })
+        }
+        (Some(first), _) if first_status == BlockStatus::Valid => Ok(SlotSelection {
+            slot: SaveSlot::First,
+            reason: SlotReason::OtherInvalid(second_status),
+            footer: BlockFooter::from_block(first, format),
+        }),
+        (_, Some(second)) if second_status == BlockStatus::Valid => Ok(SlotSelection {
+            slot: SaveSlot::Second,
+            reason: SlotReason::OtherInvalid(first_status),
+            footer: BlockFooter::from_block(second, format),
+        }),
+        _ => Err(ReadError::NoValidBlock { block, offset, first: first_status, second: second_status }),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use byteorder::WriteBytesExt;
+
+    const BLOCK_SIZE: usize = 0x100;
+
+    fn make_block(fill: u8, link_id: u32, save_id: u32) -> Vec<u8> {
+        let mut block = vec![fill; BLOCK_SIZE - FOOTER_SIZE];
+        let checksum = crc16_ccitt(&block);
+        block.write_u32::<LittleEndian>(link_id).unwrap();
+        block.write_u32::<LittleEndian>(save_id).unwrap();
+        block.write_u32::<LittleEndian>(BLOCK_SIZE as u32).unwrap();
+        block.write_u32::<LittleEndian>(FOOTER_SIGNATURE).unwrap();
+        block.write_u16::<LittleEndian>(0).unwrap();
+        block.write_u16::<LittleEndian>(checksum).unwrap();
+        block
+    }
+
+    fn make_save(first: &[u8], second: &[u8]) -> Vec<u8> {
+        let mut data = vec![0xFFu8; PARTITION_SIZE * 2];
+        data[..first.len()].copy_from_slice(first);
+        data[PARTITION_SIZE..PARTITION_SIZE + second.len()].copy_from_slice(second);
+        data
+    }
+
+    /// Do we match the standard CRC-16-CCITT check value?
+    #[test]
+    fn crc16_check_value() {
+        assert_eq!(crc16_ccitt(b"123456789"), 0x29B1);
+    }
+
+    /// Is the copy with the higher save ID chosen?
+    #[test]
+    fn select_newer_block() {
+        let data = make_save(&make_block(1, 7, 10), &make_block(2, 7, 11));
+        let selection = select_block(&data, Block::General, 0, BLOCK_SIZE, FooterFormat::Sinnoh).unwrap();
+        assert_eq!(selection.slot, SaveSlot::Second);
+        assert_eq!(selection.reason, SlotReason::Newer);
+        assert_eq!(selection.footer.save_id, 11);
+    }
+
+    /// Is an older copy chosen when the newer one has a bad checksum?
+    #[test]
+    fn select_fallback_on_bad_checksum() {
+        let mut newer = make_block(2, 7, 11);
+        newer[0] ^= 0xFF;
+        let data = make_save(&make_block(1, 7, 10), &newer);
+        let selection = select_block(&data, Block::General, 0, BLOCK_SIZE, FooterFormat::Sinnoh).unwrap();
+        assert_eq!(selection.slot, SaveSlot::First);
+        assert!(matches!(selection.reason, SlotReason::OtherInvalid(BlockStatus::BadChecksum { .. })));
+    }
+
+    /// Does a written footer validate, and read back the same?
+    #[cfg(feature = "write")]
+    #[test]
+    fn write_footer() {
+        let mut block = make_block(3, 1, 2);
+        block[0] = 4;
+        let mut footer = BlockFooter::from_block(&block, FooterFormat::Sinnoh);
+        footer.save_id += 1;
+        footer.write_to_block(&mut block);
+
+        assert_eq!(validate_block(&block, FooterFormat::Sinnoh), BlockStatus::Valid);
+        assert_eq!(BlockFooter::from_block(&block, FooterFormat::Sinnoh), footer);
+    }
+
+    /// Does an HGSS footer, which has no link ID, round trip?
+    #[cfg(feature = "write")]
+    #[test]
+    fn write_hgss_footer() {
+        let mut block = vec![5u8; BLOCK_SIZE];
+        let mut footer = BlockFooter {
+            format: FooterFormat::Johto,
+            link_id: 0,
+            save_id: 9,
+            size: BLOCK_SIZE as u32,
+            signature: FOOTER_SIGNATURE,
+            block_id: 1,
+            checksum: 0,
+        };
+        footer.write_to_block(&mut block);
+
+        assert_eq!(LittleEndian::read_u32(&block[BLOCK_SIZE - HGSS_FOOTER_SIZE..]), 9);
+        assert_eq!(validate_block(&block, FooterFormat::Johto), BlockStatus::Valid);
+        assert_eq!(BlockFooter::from_block(&block, FooterFormat::Johto), footer);
+        // the rest of the footer lines up, but the checksum covers less of the block
+        assert!(matches!(validate_block(&block, FooterFormat::Sinnoh), BlockStatus::BadChecksum { .. }));
+    }
+
+    /// Is an uninitialised partition rejected, and a file with no valid copies an error?
+    #[test]
+    fn select_with_uninitialised_partition() {
+        let data = make_save(&[], &make_block(2, 1, 1));
+        let selection = select_block(&data, Block::General, 0, BLOCK_SIZE, FooterFormat::Sinnoh).unwrap();
+        assert_eq!(selection.slot, SaveSlot::Second);
+        assert_eq!(selection.reason, SlotReason::OtherInvalid(BlockStatus::BadSignature));
+
+        let data = make_save(&[], &[]);
+        assert!(matches!(
+            select_block(&data, Block::General, 0, BLOCK_SIZE, FooterFormat::Sinnoh),
+            Err(ReadError::NoValidBlock {
+                first: BlockStatus::BadSignature,
+                second: BlockStatus::BadSignature,
+                ..
+            })
+        ));
+    }
+}
@@ -0,0 +1,119 @@
+use crate::save::error::ReadError;
+#[cfg(feature = "write")]
+use crate::save::error::WriteError;
+use crate::save::data::pocket::Pocket;
+use crate::save::format::dppt::block::FooterFormat;
+use crate::save::format::dppt::pokemon::BOX_POKEMON_SIZE;
+use crate::save::format::dppt::save::{Badge, Game, Gen4Save};
+use crate::save::format::dppt::sinnoh::{self, Layout};
+use crate::save::format::{self, ParseOptions};
+use std::io::{Read, Seek};
+use std::path::PathBuf;
+
+const GENERAL_SIZE: usize = 0xC100;
+const STORAGE_OFFSET: usize = 0xC100;
+const STORAGE_SIZE: usize = 0x121E0;
+
+pub(crate) const LAYOUT: Layout = Layout {
+    general_size: GENERAL_SIZE,
+    storage_offset: STORAGE_OFFSET,
+    storage_size: STORAGE_SIZE,
+    footer: FooterFormat::Sinnoh,
+    trainer: 0x64,
+    badges: &[(0x1A, &Badge::SINNOH)],
+    party: 0x98,
+    bag: 0x624,
+    pocket_capacity: Pocket::capacity,
+    vars: 0xD9C,
+    var_count: 288,
+    flags: 0xFDC,
+    flag_size: 364,
+    current_box: 0x00,
+    boxes: 0x04,
+    box_size: 30 * BOX_POKEMON_SIZE,
+    box_names: 0x11EE4,
+    games: &[Game::Diamond, Game::Pearl],
+};
+
+/// Read a Diamond or Pearl save from `save_file`
+pub fn read_save(save_file: impl Into<PathBuf>) -> Result<Gen4Save, ReadError> {
+    read_save_with_options(save_file, ParseOptions::default())
+}
+
+/// Read a Diamond or Pearl save from `save_file`, see `ParseOptions`
+pub fn read_save_with_options(save_file: impl Into<PathBuf>, options: ParseOptions) -> Result<Gen4Save, ReadError> {
+    format::read_file(save_file, options, read_save_from_bytes)
+}
+
+/// Read a Diamond or Pearl save from `reader`, starting at its current position
+pub fn read_save_from_reader<R: Read + Seek>(reader: &mut R) -> Result<Gen4Save, ReadError> {
+    read_save_from_reader_with_options(reader, ParseOptions::default())
+}
+
+/// Read a Diamond or Pearl save from `reader`, starting at its current position. See `ParseOptions`.
+pub fn read_save_from_reader_with_options<R: Read + Seek>(reader: &mut R, options: ParseOptions) -> Result<Gen4Save, ReadError> {
+    format::read_reader(reader, options, read_save_from_bytes)
+}
+
+pub(crate) fn read_save_from_bytes(data: Vec<u8>, options: ParseOptions) -> Result<Gen4Save, ReadError> {
+    sinnoh::read_save_from_bytes(data, options, &LAYOUT)
+}
+
+/// Write `save` to `save_file`. See `write_save_to_bytes`.
+#[cfg(feature = "write")]
+pub fn write_save(save: &Gen4Save, save_file: impl Into<PathBuf>) -> Result<(), WriteError> {
+    let data = write_save_to_bytes(save)?;
+    Ok(std::fs::write(save_file.into(), data)?)
+}
+
+/// Serialize `save` into a full 512 KiB `.sav` image, see `platinum::write_save_to_bytes`. The two
+/// only differ in where things are, which is decided by `save.game`.
+#[cfg(feature = "write")]
+pub fn write_save_to_bytes(save: &Gen4Save) -> Result<Vec<u8>, WriteError> {
+    sinnoh::write_save_to_bytes(save)
+}
+
+#[cfg(test)]
+#[cfg(feature = "write")]
+mod tests {
+    use super::*;
+    use crate::save::error::Block;
+    use crate::save::format::dppt::block::{BlockFooter, PARTITION_SIZE};
+    use crate::save::format::dppt::platinum;
+
+    /// Does a Diamond or Pearl save read back as written, and get told apart from Platinum?
+    #[test]
+    fn write_round_trip() {
+        for game in [Game::Diamond, Game::Pearl] {
+            let save = sinnoh::test_save(game);
+            let written = write_save_to_bytes(&save).unwrap();
+
+            let read = read_save_from_bytes(written.clone(), ParseOptions::default()).unwrap();
+            assert_eq!(read.base, save.base);
+            assert_eq!(Gen4Save::from_bytes(&written).unwrap(), read);
+            assert_eq!(write_save_to_bytes(&read).unwrap(), written);
+            assert_eq!(sinnoh::rewrite_blank(read), written);
+            assert!(matches!(platinum::read_save_from_bytes(written, ParseOptions::default()), Err(ReadError::NoValidBlock { .. })));
+        }
+
+        let platinum = platinum::write_save_to_bytes(&sinnoh::test_save(Game::Platinum)).unwrap();
+        assert_eq!(Gen4Save::from_bytes(&platinum).unwrap().game, Game::Platinum);
+    }
+
+    /// Is a game code that doesn't match the layout reported?
+    #[test]
+    fn read_wrong_game_code() {
+        let mut written = write_save_to_bytes(&sinnoh::test_save(Game::Diamond)).unwrap();
+        let start = PARTITION_SIZE; // the second partition, as the first copy was read from the first
+        let general = &mut written[start..start + GENERAL_SIZE];
+        general[LAYOUT.trainer + 0x1C] = 12;
+        BlockFooter::from_block(general, FooterFormat::Sinnoh).write_to_block(general);
+
+        let err = read_save_from_bytes(written.clone(), ParseOptions::default()).unwrap_err();
+        assert!(matches!(err, ReadError::InvalidValue { block: Block::Player, field: "game code", value: 12, .. }));
+
+        let read = read_save_from_bytes(written, ParseOptions { strict: false }).unwrap();
+        assert_eq!(read.game, Game::Diamond);
+        assert_eq!(read.diagnostics.len(), 1);
+    }
+}
@@ -1,349 +1,308 @@
+pub mod block;
+pub mod diamond_pearl;
 pub mod platinum;
+pub mod pokemon;
+pub(crate) mod sinnoh;
+pub mod string;
 
-// string funcs; thank you to https://github.com/kwsch/PKHeX/blob/master/PKHeX.Core/PKM/Strings/StringConverter4Util.cs !
-const NUL: char = '\u{FFFF}';
-const HALF_WIDTH_GENDER_MALE: char = '\u{246D}';
-const HALF_WIDTH_GENDER_FEMALE: char = '\u{246E}';
-const INTERNATIONAL_TABLE: [char; 493] = [
-    NUL, '　', 'ぁ', 'あ', 'ぃ', 'い', 'ぅ', 'う', 'ぇ', 'え', 'ぉ', 'お', 'か', 'が', 'き', 'ぎ', // 000-00F
-    'く', 'ぐ', 'け', 'げ', 'こ', 'ご', 'さ', 'ざ', 'し', 'じ', 'す', 'ず', 'せ', 'ぜ', 'そ', 'ぞ', // 010-01F
-    'た', 'だ', 'ち', 'ぢ', 'っ', 'つ', 'づ', 'て', 'で', 'と', 'ど', 'な', 'に', 'ぬ', 'ね', 'の', // 020-02F
-    'は', 'ば', 'ぱ', 'ひ', 'び', 'ぴ', 'ふ', 'ぶ', 'ぷ', 'へ', 'べ', 'ぺ', 'ほ', 'ぼ', 'ぽ', 'ま', // 030-03F
-    'み', 'む', 'め', 'も', 'ゃ', 'や', 'ゅ', 'ゆ', 'ょ', 'よ', 'ら', 'り', 'る', 'れ', 'ろ', 'わ', // 040-04F
-    'を', 'ん', 'ァ', 'ア', 'ィ', 'イ', 'ゥ', 'ウ', 'ェ', 'エ', 'ォ', 'オ', 'カ', 'ガ', 'キ', 'ギ', // 050-05F
-    'ク', 'グ', 'ケ', 'ゲ', 'コ', 'ゴ', 'サ', 'ザ', 'シ', 'ジ', 'ス', 'ズ', 'セ', 'ゼ', 'ソ', 'ゾ', // 060-06F
-    'タ', 'ダ', 'チ', 'ヂ', 'ッ', 'ツ', 'ヅ', 'テ', 'デ', 'ト', 'ド', 'ナ', 'ニ', 'ヌ', 'ネ', 'ノ', // 070-07F
-    'ハ', 'バ', 'パ', 'ヒ', 'ビ', 'ピ', 'フ', 'ブ', 'プ', 'ヘ', 'ベ', 'ペ', 'ホ', 'ボ', 'ポ', 'マ', // 080-08F
-    'ミ', 'ム', 'メ', 'モ', 'ャ', 'ヤ', 'ュ', 'ユ', 'ョ', 'ヨ', 'ラ', 'リ', 'ル', 'レ', 'ロ', 'ワ', // 090-09F
-    'ヲ', 'ン', '０', '１', '２', '３', '４', '５', '６', '７', '８', '９', 'Ａ', 'Ｂ', 'Ｃ', 'Ｄ', // 0A0-0AF
-    'Ｅ', 'Ｆ', 'Ｇ', 'Ｈ', 'Ｉ', 'Ｊ', 'Ｋ', 'Ｌ', 'Ｍ', 'Ｎ', 'Ｏ', 'Ｐ', 'Ｑ', 'Ｒ', 'Ｓ', 'Ｔ', // 0B0-0BF
-    'Ｕ', 'Ｖ', 'Ｗ', 'Ｘ', 'Ｙ', 'Ｚ', 'ａ', 'ｂ', 'ｃ', 'ｄ', 'ｅ', 'ｆ', 'ｇ', 'ｈ', 'ｉ', 'ｊ', // 0C0-0CF
-    'ｋ', 'ｌ', 'ｍ', 'ｎ', 'ｏ', 'ｐ', 'ｑ', 'ｒ', 'ｓ', 'ｔ', 'ｕ', 'ｖ', 'ｗ', 'ｘ', 'ｙ', 'ｚ', // 0D0-0DF
-    NUL, '！', '？', '、', '。', '…', '・', '／', '「', '」', '『', '』', '（', '）', '♂', '♀', // 0E0-0EF
-    '＋', 'ー', '×', '÷', '＝', '～', '：', '；', '．', '，', '♠', '♣', '♥', '♦', '★', '◎', // 0F0-0FF
-    '○', '□', '△', '◇', '＠', '♪', '％', '☀', '☁', '☂', '☃', '①', '②', '③', '④', '⑤', // 100-10F
-    '⑥', '⑦', '円', '♈', '♉', '♊', '♋', '♌', '♍', '♎', '♏', '←', '↑', '↓', '→', '►', // 110-11F
-    '＆', '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', 'A', 'B', 'C', 'D', 'E', // 120-12F
-    'F', 'G', 'H', 'I', 'J', 'K', 'L', 'M', 'N', 'O', 'P', 'Q', 'R', 'S', 'T', 'U', // 130-13F
-    'V', 'W', 'X', 'Y', 'Z', 'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', // 140-14F
-    'l', 'm', 'n', 'o', 'p', 'q', 'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z', 'À', // 150-15F
-    'Á', 'Â', 'Ã', 'Ä', 'Å', 'Æ', 'Ç', 'È', 'É', 'Ê', 'Ë', 'Ì', 'Í', 'Î', 'Ï', 'Ð', // 160-16F
-    'Ñ', 'Ò', 'Ó', 'Ô', 'Õ', 'Ö', '⑧', 'Ø', 'Ù', 'Ú', 'Û', 'Ü', 'Ý', 'Þ', 'ß', 'à', // 170-17F
-    'á', 'â', 'ã', 'ä', 'å', 'æ', 'ç', 'è', 'é', 'ê', 'ë', 'ì', 'í', 'î', 'ï', 'ð', // 180-18F
-    'ñ', 'ò', 'ó', 'ô', 'õ', 'ö', '⑨', 'ø', 'ù', 'ú', 'û', 'ü', 'ý', 'þ', 'ÿ', 'Œ', // 190-19F
-    'œ', 'Ş', 'ş', 'ª', 'º', '⑩', '⑪', '⑫', '$', '¡', '¿', '!', '?', ',', '.', '⑬', // 1A0-1AF
-    '･', '/', '‘', '\'', '“', '”', '„', '«', '»', '(', ')', HALF_WIDTH_GENDER_MALE, HALF_WIDTH_GENDER_FEMALE, '+', '-', '*', // 1B0-1BF
-    '#', '=', '&', '~', ':', ';', '⑯', '⑰', '⑱', '⑲', '⑳', '⑴', '⑵', '⑶', '⑷', '⑸', // 1C0-1CF
-    '@', '⑹', '%', '⑺', '⑻', '⑼', '⑽', '⑾', '⑿', '⒀', '⒁', '⒂', '⒃', '⒄', ' ', '⒅', // 1D0-1DF
-    '⒆', '⒇', '⒈', '⒉', '⒊', '⒋', '⒌', '⒍', '°', '_', '＿', '⒎', '⒏', // 1E0-1EC*
-];
-const INTERNATIONAL_TABLE_START: u16 = 0x000;
-const INTERNATIONAL_TABLE_END: u16 = 0x01EC;
-const KOREAN_TABLE_START: u16 = 0x400;
-const KOREAN_TABLE_END: u16 = 0xD65;
-const KOREAN_TABLE: [char; 2406] = [
-    NUL, '가', '각', '간', '갇', '갈', '갉', '갊', '감', '갑', '값', '갓', '갔', '강', '갖', '갗', // 400-40F
-    '같', '갚', '갛', '개', '객', '갠', '갤', '갬', '갭', '갯', '갰', '갱', '갸', '갹', '갼', '걀', // 410-41F
-    '걋', '걍', '걔', '걘', '걜', '거', '걱', '건', '걷', '걸', '걺', '검', '겁', '것', '겄', '겅', // 420-42F
-    '겆', '겉', '겊', '겋', '게', '겐', '겔', '겜', '겝', '겟', '겠', '겡', '겨', '격', '겪', '견', // 430-43F
-    '겯', '결', '겸', '겹', '겻', '겼', '경', '곁', '계', '곈', '곌', '곕', '곗', '고', '곡', '곤', // 440-44F
-    '곧', '골', '곪', '곬', '곯', '곰', '곱', '곳', '공', '곶', '과', '곽', '관', '괄', '괆', '괌', // 450-45F
-    '괍', '괏', '광', '괘', '괜', '괠', '괩', '괬', '괭', '괴', '괵', '괸', '괼', '굄', '굅', '굇', // 460-46F
-    '굉', '교', '굔', '굘', '굡', '굣', '구', '국', '군', '굳', '굴', '굵', '굶', '굻', '굼', '굽', // 470-47F
-    '굿', '궁', '궂', '궈', '궉', '권', '궐', '궜', '궝', '궤', '궷', '귀', '귁', '귄', '귈', '귐', // 480-48F
-    '귑', '귓', '규', '균', '귤', '그', '극', '근', '귿', '글', '긁', '금', '급', '긋', '긍', '긔', // 490-49F
-    '기', '긱', '긴', '긷', '길', '긺', '김', '깁', '깃', '깅', '깆', '깊', '까', '깍', '깎', '깐', // 4A0-4AF
-    '깔', '깖', '깜', '깝', '깟', '깠', '깡', '깥', '깨', '깩', '깬', '깰', '깸', '깹', '깻', '깼', // 4B0-4BF
-    '깽', '꺄', '꺅', '꺌', '꺼', '꺽', '꺾', '껀', '껄', '껌', '껍', '껏', '껐', '껑', '께', '껙', // 4C0-4CF
-    '껜', '껨', '껫', '껭', '껴', '껸', '껼', '꼇', '꼈', '꼍', '꼐', '꼬', '꼭', '꼰', '꼲', '꼴', // 4D0-4DF
-    '꼼', '꼽', '꼿', '꽁', '꽂', '꽃', '꽈', '꽉', '꽐', '꽜', '꽝', '꽤', '꽥', '꽹', '꾀', '꾄', // 4E0-4EF
-    '꾈', '꾐', '꾑', '꾕', '꾜', '꾸', '꾹', '꾼', '꿀', '꿇', '꿈', '꿉', '꿋', '꿍', '꿎', '꿔', // 4F0-4FF
-    '꿜', '꿨', '꿩', '꿰', '꿱', '꿴', '꿸', '뀀', '뀁', '뀄', '뀌', '뀐', '뀔', '뀜', '뀝', '뀨', // 500-50F
-    '끄', '끅', '끈', '끊', '끌', '끎', '끓', '끔', '끕', '끗', '끙', '끝', '끼', '끽', '낀', '낄', // 510-51F
-    '낌', '낍', '낏', '낑', '나', '낙', '낚', '난', '낟', '날', '낡', '낢', '남', '납', '낫', '났', // 520-52F
-    '낭', '낮', '낯', '낱', '낳', '내', '낵', '낸', '낼', '냄', '냅', '냇', '냈', '냉', '냐', '냑', // 530-53F
-    '냔', '냘', '냠', '냥', '너', '넉', '넋', '넌', '널', '넒', '넓', '넘', '넙', '넛', '넜', '넝', // 540-54F
-    '넣', '네', '넥', '넨', '넬', '넴', '넵', '넷', '넸', '넹', '녀', '녁', '년', '녈', '념', '녑', // 550-55F
-    '녔', '녕', '녘', '녜', '녠', '노', '녹', '논', '놀', '놂', '놈', '놉', '놋', '농', '높', '놓', // 560-56F
-    '놔', '놘', '놜', '놨', '뇌', '뇐', '뇔', '뇜', '뇝', '뇟', '뇨', '뇩', '뇬', '뇰', '뇹', '뇻', // 570-57F
-    '뇽', '누', '눅', '눈', '눋', '눌', '눔', '눕', '눗', '눙', '눠', '눴', '눼', '뉘', '뉜', '뉠', // 580-58F
-    '뉨', '뉩', '뉴', '뉵', '뉼', '늄', '늅', '늉', '느', '늑', '는', '늘', '늙', '늚', '늠', '늡', // 590-59F
-    '늣', '능', '늦', '늪', '늬', '늰', '늴', '니', '닉', '닌', '닐', '닒', '님', '닙', '닛', '닝', // 5A0-5AF
-    '닢', '다', '닥', '닦', '단', '닫', '달', '닭', '닮', '닯', '닳', '담', '답', '닷', '닸', '당', // 5B0-5BF
-    '닺', '닻', '닿', '대', '댁', '댄', '댈', '댐', '댑', '댓', '댔', '댕', '댜', '더', '덕', '덖', // 5C0-5CF
-    '던', '덛', '덜', '덞', '덟', '덤', '덥', '덧', '덩', '덫', '덮', '데', '덱', '덴', '델', '뎀', // 5D0-5DF
-    '뎁', '뎃', '뎄', '뎅', '뎌', '뎐', '뎔', '뎠', '뎡', '뎨', '뎬', '도', '독', '돈', '돋', '돌', // 5E0-5EF
-    '돎', '돐', '돔', '돕', '돗', '동', '돛', '돝', '돠', '돤', '돨', '돼', '됐', '되', '된', '될', // 5F0-5FF
-    '됨', '됩', '됫', '됴', '두', '둑', '둔', '둘', '둠', '둡', '둣', '둥', '둬', '뒀', '뒈', '뒝', // 600-60F
-    '뒤', '뒨', '뒬', '뒵', '뒷', '뒹', '듀', '듄', '듈', '듐', '듕', '드', '득', '든', '듣', '들', // 610-61F
-    '듦', '듬', '듭', '듯', '등', '듸', '디', '딕', '딘', '딛', '딜', '딤', '딥', '딧', '딨', '딩', // 620-62F
-    '딪', '따', '딱', '딴', '딸', '땀', '땁', '땃', '땄', '땅', '땋', '때', '땍', '땐', '땔', '땜', // 630-63F
-    '땝', '땟', '땠', '땡', '떠', '떡', '떤', '떨', '떪', '떫', '떰', '떱', '떳', '떴', '떵', '떻', // 640-64F
-    '떼', '떽', '뗀', '뗄', '뗌', '뗍', '뗏', '뗐', '뗑', '뗘', '뗬', '또', '똑', '똔', '똘', '똥', // 650-65F
-    '똬', '똴', '뙈', '뙤', '뙨', '뚜', '뚝', '뚠', '뚤', '뚫', '뚬', '뚱', '뛔', '뛰', '뛴', '뛸', // 660-66F
-    '뜀', '뜁', '뜅', '뜨', '뜩', '뜬', '뜯', '뜰', '뜸', '뜹', '뜻', '띄', '띈', '띌', '띔', '띕', // 670-67F
-    '띠', '띤', '띨', '띰', '띱', '띳', '띵', '라', '락', '란', '랄', '람', '랍', '랏', '랐', '랑', // 680-68F
-    '랒', '랖', '랗', '래', '랙', '랜', '랠', '램', '랩', '랫', '랬', '랭', '랴', '략', '랸', '럇', // 690-69F
-    '량', '러', '럭', '런', '럴', '럼', '럽', '럿', '렀', '렁', '렇', '레', '렉', '렌', '렐', '렘', // 6A0-6AF
-    '렙', '렛', '렝', '려', '력', '련', '렬', '렴', '렵', '렷', '렸', '령', '례', '롄', '롑', '롓', // 6B0-6BF
-    '로', '록', '론', '롤', '롬', '롭', '롯', '롱', '롸', '롼', '뢍', '뢨', '뢰', '뢴', '뢸', '룀', // 6C0-6CF
-    '룁', '룃', '룅', '료', '룐', '룔', '룝', '룟', '룡', '루', '룩', '룬', '룰', '룸', '룹', '룻', // 6D0-6DF
-    '룽', '뤄', '뤘', '뤠', '뤼', '뤽', '륀', '륄', '륌', '륏', '륑', '류', '륙', '륜', '률', '륨', // 6E0-6EF
-    '륩', '륫', '륭', '르', '륵', '른', '를', '름', '릅', '릇', '릉', '릊', '릍', '릎', '리', '릭', // 6F0-6FF
-    '린', '릴', '림', '립', '릿', '링', '마', '막', '만', '많', '맏', '말', '맑', '맒', '맘', '맙', // 700-70F
-    '맛', '망', '맞', '맡', '맣', '매', '맥', '맨', '맬', '맴', '맵', '맷', '맸', '맹', '맺', '먀', // 710-71F
-    '먁', '먈', '먕', '머', '먹', '먼', '멀', '멂', '멈', '멉', '멋', '멍', '멎', '멓', '메', '멕', // 720-72F
-    '멘', '멜', '멤', '멥', '멧', '멨', '멩', '며', '멱', '면', '멸', '몃', '몄', '명', '몇', '몌', // 730-73F
-    '모', '목', '몫', '몬', '몰', '몲', '몸', '몹', '못', '몽', '뫄', '뫈', '뫘', '뫙', '뫼', '묀', // 740-74F
-    '묄', '묍', '묏', '묑', '묘', '묜', '묠', '묩', '묫', '무', '묵', '묶', '문', '묻', '물', '묽', // 750-75F
-    '묾', '뭄', '뭅', '뭇', '뭉', '뭍', '뭏', '뭐', '뭔', '뭘', '뭡', '뭣', '뭬', '뮈', '뮌', '뮐', // 760-76F
-    '뮤', '뮨', '뮬', '뮴', '뮷', '므', '믄', '믈', '믐', '믓', '미', '믹', '민', '믿', '밀', '밂', // 770-77F
-    '밈', '밉', '밋', '밌', '밍', '및', '밑', '바', '박', '밖', '밗', '반', '받', '발', '밝', '밞', // 780-78F
-    '밟', '밤', '밥', '밧', '방', '밭', '배', '백', '밴', '밸', '뱀', '뱁', '뱃', '뱄', '뱅', '뱉', // 790-79F
-    '뱌', '뱍', '뱐', '뱝', '버', '벅', '번', '벋', '벌', '벎', '범', '법', '벗', '벙', '벚', '베', // 7A0-7AF
-    '벡', '벤', '벧', '벨', '벰', '벱', '벳', '벴', '벵', '벼', '벽', '변', '별', '볍', '볏', '볐', // 7B0-7BF
-    '병', '볕', '볘', '볜', '보', '복', '볶', '본', '볼', '봄', '봅', '봇', '봉', '봐', '봔', '봤', // 7C0-7CF
-    '봬', '뵀', '뵈', '뵉', '뵌', '뵐', '뵘', '뵙', '뵤', '뵨', '부', '북', '분', '붇', '불', '붉', // 7D0-7DF
-    '붊', '붐', '붑', '붓', '붕', '붙', '붚', '붜', '붤', '붰', '붸', '뷔', '뷕', '뷘', '뷜', '뷩', // 7E0-7EF
-    '뷰', '뷴', '뷸', '븀', '븃', '븅', '브', '븍', '븐', '블', '븜', '븝', '븟', '비', '빅', '빈', // 7F0-7FF
-    '빌', '빎', '빔', '빕', '빗', '빙', '빚', '빛', '빠', '빡', '빤', '빨', '빪', '빰', '빱', '빳', // 800-80F
-    '빴', '빵', '빻', '빼', '빽', '뺀', '뺄', '뺌', '뺍', '뺏', '뺐', '뺑', '뺘', '뺙', '뺨', '뻐', // 810-81F
-    '뻑', '뻔', '뻗', '뻘', '뻠', '뻣', '뻤', '뻥', '뻬', '뼁', '뼈', '뼉', '뼘', '뼙', '뼛', '뼜', // 820-82F
-    '뼝', '뽀', '뽁', '뽄', '뽈', '뽐', '뽑', '뽕', '뾔', '뾰', '뿅', '뿌', '뿍', '뿐', '뿔', '뿜', // 830-83F
-    '뿟', '뿡', '쀼', '쁑', '쁘', '쁜', '쁠', '쁨', '쁩', '삐', '삑', '삔', '삘', '삠', '삡', '삣', // 840-84F
-    '삥', '사', '삭', '삯', '산', '삳', '살', '삵', '삶', '삼', '삽', '삿', '샀', '상', '샅', '새', // 850-85F
-    '색', '샌', '샐', '샘', '샙', '샛', '샜', '생', '샤', '샥', '샨', '샬', '샴', '샵', '샷', '샹', // 860-86F
-    '섀', '섄', '섈', '섐', '섕', '서', '석', '섞', '섟', '선', '섣', '설', '섦', '섧', '섬', '섭', // 870-87F
-    '섯', '섰', '성', '섶', '세', '섹', '센', '셀', '셈', '셉', '셋', '셌', '셍', '셔', '셕', '션', // 880-88F
-    '셜', '셤', '셥', '셧', '셨', '셩', '셰', '셴', '셸', '솅', '소', '속', '솎', '손', '솔', '솖', // 890-89F
-    '솜', '솝', '솟', '송', '솥', '솨', '솩', '솬', '솰', '솽', '쇄', '쇈', '쇌', '쇔', '쇗', '쇘', // 8A0-8AF
-    '쇠', '쇤', '쇨', '쇰', '쇱', '쇳', '쇼', '쇽', '숀', '숄', '숌', '숍', '숏', '숑', '수', '숙', // 8B0-8BF
-    '순', '숟', '술', '숨', '숩', '숫', '숭', '숯', '숱', '숲', '숴', '쉈', '쉐', '쉑', '쉔', '쉘', // 8C0-8CF
-    '쉠', '쉥', '쉬', '쉭', '쉰', '쉴', '쉼', '쉽', '쉿', '슁', '슈', '슉', '슐', '슘', '슛', '슝', // 8D0-8DF
-    '스', '슥', '슨', '슬', '슭', '슴', '습', '슷', '승', '시', '식', '신', '싣', '실', '싫', '심', // 8E0-8EF
-    '십', '싯', '싱', '싶', '싸', '싹', '싻', '싼', '쌀', '쌈', '쌉', '쌌', '쌍', '쌓', '쌔', '쌕', // 8F0-8FF
-    '쌘', '쌜', '쌤', '쌥', '쌨', '쌩', '썅', '써', '썩', '썬', '썰', '썲', '썸', '썹', '썼', '썽', // 900-90F
-    '쎄', '쎈', '쎌', '쏀', '쏘', '쏙', '쏜', '쏟', '쏠', '쏢', '쏨', '쏩', '쏭', '쏴', '쏵', '쏸', // 910-91F
-    '쐈', '쐐', '쐤', '쐬', '쐰', '쐴', '쐼', '쐽', '쑈', '쑤', '쑥', '쑨', '쑬', '쑴', '쑵', '쑹', // 920-92F
-    '쒀', '쒔', '쒜', '쒸', '쒼', '쓩', '쓰', '쓱', '쓴', '쓸', '쓺', '쓿', '씀', '씁', '씌', '씐', // 930-93F
-    '씔', '씜', '씨', '씩', '씬', '씰', '씸', '씹', '씻', '씽', '아', '악', '안', '앉', '않', '알', // 940-94F
-    '앍', '앎', '앓', '암', '압', '앗', '았', '앙', '앝', '앞', '애', '액', '앤', '앨', '앰', '앱', // 950-95F
-    '앳', '앴', '앵', '야', '약', '얀', '얄', '얇', '얌', '얍', '얏', '양', '얕', '얗', '얘', '얜', // 960-96F
-    '얠', '얩', '어', '억', '언', '얹', '얻', '얼', '얽', '얾', '엄', '업', '없', '엇', '었', '엉', // 970-97F
-    '엊', '엌', '엎', '에', '엑', '엔', '엘', '엠', '엡', '엣', '엥', '여', '역', '엮', '연', '열', // 980-98F
-    '엶', '엷', '염', '엽', '엾', '엿', '였', '영', '옅', '옆', '옇', '예', '옌', '옐', '옘', '옙', // 990-99F
-    '옛', '옜', '오', '옥', '온', '올', '옭', '옮', '옰', '옳', '옴', '옵', '옷', '옹', '옻', '와', // 9A0-9AF
-    '왁', '완', '왈', '왐', '왑', '왓', '왔', '왕', '왜', '왝', '왠', '왬', '왯', '왱', '외', '왹', // 9B0-9BF
-    '왼', '욀', '욈', '욉', '욋', '욍', '요', '욕', '욘', '욜', '욤', '욥', '욧', '용', '우', '욱', // 9C0-9CF
-    '운', '울', '욹', '욺', '움', '웁', '웃', '웅', '워', '웍', '원', '월', '웜', '웝', '웠', '웡', // 9D0-9DF
-    '웨', '웩', '웬', '웰', '웸', '웹', '웽', '위', '윅', '윈', '윌', '윔', '윕', '윗', '윙', '유', // 9E0-9EF
-    '육', '윤', '율', '윰', '윱', '윳', '융', '윷', '으', '윽', '은', '을', '읊', '음', '읍', '읏', // 9F0-9FF
-    '응', '읒', '읓', '읔', '읕', '읖', '읗', '의', '읜', '읠', '읨', '읫', '이', '익', '인', '일', // A00-A0F
-    '읽', '읾', '잃', '임', '입', '잇', '있', '잉', '잊', '잎', '자', '작', '잔', '잖', '잗', '잘', // A10-A1F
-    '잚', '잠', '잡', '잣', '잤', '장', '잦', '재', '잭', '잰', '잴', '잼', '잽', '잿', '쟀', '쟁', // A20-A2F
-    '쟈', '쟉', '쟌', '쟎', '쟐', '쟘', '쟝', '쟤', '쟨', '쟬', '저', '적', '전', '절', '젊', '점', // A30-A3F
-    '접', '젓', '정', '젖', '제', '젝', '젠', '젤', '젬', '젭', '젯', '젱', '져', '젼', '졀', '졈', // A40-A4F
-    '졉', '졌', '졍', '졔', '조', '족', '존', '졸', '졺', '좀', '좁', '좃', '종', '좆', '좇', '좋', // A50-A5F
-    '좌', '좍', '좔', '좝', '좟', '좡', '좨', '좼', '좽', '죄', '죈', '죌', '죔', '죕', '죗', '죙', // A60-A6F
-    '죠', '죡', '죤', '죵', '주', '죽', '준', '줄', '줅', '줆', '줌', '줍', '줏', '중', '줘', '줬', // A70-A7F
-    '줴', '쥐', '쥑', '쥔', '쥘', '쥠', '쥡', '쥣', '쥬', '쥰', '쥴', '쥼', '즈', '즉', '즌', '즐', // A80-A8F
-    '즘', '즙', '즛', '증', '지', '직', '진', '짇', '질', '짊', '짐', '집', '짓', '징', '짖', '짙', // A90-A9F
-    '짚', '짜', '짝', '짠', '짢', '짤', '짧', '짬', '짭', '짯', '짰', '짱', '째', '짹', '짼', '쨀', // AA0-AAF
-    '쨈', '쨉', '쨋', '쨌', '쨍', '쨔', '쨘', '쨩', '쩌', '쩍', '쩐', '쩔', '쩜', '쩝', '쩟', '쩠', // AB0-ABF
-    '쩡', '쩨', '쩽', '쪄', '쪘', '쪼', '쪽', '쫀', '쫄', '쫌', '쫍', '쫏', '쫑', '쫓', '쫘', '쫙', // AC0-ACF
-    '쫠', '쫬', '쫴', '쬈', '쬐', '쬔', '쬘', '쬠', '쬡', '쭁', '쭈', '쭉', '쭌', '쭐', '쭘', '쭙', // AD0-ADF
-    '쭝', '쭤', '쭸', '쭹', '쮜', '쮸', '쯔', '쯤', '쯧', '쯩', '찌', '찍', '찐', '찔', '찜', '찝', // AE0-AEF
-    '찡', '찢', '찧', '차', '착', '찬', '찮', '찰', '참', '찹', '찻', '찼', '창', '찾', '채', '책', // AF0-AFF
-    '챈', '챌', '챔', '챕', '챗', '챘', '챙', '챠', '챤', '챦', '챨', '챰', '챵', '처', '척', '천', // B00-B0F
-    '철', '첨', '첩', '첫', '첬', '청', '체', '첵', '첸', '첼', '쳄', '쳅', '쳇', '쳉', '쳐', '쳔', // B10-B1F
-    '쳤', '쳬', '쳰', '촁', '초', '촉', '촌', '촐', '촘', '촙', '촛', '총', '촤', '촨', '촬', '촹', // B20-B2F
-    '최', '쵠', '쵤', '쵬', '쵭', '쵯', '쵱', '쵸', '춈', '추', '축', '춘', '출', '춤', '춥', '춧', // B30-B3F
-    '충', '춰', '췄', '췌', '췐', '취', '췬', '췰', '췸', '췹', '췻', '췽', '츄', '츈', '츌', '츔', // B40-B4F
-    '츙', '츠', '측', '츤', '츨', '츰', '츱', '츳', '층', '치', '칙', '친', '칟', '칠', '칡', '침', // B50-B5F
-    '칩', '칫', '칭', '카', '칵', '칸', '칼', '캄', '캅', '캇', '캉', '캐', '캑', '캔', '캘', '캠', // B60-B6F
-    '캡', '캣', '캤', '캥', '캬', '캭', '컁', '커', '컥', '컨', '컫', '컬', '컴', '컵', '컷', '컸', // B70-B7F
-    '컹', '케', '켁', '켄', '켈', '켐', '켑', '켓', '켕', '켜', '켠', '켤', '켬', '켭', '켯', '켰', // B80-B8F
-    '켱', '켸', '코', '콕', '콘', '콜', '콤', '콥', '콧', '콩', '콰', '콱', '콴', '콸', '쾀', '쾅', // B90-B9F
-    '쾌', '쾡', '쾨', '쾰', '쿄', '쿠', '쿡', '쿤', '쿨', '쿰', '쿱', '쿳', '쿵', '쿼', '퀀', '퀄', // BA0-BAF
-    '퀑', '퀘', '퀭', '퀴', '퀵', '퀸', '퀼', '큄', '큅', '큇', '큉', '큐', '큔', '큘', '큠', '크', // BB0-BBF
-    '큭', '큰', '클', '큼', '큽', '킁', '키', '킥', '킨', '킬', '킴', '킵', '킷', '킹', '타', '탁', // BC0-BCF
-    '탄', '탈', '탉', '탐', '탑', '탓', '탔', '탕', '태', '택', '탠', '탤', '탬', '탭', '탯', '탰', // BD0-BDF
-    '탱', '탸', '턍', '터', '턱', '턴', '털', '턺', '텀', '텁', '텃', '텄', '텅', '테', '텍', '텐', // BE0-BEF
-    '텔', '템', '텝', '텟', '텡', '텨', '텬', '텼', '톄', '톈', '토', '톡', '톤', '톨', '톰', '톱', // BF0-BFF
-    '톳', '통', '톺', '톼', '퇀', '퇘', '퇴', '퇸', '툇', '툉', '툐', '투', '툭', '툰', '툴', '툼', // C00-C0F
-    '툽', '툿', '퉁', '퉈', '퉜', '퉤', '튀', '튁', '튄', '튈', '튐', '튑', '튕', '튜', '튠', '튤', // C10-C1F
-    '튬', '튱', '트', '특', '튼', '튿', '틀', '틂', '틈', '틉', '틋', '틔', '틘', '틜', '틤', '틥', // C20-C2F
-    '티', '틱', '틴', '틸', '팀', '팁', '팃', '팅', '파', '팍', '팎', '판', '팔', '팖', '팜', '팝', // C30-C3F
-    '팟', '팠', '팡', '팥', '패', '팩', '팬', '팰', '팸', '팹', '팻', '팼', '팽', '퍄', '퍅', '퍼', // C40-C4F
-    '퍽', '펀', '펄', '펌', '펍', '펏', '펐', '펑', '페', '펙', '펜', '펠', '펨', '펩', '펫', '펭', // C50-C5F
-    '펴', '편', '펼', '폄', '폅', '폈', '평', '폐', '폘', '폡', '폣', '포', '폭', '폰', '폴', '폼', // C60-C6F
-    '폽', '폿', '퐁', '퐈', '퐝', '푀', '푄', '표', '푠', '푤', '푭', '푯', '푸', '푹', '푼', '푿', // C70-C7F
-    '풀', '풂', '품', '풉', '풋', '풍', '풔', '풩', '퓌', '퓐', '퓔', '퓜', '퓟', '퓨', '퓬', '퓰', // C80-C8F
-    '퓸', '퓻', '퓽', '프', '픈', '플', '픔', '픕', '픗', '피', '픽', '핀', '필', '핌', '핍', '핏', // C90-C9F
-    '핑', '하', '학', '한', '할', '핥', '함', '합', '핫', '항', '해', '핵', '핸', '핼', '햄', '햅', // CA0-CAF
-    '햇', '했', '행', '햐', '향', '허', '헉', '헌', '헐', '헒', '험', '헙', '헛', '헝', '헤', '헥', // CB0-CBF
-    '헨', '헬', '헴', '헵', '헷', '헹', '혀', '혁', '현', '혈', '혐', '협', '혓', '혔', '형', '혜', // CC0-CCF
-    '혠', '혤', '혭', '호', '혹', '혼', '홀', '홅', '홈', '홉', '홋', '홍', '홑', '화', '확', '환', // CD0-CDF
-    '활', '홧', '황', '홰', '홱', '홴', '횃', '횅', '회', '획', '횐', '횔', '횝', '횟', '횡', '효', // CE0-CEF
-    '횬', '횰', '횹', '횻', '후', '훅', '훈', '훌', '훑', '훔', '훗', '훙', '훠', '훤', '훨', '훰', // CF0-CFF
-    '훵', '훼', '훽', '휀', '휄', '휑', '휘', '휙', '휜', '휠', '휨', '휩', '휫', '휭', '휴', '휵', // D00-D0F
-    '휸', '휼', '흄', '흇', '흉', '흐', '흑', '흔', '흖', '흗', '흘', '흙', '흠', '흡', '흣', '흥', // D10-D1F
-    '흩', '희', '흰', '흴', '흼', '흽', '힁', '히', '힉', '힌', '힐', '힘', '힙', '힛', '힝', NUL, // D20-D2F
-
-    // 33 Jamo characters
-    NUL, 'ㄱ', 'ㄲ', 'ㄴ', 'ㄷ', 'ㄸ', 'ㄹ', 'ㅁ', 'ㅂ', 'ㅃ', 'ㅅ', 'ㅆ', 'ㅇ', 'ㅈ', 'ㅉ', 'ㅊ', // D30-D3F
-    'ㅋ', 'ㅌ', 'ㅍ', 'ㅎ', 'ㅏ', 'ㅐ', 'ㅑ', 'ㅒ', 'ㅓ', 'ㅔ', 'ㅕ', 'ㅖ', 'ㅗ', 'ㅛ', 'ㅜ', 'ㅠ', // D40-D4F
-    'ㅡ', 'ㅣ', NUL, NUL, NUL, NUL, NUL, NUL, NUL, NUL, NUL, NUL, NUL, NUL, NUL, NUL, // D50-D5F
-
-    // 5 additional syllables
-    NUL, '뢔', '쌰', '쎼', '쓔', '쬬', // D60-D65
-];
-pub(crate) struct Gen4StringBuffer<const N: usize>([u16; N]);
-pub(crate) struct Gen4StringVector(Vec<u16>);
-impl<const N: usize> From<Gen4StringBuffer<N>> for String {
-    fn from(value: Gen4StringBuffer<N>) -> Self {
-        let buf = value.0;
-        buf.iter().take_while(|v| **v != 0xFFFF).map(|v| u16_to_char(*v)).into_iter().collect()
-    }
-}
-
-impl From<Gen4StringVector> for String {
-    fn from(value: Gen4StringVector) -> Self {
-        let buf = value.0;
-        buf.iter().take_while(|v| **v != 0xFFFF).map(|v| u16_to_char(*v)).into_iter().collect()
-    }
-}
-
-fn u16_to_char(value: u16) -> char {
-    match value {
-        INTERNATIONAL_TABLE_START..=INTERNATIONAL_TABLE_END => INTERNATIONAL_TABLE[value as usize],
-        KOREAN_TABLE_START..=KOREAN_TABLE_END => KOREAN_TABLE[(value - KOREAN_TABLE_START) as usize],
-        _ => NUL
-    }
-}
-
-mod save {
+pub mod save {
     use chrono::{DateTime, Utc};
-    use crate::save::save::SaveFile;
+    use crate::save::error::{Diagnostic, ReadError, UnknownValue};
+    #[cfg(feature = "write")]
+    use crate::save::error::WriteError;
+    use crate::save::format::container::Container;
+    use crate::save::format::dppt::block::{RawSave, SlotSelection};
+    use crate::save::format::dppt::platinum;
+    use crate::save::format::dppt::sinnoh::{self, Layout};
+    use crate::save::data::badge::Badges;
+    use crate::save::format::dppt::string::{self, Gen4String};
+    use crate::save::format::{CorruptSlot, ParseOptions, SlotLocation};
+    use crate::save::save::{Playtime, SaveFile, SaveGame};
+    #[cfg(feature = "write")]
+    use crate::save::save::SaveGameMut;
 
     pub const EPOCH: i64 = 946684800;
 
+    /// The ID of the first script variable; IDs below this are flags
+    pub const VAR_START: u16 = 0x4000;
+
     pub(crate) struct Timestamp(pub i64);
 
-    impl Into<DateTime<Utc>> for Timestamp {
-        fn into(self) -> DateTime<Utc> {
-            DateTime::from_timestamp(EPOCH + self.0, 0).expect("Invalid timestamp")
+    impl Timestamp {
+        /// The date this represents, if it's in range
+        pub fn to_date(&self) -> Option<DateTime<Utc>> {
+            DateTime::from_timestamp(EPOCH.checked_add(self.0)?, 0)
+        }
+    }
+
+    #[cfg(feature = "write")]
+    impl From<DateTime<Utc>> for Timestamp {
+        fn from(value: DateTime<Utc>) -> Self {
+            Timestamp(value.timestamp() - EPOCH)
         }
     }
 
-    #[derive(Debug)]
-pub enum Locale {
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    #[repr(u8)]
+    pub enum Locale {
         Japan = 1,
         WesternEnglish = 2,
         French = 3,
         Italian = 4,
         German = 5,
         Spanish = 6,
-        SouthKorean = 7
+        SouthKorean = 7,
+        /// Only read in lenient mode
+        Unknown(u8)
     }
 
-    impl From<u8> for Locale {
-        fn from(value: u8) -> Self {
+    impl TryFrom<u8> for Locale {
+        type Error = UnknownValue<u8>;
+
+        fn try_from(value: u8) -> Result<Self, Self::Error> {
             match value {
-                1 => Locale::Japan,
-                2 => Locale::WesternEnglish,
-                3 => Locale::French,
-                4 => Locale::Italian,
-                5 => Locale::German,
-                6 => Locale::Spanish,
-                7 => Locale::SouthKorean,
-                _ => unreachable!()
+                1 => Ok(Locale::Japan),
+                2 => Ok(Locale::WesternEnglish),
+                3 => Ok(Locale::French),
+                4 => Ok(Locale::Italian),
+                5 => Ok(Locale::German),
+                6 => Ok(Locale::Spanish),
+                7 => Ok(Locale::SouthKorean),
+                _ => Err(UnknownValue { name: "Locale", value })
             }
         }
     }
 
-    #[derive(Debug)]
-pub enum Badge {
-        Coal = 1,
-        Forest = 2,
-        Cobble = 4,
-        Fen = 8,
-        Relic = 16,
-        Mine = 32,
-        Icicle = 64,
-        Beacon = 128
+    impl From<Locale> for u8 {
+        fn from(value: Locale) -> Self {
+            match value {
+                Locale::Japan => 1,
+                Locale::WesternEnglish => 2,
+                Locale::French => 3,
+                Locale::Italian => 4,
+                Locale::German => 5,
+                Locale::Spanish => 6,
+                Locale::SouthKorean => 7,
+                Locale::Unknown(value) => value
+            }
+        }
     }
 
-    impl From<u8> for Badge {
-        fn from(value: u8) -> Self {
+    /// Which game a save is from, as stored in the player block
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub enum Game {
+        Diamond,
+        Pearl,
+        Platinum,
+        HeartGold,
+        SoulSilver,
+    }
+
+    impl TryFrom<u8> for Game {
+        type Error = UnknownValue<u8>;
+
+        fn try_from(value: u8) -> Result<Self, Self::Error> {
             match value {
-                1 => Badge::Coal,
-                2 => Badge::Forest,
-                4 => Badge::Cobble,
-                8 => Badge::Fen,
-                16 => Badge::Relic,
-                32 => Badge::Mine,
-                64 => Badge::Icicle,
-                128 => Badge::Beacon,
-                _ => unreachable!()
+                7 => Ok(Game::HeartGold),
+                8 => Ok(Game::SoulSilver),
+                10 => Ok(Game::Diamond),
+                11 => Ok(Game::Pearl),
+                12 => Ok(Game::Platinum),
+                _ => Err(UnknownValue { name: "Game", value })
             }
         }
     }
-    pub(crate) struct Badges(pub Vec<Badge>);
-    impl From<u8> for Badges {
-        fn from(value: u8) -> Self {
-            let mut vec = Vec::new();
-            for i in 0..=7 {
-                let exp = 2_u8.pow(i);
-                if value & exp == exp {
-                    vec.push(Badge::from(exp));
-                }
+
+    impl From<Game> for u8 {
+        fn from(value: Game) -> Self {
+            match value {
+                Game::HeartGold => 7,
+                Game::SoulSilver => 8,
+                Game::Diamond => 10,
+                Game::Pearl => 11,
+                Game::Platinum => 12,
             }
+        }
+    }
 
-            Badges(vec)
+    /// DPPt saves have the Sinnoh badges, HGSS saves the Johto & Kanto badges
+    pub use crate::save::data::badge::Badge;
+
+    /// Convert a single Sinnoh badge's bit; use `Badges` for the whole bitfield
+    impl TryFrom<u8> for Badge {
+        type Error = UnknownValue<u8>;
+
+        fn try_from(value: u8) -> Result<Self, Self::Error> {
+            Badge::SINNOH.into_iter()
+                .enumerate()
+                .find(|(i, _)| 1 << i == value)
+                .map(|(_, badge)| badge)
+                .ok_or(UnknownValue { name: "Badge", value })
+        }
+    }
+
+    impl From<u8> for Badges {
+        fn from(value: u8) -> Self {
+            Badges::from_bits(value, &Badge::SINNOH)
         }
     }
 
     #[cfg(feature = "write")]
-    impl Into<u8> for Badges {
-        fn into(self) -> u8 {
-            let vec = self.0;
-            vec.into_iter().fold(0, |acc, badge| acc + (badge as u8))
+    impl TryFrom<Badges> for u8 {
+        type Error = WriteError;
+
+        fn try_from(value: Badges) -> Result<Self, Self::Error> {
+            Ok(Badges::to_bits(&value.0, &[&Badge::SINNOH])?[0])
         }
     }
 
-    #[derive(Debug)]
+    /// A DPPt or HGSS save
+    #[derive(Debug, PartialEq)]
     pub struct Gen4Save {
+        /// Decides the layout the save is written with
+        pub game: Game,
         pub save_started: DateTime<Utc>,
         pub hall_of_fame_entered: DateTime<Utc>,
         pub base: SaveFile,
         pub locale: Locale,
         pub badges: Vec<Badge>,
+        pub playtime: Playtime,
+        /// Script variables, from `VAR_START`. Platinum's are named by `Vars`.
+        pub vars: Vec<u16>,
+        /// Script flags, 8 to a byte
+        pub flags: Vec<u8>,
+        /// Which copy of the general block was read, and why
+        pub general_slot: SlotSelection,
+        /// Which copy of the storage block was read, and why
+        pub storage_slot: SlotSelection,
+        /// The original file. Typed fields are written over this, so that anything not parsed survives
+        /// a round trip.
+        pub raw: RawSave,
+        /// Pokémon that couldn't be read, in lenient mode
+        pub corrupt_slots: Vec<CorruptSlot>,
+        /// Everything that was skipped over or replaced, in lenient mode
+        pub diagnostics: Vec<Diagnostic>,
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use crate::save::format::dppt::Gen4StringBuffer;
+    impl Gen4Save {
+        /// Parse a Diamond, Pearl, Platinum, HeartGold or SoulSilver save from the contents of a `.sav`
+        pub fn from_bytes(bytes: &[u8]) -> Result<Self, ReadError> {
+            Self::from_bytes_with_options(bytes, ParseOptions::default())
+        }
 
-    /// Can we correctly parse an international name?
-    #[test]
-    fn convert_to_int_string() {
-        let from: [u16; 5] = [0x12F, 0x136, 0x136, 0x14D, 0x149];
-        assert_eq!(String::from(Gen4StringBuffer(from)), "ELLie");
+        /// Parse a Diamond, Pearl, Platinum, HeartGold or SoulSilver save from the contents of a `.sav`,
+        /// see `ParseOptions`.
+        ///
+        /// Which of the three layouts to use is decided by where a valid general block is found. If there
+        /// isn't one, it's read as Platinum, to report why. The save can be in any `Container`.
+        pub fn from_bytes_with_options(bytes: &[u8], options: ParseOptions) -> Result<Self, ReadError> {
+            let (_, image) = Container::unwrap(bytes.to_vec(), sinnoh::SAVE_SIZE);
+            let layout = Layout::detect(&image).unwrap_or(&platinum::LAYOUT);
+            sinnoh::read_save_from_bytes(bytes.to_vec(), options, layout)
+        }
+
+        /// The value of the script variable `id`, if the game has it
+        pub fn var(&self, id: u16) -> Option<u16> {
+            let index = id.checked_sub(VAR_START)?;
+            self.vars.get(index as usize).copied()
+        }
+
+        /// Is the script flag `id` set? `None` if the game doesn't have it.
+        pub fn flag(&self, id: u16) -> Option<bool> {
+            let byte = self.flags.get(id as usize / 8)?;
+            Some(byte & (1 << (id % 8)) != 0)
+        }
+
+        /// Set the script flag `id`, returning `false` if the game doesn't have it
+        pub fn set_flag(&mut self, id: u16, value: bool) -> bool {
+            let Some(byte) = self.flags.get_mut(id as usize / 8) else {
+                return false;
+            };
+
+            match value {
+                true => *byte |= 1 << (id % 8),
+                false => *byte &= !(1 << (id % 8)),
+            }
+            true
+        }
+
+        /// The trainer's name as it's stored in the save this was read from, with its trash. `base`'s
+        /// trainer name is what's written over it. `None` if this wasn't read from a save.
+        pub fn stored_trainer_name(&self) -> Option<Gen4String> {
+            let start = Layout::of(self.game).trainer;
+            self.raw.general_block().map(|block| string::read_string(&block[start..start + 16]))
+        }
+
+        /// Box `index`'s name as it's stored, like `stored_trainer_name`
+        pub fn stored_box_name(&self, index: usize) -> Option<Gen4String> {
+            let start = Layout::of(self.game).box_names + index * 40;
+            self.raw.storage_block().filter(|_| index < 18).map(|block| string::read_string(&block[start..start + 40]))
+        }
+
+        /// The corrupt Pokémon at `location`, if there is one
+        pub fn corrupt_slot(&self, location: SlotLocation) -> Option<&CorruptSlot> {
+            self.corrupt_slots.iter().find(|slot| slot.location == location)
+        }
     }
 
-    /// Can we correctly parse a Korean name?
-    #[test]
-    fn convert_to_kr_string() {
-        let from: [u16; 3] = [0x413, 0x7DE, 0x942];
-        assert_eq!(String::from(Gen4StringBuffer(from)), "개불씨");
+    impl SaveGame for Gen4Save {
+        fn base(&self) -> &SaveFile {
+            &self.base
+        }
+
+        fn playtime(&self) -> Playtime {
+            self.playtime
+        }
+
+        fn badges(&self) -> &[Badge] {
+            &self.badges
+        }
+    }
+
+    #[cfg(feature = "write")]
+    impl SaveGameMut for Gen4Save {
+        fn base_mut(&mut self) -> &mut SaveFile {
+            &mut self.base
+        }
+
+        fn set_playtime(&mut self, playtime: Playtime) {
+            self.playtime = playtime;
+        }
+
+        /// Badges from another region than the game's are `WriteError::InvalidBadge` when the save is
+        /// written
+        fn set_badges(&mut self, badges: Vec<Badge>) {
+            self.badges = badges;
+        }
     }
+}
 
-    /// Do we correctly parse a string with a terminator?
+#[cfg(test)]
+mod tests {
+    /// Are unknown IDs errors rather than panics?
     #[test]
-    fn string_respect_terminator() {
-        let from: [u16; 5] = [0x14C, 0x14D, 0x1AB, 0xFFFF, 0x134];
-        assert_eq!(String::from(Gen4StringBuffer(from)), "hi!");
+    fn convert_unknown_values() {
+        use crate::save::data::dppt::enums::Vars;
+        use crate::save::data::dppt::item::DPPTItem;
+        use crate::save::data::species::Species;
+        use crate::save::error::UnknownValue;
+        use crate::save::format::dppt::save::{Badge, Locale};
+
+        assert_eq!(Species::try_from(393), Ok(Species::Piplup));
+        assert_eq!(Species::try_from(1000), Err(UnknownValue { name: "Species", value: 1000 }));
+        assert_eq!(u16::from(Species::Unknown(1000)), 1000);
+
+        assert_eq!(DPPTItem::try_from(1), Ok(DPPTItem::MasterBall));
+        assert!(DPPTItem::try_from(113).is_err());
+        assert_eq!(DPPTItem::try_from(537).unwrap_err().to_string(), "unknown DPPTItem 537");
+
+        assert_eq!(Vars::try_from(16384), Ok(Vars::VAR_MAP_LOCAL_0));
+        assert_eq!(u16::from(Vars::VAR_MAP_LOCAL_1), 16385);
+        assert!(Vars::try_from(0).is_err());
+
+        assert_eq!(Locale::try_from(2), Ok(Locale::WesternEnglish));
+        assert!(Locale::try_from(0).is_err());
+        assert_eq!(Badge::try_from(16), Ok(Badge::Relic));
+        assert!(Badge::try_from(3).is_err());
     }
 }
\ No newline at end of file
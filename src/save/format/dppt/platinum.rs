@@ -1,350 +1,504 @@
 use crate::save::error::ReadError;
-use crate::save::format::dppt::Gen4StringVector;
-use crate::save::save::{Gender, Pokemon, SaveFile, Trainer};
-use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
-use std::fs::File;
-use std::io;
-use std::io::{Cursor, Read, SeekFrom};
+#[cfg(feature = "write")]
+use crate::save::error::WriteError;
+use crate::save::data::pocket::Pocket;
+use crate::save::format::dppt::block::FooterFormat;
+use crate::save::format::dppt::pokemon::BOX_POKEMON_SIZE;
+use crate::save::format::dppt::save::{Badge, Game, Gen4Save};
+use crate::save::format::dppt::sinnoh::{self, Layout};
+use crate::save::format::{self, ParseOptions};
+use std::io::{Read, Seek};
 use std::path::PathBuf;
-use chrono::{DateTime, Utc};
-use crate::save::data::dppt::item::DPPTItem;
-use crate::save::data::species::Species;
-use crate::save::format::dppt::save::{Badges, Gen4Save, Locale, Timestamp};
-
-const PADDING_BETWEEN_ENTRIES: i64 = 0x08;
 
+const GENERAL_SIZE: usize = 0xCF2C;
+const STORAGE_OFFSET: usize = 0xCF2C;
+const STORAGE_SIZE: usize = 0x121E4;
+
+pub(crate) const LAYOUT: Layout = Layout {
+    general_size: GENERAL_SIZE,
+    storage_offset: STORAGE_OFFSET,
+    storage_size: STORAGE_SIZE,
+    footer: FooterFormat::Sinnoh,
+    trainer: 0x68,
+    badges: &[(0x1A, &Badge::SINNOH)],
+    party: 0xA0,
+    bag: 0x630,
+    pocket_capacity: Pocket::capacity,
+    vars: 0xDAC,
+    var_count: 288,
+    flags: 0xFEC,
+    flag_size: 364,
+    current_box: 0x00,
+    boxes: 0x04,
+    box_size: 30 * BOX_POKEMON_SIZE,
+    box_names: 0x11EE4,
+    games: &[Game::Platinum],
+};
+
+/// Read a Platinum save from `save_file`
 pub fn read_save(save_file: impl Into<PathBuf>) -> Result<Gen4Save, ReadError> {
+    read_save_with_options(save_file, ParseOptions::default())
+}
 
-    fn read_date(readable: &mut impl io::Read) -> Result<DateTime<Utc>, ReadError> {
-        let timestamp = read_i64(readable)?;
-        Ok(Timestamp(timestamp).into())
-    }
-
-    fn seek(seekable: &mut impl io::Seek, position: SeekFrom) -> Result<u64, ReadError> {
-        seekable.seek(position).map_err(|_| ReadError::Generic)
-    }
-
-    let mut save_file = match File::open(save_file.into()) {
-        Ok(file) => file,
-        Err(_e) => return Err(ReadError::FileNotFound)
-    };
-
-    // SYSTEM BLOCK
-    seek(&mut save_file, SeekFrom::Start(0x00))?;
-    let _rtc_offset = read_i64(&mut save_file)?;
-    let mut _mac_address = vec![0u8; 6];
-    let _ = &save_file.read_exact(&mut _mac_address);
-
-    let _owner_month = read_u8(&mut save_file)?;
-    let _owner_date = read_u8(&mut save_file)?;
-    let _canary = read_u32(&mut save_file)?;
-
-    let _rtc_year = read_u32(&mut save_file)?;
-    let _rtc_month = read_u32(&mut save_file)?;
-    let _rtc_date = read_u32(&mut save_file)?;
-    let _rtc_weekday = read_u32(&mut save_file)?;
-
-    let _rtc_hour = read_u32(&mut save_file)?;
-    let _rtc_minute = read_u32(&mut save_file)?;
-    let _rtc_second = read_u32(&mut save_file)?;
-
-    let _day = read_u32(&mut save_file)?;
-
-
-    // PLAYER BLOCK
-    let start_date: DateTime<Utc> = read_date(&mut save_file)?;
-    let hof_date: DateTime<Utc> = read_date(&mut save_file)?;
-
-    let _save_penalty = read_u32(&mut save_file)?;
-    let _mystery_gift_unlocked = read_u8(&mut save_file)?;
-
-    seek(&mut save_file, SeekFrom::Current(0x03))?; // padding_49
-
-    let _network_id = read_i32(&mut save_file)?;
-
-    seek(&mut save_file, SeekFrom::Current(0x0C))?; // unused_50
-
-    seek(&mut save_file, SeekFrom::Current(PADDING_BETWEEN_ENTRIES))?;
-
-    let _options = read_u16(&mut save_file)?;
-    let _opts_frame
-        = (_options & 0b0_1111_00_0_0_00_0000) >> 10;
-    let _opts_button_mode
-        = (_options & 0b0_0000_11_0_0_00_0000) >> 8;
-    let _opts_battle_scene
-        = (_options & 0b0_0000_00_1_0_00_0000) >> 7;
-    let _opts_battle_style
-        = (_options & 0b0_0000_00_0_1_00_0000) >> 6;
-    let _opts_sound_mode
-        = (_options & 0b0_0000_00_0_0_11_0000) >> 4;
-    let _opts_text_speed
-        = _options & 0b0_0000_00_0_0_00_1111;
-
-    seek(&mut save_file, SeekFrom::Current(0x02))?; // padding_02
-    
-    let trainer_name = read_string(&mut save_file, 8)?;
-
-    let trainer_id = read_u16(&mut save_file)?;
-    let trainer_secret_id = read_u16(&mut save_file)?;
-    let trainer_money = read_u32(&mut save_file)?;
-    let trainer_gender = match read_u8(&mut save_file)? {
-        0 => Gender::Male,
-        1 => Gender::Female,
-        _ => return Err(ReadError::Generic)
-    };
-    let locale = Locale::from(read_u8(&mut save_file)?);
-    let badges: Badges = read_u8(&mut save_file)?.into();
-
-    let trainer = Trainer::new(trainer_name, trainer_id, Some(trainer_secret_id), trainer_gender);
-    let mut base_save = SaveFile::new(trainer.clone(), trainer_money);
-
-    let _appearance = read_u8(&mut save_file)?;
-    let _game_code = read_u8(&mut save_file)?; // @todo: can we assert this is valid?
-    let _postgame_flags = read_u8(&mut save_file)?; // isMainStoryCleared, hasNationalDex
-
-    let playtime = (read_u16(&mut save_file)? as u32 * 3660) + (read_u8(&mut save_file)? as u32 * 60) + (read_u8(&mut save_file)? as u32);
-    println!("{:?}", playtime);
-
-    seek(&mut save_file, SeekFrom::Current(0x02))?; // padding_04
-
-    seek(&mut save_file, SeekFrom::Current(PADDING_BETWEEN_ENTRIES))?;
-
-    // PARTY BLOCK
-    let _max_party_count = read_u8(&mut save_file)?;
-    seek(&mut save_file, SeekFrom::Current(0x03))?;
-    let _number_in_party = read_u8(&mut save_file)?;
-    seek(&mut save_file, SeekFrom::Current(0x03))?;
-
-    seek(&mut save_file, SeekFrom::Start(0xA0))?;
-    for _i in 0..6 {
-        let mut buf = vec![0u8; 236];
-        let _ = &save_file.read_exact(&mut buf);
-
-        let mut decrypted_blob = Cursor::new(decrypt_pokemon_blob(buf.clone())?);
-        seek(&mut decrypted_blob, SeekFrom::Start(0x08))?;
-        let species = read_u16(&mut decrypted_blob)?;
-        if species == 0 {
-            continue;
-        }
-        
-        let species = Species::from(species);
-        let _held_item = read_u16(&mut decrypted_blob)?;
-        let original_trainer_id = read_u16(&mut decrypted_blob)?;
-        let original_secret_id = read_u16(&mut decrypted_blob)?;
-        let mut pkmn = Pokemon::new(species);
-        
-        seek(&mut decrypted_blob, SeekFrom::Start(0x48))?;
-        let pokemon_name = read_string(&mut decrypted_blob, 20)?;
-        pkmn.set_name(pokemon_name);
-        
-        if original_trainer_id == trainer_id && original_secret_id == trainer_secret_id {
-            pkmn.set_trainer(trainer.clone());
-        }
-        
-        base_save.party.push(pkmn);
-    }
-
-    // BAG BLOCK
-    seek(&mut save_file, SeekFrom::Start(0x00630))?;
-    for _i in 0..(165+50+100+40+64+15+13+12) {
-        // @todo: skip offsets when reaching a None item
-        let item_id = read_u16(&mut save_file)?;
-        let qty = read_u16(&mut save_file)?;
+/// Read a Platinum save from `save_file`, see `ParseOptions`
+pub fn read_save_with_options(save_file: impl Into<PathBuf>, options: ParseOptions) -> Result<Gen4Save, ReadError> {
+    format::read_file(save_file, options, read_save_from_bytes)
+}
 
-        let item = DPPTItem::from(item_id);
-        base_save.add_item(item, qty);
-    }
+/// Read a Platinum save from `reader`, starting at its current position
+pub fn read_save_from_reader<R: Read + Seek>(reader: &mut R) -> Result<Gen4Save, ReadError> {
+    read_save_from_reader_with_options(reader, ParseOptions::default())
+}
 
-    seek(&mut save_file, SeekFrom::Start(0x0CF2C))?;
-    println!("current_box: {:?}", read_u32(&mut save_file)?); // current_box?
-    let mut boxes: Vec<crate::save::save::Box> = Vec::with_capacity(18);
-    for _i in 0..18 {
-        let mut pkmn_box = crate::save::save::Box::new(30);
-        for _j in 0..30 {
-            let mut buf = vec![0u8; 136];
-            let _ = &save_file.read_exact(&mut buf);
-
-            let mut decrypted_blob = Cursor::new(decrypt_pokemon_blob(buf.clone())?);
-            seek(&mut decrypted_blob, SeekFrom::Start(0x08))?;
-            let species = read_u16(&mut decrypted_blob)?;
-            if species == 0 {
-                continue;
-            }
+/// Read a Platinum save from `reader`, starting at its current position. See `ParseOptions`.
+pub fn read_save_from_reader_with_options<R: Read + Seek>(reader: &mut R, options: ParseOptions) -> Result<Gen4Save, ReadError> {
+    format::read_reader(reader, options, read_save_from_bytes)
+}
 
-            let species = Species::from(species);
-            let _held_item = read_u16(&mut decrypted_blob)?;
-            let original_trainer_id = read_u16(&mut decrypted_blob)?;
-            let original_secret_id = read_u16(&mut decrypted_blob)?;
-            let mut pkmn = Pokemon::new(species);
+pub(crate) fn read_save_from_bytes(data: Vec<u8>, options: ParseOptions) -> Result<Gen4Save, ReadError> {
+    sinnoh::read_save_from_bytes(data, options, &LAYOUT)
+}
 
-            seek(&mut decrypted_blob, SeekFrom::Start(0x48))?;
-            let pokemon_name = read_string(&mut decrypted_blob, 20)?;
-            pkmn.set_name(pokemon_name);
+/// Write `save` to `save_file`. See `write_save_to_bytes`.
+#[cfg(feature = "write")]
+pub fn write_save(save: &Gen4Save, save_file: impl Into<PathBuf>) -> Result<(), WriteError> {
+    let data = write_save_to_bytes(save)?;
+    Ok(std::fs::write(save_file.into(), data)?)
+}
 
-            if original_trainer_id == trainer_id && original_secret_id == trainer_secret_id {
-                pkmn.set_trainer(trainer.clone());
-            }
+/// Serialize `save` into a full 512 KiB `.sav` image.
+///
+/// Typed fields are written over the original file (`save.raw`), so anything not parsed is kept. A
+/// block is only written if its contents have changed; like the game, the new copy goes to the opposite
+/// partition to the one it was read from, with its footer's link & save IDs bumped. Writing back an
+/// unmodified save therefore gives an identical file.
+///
+/// If there is no original file, the save is written over blank blocks. A Diamond or Pearl save is
+/// written with their layout, see `save.game`.
+#[cfg(feature = "write")]
+pub fn write_save_to_bytes(save: &Gen4Save) -> Result<Vec<u8>, WriteError> {
+    sinnoh::write_save_to_bytes(save)
+}
 
-            pkmn_box.set_pkmn(_j, pkmn);
-        }
-        boxes.push(pkmn_box);
-    }
+#[cfg(test)]
+mod tests {
+    use crate::save::format::dppt::platinum::read_save;
 
-    // box names
-    for i in 0..boxes.len() {
-        let current_box: &mut crate::save::save::Box = boxes.get_mut(i).unwrap();
-        (*current_box).set_name(read_string(&mut save_file, 20)?);
+    #[test]
+    fn read_platinum() {
+        let read = read_save("test-files/platinum.sav");
+        println!("{:#?}", read);
     }
 
-    // box wallpapers
-    for i in 0..boxes.len() {
-        let current_box: &mut crate::save::save::Box = boxes.get_mut(i).unwrap();
-        (*current_box).set_wallpaper(read_u8(&mut save_file)?);
+    /// Do we keep the underlying IO error when the file can't be opened?
+    #[test]
+    fn read_missing_file() {
+        let read = read_save("test-files/does-not-exist.sav");
+        assert!(matches!(read, Err(crate::save::error::ReadError::Io(e)) if e.kind() == std::io::ErrorKind::NotFound));
     }
 
-    base_save.boxes = boxes;
-
-    // can we get starter pokemon?
-    let var_idx: u16 = crate::save::data::dppt::enums::Vars::VAR_PLAYER_STARTER.into();
+    #[cfg(feature = "write")]
+    mod write {
+        use super::super::*;
+        use crate::save::data::dppt::item::DPPTItem;
+        use crate::save::data::species::Species;
+        use crate::save::error::Block;
+        use crate::save::format::dppt::block::{BlockFooter, SaveSlot, PARTITION_SIZE};
+        use crate::save::format::dppt::pokemon::{decrypt_pokemon_blob, encrypt_pokemon_blob, Gen4Pokemon, BOX_POKEMON_SIZE, PARTY_POKEMON_SIZE};
+        use crate::save::format::dppt::save::{Badge, Locale};
+        use crate::save::format::SlotLocation;
+        use crate::save::save::{Gender, Playtime, Pokemon};
+        use std::io::Cursor;
+
+        fn temp_path(name: &str) -> PathBuf {
+            std::env::temp_dir().join(format!("pokerus-{}-{}.sav", name, std::process::id()))
+        }
 
-    seek(&mut save_file, SeekFrom::Start(0xDAC))?;
-    seek(&mut save_file, SeekFrom::Current(((var_idx - 16384) * 2) as i64))?;
-    println!("starter: {:?}", Species::from(read_u16(&mut save_file)?));
+        /// Does a written save read back identically, alternating partitions each time?
+        #[test]
+        fn write_round_trip() {
+            let save = sinnoh::test_save(Game::Platinum);
+            let path = temp_path("round-trip");
+
+            write_save(&save, &path).unwrap();
+            let written = std::fs::read(&path).unwrap();
+            let read = read_save(&path).unwrap();
+            assert_eq!(read.base, save.base);
+            let sparky = read.base.party[1].gen4().unwrap();
+            assert_eq!((sparky.species, sparky.nickname.text()), (Species::Pikachu, "Sparky"));
+            assert_eq!((sparky.experience, sparky.friendship, sparky.ot_name.text()), (8_000, 255, "ASH"));
+            assert_eq!(sparky.battle_stats, Some(sparky.healed_battle_stats()));
+            // nothing else was lost either
+            assert_eq!(sinnoh::rewrite_blank(read_save(&path).unwrap()), written);
+            assert_eq!(read.general_slot.slot, SaveSlot::Second);
+            assert_eq!(read.storage_slot.slot, SaveSlot::Second);
+            assert_eq!(read.general_slot.footer.save_id, 2);
+
+            let mut edited = read;
+            edited.base.party[0].set_name("Pip".into());
+            write_save(&edited, &path).unwrap();
+            let reread = read_save(&path).unwrap();
+            assert_eq!(reread.base.party[0].name(), "Pip");
+            assert_eq!(reread.general_slot.slot, SaveSlot::First);
+            assert_eq!(reread.general_slot.footer.save_id, 3);
+            // the storage block wasn't changed, so stays where it was
+            assert_eq!(reread.storage_slot.slot, SaveSlot::Second);
+            // the edited Pokémon's data was read as it was written, so the rest of it matches
+            assert_eq!(sinnoh::rewrite_blank(reread), sinnoh::rewrite_blank(edited));
+
+            std::fs::remove_file(path).unwrap();
+        }
 
-    Ok(Gen4Save {
-        save_started: start_date,
-        hall_of_fame_entered: hof_date,
-        base: base_save,
-        locale,
-        badges: badges.0,
-    })
-}
+        /// Are edits to a Pokémon's gen 4 data written, including when it's moved between party & box?
+        #[test]
+        fn write_gen4_pokemon() {
+            let bytes = write_save_to_bytes(&sinnoh::test_save(Game::Platinum)).unwrap();
+            let mut save = read_save_from_bytes(bytes, ParseOptions::default()).unwrap();
+
+            let mut piplup = save.base.party.remove(0);
+            let mut gen4 = piplup.gen4().unwrap().clone();
+            gen4.moves[1].id = 33; // Tackle
+            gen4.ivs.attack = 31;
+            gen4.nickname = "Pip".into();
+            piplup.set_gen4(gen4);
+            assert_eq!(piplup.name(), "Pip");
+            piplup.set_friendship(200);
+            save.base.boxes[1].set_pkmn(0, piplup);
+
+            let bytes = write_save_to_bytes(&save).unwrap();
+            let reread = read_save_from_bytes(bytes, ParseOptions::default()).unwrap();
+            let moved = reread.base.boxes[1].get_pkmn(0).unwrap();
+            assert_eq!(moved.name(), "Pip");
+            let gen4 = moved.gen4().unwrap();
+            assert_eq!((gen4.moves[1].id, gen4.ivs.attack, gen4.friendship), (33, 31, 200));
+            assert_eq!(gen4.battle_stats, None);
+            assert_eq!(reread.base.party.len(), save.base.party.len());
+        }
 
-fn read_u8(readable: &mut impl io::Read) -> Result<u8, ReadError> {
-    readable.read_u8().map_err(|_| ReadError::Generic)
-}
+        /// Is a Pokémon without gen 4 data converted in full, rather than just its species, OT & name?
+        #[test]
+        fn write_generic_pokemon() {
+            let mut save = read_save_from_bytes(write_save_to_bytes(&sinnoh::test_save(Game::Platinum)).unwrap(), ParseOptions::default()).unwrap();
+            let trainer = save.base.trainer().clone();
+            for (species, location) in [(Species::Turtwig, SlotLocation::Party(2)), (Species::Chimchar, SlotLocation::Box(4, 7))] {
+                let mut pokemon = Pokemon::new(species);
+                pokemon.set_trainer(trainer.clone());
+                pokemon.set_experience(2_000);
+                pokemon.set_friendship(120);
+                match location {
+                    SlotLocation::Party(_) => save.base.party.push(pokemon),
+                    SlotLocation::Box(i, j) => save.base.boxes[i].set_pkmn(j, pokemon),
+                }
+            }
 
-fn read_u16(readable: &mut impl io::Read) -> Result<u16, ReadError> {
-    readable.read_u16::<LittleEndian>().map_err(|_| ReadError::Generic)
-}
+            let bytes = write_save_to_bytes(&save).unwrap();
+            let reread = read_save_from_bytes(bytes, ParseOptions::default()).unwrap();
+            let turtwig = &reread.base.party[2];
+            let chimchar = reread.base.boxes[4].get_pkmn(7).unwrap();
+            for (pokemon, species, name) in [(turtwig, Species::Turtwig, "TURTWIG"), (chimchar, Species::Chimchar, "CHIMCHAR")] {
+                assert_eq!((pokemon.species(), pokemon.name(), pokemon.trainer()), (&species, name, Some(&trainer)));
+                assert_eq!((pokemon.experience(), pokemon.friendship()), (2_000, 120));
+
+                let gen4 = pokemon.gen4().unwrap();
+                assert_eq!((gen4.experience, gen4.friendship, gen4.met_level), (2_000, 120, gen4.level()));
+                assert_eq!((gen4.ot_name.text(), gen4.ot_id, gen4.ot_secret_id, gen4.ot_gender), ("ELLie", 12345, 54321, Gender::Female));
+                assert!(!gen4.is_shiny());
+                assert_eq!(gen4.gender, gen4.gender_from_pid());
+                assert_eq!(gen4.ability, u8::from(gen4.ability_from_pid()));
+                assert_eq!((gen4.moves[0].id, gen4.moves[0].pp), (33, 35));
+                assert_eq!((gen4.ball, gen4.met_location, gen4.met_location_extended), (4, 3002, 3002));
+                assert_eq!((gen4.origin_game, gen4.language), (u8::from(Game::Platinum), u8::from(Locale::WesternEnglish)));
+                assert_eq!(gen4.met_date, Some(chrono::Local::now().date_naive()));
+                assert_eq!((gen4.nickname.text(), gen4.is_nicknamed), (name, false));
+            }
+            assert_eq!(turtwig.gen4().unwrap().battle_stats, Some(turtwig.gen4().unwrap().healed_battle_stats()));
+            assert_eq!(chimchar.gen4().unwrap().battle_stats, None);
+            // each is given its own PID
+            assert_ne!(turtwig.gen4().unwrap().pid, chimchar.gen4().unwrap().pid);
+
+            // a species gen 4 doesn't have can't be written
+            save.base.party.push(Pokemon::new(Species::Snivy));
+            assert!(matches!(write_save_to_bytes(&save), Err(WriteError::UnknownValue(_))));
+        }
 
-fn read_u32(readable: &mut impl io::Read) -> Result<u32, ReadError> {
-    readable.read_u32::<LittleEndian>().map_err(|_| ReadError::Generic)
-}
+        /// Does a Pokémon withdrawn from a box get battle stats for its level, at full HP?
+        #[test]
+        fn write_box_pokemon_to_party() {
+            let bytes = write_save_to_bytes(&sinnoh::test_save(Game::Platinum)).unwrap();
+            let mut save = read_save_from_bytes(bytes, ParseOptions::default()).unwrap();
+            let bidoof = save.base.boxes[0].get_pkmn(3).unwrap().gen4().unwrap();
+            assert_eq!(bidoof.battle_stats, None);
+            save.base.party.push(Pokemon::from(bidoof));
+
+            let bytes = write_save_to_bytes(&save).unwrap();
+            let reread = read_save_from_bytes(bytes, ParseOptions::default()).unwrap();
+            let gen4 = reread.base.party[2].gen4().unwrap();
+            let stats = gen4.battle_stats.unwrap();
+            assert_eq!(stats.level, gen4.level());
+            assert_eq!((stats.current_hp, stats.max_hp), (gen4.stats().hp, gen4.stats().hp));
+            assert!(stats.max_hp > 0);
+        }
 
-fn read_i64(readable: &mut impl io::Read) -> Result<i64, ReadError> {
-    readable.read_i64::<LittleEndian>().map_err(|_| ReadError::Generic)
-}
+        /// Can a save be read & edited through `SaveGameMut`, without knowing it's a gen 4 save?
+        #[test]
+        fn edit_through_save_game() {
+            use crate::save::data::pocket::Pocket;
+            use crate::save::data::item::Item;
+            use crate::save::save::{SaveGame, SaveGameMut};
+
+            fn play_more(save: &mut impl SaveGameMut) {
+                let playtime = save.playtime();
+                save.set_playtime(Playtime { hours: playtime.hours + 1, ..playtime });
+                save.set_money(save.money() + 500);
+                save.set_item(Item::Gen4(DPPTItem::UltraBall), 10);
+                let caught = save.party_mut().pop().unwrap();
+                save.boxes_mut()[2].set_pkmn(0, caught);
+                let mut badges = save.badges().to_vec();
+                badges.push(Badge::Beacon);
+                save.set_badges(badges);
+            }
 
-fn read_i32(readable: &mut impl io::Read) -> Result<i32, ReadError> {
-    readable.read_i32::<LittleEndian>().map_err(|_| ReadError::Generic)
-}
+            let mut save = read_save_from_bytes(write_save_to_bytes(&sinnoh::test_save(Game::Platinum)).unwrap(), ParseOptions::default()).unwrap();
+            play_more(&mut save);
+            let reread = read_save_from_bytes(write_save_to_bytes(&save).unwrap(), ParseOptions::default()).unwrap();
+
+            assert_eq!(reread.playtime(), Playtime { hours: 124, minutes: 45, seconds: 6 });
+            assert_eq!(reread.money(), 3500);
+            assert_eq!(reread.badges(), &[Badge::Coal, Badge::Relic, Badge::Beacon]);
+            assert_eq!(reread.party().len(), 1);
+            assert_eq!(reread.boxes()[2].get_pkmn(0).unwrap().name(), "Sparky");
+            assert_eq!(reread.pocket(Pocket::PokeBalls), vec![(Item::Gen4(DPPTItem::MasterBall), 1), (Item::Gen4(DPPTItem::UltraBall), 10)]);
+            assert_eq!(reread.trainer().name(), "ELLie");
+        }
 
-fn read_string(readable: &mut impl io::Read, length: usize) -> Result<String, ReadError> {
-    let mut vec: Vec<u16> = Vec::with_capacity(length);
-    for _i in 0..length {
-        vec.push(read_u16(readable)?);
-    }
+        /// Can Pokémon be exported to `.pk4` files and imported into another slot?
+        #[test]
+        fn export_import_pk4() {
+            let mut save = read_save_from_bytes(write_save_to_bytes(&sinnoh::test_save(Game::Platinum)).unwrap(), ParseOptions::default()).unwrap();
+            let party_path = temp_path("party-pk4");
+            let box_path = temp_path("box-pk4");
+
+            save.base.export_pk4(SlotLocation::Party(0), &party_path).unwrap();
+            save.base.boxes[0].export_pk4(3, &box_path).unwrap();
+            assert_eq!(std::fs::read(&party_path).unwrap().len(), PARTY_POKEMON_SIZE);
+            assert_eq!(std::fs::read(&box_path).unwrap().len(), BOX_POKEMON_SIZE);
+            assert!(matches!(save.base.boxes[0].export_pk4(29, &box_path), Err(WriteError::MissingPokemon)));
+
+            save.base.import_pk4(SlotLocation::Box(2, 5), &party_path).unwrap();
+            save.base.import_pk4(SlotLocation::Party(6), &box_path).unwrap();
+            save.base.boxes[3].import_pk4(0, &box_path).unwrap();
+            assert!(matches!(save.base.import_pk4(SlotLocation::Box(18, 0), &box_path), Err(ReadError::InvalidBox(18))));
+            assert!(matches!(save.base.import_pk4(SlotLocation::Box(0, 30), &box_path), Err(ReadError::InvalidSlot(30))));
+            assert!(matches!(save.base.boxes[0].import_pk4(30, &box_path), Err(ReadError::InvalidSlot(30))));
+
+            let reread = read_save_from_bytes(write_save_to_bytes(&save).unwrap(), ParseOptions::default()).unwrap();
+            // moving between party & box changes the battle stats, so compare what's in a box
+            let pk4 = |pokemon: Option<&Pokemon>| pokemon.unwrap().gen4().unwrap().to_pk4().unwrap();
+            let piplup = reread.base.boxes[2].get_pkmn(5).unwrap();
+            assert_eq!(pk4(Some(piplup)), pk4(save.base.party.first()));
+            assert_eq!(piplup.trainer(), Some(save.base.trainer()));
+            assert_eq!(pk4(reread.base.party.last()), pk4(save.base.boxes[0].get_pkmn(3)));
+            // a box has no trainer to compare with, until the save is read back
+            assert_eq!(save.base.boxes[3].get_pkmn(0).unwrap().trainer(), None);
+            assert_eq!(reread.base.boxes[3].get_pkmn(0), save.base.boxes[0].get_pkmn(3));
+
+            // the box Pokémon was given battle stats in the party, and exports with them
+            let imported = save.base.party.last().unwrap().gen4().unwrap();
+            assert_eq!(imported.battle_stats, Some(imported.healed_battle_stats()));
+            save.base.export_pk4(SlotLocation::Party(2), &party_path).unwrap();
+            let exported = Gen4Pokemon::from_pk4(&std::fs::read(&party_path).unwrap()).unwrap();
+            assert_eq!(exported.battle_stats.unwrap().level, imported.level());
+
+            while save.base.party.len() < 6 {
+                save.base.import_pk4(SlotLocation::Party(6), &box_path).unwrap();
+            }
+            assert!(matches!(save.base.import_pk4(SlotLocation::Party(6), &box_path), Err(ReadError::PartyFull)));
 
-    Ok(String::from(Gen4StringVector(vec)))
-}
+            std::fs::remove_file(party_path).unwrap();
+            std::fs::remove_file(box_path).unwrap();
+        }
 
-fn decrypt_pokemon_blob(blob: Vec<u8>) -> Result<Vec<u8>, ReadError> {
-    let blob_len = blob.len();
-    let num_words = (blob_len - 8) / 2;
-    let is_party = blob_len == 236;
+        /// Does writing back an unmodified save give an identical file?
+        #[test]
+        fn write_unmodified_is_identical() {
+            let path = temp_path("unmodified");
+            write_save(&sinnoh::test_save(Game::Platinum), &path).unwrap();
 
-    let mut cursor = Cursor::new(blob);
+            // fill in some data we don't parse, e.g. the MAC address, and fix up the checksum
+            let mut original = std::fs::read(&path).unwrap();
+            let general = &mut original[PARTITION_SIZE..PARTITION_SIZE + GENERAL_SIZE];
+            general[0x08..0x0E].copy_from_slice(&[0x00, 0x09, 0xBF, 0x12, 0x34, 0x56]);
+            BlockFooter::from_block(general, FooterFormat::Sinnoh).write_to_block(general);
+            std::fs::write(&path, &original).unwrap();
 
-    let pv = read_u32(&mut cursor)?;
-    let flags = read_u16(&mut cursor)?;
-    let checksum = read_u16(&mut cursor)?;
-    let shift = (pv >> 13) & 31;
+            let read = read_save(&path).unwrap();
+            assert_eq!(write_save_to_bytes(&read).unwrap(), original);
 
-    let mut decrypted_blob: Vec<u16> = Vec::with_capacity(num_words);
+            std::fs::remove_file(path).unwrap();
+        }
 
-    let mut prng: u32 = checksum as u32;
-    for _i in 0..num_words {
-        prng = u32::wrapping_mul(0x41C64E6D, prng) + 0x00006073;
-        let mut xor: u16 = (prng >> 16) as u16;
+        /// Does editing one field change only its bytes, and the footer?
+        #[test]
+        fn write_edit_changes_only_field() {
+            let path = temp_path("edit");
+            write_save(&sinnoh::test_save(Game::Platinum), &path).unwrap();
+            let original = std::fs::read(&path).unwrap();
+
+            let mut read = read_save(&path).unwrap();
+            read.base.boxes[1].set_name("PC".into());
+            let written = write_save_to_bytes(&read).unwrap();
+
+            // the general block is untouched
+            assert_eq!(written[..STORAGE_OFFSET], original[..STORAGE_OFFSET]);
+            assert_eq!(written[PARTITION_SIZE..PARTITION_SIZE + STORAGE_OFFSET], original[PARTITION_SIZE..PARTITION_SIZE + STORAGE_OFFSET]);
+
+            let original_start = read.storage_slot.slot.offset() + STORAGE_OFFSET;
+            let written_start = read.storage_slot.slot.other().offset() + STORAGE_OFFSET;
+            let name = (0x11EE4 + 40)..(0x11EE4 + 80);
+            let footer = (STORAGE_SIZE - 0x14)..STORAGE_SIZE;
+            for i in 0..STORAGE_SIZE {
+                if original[original_start + i] != written[written_start + i] {
+                    assert!(name.contains(&i) || footer.contains(&i), "unexpected change at {:#x}", i);
+                }
+            }
 
-        #[cfg(target_endian = "big")]
-        {
-            xor = xor.to_le();
+            let reread = Gen4Save::from_bytes(&written).unwrap();
+            assert_eq!(reread.base.boxes[1].name(), "PC");
+            // the rest of the old name is kept after the terminator
+            assert_eq!(reread.stored_box_name(1).unwrap().trash()[..3], read.stored_box_name(1).unwrap().raw()[3..6]);
+            assert_eq!(reread.stored_trainer_name().unwrap().text(), "ELLie");
+            assert_eq!(sinnoh::test_save(Game::Platinum).stored_box_name(1), None);
+            std::fs::remove_file(path).unwrap();
         }
 
-        let word = read_u16(&mut cursor)?;
-        decrypted_blob.push(word ^ xor);
-    }
+        /// Is a pocket with more items than it has slots an error, naming the pocket?
+        #[test]
+        fn write_full_pocket() {
+            use crate::save::data::pocket::Pocket;
 
-    // now shuffle
-    let num_blocks = 4;
-    let idx = shift * 4;
-    let block_positions: [u8; 128] = [
-        0, 1, 2, 3,
-        0, 1, 3, 2,
-        0, 2, 1, 3,
-        0, 3, 1, 2,
-        0, 2, 3, 1,
-        0, 3, 2, 1,
-        1, 0, 2, 3,
-        1, 0, 3, 2,
-        2, 0, 1, 3,
-        3, 0, 1, 2,
-        2, 0, 3, 1,
-        3, 0, 2, 1,
-        1, 2, 0, 3,
-        1, 3, 0, 2,
-        2, 1, 0, 3,
-        3, 1, 0, 2,
-        2, 3, 0, 1,
-        3, 2, 0, 1,
-        1, 2, 3, 0,
-        1, 3, 2, 0,
-        2, 1, 3, 0,
-        3, 1, 2, 0,
-        2, 3, 1, 0,
-        3, 2, 1, 0,
-
-        // duplicates of 0-7 to eliminate modulus
-        0, 1, 2, 3,
-        0, 1, 3, 2,
-        0, 2, 1, 3,
-        0, 3, 1, 2,
-        0, 2, 3, 1,
-        0, 3, 2, 1,
-        1, 0, 2, 3,
-        1, 0, 3, 2,
-    ];
-
-    let res: Vec<u8> = Vec::with_capacity(blob_len);
-    let mut res_cursor = Cursor::new(res);
-    res_cursor.write_u32::<LittleEndian>(pv).map_err(|_| ReadError::Generic)?;
-    res_cursor.write_u16::<LittleEndian>(flags).map_err(|_| ReadError::Generic)?;
-    res_cursor.write_u16::<LittleEndian>(checksum).map_err(|_| ReadError::Generic)?;
-
-    let start_pos: usize = 0;
-    for i in 0..num_blocks {
-        let src_idx = start_pos + (16 * block_positions[(idx + i) as usize]) as usize;
-        let src_blob: &[u16] = &decrypted_blob[src_idx..(src_idx + 16)];
-        src_blob.iter().for_each(|&x| res_cursor.write_u16::<LittleEndian>(x).unwrap());
-    }
+            let mut save = sinnoh::test_save(Game::Platinum);
+            for id in 1..=16u16 {
+                save.base.add_item(DPPTItem::try_from(id).unwrap(), 1);
+            }
+            let err = write_save_to_bytes(&save).unwrap_err();
+            assert!(matches!(err, WriteError::PocketFull { pocket: Pocket::PokeBalls, capacity: 15 }));
+        }
 
-    if is_party {
-        let party_blob = &decrypted_blob[64..];
-        party_blob.iter().for_each(|&x| res_cursor.write_u16::<LittleEndian>(x).unwrap());
-    }
+        /// Can we read from memory, and from a reader that doesn't start at the save?
+        #[test]
+        fn read_from_bytes_and_reader() {
+            let save = sinnoh::test_save(Game::Platinum);
+            let written = write_save_to_bytes(&save).unwrap();
+            let read = Gen4Save::from_bytes(&written).unwrap();
+            assert_eq!(read.base, save.base);
+
+            let mut prefixed = vec![0xAAu8; 0x10];
+            prefixed.extend_from_slice(&written);
+            let mut reader = Cursor::new(prefixed);
+            reader.set_position(0x10);
+            assert_eq!(read_save_from_reader(&mut reader).unwrap(), read);
+
+            assert!(matches!(Gen4Save::from_bytes(&written[..0x100]), Err(ReadError::NoValidBlock { .. })));
+        }
 
-    Ok(res_cursor.into_inner())
-}
+        /// Are bad values and corrupt Pokémon errors in strict mode, and kept in lenient mode?
+        #[test]
+        fn read_lenient() {
+            let mut written = write_save_to_bytes(&sinnoh::test_save(Game::Platinum)).unwrap();
+            let general_start = Gen4Save::from_bytes(&written).unwrap().general_slot.slot.offset();
+            let storage_start = Gen4Save::from_bytes(&written).unwrap().storage_slot.slot.offset() + STORAGE_OFFSET;
+
+            let general = &mut written[general_start..general_start + GENERAL_SIZE];
+            general[0x81] = 0x20; // locale
+            general[0x630..0x632].copy_from_slice(&1000u16.to_le_bytes()); // first item
+            general[0x632..0x634].copy_from_slice(&5u16.to_le_bytes());
+
+            // Piplup becomes an unknown species
+            let mut piplup = decrypt_pokemon_blob(&general[0xA0..0xA0 + PARTY_POKEMON_SIZE]).unwrap();
+            piplup[0x08..0x0A].copy_from_slice(&1000u16.to_le_bytes());
+            general[0xA0..0xA0 + PARTY_POKEMON_SIZE].copy_from_slice(&encrypt_pokemon_blob(&piplup).unwrap());
+            // Sparky's data no longer matches its checksum
+            let sparky = 0xA0 + PARTY_POKEMON_SIZE;
+            general[sparky + 0x20] ^= 0xFF;
+            BlockFooter::from_block(general, FooterFormat::Sinnoh).write_to_block(general);
+
+            // Bidoof's data no longer matches its checksum
+            let storage = &mut written[storage_start..storage_start + STORAGE_SIZE];
+            let bidoof = 0x04 + 3 * BOX_POKEMON_SIZE;
+            storage[bidoof + 0x20] ^= 0xFF;
+            BlockFooter::from_block(storage, FooterFormat::Sinnoh).write_to_block(storage);
+
+            assert!(matches!(Gen4Save::from_bytes(&written), Err(ReadError::InvalidValue { field: "locale", .. })));
+
+            let read = Gen4Save::from_bytes_with_options(&written, ParseOptions { strict: false }).unwrap();
+            assert_eq!(read.locale, Locale::Unknown(0x20));
+            assert_eq!(read.base.get_item(DPPTItem::Unknown(1000)), Some(&5));
+            assert_eq!(*read.base.party[0].species(), Species::Unknown(1000));
+            assert!(read.base.boxes[0].get_pkmn(3).is_none());
+            assert_eq!(read.base.party.len(), 1);
+
+            let corrupt = read.corrupt_slot(SlotLocation::Box(0, 3)).unwrap();
+            assert_eq!(corrupt.offset, storage_start + bidoof);
+            assert_eq!(corrupt.bytes, written[corrupt.offset..corrupt.offset + BOX_POKEMON_SIZE]);
+            let corrupt = read.corrupt_slot(SlotLocation::Party(1)).unwrap();
+            assert_eq!(corrupt.offset, general_start + sparky);
+            assert_eq!(corrupt.bytes, written[corrupt.offset..corrupt.offset + PARTY_POKEMON_SIZE]);
+
+            let fields: Vec<&str> = read.diagnostics.iter().map(|d| d.field).collect();
+            assert_eq!(fields, vec!["locale", "species", "checksum", "item", "checksum"]);
+            assert_eq!(read.diagnostics[2].offset, general_start + sparky + 0x06);
+            assert_eq!(read.diagnostics[4].offset, storage_start + bidoof + 0x06);
+
+            // nothing is lost writing it back
+            assert_eq!(write_save_to_bytes(&read).unwrap(), written);
+
+            // the corrupt Pokémon still counts towards the party, and keeps its slot
+            let mut read = read;
+            read.base.party.clear();
+            let rewritten = write_save_to_bytes(&read).unwrap();
+            let reread = Gen4Save::from_bytes_with_options(&rewritten, ParseOptions { strict: false }).unwrap();
+            assert_eq!(rewritten[reread.general_slot.slot.offset() + 0x9C], 1);
+            assert!(reread.base.party.is_empty());
+            assert_eq!(reread.corrupt_slot(SlotLocation::Party(1)).unwrap().bytes, read.corrupt_slot(SlotLocation::Party(1)).unwrap().bytes);
+
+            for _i in 0..6 {
+                read.base.party.push(Pokemon::new(Species::Piplup));
+            }
+            assert!(matches!(write_save_to_bytes(&read), Err(WriteError::PartyFull(7))));
+        }
 
-#[cfg(test)]
-mod tests {
-    use crate::save::format::dppt::platinum::read_save;
+        /// Are over-full and duplicate stacks in the bag reported, and combined leniently?
+        #[test]
+        fn read_bag_diagnostics() {
+            let mut written = write_save_to_bytes(&sinnoh::test_save(Game::Platinum)).unwrap();
+            let start = Gen4Save::from_bytes(&written).unwrap().general_slot.slot.offset();
+            let general = &mut written[start..start + GENERAL_SIZE];
+            // a second stack of Potions after the first
+            general[0x634..0x636].copy_from_slice(&u16::from(DPPTItem::Potion).to_le_bytes());
+            general[0x636..0x638].copy_from_slice(&1000u16.to_le_bytes());
+            BlockFooter::from_block(general, FooterFormat::Sinnoh).write_to_block(general);
+
+            let err = Gen4Save::from_bytes(&written).unwrap_err();
+            assert!(matches!(err, ReadError::InvalidValue { block: Block::Bag, field: "item quantity", value: 1000, .. }));
+
+            let read = Gen4Save::from_bytes_with_options(&written, ParseOptions { strict: false }).unwrap();
+            assert_eq!(read.base.get_item(DPPTItem::Potion), Some(&1005));
+            let fields: Vec<&str> = read.diagnostics.iter().map(|d| d.field).collect();
+            assert_eq!(fields, vec!["item quantity", "duplicate item"]);
+        }
 
-    #[test]
-    fn read_platinum() {
-        let read = read_save("test-files/platinum.sav");
-        println!("{:#?}", read);
+        /// Does an invalid field report where it is?
+        #[test]
+        fn read_invalid_gender() {
+            let mut written = write_save_to_bytes(&sinnoh::test_save(Game::Platinum)).unwrap();
+            let start = Gen4Save::from_bytes(&written).unwrap().general_slot.slot.offset();
+            let general = &mut written[start..start + GENERAL_SIZE];
+            general[0x80] = 7;
+            BlockFooter::from_block(general, FooterFormat::Sinnoh).write_to_block(general);
+
+            let err = Gen4Save::from_bytes(&written).unwrap_err();
+            assert!(matches!(err, ReadError::InvalidValue { block: Block::Player, field: "gender", value: 7, .. }));
+            assert_eq!(err.to_string(), format!("invalid gender 0x7 (Player block, offset {:#07X})", start + 0x80));
+        }
     }
 }
\ No newline at end of file
@@ -0,0 +1,1312 @@
+use crate::save::data::dppt::item::DPPTItem;
+use crate::save::data::gender::GenderRatio;
+pub use crate::save::data::gender::PokemonGender;
+use crate::save::data::growth::GrowthRate;
+use crate::save::data::nature::Nature;
+use crate::save::data::species::Species;
+use crate::save::data::species_info::{Ability, PokemonType, SpeciesInfo};
+pub use crate::save::data::stat::Stat;
+use crate::save::error::{Block, ReadError, UnknownValue};
+#[cfg(feature = "write")]
+use crate::save::error::WriteError;
+#[cfg(feature = "write")]
+use crate::save::format::dppt::string::write_string;
+use crate::save::format::dppt::save::{Game, Gen4Save, Locale};
+use crate::save::format::dppt::string::Gen4String;
+use crate::save::format::reader::{BlockReader, Diagnostics};
+use crate::save::format::{read_date, read_stats, ParseOptions, StoredPokemon};
+use crate::save::save::{Gender, Pokemon, Trainer};
+use byteorder::{ByteOrder, LittleEndian};
+#[cfg(feature = "write")]
+use chrono::Datelike;
+use chrono::{Local, NaiveDate};
+use std::io::SeekFrom;
+
+/// Size of a Pokémon in a box, i.e. without battle stats
+pub const BOX_POKEMON_SIZE: usize = 136;
+/// Size of a Pokémon in the party, with the 100 bytes of battle stats
+pub const PARTY_POKEMON_SIZE: usize = 236;
+
+/// One of the six stats, e.g. for EVs & IVs
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Stats {
+    pub hp: u8,
+    pub attack: u8,
+    pub defense: u8,
+    pub speed: u8,
+    pub sp_attack: u8,
+    pub sp_defense: u8,
+}
+
+impl Stats {
+    /// The value of `stat`
+    pub fn get(&self, stat: Stat) -> u8 {
+        match stat {
+            Stat::Hp => self.hp,
+            Stat::Attack => self.attack,
+            Stat::Defense => self.defense,
+            Stat::Speed => self.speed,
+            Stat::SpAttack => self.sp_attack,
+            Stat::SpDefense => self.sp_defense,
+        }
+    }
+
+    /// All six values, in the order they're stored
+    fn values(&self) -> [u8; 6] {
+        [self.hp, self.attack, self.defense, self.speed, self.sp_attack, self.sp_defense]
+    }
+}
+
+/// A Pokémon's stats at its level, as the game works them out from its base stats, IVs, EVs & nature
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct CalculatedStats {
+    pub hp: u16,
+    pub attack: u16,
+    pub defense: u16,
+    pub speed: u16,
+    pub sp_attack: u16,
+    pub sp_defense: u16,
+}
+
+/// The type & power of a Pokémon's Hidden Power, which gen 4 works out from its IVs
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct HiddenPower {
+    pub power_type: PokemonType,
+    /// From 30 to 70
+    pub power: u8,
+}
+
+/// The types Hidden Power can be, in the order its IVs pick them
+const HIDDEN_POWER_TYPES: [PokemonType; 16] = {
+    use PokemonType::*;
+    [Fighting, Flying, Poison, Ground, Rock, Bug, Ghost, Steel, Fire, Water, Grass, Electric, Psychic, Ice, Dragon, Dark]
+};
+
+/// The summary screen's characteristic for each stat, by the stat's IV mod 5
+const CHARACTERISTICS: [[&str; 5]; 6] = [
+    ["Loves to eat", "Often dozes off", "Often scatters things", "Scatters things often", "Likes to relax"],
+    ["Proud of its power", "Likes to thrash about", "A little quick tempered", "Likes to fight", "Quick tempered"],
+    ["Sturdy body", "Capable of taking hits", "Highly persistent", "Good endurance", "Good perseverance"],
+    ["Likes to run", "Alert to sounds", "Impetuous and silly", "Somewhat of a clown", "Quick to flee"],
+    ["Highly curious", "Mischievous", "Thoroughly cunning", "Often lost in thought", "Very finicky"],
+    ["Strong willed", "Somewhat vain", "Strongly defiant", "Hates to lose", "Somewhat stubborn"],
+];
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ContestStats {
+    pub cool: u8,
+    pub beauty: u8,
+    pub cute: u8,
+    pub smart: u8,
+    pub tough: u8,
+    pub sheen: u8,
+}
+
+/// A move slot
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Move {
+    /// Move ID, `0` for an empty slot
+    pub id: u16,
+    pub pp: u8,
+    pub pp_ups: u8,
+}
+
+/// The ribbon bitfields, as stored
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Ribbons {
+    /// Sinnoh ribbons, from Sinnoh Champ to Smile
+    pub sinnoh_1: u32,
+    /// Hoenn ribbons, i.e. contest & battle tower ribbons, Champion, Winning, etc.
+    pub hoenn: u32,
+    /// Sinnoh contest ribbons
+    pub sinnoh_2: u32,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Pokerus {
+    pub strain: u8,
+    /// Days until cured; `0` with a strain means cured
+    pub days: u8,
+}
+
+impl From<u8> for Pokerus {
+    fn from(value: u8) -> Self {
+        Pokerus { strain: value >> 4, days: value & 0x0F }
+    }
+}
+
+impl From<Pokerus> for u8 {
+    fn from(value: Pokerus) -> Self {
+        (value.strain << 4) | (value.days & 0x0F)
+    }
+}
+
+/// A major status condition
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StatusCondition {
+    None,
+    /// Asleep for the given number of turns
+    Asleep(u8),
+    Poisoned,
+    Burned,
+    Frozen,
+    Paralyzed,
+    BadlyPoisoned,
+}
+
+impl From<u32> for StatusCondition {
+    fn from(value: u32) -> Self {
+        match value {
+            _ if value & 0b111 != 0 => StatusCondition::Asleep((value & 0b111) as u8),
+            _ if value & (1 << 3) != 0 => StatusCondition::Poisoned,
+            _ if value & (1 << 4) != 0 => StatusCondition::Burned,
+            _ if value & (1 << 5) != 0 => StatusCondition::Frozen,
+            _ if value & (1 << 6) != 0 => StatusCondition::Paralyzed,
+            _ if value & (1 << 7) != 0 => StatusCondition::BadlyPoisoned,
+            _ => StatusCondition::None,
+        }
+    }
+}
+
+impl From<StatusCondition> for u32 {
+    fn from(value: StatusCondition) -> Self {
+        match value {
+            StatusCondition::None => 0,
+            StatusCondition::Asleep(turns) => (turns & 0b111) as u32,
+            StatusCondition::Poisoned => 1 << 3,
+            StatusCondition::Burned => 1 << 4,
+            StatusCondition::Frozen => 1 << 5,
+            StatusCondition::Paralyzed => 1 << 6,
+            StatusCondition::BadlyPoisoned => 1 << 7,
+        }
+    }
+}
+
+/// The stats the game calculates for party Pokémon, so they don't need working out in battle.
+///
+/// These are encrypted separately from the rest of the Pokémon, using its PID.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BattleStats {
+    pub status: StatusCondition,
+    pub level: u8,
+    /// The seal capsule attached for Poké Ball effects
+    pub seal_capsule: u8,
+    pub current_hp: u16,
+    pub max_hp: u16,
+    pub attack: u16,
+    pub defense: u16,
+    pub speed: u16,
+    pub sp_attack: u16,
+    pub sp_defense: u16,
+}
+
+/// A date stored as years since 2000, month & day. Unset dates are zeroed.
+pub(crate) fn date_from_bytes(date: &[u8]) -> Option<NaiveDate> {
+    NaiveDate::from_ymd_opt(2000 + date[0] as i32, date[1] as u32, date[2] as u32)
+}
+
+/// A gen 4 Pokémon, decoded from the four blocks of its decrypted data.
+///
+/// See the "Pokémon" section of `doc/format/gen4/dppt.md`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Gen4Pokemon {
+    // HEADER
+    /// Personality value, which decides nature, gender, shininess, etc.
+    pub pid: u32,
+    /// Set while the game is working on the Pokémon; includes the "bad egg" bit
+    pub flags: u16,
+    pub checksum: u16,
+
+    // BLOCK A
+    pub species: Species,
+    pub held_item: DPPTItem,
+    pub ot_id: u16,
+    pub ot_secret_id: u16,
+    pub experience: u32,
+    pub friendship: u8,
+    pub ability: u8,
+    /// Circle, triangle, square, heart, star & diamond, from the lowest bit
+    pub markings: u8,
+    pub language: u8,
+    pub evs: Stats,
+    pub contest: ContestStats,
+
+    // BLOCK B
+    pub moves: [Move; 4],
+    pub ivs: Stats,
+    pub is_egg: bool,
+    pub is_nicknamed: bool,
+    pub ribbons: Ribbons,
+    pub fateful_encounter: bool,
+    pub gender: PokemonGender,
+    pub form: u8,
+    /// HGSS only
+    pub shiny_leaves: u8,
+    /// Platinum's egg location, for locations DP don't have
+    pub egg_location_extended: u16,
+    /// Platinum's met location, for locations DP don't have
+    pub met_location_extended: u16,
+
+    // BLOCK C
+    pub nickname: Gen4String,
+    pub origin_game: u8,
+
+    // BLOCK D
+    pub ot_name: Gen4String,
+    pub egg_date: Option<NaiveDate>,
+    pub met_date: Option<NaiveDate>,
+    pub egg_location: u16,
+    pub met_location: u16,
+    pub pokerus: Pokerus,
+    pub ball: u8,
+    pub met_level: u8,
+    pub ot_gender: Gender,
+    pub encounter_type: u8,
+    /// HGSS only, for the Apricorn balls
+    pub hgss_ball: u8,
+
+    // PARTY ONLY
+    /// Only set for party Pokémon
+    pub battle_stats: Option<BattleStats>,
+
+    /// The decrypted data this was read from, which `to_bytes` writes over
+    raw: Vec<u8>,
+}
+
+impl Gen4Pokemon {
+    /// Read a `.pk4` or `.pkm` file: a box or party Pokémon, either encrypted as it's stored in a save or
+    /// decrypted as PKHeX exports it. Whichever of the two passes its checksum is used.
+    pub fn from_pk4(data: &[u8]) -> Result<Self, ReadError> {
+        if !is_pokemon_size(data.len()) {
+            return Err(ReadError::InvalidPokemonSize(data.len()));
+        }
+
+        let checksum = LittleEndian::read_u16(&data[0x06..0x08]);
+        let decrypted = match pokemon_checksum(data) == checksum {
+            true => data.to_vec(),
+            false => decrypt_pokemon_blob(data)?,
+        };
+        if pokemon_checksum(&decrypted) != checksum {
+            return Err(ReadError::InvalidValue { block: Block::Pokemon, offset: 0x06, field: "checksum", value: checksum as u64 });
+        }
+
+        let mut diagnostics = Diagnostics::new(&ParseOptions::default());
+        Self::read(&mut BlockReader::new(&decrypted, 0, Block::Pokemon), &mut diagnostics)
+    }
+
+    /// The Pokémon as a decrypted, `BOX_POKEMON_SIZE` byte `.pk4` file
+    #[cfg(feature = "write")]
+    pub fn to_pk4(&self) -> Result<Vec<u8>, WriteError> {
+        let mut data = self.to_bytes()?;
+        data.truncate(BOX_POKEMON_SIZE);
+        Ok(data)
+    }
+
+    /// The Pokémon as a decrypted, `PARTY_POKEMON_SIZE` byte `.pk4` file. See `to_party_bytes`.
+    #[cfg(feature = "write")]
+    pub fn to_pk4_party(&self) -> Result<Vec<u8>, WriteError> {
+        self.to_party_bytes()
+    }
+
+    /// Decode a decrypted Pokémon, with blocks in A-D order. If there are `PARTY_POKEMON_SIZE` bytes, the
+    /// battle stats are read too.
+    pub(crate) fn read(reader: &mut BlockReader, diagnostics: &mut Diagnostics) -> Result<Self, ReadError> {
+        reader.seek(SeekFrom::Start(0x00));
+        let pid = reader.read_u32("PID")?;
+        let flags = reader.read_u16("flags")?;
+        let checksum = reader.read_u16("checksum")?;
+
+        // BLOCK A
+        let species = reader.read_u16("species")?;
+        let species = match Species::try_from(species) {
+            Ok(species) => species,
+            Err(_) => {
+                diagnostics.report(reader.diagnostic("species", species))?;
+                Species::Unknown(species)
+            }
+        };
+        let held_item = reader.read_u16("held item")?;
+        let held_item = match DPPTItem::try_from(held_item) {
+            Ok(item) => item,
+            Err(_) => {
+                diagnostics.report(reader.diagnostic("held item", held_item))?;
+                DPPTItem::Unknown(held_item)
+            }
+        };
+        let ot_id = reader.read_u16("original trainer ID")?;
+        let ot_secret_id = reader.read_u16("original trainer secret ID")?;
+        let experience = reader.read_u32("experience")?;
+        let friendship = reader.read_u8("friendship")?;
+        let ability = reader.read_u8("ability")?;
+        let markings = reader.read_u8("markings")?;
+        let language = reader.read_u8("language")?;
+        let evs = read_stats(reader, "EVs")?;
+        let contest = reader.read_bytes("contest stats", 6)?;
+        let contest = ContestStats {
+            cool: contest[0],
+            beauty: contest[1],
+            cute: contest[2],
+            smart: contest[3],
+            tough: contest[4],
+            sheen: contest[5],
+        };
+        let sinnoh_ribbons_1 = reader.read_u32("Sinnoh ribbons")?;
+
+        // BLOCK B
+        let mut moves = [Move::default(); 4];
+        for pkmn_move in moves.iter_mut() {
+            pkmn_move.id = reader.read_u16("move")?;
+        }
+        for pkmn_move in moves.iter_mut() {
+            pkmn_move.pp = reader.read_u8("move PP")?;
+        }
+        for pkmn_move in moves.iter_mut() {
+            pkmn_move.pp_ups = reader.read_u8("move PP ups")?;
+        }
+
+        let iv_bits = reader.read_u32("IVs")?;
+        let iv = |i: u32| ((iv_bits >> (i * 5)) & 0x1F) as u8;
+        let ivs = Stats {
+            hp: iv(0),
+            attack: iv(1),
+            defense: iv(2),
+            speed: iv(3),
+            sp_attack: iv(4),
+            sp_defense: iv(5),
+        };
+        let is_egg = iv_bits & (1 << 30) != 0;
+        let is_nicknamed = iv_bits & (1 << 31) != 0;
+
+        let hoenn_ribbons = reader.read_u32("Hoenn ribbons")?;
+        let form_bits = reader.read_u8("form")?;
+        let fateful_encounter = form_bits & 0b001 != 0;
+        let gender = PokemonGender::from_bits(form_bits);
+        let form = form_bits >> 3;
+        let shiny_leaves = reader.read_u8("shiny leaves")?;
+        reader.seek(SeekFrom::Current(0x02));
+        let egg_location_extended = reader.read_u16("egg location")?;
+        let met_location_extended = reader.read_u16("met location")?;
+
+        // BLOCK C
+        let nickname = Gen4String::from_raw(reader.read_u16s("nickname", 11)?);
+        reader.seek(SeekFrom::Current(0x01));
+        let origin_game = reader.read_u8("origin game")?;
+        let sinnoh_ribbons_2 = reader.read_u32("Sinnoh ribbons")?;
+        reader.seek(SeekFrom::Current(0x04));
+
+        // BLOCK D
+        let ot_name = Gen4String::from_raw(reader.read_u16s("original trainer name", 8)?);
+        let egg_date = read_date(reader, "egg date")?;
+        let met_date = read_date(reader, "met date")?;
+        let egg_location = reader.read_u16("egg location")?;
+        let met_location = reader.read_u16("met location")?;
+        let pokerus = reader.read_u8("Pokérus")?.into();
+        let ball = reader.read_u8("ball")?;
+        let met_level_bits = reader.read_u8("met level")?;
+        let met_level = met_level_bits & 0x7F;
+        let ot_gender = match met_level_bits >> 7 {
+            0 => Gender::Male,
+            _ => Gender::Female,
+        };
+        let encounter_type = reader.read_u8("encounter type")?;
+        let hgss_ball = reader.read_u8("ball")?;
+
+        // PARTY ONLY
+        let battle_stats = match reader.len() >= PARTY_POKEMON_SIZE {
+            true => {
+                reader.seek(SeekFrom::Start(0x88));
+                Some(BattleStats {
+                    status: reader.read_u32("status condition")?.into(),
+                    level: reader.read_u8("level")?,
+                    seal_capsule: reader.read_u8("seal capsule")?,
+                    current_hp: reader.read_u16("current HP")?,
+                    max_hp: reader.read_u16("max HP")?,
+                    attack: reader.read_u16("attack")?,
+                    defense: reader.read_u16("defense")?,
+                    speed: reader.read_u16("speed")?,
+                    sp_attack: reader.read_u16("special attack")?,
+                    sp_defense: reader.read_u16("special defense")?,
+                })
+            }
+            false => None,
+        };
+
+        Ok(Gen4Pokemon {
+            pid,
+            flags,
+            checksum,
+            species,
+            held_item,
+            ot_id,
+            ot_secret_id,
+            experience,
+            friendship,
+            ability,
+            markings,
+            language,
+            evs,
+            contest,
+            moves,
+            ivs,
+            is_egg,
+            is_nicknamed,
+            ribbons: Ribbons { sinnoh_1: sinnoh_ribbons_1, hoenn: hoenn_ribbons, sinnoh_2: sinnoh_ribbons_2 },
+            fateful_encounter,
+            gender,
+            form,
+            shiny_leaves,
+            egg_location_extended,
+            met_location_extended,
+            nickname,
+            origin_game,
+            ot_name,
+            egg_date,
+            met_date,
+            egg_location,
+            met_location,
+            pokerus,
+            ball,
+            met_level,
+            ot_gender,
+            encounter_type,
+            hgss_ball,
+            battle_stats,
+            raw: reader.data().to_vec(),
+        })
+    }
+
+    /// Encode the Pokémon as decrypted data with its blocks in A-D order, ready for
+    /// `encrypt_pokemon_blob`. This is `PARTY_POKEMON_SIZE` bytes if it has battle stats, otherwise
+    /// `BOX_POKEMON_SIZE`.
+    ///
+    /// Fields are written over the data the Pokémon was read from, so unused bits and anything after a
+    /// string's terminator are kept. The checksum is worked out again rather than taken from `checksum`.
+    #[cfg(feature = "write")]
+    pub fn to_bytes(&self) -> Result<Vec<u8>, WriteError> {
+        fn write_stats(data: &mut [u8], stats: &Stats) {
+            data[..6].copy_from_slice(&[stats.hp, stats.attack, stats.defense, stats.speed, stats.sp_attack, stats.sp_defense]);
+        }
+
+        fn write_date(data: &mut [u8], date: Option<NaiveDate>) {
+            // dates that don't decode are left as they are
+            if date_from_bytes(data) == date {
+                return;
+            }
+            let bytes = match date {
+                Some(date) => [(date.year() - 2000).clamp(0, 255) as u8, date.month() as u8, date.day() as u8],
+                None => [0; 3],
+            };
+            data[..3].copy_from_slice(&bytes);
+        }
+
+        let size = match self.battle_stats {
+            Some(_) => PARTY_POKEMON_SIZE,
+            None => BOX_POKEMON_SIZE,
+        };
+        let mut data = self.raw.clone();
+        data.resize(size, 0);
+
+        // HEADER
+        LittleEndian::write_u32(&mut data[0x00..], self.pid);
+        LittleEndian::write_u16(&mut data[0x04..], self.flags);
+
+        // BLOCK A
+        LittleEndian::write_u16(&mut data[0x08..], self.species.into());
+        LittleEndian::write_u16(&mut data[0x0A..], self.held_item.into());
+        LittleEndian::write_u16(&mut data[0x0C..], self.ot_id);
+        LittleEndian::write_u16(&mut data[0x0E..], self.ot_secret_id);
+        LittleEndian::write_u32(&mut data[0x10..], self.experience);
+        data[0x14] = self.friendship;
+        data[0x15] = self.ability;
+        data[0x16] = self.markings;
+        data[0x17] = self.language;
+        write_stats(&mut data[0x18..], &self.evs);
+        let contest = &self.contest;
+        data[0x1E..0x24].copy_from_slice(&[contest.cool, contest.beauty, contest.cute, contest.smart, contest.tough, contest.sheen]);
+        LittleEndian::write_u32(&mut data[0x24..], self.ribbons.sinnoh_1);
+
+        // BLOCK B
+        for (i, pkmn_move) in self.moves.iter().enumerate() {
+            LittleEndian::write_u16(&mut data[0x28 + i * 2..], pkmn_move.id);
+            data[0x30 + i] = pkmn_move.pp;
+            data[0x34 + i] = pkmn_move.pp_ups;
+        }
+        let ivs = [self.ivs.hp, self.ivs.attack, self.ivs.defense, self.ivs.speed, self.ivs.sp_attack, self.ivs.sp_defense];
+        let iv_bits = ivs.iter().enumerate().fold(0u32, |acc, (i, iv)| acc | ((*iv as u32 & 0x1F) << (i * 5)))
+            | (self.is_egg as u32) << 30
+            | (self.is_nicknamed as u32) << 31;
+        LittleEndian::write_u32(&mut data[0x38..], iv_bits);
+        LittleEndian::write_u32(&mut data[0x3C..], self.ribbons.hoenn);
+        // genderless can be stored either way, so keep the bits if they already mean the same thing
+        let gender_bits = match PokemonGender::from_bits(data[0x40]) == self.gender {
+            true => data[0x40] & 0b110,
+            false => self.gender.to_bits(),
+        };
+        data[0x40] = (self.form << 3) | gender_bits | self.fateful_encounter as u8;
+        data[0x41] = self.shiny_leaves;
+        LittleEndian::write_u16(&mut data[0x44..], self.egg_location_extended);
+        LittleEndian::write_u16(&mut data[0x46..], self.met_location_extended);
+
+        // BLOCK C
+        write_string(&mut data[0x48..], &self.nickname, 11)?;
+        data[0x5F] = self.origin_game;
+        LittleEndian::write_u32(&mut data[0x60..], self.ribbons.sinnoh_2);
+
+        // BLOCK D
+        write_string(&mut data[0x68..], &self.ot_name, 8)?;
+        write_date(&mut data[0x78..], self.egg_date);
+        write_date(&mut data[0x7B..], self.met_date);
+        LittleEndian::write_u16(&mut data[0x7E..], self.egg_location);
+        LittleEndian::write_u16(&mut data[0x80..], self.met_location);
+        data[0x82] = self.pokerus.into();
+        data[0x83] = self.ball;
+        data[0x84] = (self.met_level & 0x7F) | ((self.ot_gender == Gender::Female) as u8) << 7;
+        data[0x85] = self.encounter_type;
+        data[0x86] = self.hgss_ball;
+
+        // PARTY ONLY
+        if let Some(stats) = &self.battle_stats {
+            // as with gender, only the bits for the status need to match
+            if StatusCondition::from(LittleEndian::read_u32(&data[0x88..])) != stats.status {
+                LittleEndian::write_u32(&mut data[0x88..], stats.status.into());
+            }
+            data[0x8C] = stats.level;
+            data[0x8D] = stats.seal_capsule;
+            let values = [stats.current_hp, stats.max_hp, stats.attack, stats.defense, stats.speed, stats.sp_attack, stats.sp_defense];
+            for (i, value) in values.iter().enumerate() {
+                LittleEndian::write_u16(&mut data[0x8E + i * 2..], *value);
+            }
+        }
+
+        let checksum = pokemon_checksum(&data);
+        LittleEndian::write_u16(&mut data[0x06..], checksum);
+
+        Ok(data)
+    }
+
+    /// Encode the Pokémon as `to_bytes` does, always `PARTY_POKEMON_SIZE` bytes. A Pokémon without battle
+    /// stats (e.g. it was read from a box) is given `healed_battle_stats`, as the game does when it's
+    /// withdrawn.
+    #[cfg(feature = "write")]
+    pub fn to_party_bytes(&self) -> Result<Vec<u8>, WriteError> {
+        match self.battle_stats {
+            Some(_) => self.to_bytes(),
+            None => Self { battle_stats: Some(self.healed_battle_stats()), ..self.clone() }.to_bytes(),
+        }
+    }
+}
+
+impl Gen4Pokemon {
+    /// The info of the Pokémon's species in its form
+    pub fn species_info(&self) -> &'static SpeciesInfo {
+        self.species.form_info(self.form)
+    }
+
+    /// The level its experience gives on its species' growth rate. Party Pokémon store this in
+    /// `battle_stats` too, which should match it.
+    pub fn level(&self) -> u8 {
+        self.species.info().growth_rate.level(self.experience)
+    }
+
+    pub fn nature(&self) -> Nature {
+        Nature::from_pid(self.pid)
+    }
+
+    /// Its stats at `level`, as party Pokémon have them in `battle_stats`
+    pub fn stats(&self) -> CalculatedStats {
+        let level = self.level() as u32;
+        let base_stats = self.species_info().base_stats;
+        let nature = self.nature();
+        let scaled = |stat: Stat| {
+            let points = 2 * base_stats.get(stat) as u32 + self.ivs.get(stat) as u32 + self.evs.get(stat) as u32 / 4;
+            points * level / 100
+        };
+        let stat = |stat: Stat| nature.apply(stat, (scaled(stat) + 5) as u16);
+
+        CalculatedStats {
+            // Shedinja's HP is always 1
+            hp: match self.species {
+                Species::Shedinja => 1,
+                _ => (scaled(Stat::Hp) + level + 10) as u16,
+            },
+            attack: stat(Stat::Attack),
+            defense: stat(Stat::Defense),
+            speed: stat(Stat::Speed),
+            sp_attack: stat(Stat::SpAttack),
+            sp_defense: stat(Stat::SpDefense),
+        }
+    }
+
+    /// Battle stats for its `level` & `stats`, at full HP with no status condition
+    pub fn healed_battle_stats(&self) -> BattleStats {
+        let stats = self.stats();
+        BattleStats {
+            status: StatusCondition::None,
+            level: self.level(),
+            seal_capsule: 0,
+            current_hp: stats.hp,
+            max_hp: stats.hp,
+            attack: stats.attack,
+            defense: stats.defense,
+            speed: stats.speed,
+            sp_attack: stats.sp_attack,
+            sp_defense: stats.sp_defense,
+        }
+    }
+
+    /// The gender its PID gives for its species, which `gender` should match
+    pub fn gender_from_pid(&self) -> PokemonGender {
+        self.species.info().gender_ratio.gender(self.pid)
+    }
+
+    /// Which of its species' two abilities it has, from the lowest bit of its PID
+    pub fn ability_slot(&self) -> usize {
+        (self.pid & 1) as usize
+    }
+
+    /// The ability in its `ability_slot`, which `ability` should match. Species with one ability have it
+    /// in both slots.
+    pub fn ability_from_pid(&self) -> Ability {
+        let abilities = self.species_info().abilities;
+        match abilities[self.ability_slot()] {
+            Ability::None => abilities[0],
+            ability => ability,
+        }
+    }
+
+    /// The trainer who caught or hatched it
+    pub fn original_trainer(&self) -> Trainer {
+        Trainer::new(self.ot_name.to_string(), self.ot_id, Some(self.ot_secret_id), self.ot_gender)
+    }
+
+    /// Whether it's shiny, which depends on its PID & its original trainer's IDs
+    pub fn is_shiny(&self) -> bool {
+        is_shiny(self.pid, self.ot_id, self.ot_secret_id)
+    }
+
+    /// Its Hidden Power, from the lowest two bits of each IV
+    pub fn hidden_power(&self) -> HiddenPower {
+        let bits = |bit: u8| self.ivs.values().iter().enumerate()
+            .map(|(i, iv)| (((iv >> bit) & 1) as u32) << i)
+            .sum::<u32>();
+
+        HiddenPower {
+            power_type: HIDDEN_POWER_TYPES[(bits(0) * 15 / 63) as usize],
+            power: (bits(1) * 40 / 63 + 30) as u8,
+        }
+    }
+
+    /// The characteristic on its summary screen, from its highest IV. Ties go to the first highest stat
+    /// counting on from the PID mod 6.
+    pub fn characteristic(&self) -> &'static str {
+        let ivs = self.ivs.values();
+        let start = (self.pid % 6) as usize;
+        let highest = *ivs.iter().max().unwrap_or(&0);
+        let stat = (start..start + 6).map(|i| i % 6).find(|i| ivs[*i] == highest).unwrap_or(0);
+        CHARACTERISTICS[stat][(highest % 5) as usize]
+    }
+}
+
+/// Where a Pokémon from outside the gen 4 games was met, in both the DP & Platinum location fields
+const FARAWAY_PLACE: u16 = 3002;
+/// The move a Pokémon from outside the gen 4 games knows, as the generic Pokémon has no moves to go by
+const TACKLE: Move = Move { id: 33, pp: 35, pp_ups: 0 };
+
+/// The game, language & date a Pokémon converted to gen 4 is said to come from
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Origin {
+    pub game: Game,
+    pub locale: Locale,
+    pub met_date: NaiveDate,
+    /// Seeds the gen 4 PRNG that picks the PID; the same seed gives the same PID
+    pub seed: u32,
+}
+
+impl Origin {
+    /// Met today, in `save`'s game & language. Like the games, the PRNG is seeded from the clock.
+    pub fn today(save: &Gen4Save) -> Self {
+        let now = Local::now();
+        Self { game: save.game, locale: save.locale, met_date: now.date_naive(), seed: now.timestamp() as u32 ^ now.timestamp_subsec_nanos() }
+    }
+}
+
+impl TryFrom<(&Pokemon, &Origin)> for Gen4Pokemon {
+    type Error = UnknownValue<u16>;
+
+    /// The Pokémon's gen 4 data if it has any, otherwise a new box Pokémon with the generic fields filled
+    /// in. It's caught in a Poké Ball at a faraway place in `origin`, at the level its experience gives,
+    /// with a PID from `origin`'s seed that isn't shiny for its trainer, and knows Tackle. Without a name
+    /// it's named after its species.
+    ///
+    /// This isn't a `From<&Pokemon>`: a species from after gen 4 is an error, and a new Pokémon needs
+    /// somewhere to have been met. `Origin::today` gives the save it's going into.
+    fn try_from((pokemon, origin): (&Pokemon, &Origin)) -> Result<Self, Self::Error> {
+        if let Some(gen4) = pokemon.gen4() {
+            return Ok(gen4.clone());
+        }
+
+        let species = *pokemon.species();
+        if species.try_info().is_none() {
+            return Err(UnknownValue { name: "Species", value: species.into() });
+        }
+        let (ot_name, ot_id, ot_secret_id, ot_gender) = match pokemon.trainer() {
+            Some(trainer) => (Gen4String::new(trainer.name()), trainer.id(), trainer.secret_id().unwrap_or(0), *trainer.gender()),
+            None => (Gen4String::default(), 0, 0, Gender::Male),
+        };
+        let pid = random_pid(origin.seed, ot_id, ot_secret_id);
+        let gender = GenderRatio::of(species).unwrap_or(GenderRatio::GENDERLESS).gender(pid);
+        let growth_rate = GrowthRate::of(species).unwrap_or(GrowthRate::MediumFast);
+        let experience = pokemon.experience().min(growth_rate.max_experience());
+        let met_location = FARAWAY_PLACE;
+        let species_name = species_name(species);
+        let is_nicknamed = !pokemon.name().is_empty() && !pokemon.name().eq_ignore_ascii_case(&species_name);
+        let nickname = match is_nicknamed {
+            true => pokemon.name().to_string(),
+            false => species_name,
+        };
+
+        let mut gen4 = Self {
+            pid,
+            flags: 0,
+            checksum: 0,
+            species,
+            held_item: DPPTItem::None,
+            ot_id,
+            ot_secret_id,
+            experience,
+            friendship: pokemon.friendship(),
+            ability: 0,
+            markings: 0,
+            language: origin.locale.into(),
+            evs: Stats::default(),
+            contest: ContestStats::default(),
+            moves: [TACKLE, Move::default(), Move::default(), Move::default()],
+            ivs: Stats::default(),
+            is_egg: false,
+            is_nicknamed,
+            ribbons: Ribbons::default(),
+            fateful_encounter: false,
+            gender,
+            form: 0,
+            shiny_leaves: 0,
+            egg_location_extended: 0,
+            met_location_extended: met_location,
+            nickname: Gen4String::new(nickname),
+            origin_game: origin.game.into(),
+            ot_name,
+            egg_date: None,
+            met_date: Some(origin.met_date),
+            egg_location: 0,
+            met_location,
+            pokerus: Pokerus::default(),
+            ball: 4, // Poké Ball
+            met_level: growth_rate.level(experience),
+            ot_gender,
+            encounter_type: 0,
+            hgss_ball: 0,
+            battle_stats: None,
+            raw: Vec::new(),
+        };
+        gen4.ability = gen4.ability_from_pid().into();
+        Ok(gen4)
+    }
+}
+
+/// The species' English name in capitals, which is what gen 4 names a Pokémon that isn't nicknamed
+fn species_name(species: Species) -> String {
+    match species {
+        Species::NidoranFemale => String::from("NIDORAN♀"),
+        Species::NidoranMale => String::from("NIDORAN♂"),
+        Species::Farfetchd => String::from("FARFETCH'D"),
+        Species::MrMime => String::from("MR. MIME"),
+        Species::Hooh => String::from("HO-OH"),
+        Species::MimeJr => String::from("MIME JR."),
+        Species::Porygonz => String::from("PORYGON-Z"),
+        species => format!("{species:?}").to_uppercase(),
+    }
+}
+
+impl From<&Gen4Pokemon> for Pokemon {
+    /// A generic Pokémon holding `gen4`, with its original trainer as its trainer
+    fn from(gen4: &Gen4Pokemon) -> Self {
+        let mut pokemon = Pokemon::new(gen4.species);
+        pokemon.set_trainer(gen4.original_trainer());
+        pokemon.set_gen4(gen4.clone());
+        pokemon
+    }
+}
+
+impl StoredPokemon for Gen4Pokemon {
+    const PARTY_POKEMON_SIZE: usize = PARTY_POKEMON_SIZE;
+    const CHECKSUM_OFFSET: usize = 0x06;
+    const SPECIES_OFFSET: usize = 0x08;
+
+    fn decrypt(blob: &[u8]) -> Result<Vec<u8>, ReadError> {
+        decrypt_pokemon_blob(blob)
+    }
+
+    fn checksum(decrypted: &[u8]) -> u16 {
+        pokemon_checksum(decrypted)
+    }
+
+    fn read(reader: &mut BlockReader, diagnostics: &mut Diagnostics) -> Result<Self, ReadError> {
+        Gen4Pokemon::read(reader, diagnostics)
+    }
+
+    fn species(&self) -> Species {
+        self.species
+    }
+
+    fn ot_ids(&self) -> (u16, u16) {
+        (self.ot_id, self.ot_secret_id)
+    }
+
+    /// The full data is kept as the Pokémon's `Gen4Pokemon`
+    fn fill(self, pokemon: &mut Pokemon) {
+        pokemon.set_gen4(self);
+    }
+}
+
+/// Whether `pid` is shiny for the trainer with `ot_id` & `ot_secret_id`
+fn is_shiny(pid: u32, ot_id: u16, ot_secret_id: u16) -> bool {
+    (ot_id ^ ot_secret_id ^ (pid >> 16) as u16 ^ pid as u16) < 8
+}
+
+/// A PID that isn't shiny for the trainer with `ot_id` & `ot_secret_id`, made from two calls of the gen 4
+/// PRNG from `seed` like a wild Pokémon's
+fn random_pid(mut seed: u32, ot_id: u16, ot_secret_id: u16) -> u32 {
+    loop {
+        seed = next_rand(seed);
+        let low = seed >> 16;
+        seed = next_rand(seed);
+        let pid = (seed & 0xFFFF_0000) | low;
+        if !is_shiny(pid, ot_id, ot_secret_id) {
+            return pid;
+        }
+    }
+}
+
+/// Order of the 4 Pokémon data blocks for each shift value, as they are stored
+pub(crate) const BLOCK_POSITIONS: [u8; 128] = [
+    0, 1, 2, 3,
+    0, 1, 3, 2,
+    0, 2, 1, 3,
+    0, 3, 1, 2,
+    0, 2, 3, 1,
+    0, 3, 2, 1,
+    1, 0, 2, 3,
+    1, 0, 3, 2,
+    2, 0, 1, 3,
+    3, 0, 1, 2,
+    2, 0, 3, 1,
+    3, 0, 2, 1,
+    1, 2, 0, 3,
+    1, 3, 0, 2,
+    2, 1, 0, 3,
+    3, 1, 0, 2,
+    2, 3, 0, 1,
+    3, 2, 0, 1,
+    1, 2, 3, 0,
+    1, 3, 2, 0,
+    2, 1, 3, 0,
+    3, 1, 2, 0,
+    2, 3, 1, 0,
+    3, 2, 1, 0,
+
+    // duplicates of 0-7 to eliminate modulus
+    0, 1, 2, 3,
+    0, 1, 3, 2,
+    0, 2, 1, 3,
+    0, 3, 1, 2,
+    0, 2, 3, 1,
+    0, 3, 2, 1,
+    1, 0, 2, 3,
+    1, 0, 3, 2,
+];
+
+/// The checksum of a Pokémon: the sum of the words in blocks A-D
+fn checksum_words(words: &[u16]) -> u16 {
+    words.iter().fold(0u16, |acc, word| acc.wrapping_add(*word))
+}
+
+/// The checksum of the decrypted Pokémon `blob`, to compare with the one stored at `0x06`
+pub fn pokemon_checksum(blob: &[u8]) -> u16 {
+    checksum_words(&to_words(&blob[0x08..0x88]))
+}
+
+/// XOR `words` with the output of the gen 4 PRNG seeded with `seed`. This is its own inverse.
+fn crypt_words(words: &mut [u16], seed: u32) {
+    let mut prng = seed;
+    for word in words.iter_mut() {
+        prng = next_rand(prng);
+        *word ^= (prng >> 16) as u16;
+    }
+}
+
+/// The next state of the gen 4 PRNG, a linear congruential generator
+fn next_rand(seed: u32) -> u32 {
+    seed.wrapping_mul(0x41C64E6D).wrapping_add(0x00006073)
+}
+
+fn to_words(bytes: &[u8]) -> Vec<u16> {
+    bytes.chunks_exact(2).map(LittleEndian::read_u16).collect()
+}
+
+fn is_pokemon_size(len: usize) -> bool {
+    len == BOX_POKEMON_SIZE || len == PARTY_POKEMON_SIZE
+}
+
+/// Decrypt a Pokémon as stored in a save, giving its blocks in A-D order.
+///
+/// Blocks A-D are encrypted with the checksum and then shuffled by the PID; a party Pokémon's battle
+/// stats are encrypted with the PID. The checksum isn't verified, see `pokemon_checksum`.
+pub fn decrypt_pokemon_blob(blob: &[u8]) -> Result<Vec<u8>, ReadError> {
+    if !is_pokemon_size(blob.len()) {
+        return Err(ReadError::InvalidPokemonSize(blob.len()));
+    }
+
+    Ok(decrypt_blob(blob))
+}
+
+/// Decrypt a gen 4 or 5 Pokémon of any length; they only differ in how much battle data a party Pokémon
+/// has. `blob` must be at least `BOX_POKEMON_SIZE` bytes, and an even length.
+pub(crate) fn decrypt_blob(blob: &[u8]) -> Vec<u8> {
+    let pid = LittleEndian::read_u32(&blob[0x00..0x04]);
+    let checksum = LittleEndian::read_u16(&blob[0x06..0x08]);
+    let shift = ((pid >> 13) & 31) as usize;
+
+    let mut words = to_words(&blob[0x08..]);
+    crypt_words(&mut words[..64], checksum as u32);
+    crypt_words(&mut words[64..], pid);
+
+    // block i is stored at position BLOCK_POSITIONS[shift * 4 + i]
+    let mut decrypted = blob[..0x08].to_vec();
+    for i in 0..4 {
+        let src_idx = 16 * BLOCK_POSITIONS[shift * 4 + i] as usize;
+        words[src_idx..src_idx + 16].iter().for_each(|word| decrypted.extend_from_slice(&word.to_le_bytes()));
+    }
+    words[64..].iter().for_each(|word| decrypted.extend_from_slice(&word.to_le_bytes()));
+
+    decrypted
+}
+
+/// The inverse of `decrypt_pokemon_blob`: recompute the checksum, shuffle the blocks back into their
+/// stored order and encrypt.
+#[cfg(feature = "write")]
+pub fn encrypt_pokemon_blob(blob: &[u8]) -> Result<Vec<u8>, WriteError> {
+    if !is_pokemon_size(blob.len()) {
+        return Err(WriteError::InvalidPokemonSize(blob.len()));
+    }
+
+    Ok(encrypt_blob(blob))
+}
+
+/// The inverse of `decrypt_blob`
+#[cfg(any(feature = "write", test))]
+pub(crate) fn encrypt_blob(blob: &[u8]) -> Vec<u8> {
+    let pid = LittleEndian::read_u32(&blob[0x00..0x04]);
+    let shift = ((pid >> 13) & 31) as usize;
+
+    let words = to_words(&blob[0x08..]);
+    let checksum = checksum_words(&words[..64]);
+
+    let mut encrypted_words = words.clone();
+    for i in 0..4 {
+        let dest_idx = 16 * BLOCK_POSITIONS[shift * 4 + i] as usize;
+        encrypted_words[dest_idx..dest_idx + 16].copy_from_slice(&words[16 * i..16 * i + 16]);
+    }
+    crypt_words(&mut encrypted_words[..64], checksum as u32);
+    crypt_words(&mut encrypted_words[64..], pid);
+
+    let mut encrypted = blob[..0x06].to_vec();
+    encrypted.extend_from_slice(&checksum.to_le_bytes());
+    encrypted_words.iter().for_each(|word| encrypted.extend_from_slice(&word.to_le_bytes()));
+
+    encrypted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::save::error::Block;
+    use crate::save::format::ParseOptions;
+
+    const ORIGIN: Origin = Origin {
+        game: Game::HeartGold,
+        locale: Locale::French,
+        met_date: match NaiveDate::from_ymd_opt(2010, 3, 14) {
+            Some(date) => date,
+            None => panic!("invalid date"),
+        },
+        seed: 0x1234_5678,
+    };
+
+    /// Is every field read from the right place?
+    #[test]
+    fn read_all_blocks() {
+        let mut data = vec![0u8; BOX_POKEMON_SIZE];
+        data[0x00..0x04].copy_from_slice(&0x12345678u32.to_le_bytes());
+        data[0x08..0x0A].copy_from_slice(&393u16.to_le_bytes()); // Piplup
+        data[0x0A..0x0C].copy_from_slice(&234u16.to_le_bytes()); // Leftovers
+        data[0x0C..0x0E].copy_from_slice(&12345u16.to_le_bytes());
+        data[0x0E..0x10].copy_from_slice(&54321u16.to_le_bytes());
+        data[0x10..0x14].copy_from_slice(&1000u32.to_le_bytes());
+        data[0x14] = 70;
+        data[0x15] = 67; // Torrent
+        data[0x17] = 2;
+        data[0x18..0x1E].copy_from_slice(&[252, 0, 0, 6, 252, 0]);
+        data[0x1E..0x24].copy_from_slice(&[10, 20, 30, 40, 50, 60]);
+        data[0x28..0x2A].copy_from_slice(&145u16.to_le_bytes()); // Bubble
+        data[0x30] = 30;
+        data[0x34] = 3;
+        // IVs of 31, 30, 29, 28, 27, 26, nicknamed
+        let ivs = 31 | (30 << 5) | (29 << 10) | (28 << 15) | (27 << 20) | (26 << 25) | (1 << 31);
+        data[0x38..0x3C].copy_from_slice(&(ivs as u32).to_le_bytes());
+        data[0x40] = (2 << 3) | 0b010 | 0b001; // form 2, female, fateful encounter
+        data[0x46..0x48].copy_from_slice(&2000u16.to_le_bytes());
+        for (i, c) in [0x12Fu16, 0x136, 0x136, 0xFFFF].iter().enumerate() {
+            data[0x48 + i * 2..0x4A + i * 2].copy_from_slice(&c.to_le_bytes());
+        }
+        data[0x5F] = 12;
+        for (i, c) in [0x136u16, 0xFFFF].iter().enumerate() {
+            data[0x68 + i * 2..0x6A + i * 2].copy_from_slice(&c.to_le_bytes());
+        }
+        data[0x7B..0x7E].copy_from_slice(&[9, 11, 24]);
+        data[0x80..0x82].copy_from_slice(&16u16.to_le_bytes());
+        data[0x82] = 0x21;
+        data[0x83] = 4;
+        data[0x84] = 0x80 | 5;
+
+        let mut diagnostics = Diagnostics::new(&ParseOptions::default());
+        let pkmn = Gen4Pokemon::read(&mut BlockReader::new(&data, 0, Block::Party), &mut diagnostics).unwrap();
+
+        assert_eq!(pkmn.pid, 0x12345678);
+        assert_eq!(pkmn.species, Species::Piplup);
+        assert_eq!(pkmn.held_item, DPPTItem::Leftovers);
+        assert_eq!((pkmn.ot_id, pkmn.ot_secret_id), (12345, 54321));
+        assert_eq!((pkmn.experience, pkmn.friendship, pkmn.ability, pkmn.language), (1000, 70, 67, 2));
+        assert_eq!(pkmn.evs, Stats { hp: 252, attack: 0, defense: 0, speed: 6, sp_attack: 252, sp_defense: 0 });
+        assert_eq!(pkmn.contest.sheen, 60);
+        assert_eq!(pkmn.moves[0], Move { id: 145, pp: 30, pp_ups: 3 });
+        assert_eq!(pkmn.moves[1], Move::default());
+        assert_eq!(pkmn.ivs, Stats { hp: 31, attack: 30, defense: 29, speed: 28, sp_attack: 27, sp_defense: 26 });
+        assert!(!pkmn.is_egg && pkmn.is_nicknamed && pkmn.fateful_encounter);
+        assert_eq!((pkmn.gender, pkmn.form), (PokemonGender::Female, 2));
+        assert_eq!(pkmn.met_location_extended, 2000);
+        assert_eq!(pkmn.nickname, "ELL");
+        assert_eq!(pkmn.origin_game, 12);
+        assert_eq!(pkmn.ot_name, "L");
+        assert_eq!(pkmn.egg_date, None);
+        assert_eq!(pkmn.met_date, NaiveDate::from_ymd_opt(2009, 11, 24));
+        assert_eq!(pkmn.met_location, 16);
+        assert_eq!(pkmn.pokerus, Pokerus { strain: 2, days: 1 });
+        assert_eq!((pkmn.ball, pkmn.met_level, pkmn.ot_gender), (4, 5, Gender::Female));
+        assert_eq!(pkmn.battle_stats, None);
+    }
+
+    /// Are battle stats read for party Pokémon?
+    #[test]
+    fn read_battle_stats() {
+        let mut data = vec![0u8; PARTY_POKEMON_SIZE];
+        data[0x08..0x0A].copy_from_slice(&393u16.to_le_bytes());
+        data[0x88] = 1 << 6;
+        data[0x8C] = 12;
+        for (i, stat) in [30u16, 35, 20, 22, 18, 25, 24].iter().enumerate() {
+            data[0x8E + i * 2..0x90 + i * 2].copy_from_slice(&stat.to_le_bytes());
+        }
+
+        let mut diagnostics = Diagnostics::new(&ParseOptions::default());
+        let pkmn = Gen4Pokemon::read(&mut BlockReader::new(&data, 0, Block::Party), &mut diagnostics).unwrap();
+        assert_eq!(pkmn.battle_stats, Some(BattleStats {
+            status: StatusCondition::Paralyzed,
+            level: 12,
+            seal_capsule: 0,
+            current_hp: 30,
+            max_hp: 35,
+            attack: 20,
+            defense: 22,
+            speed: 18,
+            sp_attack: 25,
+            sp_defense: 24,
+        }));
+    }
+
+    /// Does a generic Pokémon become a sensible gen 4 one, and does it come back the same?
+    #[test]
+    fn convert_generic_pokemon() {
+        let trainer = Trainer::new(String::from("ELL"), 12345, Some(54321), Gender::Female);
+        let mut pokemon = Pokemon::new(Species::Piplup);
+        pokemon.set_trainer(trainer.clone());
+        pokemon.set_experience(u32::MAX);
+        pokemon.set_friendship(70);
+
+        let gen4 = Gen4Pokemon::try_from((&pokemon, &ORIGIN)).unwrap();
+        assert!(!is_shiny(gen4.pid, 12345, 54321));
+        // the PID only depends on the seed
+        assert_eq!(Gen4Pokemon::try_from((&pokemon, &ORIGIN)).unwrap().pid, gen4.pid);
+        assert_ne!(Gen4Pokemon::try_from((&pokemon, &Origin { seed: 1, ..ORIGIN })).unwrap().pid, gen4.pid);
+        assert_eq!(gen4.gender, GenderRatio(31).gender(gen4.pid));
+        assert_eq!((gen4.experience, gen4.met_level), (1_059_860, 100));
+        assert_eq!((gen4.ot_name.text(), gen4.ot_id, gen4.ot_secret_id, gen4.ot_gender), ("ELL", 12345, 54321, Gender::Female));
+        assert_eq!((gen4.ball, gen4.met_location, gen4.friendship), (4, FARAWAY_PLACE, 70));
+        assert_eq!(gen4.ability, u8::from(Ability::Torrent));
+        assert_eq!((gen4.origin_game, gen4.language, gen4.met_date), (7, 3, Some(ORIGIN.met_date)));
+        assert_eq!(gen4.moves[0], TACKLE);
+        assert_eq!((gen4.nickname.text(), gen4.is_nicknamed), ("PIPLUP", false));
+
+        let back = Pokemon::from(&gen4);
+        assert_eq!((back.species(), back.trainer(), back.experience()), (&Species::Piplup, Some(&trainer), 1_059_860));
+        assert_eq!(Gen4Pokemon::try_from((&back, &ORIGIN)).unwrap(), gen4);
+
+        pokemon.set_name(String::from("Mr. Mime"));
+        let gen4 = Gen4Pokemon::try_from((&pokemon, &ORIGIN)).unwrap();
+        assert_eq!((gen4.nickname.text(), gen4.is_nicknamed), ("Mr. Mime", true));
+        let mr_mime = Gen4Pokemon::try_from((&Pokemon::new(Species::MrMime), &ORIGIN)).unwrap();
+        assert_eq!((mr_mime.nickname.text(), mr_mime.is_nicknamed), ("MR. MIME", false));
+        assert_eq!(Gen4Pokemon::try_from((&Pokemon::new(Species::Snivy), &ORIGIN)), Err(UnknownValue { name: "Species", value: 495 }));
+        #[cfg(feature = "write")]
+        assert_eq!(Gen4Pokemon::from_pk4(&gen4.to_pk4().unwrap()).unwrap().met_date, gen4.met_date);
+    }
+
+    /// Are level, stats & the PID and IV based properties worked out like the games do?
+    #[test]
+    fn derived_properties() {
+        let mut pkmn = Gen4Pokemon::try_from((&Pokemon::new(Species::Garchomp), &ORIGIN)).unwrap();
+        pkmn.pid = 3; // Adamant, female, second ability slot
+        pkmn.experience = GrowthRate::Slow.experience(78);
+        pkmn.ivs = Stats { hp: 24, attack: 12, defense: 30, speed: 5, sp_attack: 16, sp_defense: 23 };
+        pkmn.evs = Stats { hp: 74, attack: 190, defense: 91, speed: 23, sp_attack: 48, sp_defense: 84 };
+        (pkmn.ot_id, pkmn.ot_secret_id) = (0, 0);
+
+        assert_eq!((pkmn.level(), pkmn.nature()), (78, Nature::Adamant));
+        assert_eq!(pkmn.stats(), CalculatedStats { hp: 289, attack: 278, defense: 193, speed: 171, sp_attack: 135, sp_defense: 171 });
+        assert_eq!(pkmn.gender_from_pid(), PokemonGender::Female);
+        assert_eq!((pkmn.ability_slot(), pkmn.ability_from_pid()), (1, Ability::SandVeil));
+        assert!(pkmn.is_shiny());
+        assert_eq!(pkmn.original_trainer().id(), 0);
+        assert_eq!(pkmn.hidden_power(), HiddenPower { power_type: PokemonType::Water, power: 52 });
+        assert_eq!(pkmn.characteristic(), "Sturdy body");
+
+        // ties go to the first highest IV from the PID mod 6
+        pkmn.ivs = Stats { hp: 31, attack: 31, defense: 31, speed: 31, sp_attack: 31, sp_defense: 31 };
+        assert_eq!(pkmn.hidden_power(), HiddenPower { power_type: PokemonType::Dark, power: 70 });
+        assert_eq!(pkmn.characteristic(), "Alert to sounds");
+
+        pkmn.species = Species::Shedinja;
+        assert_eq!(pkmn.stats().hp, 1);
+    }
+
+    /// Do status conditions survive a round trip?
+    #[test]
+    fn convert_status_condition() {
+        for status in [StatusCondition::None, StatusCondition::Asleep(3), StatusCondition::Burned, StatusCondition::BadlyPoisoned] {
+            assert_eq!(StatusCondition::from(u32::from(status)), status);
+        }
+    }
+
+    /// Are a party Pokémon's battle stats decrypted with its PID, rather than its checksum?
+    #[test]
+    fn decrypt_battle_stats() {
+        let pid: u32 = 0x8765_4321;
+        let mut words = vec![0u16; (PARTY_POKEMON_SIZE - 8) / 2];
+        words[(0x8C - 8) / 2] = 42; // level
+        crypt_words(&mut words[..64], 0);
+        crypt_words(&mut words[64..], pid);
+
+        let mut blob = pid.to_le_bytes().to_vec();
+        blob.extend_from_slice(&[0; 4]);
+        words.iter().for_each(|word| blob.extend_from_slice(&word.to_le_bytes()));
+
+        let decrypted = decrypt_pokemon_blob(&blob).unwrap();
+        assert!(decrypted[0x08..0x88].iter().all(|byte| *byte == 0));
+        assert_eq!(decrypted[0x8C], 42);
+    }
+
+    /// Are Pokémon re-encrypted so that they decrypt to what was written?
+    #[cfg(feature = "write")]
+    #[test]
+    fn encrypt_round_trip() {
+        for pv in [0u32, 0x1234_5678, 0xFFFF_FFFF] {
+            let mut blob = vec![0u8; PARTY_POKEMON_SIZE];
+            blob[..4].copy_from_slice(&pv.to_le_bytes());
+            for (i, byte) in blob.iter_mut().enumerate().skip(8) {
+                *byte = i as u8;
+            }
+
+            let encrypted = encrypt_pokemon_blob(&blob).unwrap();
+            let decrypted = decrypt_pokemon_blob(&encrypted).unwrap();
+            assert_eq!(decrypted[8..], blob[8..]);
+        }
+    }
+
+    /// Is an unmodified Pokémon written back as it was read, and do edits survive a round trip?
+    #[cfg(feature = "write")]
+    #[test]
+    fn write_round_trip() {
+        let mut data = vec![0u8; PARTY_POKEMON_SIZE];
+        data[0x08..0x0A].copy_from_slice(&393u16.to_le_bytes());
+        data[0x40] = 0b110; // genderless, the unusual way
+        // "E", then trash after the terminator
+        for (i, c) in [0x12Fu16, 0xFFFF, 0x136, 0x136].iter().enumerate() {
+            data[0x48 + i * 2..0x4A + i * 2].copy_from_slice(&c.to_le_bytes());
+        }
+        data[0x7B..0x7E].copy_from_slice(&[9, 13, 40]); // not a date
+        data[0x88] = 0xFF; // asleep, with every other status bit set too
+        data[0xD0] = 0x55; // mail
+        let checksum = pokemon_checksum(&data);
+        data[0x06..0x08].copy_from_slice(&checksum.to_le_bytes());
+
+        let read = |data: &[u8]| {
+            let mut diagnostics = Diagnostics::new(&ParseOptions::default());
+            Gen4Pokemon::read(&mut BlockReader::new(data, 0, Block::Party), &mut diagnostics).unwrap()
+        };
+        let mut pkmn = read(&data);
+        assert_eq!(pkmn.to_bytes().unwrap(), data);
+        assert_eq!((pkmn.nickname.text(), &pkmn.nickname.trash()[..2]), ("E", &[0x136, 0x136][..]));
+
+        pkmn.moves[0] = Move { id: 145, pp: 30, pp_ups: 3 };
+        pkmn.ivs.speed = 31;
+        pkmn.is_egg = true;
+        pkmn.gender = PokemonGender::Female;
+        pkmn.nickname.set_text("ELL");
+        pkmn.met_date = NaiveDate::from_ymd_opt(2009, 11, 24);
+        pkmn.battle_stats.as_mut().unwrap().level = 50;
+
+        let written = pkmn.to_bytes().unwrap();
+        assert_eq!(u16::from_le_bytes([written[0x06], written[0x07]]), pokemon_checksum(&written));
+        assert_eq!(written[0xD0], 0x55);
+        let mut reread = read(&written);
+        reread.checksum = pkmn.checksum;
+        reread.raw = pkmn.raw.clone();
+        assert_eq!(reread, pkmn);
+
+        // box Pokémon have no battle stats
+        pkmn.battle_stats = None;
+        assert_eq!(pkmn.to_bytes().unwrap().len(), BOX_POKEMON_SIZE);
+    }
+
+    /// Are `.pk4` files read whether or not they're encrypted, and rejected if they're corrupt?
+    #[cfg(feature = "write")]
+    #[test]
+    fn read_pk4() {
+        let mut data = vec![0u8; BOX_POKEMON_SIZE];
+        data[0x00..0x04].copy_from_slice(&0x12345678u32.to_le_bytes());
+        data[0x08..0x0A].copy_from_slice(&393u16.to_le_bytes());
+        data[0x28..0x2A].copy_from_slice(&145u16.to_le_bytes());
+        let checksum = pokemon_checksum(&data);
+        data[0x06..0x08].copy_from_slice(&checksum.to_le_bytes());
+
+        let decrypted = Gen4Pokemon::from_pk4(&data).unwrap();
+        let encrypted = Gen4Pokemon::from_pk4(&encrypt_pokemon_blob(&data).unwrap()).unwrap();
+        assert_eq!(decrypted, encrypted);
+        assert_eq!((decrypted.species, decrypted.moves[0].id), (Species::Piplup, 145));
+
+        assert_eq!(decrypted.to_pk4().unwrap(), data);
+        let party = decrypted.to_pk4_party().unwrap();
+        assert_eq!((party.len(), &party[..BOX_POKEMON_SIZE]), (PARTY_POKEMON_SIZE, &data[..]));
+
+        data[0x10] = 1;
+        assert!(matches!(Gen4Pokemon::from_pk4(&data), Err(ReadError::InvalidValue { block: Block::Pokemon, field: "checksum", .. })));
+        assert!(matches!(Gen4Pokemon::from_pk4(&data[..100]), Err(ReadError::InvalidPokemonSize(100))));
+    }
+}
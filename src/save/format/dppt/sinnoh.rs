@@ -0,0 +1,780 @@
+use crate::save::error::{Block, ReadError};
+use crate::save::format::container::Container;
+use crate::save::format::{self, BlockStatus, ParseOptions, SlotLocation};
+#[cfg(feature = "write")]
+use crate::save::error::WriteError;
+use crate::save::format::dppt::block::{select_block, validate_block, FooterFormat, RawSave, SaveSlot, PARTITION_SIZE};
+#[cfg(feature = "write")]
+use crate::save::format::dppt::block::SlotSelection;
+use crate::save::format::dppt::pokemon::{Gen4Pokemon, BOX_POKEMON_SIZE};
+#[cfg(feature = "write")]
+use crate::save::format::dppt::pokemon::{decrypt_pokemon_blob, encrypt_pokemon_blob, Origin, PARTY_POKEMON_SIZE};
+use crate::save::format::dppt::string::Gen4String;
+use crate::save::format::reader::{BlockReader, Diagnostics};
+#[cfg(feature = "write")]
+use crate::save::format::dppt::string;
+use crate::save::save::{Gender, Playtime, SaveFile, Trainer};
+#[cfg(feature = "write")]
+use crate::save::save::{Pokemon, PARTY_SIZE};
+#[cfg(feature = "write")]
+use byteorder::{LittleEndian, ReadBytesExt, WriteBytesExt};
+#[cfg(feature = "write")]
+use std::io;
+#[cfg(feature = "write")]
+use std::io::Cursor;
+use std::io::SeekFrom;
+#[cfg(feature = "write")]
+use std::collections::HashMap;
+use chrono::{DateTime, Utc};
+use crate::save::data::badge::Badges;
+use crate::save::data::dppt::item::DPPTItem;
+use crate::save::data::item::Item;
+use crate::save::data::pocket::Pocket;
+use crate::save::format::dppt::save::{Badge, Game, Gen4Save, Locale, Timestamp};
+use crate::save::format::dppt::{diamond_pearl, platinum};
+use crate::save::format::hgss;
+
+/// Every gen 4 save is two partitions, out of its container
+pub(crate) const SAVE_SIZE: usize = 2 * PARTITION_SIZE;
+
+/// Where things are in the general & storage blocks, which differ between DP, Pt & HGSS
+pub(crate) struct Layout {
+    pub general_size: usize,
+    pub storage_offset: usize,
+    pub storage_size: usize,
+    pub footer: FooterFormat,
+    /// Start of the trainer's name, and the rest of the player block
+    pub trainer: usize,
+    /// Each region's badge byte, relative to `trainer`
+    pub badges: &'static [(usize, &'static [Badge; 8])],
+    /// Start of the party Pokémon, after the max party size & party count
+    pub party: usize,
+    pub bag: usize,
+    pub pocket_capacity: fn(Pocket) -> usize,
+    pub vars: usize,
+    pub var_count: usize,
+    pub flags: usize,
+    /// Number of bytes of flags
+    pub flag_size: usize,
+    /// The current box, relative to the storage block
+    pub current_box: usize,
+    /// The first box, relative to the storage block
+    pub boxes: usize,
+    /// Distance between the start of each box
+    pub box_size: usize,
+    /// The box names, followed by the wallpapers, relative to the storage block
+    pub box_names: usize,
+    /// The games with this layout; the first is assumed if the game code is invalid
+    pub games: &'static [Game],
+}
+
+impl Layout {
+    pub fn of(game: Game) -> &'static Layout {
+        match game {
+            Game::Diamond | Game::Pearl => &diamond_pearl::LAYOUT,
+            Game::Platinum => &platinum::LAYOUT,
+            Game::HeartGold | Game::SoulSilver => &hgss::LAYOUT,
+        }
+    }
+
+    /// The layout whose general block footer is where it should be in either partition of `data`, a save
+    /// out of its container, if any
+    pub fn detect(data: &[u8]) -> Option<&'static Layout> {
+        [&platinum::LAYOUT, &diamond_pearl::LAYOUT, &hgss::LAYOUT].into_iter().find(|layout| {
+            [SaveSlot::First, SaveSlot::Second].into_iter().any(|slot| {
+                let start = slot.offset();
+                data.get(start..start + layout.general_size)
+                    .is_some_and(|block| validate_block(block, layout.footer) == BlockStatus::Valid)
+            })
+        })
+    }
+
+    /// Offset of slot `slot` of box `index`, relative to the storage block
+    fn box_slot(&self, index: usize, slot: usize) -> usize {
+        self.boxes + index * self.box_size + slot * BOX_POKEMON_SIZE
+    }
+}
+
+/// A save by "ELLie" from `game`, read from the first partition, with a Piplup & a traded Pikachu in the
+/// party, a Bidoof in the first box & a Starly in the last slot of the second. HGSS saves have more
+/// kinds of ball than fit in the DPPt pocket.
+///
+/// The Pokémon have their gen 4 data as it's read back, battle stats & all.
+#[cfg(all(test, feature = "write"))]
+pub(crate) fn test_save(game: Game) -> Gen4Save {
+    use crate::save::format::dppt::block::{BlockFooter, SlotReason, FOOTER_SIGNATURE};
+    use crate::save::data::species::Species;
+    use chrono::NaiveDate;
+
+    let layout = Layout::of(game);
+    let selection = |size: usize, block_id: u8| SlotSelection {
+        slot: SaveSlot::First,
+        reason: SlotReason::Identical,
+        footer: BlockFooter { format: layout.footer, link_id: 1, save_id: 1, size: size as u32, signature: FOOTER_SIGNATURE, block_id, checksum: 0 },
+    };
+
+    let trainer = Trainer::new("ELLie".into(), 12345, Some(54321), Gender::Female);
+    let mut base = SaveFile::new(trainer.clone(), 3000);
+
+    let origin = Origin { game, locale: Locale::WesternEnglish, met_date: NaiveDate::from_ymd_opt(2010, 1, 2).unwrap(), seed: 0 };
+    let pokemon = |species: Species, name: &str, ot: &Trainer, experience: u32, friendship: u8, size: usize| {
+        let mut source = Pokemon::new(species);
+        source.set_name(name.into());
+        source.set_trainer(ot.clone());
+        source.set_experience(experience);
+        source.set_friendship(friendship);
+        let gen4 = Gen4Pokemon::try_from((&source, &Origin { seed: experience, ..origin })).unwrap();
+        let bytes = match size {
+            PARTY_POKEMON_SIZE => gen4.to_party_bytes(),
+            _ => gen4.to_pk4(),
+        };
+
+        // only the save's own trainer is given to a Pokémon that's read
+        let mut pkmn = Pokemon::new(species);
+        if ot == &trainer {
+            pkmn.set_trainer(trainer.clone());
+        }
+        pkmn.set_gen4(Gen4Pokemon::from_pk4(&bytes.unwrap()).unwrap());
+        pkmn
+    };
+    let ash = Trainer::new("ASH".into(), 11111, Some(22222), Gender::Male);
+
+    base.party.push(pokemon(Species::Piplup, "PIPLUP", &trainer, 1_000, 120, PARTY_POKEMON_SIZE));
+    base.party.push(pokemon(Species::Pikachu, "Sparky", &ash, 8_000, 255, PARTY_POKEMON_SIZE));
+
+    base.add_item(DPPTItem::Potion, 5);
+    base.add_item(DPPTItem::MasterBall, 1);
+    base.add_item(DPPTItem::Tm01, 2);
+    base.add_item(DPPTItem::OaksLetter, 1);
+
+    for i in 0..18 {
+        let mut pkmn_box = crate::save::save::Box::new(30);
+        pkmn_box.set_name(format!("BOX {}", i + 1));
+        pkmn_box.set_wallpaper(i as u8 % 16);
+        base.boxes.push(pkmn_box);
+    }
+    base.boxes[0].set_pkmn(3, pokemon(Species::Bidoof, "BIDOOF", &trainer, 200, 70, BOX_POKEMON_SIZE));
+    base.boxes[1].set_pkmn(29, pokemon(Species::Starly, "STARLY", &ash, 50, 70, BOX_POKEMON_SIZE));
+
+    let mut vars = vec![0; layout.var_count];
+    vars[0x4030 - 0x4000] = 7;
+    vars[layout.var_count - 1] = 42;
+    let mut flags = vec![0; layout.flag_size];
+    flags[0] = 0b10;
+    flags[0x2A4 / 8] = 0b1000_0001;
+
+    let badges = match game {
+        Game::HeartGold | Game::SoulSilver => {
+            for ball in (1..=16).chain(492..=499) {
+                base.add_item(DPPTItem::try_from(ball).unwrap(), 3);
+            }
+            vec![Badge::Zephyr, Badge::Rising, Badge::Boulder, Badge::Earth]
+        }
+        _ => vec![Badge::Coal, Badge::Relic],
+    };
+
+    Gen4Save {
+        game,
+        save_started: DateTime::from_timestamp(1_262_304_000, 0).unwrap(),
+        hall_of_fame_entered: DateTime::from_timestamp(1_262_390_400, 0).unwrap(),
+        base,
+        locale: Locale::WesternEnglish,
+        badges,
+        playtime: Playtime { hours: 123, minutes: 45, seconds: 6 },
+        vars,
+        flags,
+        general_slot: selection(layout.general_size, 0),
+        storage_slot: selection(layout.storage_size, 1),
+        raw: RawSave::default(),
+        corrupt_slots: Vec::new(),
+        diagnostics: Vec::new(),
+    }
+}
+
+/// `read`, read back from `test_save(read.game)`, written over blank blocks again. If nothing was lost
+/// reading it, this is the same file as `test_save` writes.
+#[cfg(all(test, feature = "write"))]
+pub(crate) fn rewrite_blank(mut read: Gen4Save) -> Vec<u8> {
+    let fixture = test_save(read.game);
+    read.raw = RawSave::default();
+    read.general_slot = fixture.general_slot;
+    read.storage_slot = fixture.storage_slot;
+    write_save_to_bytes(&read).unwrap()
+}
+
+/// Read a DP, Pt or HGSS save laid out as `layout`
+#[allow(clippy::unusual_byte_groupings)] // options bitfield groupings
+pub(crate) fn read_save_from_bytes(data: Vec<u8>, options: ParseOptions, layout: &Layout) -> Result<Gen4Save, ReadError> {
+
+    fn read_date(reader: &mut BlockReader, diagnostics: &mut Diagnostics, field: &'static str) -> Result<DateTime<Utc>, ReadError> {
+        let timestamp = reader.read_i64(field)?;
+        match Timestamp(timestamp).to_date() {
+            Some(date) => Ok(date),
+            None => {
+                diagnostics.report(reader.diagnostic(field, timestamp as u64))?;
+                Ok(Timestamp(0).to_date().unwrap())
+            }
+        }
+    }
+
+    let mut diagnostics = Diagnostics::new(&options);
+    let (container, data) = Container::unwrap(data, SAVE_SIZE);
+
+    // pick the newest valid copy of each block independently
+    let general_slot = select_block(&data, Block::General, 0x00, layout.general_size, layout.footer)?;
+    let storage_slot = select_block(&data, Block::Storage, layout.storage_offset, layout.storage_size, layout.footer)?;
+
+    let general_start = general_slot.slot.offset();
+    let storage_start = storage_slot.slot.offset() + layout.storage_offset;
+    let mut save_file = BlockReader::new(&data[general_start..general_start + layout.general_size], general_start, Block::System);
+    let mut storage = BlockReader::new(&data[storage_start..storage_start + layout.storage_size], storage_start, Block::Storage);
+
+    // SYSTEM BLOCK
+    save_file.seek(SeekFrom::Start(0x00));
+    let _rtc_offset = save_file.read_i64("RTC offset")?;
+    let _mac_address = save_file.read_bytes("MAC address", 6)?;
+
+    let _owner_month = save_file.read_u8("owner birth month")?;
+    let _owner_date = save_file.read_u8("owner birth date")?;
+    let _canary = save_file.read_u32("canary")?;
+
+    let _rtc_year = save_file.read_u32("RTC year")?;
+    let _rtc_month = save_file.read_u32("RTC month")?;
+    let _rtc_date = save_file.read_u32("RTC date")?;
+    let _rtc_weekday = save_file.read_u32("RTC weekday")?;
+
+    let _rtc_hour = save_file.read_u32("RTC hour")?;
+    let _rtc_minute = save_file.read_u32("RTC minute")?;
+    let _rtc_second = save_file.read_u32("RTC second")?;
+
+    let _day = save_file.read_u32("day")?;
+
+
+    // PLAYER BLOCK
+    save_file.set_block(Block::Player);
+    let start_date: DateTime<Utc> = read_date(&mut save_file, &mut diagnostics, "start date")?;
+    let hof_date: DateTime<Utc> = read_date(&mut save_file, &mut diagnostics, "hall of fame date")?;
+
+    let _save_penalty = save_file.read_u32("save penalty")?;
+    let _mystery_gift_unlocked = save_file.read_u8("mystery gift unlocked")?;
+
+    save_file.seek(SeekFrom::Current(0x03)); // padding_49
+
+    let _network_id = save_file.read_i32("network ID")?;
+
+    // the options come just before the trainer; DP has less between the two
+    save_file.seek(SeekFrom::Start((layout.trainer - 0x04) as u64));
+
+    let _options = save_file.read_u16("options")?;
+    let _opts_frame
+        = (_options & 0b0_1111_00_0_0_00_0000) >> 10;
+    let _opts_button_mode
+        = (_options & 0b0_0000_11_0_0_00_0000) >> 8;
+    let _opts_battle_scene
+        = (_options & 0b0_0000_00_1_0_00_0000) >> 7;
+    let _opts_battle_style
+        = (_options & 0b0_0000_00_0_1_00_0000) >> 6;
+    let _opts_sound_mode
+        = (_options & 0b0_0000_00_0_0_11_0000) >> 4;
+    let _opts_text_speed
+        = _options & 0b0_0000_00_0_0_00_1111;
+
+    save_file.seek(SeekFrom::Current(0x02)); // padding_02
+
+    let trainer_name = read_string(&mut save_file, "trainer name", 8)?;
+
+    let trainer_id = save_file.read_u16("trainer ID")?;
+    let trainer_secret_id = save_file.read_u16("trainer secret ID")?;
+    let trainer_money = save_file.read_u32("money")?;
+    let trainer_gender = match save_file.read_u8("gender")? {
+        0 => Gender::Male,
+        1 => Gender::Female,
+        other => {
+            diagnostics.report(save_file.diagnostic("gender", other))?;
+            Gender::Male
+        }
+    };
+    let locale = save_file.read_u8("locale")?;
+    let locale = match Locale::try_from(locale) {
+        Ok(locale) => locale,
+        Err(_) => {
+            diagnostics.report(save_file.diagnostic("locale", locale))?;
+            Locale::Unknown(locale)
+        }
+    };
+    save_file.seek(SeekFrom::Current(0x01)); // badges, read below with the rest
+
+    let trainer = Trainer::new(trainer_name.to_string(), trainer_id, Some(trainer_secret_id), trainer_gender);
+    let mut base_save = SaveFile::new(trainer.clone(), trainer_money);
+
+    let _appearance = save_file.read_u8("appearance")?;
+    let game_code = save_file.read_u8("game code")?;
+    let game = match Game::try_from(game_code) {
+        Ok(game) if layout.games.contains(&game) => game,
+        _ => {
+            diagnostics.report(save_file.diagnostic("game code", game_code))?;
+            layout.games[0]
+        }
+    };
+    let _postgame_flags = save_file.read_u8("postgame flags")?; // isMainStoryCleared, hasNationalDex
+
+    save_file.seek(SeekFrom::Current(0x02));
+    let _coins = save_file.read_u16("coins")?;
+    let playtime = Playtime {
+        hours: save_file.read_u16("playtime hours")?,
+        minutes: save_file.read_u8("playtime minutes")?,
+        seconds: save_file.read_u8("playtime seconds")?,
+    };
+
+    // HGSS has a second byte of badges for Kanto
+    let mut badges = Vec::new();
+    for (offset, region) in layout.badges {
+        save_file.seek(SeekFrom::Start((layout.trainer + offset) as u64));
+        badges.extend(Badges::from_bits(save_file.read_u8("badges")?, region).0);
+    }
+
+    // PARTY BLOCK
+    save_file.set_block(Block::Party);
+    save_file.seek(SeekFrom::Start((layout.party - 0x08) as u64));
+    let _max_party_count = save_file.read_u8("max party count")?;
+    save_file.seek(SeekFrom::Current(0x03));
+    let _number_in_party = save_file.read_u8("party count")?;
+    save_file.seek(SeekFrom::Current(0x03));
+
+    let mut corrupt_slots = Vec::new();
+    save_file.seek(SeekFrom::Start(layout.party as u64));
+    base_save.party = format::read_party::<Gen4Pokemon>(&mut save_file, 6, Block::Party, &trainer, &mut diagnostics, &mut corrupt_slots)?;
+
+    // BAG BLOCK
+    save_file.set_block(Block::Bag);
+    save_file.seek(SeekFrom::Start(layout.bag as u64));
+    for pocket in Pocket::ALL {
+        for _i in 0..(layout.pocket_capacity)(pocket) {
+            let item_id = save_file.read_u16("item")?;
+            let qty = save_file.read_u16("item quantity")?;
+            if item_id == 0 {
+                continue;
+            }
+
+            let item = match DPPTItem::try_from(item_id) {
+                Ok(item) => item,
+                Err(_) => {
+                    diagnostics.report(save_file.diagnostic("item", item_id))?;
+                    DPPTItem::Unknown(item_id)
+                }
+            };
+            if qty > Item::MAX_QUANTITY {
+                diagnostics.report(save_file.diagnostic("item quantity", qty))?;
+            }
+            // the game keeps one stack of each item, so a second is combined with the first
+            if base_save.get_item(item).is_some() {
+                diagnostics.report(save_file.diagnostic("duplicate item", item_id))?;
+            }
+            base_save.add_item(item, qty);
+        }
+    }
+
+    // VARS & FLAGS BLOCK
+    save_file.set_block(Block::Events);
+    save_file.seek(SeekFrom::Start(layout.vars as u64));
+    let vars = save_file.read_u16s("vars", layout.var_count)?;
+    save_file.seek(SeekFrom::Start(layout.flags as u64));
+    let flags = save_file.read_bytes("flags", layout.flag_size)?;
+
+    // STORAGE BLOCK
+    storage.seek(SeekFrom::Start(layout.current_box as u64));
+    let _current_box = storage.read_u32("current box")?;
+    let mut boxes: Vec<crate::save::save::Box> = Vec::with_capacity(18);
+    for i in 0..18 {
+        let mut pkmn_box = crate::save::save::Box::new(30);
+        for j in 0..30 {
+            storage.seek(SeekFrom::Start(layout.box_slot(i, j) as u64));
+            let offset = storage.offset();
+            let buf = storage.read_bytes("box Pokémon", BOX_POKEMON_SIZE)?;
+            if let Some(pkmn) = format::read_slot::<Gen4Pokemon>(buf, offset, Block::Storage, SlotLocation::Box(i, j), &trainer, &mut diagnostics, &mut corrupt_slots)? {
+                pkmn_box.set_pkmn(j, pkmn);
+            }
+        }
+        boxes.push(pkmn_box);
+    }
+
+    // box names
+    storage.seek(SeekFrom::Start(layout.box_names as u64));
+    for i in 0..boxes.len() {
+        let current_box: &mut crate::save::save::Box = boxes.get_mut(i).unwrap();
+        (*current_box).set_name(read_string(&mut storage, "box name", 20)?.to_string());
+    }
+
+    // box wallpapers
+    for i in 0..boxes.len() {
+        let current_box: &mut crate::save::save::Box = boxes.get_mut(i).unwrap();
+        (*current_box).set_wallpaper(storage.read_u8("box wallpaper")?);
+    }
+
+    base_save.boxes = boxes;
+
+    Ok(Gen4Save {
+        game,
+        save_started: start_date,
+        hall_of_fame_entered: hof_date,
+        base: base_save,
+        locale,
+        badges,
+        playtime,
+        vars,
+        flags,
+        general_slot,
+        storage_slot,
+        raw: RawSave::new(
+            data,
+            general_start..general_start + layout.general_size,
+            storage_start..storage_start + layout.storage_size,
+            container,
+        ),
+        corrupt_slots,
+        diagnostics: diagnostics.into_inner(),
+    })
+}
+
+/// Serialize `save` into a full 512 KiB `.sav` image.
+///
+/// Typed fields are written over the original file (`save.raw`), so anything not parsed is kept. A
+/// block is only written if its contents have changed; like the game, the new copy goes to the opposite
+/// partition to the one it was read from, with its footer's link & save IDs bumped. Writing back an
+/// unmodified save therefore gives an identical file.
+///
+/// If there is no original file, the save is written over blank blocks. The layout is chosen by
+/// `save.game`. Pokémon without gen 4 data are converted as met today, see `Origin::today`.
+///
+/// The save is put back in the container it was read from. A trimmed save that's missing the opposite
+/// partition has its blocks written in place instead.
+#[cfg(feature = "write")]
+pub(crate) fn write_save_to_bytes(save: &Gen4Save) -> Result<Vec<u8>, WriteError> {
+    let place_block = |data: &mut [u8], contents: &[u8], offset: usize, selection: &SlotSelection| {
+        let mut footer = selection.footer;
+        footer.link_id = footer.link_id.wrapping_add(1);
+        footer.save_id = footer.save_id.wrapping_add(1);
+
+        let other = selection.slot.other().offset() + offset;
+        let start = match save.raw.container().holds(other + contents.len()) {
+            true => other,
+            false => selection.slot.offset() + offset,
+        };
+        let block = &mut data[start..start + contents.len()];
+        block.copy_from_slice(contents);
+        footer.write_to_block(block);
+    };
+
+    let layout = Layout::of(save.game);
+    let base_save = &save.base;
+    let trainer = base_save.trainer();
+    let mut origin = Origin::today(save);
+
+    // GENERAL BLOCK
+    let original_general = save.raw.general_block();
+    let mut general = Cursor::new(match original_general {
+        Some(block) => block.to_vec(),
+        None => blank_general_block(layout, save.game)?,
+    });
+
+    // PLAYER BLOCK
+    write_seek(&mut general, SeekFrom::Start(0x34))?;
+    write_date(&mut general, &save.save_started)?;
+    write_date(&mut general, &save.hall_of_fame_entered)?;
+
+    write_seek(&mut general, SeekFrom::Start(layout.trainer as u64))?;
+    write_string::<8>(&mut general, &Gen4String::new(trainer.name()))?;
+    write_u16(&mut general, trainer.id())?;
+    write_u16(&mut general, trainer.secret_id().unwrap_or(0))?;
+    write_u32(&mut general, base_save.money())?;
+    write_u8(&mut general, *trainer.gender() as u8)?;
+    write_u8(&mut general, save.locale.into())?;
+
+    let regions: Vec<&[Badge; 8]> = layout.badges.iter().map(|(_, region)| *region).collect();
+    let badge_bits = Badges::to_bits(&save.badges, &regions)?;
+    for ((offset, _), bits) in layout.badges.iter().zip(badge_bits) {
+        write_seek(&mut general, SeekFrom::Start((layout.trainer + offset) as u64))?;
+        write_u8(&mut general, bits)?;
+    }
+
+    write_seek(&mut general, SeekFrom::Start((layout.trainer + 0x22) as u64))?;
+    write_u16(&mut general, save.playtime.hours)?;
+    write_u8(&mut general, save.playtime.minutes)?;
+    write_u8(&mut general, save.playtime.seconds)?;
+
+    // PARTY BLOCK
+    // corrupt slots are still in the party, as far as the game is concerned
+    let party_count = base_save.party.len() + (0..PARTY_SIZE).filter(|i| save.corrupt_slot(SlotLocation::Party(*i)).is_some()).count();
+    if party_count > PARTY_SIZE {
+        return Err(WriteError::PartyFull(party_count));
+    }
+    write_seek(&mut general, SeekFrom::Start((layout.party - 0x04) as u64))?;
+    write_u8(&mut general, party_count as u8)?;
+
+    // corrupt slots keep their place, with the rest of the party around them
+    let mut party = base_save.party.iter();
+    for i in 0..PARTY_SIZE {
+        let offset = layout.party + i * PARTY_POKEMON_SIZE;
+        let original = general.get_ref()[offset..offset + PARTY_POKEMON_SIZE].to_vec();
+        let blob = match save.corrupt_slot(SlotLocation::Party(i)) {
+            Some(corrupt) => corrupt.bytes.clone(),
+            None => write_pokemon_blob(party.next(), &mut origin, &original)?,
+        };
+
+        write_seek(&mut general, SeekFrom::Start(offset as u64))?;
+        write_bytes(&mut general, &blob)?;
+    }
+
+    // BAG BLOCK
+    write_seek(&mut general, SeekFrom::Start(layout.bag as u64))?;
+    write_bag(&mut general, base_save, layout.pocket_capacity)?;
+
+    // VARS & FLAGS BLOCK
+    // only what's there is written, so the rest of the original is kept
+    write_seek(&mut general, SeekFrom::Start(layout.vars as u64))?;
+    for var in save.vars.iter().take(layout.var_count) {
+        write_u16(&mut general, *var)?;
+    }
+    write_seek(&mut general, SeekFrom::Start(layout.flags as u64))?;
+    write_bytes(&mut general, &save.flags[..save.flags.len().min(layout.flag_size)])?;
+
+    // STORAGE BLOCK
+    let original_storage = save.raw.storage_block();
+    let mut storage = Cursor::new(match original_storage {
+        Some(block) => block.to_vec(),
+        None => blank_storage_block(layout)?,
+    });
+
+    for i in 0..18 {
+        let pkmn_box = base_save.boxes.get(i);
+        for j in 0..30 {
+            let offset = layout.box_slot(i, j);
+            let original = storage.get_ref()[offset..offset + BOX_POKEMON_SIZE].to_vec();
+            let pkmn = pkmn_box.and_then(|b| b.get_pkmn(j));
+            let blob = match save.corrupt_slot(SlotLocation::Box(i, j)) {
+                Some(corrupt) if pkmn.is_none() => corrupt.bytes.clone(),
+                _ => write_pokemon_blob(pkmn, &mut origin, &original)?,
+            };
+
+            write_seek(&mut storage, SeekFrom::Start(offset as u64))?;
+            write_bytes(&mut storage, &blob)?;
+        }
+    }
+
+    // box names
+    write_seek(&mut storage, SeekFrom::Start(layout.box_names as u64))?;
+    for i in 0..18 {
+        let box_name = Gen4String::new(base_save.boxes.get(i).map_or("", |b| b.name()));
+        write_string::<20>(&mut storage, &box_name)?;
+    }
+
+    // box wallpapers
+    for i in 0..18 {
+        write_u8(&mut storage, base_save.boxes.get(i).map_or(0, |b| b.wallpaper()))?;
+    }
+
+    let mut data = match save.raw.is_empty() {
+        true => vec![0xFFu8; SAVE_SIZE],
+        false => save.raw.bytes().to_vec(),
+    };
+
+    let general = general.into_inner();
+    if original_general != Some(&general[..]) {
+        place_block(&mut data, &general, 0x00, &save.general_slot);
+    }
+
+    let storage = storage.into_inner();
+    if original_storage != Some(&storage[..]) {
+        place_block(&mut data, &storage, layout.storage_offset, &save.storage_slot);
+    }
+
+    Ok(save.raw.container().wrap(data))
+}
+
+/// A blank general block: zeroed, apart from the game code and the (encrypted) empty party slots
+#[cfg(feature = "write")]
+fn blank_general_block(layout: &Layout, game: Game) -> Result<Vec<u8>, WriteError> {
+    let mut block = Cursor::new(vec![0u8; layout.general_size]);
+    write_seek(&mut block, SeekFrom::Start((layout.trainer + 0x1C) as u64))?;
+    write_u8(&mut block, game.into())?;
+
+    write_seek(&mut block, SeekFrom::Start((layout.party - 0x08) as u64))?;
+    write_u8(&mut block, 6)?; // max party size
+
+    write_seek(&mut block, SeekFrom::Start(layout.party as u64))?;
+    let empty = encrypt_pokemon_blob(&[0u8; PARTY_POKEMON_SIZE])?;
+    for _i in 0..6 {
+        write_bytes(&mut block, &empty)?;
+    }
+
+    Ok(block.into_inner())
+}
+
+/// A blank storage block: zeroed, apart from the (encrypted) empty box slots
+#[cfg(feature = "write")]
+fn blank_storage_block(layout: &Layout) -> Result<Vec<u8>, WriteError> {
+    let mut block = Cursor::new(vec![0u8; layout.storage_size]);
+    let empty = encrypt_pokemon_blob(&[0u8; BOX_POKEMON_SIZE])?;
+    for i in 0..18 {
+        for j in 0..30 {
+            write_seek(&mut block, SeekFrom::Start(layout.box_slot(i, j) as u64))?;
+            write_bytes(&mut block, &empty)?;
+        }
+    }
+
+    Ok(block.into_inner())
+}
+
+/// Write the inventory over the bag at the current position.
+///
+/// Each pocket keeps the order of the items already in it, with removed items dropped and new items
+/// appended. Pockets whose contents haven't changed are left untouched.
+#[cfg(feature = "write")]
+fn write_bag(block: &mut Cursor<Vec<u8>>, base_save: &SaveFile, capacity: fn(Pocket) -> usize) -> Result<(), WriteError> {
+    let inventory = base_save.inventory();
+    let bag_offset = block.position();
+
+    let mut original: Vec<Vec<(u16, u16)>> = Vec::with_capacity(Pocket::ALL.len());
+    for pocket in Pocket::ALL {
+        let mut items = Vec::with_capacity(capacity(pocket));
+        for _i in 0..capacity(pocket) {
+            let item_id = block.read_u16::<LittleEndian>()?;
+            let qty = block.read_u16::<LittleEndian>()?;
+            if item_id != 0 {
+                items.push((item_id, qty));
+            }
+        }
+        original.push(items);
+    }
+
+    let original_ids: Vec<u16> = original.iter().flatten().map(|(item_id, _)| *item_id).collect();
+
+    let mut pocket_offset = bag_offset;
+    for (pocket, original) in Pocket::ALL.into_iter().zip(original.iter()) {
+        let mut items: Vec<(u16, u16)> = original.iter()
+            .filter_map(|(item_id, _)| {
+                let item = Item::Gen4(DPPTItem::try_from(*item_id).unwrap_or(DPPTItem::Unknown(*item_id)));
+                inventory.get(&item).filter(|qty| **qty > 0).map(|qty| (*item_id, *qty))
+            })
+            .collect();
+        items.dedup_by_key(|(item_id, _)| *item_id);
+
+        // items already somewhere in the bag stay where they are
+        let mut new_items: Vec<(u16, u16)> = inventory.iter()
+            .filter(|(item, qty)| **qty > 0 && item.pocket() == Some(pocket))
+            .map(|(item, qty)| (item.id(), *qty))
+            .filter(|(item_id, _)| !original_ids.contains(item_id))
+            .collect();
+        new_items.sort();
+        items.append(&mut new_items);
+
+        if items.len() > capacity(pocket) {
+            return Err(WriteError::PocketFull { pocket, capacity: capacity(pocket) });
+        }
+
+        // duplicate stacks are combined when reading, so compare the combined totals
+        let combined_original: HashMap<u16, u16> = original.iter()
+            .fold(HashMap::new(), |mut acc, (item_id, qty)| {
+                *acc.entry(*item_id).or_insert(0) += qty;
+                acc
+            });
+        let unchanged = items.len() == combined_original.len()
+            && items.iter().all(|(item_id, qty)| combined_original.get(item_id) == Some(qty));
+
+        if !unchanged {
+            write_seek(block, SeekFrom::Start(pocket_offset))?;
+            for i in 0..capacity(pocket) {
+                let (item_id, qty) = items.get(i).copied().unwrap_or((0, 0));
+                write_u16(block, item_id)?;
+                write_u16(block, qty)?;
+            }
+        }
+
+        pocket_offset += (capacity(pocket) * 4) as u64;
+    }
+
+    Ok(())
+}
+
+/// Write `pokemon` over the encrypted party or box Pokémon `original`, returning the new encrypted
+/// Pokémon. If nothing has changed, `original` is returned as-is.
+///
+/// A Pokémon without gen 4 data is converted with `origin`, whose seed is then moved on so that the next
+/// one gets a different PID.
+#[cfg(feature = "write")]
+fn write_pokemon_blob(pokemon: Option<&Pokemon>, origin: &mut Origin, original: &[u8]) -> Result<Vec<u8>, WriteError> {
+    let decrypted = decrypt_pokemon_blob(original).map_err(|_| WriteError::InvalidPokemonSize(original.len()))?;
+
+    let blob = match pokemon {
+        Some(pokemon) => {
+            let gen4 = Gen4Pokemon::try_from((pokemon, &*origin))?;
+            if pokemon.gen4().is_none() {
+                origin.seed = origin.seed.wrapping_add(1);
+            }
+            // box Pokémon have no battle stats, so moving to the box drops them & moving to the party works
+            // them out
+            match original.len() {
+                PARTY_POKEMON_SIZE => gen4.to_party_bytes()?,
+                _ => gen4.to_pk4()?,
+            }
+        }
+        None => vec![0u8; original.len()],
+    };
+
+    if blob == decrypted {
+        return Ok(original.to_vec());
+    }
+
+    encrypt_pokemon_blob(&blob)
+}
+
+fn read_string(reader: &mut BlockReader, field: &'static str, length: usize) -> Result<Gen4String, ReadError> {
+    Ok(Gen4String::from_raw(reader.read_u16s(field, length)?))
+}
+
+#[cfg(feature = "write")]
+fn write_seek(seekable: &mut impl io::Seek, position: SeekFrom) -> Result<u64, WriteError> {
+    Ok(seekable.seek(position)?)
+}
+
+#[cfg(feature = "write")]
+fn write_bytes(writable: &mut impl io::Write, value: &[u8]) -> Result<(), WriteError> {
+    Ok(writable.write_all(value)?)
+}
+
+#[cfg(feature = "write")]
+fn write_u8(writable: &mut impl io::Write, value: u8) -> Result<(), WriteError> {
+    Ok(writable.write_u8(value)?)
+}
+
+#[cfg(feature = "write")]
+fn write_u16(writable: &mut impl io::Write, value: u16) -> Result<(), WriteError> {
+    Ok(writable.write_u16::<LittleEndian>(value)?)
+}
+
+#[cfg(feature = "write")]
+fn write_u32(writable: &mut impl io::Write, value: u32) -> Result<(), WriteError> {
+    Ok(writable.write_u32::<LittleEndian>(value)?)
+}
+
+#[cfg(feature = "write")]
+fn write_date(writable: &mut impl io::Write, value: &DateTime<Utc>) -> Result<(), WriteError> {
+    let timestamp: Timestamp = (*value).into();
+    Ok(writable.write_i64::<LittleEndian>(timestamp.0)?)
+}
+
+/// Write `value` over the `N` code unit string at the current position, leaving the position after it.
+/// See `string::write_string`.
+#[cfg(feature = "write")]
+fn write_string<const N: usize>(cursor: &mut Cursor<Vec<u8>>, value: &Gen4String) -> Result<(), WriteError> {
+    let start = cursor.position();
+    let end = start + (N * 2) as u64;
+    if end > cursor.get_ref().len() as u64 {
+        return Err(WriteError::Truncated { offset: start as usize, length: N * 2 });
+    }
+
+    string::write_string(&mut cursor.get_mut()[start as usize..end as usize], value, N)?;
+    write_seek(cursor, SeekFrom::Start(end))?;
+    Ok(())
+}
+
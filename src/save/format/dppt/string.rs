@@ -0,0 +1,729 @@
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::OnceLock;
+use crate::save::error::EncodeError;
+#[cfg(feature = "write")]
+use crate::save::error::WriteError;
+
+// string funcs; thank you to https://github.com/kwsch/PKHeX/blob/master/PKHeX.Core/PKM/Strings/StringConverter4Util.cs !
+const NUL: char = '\u{FFFF}';
+/// The half-width ♂ used in names, which Unicode has no character for; it's read as `⑭`, like PKHeX does.
+/// The full-width `♂` is a different character code.
+pub const HALF_WIDTH_GENDER_MALE: char = '\u{246D}';
+/// The half-width ♀ used in names, read as `⑮`
+pub const HALF_WIDTH_GENDER_FEMALE: char = '\u{246E}';
+/// Ends a string, unless it fills its field
+pub const TERMINATOR: u16 = 0xFFFF;
+const INTERNATIONAL_TABLE: [char; 493] = [
+    NUL, '　', 'ぁ', 'あ', 'ぃ', 'い', 'ぅ', 'う', 'ぇ', 'え', 'ぉ', 'お', 'か', 'が', 'き', 'ぎ', // 000-00F
+    'く', 'ぐ', 'け', 'げ', 'こ', 'ご', 'さ', 'ざ', 'し', 'じ', 'す', 'ず', 'せ', 'ぜ', 'そ', 'ぞ', // 010-01F
+    'た', 'だ', 'ち', 'ぢ', 'っ', 'つ', 'づ', 'て', 'で', 'と', 'ど', 'な', 'に', 'ぬ', 'ね', 'の', // 020-02F
+    'は', 'ば', 'ぱ', 'ひ', 'び', 'ぴ', 'ふ', 'ぶ', 'ぷ', 'へ', 'べ', 'ぺ', 'ほ', 'ぼ', 'ぽ', 'ま', // 030-03F
+    'み', 'む', 'め', 'も', 'ゃ', 'や', 'ゅ', 'ゆ', 'ょ', 'よ', 'ら', 'り', 'る', 'れ', 'ろ', 'わ', // 040-04F
+    'を', 'ん', 'ァ', 'ア', 'ィ', 'イ', 'ゥ', 'ウ', 'ェ', 'エ', 'ォ', 'オ', 'カ', 'ガ', 'キ', 'ギ', // 050-05F
+    'ク', 'グ', 'ケ', 'ゲ', 'コ', 'ゴ', 'サ', 'ザ', 'シ', 'ジ', 'ス', 'ズ', 'セ', 'ゼ', 'ソ', 'ゾ', // 060-06F
+    'タ', 'ダ', 'チ', 'ヂ', 'ッ', 'ツ', 'ヅ', 'テ', 'デ', 'ト', 'ド', 'ナ', 'ニ', 'ヌ', 'ネ', 'ノ', // 070-07F
+    'ハ', 'バ', 'パ', 'ヒ', 'ビ', 'ピ', 'フ', 'ブ', 'プ', 'ヘ', 'ベ', 'ペ', 'ホ', 'ボ', 'ポ', 'マ', // 080-08F
+    'ミ', 'ム', 'メ', 'モ', 'ャ', 'ヤ', 'ュ', 'ユ', 'ョ', 'ヨ', 'ラ', 'リ', 'ル', 'レ', 'ロ', 'ワ', // 090-09F
+    'ヲ', 'ン', '０', '１', '２', '３', '４', '５', '６', '７', '８', '９', 'Ａ', 'Ｂ', 'Ｃ', 'Ｄ', // 0A0-0AF
+    'Ｅ', 'Ｆ', 'Ｇ', 'Ｈ', 'Ｉ', 'Ｊ', 'Ｋ', 'Ｌ', 'Ｍ', 'Ｎ', 'Ｏ', 'Ｐ', 'Ｑ', 'Ｒ', 'Ｓ', 'Ｔ', // 0B0-0BF
+    'Ｕ', 'Ｖ', 'Ｗ', 'Ｘ', 'Ｙ', 'Ｚ', 'ａ', 'ｂ', 'ｃ', 'ｄ', 'ｅ', 'ｆ', 'ｇ', 'ｈ', 'ｉ', 'ｊ', // 0C0-0CF
+    'ｋ', 'ｌ', 'ｍ', 'ｎ', 'ｏ', 'ｐ', 'ｑ', 'ｒ', 'ｓ', 'ｔ', 'ｕ', 'ｖ', 'ｗ', 'ｘ', 'ｙ', 'ｚ', // 0D0-0DF
+    NUL, '！', '？', '、', '。', '…', '・', '／', '「', '」', '『', '』', '（', '）', '♂', '♀', // 0E0-0EF
+    '＋', 'ー', '×', '÷', '＝', '～', '：', '；', '．', '，', '♠', '♣', '♥', '♦', '★', '◎', // 0F0-0FF
+    '○', '□', '△', '◇', '＠', '♪', '％', '☀', '☁', '☂', '☃', '①', '②', '③', '④', '⑤', // 100-10F
+    '⑥', '⑦', '円', '♈', '♉', '♊', '♋', '♌', '♍', '♎', '♏', '←', '↑', '↓', '→', '►', // 110-11F
+    '＆', '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', 'A', 'B', 'C', 'D', 'E', // 120-12F
+    'F', 'G', 'H', 'I', 'J', 'K', 'L', 'M', 'N', 'O', 'P', 'Q', 'R', 'S', 'T', 'U', // 130-13F
+    'V', 'W', 'X', 'Y', 'Z', 'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', // 140-14F
+    'l', 'm', 'n', 'o', 'p', 'q', 'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z', 'À', // 150-15F
+    'Á', 'Â', 'Ã', 'Ä', 'Å', 'Æ', 'Ç', 'È', 'É', 'Ê', 'Ë', 'Ì', 'Í', 'Î', 'Ï', 'Ð', // 160-16F
+    'Ñ', 'Ò', 'Ó', 'Ô', 'Õ', 'Ö', '⑧', 'Ø', 'Ù', 'Ú', 'Û', 'Ü', 'Ý', 'Þ', 'ß', 'à', // 170-17F
+    'á', 'â', 'ã', 'ä', 'å', 'æ', 'ç', 'è', 'é', 'ê', 'ë', 'ì', 'í', 'î', 'ï', 'ð', // 180-18F
+    'ñ', 'ò', 'ó', 'ô', 'õ', 'ö', '⑨', 'ø', 'ù', 'ú', 'û', 'ü', 'ý', 'þ', 'ÿ', 'Œ', // 190-19F
+    'œ', 'Ş', 'ş', 'ª', 'º', '⑩', '⑪', '⑫', '$', '¡', '¿', '!', '?', ',', '.', '⑬', // 1A0-1AF
+    '･', '/', '‘', '\'', '“', '”', '„', '«', '»', '(', ')', HALF_WIDTH_GENDER_MALE, HALF_WIDTH_GENDER_FEMALE, '+', '-', '*', // 1B0-1BF
+    '#', '=', '&', '~', ':', ';', '⑯', '⑰', '⑱', '⑲', '⑳', '⑴', '⑵', '⑶', '⑷', '⑸', // 1C0-1CF
+    '@', '⑹', '%', '⑺', '⑻', '⑼', '⑽', '⑾', '⑿', '⒀', '⒁', '⒂', '⒃', '⒄', ' ', '⒅', // 1D0-1DF
+    '⒆', '⒇', '⒈', '⒉', '⒊', '⒋', '⒌', '⒍', '°', '_', '＿', '⒎', '⒏', // 1E0-1EC*
+];
+const INTERNATIONAL_TABLE_START: u16 = 0x000;
+const INTERNATIONAL_TABLE_END: u16 = 0x01EC;
+const KOREAN_TABLE_START: u16 = 0x400;
+const KOREAN_TABLE_END: u16 = 0xD65;
+const KOREAN_TABLE: [char; 2406] = [
+    NUL, '가', '각', '간', '갇', '갈', '갉', '갊', '감', '갑', '값', '갓', '갔', '강', '갖', '갗', // 400-40F
+    '같', '갚', '갛', '개', '객', '갠', '갤', '갬', '갭', '갯', '갰', '갱', '갸', '갹', '갼', '걀', // 410-41F
+    '걋', '걍', '걔', '걘', '걜', '거', '걱', '건', '걷', '걸', '걺', '검', '겁', '것', '겄', '겅', // 420-42F
+    '겆', '겉', '겊', '겋', '게', '겐', '겔', '겜', '겝', '겟', '겠', '겡', '겨', '격', '겪', '견', // 430-43F
+    '겯', '결', '겸', '겹', '겻', '겼', '경', '곁', '계', '곈', '곌', '곕', '곗', '고', '곡', '곤', // 440-44F
+    '곧', '골', '곪', '곬', '곯', '곰', '곱', '곳', '공', '곶', '과', '곽', '관', '괄', '괆', '괌', // 450-45F
+    '괍', '괏', '광', '괘', '괜', '괠', '괩', '괬', '괭', '괴', '괵', '괸', '괼', '굄', '굅', '굇', // 460-46F
+    '굉', '교', '굔', '굘', '굡', '굣', '구', '국', '군', '굳', '굴', '굵', '굶', '굻', '굼', '굽', // 470-47F
+    '굿', '궁', '궂', '궈', '궉', '권', '궐', '궜', '궝', '궤', '궷', '귀', '귁', '귄', '귈', '귐', // 480-48F
+    '귑', '귓', '규', '균', '귤', '그', '극', '근', '귿', '글', '긁', '금', '급', '긋', '긍', '긔', // 490-49F
+    '기', '긱', '긴', '긷', '길', '긺', '김', '깁', '깃', '깅', '깆', '깊', '까', '깍', '깎', '깐', // 4A0-4AF
+    '깔', '깖', '깜', '깝', '깟', '깠', '깡', '깥', '깨', '깩', '깬', '깰', '깸', '깹', '깻', '깼', // 4B0-4BF
+    '깽', '꺄', '꺅', '꺌', '꺼', '꺽', '꺾', '껀', '껄', '껌', '껍', '껏', '껐', '껑', '께', '껙', // 4C0-4CF
+    '껜', '껨', '껫', '껭', '껴', '껸', '껼', '꼇', '꼈', '꼍', '꼐', '꼬', '꼭', '꼰', '꼲', '꼴', // 4D0-4DF
+    '꼼', '꼽', '꼿', '꽁', '꽂', '꽃', '꽈', '꽉', '꽐', '꽜', '꽝', '꽤', '꽥', '꽹', '꾀', '꾄', // 4E0-4EF
+    '꾈', '꾐', '꾑', '꾕', '꾜', '꾸', '꾹', '꾼', '꿀', '꿇', '꿈', '꿉', '꿋', '꿍', '꿎', '꿔', // 4F0-4FF
+    '꿜', '꿨', '꿩', '꿰', '꿱', '꿴', '꿸', '뀀', '뀁', '뀄', '뀌', '뀐', '뀔', '뀜', '뀝', '뀨', // 500-50F
+    '끄', '끅', '끈', '끊', '끌', '끎', '끓', '끔', '끕', '끗', '끙', '끝', '끼', '끽', '낀', '낄', // 510-51F
+    '낌', '낍', '낏', '낑', '나', '낙', '낚', '난', '낟', '날', '낡', '낢', '남', '납', '낫', '났', // 520-52F
+    '낭', '낮', '낯', '낱', '낳', '내', '낵', '낸', '낼', '냄', '냅', '냇', '냈', '냉', '냐', '냑', // 530-53F
+    '냔', '냘', '냠', '냥', '너', '넉', '넋', '넌', '널', '넒', '넓', '넘', '넙', '넛', '넜', '넝', // 540-54F
+    '넣', '네', '넥', '넨', '넬', '넴', '넵', '넷', '넸', '넹', '녀', '녁', '년', '녈', '념', '녑', // 550-55F
+    '녔', '녕', '녘', '녜', '녠', '노', '녹', '논', '놀', '놂', '놈', '놉', '놋', '농', '높', '놓', // 560-56F
+    '놔', '놘', '놜', '놨', '뇌', '뇐', '뇔', '뇜', '뇝', '뇟', '뇨', '뇩', '뇬', '뇰', '뇹', '뇻', // 570-57F
+    '뇽', '누', '눅', '눈', '눋', '눌', '눔', '눕', '눗', '눙', '눠', '눴', '눼', '뉘', '뉜', '뉠', // 580-58F
+    '뉨', '뉩', '뉴', '뉵', '뉼', '늄', '늅', '늉', '느', '늑', '는', '늘', '늙', '늚', '늠', '늡', // 590-59F
+    '늣', '능', '늦', '늪', '늬', '늰', '늴', '니', '닉', '닌', '닐', '닒', '님', '닙', '닛', '닝', // 5A0-5AF
+    '닢', '다', '닥', '닦', '단', '닫', '달', '닭', '닮', '닯', '닳', '담', '답', '닷', '닸', '당', // 5B0-5BF
+    '닺', '닻', '닿', '대', '댁', '댄', '댈', '댐', '댑', '댓', '댔', '댕', '댜', '더', '덕', '덖', // 5C0-5CF
+    '던', '덛', '덜', '덞', '덟', '덤', '덥', '덧', '덩', '덫', '덮', '데', '덱', '덴', '델', '뎀', // 5D0-5DF
+    '뎁', '뎃', '뎄', '뎅', '뎌', '뎐', '뎔', '뎠', '뎡', '뎨', '뎬', '도', '독', '돈', '돋', '돌', // 5E0-5EF
+    '돎', '돐', '돔', '돕', '돗', '동', '돛', '돝', '돠', '돤', '돨', '돼', '됐', '되', '된', '될', // 5F0-5FF
+    '됨', '됩', '됫', '됴', '두', '둑', '둔', '둘', '둠', '둡', '둣', '둥', '둬', '뒀', '뒈', '뒝', // 600-60F
+    '뒤', '뒨', '뒬', '뒵', '뒷', '뒹', '듀', '듄', '듈', '듐', '듕', '드', '득', '든', '듣', '들', // 610-61F
+    '듦', '듬', '듭', '듯', '등', '듸', '디', '딕', '딘', '딛', '딜', '딤', '딥', '딧', '딨', '딩', // 620-62F
+    '딪', '따', '딱', '딴', '딸', '땀', '땁', '땃', '땄', '땅', '땋', '때', '땍', '땐', '땔', '땜', // 630-63F
+    '땝', '땟', '땠', '땡', '떠', '떡', '떤', '떨', '떪', '떫', '떰', '떱', '떳', '떴', '떵', '떻', // 640-64F
+    '떼', '떽', '뗀', '뗄', '뗌', '뗍', '뗏', '뗐', '뗑', '뗘', '뗬', '또', '똑', '똔', '똘', '똥', // 650-65F
+    '똬', '똴', '뙈', '뙤', '뙨', '뚜', '뚝', '뚠', '뚤', '뚫', '뚬', '뚱', '뛔', '뛰', '뛴', '뛸', // 660-66F
+    '뜀', '뜁', '뜅', '뜨', '뜩', '뜬', '뜯', '뜰', '뜸', '뜹', '뜻', '띄', '띈', '띌', '띔', '띕', // 670-67F
+    '띠', '띤', '띨', '띰', '띱', '띳', '띵', '라', '락', '란', '랄', '람', '랍', '랏', '랐', '랑', // 680-68F
+    '랒', '랖', '랗', '래', '랙', '랜', '랠', '램', '랩', '랫', '랬', '랭', '랴', '략', '랸', '럇', // 690-69F
+    '량', '러', '럭', '런', '럴', '럼', '럽', '럿', '렀', '렁', '렇', '레', '렉', '렌', '렐', '렘', // 6A0-6AF
+    '렙', '렛', '렝', '려', '력', '련', '렬', '렴', '렵', '렷', '렸', '령', '례', '롄', '롑', '롓', // 6B0-6BF
+    '로', '록', '론', '롤', '롬', '롭', '롯', '롱', '롸', '롼', '뢍', '뢨', '뢰', '뢴', '뢸', '룀', // 6C0-6CF
+    '룁', '룃', '룅', '료', '룐', '룔', '룝', '룟', '룡', '루', '룩', '룬', '룰', '룸', '룹', '룻', // 6D0-6DF
+    '룽', '뤄', '뤘', '뤠', '뤼', '뤽', '륀', '륄', '륌', '륏', '륑', '류', '륙', '륜', '률', '륨', // 6E0-6EF
+    '륩', '륫', '륭', '르', '륵', '른', '를', '름', '릅', '릇', '릉', '릊', '릍', '릎', '리', '릭', // 6F0-6FF
+    '린', '릴', '림', '립', '릿', '링', '마', '막', '만', '많', '맏', '말', '맑', '맒', '맘', '맙', // 700-70F
+    '맛', '망', '맞', '맡', '맣', '매', '맥', '맨', '맬', '맴', '맵', '맷', '맸', '맹', '맺', '먀', // 710-71F
+    '먁', '먈', '먕', '머', '먹', '먼', '멀', '멂', '멈', '멉', '멋', '멍', '멎', '멓', '메', '멕', // 720-72F
+    '멘', '멜', '멤', '멥', '멧', '멨', '멩', '며', '멱', '면', '멸', '몃', '몄', '명', '몇', '몌', // 730-73F
+    '모', '목', '몫', '몬', '몰', '몲', '몸', '몹', '못', '몽', '뫄', '뫈', '뫘', '뫙', '뫼', '묀', // 740-74F
+    '묄', '묍', '묏', '묑', '묘', '묜', '묠', '묩', '묫', '무', '묵', '묶', '문', '묻', '물', '묽', // 750-75F
+    '묾', '뭄', '뭅', '뭇', '뭉', '뭍', '뭏', '뭐', '뭔', '뭘', '뭡', '뭣', '뭬', '뮈', '뮌', '뮐', // 760-76F
+    '뮤', '뮨', '뮬', '뮴', '뮷', '므', '믄', '믈', '믐', '믓', '미', '믹', '민', '믿', '밀', '밂', // 770-77F
+    '밈', '밉', '밋', '밌', '밍', '및', '밑', '바', '박', '밖', '밗', '반', '받', '발', '밝', '밞', // 780-78F
+    '밟', '밤', '밥', '밧', '방', '밭', '배', '백', '밴', '밸', '뱀', '뱁', '뱃', '뱄', '뱅', '뱉', // 790-79F
+    '뱌', '뱍', '뱐', '뱝', '버', '벅', '번', '벋', '벌', '벎', '범', '법', '벗', '벙', '벚', '베', // 7A0-7AF
+    '벡', '벤', '벧', '벨', '벰', '벱', '벳', '벴', '벵', '벼', '벽', '변', '별', '볍', '볏', '볐', // 7B0-7BF
+    '병', '볕', '볘', '볜', '보', '복', '볶', '본', '볼', '봄', '봅', '봇', '봉', '봐', '봔', '봤', // 7C0-7CF
+    '봬', '뵀', '뵈', '뵉', '뵌', '뵐', '뵘', '뵙', '뵤', '뵨', '부', '북', '분', '붇', '불', '붉', // 7D0-7DF
+    '붊', '붐', '붑', '붓', '붕', '붙', '붚', '붜', '붤', '붰', '붸', '뷔', '뷕', '뷘', '뷜', '뷩', // 7E0-7EF
+    '뷰', '뷴', '뷸', '븀', '븃', '븅', '브', '븍', '븐', '블', '븜', '븝', '븟', '비', '빅', '빈', // 7F0-7FF
+    '빌', '빎', '빔', '빕', '빗', '빙', '빚', '빛', '빠', '빡', '빤', '빨', '빪', '빰', '빱', '빳', // 800-80F
+    '빴', '빵', '빻', '빼', '빽', '뺀', '뺄', '뺌', '뺍', '뺏', '뺐', '뺑', '뺘', '뺙', '뺨', '뻐', // 810-81F
+    '뻑', '뻔', '뻗', '뻘', '뻠', '뻣', '뻤', '뻥', '뻬', '뼁', '뼈', '뼉', '뼘', '뼙', '뼛', '뼜', // 820-82F
+    '뼝', '뽀', '뽁', '뽄', '뽈', '뽐', '뽑', '뽕', '뾔', '뾰', '뿅', '뿌', '뿍', '뿐', '뿔', '뿜', // 830-83F
+    '뿟', '뿡', '쀼', '쁑', '쁘', '쁜', '쁠', '쁨', '쁩', '삐', '삑', '삔', '삘', '삠', '삡', '삣', // 840-84F
+    '삥', '사', '삭', '삯', '산', '삳', '살', '삵', '삶', '삼', '삽', '삿', '샀', '상', '샅', '새', // 850-85F
+    '색', '샌', '샐', '샘', '샙', '샛', '샜', '생', '샤', '샥', '샨', '샬', '샴', '샵', '샷', '샹', // 860-86F
+    '섀', '섄', '섈', '섐', '섕', '서', '석', '섞', '섟', '선', '섣', '설', '섦', '섧', '섬', '섭', // 870-87F
+    '섯', '섰', '성', '섶', '세', '섹', '센', '셀', '셈', '셉', '셋', '셌', '셍', '셔', '셕', '션', // 880-88F
+    '셜', '셤', '셥', '셧', '셨', '셩', '셰', '셴', '셸', '솅', '소', '속', '솎', '손', '솔', '솖', // 890-89F
+    '솜', '솝', '솟', '송', '솥', '솨', '솩', '솬', '솰', '솽', '쇄', '쇈', '쇌', '쇔', '쇗', '쇘', // 8A0-8AF
+    '쇠', '쇤', '쇨', '쇰', '쇱', '쇳', '쇼', '쇽', '숀', '숄', '숌', '숍', '숏', '숑', '수', '숙', // 8B0-8BF
+    '순', '숟', '술', '숨', '숩', '숫', '숭', '숯', '숱', '숲', '숴', '쉈', '쉐', '쉑', '쉔', '쉘', // 8C0-8CF
+    '쉠', '쉥', '쉬', '쉭', '쉰', '쉴', '쉼', '쉽', '쉿', '슁', '슈', '슉', '슐', '슘', '슛', '슝', // 8D0-8DF
+    '스', '슥', '슨', '슬', '슭', '슴', '습', '슷', '승', '시', '식', '신', '싣', '실', '싫', '심', // 8E0-8EF
+    '십', '싯', '싱', '싶', '싸', '싹', '싻', '싼', '쌀', '쌈', '쌉', '쌌', '쌍', '쌓', '쌔', '쌕', // 8F0-8FF
+    '쌘', '쌜', '쌤', '쌥', '쌨', '쌩', '썅', '써', '썩', '썬', '썰', '썲', '썸', '썹', '썼', '썽', // 900-90F
+    '쎄', '쎈', '쎌', '쏀', '쏘', '쏙', '쏜', '쏟', '쏠', '쏢', '쏨', '쏩', '쏭', '쏴', '쏵', '쏸', // 910-91F
+    '쐈', '쐐', '쐤', '쐬', '쐰', '쐴', '쐼', '쐽', '쑈', '쑤', '쑥', '쑨', '쑬', '쑴', '쑵', '쑹', // 920-92F
+    '쒀', '쒔', '쒜', '쒸', '쒼', '쓩', '쓰', '쓱', '쓴', '쓸', '쓺', '쓿', '씀', '씁', '씌', '씐', // 930-93F
+    '씔', '씜', '씨', '씩', '씬', '씰', '씸', '씹', '씻', '씽', '아', '악', '안', '앉', '않', '알', // 940-94F
+    '앍', '앎', '앓', '암', '압', '앗', '았', '앙', '앝', '앞', '애', '액', '앤', '앨', '앰', '앱', // 950-95F
+    '앳', '앴', '앵', '야', '약', '얀', '얄', '얇', '얌', '얍', '얏', '양', '얕', '얗', '얘', '얜', // 960-96F
+    '얠', '얩', '어', '억', '언', '얹', '얻', '얼', '얽', '얾', '엄', '업', '없', '엇', '었', '엉', // 970-97F
+    '엊', '엌', '엎', '에', '엑', '엔', '엘', '엠', '엡', '엣', '엥', '여', '역', '엮', '연', '열', // 980-98F
+    '엶', '엷', '염', '엽', '엾', '엿', '였', '영', '옅', '옆', '옇', '예', '옌', '옐', '옘', '옙', // 990-99F
+    '옛', '옜', '오', '옥', '온', '올', '옭', '옮', '옰', '옳', '옴', '옵', '옷', '옹', '옻', '와', // 9A0-9AF
+    '왁', '완', '왈', '왐', '왑', '왓', '왔', '왕', '왜', '왝', '왠', '왬', '왯', '왱', '외', '왹', // 9B0-9BF
+    '왼', '욀', '욈', '욉', '욋', '욍', '요', '욕', '욘', '욜', '욤', '욥', '욧', '용', '우', '욱', // 9C0-9CF
+    '운', '울', '욹', '욺', '움', '웁', '웃', '웅', '워', '웍', '원', '월', '웜', '웝', '웠', '웡', // 9D0-9DF
+    '웨', '웩', '웬', '웰', '웸', '웹', '웽', '위', '윅', '윈', '윌', '윔', '윕', '윗', '윙', '유', // 9E0-9EF
+    '육', '윤', '율', '윰', '윱', '윳', '융', '윷', '으', '윽', '은', '을', '읊', '음', '읍', '읏', // 9F0-9FF
+    '응', '읒', '읓', '읔', '읕', '읖', '읗', '의', '읜', '읠', '읨', '읫', '이', '익', '인', '일', // A00-A0F
+    '읽', '읾', '잃', '임', '입', '잇', '있', '잉', '잊', '잎', '자', '작', '잔', '잖', '잗', '잘', // A10-A1F
+    '잚', '잠', '잡', '잣', '잤', '장', '잦', '재', '잭', '잰', '잴', '잼', '잽', '잿', '쟀', '쟁', // A20-A2F
+    '쟈', '쟉', '쟌', '쟎', '쟐', '쟘', '쟝', '쟤', '쟨', '쟬', '저', '적', '전', '절', '젊', '점', // A30-A3F
+    '접', '젓', '정', '젖', '제', '젝', '젠', '젤', '젬', '젭', '젯', '젱', '져', '젼', '졀', '졈', // A40-A4F
+    '졉', '졌', '졍', '졔', '조', '족', '존', '졸', '졺', '좀', '좁', '좃', '종', '좆', '좇', '좋', // A50-A5F
+    '좌', '좍', '좔', '좝', '좟', '좡', '좨', '좼', '좽', '죄', '죈', '죌', '죔', '죕', '죗', '죙', // A60-A6F
+    '죠', '죡', '죤', '죵', '주', '죽', '준', '줄', '줅', '줆', '줌', '줍', '줏', '중', '줘', '줬', // A70-A7F
+    '줴', '쥐', '쥑', '쥔', '쥘', '쥠', '쥡', '쥣', '쥬', '쥰', '쥴', '쥼', '즈', '즉', '즌', '즐', // A80-A8F
+    '즘', '즙', '즛', '증', '지', '직', '진', '짇', '질', '짊', '짐', '집', '짓', '징', '짖', '짙', // A90-A9F
+    '짚', '짜', '짝', '짠', '짢', '짤', '짧', '짬', '짭', '짯', '짰', '짱', '째', '짹', '짼', '쨀', // AA0-AAF
+    '쨈', '쨉', '쨋', '쨌', '쨍', '쨔', '쨘', '쨩', '쩌', '쩍', '쩐', '쩔', '쩜', '쩝', '쩟', '쩠', // AB0-ABF
+    '쩡', '쩨', '쩽', '쪄', '쪘', '쪼', '쪽', '쫀', '쫄', '쫌', '쫍', '쫏', '쫑', '쫓', '쫘', '쫙', // AC0-ACF
+    '쫠', '쫬', '쫴', '쬈', '쬐', '쬔', '쬘', '쬠', '쬡', '쭁', '쭈', '쭉', '쭌', '쭐', '쭘', '쭙', // AD0-ADF
+    '쭝', '쭤', '쭸', '쭹', '쮜', '쮸', '쯔', '쯤', '쯧', '쯩', '찌', '찍', '찐', '찔', '찜', '찝', // AE0-AEF
+    '찡', '찢', '찧', '차', '착', '찬', '찮', '찰', '참', '찹', '찻', '찼', '창', '찾', '채', '책', // AF0-AFF
+    '챈', '챌', '챔', '챕', '챗', '챘', '챙', '챠', '챤', '챦', '챨', '챰', '챵', '처', '척', '천', // B00-B0F
+    '철', '첨', '첩', '첫', '첬', '청', '체', '첵', '첸', '첼', '쳄', '쳅', '쳇', '쳉', '쳐', '쳔', // B10-B1F
+    '쳤', '쳬', '쳰', '촁', '초', '촉', '촌', '촐', '촘', '촙', '촛', '총', '촤', '촨', '촬', '촹', // B20-B2F
+    '최', '쵠', '쵤', '쵬', '쵭', '쵯', '쵱', '쵸', '춈', '추', '축', '춘', '출', '춤', '춥', '춧', // B30-B3F
+    '충', '춰', '췄', '췌', '췐', '취', '췬', '췰', '췸', '췹', '췻', '췽', '츄', '츈', '츌', '츔', // B40-B4F
+    '츙', '츠', '측', '츤', '츨', '츰', '츱', '츳', '층', '치', '칙', '친', '칟', '칠', '칡', '침', // B50-B5F
+    '칩', '칫', '칭', '카', '칵', '칸', '칼', '캄', '캅', '캇', '캉', '캐', '캑', '캔', '캘', '캠', // B60-B6F
+    '캡', '캣', '캤', '캥', '캬', '캭', '컁', '커', '컥', '컨', '컫', '컬', '컴', '컵', '컷', '컸', // B70-B7F
+    '컹', '케', '켁', '켄', '켈', '켐', '켑', '켓', '켕', '켜', '켠', '켤', '켬', '켭', '켯', '켰', // B80-B8F
+    '켱', '켸', '코', '콕', '콘', '콜', '콤', '콥', '콧', '콩', '콰', '콱', '콴', '콸', '쾀', '쾅', // B90-B9F
+    '쾌', '쾡', '쾨', '쾰', '쿄', '쿠', '쿡', '쿤', '쿨', '쿰', '쿱', '쿳', '쿵', '쿼', '퀀', '퀄', // BA0-BAF
+    '퀑', '퀘', '퀭', '퀴', '퀵', '퀸', '퀼', '큄', '큅', '큇', '큉', '큐', '큔', '큘', '큠', '크', // BB0-BBF
+    '큭', '큰', '클', '큼', '큽', '킁', '키', '킥', '킨', '킬', '킴', '킵', '킷', '킹', '타', '탁', // BC0-BCF
+    '탄', '탈', '탉', '탐', '탑', '탓', '탔', '탕', '태', '택', '탠', '탤', '탬', '탭', '탯', '탰', // BD0-BDF
+    '탱', '탸', '턍', '터', '턱', '턴', '털', '턺', '텀', '텁', '텃', '텄', '텅', '테', '텍', '텐', // BE0-BEF
+    '텔', '템', '텝', '텟', '텡', '텨', '텬', '텼', '톄', '톈', '토', '톡', '톤', '톨', '톰', '톱', // BF0-BFF
+    '톳', '통', '톺', '톼', '퇀', '퇘', '퇴', '퇸', '툇', '툉', '툐', '투', '툭', '툰', '툴', '툼', // C00-C0F
+    '툽', '툿', '퉁', '퉈', '퉜', '퉤', '튀', '튁', '튄', '튈', '튐', '튑', '튕', '튜', '튠', '튤', // C10-C1F
+    '튬', '튱', '트', '특', '튼', '튿', '틀', '틂', '틈', '틉', '틋', '틔', '틘', '틜', '틤', '틥', // C20-C2F
+    '티', '틱', '틴', '틸', '팀', '팁', '팃', '팅', '파', '팍', '팎', '판', '팔', '팖', '팜', '팝', // C30-C3F
+    '팟', '팠', '팡', '팥', '패', '팩', '팬', '팰', '팸', '팹', '팻', '팼', '팽', '퍄', '퍅', '퍼', // C40-C4F
+    '퍽', '펀', '펄', '펌', '펍', '펏', '펐', '펑', '페', '펙', '펜', '펠', '펨', '펩', '펫', '펭', // C50-C5F
+    '펴', '편', '펼', '폄', '폅', '폈', '평', '폐', '폘', '폡', '폣', '포', '폭', '폰', '폴', '폼', // C60-C6F
+    '폽', '폿', '퐁', '퐈', '퐝', '푀', '푄', '표', '푠', '푤', '푭', '푯', '푸', '푹', '푼', '푿', // C70-C7F
+    '풀', '풂', '품', '풉', '풋', '풍', '풔', '풩', '퓌', '퓐', '퓔', '퓜', '퓟', '퓨', '퓬', '퓰', // C80-C8F
+    '퓸', '퓻', '퓽', '프', '픈', '플', '픔', '픕', '픗', '피', '픽', '핀', '필', '핌', '핍', '핏', // C90-C9F
+    '핑', '하', '학', '한', '할', '핥', '함', '합', '핫', '항', '해', '핵', '핸', '핼', '햄', '햅', // CA0-CAF
+    '햇', '했', '행', '햐', '향', '허', '헉', '헌', '헐', '헒', '험', '헙', '헛', '헝', '헤', '헥', // CB0-CBF
+    '헨', '헬', '헴', '헵', '헷', '헹', '혀', '혁', '현', '혈', '혐', '협', '혓', '혔', '형', '혜', // CC0-CCF
+    '혠', '혤', '혭', '호', '혹', '혼', '홀', '홅', '홈', '홉', '홋', '홍', '홑', '화', '확', '환', // CD0-CDF
+    '활', '홧', '황', '홰', '홱', '홴', '횃', '횅', '회', '획', '횐', '횔', '횝', '횟', '횡', '효', // CE0-CEF
+    '횬', '횰', '횹', '횻', '후', '훅', '훈', '훌', '훑', '훔', '훗', '훙', '훠', '훤', '훨', '훰', // CF0-CFF
+    '훵', '훼', '훽', '휀', '휄', '휑', '휘', '휙', '휜', '휠', '휨', '휩', '휫', '휭', '휴', '휵', // D00-D0F
+    '휸', '휼', '흄', '흇', '흉', '흐', '흑', '흔', '흖', '흗', '흘', '흙', '흠', '흡', '흣', '흥', // D10-D1F
+    '흩', '희', '흰', '흴', '흼', '흽', '힁', '히', '힉', '힌', '힐', '힘', '힙', '힛', '힝', NUL, // D20-D2F
+
+    // 33 Jamo characters
+    NUL, 'ㄱ', 'ㄲ', 'ㄴ', 'ㄷ', 'ㄸ', 'ㄹ', 'ㅁ', 'ㅂ', 'ㅃ', 'ㅅ', 'ㅆ', 'ㅇ', 'ㅈ', 'ㅉ', 'ㅊ', // D30-D3F
+    'ㅋ', 'ㅌ', 'ㅍ', 'ㅎ', 'ㅏ', 'ㅐ', 'ㅑ', 'ㅒ', 'ㅓ', 'ㅔ', 'ㅕ', 'ㅖ', 'ㅗ', 'ㅛ', 'ㅜ', 'ㅠ', // D40-D4F
+    'ㅡ', 'ㅣ', NUL, NUL, NUL, NUL, NUL, NUL, NUL, NUL, NUL, NUL, NUL, NUL, NUL, NUL, // D50-D5F
+
+    // 5 additional syllables
+    NUL, '뢔', '쌰', '쎼', '쓔', '쬬', // D60-D65
+];
+
+pub(crate) struct Gen4StringBuffer<const N: usize>([u16; N]);
+impl<const N: usize> From<Gen4StringBuffer<N>> for String {
+    fn from(value: Gen4StringBuffer<N>) -> Self {
+        decode(&value.0)
+    }
+}
+
+/// Breaks the line
+const NEWLINE: u16 = 0xE000;
+/// Waits for a button press, then scrolls up a line
+const SCROLL: u16 = 0x25BC;
+/// Waits for a button press, then clears the text box
+const CLEAR: u16 = 0x25BD;
+/// Starts a control sequence: the command, how many arguments it has, then the arguments
+const CONTROL: u16 = 0xFFFE;
+/// The control command which changes the colour of the text after it
+const COLOR: u16 = 0xFF00;
+
+/// A piece of gen 4 text: a run of characters, or one of the codes the games use to lay out messages and
+/// fill in names. Mail, Easy Chat sentences, signs & the journal all use these; names don't.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Token {
+    Text(String),
+    /// `0xE000`
+    Newline,
+    /// `0x25BC`: waits for a button press, then scrolls up a line
+    Scroll,
+    /// `0x25BD`: waits for a button press, then clears the text box
+    Clear,
+    /// The colour of the text after this
+    Color(u16),
+    /// A placeholder the game fills in with a string, like a trainer's or Pokémon's name. `command` is
+    /// between `0x0100` & `0x01FF`, and decides what's filled in.
+    Variable { command: u16, args: Vec<u16> },
+    /// Any other control sequence
+    Control { command: u16, args: Vec<u16> },
+    /// A code with no character, kept as it is
+    Unknown(u16),
+}
+
+impl Token {
+    fn from_control(command: u16, args: Vec<u16>) -> Self {
+        match (command, args.as_slice()) {
+            (COLOR, [color]) => Token::Color(*color),
+            (0x0100..=0x01FF, _) => Token::Variable { command, args },
+            _ => Token::Control { command, args },
+        }
+    }
+}
+
+/// Decode a gen 4 string, up to its terminator, with control codes in their escaped form; see `escape`
+pub fn decode(codes: &[u16]) -> String {
+    escape(&decode_tokens(codes))
+}
+
+/// Decode a gen 4 string, up to its terminator. A control sequence that runs past the end is read as
+/// `Token::Unknown` codes.
+pub fn decode_tokens(codes: &[u16]) -> Vec<Token> {
+    let end = codes.iter().position(|v| *v == TERMINATOR).unwrap_or(codes.len());
+    let codes = &codes[..end];
+
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while i < codes.len() {
+        let token = match codes[i] {
+            NEWLINE => Token::Newline,
+            SCROLL => Token::Scroll,
+            CLEAR => Token::Clear,
+            CONTROL => match codes.get(i + 1..i + 3) {
+                Some(&[command, count]) if i + 3 + count as usize <= codes.len() => {
+                    let args = codes[i + 3..i + 3 + count as usize].to_vec();
+                    i += 2 + count as usize;
+                    Token::from_control(command, args)
+                }
+                _ => Token::Unknown(CONTROL),
+            },
+            code => match u16_to_char(code) {
+                NUL => Token::Unknown(code),
+                c => {
+                    match tokens.last_mut() {
+                        Some(Token::Text(text)) => text.push(c),
+                        _ => tokens.push(Token::Text(c.to_string())),
+                    }
+                    i += 1;
+                    continue;
+                }
+            },
+        };
+        tokens.push(token);
+        i += 1;
+    }
+
+    tokens
+}
+
+/// Encode `tokens` as character codes, followed by a terminator. `max_length` is the number of codes the
+/// field fits, not counting the terminator.
+pub fn encode_tokens(tokens: &[Token], max_length: usize) -> Result<Vec<u16>, EncodeError> {
+    fn control(codes: &mut Vec<u16>, command: u16, args: &[u16]) {
+        codes.extend([CONTROL, command, args.len() as u16]);
+        codes.extend_from_slice(args);
+    }
+
+    let mut codes = Vec::new();
+    for token in tokens {
+        match token {
+            Token::Text(text) => {
+                for c in text.chars() {
+                    codes.push(char_to_u16(c)?);
+                }
+            }
+            Token::Newline => codes.push(NEWLINE),
+            Token::Scroll => codes.push(SCROLL),
+            Token::Clear => codes.push(CLEAR),
+            Token::Color(color) => control(&mut codes, COLOR, &[*color]),
+            Token::Variable { command, args } | Token::Control { command, args } => control(&mut codes, *command, args),
+            Token::Unknown(code) => codes.push(*code),
+        }
+    }
+    if codes.len() > max_length {
+        return Err(EncodeError::TooLong { length: codes.len(), max: max_length });
+    }
+    codes.push(TERMINATOR);
+
+    Ok(codes)
+}
+
+/// Write `tokens` as text, with each control code escaped so nothing is lost:
+///
+/// | Token       | Escaped                     |
+/// |-------------|-----------------------------|
+/// | `Newline`   | `\n`                        |
+/// | `Scroll`    | `\r`                        |
+/// | `Clear`     | `\f`                        |
+/// | `Color`     | `{COLOR 2}`                 |
+/// | `Variable`  | `{VAR 0x0101 0}`            |
+/// | `Control`   | `{CTRL 0xFF01 1 2}`         |
+/// | `Unknown`   | `{0x0000}`                  |
+///
+/// `\`, `{` & `}` in text are escaped with a `\`, though the games can't display them. Names never have
+/// control codes, so are written as they are.
+///
+/// ```
+/// use pokerus::save::format::dppt::string::{self, Token};
+///
+/// let tokens = vec![Token::Text("Hi".into()), Token::Newline, Token::Variable { command: 0x0100, args: vec![0] }];
+/// assert_eq!(string::escape(&tokens), "Hi\\n{VAR 0x0100 0}");
+/// assert_eq!(string::unescape("Hi\\n{VAR 0x0100 0}"), Ok(tokens));
+/// ```
+pub fn escape(tokens: &[Token]) -> String {
+    fn command(name: &str, command: u16, args: &[u16]) -> String {
+        let args: String = args.iter().map(|arg| format!(" {}", arg)).collect();
+        format!("{{{} {:#06X}{}}}", name, command, args)
+    }
+
+    tokens.iter().map(|token| match token {
+        Token::Text(text) => text.replace('\\', "\\\\").replace('{', "\\{").replace('}', "\\}"),
+        Token::Newline => String::from("\\n"),
+        Token::Scroll => String::from("\\r"),
+        Token::Clear => String::from("\\f"),
+        Token::Color(color) => format!("{{COLOR {}}}", color),
+        Token::Variable { command: id, args } => command("VAR", *id, args),
+        Token::Control { command: id, args } => command("CTRL", *id, args),
+        Token::Unknown(code) => format!("{{{:#06X}}}", code),
+    }).collect()
+}
+
+/// Read the escaped form written by `escape`. Numbers can be decimal, or hexadecimal starting `0x`.
+pub fn unescape(value: &str) -> Result<Vec<Token>, EncodeError> {
+    fn number(word: &str) -> Option<u16> {
+        match word.strip_prefix("0x") {
+            Some(hex) => u16::from_str_radix(hex, 16).ok(),
+            None => word.parse().ok(),
+        }
+    }
+
+    fn braced(contents: &str) -> Option<Token> {
+        let mut words = contents.split_whitespace();
+        let name = words.next()?;
+        let numbers = words.map(number).collect::<Option<Vec<u16>>>()?;
+        match (name, numbers.as_slice()) {
+            ("COLOR", [color]) => Some(Token::Color(*color)),
+            ("VAR", [command, args @ ..]) => Some(Token::Variable { command: *command, args: args.to_vec() }),
+            ("CTRL", [command, args @ ..]) => Some(Token::Control { command: *command, args: args.to_vec() }),
+            (code, []) => number(code).map(Token::Unknown),
+            _ => None,
+        }
+    }
+
+    let mut tokens = Vec::new();
+    let mut text = String::new();
+    let mut chars = value.char_indices();
+    while let Some((position, c)) = chars.next() {
+        let invalid = EncodeError::InvalidEscape { position };
+        let token = match c {
+            '\\' => match chars.next().ok_or(invalid)?.1 {
+                'n' => Token::Newline,
+                'r' => Token::Scroll,
+                'f' => Token::Clear,
+                escaped @ ('\\' | '{' | '}') => {
+                    text.push(escaped);
+                    continue;
+                }
+                _ => return Err(invalid),
+            },
+            '{' => {
+                let contents = &value[position + 1..];
+                let end = contents.find('}').ok_or(invalid)?;
+                chars.nth(contents[..end].chars().count());
+                braced(&contents[..end]).ok_or(invalid)?
+            }
+            '}' => return Err(invalid),
+            _ => {
+                text.push(c);
+                continue;
+            }
+        };
+        if !text.is_empty() {
+            tokens.push(Token::Text(std::mem::take(&mut text)));
+        }
+        tokens.push(token);
+    }
+    if !text.is_empty() {
+        tokens.push(Token::Text(text));
+    }
+
+    Ok(tokens)
+}
+
+fn u16_to_char(value: u16) -> char {
+    match value {
+        INTERNATIONAL_TABLE_START..=INTERNATIONAL_TABLE_END => INTERNATIONAL_TABLE[value as usize],
+        KOREAN_TABLE_START..=KOREAN_TABLE_END => KOREAN_TABLE[(value - KOREAN_TABLE_START) as usize],
+        _ => NUL
+    }
+}
+
+/// A gen 4 string as it's stored: its text, and the whole field it was read from. The games don't clear a
+/// field before writing a string to it, so what's after the terminator ("trash") is left over from
+/// whatever was there before, which can tell where a Pokémon came from.
+///
+/// Two strings are equal if their text is; compare `raw` for what's stored.
+///
+/// ```
+/// use pokerus::save::format::dppt::string::Gen4String;
+///
+/// let mut name = Gen4String::from_raw(vec![0x12F, 0x150, 0xFFFF, 0x150, 0x149, 0xFFFF, 0, 0]);
+/// assert_eq!((name.text(), name.trash()), ("El", &[0x150, 0x149, 0xFFFF, 0, 0][..]));
+///
+/// name.set_text("Ell");
+/// assert_eq!(name.encode(8), Ok(vec![0x12F, 0x150, 0x150, 0xFFFF, 0x149, 0xFFFF, 0, 0]));
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Gen4String {
+    text: String,
+    raw: Vec<u16>,
+}
+
+impl Gen4String {
+    /// A string that wasn't read from a save, so has no field of its own yet
+    pub fn new(text: impl Into<String>) -> Self {
+        Self { text: text.into(), raw: Vec::new() }
+    }
+
+    /// Decode a whole field of character codes, keeping them
+    pub fn from_raw(raw: Vec<u16>) -> Self {
+        Self { text: decode(&raw), raw }
+    }
+
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Change the text. `raw` isn't changed until the string is encoded.
+    pub fn set_text(&mut self, text: impl Into<String>) {
+        self.text = text.into();
+    }
+
+    /// The field the string was read from; empty if it wasn't
+    pub fn raw(&self) -> &[u16] {
+        &self.raw
+    }
+
+    /// What's in `raw` after the terminator
+    pub fn trash(&self) -> &[u16] {
+        match self.raw.iter().position(|v| *v == TERMINATOR) {
+            Some(terminator) => &self.raw[terminator + 1..],
+            None => &[],
+        }
+    }
+
+    /// Encode as a field of `length` code units. The text & its terminator are written over `raw`, so
+    /// the trash after them is kept; if the text hasn't changed, that's just `raw`. Anything `raw`
+    /// doesn't cover is zeroed.
+    pub fn encode(&self, length: usize) -> Result<Vec<u16>, EncodeError> {
+        let mut field = self.raw.clone();
+        field.resize(length, 0);
+        if decode(&field) == self.text {
+            return Ok(field);
+        }
+
+        let codes = encode(&self.text, length.saturating_sub(1))?;
+        field[..codes.len()].copy_from_slice(&codes);
+        Ok(field)
+    }
+}
+
+impl PartialEq for Gen4String {
+    fn eq(&self, other: &Self) -> bool {
+        self.text == other.text
+    }
+}
+
+impl Eq for Gen4String {}
+
+impl PartialEq<&str> for Gen4String {
+    fn eq(&self, other: &&str) -> bool {
+        self.text == *other
+    }
+}
+
+impl From<&str> for Gen4String {
+    fn from(value: &str) -> Self {
+        Gen4String::new(value)
+    }
+}
+
+impl From<String> for Gen4String {
+    fn from(value: String) -> Self {
+        Gen4String::new(value)
+    }
+}
+
+impl fmt::Display for Gen4String {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.text)
+    }
+}
+
+/// The longest trainer name, in characters
+pub const TRAINER_NAME_LENGTH: usize = 7;
+/// The longest nickname, in characters
+pub const NICKNAME_LENGTH: usize = 10;
+/// The longest box name, in characters
+pub const BOX_NAME_LENGTH: usize = 19;
+
+/// Encode `value`, in the escaped form written by `decode`, as character codes followed by a terminator.
+/// `max_length` is the number of codes the field fits, not counting the terminator; for names that's
+/// the number of characters, see `TRAINER_NAME_LENGTH` etc.
+///
+/// ```
+/// use pokerus::save::error::EncodeError;
+/// use pokerus::save::format::dppt::string::{self, HALF_WIDTH_GENDER_FEMALE, TRAINER_NAME_LENGTH};
+///
+/// assert_eq!(string::encode("Ellie", TRAINER_NAME_LENGTH), Ok(vec![0x12F, 0x150, 0x150, 0x14D, 0x149, 0xFFFF]));
+/// assert_eq!(string::encode(&format!("Nido{}", HALF_WIDTH_GENDER_FEMALE), 10), Ok(vec![0x138, 0x14D, 0x148, 0x153, 0x1BC, 0xFFFF]));
+/// assert_eq!(string::encode("Elizabeth", TRAINER_NAME_LENGTH), Err(EncodeError::TooLong { length: 9, max: 7 }));
+/// assert_eq!(string::encode("🦀", TRAINER_NAME_LENGTH), Err(EncodeError::UnsupportedChar('🦀')));
+/// ```
+pub fn encode(value: &str, max_length: usize) -> Result<Vec<u16>, EncodeError> {
+    encode_tokens(&unescape(value)?, max_length)
+}
+
+/// Every character the games can display, and its code. Built from the decoding tables the first time
+/// it's needed; where a character appears twice the lower code is used.
+fn char_codes() -> &'static HashMap<char, u16> {
+    static CHAR_CODES: OnceLock<HashMap<char, u16>> = OnceLock::new();
+    CHAR_CODES.get_or_init(|| {
+        let international = INTERNATIONAL_TABLE.iter().zip(INTERNATIONAL_TABLE_START..);
+        let korean = KOREAN_TABLE.iter().zip(KOREAN_TABLE_START..);
+        let mut codes = HashMap::new();
+        for (c, code) in international.chain(korean).filter(|(c, _)| **c != NUL) {
+            codes.entry(*c).or_insert(code);
+        }
+        codes
+    })
+}
+
+fn char_to_u16(value: char) -> Result<u16, EncodeError> {
+    char_codes().get(&value).copied().ok_or(EncodeError::UnsupportedChar(value))
+}
+
+#[cfg(feature = "write")]
+impl<const N: usize> TryFrom<&str> for Gen4StringBuffer<N> {
+    type Error = WriteError;
+
+    /// Encode `value`, followed by a terminator. Any remaining space is zeroed.
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let codes = encode(value, N - 1)?;
+        let mut buf = [0u16; N];
+        buf[..codes.len()].copy_from_slice(&codes);
+
+        Ok(Gen4StringBuffer(buf))
+    }
+}
+
+/// Decode a whole field of little-endian character codes, keeping them
+pub(crate) fn read_string(data: &[u8]) -> Gen4String {
+    Gen4String::from_raw(data.chunks_exact(2).map(|c| u16::from_le_bytes([c[0], c[1]])).collect())
+}
+
+/// Write `value` as the `length` code unit string at the start of `data`. If `value` wasn't read from a
+/// save, its text is written over the string already there, so that string's trash is kept instead.
+#[cfg(feature = "write")]
+pub(crate) fn write_string(data: &mut [u8], value: &Gen4String, length: usize) -> Result<(), WriteError> {
+    let field = &mut data[..length * 2];
+    let codes = match value.raw.is_empty() {
+        true => {
+            let mut existing = read_string(field);
+            existing.set_text(value.text());
+            existing.encode(length)?
+        }
+        false => value.encode(length)?,
+    };
+    for (i, v) in codes.iter().enumerate() {
+        field[i * 2..i * 2 + 2].copy_from_slice(&v.to_le_bytes());
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Can we correctly parse an international name?
+    #[test]
+    fn convert_to_int_string() {
+        let from: [u16; 5] = [0x12F, 0x136, 0x136, 0x14D, 0x149];
+        assert_eq!(String::from(Gen4StringBuffer(from)), "ELLie");
+    }
+
+    /// Can we correctly parse a Korean name?
+    #[test]
+    fn convert_to_kr_string() {
+        let from: [u16; 3] = [0x413, 0x7DE, 0x942];
+        assert_eq!(String::from(Gen4StringBuffer(from)), "개불씨");
+    }
+
+    /// Do we correctly parse a string with a terminator?
+    #[test]
+    fn string_respect_terminator() {
+        let from: [u16; 5] = [0x14C, 0x14D, 0x1AB, 0xFFFF, 0x134];
+        assert_eq!(String::from(Gen4StringBuffer(from)), "hi!");
+    }
+
+    /// Can we encode a string, and does it survive a round trip?
+    #[cfg(feature = "write")]
+    #[test]
+    fn encode_string() {
+        let encoded = Gen4StringBuffer::<8>::try_from("ELLie").unwrap();
+        assert_eq!(encoded.0, [0x12F, 0x136, 0x136, 0x14D, 0x149, 0xFFFF, 0, 0]);
+        assert_eq!(String::from(encoded), "ELLie");
+
+        let encoded = Gen4StringBuffer::<4>::try_from("개불씨").unwrap();
+        assert_eq!(String::from(encoded), "개불씨");
+    }
+
+    /// Do we reject strings which are too long, or can't be displayed?
+    #[cfg(feature = "write")]
+    #[test]
+    fn encode_string_invalid() {
+        assert!(matches!(Gen4StringBuffer::<4>::try_from("ELLie"), Err(WriteError::InvalidString(EncodeError::TooLong { length: 5, max: 3 }))));
+        assert!(matches!(Gen4StringBuffer::<8>::try_from("🦀"), Err(WriteError::InvalidString(EncodeError::UnsupportedChar('🦀')))));
+    }
+
+    /// Is every character in the tables encoded to the code it's decoded from, bar duplicates?
+    #[test]
+    fn encode_every_char() {
+        for code in (INTERNATIONAL_TABLE_START..=INTERNATIONAL_TABLE_END).chain(KOREAN_TABLE_START..=KOREAN_TABLE_END) {
+            let c = u16_to_char(code);
+            if c != NUL {
+                assert_eq!(decode(&encode(&c.to_string(), 1).unwrap()), c.to_string());
+            }
+        }
+        assert_eq!(char_to_u16(HALF_WIDTH_GENDER_MALE), Ok(0x1BB));
+        assert_eq!(char_to_u16('♂'), Ok(0x0EE));
+        assert_eq!(char_to_u16(NUL), Err(EncodeError::UnsupportedChar(NUL)));
+    }
+
+    /// Are control codes decoded into tokens, and encoded back to the same codes?
+    #[test]
+    fn control_codes() {
+        // "Hi" in colour 2, a newline, the player's name, then a scroll & an unknown code
+        let codes = [0xFFFE, 0xFF00, 1, 2, 0x132, 0x14D, 0xE000, 0xFFFE, 0x0100, 2, 0, 1, 0x25BC, 0x0000, 0xFFFF, 0x12C];
+        let tokens = vec![
+            Token::Color(2),
+            Token::Text("Hi".into()),
+            Token::Newline,
+            Token::Variable { command: 0x0100, args: vec![0, 1] },
+            Token::Scroll,
+            Token::Unknown(0x0000),
+        ];
+        assert_eq!(decode_tokens(&codes), tokens);
+        assert_eq!(encode_tokens(&tokens, 14).unwrap(), codes[..15]);
+        assert!(matches!(encode_tokens(&tokens, 13), Err(EncodeError::TooLong { length: 14, max: 13 })));
+
+        let escaped = "{COLOR 2}Hi\\n{VAR 0x0100 0 1}\\r{0x0000}";
+        assert_eq!(decode(&codes), escaped);
+        assert_eq!(encode(escaped, 14).unwrap(), codes[..15]);
+
+        // a control sequence cut short by the terminator
+        assert_eq!(decode_tokens(&[0xFFFE, 0xFF01, 2, 5, 0xFFFF]), [Token::Unknown(0xFFFE), Token::Unknown(0xFF01), Token::Text("ぁい".into())]);
+    }
+
+    /// Does the escaped form survive a round trip, and are bad escapes rejected?
+    #[test]
+    fn escape_round_trip() {
+        let tokens = vec![Token::Text("a\\{b}".into()), Token::Clear, Token::Control { command: 0xFF01, args: vec![] }];
+        assert_eq!(escape(&tokens), "a\\\\\\{b\\}\\f{CTRL 0xFF01}");
+        assert_eq!(unescape(&escape(&tokens)), Ok(tokens));
+        assert_eq!(unescape("{COLOR 0x10}{VAR 256}"), Ok(vec![Token::Color(16), Token::Variable { command: 0x100, args: vec![] }]));
+
+        for (invalid, position) in [("ab\\", 2), ("\\x", 0), ("{COLOR}", 0), ("a{COLOR 1", 1), ("a}", 1), ("{NAME 1}", 0), ("{0x10000}", 0)] {
+            assert_eq!(unescape(invalid), Err(EncodeError::InvalidEscape { position }), "{}", invalid);
+        }
+    }
+}
@@ -0,0 +1,548 @@
+pub mod pokemon;
+mod string;
+
+use crate::save::data::badge::{Badge, Badges};
+use crate::save::error::{Block, Diagnostic, ReadError};
+use crate::save::format::container::Container;
+use crate::save::format::gen12::pokemon::{Gen12Pokemon, GEN1_BOX_POKEMON_SIZE, GEN1_PARTY_POKEMON_SIZE, GEN2_BOX_POKEMON_SIZE, GEN2_PARTY_POKEMON_SIZE};
+use crate::save::format::reader::{BlockReader, Diagnostics};
+use crate::save::format::{self, BlockStatus, ParseOptions};
+use crate::save::save::{Gender, Playtime, Pokemon, SaveFile, SaveGame, Trainer};
+use byteorder::{BigEndian, ByteOrder, LittleEndian};
+use std::io::{Read, Seek, SeekFrom};
+use std::path::PathBuf;
+
+/// Every gen 1 & 2 `.sav` is the cartridge's 32 KiB of SRAM, in four banks of `0x2000` bytes
+pub const SAVE_SIZE: usize = 0x8000;
+/// Emulators can add up to this many bytes of real-time clock state after a gen 2 save
+pub const MAX_RTC_SIZE: usize = 0x30;
+
+const BANK_SIZE: usize = 0x2000;
+/// The length of a name, including its terminator
+const NAME_LENGTH: usize = 11;
+/// How many Pokémon fit in a box
+const BOX_CAPACITY: usize = 20;
+/// In the species list of a gen 2 party or box, in place of an egg's species
+const EGG_SPECIES: u8 = 0xFD;
+
+const GEN1_PLAYER_NAME: usize = 0x2598;
+const GEN1_MONEY: usize = 0x25F3;
+const GEN1_BADGES: usize = 0x2602;
+const GEN1_PLAYER_ID: usize = 0x2605;
+const GEN1_CURRENT_BOX: usize = 0x284C;
+const GEN1_PLAYTIME: usize = 0x2CED;
+const GEN1_PARTY: usize = 0x2F2C;
+const GEN1_CURRENT_BOX_DATA: usize = 0x30C0;
+const GEN1_CHECKSUM: usize = 0x3523;
+const GEN1_BOX_COUNT: usize = 12;
+const GEN1_BOX_SIZE: usize = 0x462;
+
+const GEN2_PLAYER_ID: usize = 0x2009;
+const GEN2_PLAYER_NAME: usize = 0x200B;
+const GEN2_BOX_COUNT: usize = 14;
+const GEN2_BOX_SIZE: usize = 0x450;
+const CRYSTAL_PLAYER_GENDER: usize = 0x3E3D;
+
+/// Which games a gen 1 or 2 save can be from. Red, Blue & Yellow share a layout, as do Gold & Silver,
+/// and neither stores which of them it is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Game {
+    RedBlueYellow,
+    GoldSilver,
+    Crystal,
+}
+
+impl Game {
+    /// Tell the games apart by which of their checksums is valid, trying Crystal, then Gold & Silver,
+    /// then gen 1. A blank player name is taken to mean there's no save at all.
+    pub(crate) fn detect(data: &[u8]) -> Option<Game> {
+        [Game::Crystal, Game::GoldSilver, Game::RedBlueYellow].into_iter().find(|game| {
+            let name = data.get(game.player_name()).copied().unwrap_or(0);
+            name != 0 && name != string::TERMINATOR && checksum_status(data, *game) == BlockStatus::Valid
+        })
+    }
+
+    fn player_name(&self) -> usize {
+        match self {
+            Game::RedBlueYellow => GEN1_PLAYER_NAME,
+            _ => GEN2_PLAYER_NAME,
+        }
+    }
+
+    /// The range of bank 1 that's checksummed, and where the checksum is
+    fn checksum_range(&self) -> (std::ops::RangeInclusive<usize>, usize) {
+        match self {
+            Game::RedBlueYellow => (GEN1_PLAYER_NAME..=GEN1_CHECKSUM - 1, GEN1_CHECKSUM),
+            Game::GoldSilver => (GEN2_PLAYER_ID..=0x2D68, 0x2D69),
+            Game::Crystal => (GEN2_PLAYER_ID..=0x2B82, 0x2D0D),
+        }
+    }
+
+    /// The size of a party & box Pokémon
+    fn pokemon_sizes(&self) -> (usize, usize) {
+        match self {
+            Game::RedBlueYellow => (GEN1_PARTY_POKEMON_SIZE, GEN1_BOX_POKEMON_SIZE),
+            _ => (GEN2_PARTY_POKEMON_SIZE, GEN2_BOX_POKEMON_SIZE),
+        }
+    }
+
+    fn box_count(&self) -> usize {
+        match self {
+            Game::RedBlueYellow => GEN1_BOX_COUNT,
+            _ => GEN2_BOX_COUNT,
+        }
+    }
+
+    /// Where box `index` is kept in banks 2 & 3, which hold half the boxes each
+    fn box_offset(&self, index: usize) -> usize {
+        let (size, per_bank) = match self {
+            Game::RedBlueYellow => (GEN1_BOX_SIZE, GEN1_BOX_COUNT / 2),
+            _ => (GEN2_BOX_SIZE, GEN2_BOX_COUNT / 2),
+        };
+        2 * BANK_SIZE + (index / per_bank) * BANK_SIZE + (index % per_bank) * size
+    }
+
+    /// The offsets of the fields that move between gen 2 games
+    fn gen2_offsets(&self) -> Gen2Offsets {
+        match self {
+            Game::Crystal => Gen2Offsets { playtime: 0x2052, money: 0x23DC, badges: 0x23E5, current_box: 0x2700, box_names: 0x2703, party: 0x2865, current_box_data: 0x2D10 },
+            _ => Gen2Offsets { playtime: 0x2053, money: 0x23DB, badges: 0x23E4, current_box: 0x2724, box_names: 0x2727, party: 0x288A, current_box_data: 0x2D6C },
+        }
+    }
+}
+
+struct Gen2Offsets {
+    playtime: usize,
+    money: usize,
+    /// The Johto badges, followed by the Kanto badges
+    badges: usize,
+    current_box: usize,
+    box_names: usize,
+    party: usize,
+    current_box_data: usize,
+}
+
+/// Validate the checksum of `game`'s main save data
+fn checksum_status(data: &[u8], game: Game) -> BlockStatus {
+    let (range, position) = game.checksum_range();
+    let (Some(bytes), Some(stored)) = (data.get(range), data.get(position..position + 2)) else {
+        return BlockStatus::Missing;
+    };
+
+    let (stored, computed) = match game {
+        // the complement of the 8 bit sum
+        Game::RedBlueYellow => (stored[0] as u16, !bytes.iter().fold(0u8, |acc, byte| acc.wrapping_add(*byte)) as u16),
+        // the 16 bit sum, stored little-endian unlike everything else
+        _ => (LittleEndian::read_u16(stored), bytes.iter().fold(0u16, |acc, byte| acc.wrapping_add(*byte as u16))),
+    };
+    match stored == computed {
+        true => BlockStatus::Valid,
+        false => BlockStatus::BadChecksum { stored, computed },
+    }
+}
+
+/// A Red, Blue, Yellow, Gold, Silver or Crystal save
+///
+/// The bag isn't read yet; its items would be `Item::Gen1` or `Item::Gen2`. Gen 1 boxes have no names.
+#[derive(Debug, PartialEq)]
+pub struct Gen12Save {
+    pub game: Game,
+    pub base: SaveFile,
+    pub playtime: Playtime,
+    pub badges: Vec<Badge>,
+    pub container: Container,
+    /// Everything that was skipped over or replaced, in lenient mode
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+impl SaveGame for Gen12Save {
+    fn base(&self) -> &SaveFile {
+        &self.base
+    }
+
+    fn playtime(&self) -> Playtime {
+        self.playtime
+    }
+
+    fn badges(&self) -> &[Badge] {
+        &self.badges
+    }
+}
+
+/// Read a gen 1 or 2 save from `save_file`
+pub fn read_save(save_file: impl Into<PathBuf>) -> Result<Gen12Save, ReadError> {
+    read_save_with_options(save_file, ParseOptions::default())
+}
+
+/// Read a gen 1 or 2 save from `save_file`, see `ParseOptions`
+pub fn read_save_with_options(save_file: impl Into<PathBuf>, options: ParseOptions) -> Result<Gen12Save, ReadError> {
+    format::read_file(save_file, options, read_save_from_bytes)
+}
+
+/// Read a gen 1 or 2 save from `reader`, starting at its current position
+pub fn read_save_from_reader<R: Read + Seek>(reader: &mut R) -> Result<Gen12Save, ReadError> {
+    read_save_from_reader_with_options(reader, ParseOptions::default())
+}
+
+/// Read a gen 1 or 2 save from `reader`, starting at its current position. See `ParseOptions`.
+pub fn read_save_from_reader_with_options<R: Read + Seek>(reader: &mut R, options: ParseOptions) -> Result<Gen12Save, ReadError> {
+    format::read_reader(reader, options, read_save_from_bytes)
+}
+
+/// Read a gen 1 or 2 save from the contents of a `.sav`, which can be in any `Container`. An emulator's
+/// real-time clock after the save is kept as padding.
+///
+/// Only the main copy of the save is read; gen 2's backup copy isn't.
+pub(crate) fn read_save_from_bytes(data: Vec<u8>, options: ParseOptions) -> Result<Gen12Save, ReadError> {
+    let mut diagnostics = Diagnostics::new(&options);
+    let (container, data) = Container::unwrap(data, SAVE_SIZE);
+    let Some(game) = Game::detect(&data) else {
+        // report the gen 1 checksum, the one that covers the most games
+        return Err(ReadError::NoValidBlock {
+            block: Block::General,
+            offset: GEN1_PLAYER_NAME,
+            first: checksum_status(&data, Game::RedBlueYellow),
+            second: BlockStatus::Missing,
+        });
+    };
+
+    let (base, playtime, badges) = match game {
+        Game::RedBlueYellow => read_gen1(&data, &mut diagnostics)?,
+        Game::GoldSilver | Game::Crystal => read_gen2(&data, game, &mut diagnostics)?,
+    };
+
+    Ok(Gen12Save {
+        game,
+        base,
+        playtime,
+        badges,
+        container,
+        diagnostics: diagnostics.into_inner(),
+    })
+}
+
+fn read_gen1(data: &[u8], diagnostics: &mut Diagnostics) -> Result<(SaveFile, Playtime, Vec<Badge>), ReadError> {
+    let game = Game::RedBlueYellow;
+    let mut bank = BlockReader::new(data, 0, Block::General);
+
+    bank.seek(SeekFrom::Start(GEN1_PLAYER_NAME as u64));
+    let name = string::decode(&bank.read_bytes("trainer name", NAME_LENGTH)?);
+    bank.seek(SeekFrom::Start(GEN1_MONEY as u64));
+    let bcd = bank.read_bytes("money", 3)?;
+    let money = match decode_bcd(&bcd) {
+        Some(money) => money,
+        None => {
+            diagnostics.report(bank.diagnostic("money", BigEndian::read_u24(&bcd)))?;
+            0
+        }
+    };
+    bank.seek(SeekFrom::Start(GEN1_BADGES as u64));
+    let badges = Badges::from_bits(bank.read_u8("badges")?, &Badge::KANTO).0;
+    bank.seek(SeekFrom::Start(GEN1_PLAYER_ID as u64));
+    let id = bank.read_u16_be("trainer ID")?;
+
+    bank.seek(SeekFrom::Start(GEN1_CURRENT_BOX as u64));
+    let current_box = (bank.read_u8("current box")? & 0x7F) as usize;
+
+    bank.seek(SeekFrom::Start(GEN1_PLAYTIME as u64));
+    let hours = bank.read_u8("playtime hours")?;
+    let _maxed = bank.read_u8("playtime maxed")?;
+    let playtime = Playtime {
+        hours: hours as u16,
+        minutes: bank.read_u8("playtime minutes")?,
+        seconds: bank.read_u8("playtime seconds")?,
+    };
+
+    // gen 1 has no player gender
+    let trainer = Trainer::new(name, id, None, Gender::Male);
+    let mut base_save = SaveFile::new(trainer.clone(), money);
+
+    for (_, pkmn) in read_list(data, GEN1_PARTY, 6, game, true, &trainer, diagnostics)? {
+        base_save.party.push(pkmn);
+    }
+
+    for i in 0..game.box_count() {
+        base_save.boxes.push(read_box(data, i, current_box, GEN1_CURRENT_BOX_DATA, game, &trainer, diagnostics)?);
+    }
+
+    Ok((base_save, playtime, badges))
+}
+
+fn read_gen2(data: &[u8], game: Game, diagnostics: &mut Diagnostics) -> Result<(SaveFile, Playtime, Vec<Badge>), ReadError> {
+    let offsets = game.gen2_offsets();
+    let mut bank = BlockReader::new(data, 0, Block::General);
+
+    bank.seek(SeekFrom::Start(GEN2_PLAYER_ID as u64));
+    let id = bank.read_u16_be("trainer ID")?;
+    let name = string::decode(&bank.read_bytes("trainer name", NAME_LENGTH)?);
+
+    bank.seek(SeekFrom::Start(offsets.playtime as u64));
+    let playtime = Playtime {
+        hours: bank.read_u16_be("playtime hours")?,
+        minutes: bank.read_u8("playtime minutes")?,
+        seconds: bank.read_u8("playtime seconds")?,
+    };
+
+    bank.seek(SeekFrom::Start(offsets.money as u64));
+    let money = bank.read_u24_be("money")?;
+
+    bank.seek(SeekFrom::Start(offsets.badges as u64));
+    let mut badges = Badges::from_bits(bank.read_u8("badges")?, &Badge::JOHTO).0;
+    badges.extend(Badges::from_bits(bank.read_u8("badges")?, &Badge::KANTO).0);
+
+    let gender = match game {
+        Game::Crystal => {
+            bank.seek(SeekFrom::Start(CRYSTAL_PLAYER_GENDER as u64));
+            match bank.read_u8("gender")? {
+                0 => Gender::Male,
+                1 => Gender::Female,
+                other => {
+                    diagnostics.report(bank.diagnostic("gender", other))?;
+                    Gender::Male
+                }
+            }
+        }
+        _ => Gender::Male,
+    };
+
+    bank.seek(SeekFrom::Start(offsets.current_box as u64));
+    let current_box = (bank.read_u8("current box")? & 0x7F) as usize;
+
+    let trainer = Trainer::new(name, id, None, gender);
+    let mut base_save = SaveFile::new(trainer.clone(), money);
+
+    for (_, pkmn) in read_list(data, offsets.party, 6, game, true, &trainer, diagnostics)? {
+        base_save.party.push(pkmn);
+    }
+
+    for i in 0..game.box_count() {
+        let mut pkmn_box = read_box(data, i, current_box, offsets.current_box_data, game, &trainer, diagnostics)?;
+        bank.seek(SeekFrom::Start((offsets.box_names + i * 9) as u64));
+        pkmn_box.set_name(string::decode(&bank.read_bytes("box name", 9)?));
+        base_save.boxes.push(pkmn_box);
+    }
+
+    Ok((base_save, playtime, badges))
+}
+
+/// Read box `index`. The current box is read from its copy in bank 1, as the one in banks 2 & 3 is only
+/// updated when the player changes box.
+fn read_box(data: &[u8], index: usize, current_box: usize, current_box_data: usize, game: Game, trainer: &Trainer, diagnostics: &mut Diagnostics) -> Result<crate::save::save::Box, ReadError> {
+    let offset = match index == current_box {
+        true => current_box_data,
+        false => game.box_offset(index),
+    };
+
+    let mut pkmn_box = crate::save::save::Box::new(BOX_CAPACITY);
+    for (j, pkmn) in read_list(data, offset, BOX_CAPACITY, game, false, trainer, diagnostics)? {
+        pkmn_box.set_pkmn(j, pkmn);
+    }
+    Ok(pkmn_box)
+}
+
+/// Read a party or box at `offset`: a count, a species list, the Pokémon, then their original trainer
+/// names & nicknames. Gives each Pokémon with its slot.
+///
+/// A Pokémon is only given `trainer` if its original trainer ID & name match.
+fn read_list(data: &[u8], offset: usize, capacity: usize, game: Game, party: bool, trainer: &Trainer, diagnostics: &mut Diagnostics) -> Result<Vec<(usize, Pokemon)>, ReadError> {
+    let block = if party { Block::Party } else { Block::Storage };
+    let mut list = BlockReader::new(&data[offset..], offset, block);
+    let count = list.read_u8("count")?;
+    // boxes the game hasn't written to yet are left as 0xFF, so don't report them
+    if count as usize > capacity {
+        if count != 0xFF {
+            diagnostics.report(list.diagnostic("count", count))?;
+        }
+        return Ok(Vec::new());
+    }
+    let species_list = list.read_bytes("species list", capacity + 1)?;
+
+    let (party_size, box_size) = game.pokemon_sizes();
+    let size = if party { party_size } else { box_size };
+    let ot_names = 1 + capacity + 1 + capacity * size;
+    let nicknames = ot_names + capacity * NAME_LENGTH;
+
+    let mut pokemon = Vec::new();
+    for (i, species) in species_list.iter().take(count as usize).enumerate() {
+        list.seek(SeekFrom::Start((1 + capacity + 1 + i * size) as u64));
+        let bytes = list.read_bytes("Pokémon", size)?;
+        let mut reader = BlockReader::new(&bytes, list.offset() - size, block);
+        let mut gen12 = Gen12Pokemon::read(&mut reader, game, diagnostics)?;
+
+        list.seek(SeekFrom::Start((ot_names + i * NAME_LENGTH) as u64));
+        gen12.ot_name = string::decode(&list.read_bytes("original trainer name", NAME_LENGTH)?);
+        list.seek(SeekFrom::Start((nicknames + i * NAME_LENGTH) as u64));
+        gen12.nickname = string::decode(&list.read_bytes("nickname", NAME_LENGTH)?);
+        gen12.is_egg = game != Game::RedBlueYellow && *species == EGG_SPECIES;
+
+        let mut pkmn = Pokemon::new(gen12.species);
+        pkmn.set_name(gen12.nickname.clone());
+        pkmn.set_experience(gen12.experience);
+        pkmn.set_friendship(gen12.friendship);
+
+        if gen12.ot_id == trainer.id() && gen12.ot_name == trainer.name() {
+            pkmn.set_trainer(trainer.clone());
+        }
+
+        pokemon.push((i, pkmn));
+    }
+
+    Ok(pokemon)
+}
+
+/// Money in gen 1 is 6 binary-coded decimal digits
+fn decode_bcd(bytes: &[u8]) -> Option<u32> {
+    bytes.iter().try_fold(0u32, |acc, byte| {
+        let (high, low) = (byte >> 4, byte & 0x0F);
+        (high < 10 && low < 10).then_some(acc * 100 + high as u32 * 10 + low as u32)
+    })
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::save::data::species::Species;
+    use crate::save::format::gen12::pokemon::tests::pikachu;
+
+    /// "RED" & "BOX10", in the gen 1 & 2 character set
+    const RED: [u8; 4] = [0x91, 0x84, 0x83, 0x50];
+    const BOX: [u8; 4] = [0x81, 0x8E, 0x97, 0x50];
+
+    /// Write a party or box at `offset`, with `pokemon` in its first slots
+    fn write_list(data: &mut [u8], offset: usize, capacity: usize, size: usize, pokemon: &[(u8, Vec<u8>)]) {
+        data[offset] = pokemon.len() as u8;
+        for (i, (species, bytes)) in pokemon.iter().enumerate() {
+            data[offset + 1 + i] = *species;
+            data[offset + 1 + capacity + 1 + i * size..][..size].copy_from_slice(bytes);
+            let ot_name = offset + 1 + capacity + 1 + capacity * size + i * NAME_LENGTH;
+            data[ot_name..ot_name + 4].copy_from_slice(&RED);
+            let nickname = ot_name + capacity * NAME_LENGTH;
+            data[nickname..nickname + 4].copy_from_slice(&[0x8F, 0x88, 0x8A, 0x50]); // PIK
+        }
+        data[offset + 1 + pokemon.len()] = 0xFF;
+    }
+
+    /// Fill in `game`'s checksum
+    fn write_checksum(data: &mut [u8], game: Game) {
+        let (range, position) = game.checksum_range();
+        match checksum_status(data, game) {
+            BlockStatus::BadChecksum { computed, .. } if game == Game::RedBlueYellow => data[position] = computed as u8,
+            BlockStatus::BadChecksum { computed, .. } => LittleEndian::write_u16(&mut data[position..], computed),
+            _ => {}
+        }
+        assert_eq!(checksum_status(data, game), BlockStatus::Valid, "{range:?}");
+    }
+
+    /// A save by "RED" (ID 1234) from `game`, with a Pikachu in the party, the current box (the third) & the
+    /// second-last box, and a few badges
+    pub(crate) fn test_save_bytes(game: Game) -> Vec<u8> {
+        let mut data = vec![0xFFu8; SAVE_SIZE];
+        let (party_size, box_size) = game.pokemon_sizes();
+        let species = match game {
+            Game::RedBlueYellow => 0x54,
+            _ => 25,
+        };
+        let last_box = game.box_offset(game.box_count() - 2);
+        data[BANK_SIZE..2 * BANK_SIZE].fill(0);
+
+        match game {
+            Game::RedBlueYellow => {
+                data[GEN1_PLAYER_NAME..GEN1_PLAYER_NAME + 4].copy_from_slice(&RED);
+                data[GEN1_MONEY..GEN1_MONEY + 3].copy_from_slice(&[0x01, 0x23, 0x45]);
+                data[GEN1_BADGES] = 0b1000_0001;
+                BigEndian::write_u16(&mut data[GEN1_PLAYER_ID..], 1234);
+                data[GEN1_CURRENT_BOX] = 0x80 | 2;
+                data[GEN1_PLAYTIME..GEN1_PLAYTIME + 4].copy_from_slice(&[12, 0, 34, 56]);
+                write_list(&mut data, GEN1_PARTY, 6, party_size, &[(species, pikachu(game, true))]);
+                write_list(&mut data, GEN1_CURRENT_BOX_DATA, BOX_CAPACITY, box_size, &[(species, pikachu(game, false))]);
+            }
+            _ => {
+                let offsets = game.gen2_offsets();
+                BigEndian::write_u16(&mut data[GEN2_PLAYER_ID..], 1234);
+                data[GEN2_PLAYER_NAME..GEN2_PLAYER_NAME + 4].copy_from_slice(&RED);
+                BigEndian::write_u16(&mut data[offsets.playtime..], 12);
+                data[offsets.playtime + 2..offsets.playtime + 4].copy_from_slice(&[34, 56]);
+                BigEndian::write_u24(&mut data[offsets.money..], 12345);
+                data[offsets.badges..offsets.badges + 2].copy_from_slice(&[0b1000_0001, 0b0000_0001]);
+                data[offsets.current_box] = 2;
+                for i in 0..GEN2_BOX_COUNT {
+                    data[offsets.box_names + i * 9..offsets.box_names + i * 9 + 4].copy_from_slice(&BOX);
+                }
+                if game == Game::Crystal {
+                    data[CRYSTAL_PLAYER_GENDER] = 1;
+                }
+                write_list(&mut data, offsets.party, 6, party_size, &[(EGG_SPECIES, pikachu(game, true))]);
+                write_list(&mut data, offsets.current_box_data, BOX_CAPACITY, box_size, &[(species, pikachu(game, false))]);
+            }
+        }
+
+        // the copy in bank 2 of the current box is out of date
+        write_list(&mut data, game.box_offset(2), BOX_CAPACITY, box_size, &[]);
+        write_list(&mut data, last_box, BOX_CAPACITY, box_size, &[(species, pikachu(game, false)), (species, pikachu(game, false))]);
+        write_checksum(&mut data, game);
+        data
+    }
+
+    /// Does a save from each game map into the generic save?
+    #[test]
+    fn read_gen12_save() {
+        for game in [Game::RedBlueYellow, Game::GoldSilver, Game::Crystal] {
+            let save = read_save_from_bytes(test_save_bytes(game), ParseOptions::default()).unwrap();
+            assert_eq!(save.game, game);
+            assert_eq!(save.playtime, Playtime { hours: 12, minutes: 34, seconds: 56 });
+            assert_eq!(save.container, Container::Raw);
+
+            let trainer = save.base.trainer();
+            assert_eq!(trainer.name(), "RED");
+            assert_eq!((trainer.id(), trainer.secret_id()), (1234, None));
+            assert_eq!(trainer.gender(), if game == Game::Crystal { &Gender::Female } else { &Gender::Male });
+            assert_eq!(save.base.money(), 12345);
+            let badges = match game {
+                Game::RedBlueYellow => vec![Badge::Boulder, Badge::Earth],
+                _ => vec![Badge::Zephyr, Badge::Rising, Badge::Boulder],
+            };
+            assert_eq!(save.badges, badges);
+
+            assert_eq!(save.base.party.len(), 1);
+            assert_eq!(save.base.party[0].species(), &Species::Pikachu);
+            assert_eq!(save.base.party[0].name(), "PIK");
+            assert_eq!(save.base.party[0].experience(), 135);
+            assert_eq!(save.base.party[0].trainer(), Some(trainer));
+
+            assert_eq!(save.base.boxes.len(), game.box_count());
+            assert_eq!(save.base.boxes[2].pkmn().len(), 1);
+            assert_eq!(save.base.boxes[game.box_count() - 2].pkmn().len(), 2);
+            assert!(save.base.boxes[0].pkmn().is_empty());
+            assert_eq!(save.base.boxes[3].name(), if game == Game::RedBlueYellow { "" } else { "BOX" });
+            assert!(save.diagnostics.is_empty());
+        }
+    }
+
+    /// Is a save with a bad checksum, or a nonsense box count, rejected?
+    #[test]
+    fn read_invalid_gen12_save() {
+        let mut data = test_save_bytes(Game::RedBlueYellow);
+        data[GEN1_PLAYER_ID] ^= 0xFF;
+        assert!(matches!(
+            read_save_from_bytes(data, ParseOptions::default()),
+            Err(ReadError::NoValidBlock { block: Block::General, first: BlockStatus::BadChecksum { .. }, second: BlockStatus::Missing, .. })
+        ));
+
+        let mut data = test_save_bytes(Game::GoldSilver);
+        data[Game::GoldSilver.box_offset(0)] = 21;
+        assert!(matches!(
+            read_save_from_bytes(data.clone(), ParseOptions::default()),
+            Err(ReadError::InvalidValue { block: Block::Storage, field: "count", value: 21, .. })
+        ));
+        let save = read_save_from_bytes(data, ParseOptions { strict: false }).unwrap();
+        assert_eq!(save.diagnostics.len(), 1);
+        assert_eq!(save.diagnostics[0].offset, 0x4000);
+    }
+
+    #[test]
+    fn decode_money() {
+        assert_eq!(decode_bcd(&[0x99, 0x99, 0x99]), Some(999999));
+        assert_eq!(decode_bcd(&[0x00, 0x30, 0x00]), Some(3000));
+        assert_eq!(decode_bcd(&[0x0A, 0x00, 0x00]), None);
+    }
+}
@@ -0,0 +1,354 @@
+use crate::save::data::gen1::species_from_index;
+use crate::save::data::species::Species;
+use crate::save::error::ReadError;
+use crate::save::format::dppt::pokemon::{BattleStats, Move, Pokerus, StatusCondition};
+use crate::save::format::gen12::Game;
+use crate::save::format::reader::{BlockReader, Diagnostics};
+use std::io::SeekFrom;
+
+/// Size of a gen 1 Pokémon in a box, i.e. without battle stats
+pub const GEN1_BOX_POKEMON_SIZE: usize = 33;
+/// Size of a gen 1 Pokémon in the party
+pub const GEN1_PARTY_POKEMON_SIZE: usize = 44;
+/// Size of a gen 2 Pokémon in a box, i.e. without battle stats
+pub const GEN2_BOX_POKEMON_SIZE: usize = 32;
+/// Size of a gen 2 Pokémon in the party
+pub const GEN2_PARTY_POKEMON_SIZE: usize = 48;
+
+/// Stat experience, the gen 1 & 2 version of EVs. Special is shared by both special stats.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct StatExperience {
+    pub hp: u16,
+    pub attack: u16,
+    pub defense: u16,
+    pub speed: u16,
+    pub special: u16,
+}
+
+/// Determinant values, the gen 1 & 2 version of IVs. Each is 0 to 15.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Dvs {
+    pub attack: u8,
+    pub defense: u8,
+    pub speed: u8,
+    pub special: u8,
+}
+
+impl Dvs {
+    /// The HP DV isn't stored; it's made from the lowest bit of each of the others
+    pub fn hp(&self) -> u8 {
+        ((self.attack & 1) << 3) | ((self.defense & 1) << 2) | ((self.speed & 1) << 1) | (self.special & 1)
+    }
+}
+
+impl From<u16> for Dvs {
+    fn from(value: u16) -> Self {
+        Dvs {
+            attack: (value >> 12) as u8 & 0x0F,
+            defense: (value >> 8) as u8 & 0x0F,
+            speed: (value >> 4) as u8 & 0x0F,
+            special: value as u8 & 0x0F,
+        }
+    }
+}
+
+impl From<Dvs> for u16 {
+    fn from(value: Dvs) -> Self {
+        ((value.attack as u16 & 0x0F) << 12) | ((value.defense as u16 & 0x0F) << 8) | ((value.speed as u16 & 0x0F) << 4) | (value.special as u16 & 0x0F)
+    }
+}
+
+/// A gen 1 or 2 Pokémon. Its nickname & original trainer name are stored apart from it, in the party or
+/// box it's in.
+///
+/// See the "Gen 1 Pokémon" & "Gen 2 Pokémon" sections of `doc/format/gen12/gen12.md`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Gen12Pokemon {
+    pub species: Species,
+    /// Gen 2 item ID. Gen 1 has no held items, and keeps the catch rate here instead.
+    pub held_item: u8,
+    pub moves: [Move; 4],
+    pub ot_id: u16,
+    pub experience: u32,
+    pub stat_experience: StatExperience,
+    pub dvs: Dvs,
+    /// Always `0` in gen 1
+    pub friendship: u8,
+    pub pokerus: Pokerus,
+    /// When, where & at what level it was caught, in Crystal; `0` otherwise
+    pub caught_data: u16,
+    pub level: u8,
+    pub nickname: String,
+    pub ot_name: String,
+    /// Eggs are marked in the species list of the party or box, and only exist in gen 2
+    pub is_egg: bool,
+
+    // PARTY ONLY
+    /// Only set for party Pokémon. Gen 1 has a single special stat, which is given as both `sp_attack` &
+    /// `sp_defense`.
+    pub battle_stats: Option<BattleStats>,
+}
+
+impl Gen12Pokemon {
+    /// Decode a Pokémon from `game`. If there are enough bytes for a party Pokémon, the battle stats are
+    /// read too. The nickname, original trainer name & egg flag are left for the caller to fill in.
+    pub(crate) fn read(reader: &mut BlockReader, game: Game, diagnostics: &mut Diagnostics) -> Result<Self, ReadError> {
+        match game {
+            Game::RedBlueYellow => Self::read_gen1(reader, diagnostics),
+            Game::GoldSilver | Game::Crystal => Self::read_gen2(reader, diagnostics),
+        }
+    }
+
+    fn read_gen1(reader: &mut BlockReader, diagnostics: &mut Diagnostics) -> Result<Self, ReadError> {
+        reader.seek(SeekFrom::Start(0x00));
+        let index = reader.read_u8("species")?;
+        let species = match species_from_index(index) {
+            Some(species) => species,
+            None => {
+                diagnostics.report(reader.diagnostic("species", index))?;
+                Species::Unknown(index as u16)
+            }
+        };
+        let current_hp = reader.read_u16_be("current HP")?;
+        let level = reader.read_u8("level")?;
+        let status = StatusCondition::from(reader.read_u8("status condition")? as u32);
+        let _types = reader.read_bytes("types", 2)?;
+        let catch_rate = reader.read_u8("catch rate")?;
+        let move_ids = reader.read_bytes("moves", 4)?;
+        let ot_id = reader.read_u16_be("original trainer ID")?;
+        let experience = reader.read_u24_be("experience")?;
+        let stat_experience = read_stat_experience(reader)?;
+        let dvs = Dvs::from(reader.read_u16_be("DVs")?);
+        let moves = read_moves(reader, &move_ids)?;
+
+        let battle_stats = match reader.len() >= GEN1_PARTY_POKEMON_SIZE {
+            true => {
+                reader.seek(SeekFrom::Start(0x21));
+                let level = reader.read_u8("level")?;
+                let max_hp = reader.read_u16_be("max HP")?;
+                let attack = reader.read_u16_be("attack")?;
+                let defense = reader.read_u16_be("defense")?;
+                let speed = reader.read_u16_be("speed")?;
+                let special = reader.read_u16_be("special")?;
+                Some(BattleStats {
+                    status,
+                    level,
+                    seal_capsule: 0,
+                    current_hp,
+                    max_hp,
+                    attack,
+                    defense,
+                    speed,
+                    sp_attack: special,
+                    sp_defense: special,
+                })
+            }
+            false => None,
+        };
+
+        Ok(Self {
+            species,
+            held_item: catch_rate,
+            moves,
+            ot_id,
+            experience,
+            stat_experience,
+            dvs,
+            friendship: 0,
+            pokerus: Pokerus::default(),
+            caught_data: 0,
+            level,
+            nickname: String::new(),
+            ot_name: String::new(),
+            is_egg: false,
+            battle_stats,
+        })
+    }
+
+    fn read_gen2(reader: &mut BlockReader, diagnostics: &mut Diagnostics) -> Result<Self, ReadError> {
+        reader.seek(SeekFrom::Start(0x00));
+        let national = reader.read_u8("species")?;
+        let species = match Species::try_from(national as u16) {
+            Ok(species) if (1..=251).contains(&national) => species,
+            _ => {
+                diagnostics.report(reader.diagnostic("species", national))?;
+                Species::Unknown(national as u16)
+            }
+        };
+        let held_item = reader.read_u8("held item")?;
+        let move_ids = reader.read_bytes("moves", 4)?;
+        let ot_id = reader.read_u16_be("original trainer ID")?;
+        let experience = reader.read_u24_be("experience")?;
+        let stat_experience = read_stat_experience(reader)?;
+        let dvs = Dvs::from(reader.read_u16_be("DVs")?);
+        let moves = read_moves(reader, &move_ids)?;
+        let friendship = reader.read_u8("friendship")?;
+        let pokerus = Pokerus::from(reader.read_u8("pokérus")?);
+        let caught_data = reader.read_u16_be("caught data")?;
+        let level = reader.read_u8("level")?;
+
+        let battle_stats = match reader.len() >= GEN2_PARTY_POKEMON_SIZE {
+            true => {
+                let status = StatusCondition::from(reader.read_u8("status condition")? as u32);
+                let _unused = reader.read_u8("unused")?;
+                let current_hp = reader.read_u16_be("current HP")?;
+                let max_hp = reader.read_u16_be("max HP")?;
+                let attack = reader.read_u16_be("attack")?;
+                let defense = reader.read_u16_be("defense")?;
+                let speed = reader.read_u16_be("speed")?;
+                let sp_attack = reader.read_u16_be("special attack")?;
+                let sp_defense = reader.read_u16_be("special defense")?;
+                Some(BattleStats {
+                    status,
+                    level,
+                    seal_capsule: 0,
+                    current_hp,
+                    max_hp,
+                    attack,
+                    defense,
+                    speed,
+                    sp_attack,
+                    sp_defense,
+                })
+            }
+            false => None,
+        };
+
+        Ok(Self {
+            species,
+            held_item,
+            moves,
+            ot_id,
+            experience,
+            stat_experience,
+            dvs,
+            friendship,
+            pokerus,
+            caught_data,
+            level,
+            nickname: String::new(),
+            ot_name: String::new(),
+            is_egg: false,
+            battle_stats,
+        })
+    }
+}
+
+fn read_stat_experience(reader: &mut BlockReader) -> Result<StatExperience, ReadError> {
+    Ok(StatExperience {
+        hp: reader.read_u16_be("HP stat experience")?,
+        attack: reader.read_u16_be("attack stat experience")?,
+        defense: reader.read_u16_be("defense stat experience")?,
+        speed: reader.read_u16_be("speed stat experience")?,
+        special: reader.read_u16_be("special stat experience")?,
+    })
+}
+
+/// Read the PP of `move_ids`, with the PP ups in the top 2 bits of each
+fn read_moves(reader: &mut BlockReader, move_ids: &[u8]) -> Result<[Move; 4], ReadError> {
+    let pp = reader.read_bytes("move PP", 4)?;
+    let mut moves = [Move::default(); 4];
+    for (i, move_slot) in moves.iter_mut().enumerate() {
+        move_slot.id = move_ids[i] as u16;
+        move_slot.pp = pp[i] & 0x3F;
+        move_slot.pp_ups = pp[i] >> 6;
+    }
+    Ok(moves)
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::save::error::Block;
+    use crate::save::format::ParseOptions;
+    use byteorder::{BigEndian, ByteOrder};
+
+    /// A level 5 Pikachu caught by trainer 1234, as stored in `game`
+    pub(crate) fn pikachu(game: Game, party: bool) -> Vec<u8> {
+        match game {
+            Game::RedBlueYellow => {
+                let mut data = vec![0u8; if party { GEN1_PARTY_POKEMON_SIZE } else { GEN1_BOX_POKEMON_SIZE }];
+                data[0x00] = 0x54;
+                BigEndian::write_u16(&mut data[0x01..], 19);
+                data[0x03] = 5;
+                data[0x04] = 1 << 6;
+                data[0x05..0x07].copy_from_slice(&[0x17, 0x17]);
+                data[0x07] = 190;
+                data[0x08..0x0C].copy_from_slice(&[84, 45, 0, 0]); // Thundershock, Growl
+                BigEndian::write_u16(&mut data[0x0C..], 1234);
+                BigEndian::write_u24(&mut data[0x0E..], 135);
+                BigEndian::write_u16(&mut data[0x11..], 300);
+                BigEndian::write_u16(&mut data[0x19..], 20);
+                BigEndian::write_u16(&mut data[0x1B..], 0xA5F3);
+                data[0x1D..0x21].copy_from_slice(&[30, 40 | (1 << 6), 0, 0]);
+                if party {
+                    data[0x21] = 5;
+                    BigEndian::write_u16(&mut data[0x22..], 20);
+                    BigEndian::write_u16(&mut data[0x2A..], 11);
+                }
+                data
+            }
+            Game::GoldSilver | Game::Crystal => {
+                let mut data = vec![0u8; if party { GEN2_PARTY_POKEMON_SIZE } else { GEN2_BOX_POKEMON_SIZE }];
+                data[0x00] = 25;
+                data[0x01] = 0x53; // Light Ball
+                data[0x02..0x06].copy_from_slice(&[84, 45, 0, 0]);
+                BigEndian::write_u16(&mut data[0x06..], 1234);
+                BigEndian::write_u24(&mut data[0x08..], 135);
+                BigEndian::write_u16(&mut data[0x0B..], 300);
+                BigEndian::write_u16(&mut data[0x13..], 20);
+                BigEndian::write_u16(&mut data[0x15..], 0xA5F3);
+                data[0x17..0x1B].copy_from_slice(&[30, 40 | (1 << 6), 0, 0]);
+                data[0x1B] = 70;
+                data[0x1C] = 0x21;
+                BigEndian::write_u16(&mut data[0x1D..], 0x1234);
+                data[0x1F] = 5;
+                if party {
+                    data[0x20] = 1 << 6;
+                    BigEndian::write_u16(&mut data[0x22..], 19);
+                    BigEndian::write_u16(&mut data[0x24..], 20);
+                    BigEndian::write_u16(&mut data[0x2C..], 11);
+                    BigEndian::write_u16(&mut data[0x2E..], 10);
+                }
+                data
+            }
+        }
+    }
+
+    /// Is every field read from the right place, in both generations' layouts?
+    #[test]
+    fn read_gen12_pokemon() {
+        for game in [Game::RedBlueYellow, Game::Crystal] {
+            for party in [false, true] {
+                let data = pikachu(game, party);
+                let mut diagnostics = Diagnostics::new(&ParseOptions::default());
+                let pkmn = Gen12Pokemon::read(&mut BlockReader::new(&data, 0, Block::Pokemon), game, &mut diagnostics).unwrap();
+                assert_eq!(pkmn.species, Species::Pikachu);
+                assert_eq!(pkmn.ot_id, 1234);
+                assert_eq!(pkmn.experience, 135);
+                assert_eq!(pkmn.level, 5);
+                assert_eq!(pkmn.moves[0], Move { id: 84, pp: 30, pp_ups: 0 });
+                assert_eq!(pkmn.moves[1], Move { id: 45, pp: 40, pp_ups: 1 });
+                assert_eq!((pkmn.stat_experience.hp, pkmn.stat_experience.special), (300, 20));
+                assert_eq!(pkmn.dvs, Dvs { attack: 10, defense: 5, speed: 15, special: 3 });
+                assert_eq!(pkmn.dvs.hp(), 0b0111);
+                assert_eq!(u16::from(pkmn.dvs), 0xA5F3);
+
+                let stats = pkmn.battle_stats;
+                assert_eq!(stats.is_some(), party);
+                if let Some(stats) = stats {
+                    assert_eq!((stats.level, stats.current_hp, stats.max_hp, stats.sp_attack), (5, 19, 20, 11));
+                    assert_eq!(stats.status, StatusCondition::Paralyzed);
+                }
+
+                match game {
+                    Game::RedBlueYellow => assert_eq!((pkmn.held_item, pkmn.friendship), (190, 0)),
+                    _ => {
+                        assert_eq!((pkmn.held_item, pkmn.friendship, pkmn.caught_data), (0x53, 70, 0x1234));
+                        assert_eq!(pkmn.pokerus, Pokerus { strain: 2, days: 1 });
+                    }
+                }
+            }
+        }
+    }
+}
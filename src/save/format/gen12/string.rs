@@ -0,0 +1,70 @@
+// international character set; see https://github.com/pret/pokered/blob/master/constants/charmap.asm
+// characters we don't have a `char` for are read as `NUL`
+const NUL: &str = "\u{FFFF}";
+/// Ends a string, unless it fills its field
+pub(crate) const TERMINATOR: u8 = 0x50;
+
+/// Decode one character of the international gen 1 & 2 character set. A few are whole strings, e.g. the
+/// contractions & the `PK`/`MN` glyphs.
+fn decode_char(byte: u8) -> &'static str {
+    const UPPER: [&str; 26] = ["A", "B", "C", "D", "E", "F", "G", "H", "I", "J", "K", "L", "M", "N", "O", "P", "Q", "R", "S", "T", "U", "V", "W", "X", "Y", "Z"];
+    const LOWER: [&str; 26] = ["a", "b", "c", "d", "e", "f", "g", "h", "i", "j", "k", "l", "m", "n", "o", "p", "q", "r", "s", "t", "u", "v", "w", "x", "y", "z"];
+    const DIGITS: [&str; 10] = ["0", "1", "2", "3", "4", "5", "6", "7", "8", "9"];
+
+    match byte {
+        0x7F => " ",
+        0x80..=0x99 => UPPER[(byte - 0x80) as usize],
+        0x9A => "(",
+        0x9B => ")",
+        0x9C => ":",
+        0x9D => ";",
+        0x9E => "[",
+        0x9F => "]",
+        0xA0..=0xB9 => LOWER[(byte - 0xA0) as usize],
+        0xBA => "é",
+        0xBB => "'d",
+        0xBC => "'l",
+        0xBD => "'s",
+        0xBE => "'t",
+        0xBF => "'v",
+        0xE0 => "'",
+        0xE1 => "PK",
+        0xE2 => "MN",
+        0xE3 => "-",
+        0xE4 => "'r",
+        0xE5 => "'m",
+        0xE6 => "?",
+        0xE7 => "!",
+        0xE8 => ".",
+        0xEF => "♂",
+        0xF0 => "¥",
+        0xF1 => "×",
+        0xF3 => "/",
+        0xF4 => ",",
+        0xF5 => "♀",
+        0xF6..=0xFF => DIGITS[(byte - 0xF6) as usize],
+        _ => NUL,
+    }
+}
+
+/// Decode a gen 1 or 2 string, up to its terminator. Characters we don't know are `NUL`.
+///
+/// @todo: Japanese saves use their own character set, and a different layout
+pub(crate) fn decode(bytes: &[u8]) -> String {
+    bytes.iter().take_while(|b| **b != TERMINATOR).map(|b| decode_char(*b)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Can we decode names, contractions & digits, stopping at the terminator?
+    #[test]
+    fn decode_strings() {
+        assert_eq!(decode(&[0x91, 0x84, 0x83, 0x50, 0x50, 0x50]), "RED");
+        assert_eq!(decode(&[0x8C, 0xB1, 0x7F, 0x8C, 0xA8, 0xAC, 0xA4, 0x50]), "Mr Mime");
+        assert_eq!(decode(&[0x85, 0xA0, 0xB1, 0xA5, 0xA4, 0xB3, 0xA2, 0xA7, 0xBB, 0x50]), "Farfetch'd");
+        assert_eq!(decode(&[0x81, 0x8E, 0x97, 0xF7, 0xF6, 0xF5]), "BOX10♀");
+        assert_eq!(decode(&[0x00]), "\u{FFFF}");
+    }
+}
@@ -0,0 +1,372 @@
+pub mod pokemon;
+pub mod sector;
+mod string;
+
+use crate::save::data::badge::{Badge, Badges};
+use crate::save::error::{Block, Diagnostic, ReadError};
+use crate::save::format::container::Container;
+use crate::save::format::gen3::pokemon::{Gen3Pokemon, BOX_POKEMON_SIZE};
+use crate::save::format::gen3::sector::{select_sections, Sections, SAVE_SIZE, SECTION_SIZES};
+use crate::save::format::reader::{BlockReader, Diagnostics};
+use crate::save::format::{self, CorruptSlot, ParseOptions, SlotLocation};
+use crate::save::save::{Gender, Playtime, SaveFile, SaveGame, Trainer};
+use std::io::{Read, Seek, SeekFrom};
+use std::path::PathBuf;
+
+/// The sections that are concatenated to make up the PC storage
+const STORAGE_SECTIONS: std::ops::RangeInclusive<usize> = 5..=13;
+const BOX_COUNT: usize = 14;
+const BOX_NAMES: usize = 0x8344;
+const BOX_WALLPAPERS: usize = 0x83C2;
+
+/// Which games a gen 3 save can be from. Ruby & Sapphire share a layout, as do FireRed & LeafGreen, and
+/// neither pair stores which of the two it is.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Game {
+    RubySapphire,
+    Emerald,
+    FireRedLeafGreen,
+}
+
+impl Game {
+    /// Tell the games apart by the game code in the trainer section. Emerald keeps its security key
+    /// there instead, which is anything but `0` or `1`.
+    pub(crate) fn from_code(code: u32) -> Self {
+        match code {
+            0 => Game::RubySapphire,
+            1 => Game::FireRedLeafGreen,
+            _ => Game::Emerald,
+        }
+    }
+
+    /// Offsets of the party count & money in section 1
+    fn team_offsets(&self) -> (usize, usize) {
+        match self {
+            Game::FireRedLeafGreen => (0x34, 0x290),
+            _ => (0x234, 0x490),
+        }
+    }
+
+    /// Where the first badge's event flag is in section 2, as a byte & bit; the other seven follow it
+    fn badge_flags(&self) -> (usize, u32) {
+        match self {
+            Game::RubySapphire => (0x3A0, 7),
+            Game::Emerald => (0x3F0, 7),
+            Game::FireRedLeafGreen => (0x64, 0),
+        }
+    }
+
+    /// The region's badges, in the order of their flags
+    fn badges(&self) -> &'static [Badge; 8] {
+        match self {
+            Game::FireRedLeafGreen => &Badge::KANTO,
+            _ => &Badge::HOENN,
+        }
+    }
+}
+
+/// A Ruby, Sapphire, Emerald, FireRed or LeafGreen save
+///
+/// The bag isn't read yet; its items would be `Item::Gen3`.
+#[derive(Debug, PartialEq)]
+pub struct Gen3Save {
+    pub game: Game,
+    pub base: SaveFile,
+    pub playtime: Playtime,
+    pub badges: Vec<Badge>,
+    /// Money & some items are XORed with this in Emerald & FRLG; `0` in RS
+    pub security_key: u32,
+    /// Which of the two saves was read, and where its sections are
+    pub sections: Sections,
+    pub container: Container,
+    /// Pokémon that couldn't be read, in lenient mode
+    pub corrupt_slots: Vec<CorruptSlot>,
+    /// Everything that was skipped over or replaced, in lenient mode
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+impl SaveGame for Gen3Save {
+    fn base(&self) -> &SaveFile {
+        &self.base
+    }
+
+    fn playtime(&self) -> Playtime {
+        self.playtime
+    }
+
+    fn badges(&self) -> &[Badge] {
+        &self.badges
+    }
+}
+
+/// Read a gen 3 save from `save_file`
+pub fn read_save(save_file: impl Into<PathBuf>) -> Result<Gen3Save, ReadError> {
+    read_save_with_options(save_file, ParseOptions::default())
+}
+
+/// Read a gen 3 save from `save_file`, see `ParseOptions`
+pub fn read_save_with_options(save_file: impl Into<PathBuf>, options: ParseOptions) -> Result<Gen3Save, ReadError> {
+    format::read_file(save_file, options, read_save_from_bytes)
+}
+
+/// Read a gen 3 save from `reader`, starting at its current position
+pub fn read_save_from_reader<R: Read + Seek>(reader: &mut R) -> Result<Gen3Save, ReadError> {
+    read_save_from_reader_with_options(reader, ParseOptions::default())
+}
+
+/// Read a gen 3 save from `reader`, starting at its current position. See `ParseOptions`.
+pub fn read_save_from_reader_with_options<R: Read + Seek>(reader: &mut R, options: ParseOptions) -> Result<Gen3Save, ReadError> {
+    format::read_reader(reader, options, read_save_from_bytes)
+}
+
+/// Read a gen 3 save from the contents of a `.sav`, which can be in any `Container`.
+///
+/// The newer of the two saves with every sector valid is read.
+pub(crate) fn read_save_from_bytes(data: Vec<u8>, options: ParseOptions) -> Result<Gen3Save, ReadError> {
+    let mut diagnostics = Diagnostics::new(&options);
+    let (container, data) = Container::unwrap(data, SAVE_SIZE);
+    let sections = select_sections(&data)?;
+
+    // TRAINER SECTION
+    let mut trainer_info = BlockReader::new(sections.data(&data, 0), sections.offset(0), Block::Sector(0));
+    // @todo: the language isn't stored, so a Japanese save's names are decoded as international
+    let trainer_name = string::decode(&trainer_info.read_bytes("trainer name", 8)?, false);
+    let trainer_gender = match trainer_info.read_u8("gender")? {
+        0 => Gender::Male,
+        1 => Gender::Female,
+        other => {
+            diagnostics.report(trainer_info.diagnostic("gender", other))?;
+            Gender::Male
+        }
+    };
+    trainer_info.seek(SeekFrom::Start(0x0A));
+    let trainer_id = trainer_info.read_u16("trainer ID")?;
+    let trainer_secret_id = trainer_info.read_u16("trainer secret ID")?;
+    let playtime = Playtime {
+        hours: trainer_info.read_u16("playtime hours")?,
+        minutes: trainer_info.read_u8("playtime minutes")?,
+        seconds: trainer_info.read_u8("playtime seconds")?,
+    };
+    let _playtime_frames = trainer_info.read_u8("playtime frames")?;
+
+    trainer_info.seek(SeekFrom::Start(0xAC));
+    let game_code = trainer_info.read_u32("game code")?;
+    let game = Game::from_code(game_code);
+    let security_key = match game {
+        Game::RubySapphire => 0,
+        Game::Emerald => game_code,
+        Game::FireRedLeafGreen => {
+            trainer_info.seek(SeekFrom::Start(0xF20));
+            trainer_info.read_u32("security key")?
+        }
+    };
+
+    let trainer = Trainer::new(trainer_name, trainer_id, Some(trainer_secret_id), trainer_gender);
+
+    // TEAM & ITEMS SECTION
+    let (party_offset, money_offset) = game.team_offsets();
+    let mut team = BlockReader::new(sections.data(&data, 1), sections.offset(1), Block::Sector(1));
+    team.seek(SeekFrom::Start(money_offset as u64));
+    let money = team.read_u32("money")? ^ security_key;
+    let mut base_save = SaveFile::new(trainer.clone(), money);
+
+    team.seek(SeekFrom::Start(party_offset as u64));
+    let party_count = team.read_u32("party count")?;
+    if party_count > 6 {
+        diagnostics.report(team.diagnostic("party count", party_count))?;
+    }
+
+    let mut corrupt_slots = Vec::new();
+    base_save.party = format::read_party::<Gen3Pokemon>(&mut team, party_count.min(6) as usize, Block::Sector(1), &trainer, &mut diagnostics, &mut corrupt_slots)?;
+
+    // EVENT FLAGS SECTION
+    let (flags, bit) = game.badge_flags();
+    let mut events = BlockReader::new(sections.data(&data, 2), sections.offset(2), Block::Sector(2));
+    events.seek(SeekFrom::Start(flags as u64));
+    let badges = Badges::from_bits((events.read_u16("badges")? >> bit) as u8, game.badges()).0;
+
+    // PC STORAGE SECTIONS
+    let storage_data: Vec<u8> = STORAGE_SECTIONS.flat_map(|id| sections.data(&data, id).iter().copied()).collect();
+    let mut storage = BlockReader::new(&storage_data, sections.offset(*STORAGE_SECTIONS.start()), Block::Storage);
+    let _current_box = storage.read_u32("current box")?;
+
+    for i in 0..BOX_COUNT {
+        let mut pkmn_box = crate::save::save::Box::new(30);
+        for j in 0..30 {
+            let position = 0x04 + (i * 30 + j) * BOX_POKEMON_SIZE;
+            storage.seek(SeekFrom::Start(position as u64));
+            let buf = storage.read_bytes("box Pokémon", BOX_POKEMON_SIZE)?;
+            let offset = storage_offset(&sections, position);
+            if let Some(pkmn) = format::read_slot::<Gen3Pokemon>(buf, offset, Block::Storage, SlotLocation::Box(i, j), &trainer, &mut diagnostics, &mut corrupt_slots)? {
+                pkmn_box.set_pkmn(j, pkmn);
+            }
+        }
+
+        storage.seek(SeekFrom::Start((BOX_NAMES + i * 9) as u64));
+        pkmn_box.set_name(string::decode(&storage.read_bytes("box name", 9)?, false));
+        storage.seek(SeekFrom::Start((BOX_WALLPAPERS + i) as u64));
+        pkmn_box.set_wallpaper(storage.read_u8("box wallpaper")?);
+
+        base_save.boxes.push(pkmn_box);
+    }
+
+    Ok(Gen3Save {
+        game,
+        base: base_save,
+        playtime,
+        badges,
+        security_key,
+        sections,
+        container,
+        corrupt_slots,
+        diagnostics: diagnostics.into_inner(),
+    })
+}
+
+/// Absolute offset of `position` within the concatenated storage sections
+fn storage_offset(sections: &Sections, position: usize) -> usize {
+    let mut position = position;
+    for id in STORAGE_SECTIONS {
+        if position < SECTION_SIZES[id] {
+            return sections.offset(id) + position;
+        }
+        position -= SECTION_SIZES[id];
+    }
+    unreachable!("position is within the storage sections")
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::save::data::species::Species;
+    use crate::save::format::gen3::pokemon::tests::{encrypt, treecko};
+    use crate::save::format::gen3::pokemon::PARTY_POKEMON_SIZE;
+    use crate::save::format::gen3::sector::tests::make_slot;
+    use crate::save::format::gen3::sector::{SECTOR_COUNT, SECTOR_SIZE};
+    use byteorder::{ByteOrder, LittleEndian};
+
+    /// The contents of each section of a save by "Lena", with a Treecko in the party & the second box, and the
+    /// first & last badges
+    fn section(game: Game, id: usize) -> Vec<u8> {
+        let mut section = vec![0u8; SECTION_SIZES[id]];
+        match id {
+            0 => {
+                section[..5].copy_from_slice(&[0xC6, 0xD9, 0xE2, 0xD5, 0xFF]);
+                section[0x08] = 1;
+                LittleEndian::write_u16(&mut section[0x0A..], 1234);
+                LittleEndian::write_u16(&mut section[0x0C..], 5678);
+                LittleEndian::write_u16(&mut section[0x0E..], 12);
+                section[0x10] = 34;
+                section[0x11] = 56;
+                let code = match game {
+                    Game::RubySapphire => 0,
+                    Game::Emerald => 0xA5A5_5A5A,
+                    Game::FireRedLeafGreen => 1,
+                };
+                LittleEndian::write_u32(&mut section[0xAC..], code);
+                if game == Game::FireRedLeafGreen {
+                    LittleEndian::write_u32(&mut section[0xF20..], 0x0F0F_F0F0);
+                }
+            }
+            1 => {
+                let key = match game {
+                    Game::RubySapphire => 0,
+                    Game::Emerald => 0xA5A5_5A5A,
+                    Game::FireRedLeafGreen => 0x0F0F_F0F0,
+                };
+                let (party, money) = game.team_offsets();
+                LittleEndian::write_u32(&mut section[party..], 1);
+                section[party + 4..party + 4 + PARTY_POKEMON_SIZE].copy_from_slice(&encrypt(&treecko(42, true)));
+                LittleEndian::write_u32(&mut section[money..], 3000 ^ key);
+            }
+            2 => {
+                let (flags, bit) = game.badge_flags();
+                LittleEndian::write_u16(&mut section[flags..], 0b1000_0001 << bit);
+            }
+            _ => {}
+        }
+        section
+    }
+
+    /// Concatenate storage & split it back into sections 5 to 13
+    fn with_storage(game: Game, storage: &[u8], id: usize) -> Vec<u8> {
+        if !STORAGE_SECTIONS.contains(&id) {
+            return section(game, id);
+        }
+        let start: usize = SECTION_SIZES[*STORAGE_SECTIONS.start()..id].iter().sum();
+        storage[start..start + SECTION_SIZES[id]].to_vec()
+    }
+
+    fn test_storage() -> Vec<u8> {
+        let mut storage = vec![0u8; STORAGE_SECTIONS.map(|id| SECTION_SIZES[id]).sum()];
+        // the 50th slot crosses from section 5 into section 6
+        let slot = 0x04 + 49 * BOX_POKEMON_SIZE;
+        storage[slot..slot + BOX_POKEMON_SIZE].copy_from_slice(&encrypt(&treecko(7, false)));
+        for i in 0..BOX_COUNT {
+            storage[BOX_NAMES + i * 9..BOX_NAMES + i * 9 + 6].copy_from_slice(&[0xBC, 0xC9, 0xD2, 0x00, 0xA1 + i as u8 % 10, 0xFF]);
+            storage[BOX_WALLPAPERS + i] = i as u8;
+        }
+        storage
+    }
+
+    /// A save by "Lena" from `game`, whose second copy is newer
+    pub(crate) fn test_save_bytes(game: Game) -> Vec<u8> {
+        let storage = test_storage();
+        let mut data = make_slot(1, 0, |_| Vec::new());
+        data.extend(make_slot(2, 5, |id| with_storage(game, &storage, id)));
+        data.resize(SAVE_SIZE, 0xFF);
+        data
+    }
+
+    /// Does a save from each game map into the generic save?
+    #[test]
+    fn read_gen3_save() {
+        for game in [Game::RubySapphire, Game::Emerald, Game::FireRedLeafGreen] {
+            let save = read_save_from_bytes(test_save_bytes(game), ParseOptions::default()).unwrap();
+            assert_eq!(save.game, game);
+            assert_eq!(save.sections.slot, 1);
+            assert_eq!(save.playtime, Playtime { hours: 12, minutes: 34, seconds: 56 });
+            assert_eq!(save.container, Container::Raw);
+
+            let trainer = save.base.trainer();
+            assert_eq!(trainer.name(), "Lena");
+            assert_eq!((trainer.id(), trainer.secret_id()), (1234, Some(5678)));
+            assert_eq!(trainer.gender(), &Gender::Female);
+            assert_eq!(save.base.money(), 3000);
+            assert_eq!(save.badges, vec![game.badges()[0], game.badges()[7]]);
+
+            assert_eq!(save.base.party.len(), 1);
+            assert_eq!(save.base.party[0].species(), &Species::Treecko);
+            assert_eq!(save.base.party[0].name(), "TREE");
+            assert_eq!(save.base.party[0].trainer(), Some(trainer));
+
+            assert_eq!(save.base.boxes.len(), BOX_COUNT);
+            assert_eq!(save.base.boxes[1].get_pkmn(19).unwrap().experience(), 135);
+            assert_eq!(save.base.boxes[1].pkmn().len(), 1);
+            assert_eq!(save.base.boxes[3].name(), "BOX 3");
+            assert_eq!(save.base.boxes[3].wallpaper(), 3);
+        }
+    }
+
+    /// Is a Pokémon that fails its checksum kept aside in lenient mode?
+    #[test]
+    fn read_corrupt_pokemon() {
+        let mut storage = test_storage();
+        storage[0x04 + 49 * BOX_POKEMON_SIZE + 0x30] ^= 0xFF;
+        let mut data = make_slot(1, 0, |id| with_storage(Game::Emerald, &storage, id));
+        data.resize(SAVE_SIZE, 0xFF);
+
+        assert!(matches!(
+            read_save_from_bytes(data.clone(), ParseOptions::default()),
+            Err(ReadError::InvalidValue { block: Block::Storage, field: "checksum", .. })
+        ));
+
+        let save = read_save_from_bytes(data.clone(), ParseOptions { strict: false }).unwrap();
+        assert!(save.base.boxes[1].get_pkmn(19).is_none());
+        let corrupt = &save.corrupt_slots[0];
+        assert_eq!(corrupt.location, SlotLocation::Box(1, 19));
+        assert_eq!(&data[corrupt.offset..corrupt.offset + 4], &corrupt.bytes[..4]);
+        assert_eq!(save.diagnostics.len(), 1);
+        assert!(corrupt.offset >= 5 * SECTOR_SIZE && corrupt.offset < SECTOR_COUNT * SECTOR_SIZE);
+    }
+}
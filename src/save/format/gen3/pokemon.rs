@@ -0,0 +1,404 @@
+use crate::save::data::gen3::species_from_index;
+use crate::save::data::species::Species;
+use crate::save::error::{Block, ReadError};
+use crate::save::format::dppt::pokemon::{BattleStats, ContestStats, Move, Pokerus, Stats, StatusCondition, BLOCK_POSITIONS};
+use crate::save::format::gen3::string;
+use crate::save::format::reader::{BlockReader, Diagnostics};
+use crate::save::format::{read_stats, ParseOptions, StoredPokemon};
+use crate::save::save::{Gender, Pokemon};
+use byteorder::{ByteOrder, LittleEndian};
+use std::io::SeekFrom;
+
+/// Size of a Pokémon in a box, i.e. without battle stats
+pub const BOX_POKEMON_SIZE: usize = 80;
+/// Size of a Pokémon in the party, with the 20 bytes of battle stats
+pub const PARTY_POKEMON_SIZE: usize = 100;
+
+/// The language that decides which character table a Pokémon's strings use
+const LANGUAGE_JAPANESE: u8 = 1;
+
+/// A gen 3 Pokémon, decoded from the four substructures of its decrypted data.
+///
+/// See the "Pokémon" section of `doc/format/gen3/gen3.md`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Gen3Pokemon {
+    // HEADER
+    /// Personality value, which decides nature, gender, shininess, ability, etc.
+    pub pid: u32,
+    pub ot_id: u16,
+    pub ot_secret_id: u16,
+    pub nickname: String,
+    pub language: u8,
+    /// Bad egg, has species & use egg name, from the lowest bit
+    pub flags: u8,
+    pub ot_name: String,
+    /// Circle, square, triangle & heart, from the lowest bit
+    pub markings: u8,
+    pub checksum: u16,
+
+    // GROWTH
+    pub species: Species,
+    /// Gen 3 item ID, which doesn't match gen 4's
+    pub held_item: u16,
+    pub experience: u32,
+    pub friendship: u8,
+
+    // ATTACKS
+    pub moves: [Move; 4],
+
+    // EVS & CONDITION
+    pub evs: Stats,
+    /// `sheen` is the Pokéblock "feel"
+    pub contest: ContestStats,
+
+    // MISC
+    pub pokerus: Pokerus,
+    pub met_location: u8,
+    pub met_level: u8,
+    pub origin_game: u8,
+    pub ball: u8,
+    pub ot_gender: Gender,
+    pub ivs: Stats,
+    pub is_egg: bool,
+    /// Which of the species' two abilities it has
+    pub ability: u8,
+    pub ribbons: u32,
+
+    // PARTY ONLY
+    /// Only set for party Pokémon. `seal_capsule` is always `0`, gen 3 has no seals.
+    pub battle_stats: Option<BattleStats>,
+}
+
+impl Gen3Pokemon {
+    /// Read a `.pk3` file, which can be encrypted (as stored in a save) or decrypted, with or without
+    /// battle stats.
+    pub fn from_pk3(data: &[u8]) -> Result<Self, ReadError> {
+        if !is_pokemon_size(data.len()) {
+            return Err(ReadError::InvalidPokemonSize(data.len()));
+        }
+
+        let checksum = LittleEndian::read_u16(&data[0x1C..0x1E]);
+        let decrypted = match pokemon_checksum(data) == checksum {
+            true => data.to_vec(),
+            false => decrypt_pokemon_blob(data)?,
+        };
+        if pokemon_checksum(&decrypted) != checksum {
+            return Err(ReadError::InvalidValue { block: Block::Pokemon, offset: 0x1C, field: "checksum", value: checksum as u64 });
+        }
+
+        let mut diagnostics = Diagnostics::new(&ParseOptions::default());
+        Self::read(&mut BlockReader::new(&decrypted, 0, Block::Pokemon), &mut diagnostics)
+    }
+
+    /// Decode a decrypted Pokémon, with substructures in growth, attacks, EVs, misc order. If there are
+    /// `PARTY_POKEMON_SIZE` bytes, the battle stats are read too.
+    pub(crate) fn read(reader: &mut BlockReader, diagnostics: &mut Diagnostics) -> Result<Self, ReadError> {
+        // HEADER
+        reader.seek(SeekFrom::Start(0x00));
+        let pid = reader.read_u32("PID")?;
+        let ot_id = reader.read_u16("original trainer ID")?;
+        let ot_secret_id = reader.read_u16("original trainer secret ID")?;
+        let nickname = reader.read_bytes("nickname", 10)?;
+        let language = reader.read_u8("language")?;
+        let flags = reader.read_u8("flags")?;
+        let ot_name = reader.read_bytes("original trainer name", 7)?;
+        let markings = reader.read_u8("markings")?;
+        let checksum = reader.read_u16("checksum")?;
+
+        let japanese = language == LANGUAGE_JAPANESE;
+        let nickname = string::decode(&nickname, japanese);
+        let ot_name = string::decode(&ot_name, japanese);
+
+        // GROWTH
+        reader.seek(SeekFrom::Start(0x20));
+        let species = reader.read_u16("species")?;
+        let species = match species_from_index(species) {
+            Some(species) => species,
+            None => {
+                diagnostics.report(reader.diagnostic("species", species))?;
+                Species::Unknown(species)
+            }
+        };
+        let held_item = reader.read_u16("held item")?;
+        let experience = reader.read_u32("experience")?;
+        let pp_ups = reader.read_u8("PP ups")?;
+        let friendship = reader.read_u8("friendship")?;
+
+        // ATTACKS
+        reader.seek(SeekFrom::Start(0x2C));
+        let mut moves = [Move::default(); 4];
+        for (i, move_slot) in moves.iter_mut().enumerate() {
+            move_slot.id = reader.read_u16("move")?;
+            move_slot.pp_ups = (pp_ups >> (i * 2)) & 0b11;
+        }
+        for move_slot in moves.iter_mut() {
+            move_slot.pp = reader.read_u8("move PP")?;
+        }
+
+        // EVS & CONDITION
+        reader.seek(SeekFrom::Start(0x38));
+        let evs = read_stats(reader, "EVs")?;
+        let contest = reader.read_bytes("contest stats", 6)?;
+        let contest = ContestStats {
+            cool: contest[0],
+            beauty: contest[1],
+            cute: contest[2],
+            smart: contest[3],
+            tough: contest[4],
+            sheen: contest[5],
+        };
+
+        // MISC
+        reader.seek(SeekFrom::Start(0x44));
+        let pokerus = Pokerus::from(reader.read_u8("pokérus")?);
+        let met_location = reader.read_u8("met location")?;
+        let origins = reader.read_u16("origins")?;
+        let ivs = reader.read_u32("IVs")?;
+        let ribbons = reader.read_u32("ribbons")?;
+
+        let iv = |i: u32| ((ivs >> (i * 5)) & 0x1F) as u8;
+
+        // PARTY ONLY
+        let battle_stats = match reader.len() >= PARTY_POKEMON_SIZE {
+            true => {
+                reader.seek(SeekFrom::Start(0x50));
+                let status = StatusCondition::from(reader.read_u32("status condition")?);
+                let level = reader.read_u8("level")?;
+                let _pokerus_days = reader.read_u8("pokérus days")?;
+                let stats = reader.read_u16s("battle stats", 7)?;
+                Some(BattleStats {
+                    status,
+                    level,
+                    seal_capsule: 0,
+                    current_hp: stats[0],
+                    max_hp: stats[1],
+                    attack: stats[2],
+                    defense: stats[3],
+                    speed: stats[4],
+                    sp_attack: stats[5],
+                    sp_defense: stats[6],
+                })
+            }
+            false => None,
+        };
+
+        Ok(Self {
+            pid,
+            ot_id,
+            ot_secret_id,
+            nickname,
+            language,
+            flags,
+            ot_name,
+            markings,
+            checksum,
+            species,
+            held_item,
+            experience,
+            friendship,
+            moves,
+            evs,
+            contest,
+            pokerus,
+            met_location,
+            met_level: (origins & 0x7F) as u8,
+            origin_game: ((origins >> 7) & 0x0F) as u8,
+            ball: ((origins >> 11) & 0x0F) as u8,
+            ot_gender: match origins >> 15 {
+                0 => Gender::Male,
+                _ => Gender::Female,
+            },
+            ivs: Stats {
+                hp: iv(0),
+                attack: iv(1),
+                defense: iv(2),
+                speed: iv(3),
+                sp_attack: iv(4),
+                sp_defense: iv(5),
+            },
+            is_egg: ivs & (1 << 30) != 0,
+            ability: (ivs >> 31) as u8,
+            ribbons,
+            battle_stats,
+        })
+    }
+}
+
+impl StoredPokemon for Gen3Pokemon {
+    const PARTY_POKEMON_SIZE: usize = PARTY_POKEMON_SIZE;
+    const CHECKSUM_OFFSET: usize = 0x1C;
+    const SPECIES_OFFSET: usize = 0x20;
+
+    fn decrypt(blob: &[u8]) -> Result<Vec<u8>, ReadError> {
+        decrypt_pokemon_blob(blob)
+    }
+
+    fn checksum(decrypted: &[u8]) -> u16 {
+        pokemon_checksum(decrypted)
+    }
+
+    fn read(reader: &mut BlockReader, diagnostics: &mut Diagnostics) -> Result<Self, ReadError> {
+        Gen3Pokemon::read(reader, diagnostics)
+    }
+
+    fn species(&self) -> Species {
+        self.species
+    }
+
+    fn ot_ids(&self) -> (u16, u16) {
+        (self.ot_id, self.ot_secret_id)
+    }
+
+    fn fill(self, pokemon: &mut Pokemon) {
+        pokemon.set_name(self.nickname);
+        pokemon.set_experience(self.experience);
+        pokemon.set_friendship(self.friendship);
+    }
+}
+
+fn is_pokemon_size(size: usize) -> bool {
+    size == BOX_POKEMON_SIZE || size == PARTY_POKEMON_SIZE
+}
+
+/// The sum of the decrypted substructures as `u16`s
+pub fn pokemon_checksum(blob: &[u8]) -> u16 {
+    blob[0x20..0x50].chunks_exact(2).fold(0u16, |acc, word| acc.wrapping_add(LittleEndian::read_u16(word)))
+}
+
+/// Decrypt an 80 or 100 byte Pokémon, as stored in a save.
+///
+/// The substructures are XORed with the PID XOR the original trainer IDs, and shuffled by the PID modulo
+/// 24 in the same order as gen 4's blocks. They're returned in growth, attacks, EVs, misc order. The
+/// battle stats aren't encrypted.
+pub fn decrypt_pokemon_blob(blob: &[u8]) -> Result<Vec<u8>, ReadError> {
+    if !is_pokemon_size(blob.len()) {
+        return Err(ReadError::InvalidPokemonSize(blob.len()));
+    }
+
+    let pid = LittleEndian::read_u32(&blob[0x00..]);
+    let key = pid ^ LittleEndian::read_u32(&blob[0x04..]);
+    let order = (pid % 24) as usize;
+
+    let mut substructures = blob[0x20..0x50].to_vec();
+    substructures.chunks_exact_mut(4).for_each(|word| {
+        let value = LittleEndian::read_u32(word) ^ key;
+        LittleEndian::write_u32(word, value);
+    });
+
+    // substructure i is stored at position BLOCK_POSITIONS[order * 4 + i]
+    let mut decrypted = blob[..0x20].to_vec();
+    for i in 0..4 {
+        let src_idx = 12 * BLOCK_POSITIONS[order * 4 + i] as usize;
+        decrypted.extend_from_slice(&substructures[src_idx..src_idx + 12]);
+    }
+    decrypted.extend_from_slice(&blob[0x50..]);
+
+    Ok(decrypted)
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    /// Encrypt a decrypted Pokémon, the inverse of `decrypt_pokemon_blob`, for building test saves
+    pub(crate) fn encrypt(decrypted: &[u8]) -> Vec<u8> {
+        let pid = LittleEndian::read_u32(&decrypted[0x00..]);
+        let key = pid ^ LittleEndian::read_u32(&decrypted[0x04..]);
+        let order = (pid % 24) as usize;
+
+        let mut encrypted = decrypted.to_vec();
+        for i in 0..4 {
+            let dest_idx = 0x20 + 12 * BLOCK_POSITIONS[order * 4 + i] as usize;
+            encrypted[dest_idx..dest_idx + 12].copy_from_slice(&decrypted[0x20 + 12 * i..0x20 + 12 * i + 12]);
+        }
+        encrypted[0x20..0x50].chunks_exact_mut(4).for_each(|word| {
+            let value = LittleEndian::read_u32(word) ^ key;
+            LittleEndian::write_u32(word, value);
+        });
+        encrypted
+    }
+
+    /// A decrypted Treecko caught by "Lena", with the checksum filled in
+    pub(crate) fn treecko(pid: u32, party: bool) -> Vec<u8> {
+        let mut data = vec![0u8; if party { PARTY_POKEMON_SIZE } else { BOX_POKEMON_SIZE }];
+        LittleEndian::write_u32(&mut data[0x00..], pid);
+        LittleEndian::write_u16(&mut data[0x04..], 1234);
+        LittleEndian::write_u16(&mut data[0x06..], 5678);
+        data[0x08..0x12].copy_from_slice(&[0xCE, 0xCC, 0xBF, 0xBF, 0xFF, 0, 0, 0, 0, 0]); // TREE
+        data[0x12] = 2;
+        data[0x13] = 0b10;
+        data[0x14..0x1B].copy_from_slice(&[0xC6, 0xD9, 0xE2, 0xD5, 0xFF, 0, 0]); // Lena
+
+        LittleEndian::write_u16(&mut data[0x20..], 277); // Treecko's internal index
+        LittleEndian::write_u16(&mut data[0x22..], 13); // Energy Powder
+        LittleEndian::write_u32(&mut data[0x24..], 135);
+        data[0x28] = 0b0000_0100;
+        data[0x29] = 70;
+        LittleEndian::write_u16(&mut data[0x2C..], 1); // Pound
+        LittleEndian::write_u16(&mut data[0x2E..], 43); // Leer
+        data[0x34] = 35;
+        data[0x35] = 30;
+        data[0x38] = 4; // HP EVs
+        data[0x3D] = 2; // Sp. Def EVs
+        data[0x44] = 0x21;
+        data[0x45] = 16; // Littleroot Town
+        LittleEndian::write_u16(&mut data[0x46..], 5 | (3 << 7) | (4 << 11) | (1 << 15));
+        LittleEndian::write_u32(&mut data[0x48..], 31 | (15 << 25) | (1 << 31));
+        LittleEndian::write_u32(&mut data[0x4C..], 1);
+
+        if party {
+            data[0x54] = 5;
+            LittleEndian::write_u16(&mut data[0x56..], 19);
+            LittleEndian::write_u16(&mut data[0x58..], 20);
+        }
+
+        let checksum = pokemon_checksum(&data);
+        LittleEndian::write_u16(&mut data[0x1C..], checksum);
+        data
+    }
+
+    /// Is every field read from the right place, whichever order the substructures are in?
+    #[test]
+    fn read_pk3() {
+        for pid in [0, 5, 23, 0xDEADBEEF] {
+            let decrypted = treecko(pid, false);
+            let encrypted = encrypt(&decrypted);
+            assert_eq!(decrypt_pokemon_blob(&encrypted).unwrap(), decrypted);
+
+            let pkmn = Gen3Pokemon::from_pk3(&encrypted).unwrap();
+            assert_eq!(Gen3Pokemon::from_pk3(&decrypted).unwrap(), pkmn);
+            assert_eq!(pkmn.pid, pid);
+            assert_eq!((pkmn.ot_id, pkmn.ot_secret_id), (1234, 5678));
+            assert_eq!(pkmn.nickname, "TREE");
+            assert_eq!(pkmn.ot_name, "Lena");
+            assert_eq!(pkmn.species, Species::Treecko);
+            assert_eq!(pkmn.held_item, 13);
+            assert_eq!(pkmn.experience, 135);
+            assert_eq!(pkmn.friendship, 70);
+            assert_eq!(pkmn.moves[0], Move { id: 1, pp: 35, pp_ups: 0 });
+            assert_eq!(pkmn.moves[1], Move { id: 43, pp: 30, pp_ups: 1 });
+            assert_eq!((pkmn.evs.hp, pkmn.evs.sp_defense), (4, 2));
+            assert_eq!(pkmn.pokerus, Pokerus { strain: 2, days: 1 });
+            assert_eq!((pkmn.met_location, pkmn.met_level, pkmn.origin_game, pkmn.ball), (16, 5, 3, 4));
+            assert_eq!(pkmn.ot_gender, Gender::Female);
+            assert_eq!((pkmn.ivs.hp, pkmn.ivs.sp_defense, pkmn.ivs.attack), (31, 15, 0));
+            assert!(!pkmn.is_egg);
+            assert_eq!(pkmn.ability, 1);
+            assert_eq!(pkmn.battle_stats, None);
+        }
+
+        let party = Gen3Pokemon::from_pk3(&encrypt(&treecko(7, true))).unwrap();
+        let stats = party.battle_stats.unwrap();
+        assert_eq!((stats.level, stats.current_hp, stats.max_hp), (5, 19, 20));
+    }
+
+    /// Are bad sizes & checksums rejected?
+    #[test]
+    fn read_invalid_pk3() {
+        assert!(matches!(Gen3Pokemon::from_pk3(&[0; 81]), Err(ReadError::InvalidPokemonSize(81))));
+
+        let mut encrypted = encrypt(&treecko(9, false));
+        encrypted[0x30] ^= 0xFF;
+        assert!(matches!(Gen3Pokemon::from_pk3(&encrypted), Err(ReadError::InvalidValue { field: "checksum", .. })));
+    }
+}
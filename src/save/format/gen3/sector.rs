@@ -0,0 +1,186 @@
+use crate::save::error::{Block, ReadError};
+use crate::save::format::BlockStatus;
+use byteorder::{ByteOrder, LittleEndian};
+
+pub const SECTOR_SIZE: usize = 0x1000;
+/// Each of the two saves is split into this many sectors, one per section
+pub const SECTOR_COUNT: usize = 14;
+/// Every gen 3 `.sav` holds two full saves, followed by the Hall of Fame & other extras
+pub const SAVE_SIZE: usize = 0x20000;
+
+/// Stored little endian, so this appears as `25 20 01 08` in the file
+pub const SECTOR_SIGNATURE: u32 = 0x08012025;
+
+/// The number of bytes of each section, by ID, that hold data (and are checksummed)
+pub const SECTION_SIZES: [usize; SECTOR_COUNT] = [
+    3884, 3968, 3968, 3968, 3848, 3968, 3968, 3968, 3968, 3968, 3968, 3968, 3968, 2000,
+];
+
+/// The footer found at `0xFF4` in every sector.
+///
+/// See the "Sectors" section of `doc/format/gen3/gen3.md`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SectorFooter {
+    /// Which section this sector holds
+    pub section_id: u16,
+    pub checksum: u16,
+    pub signature: u32,
+    /// Incremented every time the game saves
+    pub save_index: u32,
+}
+
+impl SectorFooter {
+    /// Parse the footer of `sector`, which must be `SECTOR_SIZE` long
+    pub fn from_sector(sector: &[u8]) -> Self {
+        Self {
+            section_id: LittleEndian::read_u16(&sector[0xFF4..]),
+            checksum: LittleEndian::read_u16(&sector[0xFF6..]),
+            signature: LittleEndian::read_u32(&sector[0xFF8..]),
+            save_index: LittleEndian::read_u32(&sector[0xFFC..]),
+        }
+    }
+}
+
+/// The 32 bit sum of `data`, folded into 16 bits
+pub fn sector_checksum(data: &[u8]) -> u16 {
+    let sum = data.chunks_exact(4).fold(0u32, |acc, word| acc.wrapping_add(LittleEndian::read_u32(word)));
+    ((sum >> 16) as u16).wrapping_add(sum as u16)
+}
+
+/// Validate a single sector
+pub fn validate_sector(sector: &[u8]) -> BlockStatus {
+    let footer = SectorFooter::from_sector(sector);
+    if footer.signature != SECTOR_SIGNATURE {
+        return BlockStatus::BadSignature;
+    }
+
+    let Some(size) = SECTION_SIZES.get(footer.section_id as usize) else {
+        return BlockStatus::BadSize;
+    };
+
+    let computed = sector_checksum(&sector[..*size]);
+    if computed != footer.checksum {
+        return BlockStatus::BadChecksum { stored: footer.checksum, computed };
+    }
+
+    BlockStatus::Valid
+}
+
+/// Where each section of the chosen save is. The sectors of a save are rotated by one every time the
+/// game saves, so they can be in any order.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Sections {
+    /// `0` for the first save in the file, `1` for the second
+    pub slot: usize,
+    pub save_index: u32,
+    /// Absolute offset of each sector, by section ID
+    offsets: [usize; SECTOR_COUNT],
+}
+
+impl Sections {
+    /// Absolute offset of the sector holding section `id`
+    pub fn offset(&self, id: usize) -> usize {
+        self.offsets[id]
+    }
+
+    /// The data of section `id`, without its footer or unused bytes
+    pub fn data<'a>(&self, data: &'a [u8], id: usize) -> &'a [u8] {
+        &data[self.offsets[id]..self.offsets[id] + SECTION_SIZES[id]]
+    }
+
+    /// Find every section of save `slot`, or the ID & status of the first invalid sector
+    fn find(data: &[u8], slot: usize) -> Result<Self, (usize, BlockStatus)> {
+        let mut offsets = [None; SECTOR_COUNT];
+        let mut save_index = 0;
+        for i in 0..SECTOR_COUNT {
+            let offset = (slot * SECTOR_COUNT + i) * SECTOR_SIZE;
+            let Some(sector) = data.get(offset..offset + SECTOR_SIZE) else {
+                return Err((i, BlockStatus::Missing));
+            };
+
+            let status = validate_sector(sector);
+            if status != BlockStatus::Valid {
+                return Err((i, status));
+            }
+
+            let footer = SectorFooter::from_sector(sector);
+            offsets[footer.section_id as usize] = Some(offset);
+            save_index = footer.save_index;
+        }
+
+        // a section that appears twice means another is missing
+        match offsets.iter().position(Option::is_none) {
+            Some(id) => Err((id, BlockStatus::Missing)),
+            None => Ok(Self { slot, save_index, offsets: offsets.map(Option::unwrap) }),
+        }
+    }
+}
+
+/// Choose the save with the highest save index whose sectors are all valid
+pub fn select_sections(data: &[u8]) -> Result<Sections, ReadError> {
+    match (Sections::find(data, 0), Sections::find(data, 1)) {
+        (Ok(first), Ok(second)) if second.save_index > first.save_index => Ok(second),
+        (Ok(first), _) => Ok(first),
+        (_, Ok(second)) => Ok(second),
+        (Err((id, first)), Err((_, second))) => Err(ReadError::NoValidBlock {
+            block: Block::Sector(id as u16),
+            offset: id * SECTOR_SIZE,
+            first,
+            second,
+        }),
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    /// A save with every section filled with `fill`, rotated by `rotation`
+    pub(crate) fn make_slot(save_index: u32, rotation: usize, fill: impl Fn(usize) -> Vec<u8>) -> Vec<u8> {
+        let mut slot = vec![0u8; SECTOR_COUNT * SECTOR_SIZE];
+        for id in 0..SECTOR_COUNT {
+            let sector = &mut slot[((id + rotation) % SECTOR_COUNT) * SECTOR_SIZE..][..SECTOR_SIZE];
+            let mut contents = fill(id);
+            contents.resize(SECTION_SIZES[id], 0);
+            sector[..SECTION_SIZES[id]].copy_from_slice(&contents);
+
+            LittleEndian::write_u16(&mut sector[0xFF4..], id as u16);
+            LittleEndian::write_u16(&mut sector[0xFF6..], sector_checksum(&contents));
+            LittleEndian::write_u32(&mut sector[0xFF8..], SECTOR_SIGNATURE);
+            LittleEndian::write_u32(&mut sector[0xFFC..], save_index);
+        }
+        slot
+    }
+
+    /// Is the newest valid save chosen, with its sections found wherever they've rotated to?
+    #[test]
+    fn select_newest_save() {
+        let mut data = make_slot(4, 3, |id| vec![id as u8; 4]);
+        data.extend(make_slot(5, 7, |id| vec![id as u8 + 100; 4]));
+
+        let sections = select_sections(&data).unwrap();
+        assert_eq!(sections.slot, 1);
+        assert_eq!(sections.save_index, 5);
+        assert_eq!(sections.offset(0), (SECTOR_COUNT + 7) * SECTOR_SIZE);
+        assert_eq!(&sections.data(&data, 13)[..4], &[113; 4]);
+
+        // a bad checksum in the newer save falls back to the older one
+        data[(SECTOR_COUNT + 7) * SECTOR_SIZE] ^= 0xFF;
+        let sections = select_sections(&data).unwrap();
+        assert_eq!(sections.slot, 0);
+        assert_eq!(sections.data(&data, 0)[0], 0);
+    }
+
+    /// Is a file with no complete save an error?
+    #[test]
+    fn select_without_valid_save() {
+        let mut data = make_slot(1, 0, |_| Vec::new());
+        data.resize(SAVE_SIZE, 0xFF);
+        data[0xFF4] = 1; // two section 1s, and no section 0
+
+        assert!(matches!(
+            select_sections(&data),
+            Err(ReadError::NoValidBlock { block: Block::Sector(0), first: BlockStatus::Missing, second: BlockStatus::BadSignature, .. })
+        ));
+    }
+}
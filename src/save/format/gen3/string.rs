@@ -0,0 +1,66 @@
+// character tables; see https://bulbapedia.bulbagarden.net/wiki/Character_encoding_(Generation_III)
+// control codes (`0xF7`-`0xFE`) and characters we don't have a `char` for are read as `NUL`
+const NUL: char = '\u{FFFF}';
+/// Ends a string, unless it fills its field
+const TERMINATOR: u8 = 0xFF;
+
+const INTERNATIONAL_TABLE: [char; 256] = [
+    ' ', 'À', 'Á', 'Â', 'Ç', 'È', 'É', 'Ê', 'Ë', 'Ì', NUL, 'Î', 'Ï', 'Ò', 'Ó', 'Ô', // 00-0F
+    'Œ', 'Ù', 'Ú', 'Û', 'Ñ', 'ß', 'à', 'á', NUL, 'ç', 'è', 'é', 'ê', 'ë', 'ì', NUL, // 10-1F
+    'î', 'ï', 'ò', 'ó', 'ô', 'œ', 'ù', 'ú', 'û', 'º', 'ª', NUL, '&', '+', NUL, NUL, // 20-2F
+    NUL, NUL, NUL, NUL, NUL, '=', ';', NUL, NUL, NUL, NUL, NUL, NUL, NUL, NUL, NUL, // 30-3F
+    NUL, NUL, NUL, NUL, NUL, NUL, NUL, NUL, NUL, NUL, NUL, NUL, NUL, NUL, NUL, NUL, // 40-4F
+    NUL, '¿', '¡', NUL, NUL, NUL, NUL, NUL, NUL, NUL, 'Í', '%', '(', ')', NUL, NUL, // 50-5F
+    NUL, NUL, NUL, NUL, NUL, NUL, NUL, NUL, 'â', NUL, NUL, NUL, NUL, NUL, NUL, 'í', // 60-6F
+    NUL, NUL, NUL, NUL, NUL, NUL, NUL, NUL, NUL, '↑', '↓', '←', '→', NUL, NUL, NUL, // 70-7F
+    NUL, NUL, NUL, NUL, NUL, NUL, NUL, NUL, NUL, NUL, NUL, NUL, NUL, NUL, NUL, NUL, // 80-8F
+    NUL, NUL, NUL, NUL, NUL, NUL, NUL, NUL, NUL, NUL, NUL, NUL, NUL, NUL, NUL, NUL, // 90-9F
+    NUL, '0', '1', '2', '3', '4', '5', '6', '7', '8', '9', '!', '?', '.', '-', '・', // A0-AF
+    '…', '“', '”', '‘', '’', '♂', '♀', NUL, ',', '×', '/', 'A', 'B', 'C', 'D', 'E', // B0-BF
+    'F', 'G', 'H', 'I', 'J', 'K', 'L', 'M', 'N', 'O', 'P', 'Q', 'R', 'S', 'T', 'U', // C0-CF
+    'V', 'W', 'X', 'Y', 'Z', 'a', 'b', 'c', 'd', 'e', 'f', 'g', 'h', 'i', 'j', 'k', // D0-DF
+    'l', 'm', 'n', 'o', 'p', 'q', 'r', 's', 't', 'u', 'v', 'w', 'x', 'y', 'z', '▶', // E0-EF
+    ':', 'Ä', 'Ö', 'Ü', 'ä', 'ö', 'ü', NUL, NUL, NUL, NUL, NUL, NUL, NUL, NUL, NUL, // F0-FF
+];
+
+const JAPANESE_TABLE: [char; 256] = [
+    '　', 'あ', 'い', 'う', 'え', 'お', 'か', 'き', 'く', 'け', 'こ', 'さ', 'し', 'す', 'せ', 'そ', // 00-0F
+    'た', 'ち', 'つ', 'て', 'と', 'な', 'に', 'ぬ', 'ね', 'の', 'は', 'ひ', 'ふ', 'へ', 'ほ', 'ま', // 10-1F
+    'み', 'む', 'め', 'も', 'や', 'ゆ', 'よ', 'ら', 'り', 'る', 'れ', 'ろ', 'わ', 'を', 'ん', 'ぁ', // 20-2F
+    'ぃ', 'ぅ', 'ぇ', 'ぉ', 'ゃ', 'ゅ', 'ょ', 'が', 'ぎ', 'ぐ', 'げ', 'ご', 'ざ', 'じ', 'ず', 'ぜ', // 30-3F
+    'ぞ', 'だ', 'ぢ', 'づ', 'で', 'ど', 'ば', 'び', 'ぶ', 'べ', 'ぼ', 'ぱ', 'ぴ', 'ぷ', 'ぺ', 'ぽ', // 40-4F
+    'っ', 'ア', 'イ', 'ウ', 'エ', 'オ', 'カ', 'キ', 'ク', 'ケ', 'コ', 'サ', 'シ', 'ス', 'セ', 'ソ', // 50-5F
+    'タ', 'チ', 'ツ', 'テ', 'ト', 'ナ', 'ニ', 'ヌ', 'ネ', 'ノ', 'ハ', 'ヒ', 'フ', 'ヘ', 'ホ', 'マ', // 60-6F
+    'ミ', 'ム', 'メ', 'モ', 'ヤ', 'ユ', 'ヨ', 'ラ', 'リ', 'ル', 'レ', 'ロ', 'ワ', 'ヲ', 'ン', 'ァ', // 70-7F
+    'ィ', 'ゥ', 'ェ', 'ォ', 'ャ', 'ュ', 'ョ', 'ガ', 'ギ', 'グ', 'ゲ', 'ゴ', 'ザ', 'ジ', 'ズ', 'ゼ', // 80-8F
+    'ゾ', 'ダ', 'ヂ', 'ヅ', 'デ', 'ド', 'バ', 'ビ', 'ブ', 'ベ', 'ボ', 'パ', 'ピ', 'プ', 'ペ', 'ポ', // 90-9F
+    'ッ', '０', '１', '２', '３', '４', '５', '６', '７', '８', '９', '！', '？', '。', 'ー', '・', // A0-AF
+    '‥', '『', '』', '「', '」', '♂', '♀', '円', '．', '×', '／', 'Ａ', 'Ｂ', 'Ｃ', 'Ｄ', 'Ｅ', // B0-BF
+    'Ｆ', 'Ｇ', 'Ｈ', 'Ｉ', 'Ｊ', 'Ｋ', 'Ｌ', 'Ｍ', 'Ｎ', 'Ｏ', 'Ｐ', 'Ｑ', 'Ｒ', 'Ｓ', 'Ｔ', 'Ｕ', // C0-CF
+    'Ｖ', 'Ｗ', 'Ｘ', 'Ｙ', 'Ｚ', 'ａ', 'ｂ', 'ｃ', 'ｄ', 'ｅ', 'ｆ', 'ｇ', 'ｈ', 'ｉ', 'ｊ', 'ｋ', // D0-DF
+    'ｌ', 'ｍ', 'ｎ', 'ｏ', 'ｐ', 'ｑ', 'ｒ', 'ｓ', 'ｔ', 'ｕ', 'ｖ', 'ｗ', 'ｘ', 'ｙ', 'ｚ', '▶', // E0-EF
+    '：', 'Ä', 'Ö', 'Ü', 'ä', 'ö', 'ü', NUL, NUL, NUL, NUL, NUL, NUL, NUL, NUL, NUL, // F0-FF
+];
+
+/// Decode a gen 3 string, up to its terminator. Japanese games use their own table.
+pub(crate) fn decode(bytes: &[u8], japanese: bool) -> String {
+    let table = match japanese {
+        true => &JAPANESE_TABLE,
+        false => &INTERNATIONAL_TABLE,
+    };
+    bytes.iter().take_while(|b| **b != TERMINATOR).map(|b| table[*b as usize]).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Can we decode international & Japanese names, stopping at the terminator?
+    #[test]
+    fn decode_strings() {
+        assert_eq!(decode(&[0xC6, 0xD9, 0xE2, 0xD5, 0xFF, 0x00, 0x00], false), "Lena");
+        assert_eq!(decode(&[0xC7, 0xCA, 0xBE, 0xA1, 0xAB], false), "MPD0!");
+        assert_eq!(decode(&[0x06, 0x00, 0x1B], false), "É é");
+        assert_eq!(decode(&[0x0A, 0x7C, 0xA0, 0xFF], true), "こワッ");
+    }
+}
@@ -0,0 +1,111 @@
+use crate::save::error::{Block, ReadError};
+use crate::save::format::dppt::block::crc16_ccitt;
+use crate::save::format::BlockStatus;
+use byteorder::{ByteOrder, LittleEndian};
+
+/// Every gen 5 `.sav` is 512 KiB, though only the first half or so is used
+pub const SAVE_SIZE: usize = 0x80000;
+
+/// Where a block is, and how many bytes of it are checksummed.
+///
+/// See the "Blocks" section of `doc/format/gen5/gen5.md`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BlockInfo {
+    pub offset: usize,
+    pub length: usize,
+    checksum: usize,
+}
+
+impl BlockInfo {
+    /// Each block is followed by a footer, with the CRC-16 of the block 2 bytes in
+    pub const fn new(offset: usize, length: usize) -> Self {
+        Self { offset, length, checksum: offset + length + 2 }
+    }
+
+    /// A block whose checksum isn't in the usual place, like the checksum table's
+    pub const fn with_checksum(offset: usize, length: usize, checksum: usize) -> Self {
+        Self { offset, length, checksum }
+    }
+
+    pub fn checksum_offset(&self) -> usize {
+        self.checksum
+    }
+
+    /// The copy of this block `backup` bytes further on
+    pub fn at_backup(&self, backup: usize) -> Self {
+        Self { offset: self.offset + backup, length: self.length, checksum: self.checksum + backup }
+    }
+}
+
+/// Validate a single copy of a block within the whole save `data`
+pub fn validate_block(data: &[u8], info: BlockInfo) -> BlockStatus {
+    let (Some(block), Some(stored)) = (data.get(info.offset..info.offset + info.length), data.get(info.checksum_offset()..info.checksum_offset() + 2)) else {
+        return BlockStatus::Missing;
+    };
+
+    let stored = LittleEndian::read_u16(stored);
+    let computed = crc16_ccitt(block);
+    if computed != stored {
+        return BlockStatus::BadChecksum { stored, computed };
+    }
+
+    BlockStatus::Valid
+}
+
+/// Which copy of a block was read
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BlockCopy {
+    Main,
+    /// The main copy failed validation
+    Backup,
+}
+
+/// Choose the main copy of a block if it's valid, or else the backup, `backup` bytes further on. Gives
+/// where the chosen copy is.
+pub fn select_block(data: &[u8], block: Block, info: BlockInfo, backup: usize) -> Result<(BlockCopy, BlockInfo), ReadError> {
+    let main = validate_block(data, info);
+    if main == BlockStatus::Valid {
+        return Ok((BlockCopy::Main, info));
+    }
+
+    let backup_info = info.at_backup(backup);
+    match validate_block(data, backup_info) {
+        BlockStatus::Valid => Ok((BlockCopy::Backup, backup_info)),
+        second => Err(ReadError::NoValidBlock { block, offset: info.offset, first: main, second }),
+    }
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    /// Write `contents` into the block at `info`, with its checksum
+    pub(crate) fn write_block(data: &mut [u8], info: BlockInfo, contents: &[u8]) {
+        let block = &mut data[info.offset..info.offset + info.length];
+        block.fill(0);
+        block[..contents.len()].copy_from_slice(contents);
+        let checksum = crc16_ccitt(block);
+        LittleEndian::write_u16(&mut data[info.checksum_offset()..], checksum);
+    }
+
+    /// Is the backup only used when the main copy is invalid?
+    #[test]
+    fn select_main_or_backup() {
+        let info = BlockInfo::new(0x100, 0x40);
+        let mut data = vec![0u8; 0x400];
+        write_block(&mut data, info, &[1; 0x40]);
+        write_block(&mut data, info.at_backup(0x200), &[2; 0x40]);
+
+        assert_eq!(select_block(&data, Block::Player, info, 0x200).unwrap(), (BlockCopy::Main, info));
+
+        data[0x100] = 0xFF;
+        assert_eq!(select_block(&data, Block::Player, info, 0x200).unwrap(), (BlockCopy::Backup, BlockInfo::new(0x300, 0x40)));
+
+        data[0x300] = 0xFF;
+        assert!(matches!(
+            select_block(&data, Block::Player, info, 0x200),
+            Err(ReadError::NoValidBlock { block: Block::Player, offset: 0x100, first: BlockStatus::BadChecksum { .. }, second: BlockStatus::BadChecksum { .. } })
+        ));
+        assert_eq!(validate_block(&data[..0x120], info), BlockStatus::Missing);
+    }
+}
@@ -0,0 +1,424 @@
+pub mod block;
+pub mod pokemon;
+mod string;
+
+use crate::save::data::badge::{Badge, Badges};
+use crate::save::data::item::Item;
+use crate::save::error::{Block, Diagnostic, ReadError, UnknownValue};
+use crate::save::format::container::Container;
+use crate::save::format::gen5::block::{select_block, validate_block, BlockCopy, BlockInfo, SAVE_SIZE};
+use crate::save::format::gen5::pokemon::{Gen5Pokemon, BOX_POKEMON_SIZE};
+use crate::save::format::reader::{BlockReader, Diagnostics};
+use crate::save::format::{self, BlockStatus, CorruptSlot, ParseOptions, SlotLocation};
+use crate::save::save::{Gender, Playtime, SaveFile, SaveGame, Trainer};
+use std::io::{Read, Seek, SeekFrom};
+use std::path::PathBuf;
+
+const BOX_COUNT: usize = 24;
+/// The current box, box names & wallpapers
+const BOX_INFO: BlockInfo = BlockInfo::new(0x00000, 0x3E0);
+const ITEMS: BlockInfo = BlockInfo::new(0x18400, 0x9EC);
+const PARTY: BlockInfo = BlockInfo::new(0x18E00, 0x534);
+
+/// Each box has a block of its own, with 30 Pokémon
+const fn box_block(i: usize) -> BlockInfo {
+    BlockInfo::new(0x400 + i * 0x1000, 30 * BOX_POKEMON_SIZE)
+}
+
+/// The capacity of each bag pocket, in the order they're stored: items, key items, TMs & HMs, medicine
+/// and berries
+const POCKET_CAPACITIES: [usize; 5] = [310, 83, 109, 48, 64];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Game {
+    Black,
+    White,
+    Black2,
+    White2,
+}
+
+impl TryFrom<u8> for Game {
+    type Error = UnknownValue<u8>;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            20 => Ok(Game::White),
+            21 => Ok(Game::Black),
+            22 => Ok(Game::White2),
+            23 => Ok(Game::Black2),
+            _ => Err(UnknownValue { name: "Game", value }),
+        }
+    }
+}
+
+/// Where the blocks that differ between BW & B2W2 are
+pub(crate) struct Layout {
+    /// Holds a copy of every block's checksum; its own is used to tell the two layouts apart
+    pub checksum_table: BlockInfo,
+    /// How far after each block its backup copy is
+    pub backup: usize,
+    /// Money & badges
+    pub misc: BlockInfo,
+    /// The trainer's name, IDs, game & playtime, which B2W2 made longer
+    pub trainer: BlockInfo,
+    /// The badges in the misc block, in the order of their bits
+    pub badges: &'static [Badge; 8],
+    pub games: &'static [Game],
+}
+
+/// As PKHeX's `SAV5BW` & `SAV5B2W2` block tables
+pub(crate) const BW_LAYOUT: Layout = Layout {
+    checksum_table: BlockInfo::with_checksum(0x23F00, 0x8C, 0x23F9A),
+    backup: 0x24000,
+    misc: BlockInfo::new(0x21200, 0xEC),
+    trainer: BlockInfo::new(0x19400, 0x68),
+    badges: &Badge::UNOVA_BW,
+    games: &[Game::Black, Game::White],
+};
+
+pub(crate) const B2W2_LAYOUT: Layout = Layout {
+    checksum_table: BlockInfo::with_checksum(0x25F00, 0x94, 0x25FA2),
+    backup: 0x26000,
+    misc: BlockInfo::new(0x21100, 0xF0),
+    trainer: BlockInfo::new(0x19400, 0xB0),
+    badges: &Badge::UNOVA_B2W2,
+    games: &[Game::Black2, Game::White2],
+};
+
+impl Layout {
+    /// The layout whose checksum table has a valid copy in `data`, an unwrapped save
+    pub fn detect(data: &[u8]) -> Option<&'static Layout> {
+        [&BW_LAYOUT, &B2W2_LAYOUT].into_iter().find(|layout| {
+            validate_block(data, layout.checksum_table) == BlockStatus::Valid
+                || validate_block(data, layout.checksum_table.at_backup(layout.backup)) == BlockStatus::Valid
+        })
+    }
+}
+
+/// A Black, White, Black 2 or White 2 save
+///
+/// Its items are `Item::Gen5`, including those gen 4 doesn't have.
+#[derive(Debug, PartialEq)]
+pub struct Gen5Save {
+    pub game: Game,
+    pub base: SaveFile,
+    pub playtime: Playtime,
+    pub badges: Vec<Badge>,
+    /// Blocks whose main copy failed validation, so their backup was read instead
+    pub backup_blocks: Vec<BlockInfo>,
+    pub container: Container,
+    /// Pokémon that couldn't be read, in lenient mode
+    pub corrupt_slots: Vec<CorruptSlot>,
+    /// Everything that was skipped over or replaced, in lenient mode
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+impl SaveGame for Gen5Save {
+    fn base(&self) -> &SaveFile {
+        &self.base
+    }
+
+    fn playtime(&self) -> Playtime {
+        self.playtime
+    }
+
+    fn badges(&self) -> &[Badge] {
+        &self.badges
+    }
+}
+
+/// Read a gen 5 save from `save_file`
+pub fn read_save(save_file: impl Into<PathBuf>) -> Result<Gen5Save, ReadError> {
+    read_save_with_options(save_file, ParseOptions::default())
+}
+
+/// Read a gen 5 save from `save_file`, see `ParseOptions`
+pub fn read_save_with_options(save_file: impl Into<PathBuf>, options: ParseOptions) -> Result<Gen5Save, ReadError> {
+    format::read_file(save_file, options, read_save_from_bytes)
+}
+
+/// Read a gen 5 save from `reader`, starting at its current position
+pub fn read_save_from_reader<R: Read + Seek>(reader: &mut R) -> Result<Gen5Save, ReadError> {
+    read_save_from_reader_with_options(reader, ParseOptions::default())
+}
+
+/// Read a gen 5 save from `reader`, starting at its current position. See `ParseOptions`.
+pub fn read_save_from_reader_with_options<R: Read + Seek>(reader: &mut R, options: ParseOptions) -> Result<Gen5Save, ReadError> {
+    format::read_reader(reader, options, read_save_from_bytes)
+}
+
+/// Read a gen 5 save from the contents of a `.sav`, which can be in any `Container`.
+///
+/// BW & B2W2 are told apart by which layout's checksum table is valid; if neither is, it's read as BW,
+/// to report why. Each block is read from its main copy, or its backup if the main copy is invalid.
+pub(crate) fn read_save_from_bytes(data: Vec<u8>, options: ParseOptions) -> Result<Gen5Save, ReadError> {
+    let mut diagnostics = Diagnostics::new(&options);
+    let (container, data) = Container::unwrap(data, SAVE_SIZE);
+    let layout = Layout::detect(&data).unwrap_or(&BW_LAYOUT);
+
+    let mut backup_blocks = Vec::new();
+    let mut select = |block: Block, info: BlockInfo| -> Result<BlockReader, ReadError> {
+        let (copy, chosen) = select_block(&data, block, info, layout.backup)?;
+        if copy == BlockCopy::Backup {
+            backup_blocks.push(info);
+        }
+        Ok(BlockReader::new(&data[chosen.offset..chosen.offset + chosen.length], chosen.offset, block))
+    };
+
+    // TRAINER BLOCK
+    let mut trainer_info = select(Block::Player, layout.trainer)?;
+    trainer_info.seek(SeekFrom::Start(0x04));
+    let trainer_name = string::decode(&trainer_info.read_u16s("trainer name", 8)?);
+    let trainer_id = trainer_info.read_u16("trainer ID")?;
+    let trainer_secret_id = trainer_info.read_u16("trainer secret ID")?;
+    trainer_info.seek(SeekFrom::Start(0x1E));
+    let _language = trainer_info.read_u8("language")?;
+    let game_code = trainer_info.read_u8("game code")?;
+    let game = match Game::try_from(game_code) {
+        Ok(game) if layout.games.contains(&game) => game,
+        _ => {
+            diagnostics.report(trainer_info.diagnostic("game code", game_code))?;
+            layout.games[0]
+        }
+    };
+    trainer_info.seek(SeekFrom::Start(0x21));
+    let trainer_gender = match trainer_info.read_u8("gender")? {
+        0 => Gender::Male,
+        1 => Gender::Female,
+        other => {
+            diagnostics.report(trainer_info.diagnostic("gender", other))?;
+            Gender::Male
+        }
+    };
+    trainer_info.seek(SeekFrom::Start(0x24));
+    let playtime = Playtime {
+        hours: trainer_info.read_u16("playtime hours")?,
+        minutes: trainer_info.read_u8("playtime minutes")?,
+        seconds: trainer_info.read_u8("playtime seconds")?,
+    };
+
+    let trainer = Trainer::new(trainer_name, trainer_id, Some(trainer_secret_id), trainer_gender);
+
+    // MISC BLOCK
+    let mut misc = select(Block::Player, layout.misc)?;
+    let money = misc.read_u32("money")?;
+    let badges = Badges::from_bits(misc.read_u8("badges")?, layout.badges).0;
+    let mut base_save = SaveFile::new(trainer.clone(), money);
+
+    // PARTY BLOCK
+    let mut party = select(Block::Party, PARTY)?;
+    party.seek(SeekFrom::Start(0x04));
+    let party_count = party.read_u32("party count")?;
+    if party_count > 6 {
+        diagnostics.report(party.diagnostic("party count", party_count))?;
+    }
+
+    let mut corrupt_slots = Vec::new();
+    base_save.party = format::read_party::<Gen5Pokemon>(&mut party, party_count.min(6) as usize, Block::Party, &trainer, &mut diagnostics, &mut corrupt_slots)?;
+
+    // BAG BLOCK
+    let mut bag = select(Block::Bag, ITEMS)?;
+    for capacity in POCKET_CAPACITIES {
+        for _i in 0..capacity {
+            let item_id = bag.read_u16("item")?;
+            let qty = bag.read_u16("item quantity")?;
+            if item_id == 0 {
+                continue;
+            }
+
+            let item = Item::Gen5(item_id);
+            if qty > Item::MAX_QUANTITY {
+                diagnostics.report(bag.diagnostic("item quantity", qty))?;
+            }
+            // the game keeps one stack of each item, so a second is combined with the first
+            if base_save.get_item(item).is_some() {
+                diagnostics.report(bag.diagnostic("duplicate item", item_id))?;
+            }
+            base_save.add_item(item, qty);
+        }
+    }
+
+    // BOX BLOCKS
+    let mut box_info = select(Block::Storage, BOX_INFO)?;
+    let _current_box = box_info.read_u8("current box")?;
+    for i in 0..BOX_COUNT {
+        let mut pkmn_box = crate::save::save::Box::new(30);
+        let mut storage = select(Block::Storage, box_block(i))?;
+        for j in 0..30 {
+            let offset = storage.offset();
+            let buf = storage.read_bytes("box Pokémon", BOX_POKEMON_SIZE)?;
+            if let Some(pkmn) = format::read_slot::<Gen5Pokemon>(buf, offset, Block::Storage, SlotLocation::Box(i, j), &trainer, &mut diagnostics, &mut corrupt_slots)? {
+                pkmn_box.set_pkmn(j, pkmn);
+            }
+        }
+
+        box_info.seek(SeekFrom::Start((0x04 + i * 0x28) as u64));
+        pkmn_box.set_name(string::decode(&box_info.read_u16s("box name", 10)?));
+        box_info.seek(SeekFrom::Start((0x3C4 + i) as u64));
+        pkmn_box.set_wallpaper(box_info.read_u8("box wallpaper")?);
+
+        base_save.boxes.push(pkmn_box);
+    }
+
+    Ok(Gen5Save {
+        game,
+        base: base_save,
+        playtime,
+        badges,
+        backup_blocks,
+        container,
+        corrupt_slots,
+        diagnostics: diagnostics.into_inner(),
+    })
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::save::data::pocket::Pocket;
+    use crate::save::data::species::Species;
+    use crate::save::format::dppt::pokemon::encrypt_blob;
+    use crate::save::format::gen5::block::tests::write_block;
+    use crate::save::format::gen5::pokemon::tests::snivy;
+    use byteorder::{ByteOrder, LittleEndian};
+
+    /// A save by "Hilda" from `game`, with a Snivy in the party & the last box, Leftovers in the bag and
+    /// the first two badges
+    pub(crate) fn test_save_bytes(game: Game) -> Vec<u8> {
+        fn utf16(text: &str) -> Vec<u8> {
+            text.encode_utf16().chain([0xFFFF]).flat_map(|w| w.to_le_bytes()).collect()
+        }
+
+        let layout = match game {
+            Game::Black | Game::White => &BW_LAYOUT,
+            Game::Black2 | Game::White2 => &B2W2_LAYOUT,
+        };
+        let mut data = vec![0u8; SAVE_SIZE];
+
+        let mut trainer = vec![0u8; layout.trainer.length];
+        trainer[0x04..0x10].copy_from_slice(&utf16("Hilda"));
+        LittleEndian::write_u16(&mut trainer[0x14..], 12345);
+        LittleEndian::write_u16(&mut trainer[0x16..], 54321);
+        trainer[0x1E] = 2;
+        trainer[0x1F] = match game {
+            Game::White => 20,
+            Game::Black => 21,
+            Game::White2 => 22,
+            Game::Black2 => 23,
+        };
+        trainer[0x21] = 1;
+        LittleEndian::write_u16(&mut trainer[0x24..], 99);
+        trainer[0x26] = 59;
+        trainer[0x27] = 1;
+        write_block(&mut data, layout.trainer, &trainer);
+
+        let mut misc = vec![0u8; 5];
+        LittleEndian::write_u32(&mut misc, 123_456);
+        misc[0x04] = 0b11;
+        write_block(&mut data, layout.misc, &misc);
+
+        let mut party = vec![0u8; 0x08];
+        party[0x04] = 1;
+        party.extend(encrypt_blob(&snivy(0x1234_5678, true)));
+        write_block(&mut data, PARTY, &party);
+
+        let mut items = vec![0u8; 8];
+        LittleEndian::write_u16(&mut items[0..], 234);
+        LittleEndian::write_u16(&mut items[2..], 2);
+        LittleEndian::write_u16(&mut items[4..], 580); // a gen 5 item
+        LittleEndian::write_u16(&mut items[6..], 1);
+        write_block(&mut data, ITEMS, &items);
+
+        let mut box_info = vec![0u8; BOX_INFO.length];
+        for i in 0..BOX_COUNT {
+            let name = utf16(&format!("Box {}", i + 1));
+            box_info[0x04 + i * 0x28..0x04 + i * 0x28 + name.len()].copy_from_slice(&name);
+            box_info[0x3C4 + i] = i as u8;
+        }
+        write_block(&mut data, BOX_INFO, &box_info);
+        for i in 0..BOX_COUNT {
+            let mut contents = vec![0u8; box_block(i).length];
+            if i == BOX_COUNT - 1 {
+                contents[29 * BOX_POKEMON_SIZE..].copy_from_slice(&encrypt_blob(&snivy(7, false)));
+            }
+            write_block(&mut data, box_block(i), &contents);
+        }
+
+        write_block(&mut data, layout.checksum_table, &[]);
+        data
+    }
+
+    /// Does a save from each game map into the generic save?
+    #[test]
+    fn read_gen5_save() {
+        for game in [Game::Black, Game::White, Game::Black2, Game::White2] {
+            let save = read_save_from_bytes(test_save_bytes(game), ParseOptions::default()).unwrap();
+            assert_eq!(save.game, game);
+            assert_eq!(save.playtime, Playtime { hours: 99, minutes: 59, seconds: 1 });
+            assert!(save.backup_blocks.is_empty());
+
+            let trainer = save.base.trainer();
+            assert_eq!(trainer.name(), "Hilda");
+            assert_eq!((trainer.id(), trainer.secret_id()), (12345, Some(54321)));
+            assert_eq!(trainer.gender(), &Gender::Female);
+            assert_eq!(save.base.money(), 123_456);
+
+            assert_eq!(save.base.party.len(), 1);
+            assert_eq!(save.base.party[0].species(), &Species::Snivy);
+            assert_eq!(save.base.party[0].name(), "Leafy");
+            assert_eq!(save.base.party[0].trainer(), Some(trainer));
+
+            assert_eq!(save.base.get_item(Item::Gen5(234)), Some(&2));
+            assert_eq!(save.base.get_item(Item::Gen5(580)), Some(&1));
+            assert_eq!(save.base.pocket(Pocket::Items), vec![(Item::Gen5(234), 2), (Item::Gen5(580), 1)]);
+            let badges = match game {
+                Game::Black | Game::White => vec![Badge::Trio, Badge::Basic],
+                Game::Black2 | Game::White2 => vec![Badge::Basic, Badge::Toxic],
+            };
+            assert_eq!(save.badges, badges);
+
+            assert_eq!(save.base.boxes.len(), BOX_COUNT);
+            assert_eq!(save.base.boxes[23].get_pkmn(29).unwrap().experience(), 135);
+            assert_eq!(save.base.boxes[23].name(), "Box 24");
+            assert_eq!(save.base.boxes[5].wallpaper(), 5);
+        }
+    }
+
+    /// Is a block with a bad checksum read from its backup, or reported if that's bad too?
+    #[test]
+    fn read_backup_block() {
+        let mut data = test_save_bytes(Game::White);
+        let misc = BW_LAYOUT.misc;
+        data.copy_within(misc.offset..misc.checksum_offset() + 2, misc.offset + BW_LAYOUT.backup);
+        data[misc.offset] ^= 0xFF;
+
+        let save = read_save_from_bytes(data.clone(), ParseOptions::default()).unwrap();
+        assert_eq!(save.base.money(), 123_456);
+        assert_eq!(save.backup_blocks, vec![misc]);
+
+        data[BW_LAYOUT.trainer.offset + 0x04] ^= 0xFF;
+        assert!(matches!(
+            read_save_from_bytes(data, ParseOptions::default()),
+            Err(ReadError::NoValidBlock { block: Block::Player, offset: 0x19400, .. })
+        ));
+    }
+
+    /// Are the blocks' checksums where PKHeX's block tables put them, and is each game's save told apart by its
+    /// checksum table?
+    #[test]
+    fn layout_checksums() {
+        assert_eq!(BW_LAYOUT.trainer.checksum_offset(), 0x1946A);
+        assert_eq!(B2W2_LAYOUT.trainer.checksum_offset(), 0x194B2);
+        assert_eq!(BW_LAYOUT.misc.checksum_offset(), 0x212EE);
+        assert_eq!(B2W2_LAYOUT.misc.checksum_offset(), 0x211F2);
+        assert_eq!(BW_LAYOUT.checksum_table.at_backup(BW_LAYOUT.backup).checksum_offset(), 0x47F9A);
+        assert_eq!(B2W2_LAYOUT.checksum_table.at_backup(B2W2_LAYOUT.backup).checksum_offset(), 0x4BFA2);
+        assert_eq!(box_block(23).checksum_offset(), 0x183F2);
+
+        for (game, layout, other) in [(Game::White, &BW_LAYOUT, &B2W2_LAYOUT), (Game::Black2, &B2W2_LAYOUT, &BW_LAYOUT)] {
+            let data = test_save_bytes(game);
+            assert_eq!(validate_block(&data, layout.checksum_table), BlockStatus::Valid);
+            assert_ne!(validate_block(&data, other.checksum_table), BlockStatus::Valid);
+        }
+    }
+}
@@ -0,0 +1,383 @@
+use crate::save::data::gender::PokemonGender;
+use crate::save::data::species::Species;
+use crate::save::error::{Block, ReadError};
+use crate::save::format::dppt::pokemon::{decrypt_blob, pokemon_checksum, BattleStats, ContestStats, Move, Pokerus, Ribbons, Stats};
+use crate::save::format::gen5::string;
+use crate::save::format::reader::{BlockReader, Diagnostics};
+use crate::save::format::{read_date, read_stats, ParseOptions, StoredPokemon};
+use crate::save::save::{Gender, Pokemon};
+use byteorder::{ByteOrder, LittleEndian};
+use chrono::NaiveDate;
+use std::io::SeekFrom;
+
+/// Size of a Pokémon in a box, i.e. without battle stats; the same as gen 4
+pub const BOX_POKEMON_SIZE: usize = 136;
+/// Size of a Pokémon in the party, with the 84 bytes of battle stats
+pub const PARTY_POKEMON_SIZE: usize = 220;
+
+/// A gen 5 Pokémon, decoded from the four blocks of its decrypted data. It's laid out like a gen 4
+/// Pokémon, with a few fields repurposed.
+///
+/// See the "Pokémon" section of `doc/format/gen5/gen5.md`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Gen5Pokemon {
+    // HEADER
+    /// Personality value, which decides gender, shininess, etc.; unlike gen 4, not the nature
+    pub pid: u32,
+    pub flags: u16,
+    pub checksum: u16,
+
+    // BLOCK A
+    pub species: Species,
+    /// Gen 5 item ID; the same as gen 4's, for gen 4 items
+    pub held_item: u16,
+    pub ot_id: u16,
+    pub ot_secret_id: u16,
+    pub experience: u32,
+    pub friendship: u8,
+    pub ability: u8,
+    pub markings: u8,
+    pub language: u8,
+    pub evs: Stats,
+    pub contest: ContestStats,
+
+    // BLOCK B
+    pub moves: [Move; 4],
+    pub ivs: Stats,
+    pub is_egg: bool,
+    pub is_nicknamed: bool,
+    pub ribbons: Ribbons,
+    pub fateful_encounter: bool,
+    pub gender: PokemonGender,
+    pub form: u8,
+    pub nature: u8,
+    pub hidden_ability: bool,
+    /// Shown as one of N's Pokémon
+    pub n_sparkle: bool,
+
+    // BLOCK C
+    pub nickname: String,
+    pub origin_game: u8,
+
+    // BLOCK D
+    pub ot_name: String,
+    pub egg_date: Option<NaiveDate>,
+    pub met_date: Option<NaiveDate>,
+    pub egg_location: u16,
+    pub met_location: u16,
+    pub pokerus: Pokerus,
+    pub ball: u8,
+    pub met_level: u8,
+    pub ot_gender: Gender,
+    pub encounter_type: u8,
+
+    // PARTY ONLY
+    /// Only set for party Pokémon. `seal_capsule` is always `0`, gen 5 has no seals.
+    pub battle_stats: Option<BattleStats>,
+}
+
+impl Gen5Pokemon {
+    /// Read a `.pk5` file: a box or party Pokémon, either encrypted as it's stored in a save or decrypted
+    /// as PKHeX exports it. Whichever of the two passes its checksum is used.
+    pub fn from_pk5(data: &[u8]) -> Result<Self, ReadError> {
+        if !is_pokemon_size(data.len()) {
+            return Err(ReadError::InvalidPokemonSize(data.len()));
+        }
+
+        let checksum = LittleEndian::read_u16(&data[0x06..0x08]);
+        let decrypted = match pokemon_checksum(data) == checksum {
+            true => data.to_vec(),
+            false => decrypt_pokemon_blob(data)?,
+        };
+        if pokemon_checksum(&decrypted) != checksum {
+            return Err(ReadError::InvalidValue { block: Block::Pokemon, offset: 0x06, field: "checksum", value: checksum as u64 });
+        }
+
+        let mut diagnostics = Diagnostics::new(&ParseOptions::default());
+        Self::read(&mut BlockReader::new(&decrypted, 0, Block::Pokemon), &mut diagnostics)
+    }
+
+    /// Decode a decrypted Pokémon, with blocks in A-D order. If there are `PARTY_POKEMON_SIZE` bytes, the
+    /// battle stats are read too.
+    pub(crate) fn read(reader: &mut BlockReader, diagnostics: &mut Diagnostics) -> Result<Self, ReadError> {
+        reader.seek(SeekFrom::Start(0x00));
+        let pid = reader.read_u32("PID")?;
+        let flags = reader.read_u16("flags")?;
+        let checksum = reader.read_u16("checksum")?;
+
+        // BLOCK A
+        let species = reader.read_u16("species")?;
+        let species = match Species::try_from(species) {
+            Ok(species) => species,
+            Err(_) => {
+                diagnostics.report(reader.diagnostic("species", species))?;
+                Species::Unknown(species)
+            }
+        };
+        let held_item = reader.read_u16("held item")?;
+        let ot_id = reader.read_u16("original trainer ID")?;
+        let ot_secret_id = reader.read_u16("original trainer secret ID")?;
+        let experience = reader.read_u32("experience")?;
+        let friendship = reader.read_u8("friendship")?;
+        let ability = reader.read_u8("ability")?;
+        let markings = reader.read_u8("markings")?;
+        let language = reader.read_u8("language")?;
+        let evs = read_stats(reader, "EVs")?;
+        let contest = reader.read_bytes("contest stats", 6)?;
+        let contest = ContestStats {
+            cool: contest[0],
+            beauty: contest[1],
+            cute: contest[2],
+            smart: contest[3],
+            tough: contest[4],
+            sheen: contest[5],
+        };
+        let sinnoh_ribbons_1 = reader.read_u32("Sinnoh ribbons")?;
+
+        // BLOCK B
+        let mut moves = [Move::default(); 4];
+        for pkmn_move in moves.iter_mut() {
+            pkmn_move.id = reader.read_u16("move")?;
+        }
+        for pkmn_move in moves.iter_mut() {
+            pkmn_move.pp = reader.read_u8("move PP")?;
+        }
+        for pkmn_move in moves.iter_mut() {
+            pkmn_move.pp_ups = reader.read_u8("move PP ups")?;
+        }
+
+        let iv_bits = reader.read_u32("IVs")?;
+        let iv = |i: u32| ((iv_bits >> (i * 5)) & 0x1F) as u8;
+        let ivs = Stats {
+            hp: iv(0),
+            attack: iv(1),
+            defense: iv(2),
+            speed: iv(3),
+            sp_attack: iv(4),
+            sp_defense: iv(5),
+        };
+
+        let hoenn_ribbons = reader.read_u32("Hoenn ribbons")?;
+        let form_bits = reader.read_u8("form")?;
+        let nature = reader.read_u8("nature")?;
+        let gen5_flags = reader.read_u8("gen 5 flags")?;
+
+        // BLOCK C
+        reader.seek(SeekFrom::Start(0x48));
+        let nickname = string::decode(&reader.read_u16s("nickname", 11)?);
+        reader.seek(SeekFrom::Current(0x01));
+        let origin_game = reader.read_u8("origin game")?;
+        let sinnoh_ribbons_2 = reader.read_u32("Sinnoh ribbons")?;
+
+        // BLOCK D
+        reader.seek(SeekFrom::Start(0x68));
+        let ot_name = string::decode(&reader.read_u16s("original trainer name", 8)?);
+        let egg_date = read_date(reader, "egg date")?;
+        let met_date = read_date(reader, "met date")?;
+        let egg_location = reader.read_u16("egg location")?;
+        let met_location = reader.read_u16("met location")?;
+        let pokerus = reader.read_u8("Pokérus")?.into();
+        let ball = reader.read_u8("ball")?;
+        let met_level_bits = reader.read_u8("met level")?;
+        let encounter_type = reader.read_u8("encounter type")?;
+
+        // PARTY ONLY
+        let battle_stats = match reader.len() >= PARTY_POKEMON_SIZE {
+            true => {
+                reader.seek(SeekFrom::Start(0x88));
+                let status = reader.read_u32("status condition")?.into();
+                let level = reader.read_u8("level")?;
+                reader.seek(SeekFrom::Current(0x01));
+                Some(BattleStats {
+                    status,
+                    level,
+                    seal_capsule: 0,
+                    current_hp: reader.read_u16("current HP")?,
+                    max_hp: reader.read_u16("max HP")?,
+                    attack: reader.read_u16("attack")?,
+                    defense: reader.read_u16("defense")?,
+                    speed: reader.read_u16("speed")?,
+                    sp_attack: reader.read_u16("special attack")?,
+                    sp_defense: reader.read_u16("special defense")?,
+                })
+            }
+            false => None,
+        };
+
+        Ok(Gen5Pokemon {
+            pid,
+            flags,
+            checksum,
+            species,
+            held_item,
+            ot_id,
+            ot_secret_id,
+            experience,
+            friendship,
+            ability,
+            markings,
+            language,
+            evs,
+            contest,
+            moves,
+            ivs,
+            is_egg: iv_bits & (1 << 30) != 0,
+            is_nicknamed: iv_bits & (1 << 31) != 0,
+            ribbons: Ribbons { sinnoh_1: sinnoh_ribbons_1, hoenn: hoenn_ribbons, sinnoh_2: sinnoh_ribbons_2 },
+            fateful_encounter: form_bits & 0b001 != 0,
+            gender: PokemonGender::from_bits(form_bits),
+            form: form_bits >> 3,
+            nature,
+            hidden_ability: gen5_flags & 0b01 != 0,
+            n_sparkle: gen5_flags & 0b10 != 0,
+            nickname,
+            origin_game,
+            ot_name,
+            egg_date,
+            met_date,
+            egg_location,
+            met_location,
+            pokerus,
+            ball,
+            met_level: met_level_bits & 0x7F,
+            ot_gender: match met_level_bits >> 7 {
+                0 => Gender::Male,
+                _ => Gender::Female,
+            },
+            encounter_type,
+            battle_stats,
+        })
+    }
+}
+
+impl StoredPokemon for Gen5Pokemon {
+    const PARTY_POKEMON_SIZE: usize = PARTY_POKEMON_SIZE;
+    const CHECKSUM_OFFSET: usize = 0x06;
+    const SPECIES_OFFSET: usize = 0x08;
+
+    fn decrypt(blob: &[u8]) -> Result<Vec<u8>, ReadError> {
+        decrypt_pokemon_blob(blob)
+    }
+
+    fn checksum(decrypted: &[u8]) -> u16 {
+        pokemon_checksum(decrypted)
+    }
+
+    fn read(reader: &mut BlockReader, diagnostics: &mut Diagnostics) -> Result<Self, ReadError> {
+        Gen5Pokemon::read(reader, diagnostics)
+    }
+
+    fn species(&self) -> Species {
+        self.species
+    }
+
+    fn ot_ids(&self) -> (u16, u16) {
+        (self.ot_id, self.ot_secret_id)
+    }
+
+    fn fill(self, pokemon: &mut Pokemon) {
+        pokemon.set_name(self.nickname);
+        pokemon.set_experience(self.experience);
+        pokemon.set_friendship(self.friendship);
+    }
+}
+
+fn is_pokemon_size(len: usize) -> bool {
+    len == BOX_POKEMON_SIZE || len == PARTY_POKEMON_SIZE
+}
+
+/// Decrypt a Pokémon as stored in a save, giving its blocks in A-D order. This is gen 4's algorithm, see
+/// `dppt::pokemon::decrypt_pokemon_blob`.
+pub fn decrypt_pokemon_blob(blob: &[u8]) -> Result<Vec<u8>, ReadError> {
+    if !is_pokemon_size(blob.len()) {
+        return Err(ReadError::InvalidPokemonSize(blob.len()));
+    }
+
+    Ok(decrypt_blob(blob))
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+    use crate::save::format::dppt::pokemon::encrypt_blob;
+
+    /// A decrypted Snivy caught by "Hilda", with the checksum filled in
+    pub(crate) fn snivy(pid: u32, party: bool) -> Vec<u8> {
+        fn write_string(data: &mut [u8], text: &str) {
+            for (i, word) in text.encode_utf16().chain([0xFFFF]).enumerate() {
+                LittleEndian::write_u16(&mut data[i * 2..], word);
+            }
+        }
+
+        let mut data = vec![0u8; if party { PARTY_POKEMON_SIZE } else { BOX_POKEMON_SIZE }];
+        LittleEndian::write_u32(&mut data[0x00..], pid);
+        LittleEndian::write_u16(&mut data[0x08..], 495);
+        LittleEndian::write_u16(&mut data[0x0A..], 234); // Leftovers
+        LittleEndian::write_u16(&mut data[0x0C..], 12345);
+        LittleEndian::write_u16(&mut data[0x0E..], 54321);
+        LittleEndian::write_u32(&mut data[0x10..], 135);
+        data[0x14] = 70;
+        data[0x15] = 65; // Overgrow
+        data[0x17] = 2;
+        LittleEndian::write_u16(&mut data[0x28..], 33); // Tackle
+        data[0x30] = 35;
+        data[0x34] = 3;
+        LittleEndian::write_u32(&mut data[0x38..], 31 | (20 << 25) | (1 << 31));
+        data[0x40] = 0b010;
+        data[0x41] = 11; // Timid
+        data[0x42] = 0b01;
+        write_string(&mut data[0x48..], "Leafy");
+        data[0x5F] = 21;
+        write_string(&mut data[0x68..], "Hilda");
+        data[0x7B..0x7E].copy_from_slice(&[10, 9, 18]);
+        LittleEndian::write_u16(&mut data[0x80..], 4);
+        data[0x83] = 4;
+        data[0x84] = 5 | 0x80;
+
+        if party {
+            data[0x8C] = 5;
+            LittleEndian::write_u16(&mut data[0x8E..], 20);
+            LittleEndian::write_u16(&mut data[0x90..], 21);
+            LittleEndian::write_u16(&mut data[0x9A..], 11);
+        }
+
+        let checksum = pokemon_checksum(&data);
+        LittleEndian::write_u16(&mut data[0x06..], checksum);
+        data
+    }
+
+    /// Is a PK5 decrypted with the gen 4 algorithm & every field read from the right place?
+    #[test]
+    fn read_pk5() {
+        for pid in [0, 0x2000, 0xFFFF_FFFF] {
+            let decrypted = snivy(pid, true);
+            let encrypted = encrypt_blob(&decrypted);
+            assert_eq!(decrypt_pokemon_blob(&encrypted).unwrap(), decrypted);
+
+            let pkmn = Gen5Pokemon::from_pk5(&encrypted).unwrap();
+            assert_eq!(Gen5Pokemon::from_pk5(&decrypted).unwrap(), pkmn);
+            assert_eq!(pkmn.species, Species::Snivy);
+            assert_eq!((pkmn.held_item, pkmn.ot_id, pkmn.ot_secret_id), (234, 12345, 54321));
+            assert_eq!((pkmn.experience, pkmn.friendship, pkmn.ability, pkmn.language), (135, 70, 65, 2));
+            assert_eq!(pkmn.moves[0], Move { id: 33, pp: 35, pp_ups: 3 });
+            assert_eq!((pkmn.ivs.hp, pkmn.ivs.sp_defense), (31, 20));
+            assert!(pkmn.is_nicknamed && !pkmn.is_egg);
+            assert_eq!(pkmn.gender, PokemonGender::Female);
+            assert_eq!(pkmn.nature, 11);
+            assert!(pkmn.hidden_ability && !pkmn.n_sparkle);
+            assert_eq!(pkmn.nickname, "Leafy");
+            assert_eq!(pkmn.origin_game, 21);
+            assert_eq!(pkmn.ot_name, "Hilda");
+            assert_eq!(pkmn.met_date, NaiveDate::from_ymd_opt(2010, 9, 18));
+            assert_eq!(pkmn.egg_date, None);
+            assert_eq!((pkmn.met_location, pkmn.ball, pkmn.met_level), (4, 4, 5));
+            assert_eq!(pkmn.ot_gender, Gender::Female);
+
+            let stats = pkmn.battle_stats.unwrap();
+            assert_eq!((stats.level, stats.current_hp, stats.max_hp, stats.sp_defense), (5, 20, 21, 11));
+        }
+
+        assert!(matches!(Gen5Pokemon::from_pk5(&snivy(0, false)[..100]), Err(ReadError::InvalidPokemonSize(100))));
+        assert_eq!(Gen5Pokemon::from_pk5(&snivy(0, false)).unwrap().battle_stats, None);
+    }
+}
@@ -0,0 +1,29 @@
+// gen 5 strings are UTF-16, except for the gender symbols; see
+// https://github.com/kwsch/PKHeX/blob/master/PKHeX.Core/PKM/Strings/StringConverter5.cs
+const TERMINATOR: u16 = 0xFFFF;
+const GENDER_MALE: u16 = 0x246D;
+const GENDER_FEMALE: u16 = 0x246E;
+
+/// Decode a gen 5 string, up to its terminator. Anything that isn't valid UTF-16 is replaced with `�`.
+pub(crate) fn decode(words: &[u16]) -> String {
+    let words = words.iter().take_while(|w| **w != TERMINATOR).map(|w| match *w {
+        GENDER_MALE => '♂' as u16,
+        GENDER_FEMALE => '♀' as u16,
+        other => other,
+    });
+    char::decode_utf16(words).map(|c| c.unwrap_or(char::REPLACEMENT_CHARACTER)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Can we decode a name with the gender symbols & one after the terminator?
+    #[test]
+    fn decode_strings() {
+        let nidoran: Vec<u16> = "Nidoran".encode_utf16().chain([GENDER_FEMALE, TERMINATOR, 0x41]).collect();
+        assert_eq!(decode(&nidoran), "Nidoran♀");
+        assert_eq!(decode(&[0x30C1, 0x30A7, 0x30EA, 0x30E0, GENDER_MALE]), "チェリム♂");
+        assert_eq!(decode(&[0xD800, TERMINATOR]), "\u{FFFD}");
+    }
+}
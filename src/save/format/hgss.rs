@@ -0,0 +1,122 @@
+use crate::save::error::ReadError;
+#[cfg(feature = "write")]
+use crate::save::error::WriteError;
+use crate::save::data::pocket::Pocket;
+use crate::save::format::dppt::block::FooterFormat;
+use crate::save::format::dppt::save::{Badge, Game, Gen4Save};
+use crate::save::format::dppt::sinnoh::{self, Layout};
+use crate::save::format::{self, ParseOptions};
+use std::io::{Read, Seek};
+use std::path::PathBuf;
+
+const GENERAL_SIZE: usize = 0xF628;
+const STORAGE_OFFSET: usize = 0xF700;
+const STORAGE_SIZE: usize = 0x12310;
+
+/// HGSS shares DPPt's Pokémon, strings & most of the player block, so it's read by the same code
+pub(crate) const LAYOUT: Layout = Layout {
+    general_size: GENERAL_SIZE,
+    storage_offset: STORAGE_OFFSET,
+    storage_size: STORAGE_SIZE,
+    footer: FooterFormat::Johto,
+    trainer: 0x64,
+    badges: &[(0x1A, &Badge::JOHTO), (0x1E, &Badge::KANTO)],
+    party: 0x98,
+    bag: 0x644,
+    pocket_capacity: Pocket::hgss_capacity,
+    vars: 0xDE4,
+    var_count: 368,
+    flags: 0x10C4,
+    flag_size: 364,
+    current_box: 0x12000,
+    // each box is padded out to 0x1000 bytes
+    boxes: 0x00,
+    box_size: 0x1000,
+    box_names: 0x12008,
+    games: &[Game::HeartGold, Game::SoulSilver],
+};
+
+/// Read a HeartGold or SoulSilver save from `save_file`
+pub fn read_save(save_file: impl Into<PathBuf>) -> Result<Gen4Save, ReadError> {
+    read_save_with_options(save_file, ParseOptions::default())
+}
+
+/// Read a HeartGold or SoulSilver save from `save_file`, see `ParseOptions`
+pub fn read_save_with_options(save_file: impl Into<PathBuf>, options: ParseOptions) -> Result<Gen4Save, ReadError> {
+    format::read_file(save_file, options, read_save_from_bytes)
+}
+
+/// Read a HeartGold or SoulSilver save from `reader`, starting at its current position
+pub fn read_save_from_reader<R: Read + Seek>(reader: &mut R) -> Result<Gen4Save, ReadError> {
+    read_save_from_reader_with_options(reader, ParseOptions::default())
+}
+
+/// Read a HeartGold or SoulSilver save from `reader`, starting at its current position. See `ParseOptions`.
+pub fn read_save_from_reader_with_options<R: Read + Seek>(reader: &mut R, options: ParseOptions) -> Result<Gen4Save, ReadError> {
+    format::read_reader(reader, options, read_save_from_bytes)
+}
+
+pub(crate) fn read_save_from_bytes(data: Vec<u8>, options: ParseOptions) -> Result<Gen4Save, ReadError> {
+    sinnoh::read_save_from_bytes(data, options, &LAYOUT)
+}
+
+/// Write `save` to `save_file`. See `write_save_to_bytes`.
+#[cfg(feature = "write")]
+pub fn write_save(save: &Gen4Save, save_file: impl Into<PathBuf>) -> Result<(), WriteError> {
+    let data = write_save_to_bytes(save)?;
+    Ok(std::fs::write(save_file.into(), data)?)
+}
+
+/// Serialize `save` into a full 512 KiB `.sav` image, see `platinum::write_save_to_bytes`.
+///
+/// A save from DPPt is written with its own layout, see `save.game`.
+#[cfg(feature = "write")]
+pub fn write_save_to_bytes(save: &Gen4Save) -> Result<Vec<u8>, WriteError> {
+    sinnoh::write_save_to_bytes(save)
+}
+
+#[cfg(test)]
+#[cfg(feature = "write")]
+mod tests {
+    use super::*;
+    use crate::save::format::dppt::block::PARTITION_SIZE;
+    use crate::save::format::dppt::platinum;
+    use crate::save::format::dppt::save::VAR_START;
+
+    /// Does a HeartGold or SoulSilver save read back as written, and get told apart from DPPt?
+    #[test]
+    fn write_round_trip() {
+        for game in [Game::HeartGold, Game::SoulSilver] {
+            let save = sinnoh::test_save(game);
+            let written = write_save_to_bytes(&save).unwrap();
+
+            let read = read_save_from_bytes(written.clone(), ParseOptions::default()).unwrap();
+            assert_eq!(read.base, save.base);
+            assert_eq!(Gen4Save::from_bytes(&written).unwrap(), read);
+            assert_eq!(write_save_to_bytes(&read).unwrap(), written);
+            assert_eq!(sinnoh::rewrite_blank(read), written);
+            assert!(matches!(platinum::read_save_from_bytes(written, ParseOptions::default()), Err(ReadError::NoValidBlock { .. })));
+        }
+    }
+
+    /// Are the Kanto badges, boxes, vars & flags where they should be?
+    #[test]
+    fn write_hgss_layout() {
+        let written = write_save_to_bytes(&sinnoh::test_save(Game::HeartGold)).unwrap();
+        // the first copies were "read" from the first partition, so these are written to the second
+        let general = &written[PARTITION_SIZE..PARTITION_SIZE + GENERAL_SIZE];
+        assert_eq!(general[LAYOUT.trainer + 0x1A], 0b1000_0001);
+        assert_eq!(general[LAYOUT.trainer + 0x1E], 0b1000_0001);
+        assert_eq!(general[LAYOUT.trainer + 0x1C], 7);
+
+        let storage = &written[PARTITION_SIZE + STORAGE_OFFSET..PARTITION_SIZE + STORAGE_OFFSET + STORAGE_SIZE];
+        assert_eq!(&storage[0xFF0..0x1000], &[0u8; 0x10]);
+
+        let read = read_save_from_bytes(written, ParseOptions::default()).unwrap();
+        assert!(read.base.boxes[1].get_pkmn(29).is_some());
+        assert_eq!(read.var(VAR_START + LAYOUT.var_count as u16 - 1), Some(42));
+        assert_eq!(read.var(VAR_START + LAYOUT.var_count as u16), None);
+        assert_eq!(read.flag(1), Some(true));
+        assert_eq!(read.flag(2), Some(false));
+    }
+}
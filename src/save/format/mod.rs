@@ -1 +1,206 @@
-pub mod dppt;
\ No newline at end of file
+pub mod container;
+pub mod dppt;
+pub mod gen12;
+pub mod gen3;
+pub mod gen5;
+pub mod hgss;
+pub(crate) mod reader;
+
+use crate::save::error::{Block, ReadError};
+use crate::save::format::dppt::pokemon::{date_from_bytes, Stats};
+use crate::save::format::reader::{BlockReader, Diagnostics};
+use crate::save::data::species::Species;
+use crate::save::save::{Pokemon, Trainer};
+use byteorder::{ByteOrder, LittleEndian};
+use chrono::NaiveDate;
+use std::fs::File;
+use std::io::{Read, Seek, SeekFrom};
+use std::path::PathBuf;
+
+/// How to treat data that doesn't make sense when reading a save
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseOptions {
+    /// Fail on the first invalid value. Otherwise, unknown values are kept as `Unknown` variants,
+    /// corrupt Pokémon are kept as raw bytes, and each is listed in the save's diagnostics.
+    pub strict: bool,
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        Self { strict: true }
+    }
+}
+/// The result of validating one copy of a block, or a gen 3 sector
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BlockStatus {
+    Valid,
+    /// The file is too short to contain this copy
+    Missing,
+    /// The footer signature doesn't match; in gen 4, usually an uninitialised partition
+    BadSignature,
+    /// The footer's block size doesn't match the expected size for this block
+    BadSize,
+    BadChecksum { stored: u16, computed: u16 },
+}
+
+/// Where a Pokémon is stored
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SlotLocation {
+    Party(usize),
+    /// Box, then slot within the box
+    Box(usize, usize),
+}
+
+/// A Pokémon that failed its checksum when reading leniently, kept as it was stored (encrypted).
+///
+/// Writing the save back leaves it as-is, unless a Pokémon has since been put in its place.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CorruptSlot {
+    pub location: SlotLocation,
+    /// Absolute offset of the Pokémon within the save
+    pub offset: usize,
+    pub bytes: Vec<u8>,
+}
+
+/// What's in a party or box slot
+pub(crate) enum PokemonSlot {
+    Empty,
+    Pokemon(Pokemon),
+    /// The Pokémon failed its checksum (only in lenient mode)
+    Corrupt,
+}
+
+/// A generation's encrypted party & box Pokémon, so that they're all read the same way by `read_slot`
+pub(crate) trait StoredPokemon: Sized {
+    /// The size of a party Pokémon, with its battle stats
+    const PARTY_POKEMON_SIZE: usize;
+    /// Offset of the checksum in the decrypted Pokémon
+    const CHECKSUM_OFFSET: usize;
+    /// Offset of the species in the decrypted Pokémon; a slot with species `0` is empty
+    const SPECIES_OFFSET: usize;
+
+    fn decrypt(blob: &[u8]) -> Result<Vec<u8>, ReadError>;
+
+    /// The checksum of a decrypted Pokémon
+    fn checksum(decrypted: &[u8]) -> u16;
+
+    /// Decode a decrypted Pokémon
+    fn read(reader: &mut BlockReader, diagnostics: &mut Diagnostics) -> Result<Self, ReadError>;
+
+    fn species(&self) -> Species;
+
+    /// The original trainer's ID & secret ID
+    fn ot_ids(&self) -> (u16, u16);
+
+    /// Fill in the generic fields of `pokemon`, a new Pokémon of its species
+    fn fill(self, pokemon: &mut Pokemon);
+}
+
+/// Read the encrypted party or box Pokémon `blob`, found at `offset` in the save.
+///
+/// A Pokémon is only given `trainer` if its original trainer IDs match.
+fn read_pokemon<P: StoredPokemon>(blob: &[u8], offset: usize, block: Block, trainer: &Trainer, diagnostics: &mut Diagnostics) -> Result<PokemonSlot, ReadError> {
+    // slots that have never been written to can be left zeroed
+    if blob.iter().all(|byte| *byte == 0) {
+        return Ok(PokemonSlot::Empty);
+    }
+
+    let decrypted = P::decrypt(blob)?;
+    let mut decrypted_blob = BlockReader::new(&decrypted, offset, block);
+
+    decrypted_blob.seek(SeekFrom::Start(P::CHECKSUM_OFFSET as u64));
+    let checksum = decrypted_blob.read_u16("checksum")?;
+    if checksum != P::checksum(&decrypted) {
+        diagnostics.report(decrypted_blob.diagnostic("checksum", checksum))?;
+        return Ok(PokemonSlot::Corrupt);
+    }
+
+    if LittleEndian::read_u16(&decrypted[P::SPECIES_OFFSET..]) == 0 {
+        return Ok(PokemonSlot::Empty);
+    }
+
+    let stored = P::read(&mut decrypted_blob, diagnostics)?;
+    let mut pkmn = Pokemon::new(stored.species());
+    let (ot_id, ot_secret_id) = stored.ot_ids();
+    if ot_id == trainer.id() && Some(ot_secret_id) == trainer.secret_id() {
+        pkmn.set_trainer(trainer.clone());
+    }
+    stored.fill(&mut pkmn);
+
+    Ok(PokemonSlot::Pokemon(pkmn))
+}
+
+/// Read the Pokémon in `blob`, found at `offset` in the save, with `read_pokemon`. A corrupt Pokémon is
+/// kept in `corrupt_slots` as being at `location`.
+pub(crate) fn read_slot<P: StoredPokemon>(
+    blob: Vec<u8>,
+    offset: usize,
+    block: Block,
+    location: SlotLocation,
+    trainer: &Trainer,
+    diagnostics: &mut Diagnostics,
+    corrupt_slots: &mut Vec<CorruptSlot>,
+) -> Result<Option<Pokemon>, ReadError> {
+    match read_pokemon::<P>(&blob, offset, block, trainer, diagnostics)? {
+        PokemonSlot::Empty => Ok(None),
+        PokemonSlot::Pokemon(pkmn) => Ok(Some(pkmn)),
+        PokemonSlot::Corrupt => {
+            corrupt_slots.push(CorruptSlot { location, offset, bytes: blob });
+            Ok(None)
+        }
+    }
+}
+
+/// Read `count` party Pokémon one after the other from `reader`'s position, see `read_slot`
+pub(crate) fn read_party<P: StoredPokemon>(
+    reader: &mut BlockReader,
+    count: usize,
+    block: Block,
+    trainer: &Trainer,
+    diagnostics: &mut Diagnostics,
+    corrupt_slots: &mut Vec<CorruptSlot>,
+) -> Result<Vec<Pokemon>, ReadError> {
+    let mut party = Vec::with_capacity(count);
+    for i in 0..count {
+        let offset = reader.offset();
+        let blob = reader.read_bytes("party Pokémon", P::PARTY_POKEMON_SIZE)?;
+        party.extend(read_slot::<P>(blob, offset, block, SlotLocation::Party(i), trainer, diagnostics, corrupt_slots)?);
+    }
+    Ok(party)
+}
+
+/// Read one byte for each stat, e.g. EVs, in the order they're stored
+pub(crate) fn read_stats(reader: &mut BlockReader, field: &'static str) -> Result<Stats, ReadError> {
+    let stats = reader.read_bytes(field, 6)?;
+    Ok(Stats {
+        hp: stats[0],
+        attack: stats[1],
+        defense: stats[2],
+        speed: stats[3],
+        sp_attack: stats[4],
+        sp_defense: stats[5],
+    })
+}
+
+/// Read a gen 4 or 5 date, see `date_from_bytes`
+pub(crate) fn read_date(reader: &mut BlockReader, field: &'static str) -> Result<Option<NaiveDate>, ReadError> {
+    Ok(date_from_bytes(&reader.read_bytes(field, 3)?))
+}
+
+/// Read `save_file` with `read`, a generation's `read_save_from_bytes`
+pub(crate) fn read_file<T>(save_file: impl Into<PathBuf>, options: ParseOptions, read: fn(Vec<u8>, ParseOptions) -> Result<T, ReadError>) -> Result<T, ReadError> {
+    let mut save_file = File::open(save_file.into())?;
+    read_reader(&mut save_file, options, read)
+}
+
+/// Read the rest of `reader` from its current position with `read`, a generation's
+/// `read_save_from_bytes`
+pub(crate) fn read_reader<R: Read + Seek, T>(reader: &mut R, options: ParseOptions, read: fn(Vec<u8>, ParseOptions) -> Result<T, ReadError>) -> Result<T, ReadError> {
+    let start = reader.stream_position()?;
+    let len = reader.seek(SeekFrom::End(0))? - start;
+    reader.seek(SeekFrom::Start(start))?;
+
+    let mut data = Vec::with_capacity(len as usize);
+    reader.read_to_end(&mut data)?;
+    read(data, options)
+}
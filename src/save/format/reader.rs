@@ -0,0 +1,163 @@
+use crate::save::error::{Block, Diagnostic, ReadError};
+use crate::save::format::ParseOptions;
+use byteorder::{BigEndian, LittleEndian, ReadBytesExt};
+use std::io::{Cursor, Read, Seek, SeekFrom};
+
+/// Reads fields out of part of a save, attaching the block, absolute offset & field name to any error.
+pub(crate) struct BlockReader<'a> {
+    cursor: Cursor<&'a [u8]>,
+    /// Absolute offset of `data[0]` within the save
+    base: usize,
+    block: Block,
+    /// Absolute offset of the last field read
+    last_offset: usize,
+}
+
+impl<'a> BlockReader<'a> {
+    pub fn new(data: &'a [u8], base: usize, block: Block) -> Self {
+        Self { cursor: Cursor::new(data), base, block, last_offset: base }
+    }
+
+    /// Move on to the next part of the save, for the sake of errors
+    pub fn set_block(&mut self, block: Block) {
+        self.block = block;
+    }
+
+    pub fn seek(&mut self, position: SeekFrom) {
+        // seeking a cursor can only fail before the start, which is a bug in the reader
+        self.cursor.seek(position).expect("seek before the start of a block");
+    }
+
+    /// Length of the data being read
+    pub fn len(&self) -> usize {
+        self.cursor.get_ref().len()
+    }
+
+    /// The data being read
+    pub fn data(&self) -> &'a [u8] {
+        self.cursor.get_ref()
+    }
+
+    /// Absolute offset of the current position within the save
+    pub fn offset(&self) -> usize {
+        self.base + self.cursor.position() as usize
+    }
+
+    /// A diagnostic for the last field read
+    pub fn diagnostic(&self, field: &'static str, value: impl Into<u64>) -> Diagnostic {
+        Diagnostic { block: self.block, offset: self.last_offset, field, value: value.into() }
+    }
+
+    fn read<T>(&mut self, field: &'static str, read: impl FnOnce(&mut Cursor<&'a [u8]>) -> std::io::Result<T>) -> Result<T, ReadError> {
+        self.last_offset = self.offset();
+        read(&mut self.cursor).map_err(|source| ReadError::Truncated {
+            block: self.block,
+            offset: self.last_offset,
+            field,
+            source,
+        })
+    }
+
+    pub fn read_u8(&mut self, field: &'static str) -> Result<u8, ReadError> {
+        self.read(field, |c| c.read_u8())
+    }
+
+    pub fn read_u16(&mut self, field: &'static str) -> Result<u16, ReadError> {
+        self.read(field, |c| c.read_u16::<LittleEndian>())
+    }
+
+    /// Gen 1 & 2 store their numbers big-endian
+    pub fn read_u16_be(&mut self, field: &'static str) -> Result<u16, ReadError> {
+        self.read(field, |c| c.read_u16::<BigEndian>())
+    }
+
+    /// A 3 byte big-endian number, like gen 1 & 2's experience & money
+    pub fn read_u24_be(&mut self, field: &'static str) -> Result<u32, ReadError> {
+        self.read(field, |c| c.read_u24::<BigEndian>())
+    }
+
+    pub fn read_u32(&mut self, field: &'static str) -> Result<u32, ReadError> {
+        self.read(field, |c| c.read_u32::<LittleEndian>())
+    }
+
+    pub fn read_i32(&mut self, field: &'static str) -> Result<i32, ReadError> {
+        self.read(field, |c| c.read_i32::<LittleEndian>())
+    }
+
+    pub fn read_i64(&mut self, field: &'static str) -> Result<i64, ReadError> {
+        self.read(field, |c| c.read_i64::<LittleEndian>())
+    }
+
+    pub fn read_bytes(&mut self, field: &'static str, length: usize) -> Result<Vec<u8>, ReadError> {
+        self.read(field, |c| {
+            let mut buf = vec![0u8; length];
+            c.read_exact(&mut buf)?;
+            Ok(buf)
+        })
+    }
+
+    pub fn read_u16s(&mut self, field: &'static str, length: usize) -> Result<Vec<u16>, ReadError> {
+        self.read(field, |c| (0..length).map(|_| c.read_u16::<LittleEndian>()).collect())
+    }
+}
+
+/// Collects the diagnostics found while reading a save
+pub(crate) struct Diagnostics {
+    strict: bool,
+    found: Vec<Diagnostic>,
+}
+
+impl Diagnostics {
+    pub fn new(options: &ParseOptions) -> Self {
+        Self { strict: options.strict, found: Vec::new() }
+    }
+
+    /// Record `diagnostic`, or in strict mode, return it as an error
+    pub fn report(&mut self, diagnostic: Diagnostic) -> Result<(), ReadError> {
+        if self.strict {
+            return Err(diagnostic.into());
+        }
+
+        self.found.push(diagnostic);
+        Ok(())
+    }
+
+    pub fn into_inner(self) -> Vec<Diagnostic> {
+        self.found
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Do errors point at the field that failed?
+    #[test]
+    fn error_context() {
+        let data = [0x01, 0x02, 0x03];
+        let mut reader = BlockReader::new(&data, 0x100, Block::Player);
+        assert_eq!(reader.read_u16("first").unwrap(), 0x0201);
+
+        let err = reader.read_u16("second").unwrap_err();
+        assert!(matches!(err, ReadError::Truncated { block: Block::Player, offset: 0x102, field: "second", .. }));
+
+        reader.seek(SeekFrom::Start(0));
+        let value = reader.read_u8("gender").unwrap();
+        let err = ReadError::from(reader.diagnostic("gender", value));
+        assert!(matches!(err, ReadError::InvalidValue { offset: 0x100, value: 1, .. }));
+        assert_eq!(err.to_string(), "invalid gender 0x1 (Player block, offset 0x00100)");
+    }
+
+    /// Are diagnostics errors in strict mode, and collected otherwise?
+    #[test]
+    fn report_diagnostics() {
+        let diagnostic = Diagnostic { block: Block::Bag, offset: 0x640, field: "item", value: 999 };
+
+        let mut strict = Diagnostics::new(&ParseOptions { strict: true });
+        assert!(matches!(strict.report(diagnostic.clone()), Err(ReadError::InvalidValue { field: "item", value: 999, .. })));
+
+        let mut lenient = Diagnostics::new(&ParseOptions { strict: false });
+        assert!(lenient.report(diagnostic.clone()).is_ok());
+        assert_eq!(lenient.into_inner(), vec![diagnostic]);
+    }
+}
@@ -1,4 +1,8 @@
 pub mod data;
+#[allow(clippy::module_inception)]
 pub mod save;
 pub mod format;
-pub mod error;
\ No newline at end of file
+pub mod error;
+pub mod detect;
+
+pub use detect::{open, Save};
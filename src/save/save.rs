@@ -1,12 +1,20 @@
 use std::collections::HashMap;
-use crate::save::data::dppt::item::DPPTItem;
+use std::path::PathBuf;
+use crate::save::data::badge::Badge;
+use crate::save::data::item::Item;
+use crate::save::data::pocket::Pocket;
 use crate::save::data::species::Species;
+use crate::save::error::ReadError;
+#[cfg(feature = "write")]
+use crate::save::error::WriteError;
+use crate::save::format::dppt::pokemon::Gen4Pokemon;
+use crate::save::format::SlotLocation;
 
 /// A trainer
 ///
 /// Depending on the context, we may not be able to retrieve all the information of a `Trainer` when
 /// reading a save file.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct Trainer {
     name: String,
     id: u16,
@@ -14,16 +22,26 @@ pub struct Trainer {
     gender: Gender
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum Gender {
     Male = 0,
     Female = 1
 }
 
+/// How long a save has been played for
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Playtime {
+    pub hours: u16,
+    pub minutes: u8,
+    pub seconds: u8,
+}
+
 /// A generic, non-generation specific Pokemon
 ///
 /// The base Pokemon struct should be used wherever possible, and if it needs to be 'upgraded' to a
-/// specific generation then do so via (eventually provided) `GenXPokemon::from(pokemon)`
+/// specific generation then do so via `GenXPokemon::try_from`, e.g. `Gen4Pokemon::try_from((&pokemon,
+/// &origin))`. Going the other way with `Pokemon::from(&gen_x_pokemon)` keeps the generation specific
+/// data, so it's written back as it was.
 ///
 /// # Examples
 ///
@@ -34,24 +52,28 @@ pub enum Gender {
 /// use pokerus::save::save::Pokemon;
 /// let pkmn = Pokemon::new(Species::Piplup);
 /// // if you have a national dex number
-/// let pkmn = Pokemon::new(Species::from(393));
+/// let pkmn = Pokemon::new(Species::try_from(393).unwrap());
 /// ```
 ///
-#[derive(Debug)]
+///
+/// Two Pokémon are equal if their generic fields and their generation specific data are equal.
+#[derive(Debug, PartialEq)]
 pub struct Pokemon {
     name: String,
     species: Species,
     trainer: Option<Trainer>,
     experience: u32,
     friendship: u8,
+    gen4: Option<std::boxed::Box<Gen4Pokemon>>,
 }
 
 //pub type BoxDecoration = HashMap<u32, u32>;
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 pub struct Box {
     name: String,
     pokemon: HashMap<usize, Pokemon>,
-    wallpaper: u8
+    wallpaper: u8,
+    size: usize,
 }
 
 impl Pokemon {
@@ -61,26 +83,93 @@ impl Pokemon {
             species,
             trainer: None,
             experience: 0,
-            friendship: 0
+            friendship: 0,
+            gen4: None
         }
     }
 
     pub fn set_trainer(&mut self, trainer: Trainer) {
+        if let Some(gen4) = self.gen4.as_mut() {
+            gen4.ot_name.set_text(trainer.name());
+            gen4.ot_id = trainer.id();
+            gen4.ot_secret_id = trainer.secret_id().unwrap_or(0);
+            gen4.ot_gender = *trainer.gender();
+        }
         self.trainer = Some(trainer);
     }
 
     pub fn set_name(&mut self, name: String) {
+        if let Some(gen4) = self.gen4.as_mut() {
+            gen4.nickname.set_text(name.clone());
+        }
         self.name = name;
     }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn species(&self) -> &Species {
+        &self.species
+    }
+
+    pub fn trainer(&self) -> Option<&Trainer> {
+        self.trainer.as_ref()
+    }
+
+    pub fn experience(&self) -> u32 {
+        self.experience
+    }
+
+    pub fn friendship(&self) -> u8 {
+        self.friendship
+    }
+
+    pub fn set_experience(&mut self, experience: u32) {
+        if let Some(gen4) = self.gen4.as_mut() {
+            gen4.experience = experience;
+        }
+        self.experience = experience;
+    }
+
+    pub fn set_friendship(&mut self, friendship: u8) {
+        if let Some(gen4) = self.gen4.as_mut() {
+            gen4.friendship = friendship;
+        }
+        self.friendship = friendship;
+    }
+
+    /// The full gen 4 data this Pokémon was read with, if it came from a gen 4 save
+    pub fn gen4(&self) -> Option<&Gen4Pokemon> {
+        self.gen4.as_deref()
+    }
+
+    /// Replace the gen 4 data, which is what gets written to a gen 4 save. The generic fields are updated
+    /// to match; the trainer is dropped if it's no longer the original trainer.
+    ///
+    /// The setters above update the gen 4 data too, so the two never disagree.
+    pub fn set_gen4(&mut self, gen4: Gen4Pokemon) {
+        self.name = gen4.nickname.to_string();
+        self.species = gen4.species;
+        self.experience = gen4.experience;
+        self.friendship = gen4.friendship;
+        if self.trainer.as_ref().is_some_and(|t| t.id() != gen4.ot_id || t.secret_id().unwrap_or(0) != gen4.ot_secret_id) {
+            self.trainer = None;
+        }
+        self.gen4 = Some(std::boxed::Box::new(gen4));
+    }
 }
 
+/// The most Pokémon a party holds
+pub const PARTY_SIZE: usize = 6;
+
 /// A generic, non-generation specific save file
-#[derive(Debug)]
+#[derive(Debug, PartialEq)]
 pub struct SaveFile {
     trainer: Trainer,
     money: u32,
     pub party: Vec<Pokemon>,
-    inventory: HashMap<DPPTItem, u16>, // @todo: convert to a Vec<Pocket>, where a Pocket -> HashMap<Item, u16>
+    inventory: HashMap<Item, u16>,
     pub boxes: Vec<Box>
 }
 
@@ -103,25 +192,25 @@ impl SaveFile {
     /// use pokerus::save::save::{Gender, SaveFile, Trainer};
     /// let mut save_file = SaveFile::new(Trainer::new("Trainer".into(), 123, Some(456), Gender::Female), 0);
     ///
-    /// save_file.add_item(DPPTItem::MasterBall, 10);
+    /// save_file.set_item(DPPTItem::MasterBall, 10);
     /// let mut qty = save_file.get_item(DPPTItem::MasterBall);
     /// assert_eq!(*qty.unwrap(), 10);
     ///
-    /// save_file.add_item(DPPTItem::MasterBall, 100);
+    /// save_file.set_item(DPPTItem::MasterBall, 100);
     /// qty = save_file.get_item(DPPTItem::MasterBall);
     /// assert_eq!(*qty.unwrap(), 100);
     /// ```
-    pub fn set_item(&mut self, item: DPPTItem, qty: u16) {
-        self.inventory.insert(item, qty);
+    pub fn set_item(&mut self, item: impl Into<Item>, qty: u16) {
+        self.inventory.insert(item.into(), qty);
     }
 
-    /// Adds `qty` of `item` to the save's inventory.
+    /// Adds `qty` of `item` to the save's inventory, stopping at `u16::MAX`.
     ///
     /// # Examples
     /// ```
     /// use pokerus::save::data::dppt::item::DPPTItem;
     /// use pokerus::save::save::{Gender, SaveFile, Trainer};
-    /// let mut save_file = SaveFile::new(Trainer::new("Trainer".into(), 123, Some(456), Gender::Female));
+    /// let mut save_file = SaveFile::new(Trainer::new("Trainer".into(), 123, Some(456), Gender::Female), 0);
     ///
     /// save_file.add_item(DPPTItem::MasterBall, 10);
     /// let mut qty = save_file.get_item(DPPTItem::MasterBall);
@@ -130,27 +219,198 @@ impl SaveFile {
     /// save_file.add_item(DPPTItem::MasterBall, 100);
     /// qty = save_file.get_item(DPPTItem::MasterBall);
     ///  assert_eq!(*qty.unwrap(), 110);
+    ///
+    /// save_file.add_item(DPPTItem::MasterBall, u16::MAX);
+    /// qty = save_file.get_item(DPPTItem::MasterBall);
+    /// assert_eq!(*qty.unwrap(), u16::MAX);
     /// ```
-    pub fn add_item(&mut self, item: DPPTItem, qty: u16) {
+    pub fn add_item(&mut self, item: impl Into<Item>, qty: u16) {
+        let item = item.into();
         let qty = match self.inventory.get(&item) {
-            Some(old_qty) => qty + old_qty,
+            Some(old_qty) => qty.saturating_add(*old_qty),
             None => qty
         };
 
         self.inventory.insert(item, qty);
     }
 
-    pub fn get_item(&self, item: DPPTItem) -> Option<&u16> {
-        self.inventory.get(&item)
+    pub fn get_item(&self, item: impl Into<Item>) -> Option<&u16> {
+        self.inventory.get(&item.into())
     }
 
-    pub fn has_item(&self, item: DPPTItem) -> bool {
-        self.inventory.contains_key(&item)
+    pub fn has_item(&self, item: impl Into<Item>) -> bool {
+        self.inventory.contains_key(&item.into())
+    }
+
+    pub fn inventory(&self) -> &HashMap<Item, u16> {
+        &self.inventory
+    }
+
+    /// The items in `pocket` & their quantities, by item ID. Items without a pocket aren't in any.
+    ///
+    /// # Examples
+    /// ```
+    /// use pokerus::save::data::dppt::item::DPPTItem;
+    /// use pokerus::save::data::pocket::Pocket;
+    /// use pokerus::save::data::item::Item;
+    /// use pokerus::save::save::{Gender, SaveFile, Trainer};
+    /// let mut save_file = SaveFile::new(Trainer::new("Trainer".into(), 123, Some(456), Gender::Female), 0);
+    ///
+    /// save_file.add_item(DPPTItem::UltraBall, 5);
+    /// save_file.add_item(DPPTItem::MasterBall, 1);
+    /// save_file.add_item(DPPTItem::Potion, 3);
+    /// assert_eq!(save_file.pocket(Pocket::PokeBalls), vec![(Item::Gen4(DPPTItem::MasterBall), 1), (Item::Gen4(DPPTItem::UltraBall), 5)]);
+    /// ```
+    pub fn pocket(&self, pocket: Pocket) -> Vec<(Item, u16)> {
+        let mut items: Vec<(Item, u16)> = self.inventory.iter()
+            .filter(|(item, _)| item.pocket() == Some(pocket))
+            .map(|(item, qty)| (*item, *qty))
+            .collect();
+        items.sort_by_key(|(item, _)| item.id());
+        items
     }
 
     pub fn get_box(&self, box_index: usize) -> &Box {
         &self.boxes[box_index]
     }
+
+    /// Read the `.pk4` file `path` into `location`. A party Pokémon past the end of the party is added
+    /// to the end instead, unless the party is full; it's given battle stats if it came from a box.
+    ///
+    /// Like Pokémon read with the save, it's given the save's trainer if its original trainer IDs match.
+    pub fn import_pk4(&mut self, location: SlotLocation, path: impl Into<PathBuf>) -> Result<(), ReadError> {
+        match location {
+            SlotLocation::Party(index) if index >= self.party.len() && self.party.len() >= PARTY_SIZE => return Err(ReadError::PartyFull),
+            SlotLocation::Party(_) => {}
+            SlotLocation::Box(box_index, index) => match self.boxes.get(box_index) {
+                None => return Err(ReadError::InvalidBox(box_index)),
+                Some(pkmn_box) if index >= pkmn_box.size() => return Err(ReadError::InvalidSlot(index)),
+                Some(_) => {}
+            },
+        }
+
+        let mut pkmn = read_pk4(path)?;
+        let trainer = &self.trainer;
+        if pkmn.gen4().is_some_and(|gen4| gen4.ot_id == trainer.id() && Some(gen4.ot_secret_id) == trainer.secret_id()) {
+            pkmn.trainer = Some(trainer.clone());
+        }
+
+        match location {
+            SlotLocation::Party(index) => {
+                if let Some(gen4) = pkmn.gen4.as_mut().filter(|gen4| gen4.battle_stats.is_none()) {
+                    gen4.battle_stats = Some(gen4.healed_battle_stats());
+                }
+                match index < self.party.len() {
+                    true => self.party[index] = pkmn,
+                    false => self.party.push(pkmn),
+                }
+            }
+            SlotLocation::Box(box_index, index) => self.boxes[box_index].set_pkmn(index, pkmn),
+        }
+        Ok(())
+    }
+
+    /// Write the Pokémon at `location` to `path` as a decrypted `.pk4` file; 236 bytes for the party,
+    /// or 136 for a box.
+    #[cfg(feature = "write")]
+    pub fn export_pk4(&self, location: SlotLocation, path: impl Into<PathBuf>) -> Result<(), WriteError> {
+        let data = match location {
+            SlotLocation::Party(index) => gen4_at(self.party.get(index))?.to_pk4_party()?,
+            SlotLocation::Box(box_index, index) => gen4_at(self.boxes.get(box_index).and_then(|b| b.get_pkmn(index)))?.to_pk4()?,
+        };
+        Ok(std::fs::write(path.into(), data)?)
+    }
+
+    pub fn trainer(&self) -> &Trainer {
+        &self.trainer
+    }
+
+    pub fn money(&self) -> u32 {
+        self.money
+    }
+
+    /// Replace the trainer. Pokémon keep the trainer they had.
+    pub fn set_trainer(&mut self, trainer: Trainer) {
+        self.trainer = trainer;
+    }
+
+    pub fn set_money(&mut self, money: u32) {
+        self.money = money;
+    }
+}
+
+/// A save from any generation, for code that doesn't need to know which
+///
+/// Everything that's common to every generation is in the save's `SaveFile`; the rest is up to each save.
+/// Saves that can be written back can be edited too, see `SaveGameMut`.
+pub trait SaveGame {
+    /// The parts of the save that every generation has
+    fn base(&self) -> &SaveFile;
+
+    /// How long the save has been played for
+    fn playtime(&self) -> Playtime;
+
+    /// The gym badges the player has, in the order they're stored
+    fn badges(&self) -> &[Badge];
+
+    fn trainer(&self) -> &Trainer {
+        self.base().trainer()
+    }
+
+    fn money(&self) -> u32 {
+        self.base().money()
+    }
+
+    fn party(&self) -> &[Pokemon] {
+        &self.base().party
+    }
+
+    fn boxes(&self) -> &[Box] {
+        &self.base().boxes
+    }
+
+    fn inventory(&self) -> &HashMap<Item, u16> {
+        self.base().inventory()
+    }
+
+    /// The items in `pocket`, see `SaveFile::pocket`
+    fn pocket(&self, pocket: Pocket) -> Vec<(Item, u16)> {
+        self.base().pocket(pocket)
+    }
+}
+
+/// A save that can be edited and written back, for code that doesn't need to know which generation
+/// it's from
+///
+/// Only gen 4 saves can be written yet, so only `Gen4Save` implements this.
+#[cfg(feature = "write")]
+pub trait SaveGameMut: SaveGame {
+    fn base_mut(&mut self) -> &mut SaveFile;
+
+    fn set_playtime(&mut self, playtime: Playtime);
+
+    fn set_badges(&mut self, badges: Vec<Badge>);
+
+    fn set_trainer(&mut self, trainer: Trainer) {
+        self.base_mut().set_trainer(trainer);
+    }
+
+    fn set_money(&mut self, money: u32) {
+        self.base_mut().set_money(money);
+    }
+
+    fn party_mut(&mut self) -> &mut Vec<Pokemon> {
+        &mut self.base_mut().party
+    }
+
+    fn boxes_mut(&mut self) -> &mut Vec<Box> {
+        &mut self.base_mut().boxes
+    }
+
+    /// Set the `qty` of `item`, see `SaveFile::set_item`
+    fn set_item(&mut self, item: Item, qty: u16) {
+        self.base_mut().set_item(item, qty);
+    }
 }
 
 impl Trainer {
@@ -162,17 +422,39 @@ impl Trainer {
             gender
         }
     }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn id(&self) -> u16 {
+        self.id
+    }
+
+    pub fn secret_id(&self) -> Option<u16> {
+        self.secret_id
+    }
+
+    pub fn gender(&self) -> &Gender {
+        &self.gender
+    }
 }
 
 impl Box {
-    pub fn new(_box_size: usize) -> Self {
+    pub fn new(box_size: usize) -> Self {
         Self {
             name: String::new(),
             pokemon: HashMap::new(),
-            wallpaper: 0
+            wallpaper: 0,
+            size: box_size,
         }
     }
 
+    /// The number of slots in the box
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
     pub fn set_pkmn(&mut self, index: usize, pokemon: Pokemon) {
         self.pokemon.insert(index, pokemon);
     }
@@ -185,19 +467,50 @@ impl Box {
         self.name = name;
     }
 
-    pub fn name(self) -> String {
-        self.name
+    pub fn name(&self) -> &str {
+        &self.name
     }
 
-    pub fn pkmn(self) -> HashMap<usize, Pokemon> {
-        self.pokemon
+    pub fn pkmn(&self) -> &HashMap<usize, Pokemon> {
+        &self.pokemon
     }
 
     pub fn set_wallpaper(&mut self, wallpaper: u8) {
         self.wallpaper = wallpaper;
     }
 
-    pub fn wallpaper(self) -> u8 {
+    pub fn wallpaper(&self) -> u8 {
         self.wallpaper
     }
+
+    /// Read the `.pk4` file `path` into slot `index`, which must be within the box. See
+    /// `Gen4Pokemon::from_pk4`.
+    pub fn import_pk4(&mut self, index: usize, path: impl Into<PathBuf>) -> Result<(), ReadError> {
+        if index >= self.size {
+            return Err(ReadError::InvalidSlot(index));
+        }
+        self.set_pkmn(index, read_pk4(path)?);
+        Ok(())
+    }
+
+    /// Write the Pokémon in slot `index` to `path` as a decrypted, 136 byte `.pk4` file
+    #[cfg(feature = "write")]
+    pub fn export_pk4(&self, index: usize, path: impl Into<PathBuf>) -> Result<(), WriteError> {
+        let data = gen4_at(self.get_pkmn(index))?.to_pk4()?;
+        Ok(std::fs::write(path.into(), data)?)
+    }
+}
+
+/// Read the `.pk4` file `path` as a `Pokemon`, with no trainer
+fn read_pk4(path: impl Into<PathBuf>) -> Result<Pokemon, ReadError> {
+    let gen4 = Gen4Pokemon::from_pk4(&std::fs::read(path.into())?)?;
+    let mut pkmn = Pokemon::new(gen4.species);
+    pkmn.set_gen4(gen4);
+    Ok(pkmn)
+}
+
+/// The gen 4 data of `pokemon`, for exporting
+#[cfg(feature = "write")]
+fn gen4_at(pokemon: Option<&Pokemon>) -> Result<&Gen4Pokemon, WriteError> {
+    pokemon.and_then(|p| p.gen4()).ok_or(WriteError::MissingPokemon)
 }
\ No newline at end of file